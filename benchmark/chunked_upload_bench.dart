@@ -0,0 +1,42 @@
+import 'dart:io';
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/services/chunked_upload_service.dart';
+import 'package:oxicloud/data/datasources/remote/chunked_upload_datasource.dart';
+
+import 'fake_remote.dart';
+import 'harness.dart';
+
+/// Split a 256 MiB file into chunks and push them to the server.
+void main() {
+  const mib = 1024 * 1024;
+  const sizeMib = 256;
+  late File file;
+
+  final dio = Dio(BaseOptions(baseUrl: 'http://bench/api'))
+    ..httpClientAdapter = FakeRemoteAdapter();
+  final service = ChunkedUploadService(ChunkedUploadDatasource(dio));
+
+  bench(
+    'chunked upload 256 MiB',
+    work: sizeMib,
+    unit: 'MiB',
+    iterations: 3,
+    setUp: () async {
+      final dir = await Directory.systemTemp.createTemp('oxicloud_bench');
+      addTearDown(() => dir.delete(recursive: true));
+      file = File(p.join(dir.path, 'large.bin'));
+      final sink = file.openWrite();
+      final block = Uint8List(mib);
+      for (var i = 0; i < sizeMib; i++) {
+        sink.add(block);
+      }
+      await sink.close();
+    },
+    body: () => service.uploadFile(file: file, filename: 'large.bin'),
+  );
+}
@@ -0,0 +1,115 @@
+import 'dart:convert';
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+
+/// Zero-latency stand-in for the server, answering only the endpoints the
+/// benchmarks exercise. Request bodies are drained so serialization and
+/// chunking costs are still paid.
+class FakeRemoteAdapter implements HttpClientAdapter {
+  /// Pre-encoded folder listing returned for every `/folders/<id>/listing`.
+  final Uint8List listing;
+  int bytesReceived = 0;
+  var _ids = 0;
+
+  FakeRemoteAdapter({Uint8List? listing}) : listing = listing ?? Uint8List(0);
+
+  /// Encode a listing with [files] files and [folders] subfolders.
+  static Uint8List buildListing({required int files, int folders = 0}) {
+    final now = DateTime(2024).toIso8601String();
+    return utf8.encode(
+      json.encode({
+        'folders': [
+          for (var i = 0; i < folders; i++)
+            {
+              'id': 'd$i',
+              'name': 'folder $i',
+              'path': '/bench/folder $i',
+              'parent_id': 'bench',
+              'owner_id': 'u',
+              'is_root': false,
+              'created_at': now,
+              'modified_at': now,
+            },
+        ],
+        'files': [
+          for (var i = 0; i < files; i++)
+            {
+              'id': 'f$i',
+              'name': 'file $i.txt',
+              'path': '/bench/file $i.txt',
+              'size': i * 37 % 65536,
+              'mime_type': 'text/plain',
+              'folder_id': 'bench',
+              'owner_id': 'u',
+              'created_at': now,
+              'modified_at': now,
+            },
+        ],
+      }),
+    );
+  }
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    if (requestStream != null) {
+      await for (final chunk in requestStream) {
+        bytesReceived += chunk.length;
+      }
+    }
+
+    final path = options.uri.path.replaceFirst(RegExp(r'^/api'), '');
+    final method = options.method.toUpperCase();
+    final segments = path.split('/').where((s) => s.isNotEmpty).toList();
+
+    switch ((method, segments)) {
+      case ('GET', ['folders', _, 'listing']):
+        return ResponseBody.fromBytes(listing, 200, headers: _json);
+      case ('POST', ['files', 'upload']):
+        return _reply({
+          'id': 'up${_ids++}',
+          'name': 'upload',
+          'path': '/bench/upload',
+          'size': 0,
+          'mime_type': 'application/octet-stream',
+        });
+      case ('POST', ['uploads']):
+        final body = options.data as Map<String, dynamic>;
+        final total = body['total_size'] as int;
+        final chunk = body['chunk_size'] as int;
+        return _reply({
+          'upload_id': 'session${_ids++}',
+          'chunk_size': chunk,
+          'total_chunks': (total + chunk - 1) ~/ chunk,
+          'expires_at': DateTime.now()
+              .add(const Duration(hours: 1))
+              .toIso8601String(),
+        });
+      case ('PATCH', ['uploads', _]):
+        return ResponseBody.fromString('', 204);
+      case ('POST', ['uploads', final id, 'complete']):
+        return _reply({
+          'file_id': id,
+          'filename': 'large.bin',
+          'size': bytesReceived,
+          'path': '/bench/large.bin',
+        });
+      default:
+        return ResponseBody.fromString('', 404);
+    }
+  }
+
+  static const _json = {
+    Headers.contentTypeHeader: [Headers.jsonContentType],
+  };
+
+  ResponseBody _reply(Map<String, dynamic> body) =>
+      ResponseBody.fromString(json.encode(body), 200, headers: _json);
+
+  @override
+  void close({bool force = false}) {}
+}
@@ -0,0 +1,80 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+
+/// Where accepted results live. Regenerate with `make bench-baseline`.
+const _baselinesPath = 'benchmark/baselines.json';
+
+/// Registers a benchmark as a test. [body] is run once to warm up, then
+/// [iterations] times; the best run is reported as [work] [unit]s per
+/// second and compared with the recorded baseline.
+///
+/// The test fails when throughput drops more than `BENCH_TOLERANCE`
+/// (default 0.2, i.e. 20%) below the baseline. With `BENCH_UPDATE=1` the
+/// result becomes the new baseline instead.
+void bench(
+  String name, {
+  required num work,
+  required String unit,
+  required Future<void> Function() body,
+  Future<void> Function()? setUp,
+  int iterations = 5,
+}) {
+  test(name, () async {
+    await setUp?.call();
+    await body();
+
+    var best = Duration.zero;
+    for (var i = 0; i < iterations; i++) {
+      final watch = Stopwatch()..start();
+      await body();
+      watch.stop();
+      if (best == Duration.zero || watch.elapsed < best) best = watch.elapsed;
+    }
+    final rate = rateOf(work, best);
+    // ignore: avoid_print
+    print('$name: ${rate.toStringAsFixed(1)} $unit/s (best of $iterations)');
+
+    final env = Platform.environment;
+    final baselines = _readBaselines();
+    if (env['BENCH_UPDATE'] == '1') {
+      baselines[name] = {'rate': rate, 'unit': unit};
+      await File(_baselinesPath).writeAsString(
+        '${const JsonEncoder.withIndent('  ').convert(baselines)}\n',
+      );
+      return;
+    }
+
+    final problem = regression(
+      name,
+      rate,
+      baseline: (baselines[name] as Map?)?['rate'] as num?,
+      tolerance: double.tryParse(env['BENCH_TOLERANCE'] ?? '') ?? 0.2,
+    );
+    if (problem != null) fail(problem);
+  }, timeout: Timeout.none);
+}
+
+/// Units of [work] per second when it took [elapsed].
+double rateOf(num work, Duration elapsed) =>
+    work / (elapsed.inMicroseconds / 1e6);
+
+/// Why [rate] counts as a regression from [baseline], or null if it is
+/// within [tolerance] of it or there is no baseline yet.
+String? regression(
+  String name,
+  num rate, {
+  num? baseline,
+  double tolerance = 0.2,
+}) {
+  if (baseline == null || rate >= baseline * (1 - tolerance)) return null;
+  return '$name regressed: ${rate.toStringAsFixed(1)}/s against a '
+      'baseline of ${baseline.toStringAsFixed(1)}/s';
+}
+
+Map<String, dynamic> _readBaselines() {
+  final file = File(_baselinesPath);
+  if (!file.existsSync()) return {};
+  return json.decode(file.readAsStringSync()) as Map<String, dynamic>;
+}
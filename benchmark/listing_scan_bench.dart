@@ -0,0 +1,30 @@
+import 'package:dio/dio.dart';
+
+import 'package:oxicloud/data/datasources/remote/folder_remote_datasource.dart';
+import 'package:oxicloud/data/mappers/file_mapper.dart';
+import 'package:oxicloud/data/mappers/folder_mapper.dart';
+
+import 'fake_remote.dart';
+import 'harness.dart';
+
+/// Fetch, decode and map one remote folder holding 100k entries.
+void main() {
+  const files = 99000;
+  const folders = 1000;
+  final dio = Dio(BaseOptions(baseUrl: 'http://bench/api'))
+    ..httpClientAdapter = FakeRemoteAdapter(
+      listing: FakeRemoteAdapter.buildListing(files: files, folders: folders),
+    );
+  final remote = FolderRemoteDatasource(dio);
+
+  bench(
+    'scan 100k-entry listing',
+    work: files + folders,
+    unit: 'entries',
+    body: () async {
+      final contents = await remote.listFolderContents('bench');
+      FolderMapper.fromDtoList(contents.folders);
+      FileMapper.fromDtoList(contents.files);
+    },
+  );
+}
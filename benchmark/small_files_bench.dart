@@ -0,0 +1,43 @@
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+
+import 'package:oxicloud/core/sync/transfer_scheduler.dart';
+import 'package:oxicloud/data/datasources/remote/file_remote_datasource.dart';
+
+import 'fake_remote.dart';
+import 'harness.dart';
+
+/// Upload 2000 files of 4 KiB through the upload scheduler.
+void main() {
+  const count = 2000;
+  final payload = Uint8List(4096);
+  final dio = Dio(BaseOptions(baseUrl: 'http://bench/api'))
+    ..httpClientAdapter = FakeRemoteAdapter();
+  final remote = FileRemoteDatasource(dio);
+  final scheduler = TransferScheduler(maxConcurrent: 3);
+
+  bench(
+    'upload 2000 small files',
+    work: count,
+    unit: 'files',
+    iterations: 3,
+    body: () async {
+      await Future.wait([
+        for (var i = 0; i < count; i++)
+          scheduler.schedule(
+            id: 'small$i',
+            name: 'small$i.bin',
+            size: payload.length,
+            run: () => remote.uploadFile(
+              name: 'small$i.bin',
+              folderId: 'bench',
+              fileStream: Stream.value(payload),
+              fileSize: payload.length,
+              mimeType: 'application/octet-stream',
+            ),
+          ),
+      ]);
+    },
+  );
+}
@@ -1,8 +1,18 @@
+import 'dart:async';
+import 'dart:io';
+
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:go_router/go_router.dart';
 
 import 'app_router.dart';
+import 'core/network/tls_trust.dart';
+import 'core/sync/sync_events.dart';
 import 'core/theme/app_theme.dart';
+import 'presentation/widgets/trust_certificate_dialog.dart';
+import 'providers.dart';
+
+final _messengerKey = GlobalKey<ScaffoldMessengerState>();
 
 class OxiCloudApp extends ConsumerWidget {
   const OxiCloudApp({super.key});
@@ -11,7 +21,41 @@ class OxiCloudApp extends ConsumerWidget {
   Widget build(BuildContext context, WidgetRef ref) {
     final router = ref.watch(routerProvider);
 
+    // Background work with news for the user, whatever page is open.
+    ref.listen(syncEventsProvider, (_, next) {
+      final event = next.value;
+      if (event is InboxArrivals) {
+        ref.invalidate(inboxItemsProvider);
+        _messengerKey.currentState?.showSnackBar(
+          SnackBar(content: Text(event.message)),
+        );
+      } else if (event is IntegrityDiscrepancies) {
+        _messengerKey.currentState?.showSnackBar(
+          SnackBar(content: Text(event.message)),
+        );
+      } else if (event is CaseConflictsFound) {
+        _messengerKey.currentState?.showSnackBar(
+          SnackBar(content: Text(event.message)),
+        );
+      } else if (event is DiskFull) {
+        _messengerKey.currentState?.showSnackBar(
+          SnackBar(content: Text(event.message)),
+        );
+      } else if (event is ServerStorageFull) {
+        _messengerKey.currentState?.showSnackBar(
+          SnackBar(content: Text(event.message)),
+        );
+      } else if (event is MeteredNetworkChanged) {
+        _messengerKey.currentState?.showSnackBar(
+          SnackBar(content: Text(event.message)),
+        );
+      } else if (event is UntrustedCertificateSeen) {
+        unawaited(_askToTrust(ref, router, event.certificate));
+      }
+    });
+
     return MaterialApp.router(
+      scaffoldMessengerKey: _messengerKey,
       title: 'OxiCloud',
       debugShowCheckedModeBanner: false,
       theme: AppTheme.light,
@@ -21,3 +65,63 @@ class OxiCloudApp extends ConsumerWidget {
     );
   }
 }
+
+/// Ask whether to trust a certificate the server presented, and trust it
+/// for the current account if the user agrees.
+Future<void> _askToTrust(
+  WidgetRef ref,
+  GoRouter router,
+  UntrustedCertificate certificate,
+) async {
+  final context = router.routerDelegate.navigatorKey.currentContext;
+  if (context == null) return;
+  if (await TrustCertificateDialog.show(context, certificate)) {
+    await ref.read(tlsTrustProvider.notifier).trust(certificate.fingerprint);
+  }
+}
+
+/// Shown instead of the app when another instance owns the local database.
+class AlreadyRunningApp extends StatelessWidget {
+  final int pid;
+  final String hostname;
+
+  const AlreadyRunningApp({
+    super.key,
+    required this.pid,
+    required this.hostname,
+  });
+
+  @override
+  Widget build(BuildContext context) {
+    return MaterialApp(
+      title: 'OxiCloud',
+      debugShowCheckedModeBanner: false,
+      theme: AppTheme.light,
+      darkTheme: AppTheme.dark,
+      themeMode: ThemeMode.system,
+      home: Scaffold(
+        body: Center(
+          child: Column(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              const Icon(Icons.lock_outline, size: 48),
+              const SizedBox(height: 16),
+              const Text('OxiCloud is already running'),
+              const SizedBox(height: 8),
+              Text(
+                'Process $pid on $hostname is using the local database. '
+                'Close it before starting another copy.',
+                textAlign: TextAlign.center,
+              ),
+              const SizedBox(height: 24),
+              FilledButton(
+                onPressed: () => exit(0),
+                child: const Text('Quit'),
+              ),
+            ],
+          ),
+        ),
+      ),
+    );
+  }
+}
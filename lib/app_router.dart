@@ -10,6 +10,7 @@ import 'presentation/features/auth/login_page.dart';
 import 'presentation/features/auth/setup_page.dart';
 import 'presentation/features/favorites/favorites_page.dart';
 import 'presentation/features/file_browser/file_browser_page.dart';
+import 'presentation/features/help/help_page.dart';
 import 'presentation/features/photos/photos_page.dart';
 import 'presentation/features/playlists/playlists_page.dart';
 import 'presentation/features/public_share/public_share_page.dart';
@@ -20,7 +21,7 @@ import 'presentation/features/shares/shares_page.dart';
 import 'presentation/features/trash/trash_page.dart';
 
 final routerProvider = Provider<GoRouter>((ref) {
-  return GoRouter(
+  final router = GoRouter(
     initialLocation: '/connect',
     routes: [
       GoRoute(
@@ -56,6 +57,7 @@ final routerProvider = Provider<GoRouter>((ref) {
         path: '/settings',
         builder: (context, state) => const SettingsPage(),
       ),
+      GoRoute(path: '/help', builder: (context, state) => const HelpPage()),
       GoRoute(
         path: '/device-login',
         builder: (context, state) => const DeviceLoginPage(),
@@ -70,8 +72,7 @@ final routerProvider = Provider<GoRouter>((ref) {
       final config = ref.read(appConfigProvider);
       final hasServer = config.hasServer;
       final secureStorage = ref.read(secureStorageProvider);
-      final token = await secureStorage.getAccessToken();
-      final isLoggedIn = token != null;
+      final isLoggedIn = await secureStorage.isSignedIn();
       final loc = state.matchedLocation;
       final isConnectRoute = loc == '/connect';
       final isAuthRoute =
@@ -93,4 +94,6 @@ final routerProvider = Provider<GoRouter>((ref) {
       return null;
     },
   );
+  ref.listen(sessionEndedProvider, (_, _) => router.go('/login'));
+  return router;
 });
@@ -0,0 +1,20 @@
+import 'dart:convert';
+
+/// How the signed-in account authenticates its requests.
+enum AuthMethod {
+  /// A bearer token from the login endpoint, refreshed as it expires.
+  token,
+
+  /// HTTP Basic with the username and an app password, the usual setup
+  /// for Nextcloud and ownCloud clients. There is nothing to refresh.
+  appPassword;
+
+  static AuthMethod parse(String? value) => AuthMethod.values.firstWhere(
+    (m) => m.name == value,
+    orElse: () => token,
+  );
+}
+
+/// The `Authorization` header value for HTTP Basic.
+String basicAuthorization(String username, String password) =>
+    'Basic ${base64.encode(utf8.encode('$username:$password'))}';
@@ -1,52 +1,305 @@
 import 'package:flutter_secure_storage/flutter_secure_storage.dart';
+import '../../domain/entities/share_template.dart';
 import '../config/constants.dart';
+import '../network/interceptors/custom_headers_interceptor.dart';
+import '../network/proxy_settings.dart';
+import '../network/tls_trust.dart';
+import '../services/inbox_service.dart';
+import '../services/usage_stats_service.dart';
+import '../sync/ignore_rules.dart';
+import '../sync/link_policy.dart';
+import '../sync/metered_network.dart';
+import '../sync/storage_optimizer.dart';
+import '../sync/sync_filter.dart';
+import 'auth_method.dart';
 
 class SecureStorage {
-  final FlutterSecureStorage _storage;
+  final FlutterSecureStorage? _storage;
+
+  /// Backing map for [SecureStorage.memory]; never touches the keychain.
+  final Map<String, String>? _memory;
 
   SecureStorage()
     : _storage = const FlutterSecureStorage(
         aOptions: AndroidOptions(encryptedSharedPreferences: true),
         iOptions: IOSOptions(accessibility: KeychainAccessibility.first_unlock),
-      );
+      ),
+      _memory = null;
+
+  /// Non-persistent storage, used by demo mode and tests.
+  SecureStorage.memory([Map<String, String>? initial])
+    : _storage = null,
+      _memory = {...?initial};
+
+  Future<String?> _read(String key) async =>
+      _memory != null ? _memory[key] : _storage!.read(key: key);
+
+  Future<void> _write(String key, String value) async {
+    if (_memory != null) {
+      _memory[key] = value;
+    } else {
+      await _storage!.write(key: key, value: value);
+    }
+  }
+
+  Future<void> _delete(String key) async {
+    if (_memory != null) {
+      _memory.remove(key);
+    } else {
+      await _storage!.delete(key: key);
+    }
+  }
 
   // Tokens
   Future<void> saveAccessToken(String token) =>
-      _storage.write(key: Constants.keyAccessToken, value: token);
+      _write(Constants.keyAccessToken, token);
 
-  Future<String?> getAccessToken() =>
-      _storage.read(key: Constants.keyAccessToken);
+  Future<String?> getAccessToken() => _read(Constants.keyAccessToken);
 
   Future<void> saveRefreshToken(String token) =>
-      _storage.write(key: Constants.keyRefreshToken, value: token);
+      _write(Constants.keyRefreshToken, token);
 
-  Future<String?> getRefreshToken() =>
-      _storage.read(key: Constants.keyRefreshToken);
+  Future<String?> getRefreshToken() => _read(Constants.keyRefreshToken);
 
-  Future<void> saveTokenExpiry(DateTime expiry) => _storage.write(
-    key: Constants.keyTokenExpiry,
-    value: expiry.toIso8601String(),
-  );
+  Future<void> saveTokenExpiry(DateTime expiry) =>
+      _write(Constants.keyTokenExpiry, expiry.toIso8601String());
 
   Future<DateTime?> getTokenExpiry() async {
-    final raw = await _storage.read(key: Constants.keyTokenExpiry);
+    final raw = await _read(Constants.keyTokenExpiry);
     return raw != null ? DateTime.tryParse(raw) : null;
   }
 
   // Server
-  Future<void> saveServerUrl(String url) =>
-      _storage.write(key: Constants.keyServerUrl, value: url);
+  Future<void> saveServerUrl(String url) => _write(Constants.keyServerUrl, url);
 
-  Future<String?> getServerUrl() => _storage.read(key: Constants.keyServerUrl);
+  Future<String?> getServerUrl() => _read(Constants.keyServerUrl);
 
   // User
-  Future<void> saveUserId(String id) =>
-      _storage.write(key: Constants.keyUserId, value: id);
+  Future<void> saveUserId(String id) => _write(Constants.keyUserId, id);
+
+  Future<String?> getUserId() => _read(Constants.keyUserId);
+
+  // Device app password, provisioned at login and revoked at logout
+  Future<void> saveAppPassword(String id, String password) async {
+    await _write(Constants.keyAppPasswordId, id);
+    await _write(Constants.keyAppPassword, password);
+  }
+
+  Future<({String id, String password})?> getAppPassword() async {
+    final id = await _read(Constants.keyAppPasswordId);
+    final password = await _read(Constants.keyAppPassword);
+    if (id == null || password == null) return null;
+    return (id: id, password: password);
+  }
+
+  Future<void> saveAuthMethod(AuthMethod method) =>
+      _write(Constants.keyAuthMethod, method.name);
+
+  Future<AuthMethod> getAuthMethod() async =>
+      AuthMethod.parse(await _read(Constants.keyAuthMethod));
+
+  Future<void> saveUsername(String username) =>
+      _write(Constants.keyUsername, username);
+
+  Future<String?> getUsername() => _read(Constants.keyUsername);
+
+  /// The `Authorization` header for the signed-in account, if any.
+  Future<String?> getAuthorization() async {
+    if (await getAuthMethod() == AuthMethod.appPassword) {
+      final username = await getUsername();
+      final appPassword = await getAppPassword();
+      if (username == null || appPassword == null) return null;
+      return basicAuthorization(username, appPassword.password);
+    }
+    final token = await getAccessToken();
+    return token == null ? null : 'Bearer $token';
+  }
+
+  // Bandwidth limits in KB/s, 0 for unlimited
+  Future<void> saveBandwidthLimits({
+    required int uploadKBps,
+    required int downloadKBps,
+  }) async {
+    await _write(Constants.keyUploadLimit, '$uploadKBps');
+    await _write(Constants.keyDownloadLimit, '$downloadKBps');
+  }
+
+  Future<({int uploadKBps, int downloadKBps})> getBandwidthLimits() async {
+    final upload = await _read(Constants.keyUploadLimit);
+    final download = await _read(Constants.keyDownloadLimit);
+    return (
+      uploadKBps: int.tryParse(upload ?? '') ?? 0,
+      downloadKBps: int.tryParse(download ?? '') ?? 0,
+    );
+  }
+
+  // Extra request headers, per server
+  Future<void> saveCustomHeaders(String serverUrl, CustomHeaders headers) =>
+      _write('${Constants.keyCustomHeadersPrefix}$serverUrl', headers.encode());
+
+  Future<CustomHeaders> getCustomHeaders(String serverUrl) async =>
+      CustomHeaders.decode(
+        await _read('${Constants.keyCustomHeadersPrefix}$serverUrl'),
+      );
+
+  // Proxy for every connection, login included
+  Future<void> saveProxySettings(ProxySettings settings) =>
+      _write(Constants.keyProxySettings, settings.encode());
+
+  Future<ProxySettings> getProxySettings() async =>
+      ProxySettings.decode(await _read(Constants.keyProxySettings));
+
+  // Certificates trusted beyond the system's, per server
+  Future<void> saveTlsTrust(String serverUrl, TlsTrust trust) =>
+      _write('${Constants.keyTlsTrustPrefix}$serverUrl', trust.encode());
+
+  Future<TlsTrust> getTlsTrust(String serverUrl) async =>
+      TlsTrust.decode(await _read('${Constants.keyTlsTrustPrefix}$serverUrl'));
+
+  // Share link templates, for every account or overridden per server
+  Future<void> saveShareTemplates(
+    ShareTemplates templates, {
+    String? serverUrl,
+  }) => _write(
+    serverUrl == null
+        ? Constants.keyShareTemplates
+        : '${Constants.keyShareTemplatesPrefix}$serverUrl',
+    templates.encode(),
+  );
+
+  Future<void> clearShareTemplates(String serverUrl) =>
+      _delete('${Constants.keyShareTemplatesPrefix}$serverUrl');
 
-  Future<String?> getUserId() => _storage.read(key: Constants.keyUserId);
+  Future<ShareTemplates> getShareTemplates(String serverUrl) async =>
+      ShareTemplates.decode(
+        await _read('${Constants.keyShareTemplatesPrefix}$serverUrl'),
+        perAccount: true,
+      ) ??
+      ShareTemplates.decode(await _read(Constants.keyShareTemplates)) ??
+      ShareTemplates.builtIn;
+
+  // Hold back background work while the machine is busy
+  Future<void> saveThrottleOnLoad(bool enabled) =>
+      _write(Constants.keyThrottleOnLoad, '$enabled');
+
+  Future<bool> getThrottleOnLoad() async =>
+      await _read(Constants.keyThrottleOnLoad) == 'true';
+
+  // Hold back sync on metered or roaming connections
+  Future<void> saveMeteredAction(MeteredAction action) =>
+      _write(Constants.keyPauseOnMetered, action.name);
+
+  Future<MeteredAction> getMeteredAction() async =>
+      MeteredAction.parse(await _read(Constants.keyPauseOnMetered));
+
+  Future<void> saveLinkPolicy(LinkPolicy policy) =>
+      _write(Constants.keyLinkPolicy, policy.name);
+
+  Future<LinkPolicy> getLinkPolicy() async =>
+      LinkPolicy.parse(await _read(Constants.keyLinkPolicy));
+
+  // File types included in or excluded from syncing
+  Future<void> saveSyncTypeFilters(SyncFilter filter) async {
+    await _write(Constants.keySyncIncludeTypes, filter.include.join(','));
+    await _write(Constants.keySyncExcludeTypes, filter.exclude.join(','));
+  }
+
+  Future<SyncFilter> getSyncTypeFilters() async {
+    return SyncFilter(
+      include: SyncFilter.parseTypes(
+        await _read(Constants.keySyncIncludeTypes) ?? '',
+      ),
+      exclude: SyncFilter.parseTypes(
+        await _read(Constants.keySyncExcludeTypes) ?? '',
+      ),
+    );
+  }
+
+  // Removing unused local copies when the disk runs low
+  Future<void> saveStorageOptimizer(StorageOptimizerSettings settings) async {
+    await _write(Constants.keyOptimizeStorage, '${settings.enabled}');
+    await _write(Constants.keyOptimizeUnusedDays, '${settings.unusedDays}');
+    await _write(
+      Constants.keyOptimizeMinFreeMb,
+      '${settings.minFreeBytes ~/ (1024 * 1024)}',
+    );
+  }
+
+  Future<StorageOptimizerSettings> getStorageOptimizer() async {
+    const defaults = StorageOptimizerSettings();
+    final days = await _read(Constants.keyOptimizeUnusedDays);
+    final minFreeMb = int.tryParse(
+      await _read(Constants.keyOptimizeMinFreeMb) ?? '',
+    );
+    return StorageOptimizerSettings(
+      enabled: await _read(Constants.keyOptimizeStorage) == 'true',
+      unusedDays: int.tryParse(days ?? '') ?? defaults.unusedDays,
+      minFreeBytes: minFreeMb == null
+          ? defaults.minFreeBytes
+          : minFreeMb * 1024 * 1024,
+    );
+  }
+
+  // Name patterns skipped on top of the built-in ones
+  Future<void> saveSyncExcludePatterns(List<String> patterns) =>
+      _write(Constants.keySyncExcludePatterns, patterns.join('\n'));
+
+  Future<List<String>> getSyncExcludePatterns() async =>
+      IgnoreRuleCache.parsePatterns(
+        await _read(Constants.keySyncExcludePatterns) ?? '',
+      );
+
+  // Local usage counters and whether they may be sent
+  Future<void> saveUsageStatistics(UsageStatisticsSettings settings) async {
+    await _write(Constants.keyUsageStatistics, '${settings.enabled}');
+    await _write(Constants.keyUsageStatisticsUpload, '${settings.upload}');
+  }
+
+  Future<UsageStatisticsSettings> getUsageStatistics() async {
+    return UsageStatisticsSettings(
+      enabled: await _read(Constants.keyUsageStatistics) == 'true',
+      upload: await _read(Constants.keyUsageStatisticsUpload) == 'true',
+    );
+  }
+
+  Future<void> saveUsageStatisticsSentAt(DateTime at) =>
+      _write(Constants.keyUsageStatisticsSentAt, at.toIso8601String());
+
+  Future<DateTime?> getUsageStatisticsSentAt() async =>
+      DateTime.tryParse(await _read(Constants.keyUsageStatisticsSentAt) ?? '');
+
+  // Inbox folder and how arrivals are handled
+  Future<void> saveInboxSettings(InboxSettings settings) async {
+    await _write(Constants.keyInboxFolderId, settings.folderId ?? '');
+    await _write(Constants.keyInboxFolderPath, settings.folderPath ?? '');
+    await _write(Constants.keyInboxDownloadDir, settings.downloadDir ?? '');
+    await _write(Constants.keyInboxScanCommand, settings.scanCommand ?? '');
+  }
+
+  Future<InboxSettings> getInboxSettings() async {
+    return InboxSettings(
+      folderId: await _read(Constants.keyInboxFolderId),
+      folderPath: await _read(Constants.keyInboxFolderPath),
+      downloadDir: await _read(Constants.keyInboxDownloadDir),
+      scanCommand: await _read(Constants.keyInboxScanCommand),
+    );
+  }
+
+  // Passwords of linked public shares, keyed by linked share id
+  Future<void> saveSharePassword(String shareId, String password) =>
+      _write('${Constants.keySharePasswordPrefix}$shareId', password);
+
+  Future<String?> getSharePassword(String shareId) =>
+      _read('${Constants.keySharePasswordPrefix}$shareId');
+
+  Future<void> deleteSharePassword(String shareId) =>
+      _delete('${Constants.keySharePasswordPrefix}$shareId');
 
   // Session management
+  Future<bool> isSignedIn() async => await getAuthorization() != null;
+
   Future<bool> hasValidSession() async {
+    if (await getAuthMethod() == AuthMethod.appPassword) return isSignedIn();
     final token = await getAccessToken();
     if (token == null) return false;
     final expiry = await getTokenExpiry();
@@ -54,12 +307,27 @@ class SecureStorage {
     return expiry.isAfter(DateTime.now());
   }
 
+  /// Forget the session tokens but not the rest of the account.
+  Future<void> clearTokens() async {
+    await _delete(Constants.keyAccessToken);
+    await _delete(Constants.keyRefreshToken);
+    await _delete(Constants.keyTokenExpiry);
+  }
+
   Future<void> clearSession() async {
-    await _storage.delete(key: Constants.keyAccessToken);
-    await _storage.delete(key: Constants.keyRefreshToken);
-    await _storage.delete(key: Constants.keyTokenExpiry);
-    await _storage.delete(key: Constants.keyUserId);
+    await clearTokens();
+    await _delete(Constants.keyUserId);
+    await _delete(Constants.keyAppPasswordId);
+    await _delete(Constants.keyAppPassword);
+    await _delete(Constants.keyAuthMethod);
+    await _delete(Constants.keyUsername);
   }
 
-  Future<void> clearAll() => _storage.deleteAll();
+  Future<void> clearAll() async {
+    if (_memory != null) {
+      _memory.clear();
+    } else {
+      await _storage!.deleteAll();
+    }
+  }
 }
@@ -0,0 +1,48 @@
+import '../../data/datasources/remote/auth_remote_datasource.dart';
+import '../../data/dtos/auth/auth_dtos.dart';
+import '../../data/mappers/auth_mapper.dart';
+import '../error/error_handler.dart';
+import '../error/exceptions.dart';
+import 'auth_method.dart';
+import 'secure_storage.dart';
+
+/// Trades the stored refresh token for a new access token when the
+/// server rejects the current one.
+class TokenRefresher {
+  final SecureStorage _secureStorage;
+  final AuthRemoteDatasource _remote;
+  Future<bool>? _running;
+
+  TokenRefresher({
+    required SecureStorage secureStorage,
+    required AuthRemoteDatasource remote,
+  }) : _secureStorage = secureStorage,
+       _remote = remote;
+
+  /// Whether the session was renewed. Concurrent callers share one
+  /// refresh. False means the user has to sign in again; throws
+  /// [ServerException] if the server could not be reached.
+  Future<bool> refresh() =>
+      _running ??= _refresh().whenComplete(() => _running = null);
+
+  Future<bool> _refresh() async {
+    if (await _secureStorage.getAuthMethod() != AuthMethod.token) {
+      return false;
+    }
+    final refreshToken = await _secureStorage.getRefreshToken();
+    if (refreshToken == null) return false;
+    try {
+      final dto = await _remote.refreshToken(
+        RefreshTokenRequestDto(refreshToken: refreshToken),
+      );
+      final tokens = AuthMapper.tokensFromDto(dto);
+      await _secureStorage.saveAccessToken(tokens.accessToken);
+      await _secureStorage.saveRefreshToken(tokens.refreshToken);
+      await _secureStorage.saveTokenExpiry(tokens.expiresAt);
+      return true;
+    } on ServerException catch (e) {
+      if (ErrorHandler.isConnectionError(e)) rethrow;
+      return false;
+    }
+  }
+}
@@ -1,5 +1,8 @@
 import 'package:flutter/foundation.dart';
 
+import '../sync/checksum_negotiation.dart';
+import '../sync/transfer_scheduler.dart';
+
 enum Environment { dev, staging, prod }
 
 class AppConfig {
@@ -7,6 +10,14 @@ class AppConfig {
   final Environment environment;
   final Duration connectTimeout;
   final Duration receiveTimeout;
+  final Duration sendTimeout;
+
+  /// Receive timeout for folder listings, which are small and block the UI.
+  final Duration listingReceiveTimeout;
+
+  /// Longest an API call other than an upload or download may take in
+  /// total before it is cancelled.
+  final Duration requestDeadline;
   final int chunkSizeBytes;
   final int chunkThresholdBytes;
   final int maxConcurrentUploads;
@@ -14,17 +25,103 @@ class AppConfig {
   final int syncIntervalSeconds;
   final int maxRetries;
 
+  /// Server upload sessions older than this with nothing to resume them
+  /// are deleted.
+  final Duration staleUploadAge;
+  final Duration uploadCleanupInterval;
+
+  /// Every cached folder is refreshed at least this often, however rarely
+  /// it changes.
+  final Duration fullRescanInterval;
+
+  /// How often the folder open in the file browser is checked for changes.
+  final Duration openFolderPollInterval;
+
+  /// Fraction of uploads re-checked against the server after each sync
+  /// cycle; 1.0 checks all of them.
+  final double ledgerSampleRate;
+
+  /// Restore the Unix permissions of files uploaded from this device when
+  /// they are downloaded again.
+  final bool preserveFileModes;
+
+  /// Restore items deleted on the server when a queued rename or move for
+  /// them comes in, instead of turning the edit into a conflict.
+  final bool restoreDeletedOnEdit;
+
+  /// Drop the local copy of files that disappear from external storage.
+  /// Off by default, since such storages can briefly list folders empty.
+  final bool propagateExternalDeletes;
+
+  /// Bulk operations touching at least this many items leave a report on
+  /// disk listing what was done, what failed and what was skipped.
+  final int operationReportThreshold;
+
+  /// Share of an account's sync requests that may fail before its sync
+  /// slows down.
+  final double maxSyncFailureRate;
+
+  /// Checksum type to use when the server supports it; otherwise the
+  /// strongest one both sides know is picked.
+  final ChecksumAlgorithm? preferredChecksum;
+
+  /// Order in which queued uploads get a slot.
+  final TransferPriorityPolicy transferPriority;
+
+  /// Files larger than this are not synced; 0 means no limit.
+  final int maxFileSizeBytes;
+
+  /// Disk images at least this large are uploaded as segmented bundles,
+  /// see `BundleService`; 0 turns bundles off.
+  final int bundleMinSizeBytes;
+
+  /// Files up to this size are uploaded several to a request, see
+  /// `BulkUploadService`; 0 uploads every file on its own.
+  final int bulkUploadMaxFileBytes;
+
+  /// Most files and bytes sent in one batch upload.
+  final int bulkUploadMaxFiles;
+  final int bulkUploadMaxBytes;
+
+  /// Uploads kept in flight at once when the server takes no batches.
+  final int uploadPipelineDepth;
+
+  /// Folder listings fetched at once while scanning the account.
+  final int listingConcurrency;
+
   const AppConfig({
     required this.serverUrl,
     this.environment = Environment.prod,
     this.connectTimeout = const Duration(seconds: 15),
     this.receiveTimeout = const Duration(seconds: 60),
+    this.sendTimeout = const Duration(seconds: 60),
+    this.listingReceiveTimeout = const Duration(seconds: 30),
+    this.requestDeadline = const Duration(minutes: 2),
     this.chunkSizeBytes = 5 * 1024 * 1024, // 5 MB
     this.chunkThresholdBytes = 10 * 1024 * 1024, // 10 MB
     this.maxConcurrentUploads = 3,
     this.maxConcurrentDownloads = 5,
     this.syncIntervalSeconds = 30,
     this.maxRetries = 3,
+    this.staleUploadAge = const Duration(hours: 24),
+    this.uploadCleanupInterval = const Duration(hours: 1),
+    this.fullRescanInterval = const Duration(hours: 6),
+    this.openFolderPollInterval = const Duration(seconds: 15),
+    this.ledgerSampleRate = 1.0,
+    this.preserveFileModes = true,
+    this.restoreDeletedOnEdit = true,
+    this.propagateExternalDeletes = false,
+    this.operationReportThreshold = 100,
+    this.maxSyncFailureRate = 0.3,
+    this.transferPriority = TransferPriorityPolicy.fifo,
+    this.maxFileSizeBytes = 0,
+    this.bundleMinSizeBytes = 1024 * 1024 * 1024, // 1 GB
+    this.bulkUploadMaxFileBytes = 1024 * 1024, // 1 MB
+    this.bulkUploadMaxFiles = 100,
+    this.bulkUploadMaxBytes = 16 * 1024 * 1024, // 16 MB
+    this.uploadPipelineDepth = 6,
+    this.listingConcurrency = 4,
+    this.preferredChecksum,
   });
 
   String get apiBaseUrl => '$serverUrl/api';
@@ -0,0 +1,184 @@
+import 'app_config.dart';
+
+/// A setting that is out of range or inconsistent with another one.
+/// [field] is the name of the [AppConfig] field, so a settings form can
+/// show the message next to the right input.
+class ConfigError {
+  final String field;
+  final String message;
+
+  const ConfigError(this.field, this.message);
+
+  @override
+  String toString() => '$field: $message';
+}
+
+/// Checks an [AppConfig] before it is used or persisted.
+class ConfigValidator {
+  ConfigValidator._();
+
+  static const minChunkSize = 256 * 1024;
+  static const maxChunkSize = 100 * 1024 * 1024;
+  static const maxConcurrentTransfers = 16;
+
+  static List<ConfigError> validate(AppConfig config) {
+    final errors = <ConfigError>[];
+    void check(bool ok, String field, String message) {
+      if (!ok) errors.add(ConfigError(field, message));
+    }
+
+    // An empty URL means no server has been chosen yet.
+    if (config.serverUrl.isNotEmpty) {
+      final urlError = validateServerUrl(config.serverUrl);
+      if (urlError != null) errors.add(ConfigError('serverUrl', urlError));
+    }
+
+    check(
+      config.connectTimeout >= const Duration(seconds: 1),
+      'connectTimeout',
+      'Must be at least 1 second',
+    );
+    check(
+      config.receiveTimeout >= const Duration(seconds: 1),
+      'receiveTimeout',
+      'Must be at least 1 second',
+    );
+    check(
+      config.sendTimeout >= const Duration(seconds: 1),
+      'sendTimeout',
+      'Must be at least 1 second',
+    );
+    check(
+      config.listingReceiveTimeout >= const Duration(seconds: 1),
+      'listingReceiveTimeout',
+      'Must be at least 1 second',
+    );
+    check(
+      config.requestDeadline > config.connectTimeout,
+      'requestDeadline',
+      'Must be longer than the connect timeout',
+    );
+    check(
+      config.chunkSizeBytes >= minChunkSize &&
+          config.chunkSizeBytes <= maxChunkSize,
+      'chunkSizeBytes',
+      'Must be between 256 KB and 100 MB',
+    );
+    check(
+      config.chunkThresholdBytes >= config.chunkSizeBytes,
+      'chunkThresholdBytes',
+      'Must not be smaller than the chunk size',
+    );
+    check(
+      config.maxConcurrentUploads >= 1 &&
+          config.maxConcurrentUploads <= maxConcurrentTransfers,
+      'maxConcurrentUploads',
+      'Must be between 1 and $maxConcurrentTransfers',
+    );
+    check(
+      config.maxConcurrentDownloads >= 1 &&
+          config.maxConcurrentDownloads <= maxConcurrentTransfers,
+      'maxConcurrentDownloads',
+      'Must be between 1 and $maxConcurrentTransfers',
+    );
+    check(
+      config.syncIntervalSeconds >= 5,
+      'syncIntervalSeconds',
+      'Must be at least 5 seconds',
+    );
+    check(
+      config.maxRetries >= 0 && config.maxRetries <= 10,
+      'maxRetries',
+      'Must be between 0 and 10',
+    );
+    check(
+      config.staleUploadAge >= const Duration(hours: 1),
+      'staleUploadAge',
+      'Must be at least 1 hour',
+    );
+    check(
+      config.uploadCleanupInterval >= const Duration(minutes: 1),
+      'uploadCleanupInterval',
+      'Must be at least 1 minute',
+    );
+    check(
+      config.fullRescanInterval >= const Duration(minutes: 5),
+      'fullRescanInterval',
+      'Must be at least 5 minutes',
+    );
+    check(
+      config.openFolderPollInterval >= const Duration(seconds: 5) &&
+          config.openFolderPollInterval < config.fullRescanInterval,
+      'openFolderPollInterval',
+      'Must be at least 5 seconds and shorter than the full rescan interval',
+    );
+    check(
+      config.ledgerSampleRate >= 0 && config.ledgerSampleRate <= 1,
+      'ledgerSampleRate',
+      'Must be between 0 and 1',
+    );
+    check(
+      config.operationReportThreshold >= 1,
+      'operationReportThreshold',
+      'Must be at least 1',
+    );
+    check(
+      config.maxSyncFailureRate > 0 && config.maxSyncFailureRate < 1,
+      'maxSyncFailureRate',
+      'Must be between 0 and 1',
+    );
+    check(
+      config.maxFileSizeBytes >= 0,
+      'maxFileSizeBytes',
+      'Must not be negative',
+    );
+    check(
+      config.bundleMinSizeBytes >= 0,
+      'bundleMinSizeBytes',
+      'Must not be negative',
+    );
+    check(
+      config.bulkUploadMaxFileBytes >= 0,
+      'bulkUploadMaxFileBytes',
+      'Must not be negative',
+    );
+    check(
+      config.bulkUploadMaxFiles >= 1,
+      'bulkUploadMaxFiles',
+      'Must be at least 1',
+    );
+    check(
+      config.bulkUploadMaxBytes >= config.bulkUploadMaxFileBytes,
+      'bulkUploadMaxBytes',
+      'Must be at least bulkUploadMaxFileBytes',
+    );
+    check(
+      config.uploadPipelineDepth >= 1,
+      'uploadPipelineDepth',
+      'Must be at least 1',
+    );
+    check(
+      config.listingConcurrency >= 1,
+      'listingConcurrency',
+      'Must be at least 1',
+    );
+    return errors;
+  }
+
+  /// Returns why [url] cannot be used as a server address, or null.
+  static String? validateServerUrl(String url) {
+    final trimmed = url.trim();
+    if (trimmed.isEmpty) return 'Server URL is required';
+    if (!trimmed.startsWith('http://') && !trimmed.startsWith('https://')) {
+      return 'URL must start with http:// or https://';
+    }
+    final uri = Uri.tryParse(trimmed);
+    if (uri == null || !uri.hasAuthority || uri.host.isEmpty) {
+      return 'Invalid URL';
+    }
+    if (uri.hasQuery || uri.hasFragment) {
+      return 'URL must not contain a query or fragment';
+    }
+    return null;
+  }
+}
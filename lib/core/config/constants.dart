@@ -34,4 +34,32 @@ class Constants {
   static const String keyServerUrl = 'server_url';
   static const String keyUserId = 'user_id';
   static const String keyTokenExpiry = 'token_expiry';
+  static const String keyAppPasswordId = 'app_password_id';
+  static const String keyAppPassword = 'app_password';
+  static const String keyAuthMethod = 'auth_method';
+  static const String keyUsername = 'username';
+  static const String keySharePasswordPrefix = 'share_password_';
+  static const String keyUploadLimit = 'upload_limit_kbps';
+  static const String keyDownloadLimit = 'download_limit_kbps';
+  static const String keyThrottleOnLoad = 'throttle_on_load';
+  static const String keyPauseOnMetered = 'pause_on_metered';
+  static const String keySyncIncludeTypes = 'sync_include_types';
+  static const String keySyncExcludeTypes = 'sync_exclude_types';
+  static const String keySyncExcludePatterns = 'sync_exclude_patterns';
+  static const String keyOptimizeStorage = 'optimize_storage';
+  static const String keyOptimizeUnusedDays = 'optimize_storage_unused_days';
+  static const String keyOptimizeMinFreeMb = 'optimize_storage_min_free_mb';
+  static const String keyShareTemplates = 'share_templates';
+  static const String keyShareTemplatesPrefix = 'share_templates_';
+  static const String keyCustomHeadersPrefix = 'custom_headers_';
+  static const String keyTlsTrustPrefix = 'tls_trust_';
+  static const String keyProxySettings = 'proxy_settings';
+  static const String keyInboxFolderId = 'inbox_folder_id';
+  static const String keyInboxFolderPath = 'inbox_folder_path';
+  static const String keyInboxDownloadDir = 'inbox_download_dir';
+  static const String keyInboxScanCommand = 'inbox_scan_command';
+  static const String keyLinkPolicy = 'link_policy';
+  static const String keyUsageStatistics = 'usage_statistics';
+  static const String keyUsageStatisticsUpload = 'usage_statistics_upload';
+  static const String keyUsageStatisticsSentAt = 'usage_statistics_sent_at';
 }
@@ -3,11 +3,32 @@ import 'package:drift/drift.dart';
 import 'package:drift/native.dart';
 import 'package:path/path.dart' as p;
 
+import 'external_views.dart';
+import 'tables/activity_log_table.dart';
 import 'tables/cached_files_table.dart';
+import 'tables/cached_listings_table.dart';
+import 'tables/conflict_copies_table.dart';
+import 'tables/conflict_rules_table.dart';
+import 'tables/content_digests_table.dart';
+import 'tables/db_lease_table.dart';
+import 'tables/file_modes_table.dart';
 import 'tables/files_table.dart';
+import 'tables/folder_scan_state_table.dart';
+import 'tables/folder_sync_modes_table.dart';
 import 'tables/folders_table.dart';
+import 'tables/inbox_items_table.dart';
+import 'tables/inbox_rules_table.dart';
+import 'tables/linked_shares_table.dart';
+import 'tables/operations_table.dart';
+import 'tables/partial_downloads_table.dart';
 import 'tables/sync_conflicts_table.dart';
+import 'tables/sync_errors_table.dart';
+import 'tables/sync_exclusions_table.dart';
 import 'tables/sync_queue_table.dart';
+import 'tables/sync_stats_table.dart';
+import 'tables/transfer_ledger_table.dart';
+import 'tables/upload_sessions_table.dart';
+import 'tables/usage_counters_table.dart';
 import 'tables/user_table.dart';
 
 part 'app_database.g.dart';
@@ -20,22 +41,144 @@ part 'app_database.g.dart';
     SyncConflictsTable,
     CachedFilesTable,
     UserTable,
+    UploadSessionsTable,
+    ActivityLogTable,
+    FolderScanStateTable,
+    LinkedSharesTable,
+    TransferLedgerTable,
+    FileModesTable,
+    DbLeaseTable,
+    ConflictRulesTable,
+    CachedListingsTable,
+    SyncExclusionsTable,
+    FolderSyncModesTable,
+    ConflictCopiesTable,
+    InboxRulesTable,
+    InboxItemsTable,
+    SyncStatsTable,
+    FolderSyncStatsTable,
+    OperationsTable,
+    UsageCountersTable,
+    SyncErrorsTable,
+    PartialDownloadsTable,
+    ContentDigestsTable,
   ],
 )
 class AppDatabase extends _$AppDatabase {
   AppDatabase(super.e);
 
   @override
-  int get schemaVersion => 2;
+  int get schemaVersion => 30;
 
   @override
   MigrationStrategy get migration => MigrationStrategy(
     onCreate: (m) => m.createAll(),
     onUpgrade: (m, from, to) async {
+      await ExternalViews.drop(this);
       if (from < 2) {
         await m.createTable(syncConflictsTable);
       }
+      if (from < 3) {
+        await m.createTable(uploadSessionsTable);
+      }
+      if (from < 4) {
+        await m.createTable(activityLogTable);
+      }
+      if (from < 5) {
+        await m.createTable(folderScanStateTable);
+      }
+      if (from < 6) {
+        await m.createTable(linkedSharesTable);
+      }
+      if (from < 7) {
+        await m.createTable(transferLedgerTable);
+      }
+      if (from < 8) {
+        await m.createTable(fileModesTable);
+      }
+      if (from < 9) {
+        await m.createTable(dbLeaseTable);
+      }
+      if (from < 10) {
+        await m.createTable(conflictRulesTable);
+      }
+      if (from < 11) {
+        await m.createTable(cachedListingsTable);
+      }
+      if (from < 12) {
+        await m.createTable(syncExclusionsTable);
+      }
+      if (from >= 5 && from < 13) {
+        await m.addColumn(folderScanStateTable, folderScanStateTable.syncToken);
+      }
+      // Before version 2 the table was just created with the column.
+      if (from >= 2 && from < 14) {
+        await m.addColumn(syncConflictsTable, syncConflictsTable.queuedAt);
+      }
+      if (from < 15) {
+        await m.createTable(conflictCopiesTable);
+      }
+      if (from < 16) {
+        await m.addColumn(userTable, userTable.checksumAlgorithm);
+      }
+      if (from >= 3 && from < 16) {
+        await m.addColumn(
+          uploadSessionsTable,
+          uploadSessionsTable.checksumAlgorithm,
+        );
+      }
+      if (from < 17) {
+        await m.createTable(inboxRulesTable);
+        await m.createTable(inboxItemsTable);
+      }
+      if (from < 18) {
+        await m.addColumn(foldersTable, foldersTable.mountType);
+        await m.addColumn(filesTable, filesTable.mountType);
+      }
+      if (from < 19) {
+        await m.addColumn(cachedFilesTable, cachedFilesTable.etag);
+        await m.addColumn(cachedFilesTable, cachedFilesTable.state);
+        await m.addColumn(cachedFilesTable, cachedFilesTable.verifiedAt);
+      }
+      if (from < 20) {
+        await m.addColumn(cachedFilesTable, cachedFilesTable.baseContent);
+      }
+      if (from < 21) {
+        await m.createTable(syncStatsTable);
+        await m.createTable(folderSyncStatsTable);
+      }
+      if (from < 22) {
+        await m.createTable(operationsTable);
+      }
+      if (from < 23) {
+        await m.createTable(usageCountersTable);
+      }
+      if (from < 24) {
+        await m.createTable(syncErrorsTable);
+      }
+      if (from < 25) {
+        await m.createTable(partialDownloadsTable);
+      }
+      if (from < 26) {
+        await m.createTable(contentDigestsTable);
+      }
+      if (from < 27) {
+        await m.createTable(folderSyncModesTable);
+      }
+      if (from < 28) {
+        await m.addColumn(syncQueueTable, syncQueueTable.transientRetryCount);
+      }
+      if (from >= 3 && from < 29) {
+        await m.addColumn(
+          uploadSessionsTable,
+          uploadSessionsTable.localModifiedAt,
+        );
+      }
+      if (from >= 2 && from < 30) {
+        await m.addColumn(syncConflictsTable, syncConflictsTable.resolvedBy);
+      }
     },
+    beforeOpen: (_) => ExternalViews.install(this),
   );
 
   // --- Files ---
@@ -110,6 +253,247 @@ class AppDatabase extends _$AppDatabase {
     return (delete(foldersTable)..where((f) => f.id.equals(id))).go();
   }
 
+  Future<List<FoldersTableData>> getAllFolders() {
+    return select(foldersTable).get();
+  }
+
+  // --- Cached Listings ---
+
+  /// When the listing of [folderId] (null for the root) was last stored.
+  Future<DateTime?> getListedAt(String? folderId) async {
+    final query = select(cachedListingsTable)
+      ..where((l) => l.folderId.equals(folderId ?? ''));
+    return (await query.getSingleOrNull())?.listedAt;
+  }
+
+  /// Replace the cached children of [folderId] (null for the root) with a
+  /// fresh listing, dropping entries the server no longer returns. With
+  /// [keepOfflineFiles], files available offline are kept regardless.
+  Future<void> storeListing(
+    String? folderId,
+    List<FoldersTableCompanion> folders,
+    List<FilesTableCompanion> files, {
+    bool keepOfflineFiles = false,
+  }) {
+    final folderIds = [for (final f in folders) f.id.value];
+    final fileIds = [for (final f in files) f.id.value];
+    Expression<bool> inFolder(GeneratedColumn<String> parent) =>
+        folderId == null ? parent.isNull() : parent.equals(folderId);
+
+    return transaction(() async {
+      final staleFolders = delete(foldersTable)
+        ..where((f) => inFolder(f.parentId) & f.id.isNotIn(folderIds));
+      await staleFolders.go();
+      final staleFiles = delete(filesTable)
+        ..where((f) => inFolder(f.folderId) & f.id.isNotIn(fileIds));
+      if (keepOfflineFiles) {
+        staleFiles.where((f) => f.isAvailableOffline.equals(false));
+      }
+      await staleFiles.go();
+      await upsertFolders(folders);
+      await upsertFiles(files);
+      await into(cachedListingsTable).insertOnConflictUpdate(
+        CachedListingsTableCompanion.insert(
+          folderId: folderId ?? '',
+          listedAt: DateTime.now(),
+        ),
+      );
+    });
+  }
+
+  // --- Conflict Copies ---
+
+  Future<int> insertConflictCopy(ConflictCopiesTableCompanion entry) {
+    return into(conflictCopiesTable).insert(entry);
+  }
+
+  /// Copies the user has not dismissed yet, newest first.
+  Future<List<ConflictCopiesTableData>> getOutstandingConflictCopies() {
+    final query = select(conflictCopiesTable)
+      ..where((c) => c.dismissedAt.isNull())
+      ..orderBy([(c) => OrderingTerm.desc(c.createdAt)]);
+    return query.get();
+  }
+
+  Future<int> dismissConflictCopy(int id) {
+    final query = update(conflictCopiesTable)..where((c) => c.id.equals(id));
+    return query.write(
+      ConflictCopiesTableCompanion(dismissedAt: Value(DateTime.now())),
+    );
+  }
+
+  // --- Sync Exclusions ---
+
+  Future<List<SyncExclusionsTableData>> getSyncExclusions() {
+    final query = select(syncExclusionsTable)
+      ..orderBy([(e) => OrderingTerm.asc(e.path)]);
+    return query.get();
+  }
+
+  Future<void> upsertSyncExclusion(SyncExclusionsTableCompanion entry) {
+    return into(syncExclusionsTable).insertOnConflictUpdate(entry);
+  }
+
+  Future<int> deleteSyncExclusion(String folderId) {
+    return (delete(
+      syncExclusionsTable,
+    )..where((e) => e.folderId.equals(folderId))).go();
+  }
+
+  /// Whether [path] is an excluded folder or lies inside one.
+  Future<bool> isPathExcludedFromSync(String path) async {
+    final exclusions = await getSyncExclusions();
+    return exclusions.any(
+      (e) => path == e.path || path.startsWith('${e.path}/'),
+    );
+  }
+
+  /// Drop the mirrored contents of folder [folderId] at [path]: every
+  /// folder and file below it and their cached listings. The folder's own
+  /// row stays so it still shows up in its parent.
+  Future<void> pruneMirror(String folderId, String path) {
+    return transaction(() async {
+      final below = [
+        for (final f in await getAllFolders())
+          if (f.path.startsWith('$path/')) f.id,
+      ];
+      final parents = [folderId, ...below];
+      await (delete(filesTable)..where((f) => f.folderId.isIn(parents))).go();
+      await (delete(foldersTable)..where((f) => f.id.isIn(below))).go();
+      await (delete(
+        cachedListingsTable,
+      )..where((l) => l.folderId.isIn(parents))).go();
+      await (delete(
+        folderScanStateTable,
+      )..where((s) => s.folderId.isIn(parents))).go();
+    });
+  }
+
+  // --- Folder Sync Modes ---
+
+  Future<List<FolderSyncModesTableData>> getFolderSyncModes() {
+    final query = select(folderSyncModesTable)
+      ..orderBy([(m) => OrderingTerm.asc(m.path)]);
+    return query.get();
+  }
+
+  Future<void> upsertFolderSyncMode(FolderSyncModesTableCompanion entry) {
+    return into(folderSyncModesTable).insertOnConflictUpdate(entry);
+  }
+
+  Future<int> deleteFolderSyncMode(String folderId) {
+    return (delete(
+      folderSyncModesTable,
+    )..where((m) => m.folderId.equals(folderId))).go();
+  }
+
+  /// The mode set on [path] or the closest folder above it, if any.
+  Future<FolderSyncModesTableData?> getFolderSyncModeForPath(
+    String path,
+  ) async {
+    final modes = await getFolderSyncModes();
+    return modes
+        .where((m) => path == m.path || path.startsWith('${m.path}/'))
+        .lastOrNull;
+  }
+
+  // --- Sync Statistics ---
+
+  /// Add to the totals of the day starting at [day].
+  Future<void> addSyncStats(
+    DateTime day, {
+    int bytesUploaded = 0,
+    int bytesDownloaded = 0,
+    int filesSynced = 0,
+    int errors = 0,
+    int transferMillis = 0,
+  }) {
+    return into(syncStatsTable).insert(
+      SyncStatsTableCompanion.insert(
+        day: day,
+        bytesUploaded: Value(bytesUploaded),
+        bytesDownloaded: Value(bytesDownloaded),
+        filesSynced: Value(filesSynced),
+        errors: Value(errors),
+        transferMillis: Value(transferMillis),
+      ),
+      onConflict: DoUpdate(
+        (old) => SyncStatsTableCompanion.custom(
+          bytesUploaded: old.bytesUploaded + Constant(bytesUploaded),
+          bytesDownloaded: old.bytesDownloaded + Constant(bytesDownloaded),
+          filesSynced: old.filesSynced + Constant(filesSynced),
+          errors: old.errors + Constant(errors),
+          transferMillis: old.transferMillis + Constant(transferMillis),
+        ),
+      ),
+    );
+  }
+
+  /// Daily totals from the day starting at [since] on, oldest first.
+  Future<List<SyncStatsTableData>> getSyncStats({DateTime? since}) {
+    final query = select(syncStatsTable)
+      ..orderBy([(s) => OrderingTerm.asc(s.day)]);
+    if (since != null) {
+      query.where((s) => s.day.isBiggerOrEqualValue(since));
+    }
+    return query.get();
+  }
+
+  Future<void> markFolderSynced(String folderId, DateTime at) {
+    return into(folderSyncStatsTable).insertOnConflictUpdate(
+      FolderSyncStatsTableCompanion.insert(
+        folderId: folderId,
+        lastSyncedAt: at,
+      ),
+    );
+  }
+
+  Future<List<FolderSyncStatsTableData>> getFolderSyncStats() {
+    final query = select(folderSyncStatsTable)
+      ..orderBy([(s) => OrderingTerm.desc(s.lastSyncedAt)]);
+    return query.get();
+  }
+
+  // --- Usage Counters ---
+  Future<void> countUsage(String feature, DateTime at) {
+    return into(usageCountersTable).insert(
+      UsageCountersTableCompanion.insert(
+        feature: feature,
+        count: const Value(1),
+        lastUsedAt: at,
+      ),
+      onConflict: DoUpdate(
+        (old) => UsageCountersTableCompanion.custom(
+          count: old.count + const Constant(1),
+          lastUsedAt: Constant(at),
+        ),
+      ),
+    );
+  }
+
+  Future<List<UsageCountersTableData>> getUsageCounters() {
+    final query = select(usageCountersTable)
+      ..orderBy([(u) => OrderingTerm.desc(u.count)]);
+    return query.get();
+  }
+
+  Future<int> clearUsageCounters() => delete(usageCountersTable).go();
+
+  // --- Folder Scan State ---
+  Future<List<FolderScanStateTableData>> getFolderScanStates() {
+    return select(folderScanStateTable).get();
+  }
+
+  Future<void> upsertFolderScanState(FolderScanStateTableCompanion entry) {
+    return into(folderScanStateTable).insertOnConflictUpdate(entry);
+  }
+
+  Future<int> deleteFolderScanState(String folderId) {
+    return (delete(
+      folderScanStateTable,
+    )..where((s) => s.folderId.equals(folderId))).go();
+  }
+
   // --- Sync Queue ---
   Future<List<SyncQueueTableData>> getPendingSyncOps({int limit = 10}) {
     return (select(syncQueueTable)
@@ -126,6 +510,47 @@ class AppDatabase extends _$AppDatabase {
     return into(syncQueueTable).insert(op);
   }
 
+  /// The pending or failed operations that may run, in priority order,
+  /// leaving out [skip] and retries not due by [dueBy].
+  Future<List<SyncQueueTableData>> dueSyncOps({
+    Set<int> skip = const {},
+    DateTime? dueBy,
+  }) {
+    return (select(syncQueueTable)
+          ..where(
+            (s) =>
+                (s.status.equals('pending') | s.status.equals('failed')) &
+                s.id.isNotIn(skip) &
+                (dueBy == null
+                    ? const Constant(true)
+                    : s.scheduledAt.isNull() |
+                          s.scheduledAt.isSmallerOrEqualValue(dueBy)),
+          )
+          ..orderBy([
+            (s) => OrderingTerm.desc(s.priority),
+            (s) => OrderingTerm.asc(s.createdAt),
+          ]))
+        .get();
+  }
+
+  /// Raise the queued operations on [itemId] to [priority] and make any
+  /// waiting retry due now. Returns how many were changed.
+  Future<int> setSyncOpPriority(String itemId, int priority) {
+    final query = update(syncQueueTable)
+      ..where(
+        (s) =>
+            s.itemId.equals(itemId) &
+            (s.status.equals('pending') | s.status.equals('failed')),
+      );
+    return query.write(
+      SyncQueueTableCompanion(
+        priority: Value(priority),
+        scheduledAt: const Value(null),
+        updatedAt: Value(DateTime.now()),
+      ),
+    );
+  }
+
   Future<void> updateSyncOpStatus(
     int id,
     String status, {
@@ -146,11 +571,189 @@ class AppDatabase extends _$AppDatabase {
     )..where((s) => s.status.equals('completed'))).go();
   }
 
-  Future<void> incrementSyncOpRetry(int id) {
-    return customStatement(
-      'UPDATE sync_queue SET retry_count = retry_count + 1, '
-      'updated_at = ? WHERE id = ?',
-      [DateTime.now().millisecondsSinceEpoch ~/ 1000, id],
+  /// Put operations that were interrupted mid-flight back in the queue.
+  Future<int> resetInProgressSyncOps() {
+    final query = update(syncQueueTable)
+      ..where((s) => s.status.equals('inProgress'));
+    return query.write(
+      SyncQueueTableCompanion(
+        status: const Value('pending'),
+        updatedAt: Value(DateTime.now()),
+      ),
+    );
+  }
+
+  Future<SyncQueueTableData?> getSyncOp(int id) {
+    return (select(
+      syncQueueTable,
+    )..where((s) => s.id.equals(id))).getSingleOrNull();
+  }
+
+  /// Put the failed or waiting operations on [itemId], or on every item
+  /// when null, back in the queue to run now with their retries reset.
+  Future<int> retrySyncOps([String? itemId]) {
+    final query = update(syncQueueTable)
+      ..where(
+        (s) =>
+            (s.status.equals('pending') | s.status.equals('failed')) &
+            (itemId == null ? const Constant(true) : s.itemId.equals(itemId)),
+      );
+    return query.write(
+      SyncQueueTableCompanion(
+        status: const Value('pending'),
+        retryCount: const Value(0),
+        transientRetryCount: const Value(0),
+        scheduledAt: const Value(null),
+        updatedAt: Value(DateTime.now()),
+      ),
+    );
+  }
+
+  // --- Sync Errors ---
+  /// Record that a change to an item failed, counting the attempt.
+  Future<void> recordSyncError({
+    required String itemId,
+    required String itemType,
+    required String operationType,
+    required String category,
+    required String message,
+  }) {
+    final now = DateTime.now();
+    return into(syncErrorsTable).insert(
+      SyncErrorsTableCompanion.insert(
+        itemId: itemId,
+        itemType: itemType,
+        operationType: operationType,
+        category: category,
+        message: message,
+        retryCount: const Value(1),
+        failedAt: now,
+      ),
+      onConflict: DoUpdate(
+        (old) => SyncErrorsTableCompanion.custom(
+          operationType: Constant(operationType),
+          category: Constant(category),
+          message: Constant(message),
+          retryCount: old.retryCount + const Constant(1),
+          failedAt: Constant(now),
+        ),
+      ),
+    );
+  }
+
+  /// Forget the error of [itemId], unless the item is ignored.
+  Future<int> clearSyncError(String itemId) {
+    return (delete(syncErrorsTable)
+          ..where((e) => e.itemId.equals(itemId) & e.ignored.equals(false)))
+        .go();
+  }
+
+  /// Items with errors, latest first; the ignored ones if [ignored].
+  Future<List<SyncErrorsTableData>> getSyncErrors({bool ignored = false}) {
+    return (select(syncErrorsTable)
+          ..where((e) => e.ignored.equals(ignored))
+          ..orderBy([(e) => OrderingTerm.desc(e.failedAt)]))
+        .get();
+  }
+
+  Future<bool> isSyncItemIgnored(String itemId) async {
+    final query = select(syncErrorsTable)
+      ..where((e) => e.itemId.equals(itemId) & e.ignored.equals(true));
+    return (await query.getSingleOrNull()) != null;
+  }
+
+  /// Stop syncing [itemId]: its queued operations are dropped and new ones
+  /// are not queued until [unignoreSyncItem].
+  Future<void> ignoreSyncItem(String itemId) {
+    return transaction(() async {
+      await (update(syncErrorsTable)..where((e) => e.itemId.equals(itemId)))
+          .write(const SyncErrorsTableCompanion(ignored: Value(true)));
+      await (delete(syncQueueTable)..where(
+            (s) =>
+                s.itemId.equals(itemId) &
+                (s.status.equals('pending') | s.status.equals('failed')),
+          ))
+          .go();
+    });
+  }
+
+  Future<int> unignoreSyncItem(String itemId) {
+    return (delete(
+      syncErrorsTable,
+    )..where((e) => e.itemId.equals(itemId))).go();
+  }
+
+  // --- Operations journal ---
+  /// Record that [op] is about to be sent to the server. Returns the
+  /// journal entry to finish once its outcome is known.
+  Future<int> startOperation(SyncQueueTableData op) {
+    return into(operationsTable).insert(
+      OperationsTableCompanion.insert(
+        syncOpId: op.id,
+        operationType: op.operationType,
+        itemId: op.itemId,
+        payload: op.payload,
+        startedAt: DateTime.now(),
+      ),
+    );
+  }
+
+  Future<void> finishOperation(int id, String state) {
+    return (update(operationsTable)..where((o) => o.id.equals(id))).write(
+      OperationsTableCompanion(
+        state: Value(state),
+        finishedAt: Value(DateTime.now()),
+      ),
+    );
+  }
+
+  /// Journal entries whose operation was sent but never finished, oldest
+  /// first.
+  Future<List<OperationsTableData>> getUnfinishedOperations() {
+    return (select(operationsTable)
+          ..where((o) => o.state.equals('started'))
+          ..orderBy([(o) => OrderingTerm.asc(o.id)]))
+        .get();
+  }
+
+  /// Write everything committed so far, the operations journal included,
+  /// from SQLite's write-ahead log into the database file, so it survives
+  /// the process ending right after.
+  Future<void> flushJournal() =>
+      customStatement('PRAGMA wal_checkpoint(TRUNCATE)');
+
+  /// Forget finished journal entries from before [before].
+  Future<int> pruneOperations(DateTime before) {
+    return (delete(operationsTable)..where(
+          (o) =>
+              o.state.equals('started').not() &
+              o.startedAt.isSmallerThanValue(before),
+        ))
+        .go();
+  }
+
+  /// Count a failed attempt and put the operation back in the queue, to
+  /// run again no earlier than [at]. [transient] failures were not the
+  /// operation's fault and are counted apart from its retries.
+  Future<void> scheduleSyncOpRetry(
+    int id,
+    DateTime at, {
+    String? errorMessage,
+    bool transient = false,
+  }) {
+    return (update(syncQueueTable)..where((s) => s.id.equals(id))).write(
+      SyncQueueTableCompanion.custom(
+        status: const Constant('pending'),
+        retryCount: transient
+            ? null
+            : syncQueueTable.retryCount + const Constant(1),
+        transientRetryCount: transient
+            ? syncQueueTable.transientRetryCount + const Constant(1)
+            : null,
+        errorMessage: Variable<String>(errorMessage),
+        scheduledAt: Variable<DateTime>(at),
+        updatedAt: Variable<DateTime>(DateTime.now()),
+      ),
     );
   }
 
@@ -167,15 +770,46 @@ class AppDatabase extends _$AppDatabase {
         .get();
   }
 
-  Future<void> resolveConflict(int id, String resolution) {
+  Future<void> resolveConflict(
+    int id,
+    String resolution, {
+    String resolvedBy = 'user',
+  }) {
     return (update(syncConflictsTable)..where((c) => c.id.equals(id))).write(
       SyncConflictsTableCompanion(
         resolution: Value(resolution),
         resolvedAt: Value(DateTime.now()),
+        resolvedBy: Value(resolvedBy),
       ),
     );
   }
 
+  Future<SyncConflictsTableData?> getConflictById(int id) {
+    return (select(
+      syncConflictsTable,
+    )..where((c) => c.id.equals(id))).getSingleOrNull();
+  }
+
+  /// Whether a conflict for the same operation was settled with
+  /// [resolution] after [since].
+  Future<bool> hasConflictResolvedSince({
+    required String itemId,
+    required String operationType,
+    required String resolution,
+    required DateTime since,
+  }) async {
+    final query = select(syncConflictsTable)
+      ..where(
+        (c) =>
+            c.itemId.equals(itemId) &
+            c.operationType.equals(operationType) &
+            c.resolution.equals(resolution) &
+            c.resolvedAt.isBiggerOrEqualValue(since),
+      )
+      ..limit(1);
+    return (await query.get()).isNotEmpty;
+  }
+
   Future<int> deleteResolvedConflicts() {
     return (delete(
       syncConflictsTable,
@@ -183,6 +817,10 @@ class AppDatabase extends _$AppDatabase {
   }
 
   // --- Cached Files ---
+  Future<List<CachedFilesTableData>> getCachedFiles() {
+    return select(cachedFilesTable).get();
+  }
+
   Future<CachedFilesTableData?> getCachedFile(String fileId) {
     return (select(
       cachedFilesTable,
@@ -193,12 +831,314 @@ class AppDatabase extends _$AppDatabase {
     return into(cachedFilesTable).insertOnConflictUpdate(entry);
   }
 
+  Future<void> setCachedFileState(
+    String fileId,
+    String state,
+    DateTime verifiedAt,
+  ) {
+    final query = update(cachedFilesTable)
+      ..where((c) => c.fileId.equals(fileId));
+    return query.write(
+      CachedFilesTableCompanion(
+        state: Value(state),
+        verifiedAt: Value(verifiedAt),
+      ),
+    );
+  }
+
   Future<int> deleteCachedFile(String fileId) {
     return (delete(
       cachedFilesTable,
     )..where((c) => c.fileId.equals(fileId))).go();
   }
 
+  // --- Upload Sessions ---
+  Future<List<UploadSessionsTableData>> getUploadSessions() {
+    return select(uploadSessionsTable).get();
+  }
+
+  Future<void> upsertUploadSession(UploadSessionsTableCompanion entry) {
+    return into(uploadSessionsTable).insertOnConflictUpdate(entry);
+  }
+
+  Future<void> updateUploadSessionProgress(String uploadId, int chunks) {
+    final query = update(uploadSessionsTable)
+      ..where((u) => u.uploadId.equals(uploadId));
+    return query.write(
+      UploadSessionsTableCompanion(
+        chunksUploaded: Value(chunks),
+        updatedAt: Value(DateTime.now()),
+      ),
+    );
+  }
+
+  Future<int> deleteUploadSession(String uploadId) {
+    return (delete(
+      uploadSessionsTable,
+    )..where((u) => u.uploadId.equals(uploadId))).go();
+  }
+
+  // --- Partial Downloads ---
+  Future<PartialDownloadsTableData?> getPartialDownload(String fileId) {
+    return (select(
+      partialDownloadsTable,
+    )..where((d) => d.fileId.equals(fileId))).getSingleOrNull();
+  }
+
+  Future<List<PartialDownloadsTableData>> getPartialDownloads() {
+    return select(partialDownloadsTable).get();
+  }
+
+  Future<void> savePartialDownload({
+    required String fileId,
+    required String path,
+    required String targetPath,
+    required String etag,
+  }) {
+    return into(partialDownloadsTable).insertOnConflictUpdate(
+      PartialDownloadsTableCompanion.insert(
+        fileId: fileId,
+        path: path,
+        targetPath: targetPath,
+        etag: etag,
+        updatedAt: DateTime.now(),
+      ),
+    );
+  }
+
+  Future<int> deletePartialDownload(String fileId) {
+    return (delete(
+      partialDownloadsTable,
+    )..where((d) => d.fileId.equals(fileId))).go();
+  }
+
+  // --- Content Digests ---
+  Future<void> recordContentDigest(ContentDigestsTableCompanion entry) {
+    return into(contentDigestsTable).insertOnConflictUpdate(entry);
+  }
+
+  Future<List<ContentDigestsTableData>> getContentDigests(
+    String algorithm,
+    String digest,
+  ) {
+    return (select(contentDigestsTable)..where(
+          (d) => d.algorithm.equals(algorithm) & d.digest.equals(digest),
+        ))
+        .get();
+  }
+
+  Future<ContentDigestsTableData?> getContentDigest(String fileId) {
+    return (select(
+      contentDigestsTable,
+    )..where((d) => d.fileId.equals(fileId))).getSingleOrNull();
+  }
+
+  Future<int> deleteContentDigest(String fileId) {
+    return (delete(
+      contentDigestsTable,
+    )..where((d) => d.fileId.equals(fileId))).go();
+  }
+
+  // --- Activity Log ---
+  Future<int> insertActivity(ActivityLogTableCompanion entry) {
+    return into(activityLogTable).insert(entry);
+  }
+
+  /// Whether [action] on [itemId] was logged before.
+  Future<bool> hasActivity(String itemId, String action) async {
+    final query = select(activityLogTable)
+      ..where((a) => a.itemId.equals(itemId) & a.action.equals(action))
+      ..limit(1);
+    return await query.getSingleOrNull() != null;
+  }
+
+  /// Activity between [from] and [to] (both optional), oldest first.
+  Future<List<ActivityLogTableData>> getActivity({
+    DateTime? from,
+    DateTime? to,
+  }) {
+    final query = select(activityLogTable);
+    if (from != null) {
+      query.where((a) => a.createdAt.isBiggerOrEqualValue(from));
+    }
+    if (to != null) {
+      query.where((a) => a.createdAt.isSmallerThanValue(to));
+    }
+    query.orderBy([(a) => OrderingTerm.asc(a.createdAt)]);
+    return query.get();
+  }
+
+  /// Conflicts resolved between [from] and [to] (both optional).
+  Future<List<SyncConflictsTableData>> getResolvedConflicts({
+    DateTime? from,
+    DateTime? to,
+  }) {
+    final query = select(syncConflictsTable)
+      ..where((c) => c.resolvedAt.isNotNull());
+    if (from != null) {
+      query.where((c) => c.resolvedAt.isBiggerOrEqualValue(from));
+    }
+    if (to != null) {
+      query.where((c) => c.resolvedAt.isSmallerThanValue(to));
+    }
+    query.orderBy([(c) => OrderingTerm.asc(c.resolvedAt)]);
+    return query.get();
+  }
+
+  // --- Linked Shares ---
+  Future<List<LinkedSharesTableData>> getLinkedShares() {
+    return (select(
+      linkedSharesTable,
+    )..orderBy([(l) => OrderingTerm.asc(l.name)])).get();
+  }
+
+  Future<void> upsertLinkedShare(LinkedSharesTableCompanion entry) {
+    return into(linkedSharesTable).insertOnConflictUpdate(entry);
+  }
+
+  Future<int> deleteLinkedShare(String id) {
+    return (delete(linkedSharesTable)..where((l) => l.id.equals(id))).go();
+  }
+
+  // --- File Modes ---
+
+  Future<int?> getFileMode(String fileId) async {
+    final query = select(fileModesTable)
+      ..where((m) => m.fileId.equals(fileId));
+    return (await query.getSingleOrNull())?.mode;
+  }
+
+  Future<void> upsertFileMode(FileModesTableCompanion entry) {
+    return into(fileModesTable).insertOnConflictUpdate(entry);
+  }
+
+  // --- Transfer Ledger ---
+  Future<int> insertLedgerEntry(TransferLedgerTableCompanion entry) {
+    return into(transferLedgerTable).insert(entry);
+  }
+
+  /// Uploads not yet checked against the server, oldest first.
+  Future<List<TransferLedgerTableData>> getPendingLedgerEntries({int? limit}) {
+    final query = select(transferLedgerTable)
+      ..where((l) => l.status.equals('pending'))
+      ..orderBy([(l) => OrderingTerm.asc(l.uploadedAt)]);
+    if (limit != null) query.limit(limit);
+    return query.get();
+  }
+
+  Future<void> updateLedgerStatus(int id, String status) {
+    final query = update(transferLedgerTable)..where((l) => l.id.equals(id));
+    return query.write(
+      TransferLedgerTableCompanion(
+        status: Value(status),
+        verifiedAt: Value(DateTime.now()),
+      ),
+    );
+  }
+
+  // --- Conflict Rules ---
+  Future<List<ConflictRulesTableData>> getConflictRules() {
+    final query = select(conflictRulesTable)
+      ..orderBy([
+        (r) => OrderingTerm.asc(r.position),
+        (r) => OrderingTerm.asc(r.id),
+      ]);
+    return query.get();
+  }
+
+  Future<int> insertConflictRule(ConflictRulesTableCompanion rule) {
+    return into(conflictRulesTable).insert(rule);
+  }
+
+  Future<bool> updateConflictRule(ConflictRulesTableData rule) {
+    return update(conflictRulesTable).replace(rule);
+  }
+
+  Future<int> deleteConflictRule(int id) {
+    return (delete(conflictRulesTable)..where((r) => r.id.equals(id))).go();
+  }
+
+  /// Renumber rules so that [ids] are evaluated in that order.
+  Future<void> reorderConflictRules(List<int> ids) {
+    return transaction(() async {
+      for (var i = 0; i < ids.length; i++) {
+        final query = update(conflictRulesTable)
+          ..where((r) => r.id.equals(ids[i]));
+        await query.write(ConflictRulesTableCompanion(position: Value(i)));
+      }
+    });
+  }
+
+  // --- Inbox ---
+  Future<List<InboxRulesTableData>> getInboxRules() {
+    final query = select(inboxRulesTable)
+      ..orderBy([
+        (r) => OrderingTerm.asc(r.position),
+        (r) => OrderingTerm.asc(r.id),
+      ]);
+    return query.get();
+  }
+
+  Future<int> insertInboxRule(InboxRulesTableCompanion rule) {
+    return into(inboxRulesTable).insert(rule);
+  }
+
+  Future<int> deleteInboxRule(int id) {
+    return (delete(inboxRulesTable)..where((r) => r.id.equals(id))).go();
+  }
+
+  /// The recorded status of each of [fileIds] that has been seen before.
+  Future<Map<String, String>> getInboxItemStatuses(
+    Iterable<String> fileIds,
+  ) async {
+    final query = select(inboxItemsTable)
+      ..where((i) => i.fileId.isIn(fileIds));
+    return {for (final i in await query.get()) i.fileId: i.status};
+  }
+
+  Future<void> recordInboxItem(InboxItemsTableCompanion item) {
+    return into(inboxItemsTable).insertOnConflictUpdate(item);
+  }
+
+  Future<List<InboxItemsTableData>> getRecentInboxItems({int limit = 50}) {
+    final query = select(inboxItemsTable)
+      ..orderBy([(i) => OrderingTerm.desc(i.arrivedAt)])
+      ..limit(limit);
+    return query.get();
+  }
+
+  // --- Ownership Lease ---
+  Future<DbLeaseTableData?> getDbLease() {
+    return select(dbLeaseTable).getSingleOrNull();
+  }
+
+  Future<void> upsertDbLease(DbLeaseTableCompanion entry) {
+    return into(dbLeaseTable).insertOnConflictUpdate(entry);
+  }
+
+  /// Refresh the heartbeat if [ownerId] still holds the lease. Returns
+  /// false when another process has taken it over.
+  Future<bool> renewDbLease(String ownerId) async {
+    final query = update(dbLeaseTable)..where((l) => l.ownerId.equals(ownerId));
+    final rows = await query.write(
+      DbLeaseTableCompanion(heartbeatAt: Value(DateTime.now())),
+    );
+    return rows > 0;
+  }
+
+  Future<int> releaseDbLease(String ownerId) {
+    return (delete(
+      dbLeaseTable,
+    )..where((l) => l.ownerId.equals(ownerId))).go();
+  }
+
+  /// SQLite's `data_version`, which changes whenever another connection
+  /// commits to the database file.
+  Future<int> dataVersion() async {
+    final row = await customSelect('PRAGMA data_version').getSingle();
+    return row.read<int>('data_version');
+  }
+
   // --- User ---
   Future<UserTableData?> getCurrentUser() {
     return select(userTable).getSingleOrNull();
@@ -208,6 +1148,22 @@ class AppDatabase extends _$AppDatabase {
     return into(userTable).insertOnConflictUpdate(user);
   }
 
+  Future<void> setChecksumAlgorithm(String userId, String algorithm) {
+    final query = update(userTable)..where((u) => u.id.equals(userId));
+    return query.write(UserTableCompanion(checksumAlgorithm: Value(algorithm)));
+  }
+
+  /// Store the storage quota of the signed-in user; [quotaBytes] is null
+  /// when the server sets no limit.
+  Future<void> setStorageQuota({required int usedBytes, int? quotaBytes}) {
+    return update(userTable).write(
+      UserTableCompanion(
+        storageUsedBytes: Value(usedBytes),
+        storageQuotaBytes: Value(quotaBytes),
+      ),
+    );
+  }
+
   Future<int> clearUser() {
     return delete(userTable).go();
   }
@@ -217,9 +1173,29 @@ class AppDatabase extends _$AppDatabase {
     await delete(filesTable).go();
     await delete(foldersTable).go();
     await delete(syncQueueTable).go();
+    await delete(operationsTable).go();
+    await delete(syncErrorsTable).go();
     await delete(syncConflictsTable).go();
     await delete(cachedFilesTable).go();
     await delete(userTable).go();
+    await delete(uploadSessionsTable).go();
+    await delete(partialDownloadsTable).go();
+    await delete(contentDigestsTable).go();
+    await delete(activityLogTable).go();
+    await delete(folderScanStateTable).go();
+    await delete(linkedSharesTable).go();
+    await delete(transferLedgerTable).go();
+    await delete(fileModesTable).go();
+    await delete(conflictRulesTable).go();
+    await delete(cachedListingsTable).go();
+    await delete(syncExclusionsTable).go();
+    await delete(folderSyncModesTable).go();
+    await delete(conflictCopiesTable).go();
+    await delete(inboxRulesTable).go();
+    await delete(inboxItemsTable).go();
+    await delete(usageCountersTable).go();
+    await delete(syncStatsTable).go();
+    await delete(folderSyncStatsTable).go();
   }
 }
 
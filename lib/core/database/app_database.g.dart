@@ -169,6 +169,17 @@ class $FilesTableTable extends FilesTable
     type: DriftSqlType.string,
     requiredDuringInsert: false,
   );
+  static const VerificationMeta _mountTypeMeta = const VerificationMeta(
+    'mountType',
+  );
+  @override
+  late final GeneratedColumn<String> mountType = GeneratedColumn<String>(
+    'mount_type',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
   @override
   List<GeneratedColumn> get $columns => [
     id,
@@ -186,6 +197,7 @@ class $FilesTableTable extends FilesTable
     isFavorite,
     isAvailableOffline,
     localCachePath,
+    mountType,
   ];
   @override
   String get aliasedName => _alias ?? actualTableName;
@@ -306,6 +318,12 @@ class $FilesTableTable extends FilesTable
         ),
       );
     }
+    if (data.containsKey('mount_type')) {
+      context.handle(
+        _mountTypeMeta,
+        mountType.isAcceptableOrUnknown(data['mount_type']!, _mountTypeMeta),
+      );
+    }
     return context;
   }
 
@@ -375,6 +393,10 @@ class $FilesTableTable extends FilesTable
         DriftSqlType.string,
         data['${effectivePrefix}local_cache_path'],
       ),
+      mountType: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}mount_type'],
+      ),
     );
   }
 
@@ -400,6 +422,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
   final bool isFavorite;
   final bool isAvailableOffline;
   final String? localCachePath;
+  final String? mountType;
   const FilesTableData({
     required this.id,
     required this.name,
@@ -416,6 +439,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
     required this.isFavorite,
     required this.isAvailableOffline,
     this.localCachePath,
+    this.mountType,
   });
   @override
   Map<String, Expression> toColumns(bool nullToAbsent) {
@@ -447,6 +471,9 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
     if (!nullToAbsent || localCachePath != null) {
       map['local_cache_path'] = Variable<String>(localCachePath);
     }
+    if (!nullToAbsent || mountType != null) {
+      map['mount_type'] = Variable<String>(mountType);
+    }
     return map;
   }
 
@@ -475,6 +502,9 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
       localCachePath: localCachePath == null && nullToAbsent
           ? const Value.absent()
           : Value(localCachePath),
+      mountType: mountType == null && nullToAbsent
+          ? const Value.absent()
+          : Value(mountType),
     );
   }
 
@@ -499,6 +529,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
       isFavorite: serializer.fromJson<bool>(json['isFavorite']),
       isAvailableOffline: serializer.fromJson<bool>(json['isAvailableOffline']),
       localCachePath: serializer.fromJson<String?>(json['localCachePath']),
+      mountType: serializer.fromJson<String?>(json['mountType']),
     );
   }
   @override
@@ -520,6 +551,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
       'isFavorite': serializer.toJson<bool>(isFavorite),
       'isAvailableOffline': serializer.toJson<bool>(isAvailableOffline),
       'localCachePath': serializer.toJson<String?>(localCachePath),
+      'mountType': serializer.toJson<String?>(mountType),
     };
   }
 
@@ -539,6 +571,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
     bool? isFavorite,
     bool? isAvailableOffline,
     Value<String?> localCachePath = const Value.absent(),
+    Value<String?> mountType = const Value.absent(),
   }) => FilesTableData(
     id: id ?? this.id,
     name: name ?? this.name,
@@ -557,6 +590,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
     localCachePath: localCachePath.present
         ? localCachePath.value
         : this.localCachePath,
+    mountType: mountType.present ? mountType.value : this.mountType,
   );
   FilesTableData copyWithCompanion(FilesTableCompanion data) {
     return FilesTableData(
@@ -583,6 +617,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
       localCachePath: data.localCachePath.present
           ? data.localCachePath.value
           : this.localCachePath,
+      mountType: data.mountType.present ? data.mountType.value : this.mountType,
     );
   }
 
@@ -603,7 +638,8 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
           ..write('syncedAt: $syncedAt, ')
           ..write('isFavorite: $isFavorite, ')
           ..write('isAvailableOffline: $isAvailableOffline, ')
-          ..write('localCachePath: $localCachePath')
+          ..write('localCachePath: $localCachePath, ')
+          ..write('mountType: $mountType')
           ..write(')'))
         .toString();
   }
@@ -625,6 +661,7 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
     isFavorite,
     isAvailableOffline,
     localCachePath,
+    mountType,
   );
   @override
   bool operator ==(Object other) =>
@@ -644,7 +681,8 @@ class FilesTableData extends DataClass implements Insertable<FilesTableData> {
           other.syncedAt == this.syncedAt &&
           other.isFavorite == this.isFavorite &&
           other.isAvailableOffline == this.isAvailableOffline &&
-          other.localCachePath == this.localCachePath);
+          other.localCachePath == this.localCachePath &&
+          other.mountType == this.mountType);
 }
 
 class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
@@ -663,6 +701,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
   final Value<bool> isFavorite;
   final Value<bool> isAvailableOffline;
   final Value<String?> localCachePath;
+  final Value<String?> mountType;
   final Value<int> rowid;
   const FilesTableCompanion({
     this.id = const Value.absent(),
@@ -680,6 +719,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
     this.isFavorite = const Value.absent(),
     this.isAvailableOffline = const Value.absent(),
     this.localCachePath = const Value.absent(),
+    this.mountType = const Value.absent(),
     this.rowid = const Value.absent(),
   });
   FilesTableCompanion.insert({
@@ -698,6 +738,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
     this.isFavorite = const Value.absent(),
     this.isAvailableOffline = const Value.absent(),
     this.localCachePath = const Value.absent(),
+    this.mountType = const Value.absent(),
     this.rowid = const Value.absent(),
   }) : id = Value(id),
        name = Value(name),
@@ -722,6 +763,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
     Expression<bool>? isFavorite,
     Expression<bool>? isAvailableOffline,
     Expression<String>? localCachePath,
+    Expression<String>? mountType,
     Expression<int>? rowid,
   }) {
     return RawValuesInsertable({
@@ -741,6 +783,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
       if (isAvailableOffline != null)
         'is_available_offline': isAvailableOffline,
       if (localCachePath != null) 'local_cache_path': localCachePath,
+      if (mountType != null) 'mount_type': mountType,
       if (rowid != null) 'rowid': rowid,
     });
   }
@@ -761,6 +804,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
     Value<bool>? isFavorite,
     Value<bool>? isAvailableOffline,
     Value<String?>? localCachePath,
+    Value<String?>? mountType,
     Value<int>? rowid,
   }) {
     return FilesTableCompanion(
@@ -779,6 +823,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
       isFavorite: isFavorite ?? this.isFavorite,
       isAvailableOffline: isAvailableOffline ?? this.isAvailableOffline,
       localCachePath: localCachePath ?? this.localCachePath,
+      mountType: mountType ?? this.mountType,
       rowid: rowid ?? this.rowid,
     );
   }
@@ -831,6 +876,9 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
     if (localCachePath.present) {
       map['local_cache_path'] = Variable<String>(localCachePath.value);
     }
+    if (mountType.present) {
+      map['mount_type'] = Variable<String>(mountType.value);
+    }
     if (rowid.present) {
       map['rowid'] = Variable<int>(rowid.value);
     }
@@ -855,6 +903,7 @@ class FilesTableCompanion extends UpdateCompanion<FilesTableData> {
           ..write('isFavorite: $isFavorite, ')
           ..write('isAvailableOffline: $isAvailableOffline, ')
           ..write('localCachePath: $localCachePath, ')
+          ..write('mountType: $mountType, ')
           ..write('rowid: $rowid')
           ..write(')'))
         .toString();
@@ -962,6 +1011,17 @@ class $FoldersTableTable extends FoldersTable
     type: DriftSqlType.dateTime,
     requiredDuringInsert: false,
   );
+  static const VerificationMeta _mountTypeMeta = const VerificationMeta(
+    'mountType',
+  );
+  @override
+  late final GeneratedColumn<String> mountType = GeneratedColumn<String>(
+    'mount_type',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
   @override
   List<GeneratedColumn> get $columns => [
     id,
@@ -973,6 +1033,7 @@ class $FoldersTableTable extends FoldersTable
     createdAt,
     modifiedAt,
     syncedAt,
+    mountType,
   ];
   @override
   String get aliasedName => _alias ?? actualTableName;
@@ -1047,6 +1108,12 @@ class $FoldersTableTable extends FoldersTable
         syncedAt.isAcceptableOrUnknown(data['synced_at']!, _syncedAtMeta),
       );
     }
+    if (data.containsKey('mount_type')) {
+      context.handle(
+        _mountTypeMeta,
+        mountType.isAcceptableOrUnknown(data['mount_type']!, _mountTypeMeta),
+      );
+    }
     return context;
   }
 
@@ -1092,6 +1159,10 @@ class $FoldersTableTable extends FoldersTable
         DriftSqlType.dateTime,
         data['${effectivePrefix}synced_at'],
       ),
+      mountType: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}mount_type'],
+      ),
     );
   }
 
@@ -1112,6 +1183,7 @@ class FoldersTableData extends DataClass
   final DateTime createdAt;
   final DateTime modifiedAt;
   final DateTime? syncedAt;
+  final String? mountType;
   const FoldersTableData({
     required this.id,
     required this.name,
@@ -1122,6 +1194,7 @@ class FoldersTableData extends DataClass
     required this.createdAt,
     required this.modifiedAt,
     this.syncedAt,
+    this.mountType,
   });
   @override
   Map<String, Expression> toColumns(bool nullToAbsent) {
@@ -1141,6 +1214,9 @@ class FoldersTableData extends DataClass
     if (!nullToAbsent || syncedAt != null) {
       map['synced_at'] = Variable<DateTime>(syncedAt);
     }
+    if (!nullToAbsent || mountType != null) {
+      map['mount_type'] = Variable<String>(mountType);
+    }
     return map;
   }
 
@@ -1161,6 +1237,9 @@ class FoldersTableData extends DataClass
       syncedAt: syncedAt == null && nullToAbsent
           ? const Value.absent()
           : Value(syncedAt),
+      mountType: mountType == null && nullToAbsent
+          ? const Value.absent()
+          : Value(mountType),
     );
   }
 
@@ -1179,6 +1258,7 @@ class FoldersTableData extends DataClass
       createdAt: serializer.fromJson<DateTime>(json['createdAt']),
       modifiedAt: serializer.fromJson<DateTime>(json['modifiedAt']),
       syncedAt: serializer.fromJson<DateTime?>(json['syncedAt']),
+      mountType: serializer.fromJson<String?>(json['mountType']),
     );
   }
   @override
@@ -1194,6 +1274,7 @@ class FoldersTableData extends DataClass
       'createdAt': serializer.toJson<DateTime>(createdAt),
       'modifiedAt': serializer.toJson<DateTime>(modifiedAt),
       'syncedAt': serializer.toJson<DateTime?>(syncedAt),
+      'mountType': serializer.toJson<String?>(mountType),
     };
   }
 
@@ -1207,6 +1288,7 @@ class FoldersTableData extends DataClass
     DateTime? createdAt,
     DateTime? modifiedAt,
     Value<DateTime?> syncedAt = const Value.absent(),
+    Value<String?> mountType = const Value.absent(),
   }) => FoldersTableData(
     id: id ?? this.id,
     name: name ?? this.name,
@@ -1217,6 +1299,7 @@ class FoldersTableData extends DataClass
     createdAt: createdAt ?? this.createdAt,
     modifiedAt: modifiedAt ?? this.modifiedAt,
     syncedAt: syncedAt.present ? syncedAt.value : this.syncedAt,
+    mountType: mountType.present ? mountType.value : this.mountType,
   );
   FoldersTableData copyWithCompanion(FoldersTableCompanion data) {
     return FoldersTableData(
@@ -1231,6 +1314,7 @@ class FoldersTableData extends DataClass
           ? data.modifiedAt.value
           : this.modifiedAt,
       syncedAt: data.syncedAt.present ? data.syncedAt.value : this.syncedAt,
+      mountType: data.mountType.present ? data.mountType.value : this.mountType,
     );
   }
 
@@ -1245,7 +1329,8 @@ class FoldersTableData extends DataClass
           ..write('isRoot: $isRoot, ')
           ..write('createdAt: $createdAt, ')
           ..write('modifiedAt: $modifiedAt, ')
-          ..write('syncedAt: $syncedAt')
+          ..write('syncedAt: $syncedAt, ')
+          ..write('mountType: $mountType')
           ..write(')'))
         .toString();
   }
@@ -1261,6 +1346,7 @@ class FoldersTableData extends DataClass
     createdAt,
     modifiedAt,
     syncedAt,
+    mountType,
   );
   @override
   bool operator ==(Object other) =>
@@ -1274,7 +1360,8 @@ class FoldersTableData extends DataClass
           other.isRoot == this.isRoot &&
           other.createdAt == this.createdAt &&
           other.modifiedAt == this.modifiedAt &&
-          other.syncedAt == this.syncedAt);
+          other.syncedAt == this.syncedAt &&
+          other.mountType == this.mountType);
 }
 
 class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
@@ -1287,6 +1374,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
   final Value<DateTime> createdAt;
   final Value<DateTime> modifiedAt;
   final Value<DateTime?> syncedAt;
+  final Value<String?> mountType;
   final Value<int> rowid;
   const FoldersTableCompanion({
     this.id = const Value.absent(),
@@ -1298,6 +1386,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
     this.createdAt = const Value.absent(),
     this.modifiedAt = const Value.absent(),
     this.syncedAt = const Value.absent(),
+    this.mountType = const Value.absent(),
     this.rowid = const Value.absent(),
   });
   FoldersTableCompanion.insert({
@@ -1310,6 +1399,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
     required DateTime createdAt,
     required DateTime modifiedAt,
     this.syncedAt = const Value.absent(),
+    this.mountType = const Value.absent(),
     this.rowid = const Value.absent(),
   }) : id = Value(id),
        name = Value(name),
@@ -1326,6 +1416,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
     Expression<DateTime>? createdAt,
     Expression<DateTime>? modifiedAt,
     Expression<DateTime>? syncedAt,
+    Expression<String>? mountType,
     Expression<int>? rowid,
   }) {
     return RawValuesInsertable({
@@ -1338,6 +1429,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
       if (createdAt != null) 'created_at': createdAt,
       if (modifiedAt != null) 'modified_at': modifiedAt,
       if (syncedAt != null) 'synced_at': syncedAt,
+      if (mountType != null) 'mount_type': mountType,
       if (rowid != null) 'rowid': rowid,
     });
   }
@@ -1352,6 +1444,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
     Value<DateTime>? createdAt,
     Value<DateTime>? modifiedAt,
     Value<DateTime?>? syncedAt,
+    Value<String?>? mountType,
     Value<int>? rowid,
   }) {
     return FoldersTableCompanion(
@@ -1364,6 +1457,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
       createdAt: createdAt ?? this.createdAt,
       modifiedAt: modifiedAt ?? this.modifiedAt,
       syncedAt: syncedAt ?? this.syncedAt,
+      mountType: mountType ?? this.mountType,
       rowid: rowid ?? this.rowid,
     );
   }
@@ -1398,6 +1492,9 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
     if (syncedAt.present) {
       map['synced_at'] = Variable<DateTime>(syncedAt.value);
     }
+    if (mountType.present) {
+      map['mount_type'] = Variable<String>(mountType.value);
+    }
     if (rowid.present) {
       map['rowid'] = Variable<int>(rowid.value);
     }
@@ -1416,6 +1513,7 @@ class FoldersTableCompanion extends UpdateCompanion<FoldersTableData> {
           ..write('createdAt: $createdAt, ')
           ..write('modifiedAt: $modifiedAt, ')
           ..write('syncedAt: $syncedAt, ')
+          ..write('mountType: $mountType, ')
           ..write('rowid: $rowid')
           ..write(')'))
         .toString();
@@ -1505,6 +1603,17 @@ class $SyncQueueTableTable extends SyncQueueTable
     requiredDuringInsert: false,
     defaultValue: const Constant(0),
   );
+  static const VerificationMeta _transientRetryCountMeta =
+      const VerificationMeta('transientRetryCount');
+  @override
+  late final GeneratedColumn<int> transientRetryCount = GeneratedColumn<int>(
+    'transient_retry_count',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
   static const VerificationMeta _errorMessageMeta = const VerificationMeta(
     'errorMessage',
   );
@@ -1570,6 +1679,7 @@ class $SyncQueueTableTable extends SyncQueueTable
     itemType,
     payload,
     retryCount,
+    transientRetryCount,
     errorMessage,
     createdAt,
     updatedAt,
@@ -1638,6 +1748,15 @@ class $SyncQueueTableTable extends SyncQueueTable
         retryCount.isAcceptableOrUnknown(data['retry_count']!, _retryCountMeta),
       );
     }
+    if (data.containsKey('transient_retry_count')) {
+      context.handle(
+        _transientRetryCountMeta,
+        transientRetryCount.isAcceptableOrUnknown(
+          data['transient_retry_count']!,
+          _transientRetryCountMeta,
+        ),
+      );
+    }
     if (data.containsKey('error_message')) {
       context.handle(
         _errorMessageMeta,
@@ -1715,6 +1834,10 @@ class $SyncQueueTableTable extends SyncQueueTable
         DriftSqlType.int,
         data['${effectivePrefix}retry_count'],
       )!,
+      transientRetryCount: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}transient_retry_count'],
+      )!,
       errorMessage: attachedDatabase.typeMapping.read(
         DriftSqlType.string,
         data['${effectivePrefix}error_message'],
@@ -1753,6 +1876,7 @@ class SyncQueueTableData extends DataClass
   final String itemType;
   final String payload;
   final int retryCount;
+  final int transientRetryCount;
   final String? errorMessage;
   final DateTime createdAt;
   final DateTime updatedAt;
@@ -1766,6 +1890,7 @@ class SyncQueueTableData extends DataClass
     required this.itemType,
     required this.payload,
     required this.retryCount,
+    required this.transientRetryCount,
     this.errorMessage,
     required this.createdAt,
     required this.updatedAt,
@@ -1782,6 +1907,7 @@ class SyncQueueTableData extends DataClass
     map['item_type'] = Variable<String>(itemType);
     map['payload'] = Variable<String>(payload);
     map['retry_count'] = Variable<int>(retryCount);
+    map['transient_retry_count'] = Variable<int>(transientRetryCount);
     if (!nullToAbsent || errorMessage != null) {
       map['error_message'] = Variable<String>(errorMessage);
     }
@@ -1803,6 +1929,7 @@ class SyncQueueTableData extends DataClass
       itemType: Value(itemType),
       payload: Value(payload),
       retryCount: Value(retryCount),
+      transientRetryCount: Value(transientRetryCount),
       errorMessage: errorMessage == null && nullToAbsent
           ? const Value.absent()
           : Value(errorMessage),
@@ -1828,6 +1955,9 @@ class SyncQueueTableData extends DataClass
       itemType: serializer.fromJson<String>(json['itemType']),
       payload: serializer.fromJson<String>(json['payload']),
       retryCount: serializer.fromJson<int>(json['retryCount']),
+      transientRetryCount: serializer.fromJson<int>(
+        json['transientRetryCount'],
+      ),
       errorMessage: serializer.fromJson<String?>(json['errorMessage']),
       createdAt: serializer.fromJson<DateTime>(json['createdAt']),
       updatedAt: serializer.fromJson<DateTime>(json['updatedAt']),
@@ -1846,6 +1976,7 @@ class SyncQueueTableData extends DataClass
       'itemType': serializer.toJson<String>(itemType),
       'payload': serializer.toJson<String>(payload),
       'retryCount': serializer.toJson<int>(retryCount),
+      'transientRetryCount': serializer.toJson<int>(transientRetryCount),
       'errorMessage': serializer.toJson<String?>(errorMessage),
       'createdAt': serializer.toJson<DateTime>(createdAt),
       'updatedAt': serializer.toJson<DateTime>(updatedAt),
@@ -1862,6 +1993,7 @@ class SyncQueueTableData extends DataClass
     String? itemType,
     String? payload,
     int? retryCount,
+    int? transientRetryCount,
     Value<String?> errorMessage = const Value.absent(),
     DateTime? createdAt,
     DateTime? updatedAt,
@@ -1875,6 +2007,7 @@ class SyncQueueTableData extends DataClass
     itemType: itemType ?? this.itemType,
     payload: payload ?? this.payload,
     retryCount: retryCount ?? this.retryCount,
+    transientRetryCount: transientRetryCount ?? this.transientRetryCount,
     errorMessage: errorMessage.present ? errorMessage.value : this.errorMessage,
     createdAt: createdAt ?? this.createdAt,
     updatedAt: updatedAt ?? this.updatedAt,
@@ -1894,6 +2027,9 @@ class SyncQueueTableData extends DataClass
       retryCount: data.retryCount.present
           ? data.retryCount.value
           : this.retryCount,
+      transientRetryCount: data.transientRetryCount.present
+          ? data.transientRetryCount.value
+          : this.transientRetryCount,
       errorMessage: data.errorMessage.present
           ? data.errorMessage.value
           : this.errorMessage,
@@ -1916,6 +2052,7 @@ class SyncQueueTableData extends DataClass
           ..write('itemType: $itemType, ')
           ..write('payload: $payload, ')
           ..write('retryCount: $retryCount, ')
+          ..write('transientRetryCount: $transientRetryCount, ')
           ..write('errorMessage: $errorMessage, ')
           ..write('createdAt: $createdAt, ')
           ..write('updatedAt: $updatedAt, ')
@@ -1934,6 +2071,7 @@ class SyncQueueTableData extends DataClass
     itemType,
     payload,
     retryCount,
+    transientRetryCount,
     errorMessage,
     createdAt,
     updatedAt,
@@ -1951,6 +2089,7 @@ class SyncQueueTableData extends DataClass
           other.itemType == this.itemType &&
           other.payload == this.payload &&
           other.retryCount == this.retryCount &&
+          other.transientRetryCount == this.transientRetryCount &&
           other.errorMessage == this.errorMessage &&
           other.createdAt == this.createdAt &&
           other.updatedAt == this.updatedAt &&
@@ -1966,6 +2105,7 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
   final Value<String> itemType;
   final Value<String> payload;
   final Value<int> retryCount;
+  final Value<int> transientRetryCount;
   final Value<String?> errorMessage;
   final Value<DateTime> createdAt;
   final Value<DateTime> updatedAt;
@@ -1979,6 +2119,7 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
     this.itemType = const Value.absent(),
     this.payload = const Value.absent(),
     this.retryCount = const Value.absent(),
+    this.transientRetryCount = const Value.absent(),
     this.errorMessage = const Value.absent(),
     this.createdAt = const Value.absent(),
     this.updatedAt = const Value.absent(),
@@ -1993,6 +2134,7 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
     required String itemType,
     required String payload,
     this.retryCount = const Value.absent(),
+    this.transientRetryCount = const Value.absent(),
     this.errorMessage = const Value.absent(),
     required DateTime createdAt,
     required DateTime updatedAt,
@@ -2012,6 +2154,7 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
     Expression<String>? itemType,
     Expression<String>? payload,
     Expression<int>? retryCount,
+    Expression<int>? transientRetryCount,
     Expression<String>? errorMessage,
     Expression<DateTime>? createdAt,
     Expression<DateTime>? updatedAt,
@@ -2026,6 +2169,8 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
       if (itemType != null) 'item_type': itemType,
       if (payload != null) 'payload': payload,
       if (retryCount != null) 'retry_count': retryCount,
+      if (transientRetryCount != null)
+        'transient_retry_count': transientRetryCount,
       if (errorMessage != null) 'error_message': errorMessage,
       if (createdAt != null) 'created_at': createdAt,
       if (updatedAt != null) 'updated_at': updatedAt,
@@ -2042,6 +2187,7 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
     Value<String>? itemType,
     Value<String>? payload,
     Value<int>? retryCount,
+    Value<int>? transientRetryCount,
     Value<String?>? errorMessage,
     Value<DateTime>? createdAt,
     Value<DateTime>? updatedAt,
@@ -2056,6 +2202,7 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
       itemType: itemType ?? this.itemType,
       payload: payload ?? this.payload,
       retryCount: retryCount ?? this.retryCount,
+      transientRetryCount: transientRetryCount ?? this.transientRetryCount,
       errorMessage: errorMessage ?? this.errorMessage,
       createdAt: createdAt ?? this.createdAt,
       updatedAt: updatedAt ?? this.updatedAt,
@@ -2088,6 +2235,9 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
     if (retryCount.present) {
       map['retry_count'] = Variable<int>(retryCount.value);
     }
+    if (transientRetryCount.present) {
+      map['transient_retry_count'] = Variable<int>(transientRetryCount.value);
+    }
     if (errorMessage.present) {
       map['error_message'] = Variable<String>(errorMessage.value);
     }
@@ -2116,6 +2266,7 @@ class SyncQueueTableCompanion extends UpdateCompanion<SyncQueueTableData> {
           ..write('itemType: $itemType, ')
           ..write('payload: $payload, ')
           ..write('retryCount: $retryCount, ')
+          ..write('transientRetryCount: $transientRetryCount, ')
           ..write('errorMessage: $errorMessage, ')
           ..write('createdAt: $createdAt, ')
           ..write('updatedAt: $updatedAt, ')
@@ -2232,6 +2383,17 @@ class $SyncConflictsTableTable extends SyncConflictsTable
     type: DriftSqlType.dateTime,
     requiredDuringInsert: false,
   );
+  static const VerificationMeta _resolvedByMeta = const VerificationMeta(
+    'resolvedBy',
+  );
+  @override
+  late final GeneratedColumn<String> resolvedBy = GeneratedColumn<String>(
+    'resolved_by',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
   static const VerificationMeta _createdAtMeta = const VerificationMeta(
     'createdAt',
   );
@@ -2243,6 +2405,17 @@ class $SyncConflictsTableTable extends SyncConflictsTable
     type: DriftSqlType.dateTime,
     requiredDuringInsert: true,
   );
+  static const VerificationMeta _queuedAtMeta = const VerificationMeta(
+    'queuedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> queuedAt = GeneratedColumn<DateTime>(
+    'queued_at',
+    aliasedName,
+    true,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: false,
+  );
   @override
   List<GeneratedColumn> get $columns => [
     id,
@@ -2254,7 +2427,9 @@ class $SyncConflictsTableTable extends SyncConflictsTable
     errorMessage,
     resolution,
     resolvedAt,
+    resolvedBy,
     createdAt,
+    queuedAt,
   ];
   @override
   String get aliasedName => _alias ?? actualTableName;
@@ -2336,6 +2511,12 @@ class $SyncConflictsTableTable extends SyncConflictsTable
         resolvedAt.isAcceptableOrUnknown(data['resolved_at']!, _resolvedAtMeta),
       );
     }
+    if (data.containsKey('resolved_by')) {
+      context.handle(
+        _resolvedByMeta,
+        resolvedBy.isAcceptableOrUnknown(data['resolved_by']!, _resolvedByMeta),
+      );
+    }
     if (data.containsKey('created_at')) {
       context.handle(
         _createdAtMeta,
@@ -2344,6 +2525,12 @@ class $SyncConflictsTableTable extends SyncConflictsTable
     } else if (isInserting) {
       context.missing(_createdAtMeta);
     }
+    if (data.containsKey('queued_at')) {
+      context.handle(
+        _queuedAtMeta,
+        queuedAt.isAcceptableOrUnknown(data['queued_at']!, _queuedAtMeta),
+      );
+    }
     return context;
   }
 
@@ -2389,10 +2576,18 @@ class $SyncConflictsTableTable extends SyncConflictsTable
         DriftSqlType.dateTime,
         data['${effectivePrefix}resolved_at'],
       ),
+      resolvedBy: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}resolved_by'],
+      ),
       createdAt: attachedDatabase.typeMapping.read(
         DriftSqlType.dateTime,
         data['${effectivePrefix}created_at'],
       )!,
+      queuedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}queued_at'],
+      ),
     );
   }
 
@@ -2413,7 +2608,9 @@ class SyncConflictsTableData extends DataClass
   final String? errorMessage;
   final String? resolution;
   final DateTime? resolvedAt;
+  final String? resolvedBy;
   final DateTime createdAt;
+  final DateTime? queuedAt;
   const SyncConflictsTableData({
     required this.id,
     required this.itemId,
@@ -2424,7 +2621,9 @@ class SyncConflictsTableData extends DataClass
     this.errorMessage,
     this.resolution,
     this.resolvedAt,
+    this.resolvedBy,
     required this.createdAt,
+    this.queuedAt,
   });
   @override
   Map<String, Expression> toColumns(bool nullToAbsent) {
@@ -2444,7 +2643,13 @@ class SyncConflictsTableData extends DataClass
     if (!nullToAbsent || resolvedAt != null) {
       map['resolved_at'] = Variable<DateTime>(resolvedAt);
     }
+    if (!nullToAbsent || resolvedBy != null) {
+      map['resolved_by'] = Variable<String>(resolvedBy);
+    }
     map['created_at'] = Variable<DateTime>(createdAt);
+    if (!nullToAbsent || queuedAt != null) {
+      map['queued_at'] = Variable<DateTime>(queuedAt);
+    }
     return map;
   }
 
@@ -2465,7 +2670,13 @@ class SyncConflictsTableData extends DataClass
       resolvedAt: resolvedAt == null && nullToAbsent
           ? const Value.absent()
           : Value(resolvedAt),
+      resolvedBy: resolvedBy == null && nullToAbsent
+          ? const Value.absent()
+          : Value(resolvedBy),
       createdAt: Value(createdAt),
+      queuedAt: queuedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(queuedAt),
     );
   }
 
@@ -2484,7 +2695,9 @@ class SyncConflictsTableData extends DataClass
       errorMessage: serializer.fromJson<String?>(json['errorMessage']),
       resolution: serializer.fromJson<String?>(json['resolution']),
       resolvedAt: serializer.fromJson<DateTime?>(json['resolvedAt']),
+      resolvedBy: serializer.fromJson<String?>(json['resolvedBy']),
       createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+      queuedAt: serializer.fromJson<DateTime?>(json['queuedAt']),
     );
   }
   @override
@@ -2500,7 +2713,9 @@ class SyncConflictsTableData extends DataClass
       'errorMessage': serializer.toJson<String?>(errorMessage),
       'resolution': serializer.toJson<String?>(resolution),
       'resolvedAt': serializer.toJson<DateTime?>(resolvedAt),
+      'resolvedBy': serializer.toJson<String?>(resolvedBy),
       'createdAt': serializer.toJson<DateTime>(createdAt),
+      'queuedAt': serializer.toJson<DateTime?>(queuedAt),
     };
   }
 
@@ -2514,7 +2729,9 @@ class SyncConflictsTableData extends DataClass
     Value<String?> errorMessage = const Value.absent(),
     Value<String?> resolution = const Value.absent(),
     Value<DateTime?> resolvedAt = const Value.absent(),
+    Value<String?> resolvedBy = const Value.absent(),
     DateTime? createdAt,
+    Value<DateTime?> queuedAt = const Value.absent(),
   }) => SyncConflictsTableData(
     id: id ?? this.id,
     itemId: itemId ?? this.itemId,
@@ -2525,7 +2742,9 @@ class SyncConflictsTableData extends DataClass
     errorMessage: errorMessage.present ? errorMessage.value : this.errorMessage,
     resolution: resolution.present ? resolution.value : this.resolution,
     resolvedAt: resolvedAt.present ? resolvedAt.value : this.resolvedAt,
+    resolvedBy: resolvedBy.present ? resolvedBy.value : this.resolvedBy,
     createdAt: createdAt ?? this.createdAt,
+    queuedAt: queuedAt.present ? queuedAt.value : this.queuedAt,
   );
   SyncConflictsTableData copyWithCompanion(SyncConflictsTableCompanion data) {
     return SyncConflictsTableData(
@@ -2548,7 +2767,11 @@ class SyncConflictsTableData extends DataClass
       resolvedAt: data.resolvedAt.present
           ? data.resolvedAt.value
           : this.resolvedAt,
+      resolvedBy: data.resolvedBy.present
+          ? data.resolvedBy.value
+          : this.resolvedBy,
       createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+      queuedAt: data.queuedAt.present ? data.queuedAt.value : this.queuedAt,
     );
   }
 
@@ -2564,7 +2787,9 @@ class SyncConflictsTableData extends DataClass
           ..write('errorMessage: $errorMessage, ')
           ..write('resolution: $resolution, ')
           ..write('resolvedAt: $resolvedAt, ')
-          ..write('createdAt: $createdAt')
+          ..write('resolvedBy: $resolvedBy, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('queuedAt: $queuedAt')
           ..write(')'))
         .toString();
   }
@@ -2580,7 +2805,9 @@ class SyncConflictsTableData extends DataClass
     errorMessage,
     resolution,
     resolvedAt,
+    resolvedBy,
     createdAt,
+    queuedAt,
   );
   @override
   bool operator ==(Object other) =>
@@ -2595,7 +2822,9 @@ class SyncConflictsTableData extends DataClass
           other.errorMessage == this.errorMessage &&
           other.resolution == this.resolution &&
           other.resolvedAt == this.resolvedAt &&
-          other.createdAt == this.createdAt);
+          other.resolvedBy == this.resolvedBy &&
+          other.createdAt == this.createdAt &&
+          other.queuedAt == this.queuedAt);
 }
 
 class SyncConflictsTableCompanion
@@ -2609,7 +2838,9 @@ class SyncConflictsTableCompanion
   final Value<String?> errorMessage;
   final Value<String?> resolution;
   final Value<DateTime?> resolvedAt;
+  final Value<String?> resolvedBy;
   final Value<DateTime> createdAt;
+  final Value<DateTime?> queuedAt;
   const SyncConflictsTableCompanion({
     this.id = const Value.absent(),
     this.itemId = const Value.absent(),
@@ -2620,7 +2851,9 @@ class SyncConflictsTableCompanion
     this.errorMessage = const Value.absent(),
     this.resolution = const Value.absent(),
     this.resolvedAt = const Value.absent(),
+    this.resolvedBy = const Value.absent(),
     this.createdAt = const Value.absent(),
+    this.queuedAt = const Value.absent(),
   });
   SyncConflictsTableCompanion.insert({
     this.id = const Value.absent(),
@@ -2632,7 +2865,9 @@ class SyncConflictsTableCompanion
     this.errorMessage = const Value.absent(),
     this.resolution = const Value.absent(),
     this.resolvedAt = const Value.absent(),
+    this.resolvedBy = const Value.absent(),
     required DateTime createdAt,
+    this.queuedAt = const Value.absent(),
   }) : itemId = Value(itemId),
        itemType = Value(itemType),
        operationType = Value(operationType),
@@ -2648,7 +2883,9 @@ class SyncConflictsTableCompanion
     Expression<String>? errorMessage,
     Expression<String>? resolution,
     Expression<DateTime>? resolvedAt,
+    Expression<String>? resolvedBy,
     Expression<DateTime>? createdAt,
+    Expression<DateTime>? queuedAt,
   }) {
     return RawValuesInsertable({
       if (id != null) 'id': id,
@@ -2660,7 +2897,9 @@ class SyncConflictsTableCompanion
       if (errorMessage != null) 'error_message': errorMessage,
       if (resolution != null) 'resolution': resolution,
       if (resolvedAt != null) 'resolved_at': resolvedAt,
+      if (resolvedBy != null) 'resolved_by': resolvedBy,
       if (createdAt != null) 'created_at': createdAt,
+      if (queuedAt != null) 'queued_at': queuedAt,
     });
   }
 
@@ -2674,7 +2913,9 @@ class SyncConflictsTableCompanion
     Value<String?>? errorMessage,
     Value<String?>? resolution,
     Value<DateTime?>? resolvedAt,
+    Value<String?>? resolvedBy,
     Value<DateTime>? createdAt,
+    Value<DateTime?>? queuedAt,
   }) {
     return SyncConflictsTableCompanion(
       id: id ?? this.id,
@@ -2686,7 +2927,9 @@ class SyncConflictsTableCompanion
       errorMessage: errorMessage ?? this.errorMessage,
       resolution: resolution ?? this.resolution,
       resolvedAt: resolvedAt ?? this.resolvedAt,
+      resolvedBy: resolvedBy ?? this.resolvedBy,
       createdAt: createdAt ?? this.createdAt,
+      queuedAt: queuedAt ?? this.queuedAt,
     );
   }
 
@@ -2720,9 +2963,15 @@ class SyncConflictsTableCompanion
     if (resolvedAt.present) {
       map['resolved_at'] = Variable<DateTime>(resolvedAt.value);
     }
+    if (resolvedBy.present) {
+      map['resolved_by'] = Variable<String>(resolvedBy.value);
+    }
     if (createdAt.present) {
       map['created_at'] = Variable<DateTime>(createdAt.value);
     }
+    if (queuedAt.present) {
+      map['queued_at'] = Variable<DateTime>(queuedAt.value);
+    }
     return map;
   }
 
@@ -2738,7 +2987,9 @@ class SyncConflictsTableCompanion
           ..write('errorMessage: $errorMessage, ')
           ..write('resolution: $resolution, ')
           ..write('resolvedAt: $resolvedAt, ')
-          ..write('createdAt: $createdAt')
+          ..write('resolvedBy: $resolvedBy, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('queuedAt: $queuedAt')
           ..write(')'))
         .toString();
   }
@@ -2828,6 +3079,47 @@ class $CachedFilesTableTable extends CachedFilesTable
     ),
     defaultValue: const Constant(false),
   );
+  static const VerificationMeta _etagMeta = const VerificationMeta('etag');
+  @override
+  late final GeneratedColumn<String> etag = GeneratedColumn<String>(
+    'etag',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _baseContentMeta = const VerificationMeta(
+    'baseContent',
+  );
+  @override
+  late final GeneratedColumn<String> baseContent = GeneratedColumn<String>(
+    'base_content',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _stateMeta = const VerificationMeta('state');
+  @override
+  late final GeneratedColumn<String> state = GeneratedColumn<String>(
+    'state',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+    defaultValue: const Constant('ok'),
+  );
+  static const VerificationMeta _verifiedAtMeta = const VerificationMeta(
+    'verifiedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> verifiedAt = GeneratedColumn<DateTime>(
+    'verified_at',
+    aliasedName,
+    true,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: false,
+  );
   @override
   List<GeneratedColumn> get $columns => [
     fileId,
@@ -2837,6 +3129,10 @@ class $CachedFilesTableTable extends CachedFilesTable
     cachedAt,
     lastAccessedAt,
     isPinned,
+    etag,
+    baseContent,
+    state,
+    verifiedAt,
   ];
   @override
   String get aliasedName => _alias ?? actualTableName;
@@ -2905,6 +3201,33 @@ class $CachedFilesTableTable extends CachedFilesTable
         isPinned.isAcceptableOrUnknown(data['is_pinned']!, _isPinnedMeta),
       );
     }
+    if (data.containsKey('etag')) {
+      context.handle(
+        _etagMeta,
+        etag.isAcceptableOrUnknown(data['etag']!, _etagMeta),
+      );
+    }
+    if (data.containsKey('base_content')) {
+      context.handle(
+        _baseContentMeta,
+        baseContent.isAcceptableOrUnknown(
+          data['base_content']!,
+          _baseContentMeta,
+        ),
+      );
+    }
+    if (data.containsKey('state')) {
+      context.handle(
+        _stateMeta,
+        state.isAcceptableOrUnknown(data['state']!, _stateMeta),
+      );
+    }
+    if (data.containsKey('verified_at')) {
+      context.handle(
+        _verifiedAtMeta,
+        verifiedAt.isAcceptableOrUnknown(data['verified_at']!, _verifiedAtMeta),
+      );
+    }
     return context;
   }
 
@@ -2942,6 +3265,22 @@ class $CachedFilesTableTable extends CachedFilesTable
         DriftSqlType.bool,
         data['${effectivePrefix}is_pinned'],
       )!,
+      etag: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}etag'],
+      ),
+      baseContent: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}base_content'],
+      ),
+      state: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}state'],
+      )!,
+      verifiedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}verified_at'],
+      ),
     );
   }
 
@@ -2960,6 +3299,10 @@ class CachedFilesTableData extends DataClass
   final DateTime cachedAt;
   final DateTime lastAccessedAt;
   final bool isPinned;
+  final String? etag;
+  final String? baseContent;
+  final String state;
+  final DateTime? verifiedAt;
   const CachedFilesTableData({
     required this.fileId,
     required this.localPath,
@@ -2968,6 +3311,10 @@ class CachedFilesTableData extends DataClass
     required this.cachedAt,
     required this.lastAccessedAt,
     required this.isPinned,
+    this.etag,
+    this.baseContent,
+    required this.state,
+    this.verifiedAt,
   });
   @override
   Map<String, Expression> toColumns(bool nullToAbsent) {
@@ -2981,6 +3328,16 @@ class CachedFilesTableData extends DataClass
     map['cached_at'] = Variable<DateTime>(cachedAt);
     map['last_accessed_at'] = Variable<DateTime>(lastAccessedAt);
     map['is_pinned'] = Variable<bool>(isPinned);
+    if (!nullToAbsent || etag != null) {
+      map['etag'] = Variable<String>(etag);
+    }
+    if (!nullToAbsent || baseContent != null) {
+      map['base_content'] = Variable<String>(baseContent);
+    }
+    map['state'] = Variable<String>(state);
+    if (!nullToAbsent || verifiedAt != null) {
+      map['verified_at'] = Variable<DateTime>(verifiedAt);
+    }
     return map;
   }
 
@@ -2993,6 +3350,14 @@ class CachedFilesTableData extends DataClass
       cachedAt: Value(cachedAt),
       lastAccessedAt: Value(lastAccessedAt),
       isPinned: Value(isPinned),
+      etag: etag == null && nullToAbsent ? const Value.absent() : Value(etag),
+      baseContent: baseContent == null && nullToAbsent
+          ? const Value.absent()
+          : Value(baseContent),
+      state: Value(state),
+      verifiedAt: verifiedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(verifiedAt),
     );
   }
 
@@ -3009,6 +3374,10 @@ class CachedFilesTableData extends DataClass
       cachedAt: serializer.fromJson<DateTime>(json['cachedAt']),
       lastAccessedAt: serializer.fromJson<DateTime>(json['lastAccessedAt']),
       isPinned: serializer.fromJson<bool>(json['isPinned']),
+      etag: serializer.fromJson<String?>(json['etag']),
+      baseContent: serializer.fromJson<String?>(json['baseContent']),
+      state: serializer.fromJson<String>(json['state']),
+      verifiedAt: serializer.fromJson<DateTime?>(json['verifiedAt']),
     );
   }
   @override
@@ -3022,6 +3391,10 @@ class CachedFilesTableData extends DataClass
       'cachedAt': serializer.toJson<DateTime>(cachedAt),
       'lastAccessedAt': serializer.toJson<DateTime>(lastAccessedAt),
       'isPinned': serializer.toJson<bool>(isPinned),
+      'etag': serializer.toJson<String?>(etag),
+      'baseContent': serializer.toJson<String?>(baseContent),
+      'state': serializer.toJson<String>(state),
+      'verifiedAt': serializer.toJson<DateTime?>(verifiedAt),
     };
   }
 
@@ -3033,6 +3406,10 @@ class CachedFilesTableData extends DataClass
     DateTime? cachedAt,
     DateTime? lastAccessedAt,
     bool? isPinned,
+    Value<String?> etag = const Value.absent(),
+    Value<String?> baseContent = const Value.absent(),
+    String? state,
+    Value<DateTime?> verifiedAt = const Value.absent(),
   }) => CachedFilesTableData(
     fileId: fileId ?? this.fileId,
     localPath: localPath ?? this.localPath,
@@ -3041,6 +3418,10 @@ class CachedFilesTableData extends DataClass
     cachedAt: cachedAt ?? this.cachedAt,
     lastAccessedAt: lastAccessedAt ?? this.lastAccessedAt,
     isPinned: isPinned ?? this.isPinned,
+    etag: etag.present ? etag.value : this.etag,
+    baseContent: baseContent.present ? baseContent.value : this.baseContent,
+    state: state ?? this.state,
+    verifiedAt: verifiedAt.present ? verifiedAt.value : this.verifiedAt,
   );
   CachedFilesTableData copyWithCompanion(CachedFilesTableCompanion data) {
     return CachedFilesTableData(
@@ -3053,6 +3434,14 @@ class CachedFilesTableData extends DataClass
           ? data.lastAccessedAt.value
           : this.lastAccessedAt,
       isPinned: data.isPinned.present ? data.isPinned.value : this.isPinned,
+      etag: data.etag.present ? data.etag.value : this.etag,
+      baseContent: data.baseContent.present
+          ? data.baseContent.value
+          : this.baseContent,
+      state: data.state.present ? data.state.value : this.state,
+      verifiedAt: data.verifiedAt.present
+          ? data.verifiedAt.value
+          : this.verifiedAt,
     );
   }
 
@@ -3065,7 +3454,11 @@ class CachedFilesTableData extends DataClass
           ..write('hash: $hash, ')
           ..write('cachedAt: $cachedAt, ')
           ..write('lastAccessedAt: $lastAccessedAt, ')
-          ..write('isPinned: $isPinned')
+          ..write('isPinned: $isPinned, ')
+          ..write('etag: $etag, ')
+          ..write('baseContent: $baseContent, ')
+          ..write('state: $state, ')
+          ..write('verifiedAt: $verifiedAt')
           ..write(')'))
         .toString();
   }
@@ -3079,6 +3472,10 @@ class CachedFilesTableData extends DataClass
     cachedAt,
     lastAccessedAt,
     isPinned,
+    etag,
+    baseContent,
+    state,
+    verifiedAt,
   );
   @override
   bool operator ==(Object other) =>
@@ -3090,7 +3487,11 @@ class CachedFilesTableData extends DataClass
           other.hash == this.hash &&
           other.cachedAt == this.cachedAt &&
           other.lastAccessedAt == this.lastAccessedAt &&
-          other.isPinned == this.isPinned);
+          other.isPinned == this.isPinned &&
+          other.etag == this.etag &&
+          other.baseContent == this.baseContent &&
+          other.state == this.state &&
+          other.verifiedAt == this.verifiedAt);
 }
 
 class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
@@ -3101,6 +3502,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
   final Value<DateTime> cachedAt;
   final Value<DateTime> lastAccessedAt;
   final Value<bool> isPinned;
+  final Value<String?> etag;
+  final Value<String?> baseContent;
+  final Value<String> state;
+  final Value<DateTime?> verifiedAt;
   final Value<int> rowid;
   const CachedFilesTableCompanion({
     this.fileId = const Value.absent(),
@@ -3110,6 +3515,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
     this.cachedAt = const Value.absent(),
     this.lastAccessedAt = const Value.absent(),
     this.isPinned = const Value.absent(),
+    this.etag = const Value.absent(),
+    this.baseContent = const Value.absent(),
+    this.state = const Value.absent(),
+    this.verifiedAt = const Value.absent(),
     this.rowid = const Value.absent(),
   });
   CachedFilesTableCompanion.insert({
@@ -3120,6 +3529,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
     required DateTime cachedAt,
     required DateTime lastAccessedAt,
     this.isPinned = const Value.absent(),
+    this.etag = const Value.absent(),
+    this.baseContent = const Value.absent(),
+    this.state = const Value.absent(),
+    this.verifiedAt = const Value.absent(),
     this.rowid = const Value.absent(),
   }) : fileId = Value(fileId),
        localPath = Value(localPath),
@@ -3134,6 +3547,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
     Expression<DateTime>? cachedAt,
     Expression<DateTime>? lastAccessedAt,
     Expression<bool>? isPinned,
+    Expression<String>? etag,
+    Expression<String>? baseContent,
+    Expression<String>? state,
+    Expression<DateTime>? verifiedAt,
     Expression<int>? rowid,
   }) {
     return RawValuesInsertable({
@@ -3144,6 +3561,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
       if (cachedAt != null) 'cached_at': cachedAt,
       if (lastAccessedAt != null) 'last_accessed_at': lastAccessedAt,
       if (isPinned != null) 'is_pinned': isPinned,
+      if (etag != null) 'etag': etag,
+      if (baseContent != null) 'base_content': baseContent,
+      if (state != null) 'state': state,
+      if (verifiedAt != null) 'verified_at': verifiedAt,
       if (rowid != null) 'rowid': rowid,
     });
   }
@@ -3156,6 +3577,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
     Value<DateTime>? cachedAt,
     Value<DateTime>? lastAccessedAt,
     Value<bool>? isPinned,
+    Value<String?>? etag,
+    Value<String?>? baseContent,
+    Value<String>? state,
+    Value<DateTime?>? verifiedAt,
     Value<int>? rowid,
   }) {
     return CachedFilesTableCompanion(
@@ -3166,6 +3591,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
       cachedAt: cachedAt ?? this.cachedAt,
       lastAccessedAt: lastAccessedAt ?? this.lastAccessedAt,
       isPinned: isPinned ?? this.isPinned,
+      etag: etag ?? this.etag,
+      baseContent: baseContent ?? this.baseContent,
+      state: state ?? this.state,
+      verifiedAt: verifiedAt ?? this.verifiedAt,
       rowid: rowid ?? this.rowid,
     );
   }
@@ -3194,6 +3623,18 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
     if (isPinned.present) {
       map['is_pinned'] = Variable<bool>(isPinned.value);
     }
+    if (etag.present) {
+      map['etag'] = Variable<String>(etag.value);
+    }
+    if (baseContent.present) {
+      map['base_content'] = Variable<String>(baseContent.value);
+    }
+    if (state.present) {
+      map['state'] = Variable<String>(state.value);
+    }
+    if (verifiedAt.present) {
+      map['verified_at'] = Variable<DateTime>(verifiedAt.value);
+    }
     if (rowid.present) {
       map['rowid'] = Variable<int>(rowid.value);
     }
@@ -3210,6 +3651,10 @@ class CachedFilesTableCompanion extends UpdateCompanion<CachedFilesTableData> {
           ..write('cachedAt: $cachedAt, ')
           ..write('lastAccessedAt: $lastAccessedAt, ')
           ..write('isPinned: $isPinned, ')
+          ..write('etag: $etag, ')
+          ..write('baseContent: $baseContent, ')
+          ..write('state: $state, ')
+          ..write('verifiedAt: $verifiedAt, ')
           ..write('rowid: $rowid')
           ..write(')'))
         .toString();
@@ -3294,6 +3739,18 @@ class $UserTableTable extends UserTable
     type: DriftSqlType.dateTime,
     requiredDuringInsert: false,
   );
+  static const VerificationMeta _checksumAlgorithmMeta = const VerificationMeta(
+    'checksumAlgorithm',
+  );
+  @override
+  late final GeneratedColumn<String> checksumAlgorithm =
+      GeneratedColumn<String>(
+        'checksum_algorithm',
+        aliasedName,
+        true,
+        type: DriftSqlType.string,
+        requiredDuringInsert: false,
+      );
   @override
   List<GeneratedColumn> get $columns => [
     id,
@@ -3303,6 +3760,7 @@ class $UserTableTable extends UserTable
     storageQuotaBytes,
     storageUsedBytes,
     lastSyncAt,
+    checksumAlgorithm,
   ];
   @override
   String get aliasedName => _alias ?? actualTableName;
@@ -3368,6 +3826,15 @@ class $UserTableTable extends UserTable
         ),
       );
     }
+    if (data.containsKey('checksum_algorithm')) {
+      context.handle(
+        _checksumAlgorithmMeta,
+        checksumAlgorithm.isAcceptableOrUnknown(
+          data['checksum_algorithm']!,
+          _checksumAlgorithmMeta,
+        ),
+      );
+    }
     return context;
   }
 
@@ -3405,6 +3872,10 @@ class $UserTableTable extends UserTable
         DriftSqlType.dateTime,
         data['${effectivePrefix}last_sync_at'],
       ),
+      checksumAlgorithm: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}checksum_algorithm'],
+      ),
     );
   }
 
@@ -3422,6 +3893,7 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
   final int? storageQuotaBytes;
   final int? storageUsedBytes;
   final DateTime? lastSyncAt;
+  final String? checksumAlgorithm;
   const UserTableData({
     required this.id,
     required this.username,
@@ -3430,6 +3902,7 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
     this.storageQuotaBytes,
     this.storageUsedBytes,
     this.lastSyncAt,
+    this.checksumAlgorithm,
   });
   @override
   Map<String, Expression> toColumns(bool nullToAbsent) {
@@ -3449,6 +3922,9 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
     if (!nullToAbsent || lastSyncAt != null) {
       map['last_sync_at'] = Variable<DateTime>(lastSyncAt);
     }
+    if (!nullToAbsent || checksumAlgorithm != null) {
+      map['checksum_algorithm'] = Variable<String>(checksumAlgorithm);
+    }
     return map;
   }
 
@@ -3469,6 +3945,9 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
       lastSyncAt: lastSyncAt == null && nullToAbsent
           ? const Value.absent()
           : Value(lastSyncAt),
+      checksumAlgorithm: checksumAlgorithm == null && nullToAbsent
+          ? const Value.absent()
+          : Value(checksumAlgorithm),
     );
   }
 
@@ -3485,6 +3964,9 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
       storageQuotaBytes: serializer.fromJson<int?>(json['storageQuotaBytes']),
       storageUsedBytes: serializer.fromJson<int?>(json['storageUsedBytes']),
       lastSyncAt: serializer.fromJson<DateTime?>(json['lastSyncAt']),
+      checksumAlgorithm: serializer.fromJson<String?>(
+        json['checksumAlgorithm'],
+      ),
     );
   }
   @override
@@ -3498,6 +3980,7 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
       'storageQuotaBytes': serializer.toJson<int?>(storageQuotaBytes),
       'storageUsedBytes': serializer.toJson<int?>(storageUsedBytes),
       'lastSyncAt': serializer.toJson<DateTime?>(lastSyncAt),
+      'checksumAlgorithm': serializer.toJson<String?>(checksumAlgorithm),
     };
   }
 
@@ -3509,6 +3992,7 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
     Value<int?> storageQuotaBytes = const Value.absent(),
     Value<int?> storageUsedBytes = const Value.absent(),
     Value<DateTime?> lastSyncAt = const Value.absent(),
+    Value<String?> checksumAlgorithm = const Value.absent(),
   }) => UserTableData(
     id: id ?? this.id,
     username: username ?? this.username,
@@ -3521,6 +4005,9 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
         ? storageUsedBytes.value
         : this.storageUsedBytes,
     lastSyncAt: lastSyncAt.present ? lastSyncAt.value : this.lastSyncAt,
+    checksumAlgorithm: checksumAlgorithm.present
+        ? checksumAlgorithm.value
+        : this.checksumAlgorithm,
   );
   UserTableData copyWithCompanion(UserTableCompanion data) {
     return UserTableData(
@@ -3537,6 +4024,9 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
       lastSyncAt: data.lastSyncAt.present
           ? data.lastSyncAt.value
           : this.lastSyncAt,
+      checksumAlgorithm: data.checksumAlgorithm.present
+          ? data.checksumAlgorithm.value
+          : this.checksumAlgorithm,
     );
   }
 
@@ -3549,7 +4039,8 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
           ..write('role: $role, ')
           ..write('storageQuotaBytes: $storageQuotaBytes, ')
           ..write('storageUsedBytes: $storageUsedBytes, ')
-          ..write('lastSyncAt: $lastSyncAt')
+          ..write('lastSyncAt: $lastSyncAt, ')
+          ..write('checksumAlgorithm: $checksumAlgorithm')
           ..write(')'))
         .toString();
   }
@@ -3563,6 +4054,7 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
     storageQuotaBytes,
     storageUsedBytes,
     lastSyncAt,
+    checksumAlgorithm,
   );
   @override
   bool operator ==(Object other) =>
@@ -3574,7 +4066,8 @@ class UserTableData extends DataClass implements Insertable<UserTableData> {
           other.role == this.role &&
           other.storageQuotaBytes == this.storageQuotaBytes &&
           other.storageUsedBytes == this.storageUsedBytes &&
-          other.lastSyncAt == this.lastSyncAt);
+          other.lastSyncAt == this.lastSyncAt &&
+          other.checksumAlgorithm == this.checksumAlgorithm);
 }
 
 class UserTableCompanion extends UpdateCompanion<UserTableData> {
@@ -3585,6 +4078,7 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
   final Value<int?> storageQuotaBytes;
   final Value<int?> storageUsedBytes;
   final Value<DateTime?> lastSyncAt;
+  final Value<String?> checksumAlgorithm;
   final Value<int> rowid;
   const UserTableCompanion({
     this.id = const Value.absent(),
@@ -3594,6 +4088,7 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
     this.storageQuotaBytes = const Value.absent(),
     this.storageUsedBytes = const Value.absent(),
     this.lastSyncAt = const Value.absent(),
+    this.checksumAlgorithm = const Value.absent(),
     this.rowid = const Value.absent(),
   });
   UserTableCompanion.insert({
@@ -3604,6 +4099,7 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
     this.storageQuotaBytes = const Value.absent(),
     this.storageUsedBytes = const Value.absent(),
     this.lastSyncAt = const Value.absent(),
+    this.checksumAlgorithm = const Value.absent(),
     this.rowid = const Value.absent(),
   }) : id = Value(id),
        username = Value(username);
@@ -3615,6 +4111,7 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
     Expression<int>? storageQuotaBytes,
     Expression<int>? storageUsedBytes,
     Expression<DateTime>? lastSyncAt,
+    Expression<String>? checksumAlgorithm,
     Expression<int>? rowid,
   }) {
     return RawValuesInsertable({
@@ -3625,6 +4122,7 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
       if (storageQuotaBytes != null) 'storage_quota_bytes': storageQuotaBytes,
       if (storageUsedBytes != null) 'storage_used_bytes': storageUsedBytes,
       if (lastSyncAt != null) 'last_sync_at': lastSyncAt,
+      if (checksumAlgorithm != null) 'checksum_algorithm': checksumAlgorithm,
       if (rowid != null) 'rowid': rowid,
     });
   }
@@ -3637,6 +4135,7 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
     Value<int?>? storageQuotaBytes,
     Value<int?>? storageUsedBytes,
     Value<DateTime?>? lastSyncAt,
+    Value<String?>? checksumAlgorithm,
     Value<int>? rowid,
   }) {
     return UserTableCompanion(
@@ -3647,6 +4146,7 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
       storageQuotaBytes: storageQuotaBytes ?? this.storageQuotaBytes,
       storageUsedBytes: storageUsedBytes ?? this.storageUsedBytes,
       lastSyncAt: lastSyncAt ?? this.lastSyncAt,
+      checksumAlgorithm: checksumAlgorithm ?? this.checksumAlgorithm,
       rowid: rowid ?? this.rowid,
     );
   }
@@ -3675,6 +4175,9 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
     if (lastSyncAt.present) {
       map['last_sync_at'] = Variable<DateTime>(lastSyncAt.value);
     }
+    if (checksumAlgorithm.present) {
+      map['checksum_algorithm'] = Variable<String>(checksumAlgorithm.value);
+    }
     if (rowid.present) {
       map['rowid'] = Variable<int>(rowid.value);
     }
@@ -3691,88 +4194,14337 @@ class UserTableCompanion extends UpdateCompanion<UserTableData> {
           ..write('storageQuotaBytes: $storageQuotaBytes, ')
           ..write('storageUsedBytes: $storageUsedBytes, ')
           ..write('lastSyncAt: $lastSyncAt, ')
+          ..write('checksumAlgorithm: $checksumAlgorithm, ')
           ..write('rowid: $rowid')
           ..write(')'))
         .toString();
   }
 }
 
-abstract class _$AppDatabase extends GeneratedDatabase {
-  _$AppDatabase(QueryExecutor e) : super(e);
-  $AppDatabaseManager get managers => $AppDatabaseManager(this);
-  late final $FilesTableTable filesTable = $FilesTableTable(this);
-  late final $FoldersTableTable foldersTable = $FoldersTableTable(this);
-  late final $SyncQueueTableTable syncQueueTable = $SyncQueueTableTable(this);
-  late final $SyncConflictsTableTable syncConflictsTable =
-      $SyncConflictsTableTable(this);
-  late final $CachedFilesTableTable cachedFilesTable = $CachedFilesTableTable(
-    this,
+class $UploadSessionsTableTable extends UploadSessionsTable
+    with TableInfo<$UploadSessionsTableTable, UploadSessionsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $UploadSessionsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _uploadIdMeta = const VerificationMeta(
+    'uploadId',
   );
-  late final $UserTableTable userTable = $UserTableTable(this);
   @override
-  Iterable<TableInfo<Table, Object?>> get allTables =>
-      allSchemaEntities.whereType<TableInfo<Table, Object?>>();
+  late final GeneratedColumn<String> uploadId = GeneratedColumn<String>(
+    'upload_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _localPathMeta = const VerificationMeta(
+    'localPath',
+  );
   @override
-  List<DatabaseSchemaEntity> get allSchemaEntities => [
-    filesTable,
-    foldersTable,
-    syncQueueTable,
-    syncConflictsTable,
-    cachedFilesTable,
-    userTable,
-  ];
-}
-
-typedef $$FilesTableTableCreateCompanionBuilder =
-    FilesTableCompanion Function({
-      required String id,
-      required String name,
-      required String path,
-      required int size,
-      required String mimeType,
-      Value<String?> folderId,
-      Value<String?> ownerId,
-      Value<String?> hash,
-      Value<String?> etag,
-      required DateTime createdAt,
-      required DateTime modifiedAt,
-      Value<DateTime?> syncedAt,
-      Value<bool> isFavorite,
-      Value<bool> isAvailableOffline,
-      Value<String?> localCachePath,
-      Value<int> rowid,
-    });
-typedef $$FilesTableTableUpdateCompanionBuilder =
-    FilesTableCompanion Function({
-      Value<String> id,
-      Value<String> name,
-      Value<String> path,
-      Value<int> size,
-      Value<String> mimeType,
-      Value<String?> folderId,
-      Value<String?> ownerId,
-      Value<String?> hash,
-      Value<String?> etag,
-      Value<DateTime> createdAt,
-      Value<DateTime> modifiedAt,
-      Value<DateTime?> syncedAt,
-      Value<bool> isFavorite,
+  late final GeneratedColumn<String> localPath = GeneratedColumn<String>(
+    'local_path',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _filenameMeta = const VerificationMeta(
+    'filename',
+  );
+  @override
+  late final GeneratedColumn<String> filename = GeneratedColumn<String>(
+    'filename',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _folderIdMeta = const VerificationMeta(
+    'folderId',
+  );
+  @override
+  late final GeneratedColumn<String> folderId = GeneratedColumn<String>(
+    'folder_id',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _contentTypeMeta = const VerificationMeta(
+    'contentType',
+  );
+  @override
+  late final GeneratedColumn<String> contentType = GeneratedColumn<String>(
+    'content_type',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _totalSizeMeta = const VerificationMeta(
+    'totalSize',
+  );
+  @override
+  late final GeneratedColumn<int> totalSize = GeneratedColumn<int>(
+    'total_size',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _localModifiedAtMeta = const VerificationMeta(
+    'localModifiedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> localModifiedAt =
+      GeneratedColumn<DateTime>(
+        'local_modified_at',
+        aliasedName,
+        true,
+        type: DriftSqlType.dateTime,
+        requiredDuringInsert: false,
+      );
+  static const VerificationMeta _chunkSizeMeta = const VerificationMeta(
+    'chunkSize',
+  );
+  @override
+  late final GeneratedColumn<int> chunkSize = GeneratedColumn<int>(
+    'chunk_size',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _totalChunksMeta = const VerificationMeta(
+    'totalChunks',
+  );
+  @override
+  late final GeneratedColumn<int> totalChunks = GeneratedColumn<int>(
+    'total_chunks',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _chunksUploadedMeta = const VerificationMeta(
+    'chunksUploaded',
+  );
+  @override
+  late final GeneratedColumn<int> chunksUploaded = GeneratedColumn<int>(
+    'chunks_uploaded',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _checksumAlgorithmMeta = const VerificationMeta(
+    'checksumAlgorithm',
+  );
+  @override
+  late final GeneratedColumn<String> checksumAlgorithm =
+      GeneratedColumn<String>(
+        'checksum_algorithm',
+        aliasedName,
+        true,
+        type: DriftSqlType.string,
+        requiredDuringInsert: false,
+      );
+  static const VerificationMeta _createdAtMeta = const VerificationMeta(
+    'createdAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> createdAt = GeneratedColumn<DateTime>(
+    'created_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _updatedAtMeta = const VerificationMeta(
+    'updatedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> updatedAt = GeneratedColumn<DateTime>(
+    'updated_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    uploadId,
+    localPath,
+    filename,
+    folderId,
+    contentType,
+    totalSize,
+    localModifiedAt,
+    chunkSize,
+    totalChunks,
+    chunksUploaded,
+    checksumAlgorithm,
+    createdAt,
+    updatedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'upload_sessions';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<UploadSessionsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('upload_id')) {
+      context.handle(
+        _uploadIdMeta,
+        uploadId.isAcceptableOrUnknown(data['upload_id']!, _uploadIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_uploadIdMeta);
+    }
+    if (data.containsKey('local_path')) {
+      context.handle(
+        _localPathMeta,
+        localPath.isAcceptableOrUnknown(data['local_path']!, _localPathMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_localPathMeta);
+    }
+    if (data.containsKey('filename')) {
+      context.handle(
+        _filenameMeta,
+        filename.isAcceptableOrUnknown(data['filename']!, _filenameMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_filenameMeta);
+    }
+    if (data.containsKey('folder_id')) {
+      context.handle(
+        _folderIdMeta,
+        folderId.isAcceptableOrUnknown(data['folder_id']!, _folderIdMeta),
+      );
+    }
+    if (data.containsKey('content_type')) {
+      context.handle(
+        _contentTypeMeta,
+        contentType.isAcceptableOrUnknown(
+          data['content_type']!,
+          _contentTypeMeta,
+        ),
+      );
+    }
+    if (data.containsKey('total_size')) {
+      context.handle(
+        _totalSizeMeta,
+        totalSize.isAcceptableOrUnknown(data['total_size']!, _totalSizeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_totalSizeMeta);
+    }
+    if (data.containsKey('local_modified_at')) {
+      context.handle(
+        _localModifiedAtMeta,
+        localModifiedAt.isAcceptableOrUnknown(
+          data['local_modified_at']!,
+          _localModifiedAtMeta,
+        ),
+      );
+    }
+    if (data.containsKey('chunk_size')) {
+      context.handle(
+        _chunkSizeMeta,
+        chunkSize.isAcceptableOrUnknown(data['chunk_size']!, _chunkSizeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_chunkSizeMeta);
+    }
+    if (data.containsKey('total_chunks')) {
+      context.handle(
+        _totalChunksMeta,
+        totalChunks.isAcceptableOrUnknown(
+          data['total_chunks']!,
+          _totalChunksMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_totalChunksMeta);
+    }
+    if (data.containsKey('chunks_uploaded')) {
+      context.handle(
+        _chunksUploadedMeta,
+        chunksUploaded.isAcceptableOrUnknown(
+          data['chunks_uploaded']!,
+          _chunksUploadedMeta,
+        ),
+      );
+    }
+    if (data.containsKey('checksum_algorithm')) {
+      context.handle(
+        _checksumAlgorithmMeta,
+        checksumAlgorithm.isAcceptableOrUnknown(
+          data['checksum_algorithm']!,
+          _checksumAlgorithmMeta,
+        ),
+      );
+    }
+    if (data.containsKey('created_at')) {
+      context.handle(
+        _createdAtMeta,
+        createdAt.isAcceptableOrUnknown(data['created_at']!, _createdAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_createdAtMeta);
+    }
+    if (data.containsKey('updated_at')) {
+      context.handle(
+        _updatedAtMeta,
+        updatedAt.isAcceptableOrUnknown(data['updated_at']!, _updatedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_updatedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {uploadId};
+  @override
+  UploadSessionsTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return UploadSessionsTableData(
+      uploadId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}upload_id'],
+      )!,
+      localPath: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}local_path'],
+      )!,
+      filename: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}filename'],
+      )!,
+      folderId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}folder_id'],
+      ),
+      contentType: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}content_type'],
+      ),
+      totalSize: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}total_size'],
+      )!,
+      localModifiedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}local_modified_at'],
+      ),
+      chunkSize: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}chunk_size'],
+      )!,
+      totalChunks: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}total_chunks'],
+      )!,
+      chunksUploaded: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}chunks_uploaded'],
+      )!,
+      checksumAlgorithm: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}checksum_algorithm'],
+      ),
+      createdAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}created_at'],
+      )!,
+      updatedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}updated_at'],
+      )!,
+    );
+  }
+
+  @override
+  $UploadSessionsTableTable createAlias(String alias) {
+    return $UploadSessionsTableTable(attachedDatabase, alias);
+  }
+}
+
+class UploadSessionsTableData extends DataClass
+    implements Insertable<UploadSessionsTableData> {
+  final String uploadId;
+  final String localPath;
+  final String filename;
+  final String? folderId;
+  final String? contentType;
+  final int totalSize;
+  final DateTime? localModifiedAt;
+  final int chunkSize;
+  final int totalChunks;
+  final int chunksUploaded;
+  final String? checksumAlgorithm;
+  final DateTime createdAt;
+  final DateTime updatedAt;
+  const UploadSessionsTableData({
+    required this.uploadId,
+    required this.localPath,
+    required this.filename,
+    this.folderId,
+    this.contentType,
+    required this.totalSize,
+    this.localModifiedAt,
+    required this.chunkSize,
+    required this.totalChunks,
+    required this.chunksUploaded,
+    this.checksumAlgorithm,
+    required this.createdAt,
+    required this.updatedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['upload_id'] = Variable<String>(uploadId);
+    map['local_path'] = Variable<String>(localPath);
+    map['filename'] = Variable<String>(filename);
+    if (!nullToAbsent || folderId != null) {
+      map['folder_id'] = Variable<String>(folderId);
+    }
+    if (!nullToAbsent || contentType != null) {
+      map['content_type'] = Variable<String>(contentType);
+    }
+    map['total_size'] = Variable<int>(totalSize);
+    if (!nullToAbsent || localModifiedAt != null) {
+      map['local_modified_at'] = Variable<DateTime>(localModifiedAt);
+    }
+    map['chunk_size'] = Variable<int>(chunkSize);
+    map['total_chunks'] = Variable<int>(totalChunks);
+    map['chunks_uploaded'] = Variable<int>(chunksUploaded);
+    if (!nullToAbsent || checksumAlgorithm != null) {
+      map['checksum_algorithm'] = Variable<String>(checksumAlgorithm);
+    }
+    map['created_at'] = Variable<DateTime>(createdAt);
+    map['updated_at'] = Variable<DateTime>(updatedAt);
+    return map;
+  }
+
+  UploadSessionsTableCompanion toCompanion(bool nullToAbsent) {
+    return UploadSessionsTableCompanion(
+      uploadId: Value(uploadId),
+      localPath: Value(localPath),
+      filename: Value(filename),
+      folderId: folderId == null && nullToAbsent
+          ? const Value.absent()
+          : Value(folderId),
+      contentType: contentType == null && nullToAbsent
+          ? const Value.absent()
+          : Value(contentType),
+      totalSize: Value(totalSize),
+      localModifiedAt: localModifiedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(localModifiedAt),
+      chunkSize: Value(chunkSize),
+      totalChunks: Value(totalChunks),
+      chunksUploaded: Value(chunksUploaded),
+      checksumAlgorithm: checksumAlgorithm == null && nullToAbsent
+          ? const Value.absent()
+          : Value(checksumAlgorithm),
+      createdAt: Value(createdAt),
+      updatedAt: Value(updatedAt),
+    );
+  }
+
+  factory UploadSessionsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return UploadSessionsTableData(
+      uploadId: serializer.fromJson<String>(json['uploadId']),
+      localPath: serializer.fromJson<String>(json['localPath']),
+      filename: serializer.fromJson<String>(json['filename']),
+      folderId: serializer.fromJson<String?>(json['folderId']),
+      contentType: serializer.fromJson<String?>(json['contentType']),
+      totalSize: serializer.fromJson<int>(json['totalSize']),
+      localModifiedAt: serializer.fromJson<DateTime?>(json['localModifiedAt']),
+      chunkSize: serializer.fromJson<int>(json['chunkSize']),
+      totalChunks: serializer.fromJson<int>(json['totalChunks']),
+      chunksUploaded: serializer.fromJson<int>(json['chunksUploaded']),
+      checksumAlgorithm: serializer.fromJson<String?>(
+        json['checksumAlgorithm'],
+      ),
+      createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+      updatedAt: serializer.fromJson<DateTime>(json['updatedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'uploadId': serializer.toJson<String>(uploadId),
+      'localPath': serializer.toJson<String>(localPath),
+      'filename': serializer.toJson<String>(filename),
+      'folderId': serializer.toJson<String?>(folderId),
+      'contentType': serializer.toJson<String?>(contentType),
+      'totalSize': serializer.toJson<int>(totalSize),
+      'localModifiedAt': serializer.toJson<DateTime?>(localModifiedAt),
+      'chunkSize': serializer.toJson<int>(chunkSize),
+      'totalChunks': serializer.toJson<int>(totalChunks),
+      'chunksUploaded': serializer.toJson<int>(chunksUploaded),
+      'checksumAlgorithm': serializer.toJson<String?>(checksumAlgorithm),
+      'createdAt': serializer.toJson<DateTime>(createdAt),
+      'updatedAt': serializer.toJson<DateTime>(updatedAt),
+    };
+  }
+
+  UploadSessionsTableData copyWith({
+    String? uploadId,
+    String? localPath,
+    String? filename,
+    Value<String?> folderId = const Value.absent(),
+    Value<String?> contentType = const Value.absent(),
+    int? totalSize,
+    Value<DateTime?> localModifiedAt = const Value.absent(),
+    int? chunkSize,
+    int? totalChunks,
+    int? chunksUploaded,
+    Value<String?> checksumAlgorithm = const Value.absent(),
+    DateTime? createdAt,
+    DateTime? updatedAt,
+  }) => UploadSessionsTableData(
+    uploadId: uploadId ?? this.uploadId,
+    localPath: localPath ?? this.localPath,
+    filename: filename ?? this.filename,
+    folderId: folderId.present ? folderId.value : this.folderId,
+    contentType: contentType.present ? contentType.value : this.contentType,
+    totalSize: totalSize ?? this.totalSize,
+    localModifiedAt: localModifiedAt.present
+        ? localModifiedAt.value
+        : this.localModifiedAt,
+    chunkSize: chunkSize ?? this.chunkSize,
+    totalChunks: totalChunks ?? this.totalChunks,
+    chunksUploaded: chunksUploaded ?? this.chunksUploaded,
+    checksumAlgorithm: checksumAlgorithm.present
+        ? checksumAlgorithm.value
+        : this.checksumAlgorithm,
+    createdAt: createdAt ?? this.createdAt,
+    updatedAt: updatedAt ?? this.updatedAt,
+  );
+  UploadSessionsTableData copyWithCompanion(UploadSessionsTableCompanion data) {
+    return UploadSessionsTableData(
+      uploadId: data.uploadId.present ? data.uploadId.value : this.uploadId,
+      localPath: data.localPath.present ? data.localPath.value : this.localPath,
+      filename: data.filename.present ? data.filename.value : this.filename,
+      folderId: data.folderId.present ? data.folderId.value : this.folderId,
+      contentType: data.contentType.present
+          ? data.contentType.value
+          : this.contentType,
+      totalSize: data.totalSize.present ? data.totalSize.value : this.totalSize,
+      localModifiedAt: data.localModifiedAt.present
+          ? data.localModifiedAt.value
+          : this.localModifiedAt,
+      chunkSize: data.chunkSize.present ? data.chunkSize.value : this.chunkSize,
+      totalChunks: data.totalChunks.present
+          ? data.totalChunks.value
+          : this.totalChunks,
+      chunksUploaded: data.chunksUploaded.present
+          ? data.chunksUploaded.value
+          : this.chunksUploaded,
+      checksumAlgorithm: data.checksumAlgorithm.present
+          ? data.checksumAlgorithm.value
+          : this.checksumAlgorithm,
+      createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+      updatedAt: data.updatedAt.present ? data.updatedAt.value : this.updatedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('UploadSessionsTableData(')
+          ..write('uploadId: $uploadId, ')
+          ..write('localPath: $localPath, ')
+          ..write('filename: $filename, ')
+          ..write('folderId: $folderId, ')
+          ..write('contentType: $contentType, ')
+          ..write('totalSize: $totalSize, ')
+          ..write('localModifiedAt: $localModifiedAt, ')
+          ..write('chunkSize: $chunkSize, ')
+          ..write('totalChunks: $totalChunks, ')
+          ..write('chunksUploaded: $chunksUploaded, ')
+          ..write('checksumAlgorithm: $checksumAlgorithm, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('updatedAt: $updatedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    uploadId,
+    localPath,
+    filename,
+    folderId,
+    contentType,
+    totalSize,
+    localModifiedAt,
+    chunkSize,
+    totalChunks,
+    chunksUploaded,
+    checksumAlgorithm,
+    createdAt,
+    updatedAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is UploadSessionsTableData &&
+          other.uploadId == this.uploadId &&
+          other.localPath == this.localPath &&
+          other.filename == this.filename &&
+          other.folderId == this.folderId &&
+          other.contentType == this.contentType &&
+          other.totalSize == this.totalSize &&
+          other.localModifiedAt == this.localModifiedAt &&
+          other.chunkSize == this.chunkSize &&
+          other.totalChunks == this.totalChunks &&
+          other.chunksUploaded == this.chunksUploaded &&
+          other.checksumAlgorithm == this.checksumAlgorithm &&
+          other.createdAt == this.createdAt &&
+          other.updatedAt == this.updatedAt);
+}
+
+class UploadSessionsTableCompanion
+    extends UpdateCompanion<UploadSessionsTableData> {
+  final Value<String> uploadId;
+  final Value<String> localPath;
+  final Value<String> filename;
+  final Value<String?> folderId;
+  final Value<String?> contentType;
+  final Value<int> totalSize;
+  final Value<DateTime?> localModifiedAt;
+  final Value<int> chunkSize;
+  final Value<int> totalChunks;
+  final Value<int> chunksUploaded;
+  final Value<String?> checksumAlgorithm;
+  final Value<DateTime> createdAt;
+  final Value<DateTime> updatedAt;
+  final Value<int> rowid;
+  const UploadSessionsTableCompanion({
+    this.uploadId = const Value.absent(),
+    this.localPath = const Value.absent(),
+    this.filename = const Value.absent(),
+    this.folderId = const Value.absent(),
+    this.contentType = const Value.absent(),
+    this.totalSize = const Value.absent(),
+    this.localModifiedAt = const Value.absent(),
+    this.chunkSize = const Value.absent(),
+    this.totalChunks = const Value.absent(),
+    this.chunksUploaded = const Value.absent(),
+    this.checksumAlgorithm = const Value.absent(),
+    this.createdAt = const Value.absent(),
+    this.updatedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  UploadSessionsTableCompanion.insert({
+    required String uploadId,
+    required String localPath,
+    required String filename,
+    this.folderId = const Value.absent(),
+    this.contentType = const Value.absent(),
+    required int totalSize,
+    this.localModifiedAt = const Value.absent(),
+    required int chunkSize,
+    required int totalChunks,
+    this.chunksUploaded = const Value.absent(),
+    this.checksumAlgorithm = const Value.absent(),
+    required DateTime createdAt,
+    required DateTime updatedAt,
+    this.rowid = const Value.absent(),
+  }) : uploadId = Value(uploadId),
+       localPath = Value(localPath),
+       filename = Value(filename),
+       totalSize = Value(totalSize),
+       chunkSize = Value(chunkSize),
+       totalChunks = Value(totalChunks),
+       createdAt = Value(createdAt),
+       updatedAt = Value(updatedAt);
+  static Insertable<UploadSessionsTableData> custom({
+    Expression<String>? uploadId,
+    Expression<String>? localPath,
+    Expression<String>? filename,
+    Expression<String>? folderId,
+    Expression<String>? contentType,
+    Expression<int>? totalSize,
+    Expression<DateTime>? localModifiedAt,
+    Expression<int>? chunkSize,
+    Expression<int>? totalChunks,
+    Expression<int>? chunksUploaded,
+    Expression<String>? checksumAlgorithm,
+    Expression<DateTime>? createdAt,
+    Expression<DateTime>? updatedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (uploadId != null) 'upload_id': uploadId,
+      if (localPath != null) 'local_path': localPath,
+      if (filename != null) 'filename': filename,
+      if (folderId != null) 'folder_id': folderId,
+      if (contentType != null) 'content_type': contentType,
+      if (totalSize != null) 'total_size': totalSize,
+      if (localModifiedAt != null) 'local_modified_at': localModifiedAt,
+      if (chunkSize != null) 'chunk_size': chunkSize,
+      if (totalChunks != null) 'total_chunks': totalChunks,
+      if (chunksUploaded != null) 'chunks_uploaded': chunksUploaded,
+      if (checksumAlgorithm != null) 'checksum_algorithm': checksumAlgorithm,
+      if (createdAt != null) 'created_at': createdAt,
+      if (updatedAt != null) 'updated_at': updatedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  UploadSessionsTableCompanion copyWith({
+    Value<String>? uploadId,
+    Value<String>? localPath,
+    Value<String>? filename,
+    Value<String?>? folderId,
+    Value<String?>? contentType,
+    Value<int>? totalSize,
+    Value<DateTime?>? localModifiedAt,
+    Value<int>? chunkSize,
+    Value<int>? totalChunks,
+    Value<int>? chunksUploaded,
+    Value<String?>? checksumAlgorithm,
+    Value<DateTime>? createdAt,
+    Value<DateTime>? updatedAt,
+    Value<int>? rowid,
+  }) {
+    return UploadSessionsTableCompanion(
+      uploadId: uploadId ?? this.uploadId,
+      localPath: localPath ?? this.localPath,
+      filename: filename ?? this.filename,
+      folderId: folderId ?? this.folderId,
+      contentType: contentType ?? this.contentType,
+      totalSize: totalSize ?? this.totalSize,
+      localModifiedAt: localModifiedAt ?? this.localModifiedAt,
+      chunkSize: chunkSize ?? this.chunkSize,
+      totalChunks: totalChunks ?? this.totalChunks,
+      chunksUploaded: chunksUploaded ?? this.chunksUploaded,
+      checksumAlgorithm: checksumAlgorithm ?? this.checksumAlgorithm,
+      createdAt: createdAt ?? this.createdAt,
+      updatedAt: updatedAt ?? this.updatedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (uploadId.present) {
+      map['upload_id'] = Variable<String>(uploadId.value);
+    }
+    if (localPath.present) {
+      map['local_path'] = Variable<String>(localPath.value);
+    }
+    if (filename.present) {
+      map['filename'] = Variable<String>(filename.value);
+    }
+    if (folderId.present) {
+      map['folder_id'] = Variable<String>(folderId.value);
+    }
+    if (contentType.present) {
+      map['content_type'] = Variable<String>(contentType.value);
+    }
+    if (totalSize.present) {
+      map['total_size'] = Variable<int>(totalSize.value);
+    }
+    if (localModifiedAt.present) {
+      map['local_modified_at'] = Variable<DateTime>(localModifiedAt.value);
+    }
+    if (chunkSize.present) {
+      map['chunk_size'] = Variable<int>(chunkSize.value);
+    }
+    if (totalChunks.present) {
+      map['total_chunks'] = Variable<int>(totalChunks.value);
+    }
+    if (chunksUploaded.present) {
+      map['chunks_uploaded'] = Variable<int>(chunksUploaded.value);
+    }
+    if (checksumAlgorithm.present) {
+      map['checksum_algorithm'] = Variable<String>(checksumAlgorithm.value);
+    }
+    if (createdAt.present) {
+      map['created_at'] = Variable<DateTime>(createdAt.value);
+    }
+    if (updatedAt.present) {
+      map['updated_at'] = Variable<DateTime>(updatedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('UploadSessionsTableCompanion(')
+          ..write('uploadId: $uploadId, ')
+          ..write('localPath: $localPath, ')
+          ..write('filename: $filename, ')
+          ..write('folderId: $folderId, ')
+          ..write('contentType: $contentType, ')
+          ..write('totalSize: $totalSize, ')
+          ..write('localModifiedAt: $localModifiedAt, ')
+          ..write('chunkSize: $chunkSize, ')
+          ..write('totalChunks: $totalChunks, ')
+          ..write('chunksUploaded: $chunksUploaded, ')
+          ..write('checksumAlgorithm: $checksumAlgorithm, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('updatedAt: $updatedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $ActivityLogTableTable extends ActivityLogTable
+    with TableInfo<$ActivityLogTableTable, ActivityLogTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $ActivityLogTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<int> id = GeneratedColumn<int>(
+    'id',
+    aliasedName,
+    false,
+    hasAutoIncrement: true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'PRIMARY KEY AUTOINCREMENT',
+    ),
+  );
+  static const VerificationMeta _categoryMeta = const VerificationMeta(
+    'category',
+  );
+  @override
+  late final GeneratedColumn<String> category = GeneratedColumn<String>(
+    'category',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _actionMeta = const VerificationMeta('action');
+  @override
+  late final GeneratedColumn<String> action = GeneratedColumn<String>(
+    'action',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _itemIdMeta = const VerificationMeta('itemId');
+  @override
+  late final GeneratedColumn<String> itemId = GeneratedColumn<String>(
+    'item_id',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _itemNameMeta = const VerificationMeta(
+    'itemName',
+  );
+  @override
+  late final GeneratedColumn<String> itemName = GeneratedColumn<String>(
+    'item_name',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _outcomeMeta = const VerificationMeta(
+    'outcome',
+  );
+  @override
+  late final GeneratedColumn<String> outcome = GeneratedColumn<String>(
+    'outcome',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _reasonMeta = const VerificationMeta('reason');
+  @override
+  late final GeneratedColumn<String> reason = GeneratedColumn<String>(
+    'reason',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _bytesMeta = const VerificationMeta('bytes');
+  @override
+  late final GeneratedColumn<int> bytes = GeneratedColumn<int>(
+    'bytes',
+    aliasedName,
+    true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _initiatorMeta = const VerificationMeta(
+    'initiator',
+  );
+  @override
+  late final GeneratedColumn<String> initiator = GeneratedColumn<String>(
+    'initiator',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+    defaultValue: const Constant('sync'),
+  );
+  static const VerificationMeta _createdAtMeta = const VerificationMeta(
+    'createdAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> createdAt = GeneratedColumn<DateTime>(
+    'created_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    category,
+    action,
+    itemId,
+    itemName,
+    outcome,
+    reason,
+    bytes,
+    initiator,
+    createdAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'activity_log';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<ActivityLogTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    }
+    if (data.containsKey('category')) {
+      context.handle(
+        _categoryMeta,
+        category.isAcceptableOrUnknown(data['category']!, _categoryMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_categoryMeta);
+    }
+    if (data.containsKey('action')) {
+      context.handle(
+        _actionMeta,
+        action.isAcceptableOrUnknown(data['action']!, _actionMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_actionMeta);
+    }
+    if (data.containsKey('item_id')) {
+      context.handle(
+        _itemIdMeta,
+        itemId.isAcceptableOrUnknown(data['item_id']!, _itemIdMeta),
+      );
+    }
+    if (data.containsKey('item_name')) {
+      context.handle(
+        _itemNameMeta,
+        itemName.isAcceptableOrUnknown(data['item_name']!, _itemNameMeta),
+      );
+    }
+    if (data.containsKey('outcome')) {
+      context.handle(
+        _outcomeMeta,
+        outcome.isAcceptableOrUnknown(data['outcome']!, _outcomeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_outcomeMeta);
+    }
+    if (data.containsKey('reason')) {
+      context.handle(
+        _reasonMeta,
+        reason.isAcceptableOrUnknown(data['reason']!, _reasonMeta),
+      );
+    }
+    if (data.containsKey('bytes')) {
+      context.handle(
+        _bytesMeta,
+        bytes.isAcceptableOrUnknown(data['bytes']!, _bytesMeta),
+      );
+    }
+    if (data.containsKey('initiator')) {
+      context.handle(
+        _initiatorMeta,
+        initiator.isAcceptableOrUnknown(data['initiator']!, _initiatorMeta),
+      );
+    }
+    if (data.containsKey('created_at')) {
+      context.handle(
+        _createdAtMeta,
+        createdAt.isAcceptableOrUnknown(data['created_at']!, _createdAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_createdAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  ActivityLogTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return ActivityLogTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}id'],
+      )!,
+      category: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}category'],
+      )!,
+      action: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}action'],
+      )!,
+      itemId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_id'],
+      ),
+      itemName: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_name'],
+      ),
+      outcome: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}outcome'],
+      )!,
+      reason: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}reason'],
+      ),
+      bytes: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}bytes'],
+      ),
+      initiator: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}initiator'],
+      )!,
+      createdAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}created_at'],
+      )!,
+    );
+  }
+
+  @override
+  $ActivityLogTableTable createAlias(String alias) {
+    return $ActivityLogTableTable(attachedDatabase, alias);
+  }
+}
+
+class ActivityLogTableData extends DataClass
+    implements Insertable<ActivityLogTableData> {
+  final int id;
+  final String category;
+  final String action;
+  final String? itemId;
+  final String? itemName;
+  final String outcome;
+  final String? reason;
+  final int? bytes;
+  final String initiator;
+  final DateTime createdAt;
+  const ActivityLogTableData({
+    required this.id,
+    required this.category,
+    required this.action,
+    this.itemId,
+    this.itemName,
+    required this.outcome,
+    this.reason,
+    this.bytes,
+    required this.initiator,
+    required this.createdAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<int>(id);
+    map['category'] = Variable<String>(category);
+    map['action'] = Variable<String>(action);
+    if (!nullToAbsent || itemId != null) {
+      map['item_id'] = Variable<String>(itemId);
+    }
+    if (!nullToAbsent || itemName != null) {
+      map['item_name'] = Variable<String>(itemName);
+    }
+    map['outcome'] = Variable<String>(outcome);
+    if (!nullToAbsent || reason != null) {
+      map['reason'] = Variable<String>(reason);
+    }
+    if (!nullToAbsent || bytes != null) {
+      map['bytes'] = Variable<int>(bytes);
+    }
+    map['initiator'] = Variable<String>(initiator);
+    map['created_at'] = Variable<DateTime>(createdAt);
+    return map;
+  }
+
+  ActivityLogTableCompanion toCompanion(bool nullToAbsent) {
+    return ActivityLogTableCompanion(
+      id: Value(id),
+      category: Value(category),
+      action: Value(action),
+      itemId: itemId == null && nullToAbsent
+          ? const Value.absent()
+          : Value(itemId),
+      itemName: itemName == null && nullToAbsent
+          ? const Value.absent()
+          : Value(itemName),
+      outcome: Value(outcome),
+      reason: reason == null && nullToAbsent
+          ? const Value.absent()
+          : Value(reason),
+      bytes: bytes == null && nullToAbsent
+          ? const Value.absent()
+          : Value(bytes),
+      initiator: Value(initiator),
+      createdAt: Value(createdAt),
+    );
+  }
+
+  factory ActivityLogTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return ActivityLogTableData(
+      id: serializer.fromJson<int>(json['id']),
+      category: serializer.fromJson<String>(json['category']),
+      action: serializer.fromJson<String>(json['action']),
+      itemId: serializer.fromJson<String?>(json['itemId']),
+      itemName: serializer.fromJson<String?>(json['itemName']),
+      outcome: serializer.fromJson<String>(json['outcome']),
+      reason: serializer.fromJson<String?>(json['reason']),
+      bytes: serializer.fromJson<int?>(json['bytes']),
+      initiator: serializer.fromJson<String>(json['initiator']),
+      createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<int>(id),
+      'category': serializer.toJson<String>(category),
+      'action': serializer.toJson<String>(action),
+      'itemId': serializer.toJson<String?>(itemId),
+      'itemName': serializer.toJson<String?>(itemName),
+      'outcome': serializer.toJson<String>(outcome),
+      'reason': serializer.toJson<String?>(reason),
+      'bytes': serializer.toJson<int?>(bytes),
+      'initiator': serializer.toJson<String>(initiator),
+      'createdAt': serializer.toJson<DateTime>(createdAt),
+    };
+  }
+
+  ActivityLogTableData copyWith({
+    int? id,
+    String? category,
+    String? action,
+    Value<String?> itemId = const Value.absent(),
+    Value<String?> itemName = const Value.absent(),
+    String? outcome,
+    Value<String?> reason = const Value.absent(),
+    Value<int?> bytes = const Value.absent(),
+    String? initiator,
+    DateTime? createdAt,
+  }) => ActivityLogTableData(
+    id: id ?? this.id,
+    category: category ?? this.category,
+    action: action ?? this.action,
+    itemId: itemId.present ? itemId.value : this.itemId,
+    itemName: itemName.present ? itemName.value : this.itemName,
+    outcome: outcome ?? this.outcome,
+    reason: reason.present ? reason.value : this.reason,
+    bytes: bytes.present ? bytes.value : this.bytes,
+    initiator: initiator ?? this.initiator,
+    createdAt: createdAt ?? this.createdAt,
+  );
+  ActivityLogTableData copyWithCompanion(ActivityLogTableCompanion data) {
+    return ActivityLogTableData(
+      id: data.id.present ? data.id.value : this.id,
+      category: data.category.present ? data.category.value : this.category,
+      action: data.action.present ? data.action.value : this.action,
+      itemId: data.itemId.present ? data.itemId.value : this.itemId,
+      itemName: data.itemName.present ? data.itemName.value : this.itemName,
+      outcome: data.outcome.present ? data.outcome.value : this.outcome,
+      reason: data.reason.present ? data.reason.value : this.reason,
+      bytes: data.bytes.present ? data.bytes.value : this.bytes,
+      initiator: data.initiator.present ? data.initiator.value : this.initiator,
+      createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ActivityLogTableData(')
+          ..write('id: $id, ')
+          ..write('category: $category, ')
+          ..write('action: $action, ')
+          ..write('itemId: $itemId, ')
+          ..write('itemName: $itemName, ')
+          ..write('outcome: $outcome, ')
+          ..write('reason: $reason, ')
+          ..write('bytes: $bytes, ')
+          ..write('initiator: $initiator, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    category,
+    action,
+    itemId,
+    itemName,
+    outcome,
+    reason,
+    bytes,
+    initiator,
+    createdAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is ActivityLogTableData &&
+          other.id == this.id &&
+          other.category == this.category &&
+          other.action == this.action &&
+          other.itemId == this.itemId &&
+          other.itemName == this.itemName &&
+          other.outcome == this.outcome &&
+          other.reason == this.reason &&
+          other.bytes == this.bytes &&
+          other.initiator == this.initiator &&
+          other.createdAt == this.createdAt);
+}
+
+class ActivityLogTableCompanion extends UpdateCompanion<ActivityLogTableData> {
+  final Value<int> id;
+  final Value<String> category;
+  final Value<String> action;
+  final Value<String?> itemId;
+  final Value<String?> itemName;
+  final Value<String> outcome;
+  final Value<String?> reason;
+  final Value<int?> bytes;
+  final Value<String> initiator;
+  final Value<DateTime> createdAt;
+  const ActivityLogTableCompanion({
+    this.id = const Value.absent(),
+    this.category = const Value.absent(),
+    this.action = const Value.absent(),
+    this.itemId = const Value.absent(),
+    this.itemName = const Value.absent(),
+    this.outcome = const Value.absent(),
+    this.reason = const Value.absent(),
+    this.bytes = const Value.absent(),
+    this.initiator = const Value.absent(),
+    this.createdAt = const Value.absent(),
+  });
+  ActivityLogTableCompanion.insert({
+    this.id = const Value.absent(),
+    required String category,
+    required String action,
+    this.itemId = const Value.absent(),
+    this.itemName = const Value.absent(),
+    required String outcome,
+    this.reason = const Value.absent(),
+    this.bytes = const Value.absent(),
+    this.initiator = const Value.absent(),
+    required DateTime createdAt,
+  }) : category = Value(category),
+       action = Value(action),
+       outcome = Value(outcome),
+       createdAt = Value(createdAt);
+  static Insertable<ActivityLogTableData> custom({
+    Expression<int>? id,
+    Expression<String>? category,
+    Expression<String>? action,
+    Expression<String>? itemId,
+    Expression<String>? itemName,
+    Expression<String>? outcome,
+    Expression<String>? reason,
+    Expression<int>? bytes,
+    Expression<String>? initiator,
+    Expression<DateTime>? createdAt,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (category != null) 'category': category,
+      if (action != null) 'action': action,
+      if (itemId != null) 'item_id': itemId,
+      if (itemName != null) 'item_name': itemName,
+      if (outcome != null) 'outcome': outcome,
+      if (reason != null) 'reason': reason,
+      if (bytes != null) 'bytes': bytes,
+      if (initiator != null) 'initiator': initiator,
+      if (createdAt != null) 'created_at': createdAt,
+    });
+  }
+
+  ActivityLogTableCompanion copyWith({
+    Value<int>? id,
+    Value<String>? category,
+    Value<String>? action,
+    Value<String?>? itemId,
+    Value<String?>? itemName,
+    Value<String>? outcome,
+    Value<String?>? reason,
+    Value<int?>? bytes,
+    Value<String>? initiator,
+    Value<DateTime>? createdAt,
+  }) {
+    return ActivityLogTableCompanion(
+      id: id ?? this.id,
+      category: category ?? this.category,
+      action: action ?? this.action,
+      itemId: itemId ?? this.itemId,
+      itemName: itemName ?? this.itemName,
+      outcome: outcome ?? this.outcome,
+      reason: reason ?? this.reason,
+      bytes: bytes ?? this.bytes,
+      initiator: initiator ?? this.initiator,
+      createdAt: createdAt ?? this.createdAt,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<int>(id.value);
+    }
+    if (category.present) {
+      map['category'] = Variable<String>(category.value);
+    }
+    if (action.present) {
+      map['action'] = Variable<String>(action.value);
+    }
+    if (itemId.present) {
+      map['item_id'] = Variable<String>(itemId.value);
+    }
+    if (itemName.present) {
+      map['item_name'] = Variable<String>(itemName.value);
+    }
+    if (outcome.present) {
+      map['outcome'] = Variable<String>(outcome.value);
+    }
+    if (reason.present) {
+      map['reason'] = Variable<String>(reason.value);
+    }
+    if (bytes.present) {
+      map['bytes'] = Variable<int>(bytes.value);
+    }
+    if (initiator.present) {
+      map['initiator'] = Variable<String>(initiator.value);
+    }
+    if (createdAt.present) {
+      map['created_at'] = Variable<DateTime>(createdAt.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ActivityLogTableCompanion(')
+          ..write('id: $id, ')
+          ..write('category: $category, ')
+          ..write('action: $action, ')
+          ..write('itemId: $itemId, ')
+          ..write('itemName: $itemName, ')
+          ..write('outcome: $outcome, ')
+          ..write('reason: $reason, ')
+          ..write('bytes: $bytes, ')
+          ..write('initiator: $initiator, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $FolderScanStateTableTable extends FolderScanStateTable
+    with TableInfo<$FolderScanStateTableTable, FolderScanStateTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $FolderScanStateTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _folderIdMeta = const VerificationMeta(
+    'folderId',
+  );
+  @override
+  late final GeneratedColumn<String> folderId = GeneratedColumn<String>(
+    'folder_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _fingerprintMeta = const VerificationMeta(
+    'fingerprint',
+  );
+  @override
+  late final GeneratedColumn<String> fingerprint = GeneratedColumn<String>(
+    'fingerprint',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _intervalSecondsMeta = const VerificationMeta(
+    'intervalSeconds',
+  );
+  @override
+  late final GeneratedColumn<int> intervalSeconds = GeneratedColumn<int>(
+    'interval_seconds',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _changeCountMeta = const VerificationMeta(
+    'changeCount',
+  );
+  @override
+  late final GeneratedColumn<int> changeCount = GeneratedColumn<int>(
+    'change_count',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _lastScannedAtMeta = const VerificationMeta(
+    'lastScannedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> lastScannedAt =
+      GeneratedColumn<DateTime>(
+        'last_scanned_at',
+        aliasedName,
+        true,
+        type: DriftSqlType.dateTime,
+        requiredDuringInsert: false,
+      );
+  static const VerificationMeta _nextScanAtMeta = const VerificationMeta(
+    'nextScanAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> nextScanAt = GeneratedColumn<DateTime>(
+    'next_scan_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _syncTokenMeta = const VerificationMeta(
+    'syncToken',
+  );
+  @override
+  late final GeneratedColumn<String> syncToken = GeneratedColumn<String>(
+    'sync_token',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    folderId,
+    fingerprint,
+    intervalSeconds,
+    changeCount,
+    lastScannedAt,
+    nextScanAt,
+    syncToken,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'folder_scan_state';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<FolderScanStateTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('folder_id')) {
+      context.handle(
+        _folderIdMeta,
+        folderId.isAcceptableOrUnknown(data['folder_id']!, _folderIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_folderIdMeta);
+    }
+    if (data.containsKey('fingerprint')) {
+      context.handle(
+        _fingerprintMeta,
+        fingerprint.isAcceptableOrUnknown(
+          data['fingerprint']!,
+          _fingerprintMeta,
+        ),
+      );
+    }
+    if (data.containsKey('interval_seconds')) {
+      context.handle(
+        _intervalSecondsMeta,
+        intervalSeconds.isAcceptableOrUnknown(
+          data['interval_seconds']!,
+          _intervalSecondsMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_intervalSecondsMeta);
+    }
+    if (data.containsKey('change_count')) {
+      context.handle(
+        _changeCountMeta,
+        changeCount.isAcceptableOrUnknown(
+          data['change_count']!,
+          _changeCountMeta,
+        ),
+      );
+    }
+    if (data.containsKey('last_scanned_at')) {
+      context.handle(
+        _lastScannedAtMeta,
+        lastScannedAt.isAcceptableOrUnknown(
+          data['last_scanned_at']!,
+          _lastScannedAtMeta,
+        ),
+      );
+    }
+    if (data.containsKey('next_scan_at')) {
+      context.handle(
+        _nextScanAtMeta,
+        nextScanAt.isAcceptableOrUnknown(
+          data['next_scan_at']!,
+          _nextScanAtMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_nextScanAtMeta);
+    }
+    if (data.containsKey('sync_token')) {
+      context.handle(
+        _syncTokenMeta,
+        syncToken.isAcceptableOrUnknown(data['sync_token']!, _syncTokenMeta),
+      );
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {folderId};
+  @override
+  FolderScanStateTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return FolderScanStateTableData(
+      folderId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}folder_id'],
+      )!,
+      fingerprint: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}fingerprint'],
+      ),
+      intervalSeconds: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}interval_seconds'],
+      )!,
+      changeCount: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}change_count'],
+      )!,
+      lastScannedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}last_scanned_at'],
+      ),
+      nextScanAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}next_scan_at'],
+      )!,
+      syncToken: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}sync_token'],
+      ),
+    );
+  }
+
+  @override
+  $FolderScanStateTableTable createAlias(String alias) {
+    return $FolderScanStateTableTable(attachedDatabase, alias);
+  }
+}
+
+class FolderScanStateTableData extends DataClass
+    implements Insertable<FolderScanStateTableData> {
+  final String folderId;
+  final String? fingerprint;
+  final int intervalSeconds;
+  final int changeCount;
+  final DateTime? lastScannedAt;
+  final DateTime nextScanAt;
+  final String? syncToken;
+  const FolderScanStateTableData({
+    required this.folderId,
+    this.fingerprint,
+    required this.intervalSeconds,
+    required this.changeCount,
+    this.lastScannedAt,
+    required this.nextScanAt,
+    this.syncToken,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['folder_id'] = Variable<String>(folderId);
+    if (!nullToAbsent || fingerprint != null) {
+      map['fingerprint'] = Variable<String>(fingerprint);
+    }
+    map['interval_seconds'] = Variable<int>(intervalSeconds);
+    map['change_count'] = Variable<int>(changeCount);
+    if (!nullToAbsent || lastScannedAt != null) {
+      map['last_scanned_at'] = Variable<DateTime>(lastScannedAt);
+    }
+    map['next_scan_at'] = Variable<DateTime>(nextScanAt);
+    if (!nullToAbsent || syncToken != null) {
+      map['sync_token'] = Variable<String>(syncToken);
+    }
+    return map;
+  }
+
+  FolderScanStateTableCompanion toCompanion(bool nullToAbsent) {
+    return FolderScanStateTableCompanion(
+      folderId: Value(folderId),
+      fingerprint: fingerprint == null && nullToAbsent
+          ? const Value.absent()
+          : Value(fingerprint),
+      intervalSeconds: Value(intervalSeconds),
+      changeCount: Value(changeCount),
+      lastScannedAt: lastScannedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(lastScannedAt),
+      nextScanAt: Value(nextScanAt),
+      syncToken: syncToken == null && nullToAbsent
+          ? const Value.absent()
+          : Value(syncToken),
+    );
+  }
+
+  factory FolderScanStateTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return FolderScanStateTableData(
+      folderId: serializer.fromJson<String>(json['folderId']),
+      fingerprint: serializer.fromJson<String?>(json['fingerprint']),
+      intervalSeconds: serializer.fromJson<int>(json['intervalSeconds']),
+      changeCount: serializer.fromJson<int>(json['changeCount']),
+      lastScannedAt: serializer.fromJson<DateTime?>(json['lastScannedAt']),
+      nextScanAt: serializer.fromJson<DateTime>(json['nextScanAt']),
+      syncToken: serializer.fromJson<String?>(json['syncToken']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'folderId': serializer.toJson<String>(folderId),
+      'fingerprint': serializer.toJson<String?>(fingerprint),
+      'intervalSeconds': serializer.toJson<int>(intervalSeconds),
+      'changeCount': serializer.toJson<int>(changeCount),
+      'lastScannedAt': serializer.toJson<DateTime?>(lastScannedAt),
+      'nextScanAt': serializer.toJson<DateTime>(nextScanAt),
+      'syncToken': serializer.toJson<String?>(syncToken),
+    };
+  }
+
+  FolderScanStateTableData copyWith({
+    String? folderId,
+    Value<String?> fingerprint = const Value.absent(),
+    int? intervalSeconds,
+    int? changeCount,
+    Value<DateTime?> lastScannedAt = const Value.absent(),
+    DateTime? nextScanAt,
+    Value<String?> syncToken = const Value.absent(),
+  }) => FolderScanStateTableData(
+    folderId: folderId ?? this.folderId,
+    fingerprint: fingerprint.present ? fingerprint.value : this.fingerprint,
+    intervalSeconds: intervalSeconds ?? this.intervalSeconds,
+    changeCount: changeCount ?? this.changeCount,
+    lastScannedAt: lastScannedAt.present
+        ? lastScannedAt.value
+        : this.lastScannedAt,
+    nextScanAt: nextScanAt ?? this.nextScanAt,
+    syncToken: syncToken.present ? syncToken.value : this.syncToken,
+  );
+  FolderScanStateTableData copyWithCompanion(
+    FolderScanStateTableCompanion data,
+  ) {
+    return FolderScanStateTableData(
+      folderId: data.folderId.present ? data.folderId.value : this.folderId,
+      fingerprint: data.fingerprint.present
+          ? data.fingerprint.value
+          : this.fingerprint,
+      intervalSeconds: data.intervalSeconds.present
+          ? data.intervalSeconds.value
+          : this.intervalSeconds,
+      changeCount: data.changeCount.present
+          ? data.changeCount.value
+          : this.changeCount,
+      lastScannedAt: data.lastScannedAt.present
+          ? data.lastScannedAt.value
+          : this.lastScannedAt,
+      nextScanAt: data.nextScanAt.present
+          ? data.nextScanAt.value
+          : this.nextScanAt,
+      syncToken: data.syncToken.present ? data.syncToken.value : this.syncToken,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FolderScanStateTableData(')
+          ..write('folderId: $folderId, ')
+          ..write('fingerprint: $fingerprint, ')
+          ..write('intervalSeconds: $intervalSeconds, ')
+          ..write('changeCount: $changeCount, ')
+          ..write('lastScannedAt: $lastScannedAt, ')
+          ..write('nextScanAt: $nextScanAt, ')
+          ..write('syncToken: $syncToken')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    folderId,
+    fingerprint,
+    intervalSeconds,
+    changeCount,
+    lastScannedAt,
+    nextScanAt,
+    syncToken,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is FolderScanStateTableData &&
+          other.folderId == this.folderId &&
+          other.fingerprint == this.fingerprint &&
+          other.intervalSeconds == this.intervalSeconds &&
+          other.changeCount == this.changeCount &&
+          other.lastScannedAt == this.lastScannedAt &&
+          other.nextScanAt == this.nextScanAt &&
+          other.syncToken == this.syncToken);
+}
+
+class FolderScanStateTableCompanion
+    extends UpdateCompanion<FolderScanStateTableData> {
+  final Value<String> folderId;
+  final Value<String?> fingerprint;
+  final Value<int> intervalSeconds;
+  final Value<int> changeCount;
+  final Value<DateTime?> lastScannedAt;
+  final Value<DateTime> nextScanAt;
+  final Value<String?> syncToken;
+  final Value<int> rowid;
+  const FolderScanStateTableCompanion({
+    this.folderId = const Value.absent(),
+    this.fingerprint = const Value.absent(),
+    this.intervalSeconds = const Value.absent(),
+    this.changeCount = const Value.absent(),
+    this.lastScannedAt = const Value.absent(),
+    this.nextScanAt = const Value.absent(),
+    this.syncToken = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  FolderScanStateTableCompanion.insert({
+    required String folderId,
+    this.fingerprint = const Value.absent(),
+    required int intervalSeconds,
+    this.changeCount = const Value.absent(),
+    this.lastScannedAt = const Value.absent(),
+    required DateTime nextScanAt,
+    this.syncToken = const Value.absent(),
+    this.rowid = const Value.absent(),
+  }) : folderId = Value(folderId),
+       intervalSeconds = Value(intervalSeconds),
+       nextScanAt = Value(nextScanAt);
+  static Insertable<FolderScanStateTableData> custom({
+    Expression<String>? folderId,
+    Expression<String>? fingerprint,
+    Expression<int>? intervalSeconds,
+    Expression<int>? changeCount,
+    Expression<DateTime>? lastScannedAt,
+    Expression<DateTime>? nextScanAt,
+    Expression<String>? syncToken,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (folderId != null) 'folder_id': folderId,
+      if (fingerprint != null) 'fingerprint': fingerprint,
+      if (intervalSeconds != null) 'interval_seconds': intervalSeconds,
+      if (changeCount != null) 'change_count': changeCount,
+      if (lastScannedAt != null) 'last_scanned_at': lastScannedAt,
+      if (nextScanAt != null) 'next_scan_at': nextScanAt,
+      if (syncToken != null) 'sync_token': syncToken,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  FolderScanStateTableCompanion copyWith({
+    Value<String>? folderId,
+    Value<String?>? fingerprint,
+    Value<int>? intervalSeconds,
+    Value<int>? changeCount,
+    Value<DateTime?>? lastScannedAt,
+    Value<DateTime>? nextScanAt,
+    Value<String?>? syncToken,
+    Value<int>? rowid,
+  }) {
+    return FolderScanStateTableCompanion(
+      folderId: folderId ?? this.folderId,
+      fingerprint: fingerprint ?? this.fingerprint,
+      intervalSeconds: intervalSeconds ?? this.intervalSeconds,
+      changeCount: changeCount ?? this.changeCount,
+      lastScannedAt: lastScannedAt ?? this.lastScannedAt,
+      nextScanAt: nextScanAt ?? this.nextScanAt,
+      syncToken: syncToken ?? this.syncToken,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (folderId.present) {
+      map['folder_id'] = Variable<String>(folderId.value);
+    }
+    if (fingerprint.present) {
+      map['fingerprint'] = Variable<String>(fingerprint.value);
+    }
+    if (intervalSeconds.present) {
+      map['interval_seconds'] = Variable<int>(intervalSeconds.value);
+    }
+    if (changeCount.present) {
+      map['change_count'] = Variable<int>(changeCount.value);
+    }
+    if (lastScannedAt.present) {
+      map['last_scanned_at'] = Variable<DateTime>(lastScannedAt.value);
+    }
+    if (nextScanAt.present) {
+      map['next_scan_at'] = Variable<DateTime>(nextScanAt.value);
+    }
+    if (syncToken.present) {
+      map['sync_token'] = Variable<String>(syncToken.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FolderScanStateTableCompanion(')
+          ..write('folderId: $folderId, ')
+          ..write('fingerprint: $fingerprint, ')
+          ..write('intervalSeconds: $intervalSeconds, ')
+          ..write('changeCount: $changeCount, ')
+          ..write('lastScannedAt: $lastScannedAt, ')
+          ..write('nextScanAt: $nextScanAt, ')
+          ..write('syncToken: $syncToken, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $LinkedSharesTableTable extends LinkedSharesTable
+    with TableInfo<$LinkedSharesTableTable, LinkedSharesTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $LinkedSharesTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<String> id = GeneratedColumn<String>(
+    'id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _serverUrlMeta = const VerificationMeta(
+    'serverUrl',
+  );
+  @override
+  late final GeneratedColumn<String> serverUrl = GeneratedColumn<String>(
+    'server_url',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _tokenMeta = const VerificationMeta('token');
+  @override
+  late final GeneratedColumn<String> token = GeneratedColumn<String>(
+    'token',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _nameMeta = const VerificationMeta('name');
+  @override
+  late final GeneratedColumn<String> name = GeneratedColumn<String>(
+    'name',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _itemTypeMeta = const VerificationMeta(
+    'itemType',
+  );
+  @override
+  late final GeneratedColumn<String> itemType = GeneratedColumn<String>(
+    'item_type',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _passwordProtectedMeta = const VerificationMeta(
+    'passwordProtected',
+  );
+  @override
+  late final GeneratedColumn<bool> passwordProtected = GeneratedColumn<bool>(
+    'password_protected',
+    aliasedName,
+    false,
+    type: DriftSqlType.bool,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'CHECK ("password_protected" IN (0, 1))',
+    ),
+    defaultValue: const Constant(false),
+  );
+  static const VerificationMeta _localPathMeta = const VerificationMeta(
+    'localPath',
+  );
+  @override
+  late final GeneratedColumn<String> localPath = GeneratedColumn<String>(
+    'local_path',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _lastSyncedAtMeta = const VerificationMeta(
+    'lastSyncedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> lastSyncedAt = GeneratedColumn<DateTime>(
+    'last_synced_at',
+    aliasedName,
+    true,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _addedAtMeta = const VerificationMeta(
+    'addedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> addedAt = GeneratedColumn<DateTime>(
+    'added_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    serverUrl,
+    token,
+    name,
+    itemType,
+    passwordProtected,
+    localPath,
+    lastSyncedAt,
+    addedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'linked_shares';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<LinkedSharesTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    } else if (isInserting) {
+      context.missing(_idMeta);
+    }
+    if (data.containsKey('server_url')) {
+      context.handle(
+        _serverUrlMeta,
+        serverUrl.isAcceptableOrUnknown(data['server_url']!, _serverUrlMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_serverUrlMeta);
+    }
+    if (data.containsKey('token')) {
+      context.handle(
+        _tokenMeta,
+        token.isAcceptableOrUnknown(data['token']!, _tokenMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_tokenMeta);
+    }
+    if (data.containsKey('name')) {
+      context.handle(
+        _nameMeta,
+        name.isAcceptableOrUnknown(data['name']!, _nameMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_nameMeta);
+    }
+    if (data.containsKey('item_type')) {
+      context.handle(
+        _itemTypeMeta,
+        itemType.isAcceptableOrUnknown(data['item_type']!, _itemTypeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_itemTypeMeta);
+    }
+    if (data.containsKey('password_protected')) {
+      context.handle(
+        _passwordProtectedMeta,
+        passwordProtected.isAcceptableOrUnknown(
+          data['password_protected']!,
+          _passwordProtectedMeta,
+        ),
+      );
+    }
+    if (data.containsKey('local_path')) {
+      context.handle(
+        _localPathMeta,
+        localPath.isAcceptableOrUnknown(data['local_path']!, _localPathMeta),
+      );
+    }
+    if (data.containsKey('last_synced_at')) {
+      context.handle(
+        _lastSyncedAtMeta,
+        lastSyncedAt.isAcceptableOrUnknown(
+          data['last_synced_at']!,
+          _lastSyncedAtMeta,
+        ),
+      );
+    }
+    if (data.containsKey('added_at')) {
+      context.handle(
+        _addedAtMeta,
+        addedAt.isAcceptableOrUnknown(data['added_at']!, _addedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_addedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  LinkedSharesTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return LinkedSharesTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}id'],
+      )!,
+      serverUrl: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}server_url'],
+      )!,
+      token: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}token'],
+      )!,
+      name: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}name'],
+      )!,
+      itemType: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_type'],
+      )!,
+      passwordProtected: attachedDatabase.typeMapping.read(
+        DriftSqlType.bool,
+        data['${effectivePrefix}password_protected'],
+      )!,
+      localPath: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}local_path'],
+      ),
+      lastSyncedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}last_synced_at'],
+      ),
+      addedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}added_at'],
+      )!,
+    );
+  }
+
+  @override
+  $LinkedSharesTableTable createAlias(String alias) {
+    return $LinkedSharesTableTable(attachedDatabase, alias);
+  }
+}
+
+class LinkedSharesTableData extends DataClass
+    implements Insertable<LinkedSharesTableData> {
+  final String id;
+  final String serverUrl;
+  final String token;
+  final String name;
+  final String itemType;
+  final bool passwordProtected;
+  final String? localPath;
+  final DateTime? lastSyncedAt;
+  final DateTime addedAt;
+  const LinkedSharesTableData({
+    required this.id,
+    required this.serverUrl,
+    required this.token,
+    required this.name,
+    required this.itemType,
+    required this.passwordProtected,
+    this.localPath,
+    this.lastSyncedAt,
+    required this.addedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<String>(id);
+    map['server_url'] = Variable<String>(serverUrl);
+    map['token'] = Variable<String>(token);
+    map['name'] = Variable<String>(name);
+    map['item_type'] = Variable<String>(itemType);
+    map['password_protected'] = Variable<bool>(passwordProtected);
+    if (!nullToAbsent || localPath != null) {
+      map['local_path'] = Variable<String>(localPath);
+    }
+    if (!nullToAbsent || lastSyncedAt != null) {
+      map['last_synced_at'] = Variable<DateTime>(lastSyncedAt);
+    }
+    map['added_at'] = Variable<DateTime>(addedAt);
+    return map;
+  }
+
+  LinkedSharesTableCompanion toCompanion(bool nullToAbsent) {
+    return LinkedSharesTableCompanion(
+      id: Value(id),
+      serverUrl: Value(serverUrl),
+      token: Value(token),
+      name: Value(name),
+      itemType: Value(itemType),
+      passwordProtected: Value(passwordProtected),
+      localPath: localPath == null && nullToAbsent
+          ? const Value.absent()
+          : Value(localPath),
+      lastSyncedAt: lastSyncedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(lastSyncedAt),
+      addedAt: Value(addedAt),
+    );
+  }
+
+  factory LinkedSharesTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return LinkedSharesTableData(
+      id: serializer.fromJson<String>(json['id']),
+      serverUrl: serializer.fromJson<String>(json['serverUrl']),
+      token: serializer.fromJson<String>(json['token']),
+      name: serializer.fromJson<String>(json['name']),
+      itemType: serializer.fromJson<String>(json['itemType']),
+      passwordProtected: serializer.fromJson<bool>(json['passwordProtected']),
+      localPath: serializer.fromJson<String?>(json['localPath']),
+      lastSyncedAt: serializer.fromJson<DateTime?>(json['lastSyncedAt']),
+      addedAt: serializer.fromJson<DateTime>(json['addedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<String>(id),
+      'serverUrl': serializer.toJson<String>(serverUrl),
+      'token': serializer.toJson<String>(token),
+      'name': serializer.toJson<String>(name),
+      'itemType': serializer.toJson<String>(itemType),
+      'passwordProtected': serializer.toJson<bool>(passwordProtected),
+      'localPath': serializer.toJson<String?>(localPath),
+      'lastSyncedAt': serializer.toJson<DateTime?>(lastSyncedAt),
+      'addedAt': serializer.toJson<DateTime>(addedAt),
+    };
+  }
+
+  LinkedSharesTableData copyWith({
+    String? id,
+    String? serverUrl,
+    String? token,
+    String? name,
+    String? itemType,
+    bool? passwordProtected,
+    Value<String?> localPath = const Value.absent(),
+    Value<DateTime?> lastSyncedAt = const Value.absent(),
+    DateTime? addedAt,
+  }) => LinkedSharesTableData(
+    id: id ?? this.id,
+    serverUrl: serverUrl ?? this.serverUrl,
+    token: token ?? this.token,
+    name: name ?? this.name,
+    itemType: itemType ?? this.itemType,
+    passwordProtected: passwordProtected ?? this.passwordProtected,
+    localPath: localPath.present ? localPath.value : this.localPath,
+    lastSyncedAt: lastSyncedAt.present ? lastSyncedAt.value : this.lastSyncedAt,
+    addedAt: addedAt ?? this.addedAt,
+  );
+  LinkedSharesTableData copyWithCompanion(LinkedSharesTableCompanion data) {
+    return LinkedSharesTableData(
+      id: data.id.present ? data.id.value : this.id,
+      serverUrl: data.serverUrl.present ? data.serverUrl.value : this.serverUrl,
+      token: data.token.present ? data.token.value : this.token,
+      name: data.name.present ? data.name.value : this.name,
+      itemType: data.itemType.present ? data.itemType.value : this.itemType,
+      passwordProtected: data.passwordProtected.present
+          ? data.passwordProtected.value
+          : this.passwordProtected,
+      localPath: data.localPath.present ? data.localPath.value : this.localPath,
+      lastSyncedAt: data.lastSyncedAt.present
+          ? data.lastSyncedAt.value
+          : this.lastSyncedAt,
+      addedAt: data.addedAt.present ? data.addedAt.value : this.addedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('LinkedSharesTableData(')
+          ..write('id: $id, ')
+          ..write('serverUrl: $serverUrl, ')
+          ..write('token: $token, ')
+          ..write('name: $name, ')
+          ..write('itemType: $itemType, ')
+          ..write('passwordProtected: $passwordProtected, ')
+          ..write('localPath: $localPath, ')
+          ..write('lastSyncedAt: $lastSyncedAt, ')
+          ..write('addedAt: $addedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    serverUrl,
+    token,
+    name,
+    itemType,
+    passwordProtected,
+    localPath,
+    lastSyncedAt,
+    addedAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is LinkedSharesTableData &&
+          other.id == this.id &&
+          other.serverUrl == this.serverUrl &&
+          other.token == this.token &&
+          other.name == this.name &&
+          other.itemType == this.itemType &&
+          other.passwordProtected == this.passwordProtected &&
+          other.localPath == this.localPath &&
+          other.lastSyncedAt == this.lastSyncedAt &&
+          other.addedAt == this.addedAt);
+}
+
+class LinkedSharesTableCompanion
+    extends UpdateCompanion<LinkedSharesTableData> {
+  final Value<String> id;
+  final Value<String> serverUrl;
+  final Value<String> token;
+  final Value<String> name;
+  final Value<String> itemType;
+  final Value<bool> passwordProtected;
+  final Value<String?> localPath;
+  final Value<DateTime?> lastSyncedAt;
+  final Value<DateTime> addedAt;
+  final Value<int> rowid;
+  const LinkedSharesTableCompanion({
+    this.id = const Value.absent(),
+    this.serverUrl = const Value.absent(),
+    this.token = const Value.absent(),
+    this.name = const Value.absent(),
+    this.itemType = const Value.absent(),
+    this.passwordProtected = const Value.absent(),
+    this.localPath = const Value.absent(),
+    this.lastSyncedAt = const Value.absent(),
+    this.addedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  LinkedSharesTableCompanion.insert({
+    required String id,
+    required String serverUrl,
+    required String token,
+    required String name,
+    required String itemType,
+    this.passwordProtected = const Value.absent(),
+    this.localPath = const Value.absent(),
+    this.lastSyncedAt = const Value.absent(),
+    required DateTime addedAt,
+    this.rowid = const Value.absent(),
+  }) : id = Value(id),
+       serverUrl = Value(serverUrl),
+       token = Value(token),
+       name = Value(name),
+       itemType = Value(itemType),
+       addedAt = Value(addedAt);
+  static Insertable<LinkedSharesTableData> custom({
+    Expression<String>? id,
+    Expression<String>? serverUrl,
+    Expression<String>? token,
+    Expression<String>? name,
+    Expression<String>? itemType,
+    Expression<bool>? passwordProtected,
+    Expression<String>? localPath,
+    Expression<DateTime>? lastSyncedAt,
+    Expression<DateTime>? addedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (serverUrl != null) 'server_url': serverUrl,
+      if (token != null) 'token': token,
+      if (name != null) 'name': name,
+      if (itemType != null) 'item_type': itemType,
+      if (passwordProtected != null) 'password_protected': passwordProtected,
+      if (localPath != null) 'local_path': localPath,
+      if (lastSyncedAt != null) 'last_synced_at': lastSyncedAt,
+      if (addedAt != null) 'added_at': addedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  LinkedSharesTableCompanion copyWith({
+    Value<String>? id,
+    Value<String>? serverUrl,
+    Value<String>? token,
+    Value<String>? name,
+    Value<String>? itemType,
+    Value<bool>? passwordProtected,
+    Value<String?>? localPath,
+    Value<DateTime?>? lastSyncedAt,
+    Value<DateTime>? addedAt,
+    Value<int>? rowid,
+  }) {
+    return LinkedSharesTableCompanion(
+      id: id ?? this.id,
+      serverUrl: serverUrl ?? this.serverUrl,
+      token: token ?? this.token,
+      name: name ?? this.name,
+      itemType: itemType ?? this.itemType,
+      passwordProtected: passwordProtected ?? this.passwordProtected,
+      localPath: localPath ?? this.localPath,
+      lastSyncedAt: lastSyncedAt ?? this.lastSyncedAt,
+      addedAt: addedAt ?? this.addedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<String>(id.value);
+    }
+    if (serverUrl.present) {
+      map['server_url'] = Variable<String>(serverUrl.value);
+    }
+    if (token.present) {
+      map['token'] = Variable<String>(token.value);
+    }
+    if (name.present) {
+      map['name'] = Variable<String>(name.value);
+    }
+    if (itemType.present) {
+      map['item_type'] = Variable<String>(itemType.value);
+    }
+    if (passwordProtected.present) {
+      map['password_protected'] = Variable<bool>(passwordProtected.value);
+    }
+    if (localPath.present) {
+      map['local_path'] = Variable<String>(localPath.value);
+    }
+    if (lastSyncedAt.present) {
+      map['last_synced_at'] = Variable<DateTime>(lastSyncedAt.value);
+    }
+    if (addedAt.present) {
+      map['added_at'] = Variable<DateTime>(addedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('LinkedSharesTableCompanion(')
+          ..write('id: $id, ')
+          ..write('serverUrl: $serverUrl, ')
+          ..write('token: $token, ')
+          ..write('name: $name, ')
+          ..write('itemType: $itemType, ')
+          ..write('passwordProtected: $passwordProtected, ')
+          ..write('localPath: $localPath, ')
+          ..write('lastSyncedAt: $lastSyncedAt, ')
+          ..write('addedAt: $addedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $TransferLedgerTableTable extends TransferLedgerTable
+    with TableInfo<$TransferLedgerTableTable, TransferLedgerTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $TransferLedgerTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<int> id = GeneratedColumn<int>(
+    'id',
+    aliasedName,
+    false,
+    hasAutoIncrement: true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'PRIMARY KEY AUTOINCREMENT',
+    ),
+  );
+  static const VerificationMeta _itemIdMeta = const VerificationMeta('itemId');
+  @override
+  late final GeneratedColumn<String> itemId = GeneratedColumn<String>(
+    'item_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _itemNameMeta = const VerificationMeta(
+    'itemName',
+  );
+  @override
+  late final GeneratedColumn<String> itemName = GeneratedColumn<String>(
+    'item_name',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _sizeMeta = const VerificationMeta('size');
+  @override
+  late final GeneratedColumn<int> size = GeneratedColumn<int>(
+    'size',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _remoteModifiedAtMeta = const VerificationMeta(
+    'remoteModifiedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> remoteModifiedAt =
+      GeneratedColumn<DateTime>(
+        'remote_modified_at',
+        aliasedName,
+        true,
+        type: DriftSqlType.dateTime,
+        requiredDuringInsert: false,
+      );
+  static const VerificationMeta _statusMeta = const VerificationMeta('status');
+  @override
+  late final GeneratedColumn<String> status = GeneratedColumn<String>(
+    'status',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+    defaultValue: const Constant('pending'),
+  );
+  static const VerificationMeta _uploadedAtMeta = const VerificationMeta(
+    'uploadedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> uploadedAt = GeneratedColumn<DateTime>(
+    'uploaded_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _verifiedAtMeta = const VerificationMeta(
+    'verifiedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> verifiedAt = GeneratedColumn<DateTime>(
+    'verified_at',
+    aliasedName,
+    true,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: false,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    itemId,
+    itemName,
+    size,
+    remoteModifiedAt,
+    status,
+    uploadedAt,
+    verifiedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'transfer_ledger';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<TransferLedgerTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    }
+    if (data.containsKey('item_id')) {
+      context.handle(
+        _itemIdMeta,
+        itemId.isAcceptableOrUnknown(data['item_id']!, _itemIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_itemIdMeta);
+    }
+    if (data.containsKey('item_name')) {
+      context.handle(
+        _itemNameMeta,
+        itemName.isAcceptableOrUnknown(data['item_name']!, _itemNameMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_itemNameMeta);
+    }
+    if (data.containsKey('size')) {
+      context.handle(
+        _sizeMeta,
+        size.isAcceptableOrUnknown(data['size']!, _sizeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_sizeMeta);
+    }
+    if (data.containsKey('remote_modified_at')) {
+      context.handle(
+        _remoteModifiedAtMeta,
+        remoteModifiedAt.isAcceptableOrUnknown(
+          data['remote_modified_at']!,
+          _remoteModifiedAtMeta,
+        ),
+      );
+    }
+    if (data.containsKey('status')) {
+      context.handle(
+        _statusMeta,
+        status.isAcceptableOrUnknown(data['status']!, _statusMeta),
+      );
+    }
+    if (data.containsKey('uploaded_at')) {
+      context.handle(
+        _uploadedAtMeta,
+        uploadedAt.isAcceptableOrUnknown(data['uploaded_at']!, _uploadedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_uploadedAtMeta);
+    }
+    if (data.containsKey('verified_at')) {
+      context.handle(
+        _verifiedAtMeta,
+        verifiedAt.isAcceptableOrUnknown(data['verified_at']!, _verifiedAtMeta),
+      );
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  TransferLedgerTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return TransferLedgerTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}id'],
+      )!,
+      itemId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_id'],
+      )!,
+      itemName: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_name'],
+      )!,
+      size: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}size'],
+      )!,
+      remoteModifiedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}remote_modified_at'],
+      ),
+      status: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}status'],
+      )!,
+      uploadedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}uploaded_at'],
+      )!,
+      verifiedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}verified_at'],
+      ),
+    );
+  }
+
+  @override
+  $TransferLedgerTableTable createAlias(String alias) {
+    return $TransferLedgerTableTable(attachedDatabase, alias);
+  }
+}
+
+class TransferLedgerTableData extends DataClass
+    implements Insertable<TransferLedgerTableData> {
+  final int id;
+  final String itemId;
+  final String itemName;
+  final int size;
+  final DateTime? remoteModifiedAt;
+  final String status;
+  final DateTime uploadedAt;
+  final DateTime? verifiedAt;
+  const TransferLedgerTableData({
+    required this.id,
+    required this.itemId,
+    required this.itemName,
+    required this.size,
+    this.remoteModifiedAt,
+    required this.status,
+    required this.uploadedAt,
+    this.verifiedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<int>(id);
+    map['item_id'] = Variable<String>(itemId);
+    map['item_name'] = Variable<String>(itemName);
+    map['size'] = Variable<int>(size);
+    if (!nullToAbsent || remoteModifiedAt != null) {
+      map['remote_modified_at'] = Variable<DateTime>(remoteModifiedAt);
+    }
+    map['status'] = Variable<String>(status);
+    map['uploaded_at'] = Variable<DateTime>(uploadedAt);
+    if (!nullToAbsent || verifiedAt != null) {
+      map['verified_at'] = Variable<DateTime>(verifiedAt);
+    }
+    return map;
+  }
+
+  TransferLedgerTableCompanion toCompanion(bool nullToAbsent) {
+    return TransferLedgerTableCompanion(
+      id: Value(id),
+      itemId: Value(itemId),
+      itemName: Value(itemName),
+      size: Value(size),
+      remoteModifiedAt: remoteModifiedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(remoteModifiedAt),
+      status: Value(status),
+      uploadedAt: Value(uploadedAt),
+      verifiedAt: verifiedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(verifiedAt),
+    );
+  }
+
+  factory TransferLedgerTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return TransferLedgerTableData(
+      id: serializer.fromJson<int>(json['id']),
+      itemId: serializer.fromJson<String>(json['itemId']),
+      itemName: serializer.fromJson<String>(json['itemName']),
+      size: serializer.fromJson<int>(json['size']),
+      remoteModifiedAt: serializer.fromJson<DateTime?>(
+        json['remoteModifiedAt'],
+      ),
+      status: serializer.fromJson<String>(json['status']),
+      uploadedAt: serializer.fromJson<DateTime>(json['uploadedAt']),
+      verifiedAt: serializer.fromJson<DateTime?>(json['verifiedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<int>(id),
+      'itemId': serializer.toJson<String>(itemId),
+      'itemName': serializer.toJson<String>(itemName),
+      'size': serializer.toJson<int>(size),
+      'remoteModifiedAt': serializer.toJson<DateTime?>(remoteModifiedAt),
+      'status': serializer.toJson<String>(status),
+      'uploadedAt': serializer.toJson<DateTime>(uploadedAt),
+      'verifiedAt': serializer.toJson<DateTime?>(verifiedAt),
+    };
+  }
+
+  TransferLedgerTableData copyWith({
+    int? id,
+    String? itemId,
+    String? itemName,
+    int? size,
+    Value<DateTime?> remoteModifiedAt = const Value.absent(),
+    String? status,
+    DateTime? uploadedAt,
+    Value<DateTime?> verifiedAt = const Value.absent(),
+  }) => TransferLedgerTableData(
+    id: id ?? this.id,
+    itemId: itemId ?? this.itemId,
+    itemName: itemName ?? this.itemName,
+    size: size ?? this.size,
+    remoteModifiedAt: remoteModifiedAt.present
+        ? remoteModifiedAt.value
+        : this.remoteModifiedAt,
+    status: status ?? this.status,
+    uploadedAt: uploadedAt ?? this.uploadedAt,
+    verifiedAt: verifiedAt.present ? verifiedAt.value : this.verifiedAt,
+  );
+  TransferLedgerTableData copyWithCompanion(TransferLedgerTableCompanion data) {
+    return TransferLedgerTableData(
+      id: data.id.present ? data.id.value : this.id,
+      itemId: data.itemId.present ? data.itemId.value : this.itemId,
+      itemName: data.itemName.present ? data.itemName.value : this.itemName,
+      size: data.size.present ? data.size.value : this.size,
+      remoteModifiedAt: data.remoteModifiedAt.present
+          ? data.remoteModifiedAt.value
+          : this.remoteModifiedAt,
+      status: data.status.present ? data.status.value : this.status,
+      uploadedAt: data.uploadedAt.present
+          ? data.uploadedAt.value
+          : this.uploadedAt,
+      verifiedAt: data.verifiedAt.present
+          ? data.verifiedAt.value
+          : this.verifiedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('TransferLedgerTableData(')
+          ..write('id: $id, ')
+          ..write('itemId: $itemId, ')
+          ..write('itemName: $itemName, ')
+          ..write('size: $size, ')
+          ..write('remoteModifiedAt: $remoteModifiedAt, ')
+          ..write('status: $status, ')
+          ..write('uploadedAt: $uploadedAt, ')
+          ..write('verifiedAt: $verifiedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    itemId,
+    itemName,
+    size,
+    remoteModifiedAt,
+    status,
+    uploadedAt,
+    verifiedAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is TransferLedgerTableData &&
+          other.id == this.id &&
+          other.itemId == this.itemId &&
+          other.itemName == this.itemName &&
+          other.size == this.size &&
+          other.remoteModifiedAt == this.remoteModifiedAt &&
+          other.status == this.status &&
+          other.uploadedAt == this.uploadedAt &&
+          other.verifiedAt == this.verifiedAt);
+}
+
+class TransferLedgerTableCompanion
+    extends UpdateCompanion<TransferLedgerTableData> {
+  final Value<int> id;
+  final Value<String> itemId;
+  final Value<String> itemName;
+  final Value<int> size;
+  final Value<DateTime?> remoteModifiedAt;
+  final Value<String> status;
+  final Value<DateTime> uploadedAt;
+  final Value<DateTime?> verifiedAt;
+  const TransferLedgerTableCompanion({
+    this.id = const Value.absent(),
+    this.itemId = const Value.absent(),
+    this.itemName = const Value.absent(),
+    this.size = const Value.absent(),
+    this.remoteModifiedAt = const Value.absent(),
+    this.status = const Value.absent(),
+    this.uploadedAt = const Value.absent(),
+    this.verifiedAt = const Value.absent(),
+  });
+  TransferLedgerTableCompanion.insert({
+    this.id = const Value.absent(),
+    required String itemId,
+    required String itemName,
+    required int size,
+    this.remoteModifiedAt = const Value.absent(),
+    this.status = const Value.absent(),
+    required DateTime uploadedAt,
+    this.verifiedAt = const Value.absent(),
+  }) : itemId = Value(itemId),
+       itemName = Value(itemName),
+       size = Value(size),
+       uploadedAt = Value(uploadedAt);
+  static Insertable<TransferLedgerTableData> custom({
+    Expression<int>? id,
+    Expression<String>? itemId,
+    Expression<String>? itemName,
+    Expression<int>? size,
+    Expression<DateTime>? remoteModifiedAt,
+    Expression<String>? status,
+    Expression<DateTime>? uploadedAt,
+    Expression<DateTime>? verifiedAt,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (itemId != null) 'item_id': itemId,
+      if (itemName != null) 'item_name': itemName,
+      if (size != null) 'size': size,
+      if (remoteModifiedAt != null) 'remote_modified_at': remoteModifiedAt,
+      if (status != null) 'status': status,
+      if (uploadedAt != null) 'uploaded_at': uploadedAt,
+      if (verifiedAt != null) 'verified_at': verifiedAt,
+    });
+  }
+
+  TransferLedgerTableCompanion copyWith({
+    Value<int>? id,
+    Value<String>? itemId,
+    Value<String>? itemName,
+    Value<int>? size,
+    Value<DateTime?>? remoteModifiedAt,
+    Value<String>? status,
+    Value<DateTime>? uploadedAt,
+    Value<DateTime?>? verifiedAt,
+  }) {
+    return TransferLedgerTableCompanion(
+      id: id ?? this.id,
+      itemId: itemId ?? this.itemId,
+      itemName: itemName ?? this.itemName,
+      size: size ?? this.size,
+      remoteModifiedAt: remoteModifiedAt ?? this.remoteModifiedAt,
+      status: status ?? this.status,
+      uploadedAt: uploadedAt ?? this.uploadedAt,
+      verifiedAt: verifiedAt ?? this.verifiedAt,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<int>(id.value);
+    }
+    if (itemId.present) {
+      map['item_id'] = Variable<String>(itemId.value);
+    }
+    if (itemName.present) {
+      map['item_name'] = Variable<String>(itemName.value);
+    }
+    if (size.present) {
+      map['size'] = Variable<int>(size.value);
+    }
+    if (remoteModifiedAt.present) {
+      map['remote_modified_at'] = Variable<DateTime>(remoteModifiedAt.value);
+    }
+    if (status.present) {
+      map['status'] = Variable<String>(status.value);
+    }
+    if (uploadedAt.present) {
+      map['uploaded_at'] = Variable<DateTime>(uploadedAt.value);
+    }
+    if (verifiedAt.present) {
+      map['verified_at'] = Variable<DateTime>(verifiedAt.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('TransferLedgerTableCompanion(')
+          ..write('id: $id, ')
+          ..write('itemId: $itemId, ')
+          ..write('itemName: $itemName, ')
+          ..write('size: $size, ')
+          ..write('remoteModifiedAt: $remoteModifiedAt, ')
+          ..write('status: $status, ')
+          ..write('uploadedAt: $uploadedAt, ')
+          ..write('verifiedAt: $verifiedAt')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $FileModesTableTable extends FileModesTable
+    with TableInfo<$FileModesTableTable, FileModesTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $FileModesTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _fileIdMeta = const VerificationMeta('fileId');
+  @override
+  late final GeneratedColumn<String> fileId = GeneratedColumn<String>(
+    'file_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _modeMeta = const VerificationMeta('mode');
+  @override
+  late final GeneratedColumn<int> mode = GeneratedColumn<int>(
+    'mode',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _recordedAtMeta = const VerificationMeta(
+    'recordedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> recordedAt = GeneratedColumn<DateTime>(
+    'recorded_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [fileId, mode, recordedAt];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'file_modes';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<FileModesTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('file_id')) {
+      context.handle(
+        _fileIdMeta,
+        fileId.isAcceptableOrUnknown(data['file_id']!, _fileIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_fileIdMeta);
+    }
+    if (data.containsKey('mode')) {
+      context.handle(
+        _modeMeta,
+        mode.isAcceptableOrUnknown(data['mode']!, _modeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_modeMeta);
+    }
+    if (data.containsKey('recorded_at')) {
+      context.handle(
+        _recordedAtMeta,
+        recordedAt.isAcceptableOrUnknown(data['recorded_at']!, _recordedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_recordedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {fileId};
+  @override
+  FileModesTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return FileModesTableData(
+      fileId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}file_id'],
+      )!,
+      mode: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}mode'],
+      )!,
+      recordedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}recorded_at'],
+      )!,
+    );
+  }
+
+  @override
+  $FileModesTableTable createAlias(String alias) {
+    return $FileModesTableTable(attachedDatabase, alias);
+  }
+}
+
+class FileModesTableData extends DataClass
+    implements Insertable<FileModesTableData> {
+  final String fileId;
+  final int mode;
+  final DateTime recordedAt;
+  const FileModesTableData({
+    required this.fileId,
+    required this.mode,
+    required this.recordedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['file_id'] = Variable<String>(fileId);
+    map['mode'] = Variable<int>(mode);
+    map['recorded_at'] = Variable<DateTime>(recordedAt);
+    return map;
+  }
+
+  FileModesTableCompanion toCompanion(bool nullToAbsent) {
+    return FileModesTableCompanion(
+      fileId: Value(fileId),
+      mode: Value(mode),
+      recordedAt: Value(recordedAt),
+    );
+  }
+
+  factory FileModesTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return FileModesTableData(
+      fileId: serializer.fromJson<String>(json['fileId']),
+      mode: serializer.fromJson<int>(json['mode']),
+      recordedAt: serializer.fromJson<DateTime>(json['recordedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'fileId': serializer.toJson<String>(fileId),
+      'mode': serializer.toJson<int>(mode),
+      'recordedAt': serializer.toJson<DateTime>(recordedAt),
+    };
+  }
+
+  FileModesTableData copyWith({
+    String? fileId,
+    int? mode,
+    DateTime? recordedAt,
+  }) => FileModesTableData(
+    fileId: fileId ?? this.fileId,
+    mode: mode ?? this.mode,
+    recordedAt: recordedAt ?? this.recordedAt,
+  );
+  FileModesTableData copyWithCompanion(FileModesTableCompanion data) {
+    return FileModesTableData(
+      fileId: data.fileId.present ? data.fileId.value : this.fileId,
+      mode: data.mode.present ? data.mode.value : this.mode,
+      recordedAt: data.recordedAt.present
+          ? data.recordedAt.value
+          : this.recordedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FileModesTableData(')
+          ..write('fileId: $fileId, ')
+          ..write('mode: $mode, ')
+          ..write('recordedAt: $recordedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(fileId, mode, recordedAt);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is FileModesTableData &&
+          other.fileId == this.fileId &&
+          other.mode == this.mode &&
+          other.recordedAt == this.recordedAt);
+}
+
+class FileModesTableCompanion extends UpdateCompanion<FileModesTableData> {
+  final Value<String> fileId;
+  final Value<int> mode;
+  final Value<DateTime> recordedAt;
+  final Value<int> rowid;
+  const FileModesTableCompanion({
+    this.fileId = const Value.absent(),
+    this.mode = const Value.absent(),
+    this.recordedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  FileModesTableCompanion.insert({
+    required String fileId,
+    required int mode,
+    required DateTime recordedAt,
+    this.rowid = const Value.absent(),
+  }) : fileId = Value(fileId),
+       mode = Value(mode),
+       recordedAt = Value(recordedAt);
+  static Insertable<FileModesTableData> custom({
+    Expression<String>? fileId,
+    Expression<int>? mode,
+    Expression<DateTime>? recordedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (fileId != null) 'file_id': fileId,
+      if (mode != null) 'mode': mode,
+      if (recordedAt != null) 'recorded_at': recordedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  FileModesTableCompanion copyWith({
+    Value<String>? fileId,
+    Value<int>? mode,
+    Value<DateTime>? recordedAt,
+    Value<int>? rowid,
+  }) {
+    return FileModesTableCompanion(
+      fileId: fileId ?? this.fileId,
+      mode: mode ?? this.mode,
+      recordedAt: recordedAt ?? this.recordedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (fileId.present) {
+      map['file_id'] = Variable<String>(fileId.value);
+    }
+    if (mode.present) {
+      map['mode'] = Variable<int>(mode.value);
+    }
+    if (recordedAt.present) {
+      map['recorded_at'] = Variable<DateTime>(recordedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FileModesTableCompanion(')
+          ..write('fileId: $fileId, ')
+          ..write('mode: $mode, ')
+          ..write('recordedAt: $recordedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $DbLeaseTableTable extends DbLeaseTable
+    with TableInfo<$DbLeaseTableTable, DbLeaseTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $DbLeaseTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<int> id = GeneratedColumn<int>(
+    'id',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(1),
+  );
+  static const VerificationMeta _ownerIdMeta = const VerificationMeta(
+    'ownerId',
+  );
+  @override
+  late final GeneratedColumn<String> ownerId = GeneratedColumn<String>(
+    'owner_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _pidMeta = const VerificationMeta('pid');
+  @override
+  late final GeneratedColumn<int> pid = GeneratedColumn<int>(
+    'pid',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _hostnameMeta = const VerificationMeta(
+    'hostname',
+  );
+  @override
+  late final GeneratedColumn<String> hostname = GeneratedColumn<String>(
+    'hostname',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _acquiredAtMeta = const VerificationMeta(
+    'acquiredAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> acquiredAt = GeneratedColumn<DateTime>(
+    'acquired_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _heartbeatAtMeta = const VerificationMeta(
+    'heartbeatAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> heartbeatAt = GeneratedColumn<DateTime>(
+    'heartbeat_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    ownerId,
+    pid,
+    hostname,
+    acquiredAt,
+    heartbeatAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'db_lease';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<DbLeaseTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    }
+    if (data.containsKey('owner_id')) {
+      context.handle(
+        _ownerIdMeta,
+        ownerId.isAcceptableOrUnknown(data['owner_id']!, _ownerIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_ownerIdMeta);
+    }
+    if (data.containsKey('pid')) {
+      context.handle(
+        _pidMeta,
+        pid.isAcceptableOrUnknown(data['pid']!, _pidMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_pidMeta);
+    }
+    if (data.containsKey('hostname')) {
+      context.handle(
+        _hostnameMeta,
+        hostname.isAcceptableOrUnknown(data['hostname']!, _hostnameMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_hostnameMeta);
+    }
+    if (data.containsKey('acquired_at')) {
+      context.handle(
+        _acquiredAtMeta,
+        acquiredAt.isAcceptableOrUnknown(data['acquired_at']!, _acquiredAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_acquiredAtMeta);
+    }
+    if (data.containsKey('heartbeat_at')) {
+      context.handle(
+        _heartbeatAtMeta,
+        heartbeatAt.isAcceptableOrUnknown(
+          data['heartbeat_at']!,
+          _heartbeatAtMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_heartbeatAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  DbLeaseTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return DbLeaseTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}id'],
+      )!,
+      ownerId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}owner_id'],
+      )!,
+      pid: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}pid'],
+      )!,
+      hostname: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}hostname'],
+      )!,
+      acquiredAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}acquired_at'],
+      )!,
+      heartbeatAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}heartbeat_at'],
+      )!,
+    );
+  }
+
+  @override
+  $DbLeaseTableTable createAlias(String alias) {
+    return $DbLeaseTableTable(attachedDatabase, alias);
+  }
+}
+
+class DbLeaseTableData extends DataClass
+    implements Insertable<DbLeaseTableData> {
+  final int id;
+  final String ownerId;
+  final int pid;
+  final String hostname;
+  final DateTime acquiredAt;
+  final DateTime heartbeatAt;
+  const DbLeaseTableData({
+    required this.id,
+    required this.ownerId,
+    required this.pid,
+    required this.hostname,
+    required this.acquiredAt,
+    required this.heartbeatAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<int>(id);
+    map['owner_id'] = Variable<String>(ownerId);
+    map['pid'] = Variable<int>(pid);
+    map['hostname'] = Variable<String>(hostname);
+    map['acquired_at'] = Variable<DateTime>(acquiredAt);
+    map['heartbeat_at'] = Variable<DateTime>(heartbeatAt);
+    return map;
+  }
+
+  DbLeaseTableCompanion toCompanion(bool nullToAbsent) {
+    return DbLeaseTableCompanion(
+      id: Value(id),
+      ownerId: Value(ownerId),
+      pid: Value(pid),
+      hostname: Value(hostname),
+      acquiredAt: Value(acquiredAt),
+      heartbeatAt: Value(heartbeatAt),
+    );
+  }
+
+  factory DbLeaseTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return DbLeaseTableData(
+      id: serializer.fromJson<int>(json['id']),
+      ownerId: serializer.fromJson<String>(json['ownerId']),
+      pid: serializer.fromJson<int>(json['pid']),
+      hostname: serializer.fromJson<String>(json['hostname']),
+      acquiredAt: serializer.fromJson<DateTime>(json['acquiredAt']),
+      heartbeatAt: serializer.fromJson<DateTime>(json['heartbeatAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<int>(id),
+      'ownerId': serializer.toJson<String>(ownerId),
+      'pid': serializer.toJson<int>(pid),
+      'hostname': serializer.toJson<String>(hostname),
+      'acquiredAt': serializer.toJson<DateTime>(acquiredAt),
+      'heartbeatAt': serializer.toJson<DateTime>(heartbeatAt),
+    };
+  }
+
+  DbLeaseTableData copyWith({
+    int? id,
+    String? ownerId,
+    int? pid,
+    String? hostname,
+    DateTime? acquiredAt,
+    DateTime? heartbeatAt,
+  }) => DbLeaseTableData(
+    id: id ?? this.id,
+    ownerId: ownerId ?? this.ownerId,
+    pid: pid ?? this.pid,
+    hostname: hostname ?? this.hostname,
+    acquiredAt: acquiredAt ?? this.acquiredAt,
+    heartbeatAt: heartbeatAt ?? this.heartbeatAt,
+  );
+  DbLeaseTableData copyWithCompanion(DbLeaseTableCompanion data) {
+    return DbLeaseTableData(
+      id: data.id.present ? data.id.value : this.id,
+      ownerId: data.ownerId.present ? data.ownerId.value : this.ownerId,
+      pid: data.pid.present ? data.pid.value : this.pid,
+      hostname: data.hostname.present ? data.hostname.value : this.hostname,
+      acquiredAt: data.acquiredAt.present
+          ? data.acquiredAt.value
+          : this.acquiredAt,
+      heartbeatAt: data.heartbeatAt.present
+          ? data.heartbeatAt.value
+          : this.heartbeatAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('DbLeaseTableData(')
+          ..write('id: $id, ')
+          ..write('ownerId: $ownerId, ')
+          ..write('pid: $pid, ')
+          ..write('hostname: $hostname, ')
+          ..write('acquiredAt: $acquiredAt, ')
+          ..write('heartbeatAt: $heartbeatAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    ownerId,
+    pid,
+    hostname,
+    acquiredAt,
+    heartbeatAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is DbLeaseTableData &&
+          other.id == this.id &&
+          other.ownerId == this.ownerId &&
+          other.pid == this.pid &&
+          other.hostname == this.hostname &&
+          other.acquiredAt == this.acquiredAt &&
+          other.heartbeatAt == this.heartbeatAt);
+}
+
+class DbLeaseTableCompanion extends UpdateCompanion<DbLeaseTableData> {
+  final Value<int> id;
+  final Value<String> ownerId;
+  final Value<int> pid;
+  final Value<String> hostname;
+  final Value<DateTime> acquiredAt;
+  final Value<DateTime> heartbeatAt;
+  final Value<int> rowid;
+  const DbLeaseTableCompanion({
+    this.id = const Value.absent(),
+    this.ownerId = const Value.absent(),
+    this.pid = const Value.absent(),
+    this.hostname = const Value.absent(),
+    this.acquiredAt = const Value.absent(),
+    this.heartbeatAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  DbLeaseTableCompanion.insert({
+    this.id = const Value.absent(),
+    required String ownerId,
+    required int pid,
+    required String hostname,
+    required DateTime acquiredAt,
+    required DateTime heartbeatAt,
+    this.rowid = const Value.absent(),
+  }) : ownerId = Value(ownerId),
+       pid = Value(pid),
+       hostname = Value(hostname),
+       acquiredAt = Value(acquiredAt),
+       heartbeatAt = Value(heartbeatAt);
+  static Insertable<DbLeaseTableData> custom({
+    Expression<int>? id,
+    Expression<String>? ownerId,
+    Expression<int>? pid,
+    Expression<String>? hostname,
+    Expression<DateTime>? acquiredAt,
+    Expression<DateTime>? heartbeatAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (ownerId != null) 'owner_id': ownerId,
+      if (pid != null) 'pid': pid,
+      if (hostname != null) 'hostname': hostname,
+      if (acquiredAt != null) 'acquired_at': acquiredAt,
+      if (heartbeatAt != null) 'heartbeat_at': heartbeatAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  DbLeaseTableCompanion copyWith({
+    Value<int>? id,
+    Value<String>? ownerId,
+    Value<int>? pid,
+    Value<String>? hostname,
+    Value<DateTime>? acquiredAt,
+    Value<DateTime>? heartbeatAt,
+    Value<int>? rowid,
+  }) {
+    return DbLeaseTableCompanion(
+      id: id ?? this.id,
+      ownerId: ownerId ?? this.ownerId,
+      pid: pid ?? this.pid,
+      hostname: hostname ?? this.hostname,
+      acquiredAt: acquiredAt ?? this.acquiredAt,
+      heartbeatAt: heartbeatAt ?? this.heartbeatAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<int>(id.value);
+    }
+    if (ownerId.present) {
+      map['owner_id'] = Variable<String>(ownerId.value);
+    }
+    if (pid.present) {
+      map['pid'] = Variable<int>(pid.value);
+    }
+    if (hostname.present) {
+      map['hostname'] = Variable<String>(hostname.value);
+    }
+    if (acquiredAt.present) {
+      map['acquired_at'] = Variable<DateTime>(acquiredAt.value);
+    }
+    if (heartbeatAt.present) {
+      map['heartbeat_at'] = Variable<DateTime>(heartbeatAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('DbLeaseTableCompanion(')
+          ..write('id: $id, ')
+          ..write('ownerId: $ownerId, ')
+          ..write('pid: $pid, ')
+          ..write('hostname: $hostname, ')
+          ..write('acquiredAt: $acquiredAt, ')
+          ..write('heartbeatAt: $heartbeatAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $ConflictRulesTableTable extends ConflictRulesTable
+    with TableInfo<$ConflictRulesTableTable, ConflictRulesTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $ConflictRulesTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<int> id = GeneratedColumn<int>(
+    'id',
+    aliasedName,
+    false,
+    hasAutoIncrement: true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'PRIMARY KEY AUTOINCREMENT',
+    ),
+  );
+  static const VerificationMeta _pathPrefixMeta = const VerificationMeta(
+    'pathPrefix',
+  );
+  @override
+  late final GeneratedColumn<String> pathPrefix = GeneratedColumn<String>(
+    'path_prefix',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _namePatternMeta = const VerificationMeta(
+    'namePattern',
+  );
+  @override
+  late final GeneratedColumn<String> namePattern = GeneratedColumn<String>(
+    'name_pattern',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _resolutionMeta = const VerificationMeta(
+    'resolution',
+  );
+  @override
+  late final GeneratedColumn<String> resolution = GeneratedColumn<String>(
+    'resolution',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _positionMeta = const VerificationMeta(
+    'position',
+  );
+  @override
+  late final GeneratedColumn<int> position = GeneratedColumn<int>(
+    'position',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _createdAtMeta = const VerificationMeta(
+    'createdAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> createdAt = GeneratedColumn<DateTime>(
+    'created_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    pathPrefix,
+    namePattern,
+    resolution,
+    position,
+    createdAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'conflict_rules';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<ConflictRulesTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    }
+    if (data.containsKey('path_prefix')) {
+      context.handle(
+        _pathPrefixMeta,
+        pathPrefix.isAcceptableOrUnknown(data['path_prefix']!, _pathPrefixMeta),
+      );
+    }
+    if (data.containsKey('name_pattern')) {
+      context.handle(
+        _namePatternMeta,
+        namePattern.isAcceptableOrUnknown(
+          data['name_pattern']!,
+          _namePatternMeta,
+        ),
+      );
+    }
+    if (data.containsKey('resolution')) {
+      context.handle(
+        _resolutionMeta,
+        resolution.isAcceptableOrUnknown(data['resolution']!, _resolutionMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_resolutionMeta);
+    }
+    if (data.containsKey('position')) {
+      context.handle(
+        _positionMeta,
+        position.isAcceptableOrUnknown(data['position']!, _positionMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_positionMeta);
+    }
+    if (data.containsKey('created_at')) {
+      context.handle(
+        _createdAtMeta,
+        createdAt.isAcceptableOrUnknown(data['created_at']!, _createdAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_createdAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  ConflictRulesTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return ConflictRulesTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}id'],
+      )!,
+      pathPrefix: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}path_prefix'],
+      ),
+      namePattern: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}name_pattern'],
+      ),
+      resolution: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}resolution'],
+      )!,
+      position: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}position'],
+      )!,
+      createdAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}created_at'],
+      )!,
+    );
+  }
+
+  @override
+  $ConflictRulesTableTable createAlias(String alias) {
+    return $ConflictRulesTableTable(attachedDatabase, alias);
+  }
+}
+
+class ConflictRulesTableData extends DataClass
+    implements Insertable<ConflictRulesTableData> {
+  final int id;
+  final String? pathPrefix;
+  final String? namePattern;
+  final String resolution;
+  final int position;
+  final DateTime createdAt;
+  const ConflictRulesTableData({
+    required this.id,
+    this.pathPrefix,
+    this.namePattern,
+    required this.resolution,
+    required this.position,
+    required this.createdAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<int>(id);
+    if (!nullToAbsent || pathPrefix != null) {
+      map['path_prefix'] = Variable<String>(pathPrefix);
+    }
+    if (!nullToAbsent || namePattern != null) {
+      map['name_pattern'] = Variable<String>(namePattern);
+    }
+    map['resolution'] = Variable<String>(resolution);
+    map['position'] = Variable<int>(position);
+    map['created_at'] = Variable<DateTime>(createdAt);
+    return map;
+  }
+
+  ConflictRulesTableCompanion toCompanion(bool nullToAbsent) {
+    return ConflictRulesTableCompanion(
+      id: Value(id),
+      pathPrefix: pathPrefix == null && nullToAbsent
+          ? const Value.absent()
+          : Value(pathPrefix),
+      namePattern: namePattern == null && nullToAbsent
+          ? const Value.absent()
+          : Value(namePattern),
+      resolution: Value(resolution),
+      position: Value(position),
+      createdAt: Value(createdAt),
+    );
+  }
+
+  factory ConflictRulesTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return ConflictRulesTableData(
+      id: serializer.fromJson<int>(json['id']),
+      pathPrefix: serializer.fromJson<String?>(json['pathPrefix']),
+      namePattern: serializer.fromJson<String?>(json['namePattern']),
+      resolution: serializer.fromJson<String>(json['resolution']),
+      position: serializer.fromJson<int>(json['position']),
+      createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<int>(id),
+      'pathPrefix': serializer.toJson<String?>(pathPrefix),
+      'namePattern': serializer.toJson<String?>(namePattern),
+      'resolution': serializer.toJson<String>(resolution),
+      'position': serializer.toJson<int>(position),
+      'createdAt': serializer.toJson<DateTime>(createdAt),
+    };
+  }
+
+  ConflictRulesTableData copyWith({
+    int? id,
+    Value<String?> pathPrefix = const Value.absent(),
+    Value<String?> namePattern = const Value.absent(),
+    String? resolution,
+    int? position,
+    DateTime? createdAt,
+  }) => ConflictRulesTableData(
+    id: id ?? this.id,
+    pathPrefix: pathPrefix.present ? pathPrefix.value : this.pathPrefix,
+    namePattern: namePattern.present ? namePattern.value : this.namePattern,
+    resolution: resolution ?? this.resolution,
+    position: position ?? this.position,
+    createdAt: createdAt ?? this.createdAt,
+  );
+  ConflictRulesTableData copyWithCompanion(ConflictRulesTableCompanion data) {
+    return ConflictRulesTableData(
+      id: data.id.present ? data.id.value : this.id,
+      pathPrefix: data.pathPrefix.present
+          ? data.pathPrefix.value
+          : this.pathPrefix,
+      namePattern: data.namePattern.present
+          ? data.namePattern.value
+          : this.namePattern,
+      resolution: data.resolution.present
+          ? data.resolution.value
+          : this.resolution,
+      position: data.position.present ? data.position.value : this.position,
+      createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ConflictRulesTableData(')
+          ..write('id: $id, ')
+          ..write('pathPrefix: $pathPrefix, ')
+          ..write('namePattern: $namePattern, ')
+          ..write('resolution: $resolution, ')
+          ..write('position: $position, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    pathPrefix,
+    namePattern,
+    resolution,
+    position,
+    createdAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is ConflictRulesTableData &&
+          other.id == this.id &&
+          other.pathPrefix == this.pathPrefix &&
+          other.namePattern == this.namePattern &&
+          other.resolution == this.resolution &&
+          other.position == this.position &&
+          other.createdAt == this.createdAt);
+}
+
+class ConflictRulesTableCompanion
+    extends UpdateCompanion<ConflictRulesTableData> {
+  final Value<int> id;
+  final Value<String?> pathPrefix;
+  final Value<String?> namePattern;
+  final Value<String> resolution;
+  final Value<int> position;
+  final Value<DateTime> createdAt;
+  const ConflictRulesTableCompanion({
+    this.id = const Value.absent(),
+    this.pathPrefix = const Value.absent(),
+    this.namePattern = const Value.absent(),
+    this.resolution = const Value.absent(),
+    this.position = const Value.absent(),
+    this.createdAt = const Value.absent(),
+  });
+  ConflictRulesTableCompanion.insert({
+    this.id = const Value.absent(),
+    this.pathPrefix = const Value.absent(),
+    this.namePattern = const Value.absent(),
+    required String resolution,
+    required int position,
+    required DateTime createdAt,
+  }) : resolution = Value(resolution),
+       position = Value(position),
+       createdAt = Value(createdAt);
+  static Insertable<ConflictRulesTableData> custom({
+    Expression<int>? id,
+    Expression<String>? pathPrefix,
+    Expression<String>? namePattern,
+    Expression<String>? resolution,
+    Expression<int>? position,
+    Expression<DateTime>? createdAt,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (pathPrefix != null) 'path_prefix': pathPrefix,
+      if (namePattern != null) 'name_pattern': namePattern,
+      if (resolution != null) 'resolution': resolution,
+      if (position != null) 'position': position,
+      if (createdAt != null) 'created_at': createdAt,
+    });
+  }
+
+  ConflictRulesTableCompanion copyWith({
+    Value<int>? id,
+    Value<String?>? pathPrefix,
+    Value<String?>? namePattern,
+    Value<String>? resolution,
+    Value<int>? position,
+    Value<DateTime>? createdAt,
+  }) {
+    return ConflictRulesTableCompanion(
+      id: id ?? this.id,
+      pathPrefix: pathPrefix ?? this.pathPrefix,
+      namePattern: namePattern ?? this.namePattern,
+      resolution: resolution ?? this.resolution,
+      position: position ?? this.position,
+      createdAt: createdAt ?? this.createdAt,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<int>(id.value);
+    }
+    if (pathPrefix.present) {
+      map['path_prefix'] = Variable<String>(pathPrefix.value);
+    }
+    if (namePattern.present) {
+      map['name_pattern'] = Variable<String>(namePattern.value);
+    }
+    if (resolution.present) {
+      map['resolution'] = Variable<String>(resolution.value);
+    }
+    if (position.present) {
+      map['position'] = Variable<int>(position.value);
+    }
+    if (createdAt.present) {
+      map['created_at'] = Variable<DateTime>(createdAt.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ConflictRulesTableCompanion(')
+          ..write('id: $id, ')
+          ..write('pathPrefix: $pathPrefix, ')
+          ..write('namePattern: $namePattern, ')
+          ..write('resolution: $resolution, ')
+          ..write('position: $position, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $CachedListingsTableTable extends CachedListingsTable
+    with TableInfo<$CachedListingsTableTable, CachedListingsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $CachedListingsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _folderIdMeta = const VerificationMeta(
+    'folderId',
+  );
+  @override
+  late final GeneratedColumn<String> folderId = GeneratedColumn<String>(
+    'folder_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _listedAtMeta = const VerificationMeta(
+    'listedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> listedAt = GeneratedColumn<DateTime>(
+    'listed_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [folderId, listedAt];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'cached_listings';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<CachedListingsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('folder_id')) {
+      context.handle(
+        _folderIdMeta,
+        folderId.isAcceptableOrUnknown(data['folder_id']!, _folderIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_folderIdMeta);
+    }
+    if (data.containsKey('listed_at')) {
+      context.handle(
+        _listedAtMeta,
+        listedAt.isAcceptableOrUnknown(data['listed_at']!, _listedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_listedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {folderId};
+  @override
+  CachedListingsTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return CachedListingsTableData(
+      folderId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}folder_id'],
+      )!,
+      listedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}listed_at'],
+      )!,
+    );
+  }
+
+  @override
+  $CachedListingsTableTable createAlias(String alias) {
+    return $CachedListingsTableTable(attachedDatabase, alias);
+  }
+}
+
+class CachedListingsTableData extends DataClass
+    implements Insertable<CachedListingsTableData> {
+  final String folderId;
+  final DateTime listedAt;
+  const CachedListingsTableData({
+    required this.folderId,
+    required this.listedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['folder_id'] = Variable<String>(folderId);
+    map['listed_at'] = Variable<DateTime>(listedAt);
+    return map;
+  }
+
+  CachedListingsTableCompanion toCompanion(bool nullToAbsent) {
+    return CachedListingsTableCompanion(
+      folderId: Value(folderId),
+      listedAt: Value(listedAt),
+    );
+  }
+
+  factory CachedListingsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return CachedListingsTableData(
+      folderId: serializer.fromJson<String>(json['folderId']),
+      listedAt: serializer.fromJson<DateTime>(json['listedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'folderId': serializer.toJson<String>(folderId),
+      'listedAt': serializer.toJson<DateTime>(listedAt),
+    };
+  }
+
+  CachedListingsTableData copyWith({
+    String? folderId,
+    DateTime? listedAt,
+  }) => CachedListingsTableData(
+    folderId: folderId ?? this.folderId,
+    listedAt: listedAt ?? this.listedAt,
+  );
+  CachedListingsTableData copyWithCompanion(CachedListingsTableCompanion data) {
+    return CachedListingsTableData(
+      folderId: data.folderId.present ? data.folderId.value : this.folderId,
+      listedAt: data.listedAt.present ? data.listedAt.value : this.listedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('CachedListingsTableData(')
+          ..write('folderId: $folderId, ')
+          ..write('listedAt: $listedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(folderId, listedAt);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is CachedListingsTableData &&
+          other.folderId == this.folderId &&
+          other.listedAt == this.listedAt);
+}
+
+class CachedListingsTableCompanion
+    extends UpdateCompanion<CachedListingsTableData> {
+  final Value<String> folderId;
+  final Value<DateTime> listedAt;
+  final Value<int> rowid;
+  const CachedListingsTableCompanion({
+    this.folderId = const Value.absent(),
+    this.listedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  CachedListingsTableCompanion.insert({
+    required String folderId,
+    required DateTime listedAt,
+    this.rowid = const Value.absent(),
+  }) : folderId = Value(folderId),
+       listedAt = Value(listedAt);
+  static Insertable<CachedListingsTableData> custom({
+    Expression<String>? folderId,
+    Expression<DateTime>? listedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (folderId != null) 'folder_id': folderId,
+      if (listedAt != null) 'listed_at': listedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  CachedListingsTableCompanion copyWith({
+    Value<String>? folderId,
+    Value<DateTime>? listedAt,
+    Value<int>? rowid,
+  }) {
+    return CachedListingsTableCompanion(
+      folderId: folderId ?? this.folderId,
+      listedAt: listedAt ?? this.listedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (folderId.present) {
+      map['folder_id'] = Variable<String>(folderId.value);
+    }
+    if (listedAt.present) {
+      map['listed_at'] = Variable<DateTime>(listedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('CachedListingsTableCompanion(')
+          ..write('folderId: $folderId, ')
+          ..write('listedAt: $listedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $SyncExclusionsTableTable extends SyncExclusionsTable
+    with TableInfo<$SyncExclusionsTableTable, SyncExclusionsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $SyncExclusionsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _folderIdMeta = const VerificationMeta(
+    'folderId',
+  );
+  @override
+  late final GeneratedColumn<String> folderId = GeneratedColumn<String>(
+    'folder_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _pathMeta = const VerificationMeta('path');
+  @override
+  late final GeneratedColumn<String> path = GeneratedColumn<String>(
+    'path',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _createdAtMeta = const VerificationMeta(
+    'createdAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> createdAt = GeneratedColumn<DateTime>(
+    'created_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [folderId, path, createdAt];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'sync_exclusions';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<SyncExclusionsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('folder_id')) {
+      context.handle(
+        _folderIdMeta,
+        folderId.isAcceptableOrUnknown(data['folder_id']!, _folderIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_folderIdMeta);
+    }
+    if (data.containsKey('path')) {
+      context.handle(
+        _pathMeta,
+        path.isAcceptableOrUnknown(data['path']!, _pathMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_pathMeta);
+    }
+    if (data.containsKey('created_at')) {
+      context.handle(
+        _createdAtMeta,
+        createdAt.isAcceptableOrUnknown(data['created_at']!, _createdAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_createdAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {folderId};
+  @override
+  SyncExclusionsTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return SyncExclusionsTableData(
+      folderId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}folder_id'],
+      )!,
+      path: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}path'],
+      )!,
+      createdAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}created_at'],
+      )!,
+    );
+  }
+
+  @override
+  $SyncExclusionsTableTable createAlias(String alias) {
+    return $SyncExclusionsTableTable(attachedDatabase, alias);
+  }
+}
+
+class SyncExclusionsTableData extends DataClass
+    implements Insertable<SyncExclusionsTableData> {
+  final String folderId;
+  final String path;
+  final DateTime createdAt;
+  const SyncExclusionsTableData({
+    required this.folderId,
+    required this.path,
+    required this.createdAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['folder_id'] = Variable<String>(folderId);
+    map['path'] = Variable<String>(path);
+    map['created_at'] = Variable<DateTime>(createdAt);
+    return map;
+  }
+
+  SyncExclusionsTableCompanion toCompanion(bool nullToAbsent) {
+    return SyncExclusionsTableCompanion(
+      folderId: Value(folderId),
+      path: Value(path),
+      createdAt: Value(createdAt),
+    );
+  }
+
+  factory SyncExclusionsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return SyncExclusionsTableData(
+      folderId: serializer.fromJson<String>(json['folderId']),
+      path: serializer.fromJson<String>(json['path']),
+      createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'folderId': serializer.toJson<String>(folderId),
+      'path': serializer.toJson<String>(path),
+      'createdAt': serializer.toJson<DateTime>(createdAt),
+    };
+  }
+
+  SyncExclusionsTableData copyWith({
+    String? folderId,
+    String? path,
+    DateTime? createdAt,
+  }) => SyncExclusionsTableData(
+    folderId: folderId ?? this.folderId,
+    path: path ?? this.path,
+    createdAt: createdAt ?? this.createdAt,
+  );
+  SyncExclusionsTableData copyWithCompanion(SyncExclusionsTableCompanion data) {
+    return SyncExclusionsTableData(
+      folderId: data.folderId.present ? data.folderId.value : this.folderId,
+      path: data.path.present ? data.path.value : this.path,
+      createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('SyncExclusionsTableData(')
+          ..write('folderId: $folderId, ')
+          ..write('path: $path, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(folderId, path, createdAt);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is SyncExclusionsTableData &&
+          other.folderId == this.folderId &&
+          other.path == this.path &&
+          other.createdAt == this.createdAt);
+}
+
+class SyncExclusionsTableCompanion
+    extends UpdateCompanion<SyncExclusionsTableData> {
+  final Value<String> folderId;
+  final Value<String> path;
+  final Value<DateTime> createdAt;
+  final Value<int> rowid;
+  const SyncExclusionsTableCompanion({
+    this.folderId = const Value.absent(),
+    this.path = const Value.absent(),
+    this.createdAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  SyncExclusionsTableCompanion.insert({
+    required String folderId,
+    required String path,
+    required DateTime createdAt,
+    this.rowid = const Value.absent(),
+  }) : folderId = Value(folderId),
+       path = Value(path),
+       createdAt = Value(createdAt);
+  static Insertable<SyncExclusionsTableData> custom({
+    Expression<String>? folderId,
+    Expression<String>? path,
+    Expression<DateTime>? createdAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (folderId != null) 'folder_id': folderId,
+      if (path != null) 'path': path,
+      if (createdAt != null) 'created_at': createdAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  SyncExclusionsTableCompanion copyWith({
+    Value<String>? folderId,
+    Value<String>? path,
+    Value<DateTime>? createdAt,
+    Value<int>? rowid,
+  }) {
+    return SyncExclusionsTableCompanion(
+      folderId: folderId ?? this.folderId,
+      path: path ?? this.path,
+      createdAt: createdAt ?? this.createdAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (folderId.present) {
+      map['folder_id'] = Variable<String>(folderId.value);
+    }
+    if (path.present) {
+      map['path'] = Variable<String>(path.value);
+    }
+    if (createdAt.present) {
+      map['created_at'] = Variable<DateTime>(createdAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('SyncExclusionsTableCompanion(')
+          ..write('folderId: $folderId, ')
+          ..write('path: $path, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $FolderSyncModesTableTable extends FolderSyncModesTable
+    with TableInfo<$FolderSyncModesTableTable, FolderSyncModesTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $FolderSyncModesTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _folderIdMeta = const VerificationMeta(
+    'folderId',
+  );
+  @override
+  late final GeneratedColumn<String> folderId = GeneratedColumn<String>(
+    'folder_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _pathMeta = const VerificationMeta('path');
+  @override
+  late final GeneratedColumn<String> path = GeneratedColumn<String>(
+    'path',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _directionMeta = const VerificationMeta(
+    'direction',
+  );
+  @override
+  late final GeneratedColumn<String> direction = GeneratedColumn<String>(
+    'direction',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _createdAtMeta = const VerificationMeta(
+    'createdAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> createdAt = GeneratedColumn<DateTime>(
+    'created_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [folderId, path, direction, createdAt];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'folder_sync_modes';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<FolderSyncModesTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('folder_id')) {
+      context.handle(
+        _folderIdMeta,
+        folderId.isAcceptableOrUnknown(data['folder_id']!, _folderIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_folderIdMeta);
+    }
+    if (data.containsKey('path')) {
+      context.handle(
+        _pathMeta,
+        path.isAcceptableOrUnknown(data['path']!, _pathMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_pathMeta);
+    }
+    if (data.containsKey('direction')) {
+      context.handle(
+        _directionMeta,
+        direction.isAcceptableOrUnknown(data['direction']!, _directionMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_directionMeta);
+    }
+    if (data.containsKey('created_at')) {
+      context.handle(
+        _createdAtMeta,
+        createdAt.isAcceptableOrUnknown(data['created_at']!, _createdAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_createdAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {folderId};
+  @override
+  FolderSyncModesTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return FolderSyncModesTableData(
+      folderId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}folder_id'],
+      )!,
+      path: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}path'],
+      )!,
+      direction: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}direction'],
+      )!,
+      createdAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}created_at'],
+      )!,
+    );
+  }
+
+  @override
+  $FolderSyncModesTableTable createAlias(String alias) {
+    return $FolderSyncModesTableTable(attachedDatabase, alias);
+  }
+}
+
+class FolderSyncModesTableData extends DataClass
+    implements Insertable<FolderSyncModesTableData> {
+  final String folderId;
+  final String path;
+  final String direction;
+  final DateTime createdAt;
+  const FolderSyncModesTableData({
+    required this.folderId,
+    required this.path,
+    required this.direction,
+    required this.createdAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['folder_id'] = Variable<String>(folderId);
+    map['path'] = Variable<String>(path);
+    map['direction'] = Variable<String>(direction);
+    map['created_at'] = Variable<DateTime>(createdAt);
+    return map;
+  }
+
+  FolderSyncModesTableCompanion toCompanion(bool nullToAbsent) {
+    return FolderSyncModesTableCompanion(
+      folderId: Value(folderId),
+      path: Value(path),
+      direction: Value(direction),
+      createdAt: Value(createdAt),
+    );
+  }
+
+  factory FolderSyncModesTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return FolderSyncModesTableData(
+      folderId: serializer.fromJson<String>(json['folderId']),
+      path: serializer.fromJson<String>(json['path']),
+      direction: serializer.fromJson<String>(json['direction']),
+      createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'folderId': serializer.toJson<String>(folderId),
+      'path': serializer.toJson<String>(path),
+      'direction': serializer.toJson<String>(direction),
+      'createdAt': serializer.toJson<DateTime>(createdAt),
+    };
+  }
+
+  FolderSyncModesTableData copyWith({
+    String? folderId,
+    String? path,
+    String? direction,
+    DateTime? createdAt,
+  }) => FolderSyncModesTableData(
+    folderId: folderId ?? this.folderId,
+    path: path ?? this.path,
+    direction: direction ?? this.direction,
+    createdAt: createdAt ?? this.createdAt,
+  );
+  FolderSyncModesTableData copyWithCompanion(
+    FolderSyncModesTableCompanion data,
+  ) {
+    return FolderSyncModesTableData(
+      folderId: data.folderId.present ? data.folderId.value : this.folderId,
+      path: data.path.present ? data.path.value : this.path,
+      direction: data.direction.present ? data.direction.value : this.direction,
+      createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FolderSyncModesTableData(')
+          ..write('folderId: $folderId, ')
+          ..write('path: $path, ')
+          ..write('direction: $direction, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(folderId, path, direction, createdAt);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is FolderSyncModesTableData &&
+          other.folderId == this.folderId &&
+          other.path == this.path &&
+          other.direction == this.direction &&
+          other.createdAt == this.createdAt);
+}
+
+class FolderSyncModesTableCompanion
+    extends UpdateCompanion<FolderSyncModesTableData> {
+  final Value<String> folderId;
+  final Value<String> path;
+  final Value<String> direction;
+  final Value<DateTime> createdAt;
+  final Value<int> rowid;
+  const FolderSyncModesTableCompanion({
+    this.folderId = const Value.absent(),
+    this.path = const Value.absent(),
+    this.direction = const Value.absent(),
+    this.createdAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  FolderSyncModesTableCompanion.insert({
+    required String folderId,
+    required String path,
+    required String direction,
+    required DateTime createdAt,
+    this.rowid = const Value.absent(),
+  }) : folderId = Value(folderId),
+       path = Value(path),
+       direction = Value(direction),
+       createdAt = Value(createdAt);
+  static Insertable<FolderSyncModesTableData> custom({
+    Expression<String>? folderId,
+    Expression<String>? path,
+    Expression<String>? direction,
+    Expression<DateTime>? createdAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (folderId != null) 'folder_id': folderId,
+      if (path != null) 'path': path,
+      if (direction != null) 'direction': direction,
+      if (createdAt != null) 'created_at': createdAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  FolderSyncModesTableCompanion copyWith({
+    Value<String>? folderId,
+    Value<String>? path,
+    Value<String>? direction,
+    Value<DateTime>? createdAt,
+    Value<int>? rowid,
+  }) {
+    return FolderSyncModesTableCompanion(
+      folderId: folderId ?? this.folderId,
+      path: path ?? this.path,
+      direction: direction ?? this.direction,
+      createdAt: createdAt ?? this.createdAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (folderId.present) {
+      map['folder_id'] = Variable<String>(folderId.value);
+    }
+    if (path.present) {
+      map['path'] = Variable<String>(path.value);
+    }
+    if (direction.present) {
+      map['direction'] = Variable<String>(direction.value);
+    }
+    if (createdAt.present) {
+      map['created_at'] = Variable<DateTime>(createdAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FolderSyncModesTableCompanion(')
+          ..write('folderId: $folderId, ')
+          ..write('path: $path, ')
+          ..write('direction: $direction, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $ConflictCopiesTableTable extends ConflictCopiesTable
+    with TableInfo<$ConflictCopiesTableTable, ConflictCopiesTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $ConflictCopiesTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<int> id = GeneratedColumn<int>(
+    'id',
+    aliasedName,
+    false,
+    hasAutoIncrement: true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'PRIMARY KEY AUTOINCREMENT',
+    ),
+  );
+  static const VerificationMeta _originalIdMeta = const VerificationMeta(
+    'originalId',
+  );
+  @override
+  late final GeneratedColumn<String> originalId = GeneratedColumn<String>(
+    'original_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _copyIdMeta = const VerificationMeta('copyId');
+  @override
+  late final GeneratedColumn<String> copyId = GeneratedColumn<String>(
+    'copy_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _copyNameMeta = const VerificationMeta(
+    'copyName',
+  );
+  @override
+  late final GeneratedColumn<String> copyName = GeneratedColumn<String>(
+    'copy_name',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _conflictIdMeta = const VerificationMeta(
+    'conflictId',
+  );
+  @override
+  late final GeneratedColumn<int> conflictId = GeneratedColumn<int>(
+    'conflict_id',
+    aliasedName,
+    true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _createdAtMeta = const VerificationMeta(
+    'createdAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> createdAt = GeneratedColumn<DateTime>(
+    'created_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _dismissedAtMeta = const VerificationMeta(
+    'dismissedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> dismissedAt = GeneratedColumn<DateTime>(
+    'dismissed_at',
+    aliasedName,
+    true,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: false,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    originalId,
+    copyId,
+    copyName,
+    conflictId,
+    createdAt,
+    dismissedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'conflict_copies';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<ConflictCopiesTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    }
+    if (data.containsKey('original_id')) {
+      context.handle(
+        _originalIdMeta,
+        originalId.isAcceptableOrUnknown(data['original_id']!, _originalIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_originalIdMeta);
+    }
+    if (data.containsKey('copy_id')) {
+      context.handle(
+        _copyIdMeta,
+        copyId.isAcceptableOrUnknown(data['copy_id']!, _copyIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_copyIdMeta);
+    }
+    if (data.containsKey('copy_name')) {
+      context.handle(
+        _copyNameMeta,
+        copyName.isAcceptableOrUnknown(data['copy_name']!, _copyNameMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_copyNameMeta);
+    }
+    if (data.containsKey('conflict_id')) {
+      context.handle(
+        _conflictIdMeta,
+        conflictId.isAcceptableOrUnknown(data['conflict_id']!, _conflictIdMeta),
+      );
+    }
+    if (data.containsKey('created_at')) {
+      context.handle(
+        _createdAtMeta,
+        createdAt.isAcceptableOrUnknown(data['created_at']!, _createdAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_createdAtMeta);
+    }
+    if (data.containsKey('dismissed_at')) {
+      context.handle(
+        _dismissedAtMeta,
+        dismissedAt.isAcceptableOrUnknown(
+          data['dismissed_at']!,
+          _dismissedAtMeta,
+        ),
+      );
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  ConflictCopiesTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return ConflictCopiesTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}id'],
+      )!,
+      originalId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}original_id'],
+      )!,
+      copyId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}copy_id'],
+      )!,
+      copyName: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}copy_name'],
+      )!,
+      conflictId: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}conflict_id'],
+      ),
+      createdAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}created_at'],
+      )!,
+      dismissedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}dismissed_at'],
+      ),
+    );
+  }
+
+  @override
+  $ConflictCopiesTableTable createAlias(String alias) {
+    return $ConflictCopiesTableTable(attachedDatabase, alias);
+  }
+}
+
+class ConflictCopiesTableData extends DataClass
+    implements Insertable<ConflictCopiesTableData> {
+  final int id;
+  final String originalId;
+  final String copyId;
+  final String copyName;
+  final int? conflictId;
+  final DateTime createdAt;
+  final DateTime? dismissedAt;
+  const ConflictCopiesTableData({
+    required this.id,
+    required this.originalId,
+    required this.copyId,
+    required this.copyName,
+    this.conflictId,
+    required this.createdAt,
+    this.dismissedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<int>(id);
+    map['original_id'] = Variable<String>(originalId);
+    map['copy_id'] = Variable<String>(copyId);
+    map['copy_name'] = Variable<String>(copyName);
+    if (!nullToAbsent || conflictId != null) {
+      map['conflict_id'] = Variable<int>(conflictId);
+    }
+    map['created_at'] = Variable<DateTime>(createdAt);
+    if (!nullToAbsent || dismissedAt != null) {
+      map['dismissed_at'] = Variable<DateTime>(dismissedAt);
+    }
+    return map;
+  }
+
+  ConflictCopiesTableCompanion toCompanion(bool nullToAbsent) {
+    return ConflictCopiesTableCompanion(
+      id: Value(id),
+      originalId: Value(originalId),
+      copyId: Value(copyId),
+      copyName: Value(copyName),
+      conflictId: conflictId == null && nullToAbsent
+          ? const Value.absent()
+          : Value(conflictId),
+      createdAt: Value(createdAt),
+      dismissedAt: dismissedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(dismissedAt),
+    );
+  }
+
+  factory ConflictCopiesTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return ConflictCopiesTableData(
+      id: serializer.fromJson<int>(json['id']),
+      originalId: serializer.fromJson<String>(json['originalId']),
+      copyId: serializer.fromJson<String>(json['copyId']),
+      copyName: serializer.fromJson<String>(json['copyName']),
+      conflictId: serializer.fromJson<int?>(json['conflictId']),
+      createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+      dismissedAt: serializer.fromJson<DateTime?>(json['dismissedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<int>(id),
+      'originalId': serializer.toJson<String>(originalId),
+      'copyId': serializer.toJson<String>(copyId),
+      'copyName': serializer.toJson<String>(copyName),
+      'conflictId': serializer.toJson<int?>(conflictId),
+      'createdAt': serializer.toJson<DateTime>(createdAt),
+      'dismissedAt': serializer.toJson<DateTime?>(dismissedAt),
+    };
+  }
+
+  ConflictCopiesTableData copyWith({
+    int? id,
+    String? originalId,
+    String? copyId,
+    String? copyName,
+    Value<int?> conflictId = const Value.absent(),
+    DateTime? createdAt,
+    Value<DateTime?> dismissedAt = const Value.absent(),
+  }) => ConflictCopiesTableData(
+    id: id ?? this.id,
+    originalId: originalId ?? this.originalId,
+    copyId: copyId ?? this.copyId,
+    copyName: copyName ?? this.copyName,
+    conflictId: conflictId.present ? conflictId.value : this.conflictId,
+    createdAt: createdAt ?? this.createdAt,
+    dismissedAt: dismissedAt.present ? dismissedAt.value : this.dismissedAt,
+  );
+  ConflictCopiesTableData copyWithCompanion(ConflictCopiesTableCompanion data) {
+    return ConflictCopiesTableData(
+      id: data.id.present ? data.id.value : this.id,
+      originalId: data.originalId.present
+          ? data.originalId.value
+          : this.originalId,
+      copyId: data.copyId.present ? data.copyId.value : this.copyId,
+      copyName: data.copyName.present ? data.copyName.value : this.copyName,
+      conflictId: data.conflictId.present
+          ? data.conflictId.value
+          : this.conflictId,
+      createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+      dismissedAt: data.dismissedAt.present
+          ? data.dismissedAt.value
+          : this.dismissedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ConflictCopiesTableData(')
+          ..write('id: $id, ')
+          ..write('originalId: $originalId, ')
+          ..write('copyId: $copyId, ')
+          ..write('copyName: $copyName, ')
+          ..write('conflictId: $conflictId, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('dismissedAt: $dismissedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    originalId,
+    copyId,
+    copyName,
+    conflictId,
+    createdAt,
+    dismissedAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is ConflictCopiesTableData &&
+          other.id == this.id &&
+          other.originalId == this.originalId &&
+          other.copyId == this.copyId &&
+          other.copyName == this.copyName &&
+          other.conflictId == this.conflictId &&
+          other.createdAt == this.createdAt &&
+          other.dismissedAt == this.dismissedAt);
+}
+
+class ConflictCopiesTableCompanion
+    extends UpdateCompanion<ConflictCopiesTableData> {
+  final Value<int> id;
+  final Value<String> originalId;
+  final Value<String> copyId;
+  final Value<String> copyName;
+  final Value<int?> conflictId;
+  final Value<DateTime> createdAt;
+  final Value<DateTime?> dismissedAt;
+  const ConflictCopiesTableCompanion({
+    this.id = const Value.absent(),
+    this.originalId = const Value.absent(),
+    this.copyId = const Value.absent(),
+    this.copyName = const Value.absent(),
+    this.conflictId = const Value.absent(),
+    this.createdAt = const Value.absent(),
+    this.dismissedAt = const Value.absent(),
+  });
+  ConflictCopiesTableCompanion.insert({
+    this.id = const Value.absent(),
+    required String originalId,
+    required String copyId,
+    required String copyName,
+    this.conflictId = const Value.absent(),
+    required DateTime createdAt,
+    this.dismissedAt = const Value.absent(),
+  }) : originalId = Value(originalId),
+       copyId = Value(copyId),
+       copyName = Value(copyName),
+       createdAt = Value(createdAt);
+  static Insertable<ConflictCopiesTableData> custom({
+    Expression<int>? id,
+    Expression<String>? originalId,
+    Expression<String>? copyId,
+    Expression<String>? copyName,
+    Expression<int>? conflictId,
+    Expression<DateTime>? createdAt,
+    Expression<DateTime>? dismissedAt,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (originalId != null) 'original_id': originalId,
+      if (copyId != null) 'copy_id': copyId,
+      if (copyName != null) 'copy_name': copyName,
+      if (conflictId != null) 'conflict_id': conflictId,
+      if (createdAt != null) 'created_at': createdAt,
+      if (dismissedAt != null) 'dismissed_at': dismissedAt,
+    });
+  }
+
+  ConflictCopiesTableCompanion copyWith({
+    Value<int>? id,
+    Value<String>? originalId,
+    Value<String>? copyId,
+    Value<String>? copyName,
+    Value<int?>? conflictId,
+    Value<DateTime>? createdAt,
+    Value<DateTime?>? dismissedAt,
+  }) {
+    return ConflictCopiesTableCompanion(
+      id: id ?? this.id,
+      originalId: originalId ?? this.originalId,
+      copyId: copyId ?? this.copyId,
+      copyName: copyName ?? this.copyName,
+      conflictId: conflictId ?? this.conflictId,
+      createdAt: createdAt ?? this.createdAt,
+      dismissedAt: dismissedAt ?? this.dismissedAt,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<int>(id.value);
+    }
+    if (originalId.present) {
+      map['original_id'] = Variable<String>(originalId.value);
+    }
+    if (copyId.present) {
+      map['copy_id'] = Variable<String>(copyId.value);
+    }
+    if (copyName.present) {
+      map['copy_name'] = Variable<String>(copyName.value);
+    }
+    if (conflictId.present) {
+      map['conflict_id'] = Variable<int>(conflictId.value);
+    }
+    if (createdAt.present) {
+      map['created_at'] = Variable<DateTime>(createdAt.value);
+    }
+    if (dismissedAt.present) {
+      map['dismissed_at'] = Variable<DateTime>(dismissedAt.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ConflictCopiesTableCompanion(')
+          ..write('id: $id, ')
+          ..write('originalId: $originalId, ')
+          ..write('copyId: $copyId, ')
+          ..write('copyName: $copyName, ')
+          ..write('conflictId: $conflictId, ')
+          ..write('createdAt: $createdAt, ')
+          ..write('dismissedAt: $dismissedAt')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $InboxRulesTableTable extends InboxRulesTable
+    with TableInfo<$InboxRulesTableTable, InboxRulesTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $InboxRulesTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<int> id = GeneratedColumn<int>(
+    'id',
+    aliasedName,
+    false,
+    hasAutoIncrement: true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'PRIMARY KEY AUTOINCREMENT',
+    ),
+  );
+  static const VerificationMeta _namePatternMeta = const VerificationMeta(
+    'namePattern',
+  );
+  @override
+  late final GeneratedColumn<String> namePattern = GeneratedColumn<String>(
+    'name_pattern',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _destinationFolderIdMeta =
+      const VerificationMeta('destinationFolderId');
+  @override
+  late final GeneratedColumn<String> destinationFolderId =
+      GeneratedColumn<String>(
+        'destination_folder_id',
+        aliasedName,
+        false,
+        type: DriftSqlType.string,
+        requiredDuringInsert: true,
+      );
+  static const VerificationMeta _destinationPathMeta = const VerificationMeta(
+    'destinationPath',
+  );
+  @override
+  late final GeneratedColumn<String> destinationPath = GeneratedColumn<String>(
+    'destination_path',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _positionMeta = const VerificationMeta(
+    'position',
+  );
+  @override
+  late final GeneratedColumn<int> position = GeneratedColumn<int>(
+    'position',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _createdAtMeta = const VerificationMeta(
+    'createdAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> createdAt = GeneratedColumn<DateTime>(
+    'created_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    namePattern,
+    destinationFolderId,
+    destinationPath,
+    position,
+    createdAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'inbox_rules';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<InboxRulesTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    }
+    if (data.containsKey('name_pattern')) {
+      context.handle(
+        _namePatternMeta,
+        namePattern.isAcceptableOrUnknown(
+          data['name_pattern']!,
+          _namePatternMeta,
+        ),
+      );
+    }
+    if (data.containsKey('destination_folder_id')) {
+      context.handle(
+        _destinationFolderIdMeta,
+        destinationFolderId.isAcceptableOrUnknown(
+          data['destination_folder_id']!,
+          _destinationFolderIdMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_destinationFolderIdMeta);
+    }
+    if (data.containsKey('destination_path')) {
+      context.handle(
+        _destinationPathMeta,
+        destinationPath.isAcceptableOrUnknown(
+          data['destination_path']!,
+          _destinationPathMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_destinationPathMeta);
+    }
+    if (data.containsKey('position')) {
+      context.handle(
+        _positionMeta,
+        position.isAcceptableOrUnknown(data['position']!, _positionMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_positionMeta);
+    }
+    if (data.containsKey('created_at')) {
+      context.handle(
+        _createdAtMeta,
+        createdAt.isAcceptableOrUnknown(data['created_at']!, _createdAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_createdAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  InboxRulesTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return InboxRulesTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}id'],
+      )!,
+      namePattern: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}name_pattern'],
+      ),
+      destinationFolderId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}destination_folder_id'],
+      )!,
+      destinationPath: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}destination_path'],
+      )!,
+      position: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}position'],
+      )!,
+      createdAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}created_at'],
+      )!,
+    );
+  }
+
+  @override
+  $InboxRulesTableTable createAlias(String alias) {
+    return $InboxRulesTableTable(attachedDatabase, alias);
+  }
+}
+
+class InboxRulesTableData extends DataClass
+    implements Insertable<InboxRulesTableData> {
+  final int id;
+  final String? namePattern;
+  final String destinationFolderId;
+  final String destinationPath;
+  final int position;
+  final DateTime createdAt;
+  const InboxRulesTableData({
+    required this.id,
+    this.namePattern,
+    required this.destinationFolderId,
+    required this.destinationPath,
+    required this.position,
+    required this.createdAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<int>(id);
+    if (!nullToAbsent || namePattern != null) {
+      map['name_pattern'] = Variable<String>(namePattern);
+    }
+    map['destination_folder_id'] = Variable<String>(destinationFolderId);
+    map['destination_path'] = Variable<String>(destinationPath);
+    map['position'] = Variable<int>(position);
+    map['created_at'] = Variable<DateTime>(createdAt);
+    return map;
+  }
+
+  InboxRulesTableCompanion toCompanion(bool nullToAbsent) {
+    return InboxRulesTableCompanion(
+      id: Value(id),
+      namePattern: namePattern == null && nullToAbsent
+          ? const Value.absent()
+          : Value(namePattern),
+      destinationFolderId: Value(destinationFolderId),
+      destinationPath: Value(destinationPath),
+      position: Value(position),
+      createdAt: Value(createdAt),
+    );
+  }
+
+  factory InboxRulesTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return InboxRulesTableData(
+      id: serializer.fromJson<int>(json['id']),
+      namePattern: serializer.fromJson<String?>(json['namePattern']),
+      destinationFolderId: serializer.fromJson<String>(
+        json['destinationFolderId'],
+      ),
+      destinationPath: serializer.fromJson<String>(json['destinationPath']),
+      position: serializer.fromJson<int>(json['position']),
+      createdAt: serializer.fromJson<DateTime>(json['createdAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<int>(id),
+      'namePattern': serializer.toJson<String?>(namePattern),
+      'destinationFolderId': serializer.toJson<String>(destinationFolderId),
+      'destinationPath': serializer.toJson<String>(destinationPath),
+      'position': serializer.toJson<int>(position),
+      'createdAt': serializer.toJson<DateTime>(createdAt),
+    };
+  }
+
+  InboxRulesTableData copyWith({
+    int? id,
+    Value<String?> namePattern = const Value.absent(),
+    String? destinationFolderId,
+    String? destinationPath,
+    int? position,
+    DateTime? createdAt,
+  }) => InboxRulesTableData(
+    id: id ?? this.id,
+    namePattern: namePattern.present ? namePattern.value : this.namePattern,
+    destinationFolderId: destinationFolderId ?? this.destinationFolderId,
+    destinationPath: destinationPath ?? this.destinationPath,
+    position: position ?? this.position,
+    createdAt: createdAt ?? this.createdAt,
+  );
+  InboxRulesTableData copyWithCompanion(InboxRulesTableCompanion data) {
+    return InboxRulesTableData(
+      id: data.id.present ? data.id.value : this.id,
+      namePattern: data.namePattern.present
+          ? data.namePattern.value
+          : this.namePattern,
+      destinationFolderId: data.destinationFolderId.present
+          ? data.destinationFolderId.value
+          : this.destinationFolderId,
+      destinationPath: data.destinationPath.present
+          ? data.destinationPath.value
+          : this.destinationPath,
+      position: data.position.present ? data.position.value : this.position,
+      createdAt: data.createdAt.present ? data.createdAt.value : this.createdAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('InboxRulesTableData(')
+          ..write('id: $id, ')
+          ..write('namePattern: $namePattern, ')
+          ..write('destinationFolderId: $destinationFolderId, ')
+          ..write('destinationPath: $destinationPath, ')
+          ..write('position: $position, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    namePattern,
+    destinationFolderId,
+    destinationPath,
+    position,
+    createdAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is InboxRulesTableData &&
+          other.id == this.id &&
+          other.namePattern == this.namePattern &&
+          other.destinationFolderId == this.destinationFolderId &&
+          other.destinationPath == this.destinationPath &&
+          other.position == this.position &&
+          other.createdAt == this.createdAt);
+}
+
+class InboxRulesTableCompanion extends UpdateCompanion<InboxRulesTableData> {
+  final Value<int> id;
+  final Value<String?> namePattern;
+  final Value<String> destinationFolderId;
+  final Value<String> destinationPath;
+  final Value<int> position;
+  final Value<DateTime> createdAt;
+  const InboxRulesTableCompanion({
+    this.id = const Value.absent(),
+    this.namePattern = const Value.absent(),
+    this.destinationFolderId = const Value.absent(),
+    this.destinationPath = const Value.absent(),
+    this.position = const Value.absent(),
+    this.createdAt = const Value.absent(),
+  });
+  InboxRulesTableCompanion.insert({
+    this.id = const Value.absent(),
+    this.namePattern = const Value.absent(),
+    required String destinationFolderId,
+    required String destinationPath,
+    required int position,
+    required DateTime createdAt,
+  }) : destinationFolderId = Value(destinationFolderId),
+       destinationPath = Value(destinationPath),
+       position = Value(position),
+       createdAt = Value(createdAt);
+  static Insertable<InboxRulesTableData> custom({
+    Expression<int>? id,
+    Expression<String>? namePattern,
+    Expression<String>? destinationFolderId,
+    Expression<String>? destinationPath,
+    Expression<int>? position,
+    Expression<DateTime>? createdAt,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (namePattern != null) 'name_pattern': namePattern,
+      if (destinationFolderId != null)
+        'destination_folder_id': destinationFolderId,
+      if (destinationPath != null) 'destination_path': destinationPath,
+      if (position != null) 'position': position,
+      if (createdAt != null) 'created_at': createdAt,
+    });
+  }
+
+  InboxRulesTableCompanion copyWith({
+    Value<int>? id,
+    Value<String?>? namePattern,
+    Value<String>? destinationFolderId,
+    Value<String>? destinationPath,
+    Value<int>? position,
+    Value<DateTime>? createdAt,
+  }) {
+    return InboxRulesTableCompanion(
+      id: id ?? this.id,
+      namePattern: namePattern ?? this.namePattern,
+      destinationFolderId: destinationFolderId ?? this.destinationFolderId,
+      destinationPath: destinationPath ?? this.destinationPath,
+      position: position ?? this.position,
+      createdAt: createdAt ?? this.createdAt,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<int>(id.value);
+    }
+    if (namePattern.present) {
+      map['name_pattern'] = Variable<String>(namePattern.value);
+    }
+    if (destinationFolderId.present) {
+      map['destination_folder_id'] = Variable<String>(
+        destinationFolderId.value,
+      );
+    }
+    if (destinationPath.present) {
+      map['destination_path'] = Variable<String>(destinationPath.value);
+    }
+    if (position.present) {
+      map['position'] = Variable<int>(position.value);
+    }
+    if (createdAt.present) {
+      map['created_at'] = Variable<DateTime>(createdAt.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('InboxRulesTableCompanion(')
+          ..write('id: $id, ')
+          ..write('namePattern: $namePattern, ')
+          ..write('destinationFolderId: $destinationFolderId, ')
+          ..write('destinationPath: $destinationPath, ')
+          ..write('position: $position, ')
+          ..write('createdAt: $createdAt')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $InboxItemsTableTable extends InboxItemsTable
+    with TableInfo<$InboxItemsTableTable, InboxItemsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $InboxItemsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _fileIdMeta = const VerificationMeta('fileId');
+  @override
+  late final GeneratedColumn<String> fileId = GeneratedColumn<String>(
+    'file_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _nameMeta = const VerificationMeta('name');
+  @override
+  late final GeneratedColumn<String> name = GeneratedColumn<String>(
+    'name',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _statusMeta = const VerificationMeta('status');
+  @override
+  late final GeneratedColumn<String> status = GeneratedColumn<String>(
+    'status',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _destinationPathMeta = const VerificationMeta(
+    'destinationPath',
+  );
+  @override
+  late final GeneratedColumn<String> destinationPath = GeneratedColumn<String>(
+    'destination_path',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _localPathMeta = const VerificationMeta(
+    'localPath',
+  );
+  @override
+  late final GeneratedColumn<String> localPath = GeneratedColumn<String>(
+    'local_path',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _detailMeta = const VerificationMeta('detail');
+  @override
+  late final GeneratedColumn<String> detail = GeneratedColumn<String>(
+    'detail',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _arrivedAtMeta = const VerificationMeta(
+    'arrivedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> arrivedAt = GeneratedColumn<DateTime>(
+    'arrived_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    fileId,
+    name,
+    status,
+    destinationPath,
+    localPath,
+    detail,
+    arrivedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'inbox_items';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<InboxItemsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('file_id')) {
+      context.handle(
+        _fileIdMeta,
+        fileId.isAcceptableOrUnknown(data['file_id']!, _fileIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_fileIdMeta);
+    }
+    if (data.containsKey('name')) {
+      context.handle(
+        _nameMeta,
+        name.isAcceptableOrUnknown(data['name']!, _nameMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_nameMeta);
+    }
+    if (data.containsKey('status')) {
+      context.handle(
+        _statusMeta,
+        status.isAcceptableOrUnknown(data['status']!, _statusMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_statusMeta);
+    }
+    if (data.containsKey('destination_path')) {
+      context.handle(
+        _destinationPathMeta,
+        destinationPath.isAcceptableOrUnknown(
+          data['destination_path']!,
+          _destinationPathMeta,
+        ),
+      );
+    }
+    if (data.containsKey('local_path')) {
+      context.handle(
+        _localPathMeta,
+        localPath.isAcceptableOrUnknown(data['local_path']!, _localPathMeta),
+      );
+    }
+    if (data.containsKey('detail')) {
+      context.handle(
+        _detailMeta,
+        detail.isAcceptableOrUnknown(data['detail']!, _detailMeta),
+      );
+    }
+    if (data.containsKey('arrived_at')) {
+      context.handle(
+        _arrivedAtMeta,
+        arrivedAt.isAcceptableOrUnknown(data['arrived_at']!, _arrivedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_arrivedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {fileId};
+  @override
+  InboxItemsTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return InboxItemsTableData(
+      fileId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}file_id'],
+      )!,
+      name: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}name'],
+      )!,
+      status: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}status'],
+      )!,
+      destinationPath: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}destination_path'],
+      ),
+      localPath: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}local_path'],
+      ),
+      detail: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}detail'],
+      ),
+      arrivedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}arrived_at'],
+      )!,
+    );
+  }
+
+  @override
+  $InboxItemsTableTable createAlias(String alias) {
+    return $InboxItemsTableTable(attachedDatabase, alias);
+  }
+}
+
+class InboxItemsTableData extends DataClass
+    implements Insertable<InboxItemsTableData> {
+  final String fileId;
+  final String name;
+  final String status;
+  final String? destinationPath;
+  final String? localPath;
+  final String? detail;
+  final DateTime arrivedAt;
+  const InboxItemsTableData({
+    required this.fileId,
+    required this.name,
+    required this.status,
+    this.destinationPath,
+    this.localPath,
+    this.detail,
+    required this.arrivedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['file_id'] = Variable<String>(fileId);
+    map['name'] = Variable<String>(name);
+    map['status'] = Variable<String>(status);
+    if (!nullToAbsent || destinationPath != null) {
+      map['destination_path'] = Variable<String>(destinationPath);
+    }
+    if (!nullToAbsent || localPath != null) {
+      map['local_path'] = Variable<String>(localPath);
+    }
+    if (!nullToAbsent || detail != null) {
+      map['detail'] = Variable<String>(detail);
+    }
+    map['arrived_at'] = Variable<DateTime>(arrivedAt);
+    return map;
+  }
+
+  InboxItemsTableCompanion toCompanion(bool nullToAbsent) {
+    return InboxItemsTableCompanion(
+      fileId: Value(fileId),
+      name: Value(name),
+      status: Value(status),
+      destinationPath: destinationPath == null && nullToAbsent
+          ? const Value.absent()
+          : Value(destinationPath),
+      localPath: localPath == null && nullToAbsent
+          ? const Value.absent()
+          : Value(localPath),
+      detail: detail == null && nullToAbsent
+          ? const Value.absent()
+          : Value(detail),
+      arrivedAt: Value(arrivedAt),
+    );
+  }
+
+  factory InboxItemsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return InboxItemsTableData(
+      fileId: serializer.fromJson<String>(json['fileId']),
+      name: serializer.fromJson<String>(json['name']),
+      status: serializer.fromJson<String>(json['status']),
+      destinationPath: serializer.fromJson<String?>(json['destinationPath']),
+      localPath: serializer.fromJson<String?>(json['localPath']),
+      detail: serializer.fromJson<String?>(json['detail']),
+      arrivedAt: serializer.fromJson<DateTime>(json['arrivedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'fileId': serializer.toJson<String>(fileId),
+      'name': serializer.toJson<String>(name),
+      'status': serializer.toJson<String>(status),
+      'destinationPath': serializer.toJson<String?>(destinationPath),
+      'localPath': serializer.toJson<String?>(localPath),
+      'detail': serializer.toJson<String?>(detail),
+      'arrivedAt': serializer.toJson<DateTime>(arrivedAt),
+    };
+  }
+
+  InboxItemsTableData copyWith({
+    String? fileId,
+    String? name,
+    String? status,
+    Value<String?> destinationPath = const Value.absent(),
+    Value<String?> localPath = const Value.absent(),
+    Value<String?> detail = const Value.absent(),
+    DateTime? arrivedAt,
+  }) => InboxItemsTableData(
+    fileId: fileId ?? this.fileId,
+    name: name ?? this.name,
+    status: status ?? this.status,
+    destinationPath: destinationPath.present
+        ? destinationPath.value
+        : this.destinationPath,
+    localPath: localPath.present ? localPath.value : this.localPath,
+    detail: detail.present ? detail.value : this.detail,
+    arrivedAt: arrivedAt ?? this.arrivedAt,
+  );
+  InboxItemsTableData copyWithCompanion(InboxItemsTableCompanion data) {
+    return InboxItemsTableData(
+      fileId: data.fileId.present ? data.fileId.value : this.fileId,
+      name: data.name.present ? data.name.value : this.name,
+      status: data.status.present ? data.status.value : this.status,
+      destinationPath: data.destinationPath.present
+          ? data.destinationPath.value
+          : this.destinationPath,
+      localPath: data.localPath.present ? data.localPath.value : this.localPath,
+      detail: data.detail.present ? data.detail.value : this.detail,
+      arrivedAt: data.arrivedAt.present ? data.arrivedAt.value : this.arrivedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('InboxItemsTableData(')
+          ..write('fileId: $fileId, ')
+          ..write('name: $name, ')
+          ..write('status: $status, ')
+          ..write('destinationPath: $destinationPath, ')
+          ..write('localPath: $localPath, ')
+          ..write('detail: $detail, ')
+          ..write('arrivedAt: $arrivedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    fileId,
+    name,
+    status,
+    destinationPath,
+    localPath,
+    detail,
+    arrivedAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is InboxItemsTableData &&
+          other.fileId == this.fileId &&
+          other.name == this.name &&
+          other.status == this.status &&
+          other.destinationPath == this.destinationPath &&
+          other.localPath == this.localPath &&
+          other.detail == this.detail &&
+          other.arrivedAt == this.arrivedAt);
+}
+
+class InboxItemsTableCompanion extends UpdateCompanion<InboxItemsTableData> {
+  final Value<String> fileId;
+  final Value<String> name;
+  final Value<String> status;
+  final Value<String?> destinationPath;
+  final Value<String?> localPath;
+  final Value<String?> detail;
+  final Value<DateTime> arrivedAt;
+  final Value<int> rowid;
+  const InboxItemsTableCompanion({
+    this.fileId = const Value.absent(),
+    this.name = const Value.absent(),
+    this.status = const Value.absent(),
+    this.destinationPath = const Value.absent(),
+    this.localPath = const Value.absent(),
+    this.detail = const Value.absent(),
+    this.arrivedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  InboxItemsTableCompanion.insert({
+    required String fileId,
+    required String name,
+    required String status,
+    this.destinationPath = const Value.absent(),
+    this.localPath = const Value.absent(),
+    this.detail = const Value.absent(),
+    required DateTime arrivedAt,
+    this.rowid = const Value.absent(),
+  }) : fileId = Value(fileId),
+       name = Value(name),
+       status = Value(status),
+       arrivedAt = Value(arrivedAt);
+  static Insertable<InboxItemsTableData> custom({
+    Expression<String>? fileId,
+    Expression<String>? name,
+    Expression<String>? status,
+    Expression<String>? destinationPath,
+    Expression<String>? localPath,
+    Expression<String>? detail,
+    Expression<DateTime>? arrivedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (fileId != null) 'file_id': fileId,
+      if (name != null) 'name': name,
+      if (status != null) 'status': status,
+      if (destinationPath != null) 'destination_path': destinationPath,
+      if (localPath != null) 'local_path': localPath,
+      if (detail != null) 'detail': detail,
+      if (arrivedAt != null) 'arrived_at': arrivedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  InboxItemsTableCompanion copyWith({
+    Value<String>? fileId,
+    Value<String>? name,
+    Value<String>? status,
+    Value<String?>? destinationPath,
+    Value<String?>? localPath,
+    Value<String?>? detail,
+    Value<DateTime>? arrivedAt,
+    Value<int>? rowid,
+  }) {
+    return InboxItemsTableCompanion(
+      fileId: fileId ?? this.fileId,
+      name: name ?? this.name,
+      status: status ?? this.status,
+      destinationPath: destinationPath ?? this.destinationPath,
+      localPath: localPath ?? this.localPath,
+      detail: detail ?? this.detail,
+      arrivedAt: arrivedAt ?? this.arrivedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (fileId.present) {
+      map['file_id'] = Variable<String>(fileId.value);
+    }
+    if (name.present) {
+      map['name'] = Variable<String>(name.value);
+    }
+    if (status.present) {
+      map['status'] = Variable<String>(status.value);
+    }
+    if (destinationPath.present) {
+      map['destination_path'] = Variable<String>(destinationPath.value);
+    }
+    if (localPath.present) {
+      map['local_path'] = Variable<String>(localPath.value);
+    }
+    if (detail.present) {
+      map['detail'] = Variable<String>(detail.value);
+    }
+    if (arrivedAt.present) {
+      map['arrived_at'] = Variable<DateTime>(arrivedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('InboxItemsTableCompanion(')
+          ..write('fileId: $fileId, ')
+          ..write('name: $name, ')
+          ..write('status: $status, ')
+          ..write('destinationPath: $destinationPath, ')
+          ..write('localPath: $localPath, ')
+          ..write('detail: $detail, ')
+          ..write('arrivedAt: $arrivedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $SyncStatsTableTable extends SyncStatsTable
+    with TableInfo<$SyncStatsTableTable, SyncStatsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $SyncStatsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _dayMeta = const VerificationMeta('day');
+  @override
+  late final GeneratedColumn<DateTime> day = GeneratedColumn<DateTime>(
+    'day',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _bytesUploadedMeta = const VerificationMeta(
+    'bytesUploaded',
+  );
+  @override
+  late final GeneratedColumn<int> bytesUploaded = GeneratedColumn<int>(
+    'bytes_uploaded',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _bytesDownloadedMeta = const VerificationMeta(
+    'bytesDownloaded',
+  );
+  @override
+  late final GeneratedColumn<int> bytesDownloaded = GeneratedColumn<int>(
+    'bytes_downloaded',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _filesSyncedMeta = const VerificationMeta(
+    'filesSynced',
+  );
+  @override
+  late final GeneratedColumn<int> filesSynced = GeneratedColumn<int>(
+    'files_synced',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _errorsMeta = const VerificationMeta('errors');
+  @override
+  late final GeneratedColumn<int> errors = GeneratedColumn<int>(
+    'errors',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _transferMillisMeta = const VerificationMeta(
+    'transferMillis',
+  );
+  @override
+  late final GeneratedColumn<int> transferMillis = GeneratedColumn<int>(
+    'transfer_millis',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    day,
+    bytesUploaded,
+    bytesDownloaded,
+    filesSynced,
+    errors,
+    transferMillis,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'sync_stats';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<SyncStatsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('day')) {
+      context.handle(
+        _dayMeta,
+        day.isAcceptableOrUnknown(data['day']!, _dayMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_dayMeta);
+    }
+    if (data.containsKey('bytes_uploaded')) {
+      context.handle(
+        _bytesUploadedMeta,
+        bytesUploaded.isAcceptableOrUnknown(
+          data['bytes_uploaded']!,
+          _bytesUploadedMeta,
+        ),
+      );
+    }
+    if (data.containsKey('bytes_downloaded')) {
+      context.handle(
+        _bytesDownloadedMeta,
+        bytesDownloaded.isAcceptableOrUnknown(
+          data['bytes_downloaded']!,
+          _bytesDownloadedMeta,
+        ),
+      );
+    }
+    if (data.containsKey('files_synced')) {
+      context.handle(
+        _filesSyncedMeta,
+        filesSynced.isAcceptableOrUnknown(
+          data['files_synced']!,
+          _filesSyncedMeta,
+        ),
+      );
+    }
+    if (data.containsKey('errors')) {
+      context.handle(
+        _errorsMeta,
+        errors.isAcceptableOrUnknown(data['errors']!, _errorsMeta),
+      );
+    }
+    if (data.containsKey('transfer_millis')) {
+      context.handle(
+        _transferMillisMeta,
+        transferMillis.isAcceptableOrUnknown(
+          data['transfer_millis']!,
+          _transferMillisMeta,
+        ),
+      );
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {day};
+  @override
+  SyncStatsTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return SyncStatsTableData(
+      day: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}day'],
+      )!,
+      bytesUploaded: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}bytes_uploaded'],
+      )!,
+      bytesDownloaded: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}bytes_downloaded'],
+      )!,
+      filesSynced: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}files_synced'],
+      )!,
+      errors: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}errors'],
+      )!,
+      transferMillis: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}transfer_millis'],
+      )!,
+    );
+  }
+
+  @override
+  $SyncStatsTableTable createAlias(String alias) {
+    return $SyncStatsTableTable(attachedDatabase, alias);
+  }
+}
+
+class SyncStatsTableData extends DataClass
+    implements Insertable<SyncStatsTableData> {
+  final DateTime day;
+  final int bytesUploaded;
+  final int bytesDownloaded;
+  final int filesSynced;
+  final int errors;
+  final int transferMillis;
+  const SyncStatsTableData({
+    required this.day,
+    required this.bytesUploaded,
+    required this.bytesDownloaded,
+    required this.filesSynced,
+    required this.errors,
+    required this.transferMillis,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['day'] = Variable<DateTime>(day);
+    map['bytes_uploaded'] = Variable<int>(bytesUploaded);
+    map['bytes_downloaded'] = Variable<int>(bytesDownloaded);
+    map['files_synced'] = Variable<int>(filesSynced);
+    map['errors'] = Variable<int>(errors);
+    map['transfer_millis'] = Variable<int>(transferMillis);
+    return map;
+  }
+
+  SyncStatsTableCompanion toCompanion(bool nullToAbsent) {
+    return SyncStatsTableCompanion(
+      day: Value(day),
+      bytesUploaded: Value(bytesUploaded),
+      bytesDownloaded: Value(bytesDownloaded),
+      filesSynced: Value(filesSynced),
+      errors: Value(errors),
+      transferMillis: Value(transferMillis),
+    );
+  }
+
+  factory SyncStatsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return SyncStatsTableData(
+      day: serializer.fromJson<DateTime>(json['day']),
+      bytesUploaded: serializer.fromJson<int>(json['bytesUploaded']),
+      bytesDownloaded: serializer.fromJson<int>(json['bytesDownloaded']),
+      filesSynced: serializer.fromJson<int>(json['filesSynced']),
+      errors: serializer.fromJson<int>(json['errors']),
+      transferMillis: serializer.fromJson<int>(json['transferMillis']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'day': serializer.toJson<DateTime>(day),
+      'bytesUploaded': serializer.toJson<int>(bytesUploaded),
+      'bytesDownloaded': serializer.toJson<int>(bytesDownloaded),
+      'filesSynced': serializer.toJson<int>(filesSynced),
+      'errors': serializer.toJson<int>(errors),
+      'transferMillis': serializer.toJson<int>(transferMillis),
+    };
+  }
+
+  SyncStatsTableData copyWith({
+    DateTime? day,
+    int? bytesUploaded,
+    int? bytesDownloaded,
+    int? filesSynced,
+    int? errors,
+    int? transferMillis,
+  }) => SyncStatsTableData(
+    day: day ?? this.day,
+    bytesUploaded: bytesUploaded ?? this.bytesUploaded,
+    bytesDownloaded: bytesDownloaded ?? this.bytesDownloaded,
+    filesSynced: filesSynced ?? this.filesSynced,
+    errors: errors ?? this.errors,
+    transferMillis: transferMillis ?? this.transferMillis,
+  );
+  SyncStatsTableData copyWithCompanion(SyncStatsTableCompanion data) {
+    return SyncStatsTableData(
+      day: data.day.present ? data.day.value : this.day,
+      bytesUploaded: data.bytesUploaded.present
+          ? data.bytesUploaded.value
+          : this.bytesUploaded,
+      bytesDownloaded: data.bytesDownloaded.present
+          ? data.bytesDownloaded.value
+          : this.bytesDownloaded,
+      filesSynced: data.filesSynced.present
+          ? data.filesSynced.value
+          : this.filesSynced,
+      errors: data.errors.present ? data.errors.value : this.errors,
+      transferMillis: data.transferMillis.present
+          ? data.transferMillis.value
+          : this.transferMillis,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('SyncStatsTableData(')
+          ..write('day: $day, ')
+          ..write('bytesUploaded: $bytesUploaded, ')
+          ..write('bytesDownloaded: $bytesDownloaded, ')
+          ..write('filesSynced: $filesSynced, ')
+          ..write('errors: $errors, ')
+          ..write('transferMillis: $transferMillis')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    day,
+    bytesUploaded,
+    bytesDownloaded,
+    filesSynced,
+    errors,
+    transferMillis,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is SyncStatsTableData &&
+          other.day == this.day &&
+          other.bytesUploaded == this.bytesUploaded &&
+          other.bytesDownloaded == this.bytesDownloaded &&
+          other.filesSynced == this.filesSynced &&
+          other.errors == this.errors &&
+          other.transferMillis == this.transferMillis);
+}
+
+class SyncStatsTableCompanion extends UpdateCompanion<SyncStatsTableData> {
+  final Value<DateTime> day;
+  final Value<int> bytesUploaded;
+  final Value<int> bytesDownloaded;
+  final Value<int> filesSynced;
+  final Value<int> errors;
+  final Value<int> transferMillis;
+  final Value<int> rowid;
+  const SyncStatsTableCompanion({
+    this.day = const Value.absent(),
+    this.bytesUploaded = const Value.absent(),
+    this.bytesDownloaded = const Value.absent(),
+    this.filesSynced = const Value.absent(),
+    this.errors = const Value.absent(),
+    this.transferMillis = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  SyncStatsTableCompanion.insert({
+    required DateTime day,
+    this.bytesUploaded = const Value.absent(),
+    this.bytesDownloaded = const Value.absent(),
+    this.filesSynced = const Value.absent(),
+    this.errors = const Value.absent(),
+    this.transferMillis = const Value.absent(),
+    this.rowid = const Value.absent(),
+  }) : day = Value(day);
+  static Insertable<SyncStatsTableData> custom({
+    Expression<DateTime>? day,
+    Expression<int>? bytesUploaded,
+    Expression<int>? bytesDownloaded,
+    Expression<int>? filesSynced,
+    Expression<int>? errors,
+    Expression<int>? transferMillis,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (day != null) 'day': day,
+      if (bytesUploaded != null) 'bytes_uploaded': bytesUploaded,
+      if (bytesDownloaded != null) 'bytes_downloaded': bytesDownloaded,
+      if (filesSynced != null) 'files_synced': filesSynced,
+      if (errors != null) 'errors': errors,
+      if (transferMillis != null) 'transfer_millis': transferMillis,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  SyncStatsTableCompanion copyWith({
+    Value<DateTime>? day,
+    Value<int>? bytesUploaded,
+    Value<int>? bytesDownloaded,
+    Value<int>? filesSynced,
+    Value<int>? errors,
+    Value<int>? transferMillis,
+    Value<int>? rowid,
+  }) {
+    return SyncStatsTableCompanion(
+      day: day ?? this.day,
+      bytesUploaded: bytesUploaded ?? this.bytesUploaded,
+      bytesDownloaded: bytesDownloaded ?? this.bytesDownloaded,
+      filesSynced: filesSynced ?? this.filesSynced,
+      errors: errors ?? this.errors,
+      transferMillis: transferMillis ?? this.transferMillis,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (day.present) {
+      map['day'] = Variable<DateTime>(day.value);
+    }
+    if (bytesUploaded.present) {
+      map['bytes_uploaded'] = Variable<int>(bytesUploaded.value);
+    }
+    if (bytesDownloaded.present) {
+      map['bytes_downloaded'] = Variable<int>(bytesDownloaded.value);
+    }
+    if (filesSynced.present) {
+      map['files_synced'] = Variable<int>(filesSynced.value);
+    }
+    if (errors.present) {
+      map['errors'] = Variable<int>(errors.value);
+    }
+    if (transferMillis.present) {
+      map['transfer_millis'] = Variable<int>(transferMillis.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('SyncStatsTableCompanion(')
+          ..write('day: $day, ')
+          ..write('bytesUploaded: $bytesUploaded, ')
+          ..write('bytesDownloaded: $bytesDownloaded, ')
+          ..write('filesSynced: $filesSynced, ')
+          ..write('errors: $errors, ')
+          ..write('transferMillis: $transferMillis, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $FolderSyncStatsTableTable extends FolderSyncStatsTable
+    with TableInfo<$FolderSyncStatsTableTable, FolderSyncStatsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $FolderSyncStatsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _folderIdMeta = const VerificationMeta(
+    'folderId',
+  );
+  @override
+  late final GeneratedColumn<String> folderId = GeneratedColumn<String>(
+    'folder_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _lastSyncedAtMeta = const VerificationMeta(
+    'lastSyncedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> lastSyncedAt = GeneratedColumn<DateTime>(
+    'last_synced_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [folderId, lastSyncedAt];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'folder_sync_stats';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<FolderSyncStatsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('folder_id')) {
+      context.handle(
+        _folderIdMeta,
+        folderId.isAcceptableOrUnknown(data['folder_id']!, _folderIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_folderIdMeta);
+    }
+    if (data.containsKey('last_synced_at')) {
+      context.handle(
+        _lastSyncedAtMeta,
+        lastSyncedAt.isAcceptableOrUnknown(
+          data['last_synced_at']!,
+          _lastSyncedAtMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_lastSyncedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {folderId};
+  @override
+  FolderSyncStatsTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return FolderSyncStatsTableData(
+      folderId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}folder_id'],
+      )!,
+      lastSyncedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}last_synced_at'],
+      )!,
+    );
+  }
+
+  @override
+  $FolderSyncStatsTableTable createAlias(String alias) {
+    return $FolderSyncStatsTableTable(attachedDatabase, alias);
+  }
+}
+
+class FolderSyncStatsTableData extends DataClass
+    implements Insertable<FolderSyncStatsTableData> {
+  final String folderId;
+  final DateTime lastSyncedAt;
+  const FolderSyncStatsTableData({
+    required this.folderId,
+    required this.lastSyncedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['folder_id'] = Variable<String>(folderId);
+    map['last_synced_at'] = Variable<DateTime>(lastSyncedAt);
+    return map;
+  }
+
+  FolderSyncStatsTableCompanion toCompanion(bool nullToAbsent) {
+    return FolderSyncStatsTableCompanion(
+      folderId: Value(folderId),
+      lastSyncedAt: Value(lastSyncedAt),
+    );
+  }
+
+  factory FolderSyncStatsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return FolderSyncStatsTableData(
+      folderId: serializer.fromJson<String>(json['folderId']),
+      lastSyncedAt: serializer.fromJson<DateTime>(json['lastSyncedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'folderId': serializer.toJson<String>(folderId),
+      'lastSyncedAt': serializer.toJson<DateTime>(lastSyncedAt),
+    };
+  }
+
+  FolderSyncStatsTableData copyWith({
+    String? folderId,
+    DateTime? lastSyncedAt,
+  }) => FolderSyncStatsTableData(
+    folderId: folderId ?? this.folderId,
+    lastSyncedAt: lastSyncedAt ?? this.lastSyncedAt,
+  );
+  FolderSyncStatsTableData copyWithCompanion(
+    FolderSyncStatsTableCompanion data,
+  ) {
+    return FolderSyncStatsTableData(
+      folderId: data.folderId.present ? data.folderId.value : this.folderId,
+      lastSyncedAt: data.lastSyncedAt.present
+          ? data.lastSyncedAt.value
+          : this.lastSyncedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FolderSyncStatsTableData(')
+          ..write('folderId: $folderId, ')
+          ..write('lastSyncedAt: $lastSyncedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(folderId, lastSyncedAt);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is FolderSyncStatsTableData &&
+          other.folderId == this.folderId &&
+          other.lastSyncedAt == this.lastSyncedAt);
+}
+
+class FolderSyncStatsTableCompanion
+    extends UpdateCompanion<FolderSyncStatsTableData> {
+  final Value<String> folderId;
+  final Value<DateTime> lastSyncedAt;
+  final Value<int> rowid;
+  const FolderSyncStatsTableCompanion({
+    this.folderId = const Value.absent(),
+    this.lastSyncedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  FolderSyncStatsTableCompanion.insert({
+    required String folderId,
+    required DateTime lastSyncedAt,
+    this.rowid = const Value.absent(),
+  }) : folderId = Value(folderId),
+       lastSyncedAt = Value(lastSyncedAt);
+  static Insertable<FolderSyncStatsTableData> custom({
+    Expression<String>? folderId,
+    Expression<DateTime>? lastSyncedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (folderId != null) 'folder_id': folderId,
+      if (lastSyncedAt != null) 'last_synced_at': lastSyncedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  FolderSyncStatsTableCompanion copyWith({
+    Value<String>? folderId,
+    Value<DateTime>? lastSyncedAt,
+    Value<int>? rowid,
+  }) {
+    return FolderSyncStatsTableCompanion(
+      folderId: folderId ?? this.folderId,
+      lastSyncedAt: lastSyncedAt ?? this.lastSyncedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (folderId.present) {
+      map['folder_id'] = Variable<String>(folderId.value);
+    }
+    if (lastSyncedAt.present) {
+      map['last_synced_at'] = Variable<DateTime>(lastSyncedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('FolderSyncStatsTableCompanion(')
+          ..write('folderId: $folderId, ')
+          ..write('lastSyncedAt: $lastSyncedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $OperationsTableTable extends OperationsTable
+    with TableInfo<$OperationsTableTable, OperationsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $OperationsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _idMeta = const VerificationMeta('id');
+  @override
+  late final GeneratedColumn<int> id = GeneratedColumn<int>(
+    'id',
+    aliasedName,
+    false,
+    hasAutoIncrement: true,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'PRIMARY KEY AUTOINCREMENT',
+    ),
+  );
+  static const VerificationMeta _syncOpIdMeta = const VerificationMeta(
+    'syncOpId',
+  );
+  @override
+  late final GeneratedColumn<int> syncOpId = GeneratedColumn<int>(
+    'sync_op_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _operationTypeMeta = const VerificationMeta(
+    'operationType',
+  );
+  @override
+  late final GeneratedColumn<String> operationType = GeneratedColumn<String>(
+    'operation_type',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _itemIdMeta = const VerificationMeta('itemId');
+  @override
+  late final GeneratedColumn<String> itemId = GeneratedColumn<String>(
+    'item_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _payloadMeta = const VerificationMeta(
+    'payload',
+  );
+  @override
+  late final GeneratedColumn<String> payload = GeneratedColumn<String>(
+    'payload',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _stateMeta = const VerificationMeta('state');
+  @override
+  late final GeneratedColumn<String> state = GeneratedColumn<String>(
+    'state',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+    defaultValue: const Constant('started'),
+  );
+  static const VerificationMeta _startedAtMeta = const VerificationMeta(
+    'startedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> startedAt = GeneratedColumn<DateTime>(
+    'started_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _finishedAtMeta = const VerificationMeta(
+    'finishedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> finishedAt = GeneratedColumn<DateTime>(
+    'finished_at',
+    aliasedName,
+    true,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: false,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    id,
+    syncOpId,
+    operationType,
+    itemId,
+    payload,
+    state,
+    startedAt,
+    finishedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'operations';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<OperationsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('id')) {
+      context.handle(_idMeta, id.isAcceptableOrUnknown(data['id']!, _idMeta));
+    }
+    if (data.containsKey('sync_op_id')) {
+      context.handle(
+        _syncOpIdMeta,
+        syncOpId.isAcceptableOrUnknown(data['sync_op_id']!, _syncOpIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_syncOpIdMeta);
+    }
+    if (data.containsKey('operation_type')) {
+      context.handle(
+        _operationTypeMeta,
+        operationType.isAcceptableOrUnknown(
+          data['operation_type']!,
+          _operationTypeMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_operationTypeMeta);
+    }
+    if (data.containsKey('item_id')) {
+      context.handle(
+        _itemIdMeta,
+        itemId.isAcceptableOrUnknown(data['item_id']!, _itemIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_itemIdMeta);
+    }
+    if (data.containsKey('payload')) {
+      context.handle(
+        _payloadMeta,
+        payload.isAcceptableOrUnknown(data['payload']!, _payloadMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_payloadMeta);
+    }
+    if (data.containsKey('state')) {
+      context.handle(
+        _stateMeta,
+        state.isAcceptableOrUnknown(data['state']!, _stateMeta),
+      );
+    }
+    if (data.containsKey('started_at')) {
+      context.handle(
+        _startedAtMeta,
+        startedAt.isAcceptableOrUnknown(data['started_at']!, _startedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_startedAtMeta);
+    }
+    if (data.containsKey('finished_at')) {
+      context.handle(
+        _finishedAtMeta,
+        finishedAt.isAcceptableOrUnknown(data['finished_at']!, _finishedAtMeta),
+      );
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {id};
+  @override
+  OperationsTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return OperationsTableData(
+      id: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}id'],
+      )!,
+      syncOpId: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}sync_op_id'],
+      )!,
+      operationType: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}operation_type'],
+      )!,
+      itemId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_id'],
+      )!,
+      payload: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}payload'],
+      )!,
+      state: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}state'],
+      )!,
+      startedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}started_at'],
+      )!,
+      finishedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}finished_at'],
+      ),
+    );
+  }
+
+  @override
+  $OperationsTableTable createAlias(String alias) {
+    return $OperationsTableTable(attachedDatabase, alias);
+  }
+}
+
+class OperationsTableData extends DataClass
+    implements Insertable<OperationsTableData> {
+  final int id;
+  final int syncOpId;
+  final String operationType;
+  final String itemId;
+  final String payload;
+  final String state;
+  final DateTime startedAt;
+  final DateTime? finishedAt;
+  const OperationsTableData({
+    required this.id,
+    required this.syncOpId,
+    required this.operationType,
+    required this.itemId,
+    required this.payload,
+    required this.state,
+    required this.startedAt,
+    this.finishedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['id'] = Variable<int>(id);
+    map['sync_op_id'] = Variable<int>(syncOpId);
+    map['operation_type'] = Variable<String>(operationType);
+    map['item_id'] = Variable<String>(itemId);
+    map['payload'] = Variable<String>(payload);
+    map['state'] = Variable<String>(state);
+    map['started_at'] = Variable<DateTime>(startedAt);
+    if (!nullToAbsent || finishedAt != null) {
+      map['finished_at'] = Variable<DateTime>(finishedAt);
+    }
+    return map;
+  }
+
+  OperationsTableCompanion toCompanion(bool nullToAbsent) {
+    return OperationsTableCompanion(
+      id: Value(id),
+      syncOpId: Value(syncOpId),
+      operationType: Value(operationType),
+      itemId: Value(itemId),
+      payload: Value(payload),
+      state: Value(state),
+      startedAt: Value(startedAt),
+      finishedAt: finishedAt == null && nullToAbsent
+          ? const Value.absent()
+          : Value(finishedAt),
+    );
+  }
+
+  factory OperationsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return OperationsTableData(
+      id: serializer.fromJson<int>(json['id']),
+      syncOpId: serializer.fromJson<int>(json['syncOpId']),
+      operationType: serializer.fromJson<String>(json['operationType']),
+      itemId: serializer.fromJson<String>(json['itemId']),
+      payload: serializer.fromJson<String>(json['payload']),
+      state: serializer.fromJson<String>(json['state']),
+      startedAt: serializer.fromJson<DateTime>(json['startedAt']),
+      finishedAt: serializer.fromJson<DateTime?>(json['finishedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'id': serializer.toJson<int>(id),
+      'syncOpId': serializer.toJson<int>(syncOpId),
+      'operationType': serializer.toJson<String>(operationType),
+      'itemId': serializer.toJson<String>(itemId),
+      'payload': serializer.toJson<String>(payload),
+      'state': serializer.toJson<String>(state),
+      'startedAt': serializer.toJson<DateTime>(startedAt),
+      'finishedAt': serializer.toJson<DateTime?>(finishedAt),
+    };
+  }
+
+  OperationsTableData copyWith({
+    int? id,
+    int? syncOpId,
+    String? operationType,
+    String? itemId,
+    String? payload,
+    String? state,
+    DateTime? startedAt,
+    Value<DateTime?> finishedAt = const Value.absent(),
+  }) => OperationsTableData(
+    id: id ?? this.id,
+    syncOpId: syncOpId ?? this.syncOpId,
+    operationType: operationType ?? this.operationType,
+    itemId: itemId ?? this.itemId,
+    payload: payload ?? this.payload,
+    state: state ?? this.state,
+    startedAt: startedAt ?? this.startedAt,
+    finishedAt: finishedAt.present ? finishedAt.value : this.finishedAt,
+  );
+  OperationsTableData copyWithCompanion(OperationsTableCompanion data) {
+    return OperationsTableData(
+      id: data.id.present ? data.id.value : this.id,
+      syncOpId: data.syncOpId.present ? data.syncOpId.value : this.syncOpId,
+      operationType: data.operationType.present
+          ? data.operationType.value
+          : this.operationType,
+      itemId: data.itemId.present ? data.itemId.value : this.itemId,
+      payload: data.payload.present ? data.payload.value : this.payload,
+      state: data.state.present ? data.state.value : this.state,
+      startedAt: data.startedAt.present ? data.startedAt.value : this.startedAt,
+      finishedAt: data.finishedAt.present
+          ? data.finishedAt.value
+          : this.finishedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('OperationsTableData(')
+          ..write('id: $id, ')
+          ..write('syncOpId: $syncOpId, ')
+          ..write('operationType: $operationType, ')
+          ..write('itemId: $itemId, ')
+          ..write('payload: $payload, ')
+          ..write('state: $state, ')
+          ..write('startedAt: $startedAt, ')
+          ..write('finishedAt: $finishedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    syncOpId,
+    operationType,
+    itemId,
+    payload,
+    state,
+    startedAt,
+    finishedAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is OperationsTableData &&
+          other.id == this.id &&
+          other.syncOpId == this.syncOpId &&
+          other.operationType == this.operationType &&
+          other.itemId == this.itemId &&
+          other.payload == this.payload &&
+          other.state == this.state &&
+          other.startedAt == this.startedAt &&
+          other.finishedAt == this.finishedAt);
+}
+
+class OperationsTableCompanion extends UpdateCompanion<OperationsTableData> {
+  final Value<int> id;
+  final Value<int> syncOpId;
+  final Value<String> operationType;
+  final Value<String> itemId;
+  final Value<String> payload;
+  final Value<String> state;
+  final Value<DateTime> startedAt;
+  final Value<DateTime?> finishedAt;
+  const OperationsTableCompanion({
+    this.id = const Value.absent(),
+    this.syncOpId = const Value.absent(),
+    this.operationType = const Value.absent(),
+    this.itemId = const Value.absent(),
+    this.payload = const Value.absent(),
+    this.state = const Value.absent(),
+    this.startedAt = const Value.absent(),
+    this.finishedAt = const Value.absent(),
+  });
+  OperationsTableCompanion.insert({
+    this.id = const Value.absent(),
+    required int syncOpId,
+    required String operationType,
+    required String itemId,
+    required String payload,
+    this.state = const Value.absent(),
+    required DateTime startedAt,
+    this.finishedAt = const Value.absent(),
+  }) : syncOpId = Value(syncOpId),
+       operationType = Value(operationType),
+       itemId = Value(itemId),
+       payload = Value(payload),
+       startedAt = Value(startedAt);
+  static Insertable<OperationsTableData> custom({
+    Expression<int>? id,
+    Expression<int>? syncOpId,
+    Expression<String>? operationType,
+    Expression<String>? itemId,
+    Expression<String>? payload,
+    Expression<String>? state,
+    Expression<DateTime>? startedAt,
+    Expression<DateTime>? finishedAt,
+  }) {
+    return RawValuesInsertable({
+      if (id != null) 'id': id,
+      if (syncOpId != null) 'sync_op_id': syncOpId,
+      if (operationType != null) 'operation_type': operationType,
+      if (itemId != null) 'item_id': itemId,
+      if (payload != null) 'payload': payload,
+      if (state != null) 'state': state,
+      if (startedAt != null) 'started_at': startedAt,
+      if (finishedAt != null) 'finished_at': finishedAt,
+    });
+  }
+
+  OperationsTableCompanion copyWith({
+    Value<int>? id,
+    Value<int>? syncOpId,
+    Value<String>? operationType,
+    Value<String>? itemId,
+    Value<String>? payload,
+    Value<String>? state,
+    Value<DateTime>? startedAt,
+    Value<DateTime?>? finishedAt,
+  }) {
+    return OperationsTableCompanion(
+      id: id ?? this.id,
+      syncOpId: syncOpId ?? this.syncOpId,
+      operationType: operationType ?? this.operationType,
+      itemId: itemId ?? this.itemId,
+      payload: payload ?? this.payload,
+      state: state ?? this.state,
+      startedAt: startedAt ?? this.startedAt,
+      finishedAt: finishedAt ?? this.finishedAt,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (id.present) {
+      map['id'] = Variable<int>(id.value);
+    }
+    if (syncOpId.present) {
+      map['sync_op_id'] = Variable<int>(syncOpId.value);
+    }
+    if (operationType.present) {
+      map['operation_type'] = Variable<String>(operationType.value);
+    }
+    if (itemId.present) {
+      map['item_id'] = Variable<String>(itemId.value);
+    }
+    if (payload.present) {
+      map['payload'] = Variable<String>(payload.value);
+    }
+    if (state.present) {
+      map['state'] = Variable<String>(state.value);
+    }
+    if (startedAt.present) {
+      map['started_at'] = Variable<DateTime>(startedAt.value);
+    }
+    if (finishedAt.present) {
+      map['finished_at'] = Variable<DateTime>(finishedAt.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('OperationsTableCompanion(')
+          ..write('id: $id, ')
+          ..write('syncOpId: $syncOpId, ')
+          ..write('operationType: $operationType, ')
+          ..write('itemId: $itemId, ')
+          ..write('payload: $payload, ')
+          ..write('state: $state, ')
+          ..write('startedAt: $startedAt, ')
+          ..write('finishedAt: $finishedAt')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $UsageCountersTableTable extends UsageCountersTable
+    with TableInfo<$UsageCountersTableTable, UsageCountersTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $UsageCountersTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _featureMeta = const VerificationMeta(
+    'feature',
+  );
+  @override
+  late final GeneratedColumn<String> feature = GeneratedColumn<String>(
+    'feature',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _countMeta = const VerificationMeta('count');
+  @override
+  late final GeneratedColumn<int> count = GeneratedColumn<int>(
+    'count',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _lastUsedAtMeta = const VerificationMeta(
+    'lastUsedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> lastUsedAt = GeneratedColumn<DateTime>(
+    'last_used_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [feature, count, lastUsedAt];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'usage_counters';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<UsageCountersTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('feature')) {
+      context.handle(
+        _featureMeta,
+        feature.isAcceptableOrUnknown(data['feature']!, _featureMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_featureMeta);
+    }
+    if (data.containsKey('count')) {
+      context.handle(
+        _countMeta,
+        count.isAcceptableOrUnknown(data['count']!, _countMeta),
+      );
+    }
+    if (data.containsKey('last_used_at')) {
+      context.handle(
+        _lastUsedAtMeta,
+        lastUsedAt.isAcceptableOrUnknown(
+          data['last_used_at']!,
+          _lastUsedAtMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_lastUsedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {feature};
+  @override
+  UsageCountersTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return UsageCountersTableData(
+      feature: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}feature'],
+      )!,
+      count: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}count'],
+      )!,
+      lastUsedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}last_used_at'],
+      )!,
+    );
+  }
+
+  @override
+  $UsageCountersTableTable createAlias(String alias) {
+    return $UsageCountersTableTable(attachedDatabase, alias);
+  }
+}
+
+class UsageCountersTableData extends DataClass
+    implements Insertable<UsageCountersTableData> {
+  final String feature;
+  final int count;
+  final DateTime lastUsedAt;
+  const UsageCountersTableData({
+    required this.feature,
+    required this.count,
+    required this.lastUsedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['feature'] = Variable<String>(feature);
+    map['count'] = Variable<int>(count);
+    map['last_used_at'] = Variable<DateTime>(lastUsedAt);
+    return map;
+  }
+
+  UsageCountersTableCompanion toCompanion(bool nullToAbsent) {
+    return UsageCountersTableCompanion(
+      feature: Value(feature),
+      count: Value(count),
+      lastUsedAt: Value(lastUsedAt),
+    );
+  }
+
+  factory UsageCountersTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return UsageCountersTableData(
+      feature: serializer.fromJson<String>(json['feature']),
+      count: serializer.fromJson<int>(json['count']),
+      lastUsedAt: serializer.fromJson<DateTime>(json['lastUsedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'feature': serializer.toJson<String>(feature),
+      'count': serializer.toJson<int>(count),
+      'lastUsedAt': serializer.toJson<DateTime>(lastUsedAt),
+    };
+  }
+
+  UsageCountersTableData copyWith({
+    String? feature,
+    int? count,
+    DateTime? lastUsedAt,
+  }) => UsageCountersTableData(
+    feature: feature ?? this.feature,
+    count: count ?? this.count,
+    lastUsedAt: lastUsedAt ?? this.lastUsedAt,
+  );
+  UsageCountersTableData copyWithCompanion(UsageCountersTableCompanion data) {
+    return UsageCountersTableData(
+      feature: data.feature.present ? data.feature.value : this.feature,
+      count: data.count.present ? data.count.value : this.count,
+      lastUsedAt: data.lastUsedAt.present
+          ? data.lastUsedAt.value
+          : this.lastUsedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('UsageCountersTableData(')
+          ..write('feature: $feature, ')
+          ..write('count: $count, ')
+          ..write('lastUsedAt: $lastUsedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(feature, count, lastUsedAt);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is UsageCountersTableData &&
+          other.feature == this.feature &&
+          other.count == this.count &&
+          other.lastUsedAt == this.lastUsedAt);
+}
+
+class UsageCountersTableCompanion
+    extends UpdateCompanion<UsageCountersTableData> {
+  final Value<String> feature;
+  final Value<int> count;
+  final Value<DateTime> lastUsedAt;
+  final Value<int> rowid;
+  const UsageCountersTableCompanion({
+    this.feature = const Value.absent(),
+    this.count = const Value.absent(),
+    this.lastUsedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  UsageCountersTableCompanion.insert({
+    required String feature,
+    this.count = const Value.absent(),
+    required DateTime lastUsedAt,
+    this.rowid = const Value.absent(),
+  }) : feature = Value(feature),
+       lastUsedAt = Value(lastUsedAt);
+  static Insertable<UsageCountersTableData> custom({
+    Expression<String>? feature,
+    Expression<int>? count,
+    Expression<DateTime>? lastUsedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (feature != null) 'feature': feature,
+      if (count != null) 'count': count,
+      if (lastUsedAt != null) 'last_used_at': lastUsedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  UsageCountersTableCompanion copyWith({
+    Value<String>? feature,
+    Value<int>? count,
+    Value<DateTime>? lastUsedAt,
+    Value<int>? rowid,
+  }) {
+    return UsageCountersTableCompanion(
+      feature: feature ?? this.feature,
+      count: count ?? this.count,
+      lastUsedAt: lastUsedAt ?? this.lastUsedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (feature.present) {
+      map['feature'] = Variable<String>(feature.value);
+    }
+    if (count.present) {
+      map['count'] = Variable<int>(count.value);
+    }
+    if (lastUsedAt.present) {
+      map['last_used_at'] = Variable<DateTime>(lastUsedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('UsageCountersTableCompanion(')
+          ..write('feature: $feature, ')
+          ..write('count: $count, ')
+          ..write('lastUsedAt: $lastUsedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $SyncErrorsTableTable extends SyncErrorsTable
+    with TableInfo<$SyncErrorsTableTable, SyncErrorsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $SyncErrorsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _itemIdMeta = const VerificationMeta('itemId');
+  @override
+  late final GeneratedColumn<String> itemId = GeneratedColumn<String>(
+    'item_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _itemTypeMeta = const VerificationMeta(
+    'itemType',
+  );
+  @override
+  late final GeneratedColumn<String> itemType = GeneratedColumn<String>(
+    'item_type',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _operationTypeMeta = const VerificationMeta(
+    'operationType',
+  );
+  @override
+  late final GeneratedColumn<String> operationType = GeneratedColumn<String>(
+    'operation_type',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _categoryMeta = const VerificationMeta(
+    'category',
+  );
+  @override
+  late final GeneratedColumn<String> category = GeneratedColumn<String>(
+    'category',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _messageMeta = const VerificationMeta(
+    'message',
+  );
+  @override
+  late final GeneratedColumn<String> message = GeneratedColumn<String>(
+    'message',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _retryCountMeta = const VerificationMeta(
+    'retryCount',
+  );
+  @override
+  late final GeneratedColumn<int> retryCount = GeneratedColumn<int>(
+    'retry_count',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: false,
+    defaultValue: const Constant(0),
+  );
+  static const VerificationMeta _failedAtMeta = const VerificationMeta(
+    'failedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> failedAt = GeneratedColumn<DateTime>(
+    'failed_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _ignoredMeta = const VerificationMeta(
+    'ignored',
+  );
+  @override
+  late final GeneratedColumn<bool> ignored = GeneratedColumn<bool>(
+    'ignored',
+    aliasedName,
+    false,
+    type: DriftSqlType.bool,
+    requiredDuringInsert: false,
+    defaultConstraints: GeneratedColumn.constraintIsAlways(
+      'CHECK ("ignored" IN (0, 1))',
+    ),
+    defaultValue: const Constant(false),
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    itemId,
+    itemType,
+    operationType,
+    category,
+    message,
+    retryCount,
+    failedAt,
+    ignored,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'sync_errors';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<SyncErrorsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('item_id')) {
+      context.handle(
+        _itemIdMeta,
+        itemId.isAcceptableOrUnknown(data['item_id']!, _itemIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_itemIdMeta);
+    }
+    if (data.containsKey('item_type')) {
+      context.handle(
+        _itemTypeMeta,
+        itemType.isAcceptableOrUnknown(data['item_type']!, _itemTypeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_itemTypeMeta);
+    }
+    if (data.containsKey('operation_type')) {
+      context.handle(
+        _operationTypeMeta,
+        operationType.isAcceptableOrUnknown(
+          data['operation_type']!,
+          _operationTypeMeta,
+        ),
+      );
+    } else if (isInserting) {
+      context.missing(_operationTypeMeta);
+    }
+    if (data.containsKey('category')) {
+      context.handle(
+        _categoryMeta,
+        category.isAcceptableOrUnknown(data['category']!, _categoryMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_categoryMeta);
+    }
+    if (data.containsKey('message')) {
+      context.handle(
+        _messageMeta,
+        message.isAcceptableOrUnknown(data['message']!, _messageMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_messageMeta);
+    }
+    if (data.containsKey('retry_count')) {
+      context.handle(
+        _retryCountMeta,
+        retryCount.isAcceptableOrUnknown(data['retry_count']!, _retryCountMeta),
+      );
+    }
+    if (data.containsKey('failed_at')) {
+      context.handle(
+        _failedAtMeta,
+        failedAt.isAcceptableOrUnknown(data['failed_at']!, _failedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_failedAtMeta);
+    }
+    if (data.containsKey('ignored')) {
+      context.handle(
+        _ignoredMeta,
+        ignored.isAcceptableOrUnknown(data['ignored']!, _ignoredMeta),
+      );
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {itemId};
+  @override
+  SyncErrorsTableData map(Map<String, dynamic> data, {String? tablePrefix}) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return SyncErrorsTableData(
+      itemId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_id'],
+      )!,
+      itemType: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}item_type'],
+      )!,
+      operationType: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}operation_type'],
+      )!,
+      category: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}category'],
+      )!,
+      message: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}message'],
+      )!,
+      retryCount: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}retry_count'],
+      )!,
+      failedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}failed_at'],
+      )!,
+      ignored: attachedDatabase.typeMapping.read(
+        DriftSqlType.bool,
+        data['${effectivePrefix}ignored'],
+      )!,
+    );
+  }
+
+  @override
+  $SyncErrorsTableTable createAlias(String alias) {
+    return $SyncErrorsTableTable(attachedDatabase, alias);
+  }
+}
+
+class SyncErrorsTableData extends DataClass
+    implements Insertable<SyncErrorsTableData> {
+  final String itemId;
+  final String itemType;
+  final String operationType;
+  final String category;
+  final String message;
+  final int retryCount;
+  final DateTime failedAt;
+  final bool ignored;
+  const SyncErrorsTableData({
+    required this.itemId,
+    required this.itemType,
+    required this.operationType,
+    required this.category,
+    required this.message,
+    required this.retryCount,
+    required this.failedAt,
+    required this.ignored,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['item_id'] = Variable<String>(itemId);
+    map['item_type'] = Variable<String>(itemType);
+    map['operation_type'] = Variable<String>(operationType);
+    map['category'] = Variable<String>(category);
+    map['message'] = Variable<String>(message);
+    map['retry_count'] = Variable<int>(retryCount);
+    map['failed_at'] = Variable<DateTime>(failedAt);
+    map['ignored'] = Variable<bool>(ignored);
+    return map;
+  }
+
+  SyncErrorsTableCompanion toCompanion(bool nullToAbsent) {
+    return SyncErrorsTableCompanion(
+      itemId: Value(itemId),
+      itemType: Value(itemType),
+      operationType: Value(operationType),
+      category: Value(category),
+      message: Value(message),
+      retryCount: Value(retryCount),
+      failedAt: Value(failedAt),
+      ignored: Value(ignored),
+    );
+  }
+
+  factory SyncErrorsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return SyncErrorsTableData(
+      itemId: serializer.fromJson<String>(json['itemId']),
+      itemType: serializer.fromJson<String>(json['itemType']),
+      operationType: serializer.fromJson<String>(json['operationType']),
+      category: serializer.fromJson<String>(json['category']),
+      message: serializer.fromJson<String>(json['message']),
+      retryCount: serializer.fromJson<int>(json['retryCount']),
+      failedAt: serializer.fromJson<DateTime>(json['failedAt']),
+      ignored: serializer.fromJson<bool>(json['ignored']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'itemId': serializer.toJson<String>(itemId),
+      'itemType': serializer.toJson<String>(itemType),
+      'operationType': serializer.toJson<String>(operationType),
+      'category': serializer.toJson<String>(category),
+      'message': serializer.toJson<String>(message),
+      'retryCount': serializer.toJson<int>(retryCount),
+      'failedAt': serializer.toJson<DateTime>(failedAt),
+      'ignored': serializer.toJson<bool>(ignored),
+    };
+  }
+
+  SyncErrorsTableData copyWith({
+    String? itemId,
+    String? itemType,
+    String? operationType,
+    String? category,
+    String? message,
+    int? retryCount,
+    DateTime? failedAt,
+    bool? ignored,
+  }) => SyncErrorsTableData(
+    itemId: itemId ?? this.itemId,
+    itemType: itemType ?? this.itemType,
+    operationType: operationType ?? this.operationType,
+    category: category ?? this.category,
+    message: message ?? this.message,
+    retryCount: retryCount ?? this.retryCount,
+    failedAt: failedAt ?? this.failedAt,
+    ignored: ignored ?? this.ignored,
+  );
+  SyncErrorsTableData copyWithCompanion(SyncErrorsTableCompanion data) {
+    return SyncErrorsTableData(
+      itemId: data.itemId.present ? data.itemId.value : this.itemId,
+      itemType: data.itemType.present ? data.itemType.value : this.itemType,
+      operationType: data.operationType.present
+          ? data.operationType.value
+          : this.operationType,
+      category: data.category.present ? data.category.value : this.category,
+      message: data.message.present ? data.message.value : this.message,
+      retryCount: data.retryCount.present
+          ? data.retryCount.value
+          : this.retryCount,
+      failedAt: data.failedAt.present ? data.failedAt.value : this.failedAt,
+      ignored: data.ignored.present ? data.ignored.value : this.ignored,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('SyncErrorsTableData(')
+          ..write('itemId: $itemId, ')
+          ..write('itemType: $itemType, ')
+          ..write('operationType: $operationType, ')
+          ..write('category: $category, ')
+          ..write('message: $message, ')
+          ..write('retryCount: $retryCount, ')
+          ..write('failedAt: $failedAt, ')
+          ..write('ignored: $ignored')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    itemId,
+    itemType,
+    operationType,
+    category,
+    message,
+    retryCount,
+    failedAt,
+    ignored,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is SyncErrorsTableData &&
+          other.itemId == this.itemId &&
+          other.itemType == this.itemType &&
+          other.operationType == this.operationType &&
+          other.category == this.category &&
+          other.message == this.message &&
+          other.retryCount == this.retryCount &&
+          other.failedAt == this.failedAt &&
+          other.ignored == this.ignored);
+}
+
+class SyncErrorsTableCompanion extends UpdateCompanion<SyncErrorsTableData> {
+  final Value<String> itemId;
+  final Value<String> itemType;
+  final Value<String> operationType;
+  final Value<String> category;
+  final Value<String> message;
+  final Value<int> retryCount;
+  final Value<DateTime> failedAt;
+  final Value<bool> ignored;
+  final Value<int> rowid;
+  const SyncErrorsTableCompanion({
+    this.itemId = const Value.absent(),
+    this.itemType = const Value.absent(),
+    this.operationType = const Value.absent(),
+    this.category = const Value.absent(),
+    this.message = const Value.absent(),
+    this.retryCount = const Value.absent(),
+    this.failedAt = const Value.absent(),
+    this.ignored = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  SyncErrorsTableCompanion.insert({
+    required String itemId,
+    required String itemType,
+    required String operationType,
+    required String category,
+    required String message,
+    this.retryCount = const Value.absent(),
+    required DateTime failedAt,
+    this.ignored = const Value.absent(),
+    this.rowid = const Value.absent(),
+  }) : itemId = Value(itemId),
+       itemType = Value(itemType),
+       operationType = Value(operationType),
+       category = Value(category),
+       message = Value(message),
+       failedAt = Value(failedAt);
+  static Insertable<SyncErrorsTableData> custom({
+    Expression<String>? itemId,
+    Expression<String>? itemType,
+    Expression<String>? operationType,
+    Expression<String>? category,
+    Expression<String>? message,
+    Expression<int>? retryCount,
+    Expression<DateTime>? failedAt,
+    Expression<bool>? ignored,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (itemId != null) 'item_id': itemId,
+      if (itemType != null) 'item_type': itemType,
+      if (operationType != null) 'operation_type': operationType,
+      if (category != null) 'category': category,
+      if (message != null) 'message': message,
+      if (retryCount != null) 'retry_count': retryCount,
+      if (failedAt != null) 'failed_at': failedAt,
+      if (ignored != null) 'ignored': ignored,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  SyncErrorsTableCompanion copyWith({
+    Value<String>? itemId,
+    Value<String>? itemType,
+    Value<String>? operationType,
+    Value<String>? category,
+    Value<String>? message,
+    Value<int>? retryCount,
+    Value<DateTime>? failedAt,
+    Value<bool>? ignored,
+    Value<int>? rowid,
+  }) {
+    return SyncErrorsTableCompanion(
+      itemId: itemId ?? this.itemId,
+      itemType: itemType ?? this.itemType,
+      operationType: operationType ?? this.operationType,
+      category: category ?? this.category,
+      message: message ?? this.message,
+      retryCount: retryCount ?? this.retryCount,
+      failedAt: failedAt ?? this.failedAt,
+      ignored: ignored ?? this.ignored,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (itemId.present) {
+      map['item_id'] = Variable<String>(itemId.value);
+    }
+    if (itemType.present) {
+      map['item_type'] = Variable<String>(itemType.value);
+    }
+    if (operationType.present) {
+      map['operation_type'] = Variable<String>(operationType.value);
+    }
+    if (category.present) {
+      map['category'] = Variable<String>(category.value);
+    }
+    if (message.present) {
+      map['message'] = Variable<String>(message.value);
+    }
+    if (retryCount.present) {
+      map['retry_count'] = Variable<int>(retryCount.value);
+    }
+    if (failedAt.present) {
+      map['failed_at'] = Variable<DateTime>(failedAt.value);
+    }
+    if (ignored.present) {
+      map['ignored'] = Variable<bool>(ignored.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('SyncErrorsTableCompanion(')
+          ..write('itemId: $itemId, ')
+          ..write('itemType: $itemType, ')
+          ..write('operationType: $operationType, ')
+          ..write('category: $category, ')
+          ..write('message: $message, ')
+          ..write('retryCount: $retryCount, ')
+          ..write('failedAt: $failedAt, ')
+          ..write('ignored: $ignored, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $PartialDownloadsTableTable extends PartialDownloadsTable
+    with TableInfo<$PartialDownloadsTableTable, PartialDownloadsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $PartialDownloadsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _fileIdMeta = const VerificationMeta('fileId');
+  @override
+  late final GeneratedColumn<String> fileId = GeneratedColumn<String>(
+    'file_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _pathMeta = const VerificationMeta('path');
+  @override
+  late final GeneratedColumn<String> path = GeneratedColumn<String>(
+    'path',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _targetPathMeta = const VerificationMeta(
+    'targetPath',
+  );
+  @override
+  late final GeneratedColumn<String> targetPath = GeneratedColumn<String>(
+    'target_path',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _etagMeta = const VerificationMeta('etag');
+  @override
+  late final GeneratedColumn<String> etag = GeneratedColumn<String>(
+    'etag',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _updatedAtMeta = const VerificationMeta(
+    'updatedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> updatedAt = GeneratedColumn<DateTime>(
+    'updated_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    fileId,
+    path,
+    targetPath,
+    etag,
+    updatedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'partial_downloads';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<PartialDownloadsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('file_id')) {
+      context.handle(
+        _fileIdMeta,
+        fileId.isAcceptableOrUnknown(data['file_id']!, _fileIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_fileIdMeta);
+    }
+    if (data.containsKey('path')) {
+      context.handle(
+        _pathMeta,
+        path.isAcceptableOrUnknown(data['path']!, _pathMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_pathMeta);
+    }
+    if (data.containsKey('target_path')) {
+      context.handle(
+        _targetPathMeta,
+        targetPath.isAcceptableOrUnknown(data['target_path']!, _targetPathMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_targetPathMeta);
+    }
+    if (data.containsKey('etag')) {
+      context.handle(
+        _etagMeta,
+        etag.isAcceptableOrUnknown(data['etag']!, _etagMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_etagMeta);
+    }
+    if (data.containsKey('updated_at')) {
+      context.handle(
+        _updatedAtMeta,
+        updatedAt.isAcceptableOrUnknown(data['updated_at']!, _updatedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_updatedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {fileId};
+  @override
+  PartialDownloadsTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return PartialDownloadsTableData(
+      fileId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}file_id'],
+      )!,
+      path: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}path'],
+      )!,
+      targetPath: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}target_path'],
+      )!,
+      etag: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}etag'],
+      )!,
+      updatedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}updated_at'],
+      )!,
+    );
+  }
+
+  @override
+  $PartialDownloadsTableTable createAlias(String alias) {
+    return $PartialDownloadsTableTable(attachedDatabase, alias);
+  }
+}
+
+class PartialDownloadsTableData extends DataClass
+    implements Insertable<PartialDownloadsTableData> {
+  final String fileId;
+  final String path;
+  final String targetPath;
+  final String etag;
+  final DateTime updatedAt;
+  const PartialDownloadsTableData({
+    required this.fileId,
+    required this.path,
+    required this.targetPath,
+    required this.etag,
+    required this.updatedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['file_id'] = Variable<String>(fileId);
+    map['path'] = Variable<String>(path);
+    map['target_path'] = Variable<String>(targetPath);
+    map['etag'] = Variable<String>(etag);
+    map['updated_at'] = Variable<DateTime>(updatedAt);
+    return map;
+  }
+
+  PartialDownloadsTableCompanion toCompanion(bool nullToAbsent) {
+    return PartialDownloadsTableCompanion(
+      fileId: Value(fileId),
+      path: Value(path),
+      targetPath: Value(targetPath),
+      etag: Value(etag),
+      updatedAt: Value(updatedAt),
+    );
+  }
+
+  factory PartialDownloadsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return PartialDownloadsTableData(
+      fileId: serializer.fromJson<String>(json['fileId']),
+      path: serializer.fromJson<String>(json['path']),
+      targetPath: serializer.fromJson<String>(json['targetPath']),
+      etag: serializer.fromJson<String>(json['etag']),
+      updatedAt: serializer.fromJson<DateTime>(json['updatedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'fileId': serializer.toJson<String>(fileId),
+      'path': serializer.toJson<String>(path),
+      'targetPath': serializer.toJson<String>(targetPath),
+      'etag': serializer.toJson<String>(etag),
+      'updatedAt': serializer.toJson<DateTime>(updatedAt),
+    };
+  }
+
+  PartialDownloadsTableData copyWith({
+    String? fileId,
+    String? path,
+    String? targetPath,
+    String? etag,
+    DateTime? updatedAt,
+  }) => PartialDownloadsTableData(
+    fileId: fileId ?? this.fileId,
+    path: path ?? this.path,
+    targetPath: targetPath ?? this.targetPath,
+    etag: etag ?? this.etag,
+    updatedAt: updatedAt ?? this.updatedAt,
+  );
+  PartialDownloadsTableData copyWithCompanion(
+    PartialDownloadsTableCompanion data,
+  ) {
+    return PartialDownloadsTableData(
+      fileId: data.fileId.present ? data.fileId.value : this.fileId,
+      path: data.path.present ? data.path.value : this.path,
+      targetPath: data.targetPath.present
+          ? data.targetPath.value
+          : this.targetPath,
+      etag: data.etag.present ? data.etag.value : this.etag,
+      updatedAt: data.updatedAt.present ? data.updatedAt.value : this.updatedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('PartialDownloadsTableData(')
+          ..write('fileId: $fileId, ')
+          ..write('path: $path, ')
+          ..write('targetPath: $targetPath, ')
+          ..write('etag: $etag, ')
+          ..write('updatedAt: $updatedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(fileId, path, targetPath, etag, updatedAt);
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is PartialDownloadsTableData &&
+          other.fileId == this.fileId &&
+          other.path == this.path &&
+          other.targetPath == this.targetPath &&
+          other.etag == this.etag &&
+          other.updatedAt == this.updatedAt);
+}
+
+class PartialDownloadsTableCompanion
+    extends UpdateCompanion<PartialDownloadsTableData> {
+  final Value<String> fileId;
+  final Value<String> path;
+  final Value<String> targetPath;
+  final Value<String> etag;
+  final Value<DateTime> updatedAt;
+  final Value<int> rowid;
+  const PartialDownloadsTableCompanion({
+    this.fileId = const Value.absent(),
+    this.path = const Value.absent(),
+    this.targetPath = const Value.absent(),
+    this.etag = const Value.absent(),
+    this.updatedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  PartialDownloadsTableCompanion.insert({
+    required String fileId,
+    required String path,
+    required String targetPath,
+    required String etag,
+    required DateTime updatedAt,
+    this.rowid = const Value.absent(),
+  }) : fileId = Value(fileId),
+       path = Value(path),
+       targetPath = Value(targetPath),
+       etag = Value(etag),
+       updatedAt = Value(updatedAt);
+  static Insertable<PartialDownloadsTableData> custom({
+    Expression<String>? fileId,
+    Expression<String>? path,
+    Expression<String>? targetPath,
+    Expression<String>? etag,
+    Expression<DateTime>? updatedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (fileId != null) 'file_id': fileId,
+      if (path != null) 'path': path,
+      if (targetPath != null) 'target_path': targetPath,
+      if (etag != null) 'etag': etag,
+      if (updatedAt != null) 'updated_at': updatedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  PartialDownloadsTableCompanion copyWith({
+    Value<String>? fileId,
+    Value<String>? path,
+    Value<String>? targetPath,
+    Value<String>? etag,
+    Value<DateTime>? updatedAt,
+    Value<int>? rowid,
+  }) {
+    return PartialDownloadsTableCompanion(
+      fileId: fileId ?? this.fileId,
+      path: path ?? this.path,
+      targetPath: targetPath ?? this.targetPath,
+      etag: etag ?? this.etag,
+      updatedAt: updatedAt ?? this.updatedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (fileId.present) {
+      map['file_id'] = Variable<String>(fileId.value);
+    }
+    if (path.present) {
+      map['path'] = Variable<String>(path.value);
+    }
+    if (targetPath.present) {
+      map['target_path'] = Variable<String>(targetPath.value);
+    }
+    if (etag.present) {
+      map['etag'] = Variable<String>(etag.value);
+    }
+    if (updatedAt.present) {
+      map['updated_at'] = Variable<DateTime>(updatedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('PartialDownloadsTableCompanion(')
+          ..write('fileId: $fileId, ')
+          ..write('path: $path, ')
+          ..write('targetPath: $targetPath, ')
+          ..write('etag: $etag, ')
+          ..write('updatedAt: $updatedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+class $ContentDigestsTableTable extends ContentDigestsTable
+    with TableInfo<$ContentDigestsTableTable, ContentDigestsTableData> {
+  @override
+  final GeneratedDatabase attachedDatabase;
+  final String? _alias;
+  $ContentDigestsTableTable(this.attachedDatabase, [this._alias]);
+  static const VerificationMeta _fileIdMeta = const VerificationMeta('fileId');
+  @override
+  late final GeneratedColumn<String> fileId = GeneratedColumn<String>(
+    'file_id',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _algorithmMeta = const VerificationMeta(
+    'algorithm',
+  );
+  @override
+  late final GeneratedColumn<String> algorithm = GeneratedColumn<String>(
+    'algorithm',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _digestMeta = const VerificationMeta('digest');
+  @override
+  late final GeneratedColumn<String> digest = GeneratedColumn<String>(
+    'digest',
+    aliasedName,
+    false,
+    type: DriftSqlType.string,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _sizeMeta = const VerificationMeta('size');
+  @override
+  late final GeneratedColumn<int> size = GeneratedColumn<int>(
+    'size',
+    aliasedName,
+    false,
+    type: DriftSqlType.int,
+    requiredDuringInsert: true,
+  );
+  static const VerificationMeta _etagMeta = const VerificationMeta('etag');
+  @override
+  late final GeneratedColumn<String> etag = GeneratedColumn<String>(
+    'etag',
+    aliasedName,
+    true,
+    type: DriftSqlType.string,
+    requiredDuringInsert: false,
+  );
+  static const VerificationMeta _recordedAtMeta = const VerificationMeta(
+    'recordedAt',
+  );
+  @override
+  late final GeneratedColumn<DateTime> recordedAt = GeneratedColumn<DateTime>(
+    'recorded_at',
+    aliasedName,
+    false,
+    type: DriftSqlType.dateTime,
+    requiredDuringInsert: true,
+  );
+  @override
+  List<GeneratedColumn> get $columns => [
+    fileId,
+    algorithm,
+    digest,
+    size,
+    etag,
+    recordedAt,
+  ];
+  @override
+  String get aliasedName => _alias ?? actualTableName;
+  @override
+  String get actualTableName => $name;
+  static const String $name = 'content_digests';
+  @override
+  VerificationContext validateIntegrity(
+    Insertable<ContentDigestsTableData> instance, {
+    bool isInserting = false,
+  }) {
+    final context = VerificationContext();
+    final data = instance.toColumns(true);
+    if (data.containsKey('file_id')) {
+      context.handle(
+        _fileIdMeta,
+        fileId.isAcceptableOrUnknown(data['file_id']!, _fileIdMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_fileIdMeta);
+    }
+    if (data.containsKey('algorithm')) {
+      context.handle(
+        _algorithmMeta,
+        algorithm.isAcceptableOrUnknown(data['algorithm']!, _algorithmMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_algorithmMeta);
+    }
+    if (data.containsKey('digest')) {
+      context.handle(
+        _digestMeta,
+        digest.isAcceptableOrUnknown(data['digest']!, _digestMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_digestMeta);
+    }
+    if (data.containsKey('size')) {
+      context.handle(
+        _sizeMeta,
+        size.isAcceptableOrUnknown(data['size']!, _sizeMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_sizeMeta);
+    }
+    if (data.containsKey('etag')) {
+      context.handle(
+        _etagMeta,
+        etag.isAcceptableOrUnknown(data['etag']!, _etagMeta),
+      );
+    }
+    if (data.containsKey('recorded_at')) {
+      context.handle(
+        _recordedAtMeta,
+        recordedAt.isAcceptableOrUnknown(data['recorded_at']!, _recordedAtMeta),
+      );
+    } else if (isInserting) {
+      context.missing(_recordedAtMeta);
+    }
+    return context;
+  }
+
+  @override
+  Set<GeneratedColumn> get $primaryKey => {fileId};
+  @override
+  ContentDigestsTableData map(
+    Map<String, dynamic> data, {
+    String? tablePrefix,
+  }) {
+    final effectivePrefix = tablePrefix != null ? '$tablePrefix.' : '';
+    return ContentDigestsTableData(
+      fileId: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}file_id'],
+      )!,
+      algorithm: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}algorithm'],
+      )!,
+      digest: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}digest'],
+      )!,
+      size: attachedDatabase.typeMapping.read(
+        DriftSqlType.int,
+        data['${effectivePrefix}size'],
+      )!,
+      etag: attachedDatabase.typeMapping.read(
+        DriftSqlType.string,
+        data['${effectivePrefix}etag'],
+      ),
+      recordedAt: attachedDatabase.typeMapping.read(
+        DriftSqlType.dateTime,
+        data['${effectivePrefix}recorded_at'],
+      )!,
+    );
+  }
+
+  @override
+  $ContentDigestsTableTable createAlias(String alias) {
+    return $ContentDigestsTableTable(attachedDatabase, alias);
+  }
+}
+
+class ContentDigestsTableData extends DataClass
+    implements Insertable<ContentDigestsTableData> {
+  final String fileId;
+  final String algorithm;
+  final String digest;
+  final int size;
+  final String? etag;
+  final DateTime recordedAt;
+  const ContentDigestsTableData({
+    required this.fileId,
+    required this.algorithm,
+    required this.digest,
+    required this.size,
+    this.etag,
+    required this.recordedAt,
+  });
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    map['file_id'] = Variable<String>(fileId);
+    map['algorithm'] = Variable<String>(algorithm);
+    map['digest'] = Variable<String>(digest);
+    map['size'] = Variable<int>(size);
+    if (!nullToAbsent || etag != null) {
+      map['etag'] = Variable<String>(etag);
+    }
+    map['recorded_at'] = Variable<DateTime>(recordedAt);
+    return map;
+  }
+
+  ContentDigestsTableCompanion toCompanion(bool nullToAbsent) {
+    return ContentDigestsTableCompanion(
+      fileId: Value(fileId),
+      algorithm: Value(algorithm),
+      digest: Value(digest),
+      size: Value(size),
+      etag: etag == null && nullToAbsent ? const Value.absent() : Value(etag),
+      recordedAt: Value(recordedAt),
+    );
+  }
+
+  factory ContentDigestsTableData.fromJson(
+    Map<String, dynamic> json, {
+    ValueSerializer? serializer,
+  }) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return ContentDigestsTableData(
+      fileId: serializer.fromJson<String>(json['fileId']),
+      algorithm: serializer.fromJson<String>(json['algorithm']),
+      digest: serializer.fromJson<String>(json['digest']),
+      size: serializer.fromJson<int>(json['size']),
+      etag: serializer.fromJson<String?>(json['etag']),
+      recordedAt: serializer.fromJson<DateTime>(json['recordedAt']),
+    );
+  }
+  @override
+  Map<String, dynamic> toJson({ValueSerializer? serializer}) {
+    serializer ??= driftRuntimeOptions.defaultSerializer;
+    return <String, dynamic>{
+      'fileId': serializer.toJson<String>(fileId),
+      'algorithm': serializer.toJson<String>(algorithm),
+      'digest': serializer.toJson<String>(digest),
+      'size': serializer.toJson<int>(size),
+      'etag': serializer.toJson<String?>(etag),
+      'recordedAt': serializer.toJson<DateTime>(recordedAt),
+    };
+  }
+
+  ContentDigestsTableData copyWith({
+    String? fileId,
+    String? algorithm,
+    String? digest,
+    int? size,
+    Value<String?> etag = const Value.absent(),
+    DateTime? recordedAt,
+  }) => ContentDigestsTableData(
+    fileId: fileId ?? this.fileId,
+    algorithm: algorithm ?? this.algorithm,
+    digest: digest ?? this.digest,
+    size: size ?? this.size,
+    etag: etag.present ? etag.value : this.etag,
+    recordedAt: recordedAt ?? this.recordedAt,
+  );
+  ContentDigestsTableData copyWithCompanion(ContentDigestsTableCompanion data) {
+    return ContentDigestsTableData(
+      fileId: data.fileId.present ? data.fileId.value : this.fileId,
+      algorithm: data.algorithm.present ? data.algorithm.value : this.algorithm,
+      digest: data.digest.present ? data.digest.value : this.digest,
+      size: data.size.present ? data.size.value : this.size,
+      etag: data.etag.present ? data.etag.value : this.etag,
+      recordedAt: data.recordedAt.present
+          ? data.recordedAt.value
+          : this.recordedAt,
+    );
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ContentDigestsTableData(')
+          ..write('fileId: $fileId, ')
+          ..write('algorithm: $algorithm, ')
+          ..write('digest: $digest, ')
+          ..write('size: $size, ')
+          ..write('etag: $etag, ')
+          ..write('recordedAt: $recordedAt')
+          ..write(')'))
+        .toString();
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    fileId,
+    algorithm,
+    digest,
+    size,
+    etag,
+    recordedAt,
+  );
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      (other is ContentDigestsTableData &&
+          other.fileId == this.fileId &&
+          other.algorithm == this.algorithm &&
+          other.digest == this.digest &&
+          other.size == this.size &&
+          other.etag == this.etag &&
+          other.recordedAt == this.recordedAt);
+}
+
+class ContentDigestsTableCompanion
+    extends UpdateCompanion<ContentDigestsTableData> {
+  final Value<String> fileId;
+  final Value<String> algorithm;
+  final Value<String> digest;
+  final Value<int> size;
+  final Value<String?> etag;
+  final Value<DateTime> recordedAt;
+  final Value<int> rowid;
+  const ContentDigestsTableCompanion({
+    this.fileId = const Value.absent(),
+    this.algorithm = const Value.absent(),
+    this.digest = const Value.absent(),
+    this.size = const Value.absent(),
+    this.etag = const Value.absent(),
+    this.recordedAt = const Value.absent(),
+    this.rowid = const Value.absent(),
+  });
+  ContentDigestsTableCompanion.insert({
+    required String fileId,
+    required String algorithm,
+    required String digest,
+    required int size,
+    this.etag = const Value.absent(),
+    required DateTime recordedAt,
+    this.rowid = const Value.absent(),
+  }) : fileId = Value(fileId),
+       algorithm = Value(algorithm),
+       digest = Value(digest),
+       size = Value(size),
+       recordedAt = Value(recordedAt);
+  static Insertable<ContentDigestsTableData> custom({
+    Expression<String>? fileId,
+    Expression<String>? algorithm,
+    Expression<String>? digest,
+    Expression<int>? size,
+    Expression<String>? etag,
+    Expression<DateTime>? recordedAt,
+    Expression<int>? rowid,
+  }) {
+    return RawValuesInsertable({
+      if (fileId != null) 'file_id': fileId,
+      if (algorithm != null) 'algorithm': algorithm,
+      if (digest != null) 'digest': digest,
+      if (size != null) 'size': size,
+      if (etag != null) 'etag': etag,
+      if (recordedAt != null) 'recorded_at': recordedAt,
+      if (rowid != null) 'rowid': rowid,
+    });
+  }
+
+  ContentDigestsTableCompanion copyWith({
+    Value<String>? fileId,
+    Value<String>? algorithm,
+    Value<String>? digest,
+    Value<int>? size,
+    Value<String?>? etag,
+    Value<DateTime>? recordedAt,
+    Value<int>? rowid,
+  }) {
+    return ContentDigestsTableCompanion(
+      fileId: fileId ?? this.fileId,
+      algorithm: algorithm ?? this.algorithm,
+      digest: digest ?? this.digest,
+      size: size ?? this.size,
+      etag: etag ?? this.etag,
+      recordedAt: recordedAt ?? this.recordedAt,
+      rowid: rowid ?? this.rowid,
+    );
+  }
+
+  @override
+  Map<String, Expression> toColumns(bool nullToAbsent) {
+    final map = <String, Expression>{};
+    if (fileId.present) {
+      map['file_id'] = Variable<String>(fileId.value);
+    }
+    if (algorithm.present) {
+      map['algorithm'] = Variable<String>(algorithm.value);
+    }
+    if (digest.present) {
+      map['digest'] = Variable<String>(digest.value);
+    }
+    if (size.present) {
+      map['size'] = Variable<int>(size.value);
+    }
+    if (etag.present) {
+      map['etag'] = Variable<String>(etag.value);
+    }
+    if (recordedAt.present) {
+      map['recorded_at'] = Variable<DateTime>(recordedAt.value);
+    }
+    if (rowid.present) {
+      map['rowid'] = Variable<int>(rowid.value);
+    }
+    return map;
+  }
+
+  @override
+  String toString() {
+    return (StringBuffer('ContentDigestsTableCompanion(')
+          ..write('fileId: $fileId, ')
+          ..write('algorithm: $algorithm, ')
+          ..write('digest: $digest, ')
+          ..write('size: $size, ')
+          ..write('etag: $etag, ')
+          ..write('recordedAt: $recordedAt, ')
+          ..write('rowid: $rowid')
+          ..write(')'))
+        .toString();
+  }
+}
+
+abstract class _$AppDatabase extends GeneratedDatabase {
+  _$AppDatabase(QueryExecutor e) : super(e);
+  $AppDatabaseManager get managers => $AppDatabaseManager(this);
+  late final $FilesTableTable filesTable = $FilesTableTable(this);
+  late final $FoldersTableTable foldersTable = $FoldersTableTable(this);
+  late final $SyncQueueTableTable syncQueueTable = $SyncQueueTableTable(this);
+  late final $SyncConflictsTableTable syncConflictsTable =
+      $SyncConflictsTableTable(this);
+  late final $CachedFilesTableTable cachedFilesTable = $CachedFilesTableTable(
+    this,
+  );
+  late final $UserTableTable userTable = $UserTableTable(this);
+  late final $UploadSessionsTableTable uploadSessionsTable =
+      $UploadSessionsTableTable(this);
+  late final $ActivityLogTableTable activityLogTable = $ActivityLogTableTable(
+    this,
+  );
+  late final $FolderScanStateTableTable folderScanStateTable =
+      $FolderScanStateTableTable(this);
+  late final $LinkedSharesTableTable linkedSharesTable =
+      $LinkedSharesTableTable(this);
+  late final $TransferLedgerTableTable transferLedgerTable =
+      $TransferLedgerTableTable(this);
+  late final $FileModesTableTable fileModesTable = $FileModesTableTable(this);
+  late final $DbLeaseTableTable dbLeaseTable = $DbLeaseTableTable(this);
+  late final $ConflictRulesTableTable conflictRulesTable =
+      $ConflictRulesTableTable(this);
+  late final $CachedListingsTableTable cachedListingsTable =
+      $CachedListingsTableTable(this);
+  late final $SyncExclusionsTableTable syncExclusionsTable =
+      $SyncExclusionsTableTable(this);
+  late final $FolderSyncModesTableTable folderSyncModesTable =
+      $FolderSyncModesTableTable(this);
+  late final $ConflictCopiesTableTable conflictCopiesTable =
+      $ConflictCopiesTableTable(this);
+  late final $InboxRulesTableTable inboxRulesTable = $InboxRulesTableTable(
+    this,
+  );
+  late final $InboxItemsTableTable inboxItemsTable = $InboxItemsTableTable(
+    this,
+  );
+  late final $SyncStatsTableTable syncStatsTable = $SyncStatsTableTable(this);
+  late final $FolderSyncStatsTableTable folderSyncStatsTable =
+      $FolderSyncStatsTableTable(this);
+  late final $OperationsTableTable operationsTable = $OperationsTableTable(
+    this,
+  );
+  late final $UsageCountersTableTable usageCountersTable =
+      $UsageCountersTableTable(this);
+  late final $SyncErrorsTableTable syncErrorsTable = $SyncErrorsTableTable(
+    this,
+  );
+  late final $PartialDownloadsTableTable partialDownloadsTable =
+      $PartialDownloadsTableTable(this);
+  late final $ContentDigestsTableTable contentDigestsTable =
+      $ContentDigestsTableTable(this);
+  @override
+  Iterable<TableInfo<Table, Object?>> get allTables =>
+      allSchemaEntities.whereType<TableInfo<Table, Object?>>();
+  @override
+  List<DatabaseSchemaEntity> get allSchemaEntities => [
+    filesTable,
+    foldersTable,
+    syncQueueTable,
+    syncConflictsTable,
+    cachedFilesTable,
+    userTable,
+    uploadSessionsTable,
+    activityLogTable,
+    folderScanStateTable,
+    linkedSharesTable,
+    transferLedgerTable,
+    fileModesTable,
+    dbLeaseTable,
+    conflictRulesTable,
+    cachedListingsTable,
+    syncExclusionsTable,
+    folderSyncModesTable,
+    conflictCopiesTable,
+    inboxRulesTable,
+    inboxItemsTable,
+    syncStatsTable,
+    folderSyncStatsTable,
+    operationsTable,
+    usageCountersTable,
+    syncErrorsTable,
+    partialDownloadsTable,
+    contentDigestsTable,
+  ];
+}
+
+typedef $$FilesTableTableCreateCompanionBuilder =
+    FilesTableCompanion Function({
+      required String id,
+      required String name,
+      required String path,
+      required int size,
+      required String mimeType,
+      Value<String?> folderId,
+      Value<String?> ownerId,
+      Value<String?> hash,
+      Value<String?> etag,
+      required DateTime createdAt,
+      required DateTime modifiedAt,
+      Value<DateTime?> syncedAt,
+      Value<bool> isFavorite,
+      Value<bool> isAvailableOffline,
+      Value<String?> localCachePath,
+      Value<String?> mountType,
+      Value<int> rowid,
+    });
+typedef $$FilesTableTableUpdateCompanionBuilder =
+    FilesTableCompanion Function({
+      Value<String> id,
+      Value<String> name,
+      Value<String> path,
+      Value<int> size,
+      Value<String> mimeType,
+      Value<String?> folderId,
+      Value<String?> ownerId,
+      Value<String?> hash,
+      Value<String?> etag,
+      Value<DateTime> createdAt,
+      Value<DateTime> modifiedAt,
+      Value<DateTime?> syncedAt,
+      Value<bool> isFavorite,
       Value<bool> isAvailableOffline,
       Value<String?> localCachePath,
+      Value<String?> mountType,
+      Value<int> rowid,
+    });
+
+class $$FilesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $FilesTableTable> {
+  $$FilesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get name => $composableBuilder(
+    column: $table.name,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get size => $composableBuilder(
+    column: $table.size,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get mimeType => $composableBuilder(
+    column: $table.mimeType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get ownerId => $composableBuilder(
+    column: $table.ownerId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get hash => $composableBuilder(
+    column: $table.hash,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get etag => $composableBuilder(
+    column: $table.etag,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get modifiedAt => $composableBuilder(
+    column: $table.modifiedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get syncedAt => $composableBuilder(
+    column: $table.syncedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<bool> get isFavorite => $composableBuilder(
+    column: $table.isFavorite,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<bool> get isAvailableOffline => $composableBuilder(
+    column: $table.isAvailableOffline,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get localCachePath => $composableBuilder(
+    column: $table.localCachePath,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get mountType => $composableBuilder(
+    column: $table.mountType,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$FilesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $FilesTableTable> {
+  $$FilesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get name => $composableBuilder(
+    column: $table.name,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get size => $composableBuilder(
+    column: $table.size,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get mimeType => $composableBuilder(
+    column: $table.mimeType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get ownerId => $composableBuilder(
+    column: $table.ownerId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get hash => $composableBuilder(
+    column: $table.hash,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get etag => $composableBuilder(
+    column: $table.etag,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get modifiedAt => $composableBuilder(
+    column: $table.modifiedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get syncedAt => $composableBuilder(
+    column: $table.syncedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<bool> get isFavorite => $composableBuilder(
+    column: $table.isFavorite,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<bool> get isAvailableOffline => $composableBuilder(
+    column: $table.isAvailableOffline,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get localCachePath => $composableBuilder(
+    column: $table.localCachePath,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get mountType => $composableBuilder(
+    column: $table.mountType,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$FilesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $FilesTableTable> {
+  $$FilesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get name =>
+      $composableBuilder(column: $table.name, builder: (column) => column);
+
+  GeneratedColumn<String> get path =>
+      $composableBuilder(column: $table.path, builder: (column) => column);
+
+  GeneratedColumn<int> get size =>
+      $composableBuilder(column: $table.size, builder: (column) => column);
+
+  GeneratedColumn<String> get mimeType =>
+      $composableBuilder(column: $table.mimeType, builder: (column) => column);
+
+  GeneratedColumn<String> get folderId =>
+      $composableBuilder(column: $table.folderId, builder: (column) => column);
+
+  GeneratedColumn<String> get ownerId =>
+      $composableBuilder(column: $table.ownerId, builder: (column) => column);
+
+  GeneratedColumn<String> get hash =>
+      $composableBuilder(column: $table.hash, builder: (column) => column);
+
+  GeneratedColumn<String> get etag =>
+      $composableBuilder(column: $table.etag, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get modifiedAt => $composableBuilder(
+    column: $table.modifiedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get syncedAt =>
+      $composableBuilder(column: $table.syncedAt, builder: (column) => column);
+
+  GeneratedColumn<bool> get isFavorite => $composableBuilder(
+    column: $table.isFavorite,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<bool> get isAvailableOffline => $composableBuilder(
+    column: $table.isAvailableOffline,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get localCachePath => $composableBuilder(
+    column: $table.localCachePath,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get mountType =>
+      $composableBuilder(column: $table.mountType, builder: (column) => column);
+}
+
+class $$FilesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $FilesTableTable,
+          FilesTableData,
+          $$FilesTableTableFilterComposer,
+          $$FilesTableTableOrderingComposer,
+          $$FilesTableTableAnnotationComposer,
+          $$FilesTableTableCreateCompanionBuilder,
+          $$FilesTableTableUpdateCompanionBuilder,
+          (
+            FilesTableData,
+            BaseReferences<_$AppDatabase, $FilesTableTable, FilesTableData>,
+          ),
+          FilesTableData,
+          PrefetchHooks Function()
+        > {
+  $$FilesTableTableTableManager(_$AppDatabase db, $FilesTableTable table)
+    : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$FilesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$FilesTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$FilesTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<String> id = const Value.absent(),
+                Value<String> name = const Value.absent(),
+                Value<String> path = const Value.absent(),
+                Value<int> size = const Value.absent(),
+                Value<String> mimeType = const Value.absent(),
+                Value<String?> folderId = const Value.absent(),
+                Value<String?> ownerId = const Value.absent(),
+                Value<String?> hash = const Value.absent(),
+                Value<String?> etag = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<DateTime> modifiedAt = const Value.absent(),
+                Value<DateTime?> syncedAt = const Value.absent(),
+                Value<bool> isFavorite = const Value.absent(),
+                Value<bool> isAvailableOffline = const Value.absent(),
+                Value<String?> localCachePath = const Value.absent(),
+                Value<String?> mountType = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FilesTableCompanion(
+                id: id,
+                name: name,
+                path: path,
+                size: size,
+                mimeType: mimeType,
+                folderId: folderId,
+                ownerId: ownerId,
+                hash: hash,
+                etag: etag,
+                createdAt: createdAt,
+                modifiedAt: modifiedAt,
+                syncedAt: syncedAt,
+                isFavorite: isFavorite,
+                isAvailableOffline: isAvailableOffline,
+                localCachePath: localCachePath,
+                mountType: mountType,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String id,
+                required String name,
+                required String path,
+                required int size,
+                required String mimeType,
+                Value<String?> folderId = const Value.absent(),
+                Value<String?> ownerId = const Value.absent(),
+                Value<String?> hash = const Value.absent(),
+                Value<String?> etag = const Value.absent(),
+                required DateTime createdAt,
+                required DateTime modifiedAt,
+                Value<DateTime?> syncedAt = const Value.absent(),
+                Value<bool> isFavorite = const Value.absent(),
+                Value<bool> isAvailableOffline = const Value.absent(),
+                Value<String?> localCachePath = const Value.absent(),
+                Value<String?> mountType = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FilesTableCompanion.insert(
+                id: id,
+                name: name,
+                path: path,
+                size: size,
+                mimeType: mimeType,
+                folderId: folderId,
+                ownerId: ownerId,
+                hash: hash,
+                etag: etag,
+                createdAt: createdAt,
+                modifiedAt: modifiedAt,
+                syncedAt: syncedAt,
+                isFavorite: isFavorite,
+                isAvailableOffline: isAvailableOffline,
+                localCachePath: localCachePath,
+                mountType: mountType,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$FilesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $FilesTableTable,
+      FilesTableData,
+      $$FilesTableTableFilterComposer,
+      $$FilesTableTableOrderingComposer,
+      $$FilesTableTableAnnotationComposer,
+      $$FilesTableTableCreateCompanionBuilder,
+      $$FilesTableTableUpdateCompanionBuilder,
+      (
+        FilesTableData,
+        BaseReferences<_$AppDatabase, $FilesTableTable, FilesTableData>,
+      ),
+      FilesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$FoldersTableTableCreateCompanionBuilder =
+    FoldersTableCompanion Function({
+      required String id,
+      required String name,
+      required String path,
+      Value<String?> parentId,
+      Value<String?> ownerId,
+      Value<bool> isRoot,
+      required DateTime createdAt,
+      required DateTime modifiedAt,
+      Value<DateTime?> syncedAt,
+      Value<String?> mountType,
+      Value<int> rowid,
+    });
+typedef $$FoldersTableTableUpdateCompanionBuilder =
+    FoldersTableCompanion Function({
+      Value<String> id,
+      Value<String> name,
+      Value<String> path,
+      Value<String?> parentId,
+      Value<String?> ownerId,
+      Value<bool> isRoot,
+      Value<DateTime> createdAt,
+      Value<DateTime> modifiedAt,
+      Value<DateTime?> syncedAt,
+      Value<String?> mountType,
+      Value<int> rowid,
+    });
+
+class $$FoldersTableTableFilterComposer
+    extends Composer<_$AppDatabase, $FoldersTableTable> {
+  $$FoldersTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get name => $composableBuilder(
+    column: $table.name,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get parentId => $composableBuilder(
+    column: $table.parentId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get ownerId => $composableBuilder(
+    column: $table.ownerId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<bool> get isRoot => $composableBuilder(
+    column: $table.isRoot,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get modifiedAt => $composableBuilder(
+    column: $table.modifiedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get syncedAt => $composableBuilder(
+    column: $table.syncedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get mountType => $composableBuilder(
+    column: $table.mountType,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$FoldersTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $FoldersTableTable> {
+  $$FoldersTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get name => $composableBuilder(
+    column: $table.name,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get parentId => $composableBuilder(
+    column: $table.parentId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get ownerId => $composableBuilder(
+    column: $table.ownerId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<bool> get isRoot => $composableBuilder(
+    column: $table.isRoot,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get modifiedAt => $composableBuilder(
+    column: $table.modifiedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get syncedAt => $composableBuilder(
+    column: $table.syncedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get mountType => $composableBuilder(
+    column: $table.mountType,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$FoldersTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $FoldersTableTable> {
+  $$FoldersTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get name =>
+      $composableBuilder(column: $table.name, builder: (column) => column);
+
+  GeneratedColumn<String> get path =>
+      $composableBuilder(column: $table.path, builder: (column) => column);
+
+  GeneratedColumn<String> get parentId =>
+      $composableBuilder(column: $table.parentId, builder: (column) => column);
+
+  GeneratedColumn<String> get ownerId =>
+      $composableBuilder(column: $table.ownerId, builder: (column) => column);
+
+  GeneratedColumn<bool> get isRoot =>
+      $composableBuilder(column: $table.isRoot, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get modifiedAt => $composableBuilder(
+    column: $table.modifiedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get syncedAt =>
+      $composableBuilder(column: $table.syncedAt, builder: (column) => column);
+
+  GeneratedColumn<String> get mountType =>
+      $composableBuilder(column: $table.mountType, builder: (column) => column);
+}
+
+class $$FoldersTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $FoldersTableTable,
+          FoldersTableData,
+          $$FoldersTableTableFilterComposer,
+          $$FoldersTableTableOrderingComposer,
+          $$FoldersTableTableAnnotationComposer,
+          $$FoldersTableTableCreateCompanionBuilder,
+          $$FoldersTableTableUpdateCompanionBuilder,
+          (
+            FoldersTableData,
+            BaseReferences<_$AppDatabase, $FoldersTableTable, FoldersTableData>,
+          ),
+          FoldersTableData,
+          PrefetchHooks Function()
+        > {
+  $$FoldersTableTableTableManager(_$AppDatabase db, $FoldersTableTable table)
+    : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$FoldersTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$FoldersTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$FoldersTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<String> id = const Value.absent(),
+                Value<String> name = const Value.absent(),
+                Value<String> path = const Value.absent(),
+                Value<String?> parentId = const Value.absent(),
+                Value<String?> ownerId = const Value.absent(),
+                Value<bool> isRoot = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<DateTime> modifiedAt = const Value.absent(),
+                Value<DateTime?> syncedAt = const Value.absent(),
+                Value<String?> mountType = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FoldersTableCompanion(
+                id: id,
+                name: name,
+                path: path,
+                parentId: parentId,
+                ownerId: ownerId,
+                isRoot: isRoot,
+                createdAt: createdAt,
+                modifiedAt: modifiedAt,
+                syncedAt: syncedAt,
+                mountType: mountType,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String id,
+                required String name,
+                required String path,
+                Value<String?> parentId = const Value.absent(),
+                Value<String?> ownerId = const Value.absent(),
+                Value<bool> isRoot = const Value.absent(),
+                required DateTime createdAt,
+                required DateTime modifiedAt,
+                Value<DateTime?> syncedAt = const Value.absent(),
+                Value<String?> mountType = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FoldersTableCompanion.insert(
+                id: id,
+                name: name,
+                path: path,
+                parentId: parentId,
+                ownerId: ownerId,
+                isRoot: isRoot,
+                createdAt: createdAt,
+                modifiedAt: modifiedAt,
+                syncedAt: syncedAt,
+                mountType: mountType,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$FoldersTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $FoldersTableTable,
+      FoldersTableData,
+      $$FoldersTableTableFilterComposer,
+      $$FoldersTableTableOrderingComposer,
+      $$FoldersTableTableAnnotationComposer,
+      $$FoldersTableTableCreateCompanionBuilder,
+      $$FoldersTableTableUpdateCompanionBuilder,
+      (
+        FoldersTableData,
+        BaseReferences<_$AppDatabase, $FoldersTableTable, FoldersTableData>,
+      ),
+      FoldersTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$SyncQueueTableTableCreateCompanionBuilder =
+    SyncQueueTableCompanion Function({
+      Value<int> id,
+      required String operationType,
+      Value<String> status,
+      required String itemId,
+      required String itemType,
+      required String payload,
+      Value<int> retryCount,
+      Value<int> transientRetryCount,
+      Value<String?> errorMessage,
+      required DateTime createdAt,
+      required DateTime updatedAt,
+      Value<DateTime?> scheduledAt,
+      Value<int> priority,
+    });
+typedef $$SyncQueueTableTableUpdateCompanionBuilder =
+    SyncQueueTableCompanion Function({
+      Value<int> id,
+      Value<String> operationType,
+      Value<String> status,
+      Value<String> itemId,
+      Value<String> itemType,
+      Value<String> payload,
+      Value<int> retryCount,
+      Value<int> transientRetryCount,
+      Value<String?> errorMessage,
+      Value<DateTime> createdAt,
+      Value<DateTime> updatedAt,
+      Value<DateTime?> scheduledAt,
+      Value<int> priority,
+    });
+
+class $$SyncQueueTableTableFilterComposer
+    extends Composer<_$AppDatabase, $SyncQueueTableTable> {
+  $$SyncQueueTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get operationType => $composableBuilder(
+    column: $table.operationType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get status => $composableBuilder(
+    column: $table.status,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemType => $composableBuilder(
+    column: $table.itemType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get payload => $composableBuilder(
+    column: $table.payload,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get retryCount => $composableBuilder(
+    column: $table.retryCount,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get transientRetryCount => $composableBuilder(
+    column: $table.transientRetryCount,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get errorMessage => $composableBuilder(
+    column: $table.errorMessage,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get updatedAt => $composableBuilder(
+    column: $table.updatedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get scheduledAt => $composableBuilder(
+    column: $table.scheduledAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get priority => $composableBuilder(
+    column: $table.priority,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$SyncQueueTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $SyncQueueTableTable> {
+  $$SyncQueueTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get operationType => $composableBuilder(
+    column: $table.operationType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get status => $composableBuilder(
+    column: $table.status,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemType => $composableBuilder(
+    column: $table.itemType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get payload => $composableBuilder(
+    column: $table.payload,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get retryCount => $composableBuilder(
+    column: $table.retryCount,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get transientRetryCount => $composableBuilder(
+    column: $table.transientRetryCount,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get errorMessage => $composableBuilder(
+    column: $table.errorMessage,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get updatedAt => $composableBuilder(
+    column: $table.updatedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get scheduledAt => $composableBuilder(
+    column: $table.scheduledAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get priority => $composableBuilder(
+    column: $table.priority,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$SyncQueueTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $SyncQueueTableTable> {
+  $$SyncQueueTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get operationType => $composableBuilder(
+    column: $table.operationType,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get status =>
+      $composableBuilder(column: $table.status, builder: (column) => column);
+
+  GeneratedColumn<String> get itemId =>
+      $composableBuilder(column: $table.itemId, builder: (column) => column);
+
+  GeneratedColumn<String> get itemType =>
+      $composableBuilder(column: $table.itemType, builder: (column) => column);
+
+  GeneratedColumn<String> get payload =>
+      $composableBuilder(column: $table.payload, builder: (column) => column);
+
+  GeneratedColumn<int> get retryCount => $composableBuilder(
+    column: $table.retryCount,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get transientRetryCount => $composableBuilder(
+    column: $table.transientRetryCount,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get errorMessage => $composableBuilder(
+    column: $table.errorMessage,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get updatedAt =>
+      $composableBuilder(column: $table.updatedAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get scheduledAt => $composableBuilder(
+    column: $table.scheduledAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get priority =>
+      $composableBuilder(column: $table.priority, builder: (column) => column);
+}
+
+class $$SyncQueueTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $SyncQueueTableTable,
+          SyncQueueTableData,
+          $$SyncQueueTableTableFilterComposer,
+          $$SyncQueueTableTableOrderingComposer,
+          $$SyncQueueTableTableAnnotationComposer,
+          $$SyncQueueTableTableCreateCompanionBuilder,
+          $$SyncQueueTableTableUpdateCompanionBuilder,
+          (
+            SyncQueueTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $SyncQueueTableTable,
+              SyncQueueTableData
+            >,
+          ),
+          SyncQueueTableData,
+          PrefetchHooks Function()
+        > {
+  $$SyncQueueTableTableTableManager(
+    _$AppDatabase db,
+    $SyncQueueTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$SyncQueueTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$SyncQueueTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$SyncQueueTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String> operationType = const Value.absent(),
+                Value<String> status = const Value.absent(),
+                Value<String> itemId = const Value.absent(),
+                Value<String> itemType = const Value.absent(),
+                Value<String> payload = const Value.absent(),
+                Value<int> retryCount = const Value.absent(),
+                Value<int> transientRetryCount = const Value.absent(),
+                Value<String?> errorMessage = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<DateTime> updatedAt = const Value.absent(),
+                Value<DateTime?> scheduledAt = const Value.absent(),
+                Value<int> priority = const Value.absent(),
+              }) => SyncQueueTableCompanion(
+                id: id,
+                operationType: operationType,
+                status: status,
+                itemId: itemId,
+                itemType: itemType,
+                payload: payload,
+                retryCount: retryCount,
+                transientRetryCount: transientRetryCount,
+                errorMessage: errorMessage,
+                createdAt: createdAt,
+                updatedAt: updatedAt,
+                scheduledAt: scheduledAt,
+                priority: priority,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                required String operationType,
+                Value<String> status = const Value.absent(),
+                required String itemId,
+                required String itemType,
+                required String payload,
+                Value<int> retryCount = const Value.absent(),
+                Value<int> transientRetryCount = const Value.absent(),
+                Value<String?> errorMessage = const Value.absent(),
+                required DateTime createdAt,
+                required DateTime updatedAt,
+                Value<DateTime?> scheduledAt = const Value.absent(),
+                Value<int> priority = const Value.absent(),
+              }) => SyncQueueTableCompanion.insert(
+                id: id,
+                operationType: operationType,
+                status: status,
+                itemId: itemId,
+                itemType: itemType,
+                payload: payload,
+                retryCount: retryCount,
+                transientRetryCount: transientRetryCount,
+                errorMessage: errorMessage,
+                createdAt: createdAt,
+                updatedAt: updatedAt,
+                scheduledAt: scheduledAt,
+                priority: priority,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$SyncQueueTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $SyncQueueTableTable,
+      SyncQueueTableData,
+      $$SyncQueueTableTableFilterComposer,
+      $$SyncQueueTableTableOrderingComposer,
+      $$SyncQueueTableTableAnnotationComposer,
+      $$SyncQueueTableTableCreateCompanionBuilder,
+      $$SyncQueueTableTableUpdateCompanionBuilder,
+      (
+        SyncQueueTableData,
+        BaseReferences<_$AppDatabase, $SyncQueueTableTable, SyncQueueTableData>,
+      ),
+      SyncQueueTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$SyncConflictsTableTableCreateCompanionBuilder =
+    SyncConflictsTableCompanion Function({
+      Value<int> id,
+      required String itemId,
+      required String itemType,
+      required String operationType,
+      Value<String> conflictType,
+      required String payload,
+      Value<String?> errorMessage,
+      Value<String?> resolution,
+      Value<DateTime?> resolvedAt,
+      Value<String?> resolvedBy,
+      required DateTime createdAt,
+      Value<DateTime?> queuedAt,
+    });
+typedef $$SyncConflictsTableTableUpdateCompanionBuilder =
+    SyncConflictsTableCompanion Function({
+      Value<int> id,
+      Value<String> itemId,
+      Value<String> itemType,
+      Value<String> operationType,
+      Value<String> conflictType,
+      Value<String> payload,
+      Value<String?> errorMessage,
+      Value<String?> resolution,
+      Value<DateTime?> resolvedAt,
+      Value<String?> resolvedBy,
+      Value<DateTime> createdAt,
+      Value<DateTime?> queuedAt,
+    });
+
+class $$SyncConflictsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $SyncConflictsTableTable> {
+  $$SyncConflictsTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemType => $composableBuilder(
+    column: $table.itemType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get operationType => $composableBuilder(
+    column: $table.operationType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get conflictType => $composableBuilder(
+    column: $table.conflictType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get payload => $composableBuilder(
+    column: $table.payload,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get errorMessage => $composableBuilder(
+    column: $table.errorMessage,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get resolution => $composableBuilder(
+    column: $table.resolution,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get resolvedAt => $composableBuilder(
+    column: $table.resolvedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get resolvedBy => $composableBuilder(
+    column: $table.resolvedBy,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get queuedAt => $composableBuilder(
+    column: $table.queuedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$SyncConflictsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $SyncConflictsTableTable> {
+  $$SyncConflictsTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemType => $composableBuilder(
+    column: $table.itemType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get operationType => $composableBuilder(
+    column: $table.operationType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get conflictType => $composableBuilder(
+    column: $table.conflictType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get payload => $composableBuilder(
+    column: $table.payload,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get errorMessage => $composableBuilder(
+    column: $table.errorMessage,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get resolution => $composableBuilder(
+    column: $table.resolution,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get resolvedAt => $composableBuilder(
+    column: $table.resolvedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get resolvedBy => $composableBuilder(
+    column: $table.resolvedBy,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get queuedAt => $composableBuilder(
+    column: $table.queuedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$SyncConflictsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $SyncConflictsTableTable> {
+  $$SyncConflictsTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get itemId =>
+      $composableBuilder(column: $table.itemId, builder: (column) => column);
+
+  GeneratedColumn<String> get itemType =>
+      $composableBuilder(column: $table.itemType, builder: (column) => column);
+
+  GeneratedColumn<String> get operationType => $composableBuilder(
+    column: $table.operationType,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get conflictType => $composableBuilder(
+    column: $table.conflictType,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get payload =>
+      $composableBuilder(column: $table.payload, builder: (column) => column);
+
+  GeneratedColumn<String> get errorMessage => $composableBuilder(
+    column: $table.errorMessage,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get resolution => $composableBuilder(
+    column: $table.resolution,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get resolvedAt => $composableBuilder(
+    column: $table.resolvedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get resolvedBy => $composableBuilder(
+    column: $table.resolvedBy,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get queuedAt =>
+      $composableBuilder(column: $table.queuedAt, builder: (column) => column);
+}
+
+class $$SyncConflictsTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $SyncConflictsTableTable,
+          SyncConflictsTableData,
+          $$SyncConflictsTableTableFilterComposer,
+          $$SyncConflictsTableTableOrderingComposer,
+          $$SyncConflictsTableTableAnnotationComposer,
+          $$SyncConflictsTableTableCreateCompanionBuilder,
+          $$SyncConflictsTableTableUpdateCompanionBuilder,
+          (
+            SyncConflictsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $SyncConflictsTableTable,
+              SyncConflictsTableData
+            >,
+          ),
+          SyncConflictsTableData,
+          PrefetchHooks Function()
+        > {
+  $$SyncConflictsTableTableTableManager(
+    _$AppDatabase db,
+    $SyncConflictsTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$SyncConflictsTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$SyncConflictsTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$SyncConflictsTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String> itemId = const Value.absent(),
+                Value<String> itemType = const Value.absent(),
+                Value<String> operationType = const Value.absent(),
+                Value<String> conflictType = const Value.absent(),
+                Value<String> payload = const Value.absent(),
+                Value<String?> errorMessage = const Value.absent(),
+                Value<String?> resolution = const Value.absent(),
+                Value<DateTime?> resolvedAt = const Value.absent(),
+                Value<String?> resolvedBy = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<DateTime?> queuedAt = const Value.absent(),
+              }) => SyncConflictsTableCompanion(
+                id: id,
+                itemId: itemId,
+                itemType: itemType,
+                operationType: operationType,
+                conflictType: conflictType,
+                payload: payload,
+                errorMessage: errorMessage,
+                resolution: resolution,
+                resolvedAt: resolvedAt,
+                resolvedBy: resolvedBy,
+                createdAt: createdAt,
+                queuedAt: queuedAt,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                required String itemId,
+                required String itemType,
+                required String operationType,
+                Value<String> conflictType = const Value.absent(),
+                required String payload,
+                Value<String?> errorMessage = const Value.absent(),
+                Value<String?> resolution = const Value.absent(),
+                Value<DateTime?> resolvedAt = const Value.absent(),
+                Value<String?> resolvedBy = const Value.absent(),
+                required DateTime createdAt,
+                Value<DateTime?> queuedAt = const Value.absent(),
+              }) => SyncConflictsTableCompanion.insert(
+                id: id,
+                itemId: itemId,
+                itemType: itemType,
+                operationType: operationType,
+                conflictType: conflictType,
+                payload: payload,
+                errorMessage: errorMessage,
+                resolution: resolution,
+                resolvedAt: resolvedAt,
+                resolvedBy: resolvedBy,
+                createdAt: createdAt,
+                queuedAt: queuedAt,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$SyncConflictsTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $SyncConflictsTableTable,
+      SyncConflictsTableData,
+      $$SyncConflictsTableTableFilterComposer,
+      $$SyncConflictsTableTableOrderingComposer,
+      $$SyncConflictsTableTableAnnotationComposer,
+      $$SyncConflictsTableTableCreateCompanionBuilder,
+      $$SyncConflictsTableTableUpdateCompanionBuilder,
+      (
+        SyncConflictsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $SyncConflictsTableTable,
+          SyncConflictsTableData
+        >,
+      ),
+      SyncConflictsTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$CachedFilesTableTableCreateCompanionBuilder =
+    CachedFilesTableCompanion Function({
+      required String fileId,
+      required String localPath,
+      required int sizeBytes,
+      Value<String?> hash,
+      required DateTime cachedAt,
+      required DateTime lastAccessedAt,
+      Value<bool> isPinned,
+      Value<String?> etag,
+      Value<String?> baseContent,
+      Value<String> state,
+      Value<DateTime?> verifiedAt,
+      Value<int> rowid,
+    });
+typedef $$CachedFilesTableTableUpdateCompanionBuilder =
+    CachedFilesTableCompanion Function({
+      Value<String> fileId,
+      Value<String> localPath,
+      Value<int> sizeBytes,
+      Value<String?> hash,
+      Value<DateTime> cachedAt,
+      Value<DateTime> lastAccessedAt,
+      Value<bool> isPinned,
+      Value<String?> etag,
+      Value<String?> baseContent,
+      Value<String> state,
+      Value<DateTime?> verifiedAt,
+      Value<int> rowid,
+    });
+
+class $$CachedFilesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $CachedFilesTableTable> {
+  $$CachedFilesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get fileId => $composableBuilder(
+    column: $table.fileId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get localPath => $composableBuilder(
+    column: $table.localPath,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get sizeBytes => $composableBuilder(
+    column: $table.sizeBytes,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get hash => $composableBuilder(
+    column: $table.hash,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get cachedAt => $composableBuilder(
+    column: $table.cachedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get lastAccessedAt => $composableBuilder(
+    column: $table.lastAccessedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<bool> get isPinned => $composableBuilder(
+    column: $table.isPinned,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get etag => $composableBuilder(
+    column: $table.etag,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get baseContent => $composableBuilder(
+    column: $table.baseContent,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get state => $composableBuilder(
+    column: $table.state,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get verifiedAt => $composableBuilder(
+    column: $table.verifiedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$CachedFilesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $CachedFilesTableTable> {
+  $$CachedFilesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get fileId => $composableBuilder(
+    column: $table.fileId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get localPath => $composableBuilder(
+    column: $table.localPath,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get sizeBytes => $composableBuilder(
+    column: $table.sizeBytes,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get hash => $composableBuilder(
+    column: $table.hash,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get cachedAt => $composableBuilder(
+    column: $table.cachedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get lastAccessedAt => $composableBuilder(
+    column: $table.lastAccessedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<bool> get isPinned => $composableBuilder(
+    column: $table.isPinned,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get etag => $composableBuilder(
+    column: $table.etag,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get baseContent => $composableBuilder(
+    column: $table.baseContent,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get state => $composableBuilder(
+    column: $table.state,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get verifiedAt => $composableBuilder(
+    column: $table.verifiedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$CachedFilesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $CachedFilesTableTable> {
+  $$CachedFilesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get fileId =>
+      $composableBuilder(column: $table.fileId, builder: (column) => column);
+
+  GeneratedColumn<String> get localPath =>
+      $composableBuilder(column: $table.localPath, builder: (column) => column);
+
+  GeneratedColumn<int> get sizeBytes =>
+      $composableBuilder(column: $table.sizeBytes, builder: (column) => column);
+
+  GeneratedColumn<String> get hash =>
+      $composableBuilder(column: $table.hash, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get cachedAt =>
+      $composableBuilder(column: $table.cachedAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get lastAccessedAt => $composableBuilder(
+    column: $table.lastAccessedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<bool> get isPinned =>
+      $composableBuilder(column: $table.isPinned, builder: (column) => column);
+
+  GeneratedColumn<String> get etag =>
+      $composableBuilder(column: $table.etag, builder: (column) => column);
+
+  GeneratedColumn<String> get baseContent => $composableBuilder(
+    column: $table.baseContent,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get state =>
+      $composableBuilder(column: $table.state, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get verifiedAt => $composableBuilder(
+    column: $table.verifiedAt,
+    builder: (column) => column,
+  );
+}
+
+class $$CachedFilesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $CachedFilesTableTable,
+          CachedFilesTableData,
+          $$CachedFilesTableTableFilterComposer,
+          $$CachedFilesTableTableOrderingComposer,
+          $$CachedFilesTableTableAnnotationComposer,
+          $$CachedFilesTableTableCreateCompanionBuilder,
+          $$CachedFilesTableTableUpdateCompanionBuilder,
+          (
+            CachedFilesTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $CachedFilesTableTable,
+              CachedFilesTableData
+            >,
+          ),
+          CachedFilesTableData,
+          PrefetchHooks Function()
+        > {
+  $$CachedFilesTableTableTableManager(
+    _$AppDatabase db,
+    $CachedFilesTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$CachedFilesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$CachedFilesTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$CachedFilesTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<String> fileId = const Value.absent(),
+                Value<String> localPath = const Value.absent(),
+                Value<int> sizeBytes = const Value.absent(),
+                Value<String?> hash = const Value.absent(),
+                Value<DateTime> cachedAt = const Value.absent(),
+                Value<DateTime> lastAccessedAt = const Value.absent(),
+                Value<bool> isPinned = const Value.absent(),
+                Value<String?> etag = const Value.absent(),
+                Value<String?> baseContent = const Value.absent(),
+                Value<String> state = const Value.absent(),
+                Value<DateTime?> verifiedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => CachedFilesTableCompanion(
+                fileId: fileId,
+                localPath: localPath,
+                sizeBytes: sizeBytes,
+                hash: hash,
+                cachedAt: cachedAt,
+                lastAccessedAt: lastAccessedAt,
+                isPinned: isPinned,
+                etag: etag,
+                baseContent: baseContent,
+                state: state,
+                verifiedAt: verifiedAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String fileId,
+                required String localPath,
+                required int sizeBytes,
+                Value<String?> hash = const Value.absent(),
+                required DateTime cachedAt,
+                required DateTime lastAccessedAt,
+                Value<bool> isPinned = const Value.absent(),
+                Value<String?> etag = const Value.absent(),
+                Value<String?> baseContent = const Value.absent(),
+                Value<String> state = const Value.absent(),
+                Value<DateTime?> verifiedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => CachedFilesTableCompanion.insert(
+                fileId: fileId,
+                localPath: localPath,
+                sizeBytes: sizeBytes,
+                hash: hash,
+                cachedAt: cachedAt,
+                lastAccessedAt: lastAccessedAt,
+                isPinned: isPinned,
+                etag: etag,
+                baseContent: baseContent,
+                state: state,
+                verifiedAt: verifiedAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$CachedFilesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $CachedFilesTableTable,
+      CachedFilesTableData,
+      $$CachedFilesTableTableFilterComposer,
+      $$CachedFilesTableTableOrderingComposer,
+      $$CachedFilesTableTableAnnotationComposer,
+      $$CachedFilesTableTableCreateCompanionBuilder,
+      $$CachedFilesTableTableUpdateCompanionBuilder,
+      (
+        CachedFilesTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $CachedFilesTableTable,
+          CachedFilesTableData
+        >,
+      ),
+      CachedFilesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$UserTableTableCreateCompanionBuilder =
+    UserTableCompanion Function({
+      required String id,
+      required String username,
+      Value<String?> email,
+      Value<String> role,
+      Value<int?> storageQuotaBytes,
+      Value<int?> storageUsedBytes,
+      Value<DateTime?> lastSyncAt,
+      Value<String?> checksumAlgorithm,
+      Value<int> rowid,
+    });
+typedef $$UserTableTableUpdateCompanionBuilder =
+    UserTableCompanion Function({
+      Value<String> id,
+      Value<String> username,
+      Value<String?> email,
+      Value<String> role,
+      Value<int?> storageQuotaBytes,
+      Value<int?> storageUsedBytes,
+      Value<DateTime?> lastSyncAt,
+      Value<String?> checksumAlgorithm,
+      Value<int> rowid,
+    });
+
+class $$UserTableTableFilterComposer
+    extends Composer<_$AppDatabase, $UserTableTable> {
+  $$UserTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get username => $composableBuilder(
+    column: $table.username,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get email => $composableBuilder(
+    column: $table.email,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get role => $composableBuilder(
+    column: $table.role,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get storageQuotaBytes => $composableBuilder(
+    column: $table.storageQuotaBytes,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get storageUsedBytes => $composableBuilder(
+    column: $table.storageUsedBytes,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get lastSyncAt => $composableBuilder(
+    column: $table.lastSyncAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get checksumAlgorithm => $composableBuilder(
+    column: $table.checksumAlgorithm,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$UserTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $UserTableTable> {
+  $$UserTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get username => $composableBuilder(
+    column: $table.username,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get email => $composableBuilder(
+    column: $table.email,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get role => $composableBuilder(
+    column: $table.role,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get storageQuotaBytes => $composableBuilder(
+    column: $table.storageQuotaBytes,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get storageUsedBytes => $composableBuilder(
+    column: $table.storageUsedBytes,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get lastSyncAt => $composableBuilder(
+    column: $table.lastSyncAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get checksumAlgorithm => $composableBuilder(
+    column: $table.checksumAlgorithm,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$UserTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $UserTableTable> {
+  $$UserTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get username =>
+      $composableBuilder(column: $table.username, builder: (column) => column);
+
+  GeneratedColumn<String> get email =>
+      $composableBuilder(column: $table.email, builder: (column) => column);
+
+  GeneratedColumn<String> get role =>
+      $composableBuilder(column: $table.role, builder: (column) => column);
+
+  GeneratedColumn<int> get storageQuotaBytes => $composableBuilder(
+    column: $table.storageQuotaBytes,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get storageUsedBytes => $composableBuilder(
+    column: $table.storageUsedBytes,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get lastSyncAt => $composableBuilder(
+    column: $table.lastSyncAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get checksumAlgorithm => $composableBuilder(
+    column: $table.checksumAlgorithm,
+    builder: (column) => column,
+  );
+}
+
+class $$UserTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $UserTableTable,
+          UserTableData,
+          $$UserTableTableFilterComposer,
+          $$UserTableTableOrderingComposer,
+          $$UserTableTableAnnotationComposer,
+          $$UserTableTableCreateCompanionBuilder,
+          $$UserTableTableUpdateCompanionBuilder,
+          (
+            UserTableData,
+            BaseReferences<_$AppDatabase, $UserTableTable, UserTableData>,
+          ),
+          UserTableData,
+          PrefetchHooks Function()
+        > {
+  $$UserTableTableTableManager(_$AppDatabase db, $UserTableTable table)
+    : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$UserTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$UserTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$UserTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<String> id = const Value.absent(),
+                Value<String> username = const Value.absent(),
+                Value<String?> email = const Value.absent(),
+                Value<String> role = const Value.absent(),
+                Value<int?> storageQuotaBytes = const Value.absent(),
+                Value<int?> storageUsedBytes = const Value.absent(),
+                Value<DateTime?> lastSyncAt = const Value.absent(),
+                Value<String?> checksumAlgorithm = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => UserTableCompanion(
+                id: id,
+                username: username,
+                email: email,
+                role: role,
+                storageQuotaBytes: storageQuotaBytes,
+                storageUsedBytes: storageUsedBytes,
+                lastSyncAt: lastSyncAt,
+                checksumAlgorithm: checksumAlgorithm,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String id,
+                required String username,
+                Value<String?> email = const Value.absent(),
+                Value<String> role = const Value.absent(),
+                Value<int?> storageQuotaBytes = const Value.absent(),
+                Value<int?> storageUsedBytes = const Value.absent(),
+                Value<DateTime?> lastSyncAt = const Value.absent(),
+                Value<String?> checksumAlgorithm = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => UserTableCompanion.insert(
+                id: id,
+                username: username,
+                email: email,
+                role: role,
+                storageQuotaBytes: storageQuotaBytes,
+                storageUsedBytes: storageUsedBytes,
+                lastSyncAt: lastSyncAt,
+                checksumAlgorithm: checksumAlgorithm,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$UserTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $UserTableTable,
+      UserTableData,
+      $$UserTableTableFilterComposer,
+      $$UserTableTableOrderingComposer,
+      $$UserTableTableAnnotationComposer,
+      $$UserTableTableCreateCompanionBuilder,
+      $$UserTableTableUpdateCompanionBuilder,
+      (
+        UserTableData,
+        BaseReferences<_$AppDatabase, $UserTableTable, UserTableData>,
+      ),
+      UserTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$UploadSessionsTableTableCreateCompanionBuilder =
+    UploadSessionsTableCompanion Function({
+      required String uploadId,
+      required String localPath,
+      required String filename,
+      Value<String?> folderId,
+      Value<String?> contentType,
+      required int totalSize,
+      Value<DateTime?> localModifiedAt,
+      required int chunkSize,
+      required int totalChunks,
+      Value<int> chunksUploaded,
+      Value<String?> checksumAlgorithm,
+      required DateTime createdAt,
+      required DateTime updatedAt,
+      Value<int> rowid,
+    });
+typedef $$UploadSessionsTableTableUpdateCompanionBuilder =
+    UploadSessionsTableCompanion Function({
+      Value<String> uploadId,
+      Value<String> localPath,
+      Value<String> filename,
+      Value<String?> folderId,
+      Value<String?> contentType,
+      Value<int> totalSize,
+      Value<DateTime?> localModifiedAt,
+      Value<int> chunkSize,
+      Value<int> totalChunks,
+      Value<int> chunksUploaded,
+      Value<String?> checksumAlgorithm,
+      Value<DateTime> createdAt,
+      Value<DateTime> updatedAt,
+      Value<int> rowid,
+    });
+
+class $$UploadSessionsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $UploadSessionsTableTable> {
+  $$UploadSessionsTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get uploadId => $composableBuilder(
+    column: $table.uploadId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get localPath => $composableBuilder(
+    column: $table.localPath,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get filename => $composableBuilder(
+    column: $table.filename,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get contentType => $composableBuilder(
+    column: $table.contentType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get totalSize => $composableBuilder(
+    column: $table.totalSize,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get localModifiedAt => $composableBuilder(
+    column: $table.localModifiedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get chunkSize => $composableBuilder(
+    column: $table.chunkSize,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get totalChunks => $composableBuilder(
+    column: $table.totalChunks,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get chunksUploaded => $composableBuilder(
+    column: $table.chunksUploaded,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get checksumAlgorithm => $composableBuilder(
+    column: $table.checksumAlgorithm,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get updatedAt => $composableBuilder(
+    column: $table.updatedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$UploadSessionsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $UploadSessionsTableTable> {
+  $$UploadSessionsTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get uploadId => $composableBuilder(
+    column: $table.uploadId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get localPath => $composableBuilder(
+    column: $table.localPath,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get filename => $composableBuilder(
+    column: $table.filename,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get contentType => $composableBuilder(
+    column: $table.contentType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get totalSize => $composableBuilder(
+    column: $table.totalSize,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get localModifiedAt => $composableBuilder(
+    column: $table.localModifiedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get chunkSize => $composableBuilder(
+    column: $table.chunkSize,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get totalChunks => $composableBuilder(
+    column: $table.totalChunks,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get chunksUploaded => $composableBuilder(
+    column: $table.chunksUploaded,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get checksumAlgorithm => $composableBuilder(
+    column: $table.checksumAlgorithm,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get updatedAt => $composableBuilder(
+    column: $table.updatedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$UploadSessionsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $UploadSessionsTableTable> {
+  $$UploadSessionsTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get uploadId =>
+      $composableBuilder(column: $table.uploadId, builder: (column) => column);
+
+  GeneratedColumn<String> get localPath =>
+      $composableBuilder(column: $table.localPath, builder: (column) => column);
+
+  GeneratedColumn<String> get filename =>
+      $composableBuilder(column: $table.filename, builder: (column) => column);
+
+  GeneratedColumn<String> get folderId =>
+      $composableBuilder(column: $table.folderId, builder: (column) => column);
+
+  GeneratedColumn<String> get contentType => $composableBuilder(
+    column: $table.contentType,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get totalSize =>
+      $composableBuilder(column: $table.totalSize, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get localModifiedAt => $composableBuilder(
+    column: $table.localModifiedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get chunkSize =>
+      $composableBuilder(column: $table.chunkSize, builder: (column) => column);
+
+  GeneratedColumn<int> get totalChunks => $composableBuilder(
+    column: $table.totalChunks,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get chunksUploaded => $composableBuilder(
+    column: $table.chunksUploaded,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get checksumAlgorithm => $composableBuilder(
+    column: $table.checksumAlgorithm,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get updatedAt =>
+      $composableBuilder(column: $table.updatedAt, builder: (column) => column);
+}
+
+class $$UploadSessionsTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $UploadSessionsTableTable,
+          UploadSessionsTableData,
+          $$UploadSessionsTableTableFilterComposer,
+          $$UploadSessionsTableTableOrderingComposer,
+          $$UploadSessionsTableTableAnnotationComposer,
+          $$UploadSessionsTableTableCreateCompanionBuilder,
+          $$UploadSessionsTableTableUpdateCompanionBuilder,
+          (
+            UploadSessionsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $UploadSessionsTableTable,
+              UploadSessionsTableData
+            >,
+          ),
+          UploadSessionsTableData,
+          PrefetchHooks Function()
+        > {
+  $$UploadSessionsTableTableTableManager(
+    _$AppDatabase db,
+    $UploadSessionsTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$UploadSessionsTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$UploadSessionsTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
+          createComputedFieldComposer: () =>
+              $$UploadSessionsTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<String> uploadId = const Value.absent(),
+                Value<String> localPath = const Value.absent(),
+                Value<String> filename = const Value.absent(),
+                Value<String?> folderId = const Value.absent(),
+                Value<String?> contentType = const Value.absent(),
+                Value<int> totalSize = const Value.absent(),
+                Value<DateTime?> localModifiedAt = const Value.absent(),
+                Value<int> chunkSize = const Value.absent(),
+                Value<int> totalChunks = const Value.absent(),
+                Value<int> chunksUploaded = const Value.absent(),
+                Value<String?> checksumAlgorithm = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<DateTime> updatedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => UploadSessionsTableCompanion(
+                uploadId: uploadId,
+                localPath: localPath,
+                filename: filename,
+                folderId: folderId,
+                contentType: contentType,
+                totalSize: totalSize,
+                localModifiedAt: localModifiedAt,
+                chunkSize: chunkSize,
+                totalChunks: totalChunks,
+                chunksUploaded: chunksUploaded,
+                checksumAlgorithm: checksumAlgorithm,
+                createdAt: createdAt,
+                updatedAt: updatedAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String uploadId,
+                required String localPath,
+                required String filename,
+                Value<String?> folderId = const Value.absent(),
+                Value<String?> contentType = const Value.absent(),
+                required int totalSize,
+                Value<DateTime?> localModifiedAt = const Value.absent(),
+                required int chunkSize,
+                required int totalChunks,
+                Value<int> chunksUploaded = const Value.absent(),
+                Value<String?> checksumAlgorithm = const Value.absent(),
+                required DateTime createdAt,
+                required DateTime updatedAt,
+                Value<int> rowid = const Value.absent(),
+              }) => UploadSessionsTableCompanion.insert(
+                uploadId: uploadId,
+                localPath: localPath,
+                filename: filename,
+                folderId: folderId,
+                contentType: contentType,
+                totalSize: totalSize,
+                localModifiedAt: localModifiedAt,
+                chunkSize: chunkSize,
+                totalChunks: totalChunks,
+                chunksUploaded: chunksUploaded,
+                checksumAlgorithm: checksumAlgorithm,
+                createdAt: createdAt,
+                updatedAt: updatedAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$UploadSessionsTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $UploadSessionsTableTable,
+      UploadSessionsTableData,
+      $$UploadSessionsTableTableFilterComposer,
+      $$UploadSessionsTableTableOrderingComposer,
+      $$UploadSessionsTableTableAnnotationComposer,
+      $$UploadSessionsTableTableCreateCompanionBuilder,
+      $$UploadSessionsTableTableUpdateCompanionBuilder,
+      (
+        UploadSessionsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $UploadSessionsTableTable,
+          UploadSessionsTableData
+        >,
+      ),
+      UploadSessionsTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$ActivityLogTableTableCreateCompanionBuilder =
+    ActivityLogTableCompanion Function({
+      Value<int> id,
+      required String category,
+      required String action,
+      Value<String?> itemId,
+      Value<String?> itemName,
+      required String outcome,
+      Value<String?> reason,
+      Value<int?> bytes,
+      Value<String> initiator,
+      required DateTime createdAt,
+    });
+typedef $$ActivityLogTableTableUpdateCompanionBuilder =
+    ActivityLogTableCompanion Function({
+      Value<int> id,
+      Value<String> category,
+      Value<String> action,
+      Value<String?> itemId,
+      Value<String?> itemName,
+      Value<String> outcome,
+      Value<String?> reason,
+      Value<int?> bytes,
+      Value<String> initiator,
+      Value<DateTime> createdAt,
+    });
+
+class $$ActivityLogTableTableFilterComposer
+    extends Composer<_$AppDatabase, $ActivityLogTableTable> {
+  $$ActivityLogTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get category => $composableBuilder(
+    column: $table.category,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get action => $composableBuilder(
+    column: $table.action,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemName => $composableBuilder(
+    column: $table.itemName,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get outcome => $composableBuilder(
+    column: $table.outcome,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get reason => $composableBuilder(
+    column: $table.reason,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get bytes => $composableBuilder(
+    column: $table.bytes,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get initiator => $composableBuilder(
+    column: $table.initiator,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$ActivityLogTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $ActivityLogTableTable> {
+  $$ActivityLogTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get category => $composableBuilder(
+    column: $table.category,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get action => $composableBuilder(
+    column: $table.action,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemName => $composableBuilder(
+    column: $table.itemName,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get outcome => $composableBuilder(
+    column: $table.outcome,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get reason => $composableBuilder(
+    column: $table.reason,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get bytes => $composableBuilder(
+    column: $table.bytes,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get initiator => $composableBuilder(
+    column: $table.initiator,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$ActivityLogTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $ActivityLogTableTable> {
+  $$ActivityLogTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get category =>
+      $composableBuilder(column: $table.category, builder: (column) => column);
+
+  GeneratedColumn<String> get action =>
+      $composableBuilder(column: $table.action, builder: (column) => column);
+
+  GeneratedColumn<String> get itemId =>
+      $composableBuilder(column: $table.itemId, builder: (column) => column);
+
+  GeneratedColumn<String> get itemName =>
+      $composableBuilder(column: $table.itemName, builder: (column) => column);
+
+  GeneratedColumn<String> get outcome =>
+      $composableBuilder(column: $table.outcome, builder: (column) => column);
+
+  GeneratedColumn<String> get reason =>
+      $composableBuilder(column: $table.reason, builder: (column) => column);
+
+  GeneratedColumn<int> get bytes =>
+      $composableBuilder(column: $table.bytes, builder: (column) => column);
+
+  GeneratedColumn<String> get initiator =>
+      $composableBuilder(column: $table.initiator, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+}
+
+class $$ActivityLogTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $ActivityLogTableTable,
+          ActivityLogTableData,
+          $$ActivityLogTableTableFilterComposer,
+          $$ActivityLogTableTableOrderingComposer,
+          $$ActivityLogTableTableAnnotationComposer,
+          $$ActivityLogTableTableCreateCompanionBuilder,
+          $$ActivityLogTableTableUpdateCompanionBuilder,
+          (
+            ActivityLogTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $ActivityLogTableTable,
+              ActivityLogTableData
+            >,
+          ),
+          ActivityLogTableData,
+          PrefetchHooks Function()
+        > {
+  $$ActivityLogTableTableTableManager(
+    _$AppDatabase db,
+    $ActivityLogTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$ActivityLogTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$ActivityLogTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$ActivityLogTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String> category = const Value.absent(),
+                Value<String> action = const Value.absent(),
+                Value<String?> itemId = const Value.absent(),
+                Value<String?> itemName = const Value.absent(),
+                Value<String> outcome = const Value.absent(),
+                Value<String?> reason = const Value.absent(),
+                Value<int?> bytes = const Value.absent(),
+                Value<String> initiator = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+              }) => ActivityLogTableCompanion(
+                id: id,
+                category: category,
+                action: action,
+                itemId: itemId,
+                itemName: itemName,
+                outcome: outcome,
+                reason: reason,
+                bytes: bytes,
+                initiator: initiator,
+                createdAt: createdAt,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                required String category,
+                required String action,
+                Value<String?> itemId = const Value.absent(),
+                Value<String?> itemName = const Value.absent(),
+                required String outcome,
+                Value<String?> reason = const Value.absent(),
+                Value<int?> bytes = const Value.absent(),
+                Value<String> initiator = const Value.absent(),
+                required DateTime createdAt,
+              }) => ActivityLogTableCompanion.insert(
+                id: id,
+                category: category,
+                action: action,
+                itemId: itemId,
+                itemName: itemName,
+                outcome: outcome,
+                reason: reason,
+                bytes: bytes,
+                initiator: initiator,
+                createdAt: createdAt,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$ActivityLogTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $ActivityLogTableTable,
+      ActivityLogTableData,
+      $$ActivityLogTableTableFilterComposer,
+      $$ActivityLogTableTableOrderingComposer,
+      $$ActivityLogTableTableAnnotationComposer,
+      $$ActivityLogTableTableCreateCompanionBuilder,
+      $$ActivityLogTableTableUpdateCompanionBuilder,
+      (
+        ActivityLogTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $ActivityLogTableTable,
+          ActivityLogTableData
+        >,
+      ),
+      ActivityLogTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$FolderScanStateTableTableCreateCompanionBuilder =
+    FolderScanStateTableCompanion Function({
+      required String folderId,
+      Value<String?> fingerprint,
+      required int intervalSeconds,
+      Value<int> changeCount,
+      Value<DateTime?> lastScannedAt,
+      required DateTime nextScanAt,
+      Value<String?> syncToken,
+      Value<int> rowid,
+    });
+typedef $$FolderScanStateTableTableUpdateCompanionBuilder =
+    FolderScanStateTableCompanion Function({
+      Value<String> folderId,
+      Value<String?> fingerprint,
+      Value<int> intervalSeconds,
+      Value<int> changeCount,
+      Value<DateTime?> lastScannedAt,
+      Value<DateTime> nextScanAt,
+      Value<String?> syncToken,
+      Value<int> rowid,
+    });
+
+class $$FolderScanStateTableTableFilterComposer
+    extends Composer<_$AppDatabase, $FolderScanStateTableTable> {
+  $$FolderScanStateTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get fingerprint => $composableBuilder(
+    column: $table.fingerprint,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get intervalSeconds => $composableBuilder(
+    column: $table.intervalSeconds,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get changeCount => $composableBuilder(
+    column: $table.changeCount,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get lastScannedAt => $composableBuilder(
+    column: $table.lastScannedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get nextScanAt => $composableBuilder(
+    column: $table.nextScanAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get syncToken => $composableBuilder(
+    column: $table.syncToken,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$FolderScanStateTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $FolderScanStateTableTable> {
+  $$FolderScanStateTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get fingerprint => $composableBuilder(
+    column: $table.fingerprint,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get intervalSeconds => $composableBuilder(
+    column: $table.intervalSeconds,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get changeCount => $composableBuilder(
+    column: $table.changeCount,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get lastScannedAt => $composableBuilder(
+    column: $table.lastScannedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get nextScanAt => $composableBuilder(
+    column: $table.nextScanAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get syncToken => $composableBuilder(
+    column: $table.syncToken,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$FolderScanStateTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $FolderScanStateTableTable> {
+  $$FolderScanStateTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get folderId =>
+      $composableBuilder(column: $table.folderId, builder: (column) => column);
+
+  GeneratedColumn<String> get fingerprint => $composableBuilder(
+    column: $table.fingerprint,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get intervalSeconds => $composableBuilder(
+    column: $table.intervalSeconds,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get changeCount => $composableBuilder(
+    column: $table.changeCount,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get lastScannedAt => $composableBuilder(
+    column: $table.lastScannedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get nextScanAt => $composableBuilder(
+    column: $table.nextScanAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get syncToken =>
+      $composableBuilder(column: $table.syncToken, builder: (column) => column);
+}
+
+class $$FolderScanStateTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $FolderScanStateTableTable,
+          FolderScanStateTableData,
+          $$FolderScanStateTableTableFilterComposer,
+          $$FolderScanStateTableTableOrderingComposer,
+          $$FolderScanStateTableTableAnnotationComposer,
+          $$FolderScanStateTableTableCreateCompanionBuilder,
+          $$FolderScanStateTableTableUpdateCompanionBuilder,
+          (
+            FolderScanStateTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $FolderScanStateTableTable,
+              FolderScanStateTableData
+            >,
+          ),
+          FolderScanStateTableData,
+          PrefetchHooks Function()
+        > {
+  $$FolderScanStateTableTableTableManager(
+    _$AppDatabase db,
+    $FolderScanStateTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$FolderScanStateTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$FolderScanStateTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
+          createComputedFieldComposer: () =>
+              $$FolderScanStateTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<String> folderId = const Value.absent(),
+                Value<String?> fingerprint = const Value.absent(),
+                Value<int> intervalSeconds = const Value.absent(),
+                Value<int> changeCount = const Value.absent(),
+                Value<DateTime?> lastScannedAt = const Value.absent(),
+                Value<DateTime> nextScanAt = const Value.absent(),
+                Value<String?> syncToken = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FolderScanStateTableCompanion(
+                folderId: folderId,
+                fingerprint: fingerprint,
+                intervalSeconds: intervalSeconds,
+                changeCount: changeCount,
+                lastScannedAt: lastScannedAt,
+                nextScanAt: nextScanAt,
+                syncToken: syncToken,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String folderId,
+                Value<String?> fingerprint = const Value.absent(),
+                required int intervalSeconds,
+                Value<int> changeCount = const Value.absent(),
+                Value<DateTime?> lastScannedAt = const Value.absent(),
+                required DateTime nextScanAt,
+                Value<String?> syncToken = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FolderScanStateTableCompanion.insert(
+                folderId: folderId,
+                fingerprint: fingerprint,
+                intervalSeconds: intervalSeconds,
+                changeCount: changeCount,
+                lastScannedAt: lastScannedAt,
+                nextScanAt: nextScanAt,
+                syncToken: syncToken,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$FolderScanStateTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $FolderScanStateTableTable,
+      FolderScanStateTableData,
+      $$FolderScanStateTableTableFilterComposer,
+      $$FolderScanStateTableTableOrderingComposer,
+      $$FolderScanStateTableTableAnnotationComposer,
+      $$FolderScanStateTableTableCreateCompanionBuilder,
+      $$FolderScanStateTableTableUpdateCompanionBuilder,
+      (
+        FolderScanStateTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $FolderScanStateTableTable,
+          FolderScanStateTableData
+        >,
+      ),
+      FolderScanStateTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$LinkedSharesTableTableCreateCompanionBuilder =
+    LinkedSharesTableCompanion Function({
+      required String id,
+      required String serverUrl,
+      required String token,
+      required String name,
+      required String itemType,
+      Value<bool> passwordProtected,
+      Value<String?> localPath,
+      Value<DateTime?> lastSyncedAt,
+      required DateTime addedAt,
+      Value<int> rowid,
+    });
+typedef $$LinkedSharesTableTableUpdateCompanionBuilder =
+    LinkedSharesTableCompanion Function({
+      Value<String> id,
+      Value<String> serverUrl,
+      Value<String> token,
+      Value<String> name,
+      Value<String> itemType,
+      Value<bool> passwordProtected,
+      Value<String?> localPath,
+      Value<DateTime?> lastSyncedAt,
+      Value<DateTime> addedAt,
+      Value<int> rowid,
+    });
+
+class $$LinkedSharesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $LinkedSharesTableTable> {
+  $$LinkedSharesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get serverUrl => $composableBuilder(
+    column: $table.serverUrl,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get token => $composableBuilder(
+    column: $table.token,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get name => $composableBuilder(
+    column: $table.name,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemType => $composableBuilder(
+    column: $table.itemType,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<bool> get passwordProtected => $composableBuilder(
+    column: $table.passwordProtected,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get localPath => $composableBuilder(
+    column: $table.localPath,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get lastSyncedAt => $composableBuilder(
+    column: $table.lastSyncedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get addedAt => $composableBuilder(
+    column: $table.addedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$LinkedSharesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $LinkedSharesTableTable> {
+  $$LinkedSharesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get serverUrl => $composableBuilder(
+    column: $table.serverUrl,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get token => $composableBuilder(
+    column: $table.token,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get name => $composableBuilder(
+    column: $table.name,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemType => $composableBuilder(
+    column: $table.itemType,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<bool> get passwordProtected => $composableBuilder(
+    column: $table.passwordProtected,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get localPath => $composableBuilder(
+    column: $table.localPath,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get lastSyncedAt => $composableBuilder(
+    column: $table.lastSyncedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get addedAt => $composableBuilder(
+    column: $table.addedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$LinkedSharesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $LinkedSharesTableTable> {
+  $$LinkedSharesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get serverUrl =>
+      $composableBuilder(column: $table.serverUrl, builder: (column) => column);
+
+  GeneratedColumn<String> get token =>
+      $composableBuilder(column: $table.token, builder: (column) => column);
+
+  GeneratedColumn<String> get name =>
+      $composableBuilder(column: $table.name, builder: (column) => column);
+
+  GeneratedColumn<String> get itemType =>
+      $composableBuilder(column: $table.itemType, builder: (column) => column);
+
+  GeneratedColumn<bool> get passwordProtected => $composableBuilder(
+    column: $table.passwordProtected,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get localPath =>
+      $composableBuilder(column: $table.localPath, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get lastSyncedAt => $composableBuilder(
+    column: $table.lastSyncedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get addedAt =>
+      $composableBuilder(column: $table.addedAt, builder: (column) => column);
+}
+
+class $$LinkedSharesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $LinkedSharesTableTable,
+          LinkedSharesTableData,
+          $$LinkedSharesTableTableFilterComposer,
+          $$LinkedSharesTableTableOrderingComposer,
+          $$LinkedSharesTableTableAnnotationComposer,
+          $$LinkedSharesTableTableCreateCompanionBuilder,
+          $$LinkedSharesTableTableUpdateCompanionBuilder,
+          (
+            LinkedSharesTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $LinkedSharesTableTable,
+              LinkedSharesTableData
+            >,
+          ),
+          LinkedSharesTableData,
+          PrefetchHooks Function()
+        > {
+  $$LinkedSharesTableTableTableManager(
+    _$AppDatabase db,
+    $LinkedSharesTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$LinkedSharesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$LinkedSharesTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$LinkedSharesTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<String> id = const Value.absent(),
+                Value<String> serverUrl = const Value.absent(),
+                Value<String> token = const Value.absent(),
+                Value<String> name = const Value.absent(),
+                Value<String> itemType = const Value.absent(),
+                Value<bool> passwordProtected = const Value.absent(),
+                Value<String?> localPath = const Value.absent(),
+                Value<DateTime?> lastSyncedAt = const Value.absent(),
+                Value<DateTime> addedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => LinkedSharesTableCompanion(
+                id: id,
+                serverUrl: serverUrl,
+                token: token,
+                name: name,
+                itemType: itemType,
+                passwordProtected: passwordProtected,
+                localPath: localPath,
+                lastSyncedAt: lastSyncedAt,
+                addedAt: addedAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String id,
+                required String serverUrl,
+                required String token,
+                required String name,
+                required String itemType,
+                Value<bool> passwordProtected = const Value.absent(),
+                Value<String?> localPath = const Value.absent(),
+                Value<DateTime?> lastSyncedAt = const Value.absent(),
+                required DateTime addedAt,
+                Value<int> rowid = const Value.absent(),
+              }) => LinkedSharesTableCompanion.insert(
+                id: id,
+                serverUrl: serverUrl,
+                token: token,
+                name: name,
+                itemType: itemType,
+                passwordProtected: passwordProtected,
+                localPath: localPath,
+                lastSyncedAt: lastSyncedAt,
+                addedAt: addedAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$LinkedSharesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $LinkedSharesTableTable,
+      LinkedSharesTableData,
+      $$LinkedSharesTableTableFilterComposer,
+      $$LinkedSharesTableTableOrderingComposer,
+      $$LinkedSharesTableTableAnnotationComposer,
+      $$LinkedSharesTableTableCreateCompanionBuilder,
+      $$LinkedSharesTableTableUpdateCompanionBuilder,
+      (
+        LinkedSharesTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $LinkedSharesTableTable,
+          LinkedSharesTableData
+        >,
+      ),
+      LinkedSharesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$TransferLedgerTableTableCreateCompanionBuilder =
+    TransferLedgerTableCompanion Function({
+      Value<int> id,
+      required String itemId,
+      required String itemName,
+      required int size,
+      Value<DateTime?> remoteModifiedAt,
+      Value<String> status,
+      required DateTime uploadedAt,
+      Value<DateTime?> verifiedAt,
+    });
+typedef $$TransferLedgerTableTableUpdateCompanionBuilder =
+    TransferLedgerTableCompanion Function({
+      Value<int> id,
+      Value<String> itemId,
+      Value<String> itemName,
+      Value<int> size,
+      Value<DateTime?> remoteModifiedAt,
+      Value<String> status,
+      Value<DateTime> uploadedAt,
+      Value<DateTime?> verifiedAt,
+    });
+
+class $$TransferLedgerTableTableFilterComposer
+    extends Composer<_$AppDatabase, $TransferLedgerTableTable> {
+  $$TransferLedgerTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get itemName => $composableBuilder(
+    column: $table.itemName,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get size => $composableBuilder(
+    column: $table.size,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get remoteModifiedAt => $composableBuilder(
+    column: $table.remoteModifiedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get status => $composableBuilder(
+    column: $table.status,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get uploadedAt => $composableBuilder(
+    column: $table.uploadedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get verifiedAt => $composableBuilder(
+    column: $table.verifiedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$TransferLedgerTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $TransferLedgerTableTable> {
+  $$TransferLedgerTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemId => $composableBuilder(
+    column: $table.itemId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get itemName => $composableBuilder(
+    column: $table.itemName,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get size => $composableBuilder(
+    column: $table.size,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get remoteModifiedAt => $composableBuilder(
+    column: $table.remoteModifiedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get status => $composableBuilder(
+    column: $table.status,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get uploadedAt => $composableBuilder(
+    column: $table.uploadedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get verifiedAt => $composableBuilder(
+    column: $table.verifiedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$TransferLedgerTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $TransferLedgerTableTable> {
+  $$TransferLedgerTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get itemId =>
+      $composableBuilder(column: $table.itemId, builder: (column) => column);
+
+  GeneratedColumn<String> get itemName =>
+      $composableBuilder(column: $table.itemName, builder: (column) => column);
+
+  GeneratedColumn<int> get size =>
+      $composableBuilder(column: $table.size, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get remoteModifiedAt => $composableBuilder(
+    column: $table.remoteModifiedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get status =>
+      $composableBuilder(column: $table.status, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get uploadedAt => $composableBuilder(
+    column: $table.uploadedAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get verifiedAt => $composableBuilder(
+    column: $table.verifiedAt,
+    builder: (column) => column,
+  );
+}
+
+class $$TransferLedgerTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $TransferLedgerTableTable,
+          TransferLedgerTableData,
+          $$TransferLedgerTableTableFilterComposer,
+          $$TransferLedgerTableTableOrderingComposer,
+          $$TransferLedgerTableTableAnnotationComposer,
+          $$TransferLedgerTableTableCreateCompanionBuilder,
+          $$TransferLedgerTableTableUpdateCompanionBuilder,
+          (
+            TransferLedgerTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $TransferLedgerTableTable,
+              TransferLedgerTableData
+            >,
+          ),
+          TransferLedgerTableData,
+          PrefetchHooks Function()
+        > {
+  $$TransferLedgerTableTableTableManager(
+    _$AppDatabase db,
+    $TransferLedgerTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$TransferLedgerTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$TransferLedgerTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
+          createComputedFieldComposer: () =>
+              $$TransferLedgerTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String> itemId = const Value.absent(),
+                Value<String> itemName = const Value.absent(),
+                Value<int> size = const Value.absent(),
+                Value<DateTime?> remoteModifiedAt = const Value.absent(),
+                Value<String> status = const Value.absent(),
+                Value<DateTime> uploadedAt = const Value.absent(),
+                Value<DateTime?> verifiedAt = const Value.absent(),
+              }) => TransferLedgerTableCompanion(
+                id: id,
+                itemId: itemId,
+                itemName: itemName,
+                size: size,
+                remoteModifiedAt: remoteModifiedAt,
+                status: status,
+                uploadedAt: uploadedAt,
+                verifiedAt: verifiedAt,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                required String itemId,
+                required String itemName,
+                required int size,
+                Value<DateTime?> remoteModifiedAt = const Value.absent(),
+                Value<String> status = const Value.absent(),
+                required DateTime uploadedAt,
+                Value<DateTime?> verifiedAt = const Value.absent(),
+              }) => TransferLedgerTableCompanion.insert(
+                id: id,
+                itemId: itemId,
+                itemName: itemName,
+                size: size,
+                remoteModifiedAt: remoteModifiedAt,
+                status: status,
+                uploadedAt: uploadedAt,
+                verifiedAt: verifiedAt,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$TransferLedgerTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $TransferLedgerTableTable,
+      TransferLedgerTableData,
+      $$TransferLedgerTableTableFilterComposer,
+      $$TransferLedgerTableTableOrderingComposer,
+      $$TransferLedgerTableTableAnnotationComposer,
+      $$TransferLedgerTableTableCreateCompanionBuilder,
+      $$TransferLedgerTableTableUpdateCompanionBuilder,
+      (
+        TransferLedgerTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $TransferLedgerTableTable,
+          TransferLedgerTableData
+        >,
+      ),
+      TransferLedgerTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$FileModesTableTableCreateCompanionBuilder =
+    FileModesTableCompanion Function({
+      required String fileId,
+      required int mode,
+      required DateTime recordedAt,
+      Value<int> rowid,
+    });
+typedef $$FileModesTableTableUpdateCompanionBuilder =
+    FileModesTableCompanion Function({
+      Value<String> fileId,
+      Value<int> mode,
+      Value<DateTime> recordedAt,
+      Value<int> rowid,
+    });
+
+class $$FileModesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $FileModesTableTable> {
+  $$FileModesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get fileId => $composableBuilder(
+    column: $table.fileId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get mode => $composableBuilder(
+    column: $table.mode,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get recordedAt => $composableBuilder(
+    column: $table.recordedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$FileModesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $FileModesTableTable> {
+  $$FileModesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get fileId => $composableBuilder(
+    column: $table.fileId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get mode => $composableBuilder(
+    column: $table.mode,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get recordedAt => $composableBuilder(
+    column: $table.recordedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$FileModesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $FileModesTableTable> {
+  $$FileModesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get fileId =>
+      $composableBuilder(column: $table.fileId, builder: (column) => column);
+
+  GeneratedColumn<int> get mode =>
+      $composableBuilder(column: $table.mode, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get recordedAt => $composableBuilder(
+    column: $table.recordedAt,
+    builder: (column) => column,
+  );
+}
+
+class $$FileModesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $FileModesTableTable,
+          FileModesTableData,
+          $$FileModesTableTableFilterComposer,
+          $$FileModesTableTableOrderingComposer,
+          $$FileModesTableTableAnnotationComposer,
+          $$FileModesTableTableCreateCompanionBuilder,
+          $$FileModesTableTableUpdateCompanionBuilder,
+          (
+            FileModesTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $FileModesTableTable,
+              FileModesTableData
+            >,
+          ),
+          FileModesTableData,
+          PrefetchHooks Function()
+        > {
+  $$FileModesTableTableTableManager(
+    _$AppDatabase db,
+    $FileModesTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$FileModesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$FileModesTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$FileModesTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<String> fileId = const Value.absent(),
+                Value<int> mode = const Value.absent(),
+                Value<DateTime> recordedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FileModesTableCompanion(
+                fileId: fileId,
+                mode: mode,
+                recordedAt: recordedAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String fileId,
+                required int mode,
+                required DateTime recordedAt,
+                Value<int> rowid = const Value.absent(),
+              }) => FileModesTableCompanion.insert(
+                fileId: fileId,
+                mode: mode,
+                recordedAt: recordedAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$FileModesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $FileModesTableTable,
+      FileModesTableData,
+      $$FileModesTableTableFilterComposer,
+      $$FileModesTableTableOrderingComposer,
+      $$FileModesTableTableAnnotationComposer,
+      $$FileModesTableTableCreateCompanionBuilder,
+      $$FileModesTableTableUpdateCompanionBuilder,
+      (
+        FileModesTableData,
+        BaseReferences<_$AppDatabase, $FileModesTableTable, FileModesTableData>,
+      ),
+      FileModesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$DbLeaseTableTableCreateCompanionBuilder =
+    DbLeaseTableCompanion Function({
+      Value<int> id,
+      required String ownerId,
+      required int pid,
+      required String hostname,
+      required DateTime acquiredAt,
+      required DateTime heartbeatAt,
+      Value<int> rowid,
+    });
+typedef $$DbLeaseTableTableUpdateCompanionBuilder =
+    DbLeaseTableCompanion Function({
+      Value<int> id,
+      Value<String> ownerId,
+      Value<int> pid,
+      Value<String> hostname,
+      Value<DateTime> acquiredAt,
+      Value<DateTime> heartbeatAt,
+      Value<int> rowid,
+    });
+
+class $$DbLeaseTableTableFilterComposer
+    extends Composer<_$AppDatabase, $DbLeaseTableTable> {
+  $$DbLeaseTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get ownerId => $composableBuilder(
+    column: $table.ownerId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get pid => $composableBuilder(
+    column: $table.pid,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get hostname => $composableBuilder(
+    column: $table.hostname,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get acquiredAt => $composableBuilder(
+    column: $table.acquiredAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get heartbeatAt => $composableBuilder(
+    column: $table.heartbeatAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$DbLeaseTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $DbLeaseTableTable> {
+  $$DbLeaseTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get ownerId => $composableBuilder(
+    column: $table.ownerId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get pid => $composableBuilder(
+    column: $table.pid,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get hostname => $composableBuilder(
+    column: $table.hostname,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get acquiredAt => $composableBuilder(
+    column: $table.acquiredAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get heartbeatAt => $composableBuilder(
+    column: $table.heartbeatAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$DbLeaseTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $DbLeaseTableTable> {
+  $$DbLeaseTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get ownerId =>
+      $composableBuilder(column: $table.ownerId, builder: (column) => column);
+
+  GeneratedColumn<int> get pid =>
+      $composableBuilder(column: $table.pid, builder: (column) => column);
+
+  GeneratedColumn<String> get hostname =>
+      $composableBuilder(column: $table.hostname, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get acquiredAt => $composableBuilder(
+    column: $table.acquiredAt,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get heartbeatAt => $composableBuilder(
+    column: $table.heartbeatAt,
+    builder: (column) => column,
+  );
+}
+
+class $$DbLeaseTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $DbLeaseTableTable,
+          DbLeaseTableData,
+          $$DbLeaseTableTableFilterComposer,
+          $$DbLeaseTableTableOrderingComposer,
+          $$DbLeaseTableTableAnnotationComposer,
+          $$DbLeaseTableTableCreateCompanionBuilder,
+          $$DbLeaseTableTableUpdateCompanionBuilder,
+          (
+            DbLeaseTableData,
+            BaseReferences<_$AppDatabase, $DbLeaseTableTable, DbLeaseTableData>,
+          ),
+          DbLeaseTableData,
+          PrefetchHooks Function()
+        > {
+  $$DbLeaseTableTableTableManager(_$AppDatabase db, $DbLeaseTableTable table)
+    : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$DbLeaseTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$DbLeaseTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$DbLeaseTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String> ownerId = const Value.absent(),
+                Value<int> pid = const Value.absent(),
+                Value<String> hostname = const Value.absent(),
+                Value<DateTime> acquiredAt = const Value.absent(),
+                Value<DateTime> heartbeatAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => DbLeaseTableCompanion(
+                id: id,
+                ownerId: ownerId,
+                pid: pid,
+                hostname: hostname,
+                acquiredAt: acquiredAt,
+                heartbeatAt: heartbeatAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                required String ownerId,
+                required int pid,
+                required String hostname,
+                required DateTime acquiredAt,
+                required DateTime heartbeatAt,
+                Value<int> rowid = const Value.absent(),
+              }) => DbLeaseTableCompanion.insert(
+                id: id,
+                ownerId: ownerId,
+                pid: pid,
+                hostname: hostname,
+                acquiredAt: acquiredAt,
+                heartbeatAt: heartbeatAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$DbLeaseTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $DbLeaseTableTable,
+      DbLeaseTableData,
+      $$DbLeaseTableTableFilterComposer,
+      $$DbLeaseTableTableOrderingComposer,
+      $$DbLeaseTableTableAnnotationComposer,
+      $$DbLeaseTableTableCreateCompanionBuilder,
+      $$DbLeaseTableTableUpdateCompanionBuilder,
+      (
+        DbLeaseTableData,
+        BaseReferences<_$AppDatabase, $DbLeaseTableTable, DbLeaseTableData>,
+      ),
+      DbLeaseTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$ConflictRulesTableTableCreateCompanionBuilder =
+    ConflictRulesTableCompanion Function({
+      Value<int> id,
+      Value<String?> pathPrefix,
+      Value<String?> namePattern,
+      required String resolution,
+      required int position,
+      required DateTime createdAt,
+    });
+typedef $$ConflictRulesTableTableUpdateCompanionBuilder =
+    ConflictRulesTableCompanion Function({
+      Value<int> id,
+      Value<String?> pathPrefix,
+      Value<String?> namePattern,
+      Value<String> resolution,
+      Value<int> position,
+      Value<DateTime> createdAt,
+    });
+
+class $$ConflictRulesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $ConflictRulesTableTable> {
+  $$ConflictRulesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get pathPrefix => $composableBuilder(
+    column: $table.pathPrefix,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get namePattern => $composableBuilder(
+    column: $table.namePattern,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get resolution => $composableBuilder(
+    column: $table.resolution,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get position => $composableBuilder(
+    column: $table.position,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$ConflictRulesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $ConflictRulesTableTable> {
+  $$ConflictRulesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get pathPrefix => $composableBuilder(
+    column: $table.pathPrefix,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get namePattern => $composableBuilder(
+    column: $table.namePattern,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get resolution => $composableBuilder(
+    column: $table.resolution,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get position => $composableBuilder(
+    column: $table.position,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$ConflictRulesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $ConflictRulesTableTable> {
+  $$ConflictRulesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get pathPrefix => $composableBuilder(
+    column: $table.pathPrefix,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get namePattern => $composableBuilder(
+    column: $table.namePattern,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get resolution => $composableBuilder(
+    column: $table.resolution,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get position =>
+      $composableBuilder(column: $table.position, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+}
+
+class $$ConflictRulesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $ConflictRulesTableTable,
+          ConflictRulesTableData,
+          $$ConflictRulesTableTableFilterComposer,
+          $$ConflictRulesTableTableOrderingComposer,
+          $$ConflictRulesTableTableAnnotationComposer,
+          $$ConflictRulesTableTableCreateCompanionBuilder,
+          $$ConflictRulesTableTableUpdateCompanionBuilder,
+          (
+            ConflictRulesTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $ConflictRulesTableTable,
+              ConflictRulesTableData
+            >,
+          ),
+          ConflictRulesTableData,
+          PrefetchHooks Function()
+        > {
+  $$ConflictRulesTableTableTableManager(
+    _$AppDatabase db,
+    $ConflictRulesTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$ConflictRulesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$ConflictRulesTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$ConflictRulesTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String?> pathPrefix = const Value.absent(),
+                Value<String?> namePattern = const Value.absent(),
+                Value<String> resolution = const Value.absent(),
+                Value<int> position = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+              }) => ConflictRulesTableCompanion(
+                id: id,
+                pathPrefix: pathPrefix,
+                namePattern: namePattern,
+                resolution: resolution,
+                position: position,
+                createdAt: createdAt,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String?> pathPrefix = const Value.absent(),
+                Value<String?> namePattern = const Value.absent(),
+                required String resolution,
+                required int position,
+                required DateTime createdAt,
+              }) => ConflictRulesTableCompanion.insert(
+                id: id,
+                pathPrefix: pathPrefix,
+                namePattern: namePattern,
+                resolution: resolution,
+                position: position,
+                createdAt: createdAt,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$ConflictRulesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $ConflictRulesTableTable,
+      ConflictRulesTableData,
+      $$ConflictRulesTableTableFilterComposer,
+      $$ConflictRulesTableTableOrderingComposer,
+      $$ConflictRulesTableTableAnnotationComposer,
+      $$ConflictRulesTableTableCreateCompanionBuilder,
+      $$ConflictRulesTableTableUpdateCompanionBuilder,
+      (
+        ConflictRulesTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $ConflictRulesTableTable,
+          ConflictRulesTableData
+        >,
+      ),
+      ConflictRulesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$CachedListingsTableTableCreateCompanionBuilder =
+    CachedListingsTableCompanion Function({
+      required String folderId,
+      required DateTime listedAt,
+      Value<int> rowid,
+    });
+typedef $$CachedListingsTableTableUpdateCompanionBuilder =
+    CachedListingsTableCompanion Function({
+      Value<String> folderId,
+      Value<DateTime> listedAt,
+      Value<int> rowid,
+    });
+
+class $$CachedListingsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $CachedListingsTableTable> {
+  $$CachedListingsTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get listedAt => $composableBuilder(
+    column: $table.listedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$CachedListingsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $CachedListingsTableTable> {
+  $$CachedListingsTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get listedAt => $composableBuilder(
+    column: $table.listedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$CachedListingsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $CachedListingsTableTable> {
+  $$CachedListingsTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get folderId =>
+      $composableBuilder(column: $table.folderId, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get listedAt =>
+      $composableBuilder(column: $table.listedAt, builder: (column) => column);
+}
+
+class $$CachedListingsTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $CachedListingsTableTable,
+          CachedListingsTableData,
+          $$CachedListingsTableTableFilterComposer,
+          $$CachedListingsTableTableOrderingComposer,
+          $$CachedListingsTableTableAnnotationComposer,
+          $$CachedListingsTableTableCreateCompanionBuilder,
+          $$CachedListingsTableTableUpdateCompanionBuilder,
+          (
+            CachedListingsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $CachedListingsTableTable,
+              CachedListingsTableData
+            >,
+          ),
+          CachedListingsTableData,
+          PrefetchHooks Function()
+        > {
+  $$CachedListingsTableTableTableManager(
+    _$AppDatabase db,
+    $CachedListingsTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$CachedListingsTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$CachedListingsTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
+          createComputedFieldComposer: () =>
+              $$CachedListingsTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<String> folderId = const Value.absent(),
+                Value<DateTime> listedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => CachedListingsTableCompanion(
+                folderId: folderId,
+                listedAt: listedAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String folderId,
+                required DateTime listedAt,
+                Value<int> rowid = const Value.absent(),
+              }) => CachedListingsTableCompanion.insert(
+                folderId: folderId,
+                listedAt: listedAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$CachedListingsTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $CachedListingsTableTable,
+      CachedListingsTableData,
+      $$CachedListingsTableTableFilterComposer,
+      $$CachedListingsTableTableOrderingComposer,
+      $$CachedListingsTableTableAnnotationComposer,
+      $$CachedListingsTableTableCreateCompanionBuilder,
+      $$CachedListingsTableTableUpdateCompanionBuilder,
+      (
+        CachedListingsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $CachedListingsTableTable,
+          CachedListingsTableData
+        >,
+      ),
+      CachedListingsTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$SyncExclusionsTableTableCreateCompanionBuilder =
+    SyncExclusionsTableCompanion Function({
+      required String folderId,
+      required String path,
+      required DateTime createdAt,
+      Value<int> rowid,
+    });
+typedef $$SyncExclusionsTableTableUpdateCompanionBuilder =
+    SyncExclusionsTableCompanion Function({
+      Value<String> folderId,
+      Value<String> path,
+      Value<DateTime> createdAt,
+      Value<int> rowid,
+    });
+
+class $$SyncExclusionsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $SyncExclusionsTableTable> {
+  $$SyncExclusionsTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$SyncExclusionsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $SyncExclusionsTableTable> {
+  $$SyncExclusionsTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$SyncExclusionsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $SyncExclusionsTableTable> {
+  $$SyncExclusionsTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get folderId =>
+      $composableBuilder(column: $table.folderId, builder: (column) => column);
+
+  GeneratedColumn<String> get path =>
+      $composableBuilder(column: $table.path, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+}
+
+class $$SyncExclusionsTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $SyncExclusionsTableTable,
+          SyncExclusionsTableData,
+          $$SyncExclusionsTableTableFilterComposer,
+          $$SyncExclusionsTableTableOrderingComposer,
+          $$SyncExclusionsTableTableAnnotationComposer,
+          $$SyncExclusionsTableTableCreateCompanionBuilder,
+          $$SyncExclusionsTableTableUpdateCompanionBuilder,
+          (
+            SyncExclusionsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $SyncExclusionsTableTable,
+              SyncExclusionsTableData
+            >,
+          ),
+          SyncExclusionsTableData,
+          PrefetchHooks Function()
+        > {
+  $$SyncExclusionsTableTableTableManager(
+    _$AppDatabase db,
+    $SyncExclusionsTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$SyncExclusionsTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$SyncExclusionsTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
+          createComputedFieldComposer: () =>
+              $$SyncExclusionsTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<String> folderId = const Value.absent(),
+                Value<String> path = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => SyncExclusionsTableCompanion(
+                folderId: folderId,
+                path: path,
+                createdAt: createdAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String folderId,
+                required String path,
+                required DateTime createdAt,
+                Value<int> rowid = const Value.absent(),
+              }) => SyncExclusionsTableCompanion.insert(
+                folderId: folderId,
+                path: path,
+                createdAt: createdAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$SyncExclusionsTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $SyncExclusionsTableTable,
+      SyncExclusionsTableData,
+      $$SyncExclusionsTableTableFilterComposer,
+      $$SyncExclusionsTableTableOrderingComposer,
+      $$SyncExclusionsTableTableAnnotationComposer,
+      $$SyncExclusionsTableTableCreateCompanionBuilder,
+      $$SyncExclusionsTableTableUpdateCompanionBuilder,
+      (
+        SyncExclusionsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $SyncExclusionsTableTable,
+          SyncExclusionsTableData
+        >,
+      ),
+      SyncExclusionsTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$FolderSyncModesTableTableCreateCompanionBuilder =
+    FolderSyncModesTableCompanion Function({
+      required String folderId,
+      required String path,
+      required String direction,
+      required DateTime createdAt,
+      Value<int> rowid,
+    });
+typedef $$FolderSyncModesTableTableUpdateCompanionBuilder =
+    FolderSyncModesTableCompanion Function({
+      Value<String> folderId,
+      Value<String> path,
+      Value<String> direction,
+      Value<DateTime> createdAt,
+      Value<int> rowid,
+    });
+
+class $$FolderSyncModesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $FolderSyncModesTableTable> {
+  $$FolderSyncModesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get direction => $composableBuilder(
+    column: $table.direction,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$FolderSyncModesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $FolderSyncModesTableTable> {
+  $$FolderSyncModesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get folderId => $composableBuilder(
+    column: $table.folderId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get path => $composableBuilder(
+    column: $table.path,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get direction => $composableBuilder(
+    column: $table.direction,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$FolderSyncModesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $FolderSyncModesTableTable> {
+  $$FolderSyncModesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get folderId =>
+      $composableBuilder(column: $table.folderId, builder: (column) => column);
+
+  GeneratedColumn<String> get path =>
+      $composableBuilder(column: $table.path, builder: (column) => column);
+
+  GeneratedColumn<String> get direction =>
+      $composableBuilder(column: $table.direction, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+}
+
+class $$FolderSyncModesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $FolderSyncModesTableTable,
+          FolderSyncModesTableData,
+          $$FolderSyncModesTableTableFilterComposer,
+          $$FolderSyncModesTableTableOrderingComposer,
+          $$FolderSyncModesTableTableAnnotationComposer,
+          $$FolderSyncModesTableTableCreateCompanionBuilder,
+          $$FolderSyncModesTableTableUpdateCompanionBuilder,
+          (
+            FolderSyncModesTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $FolderSyncModesTableTable,
+              FolderSyncModesTableData
+            >,
+          ),
+          FolderSyncModesTableData,
+          PrefetchHooks Function()
+        > {
+  $$FolderSyncModesTableTableTableManager(
+    _$AppDatabase db,
+    $FolderSyncModesTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$FolderSyncModesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$FolderSyncModesTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
+          createComputedFieldComposer: () =>
+              $$FolderSyncModesTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<String> folderId = const Value.absent(),
+                Value<String> path = const Value.absent(),
+                Value<String> direction = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => FolderSyncModesTableCompanion(
+                folderId: folderId,
+                path: path,
+                direction: direction,
+                createdAt: createdAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String folderId,
+                required String path,
+                required String direction,
+                required DateTime createdAt,
+                Value<int> rowid = const Value.absent(),
+              }) => FolderSyncModesTableCompanion.insert(
+                folderId: folderId,
+                path: path,
+                direction: direction,
+                createdAt: createdAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$FolderSyncModesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $FolderSyncModesTableTable,
+      FolderSyncModesTableData,
+      $$FolderSyncModesTableTableFilterComposer,
+      $$FolderSyncModesTableTableOrderingComposer,
+      $$FolderSyncModesTableTableAnnotationComposer,
+      $$FolderSyncModesTableTableCreateCompanionBuilder,
+      $$FolderSyncModesTableTableUpdateCompanionBuilder,
+      (
+        FolderSyncModesTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $FolderSyncModesTableTable,
+          FolderSyncModesTableData
+        >,
+      ),
+      FolderSyncModesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$ConflictCopiesTableTableCreateCompanionBuilder =
+    ConflictCopiesTableCompanion Function({
+      Value<int> id,
+      required String originalId,
+      required String copyId,
+      required String copyName,
+      Value<int?> conflictId,
+      required DateTime createdAt,
+      Value<DateTime?> dismissedAt,
+    });
+typedef $$ConflictCopiesTableTableUpdateCompanionBuilder =
+    ConflictCopiesTableCompanion Function({
+      Value<int> id,
+      Value<String> originalId,
+      Value<String> copyId,
+      Value<String> copyName,
+      Value<int?> conflictId,
+      Value<DateTime> createdAt,
+      Value<DateTime?> dismissedAt,
+    });
+
+class $$ConflictCopiesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $ConflictCopiesTableTable> {
+  $$ConflictCopiesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get originalId => $composableBuilder(
+    column: $table.originalId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get copyId => $composableBuilder(
+    column: $table.copyId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get copyName => $composableBuilder(
+    column: $table.copyName,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get conflictId => $composableBuilder(
+    column: $table.conflictId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get dismissedAt => $composableBuilder(
+    column: $table.dismissedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$ConflictCopiesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $ConflictCopiesTableTable> {
+  $$ConflictCopiesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get originalId => $composableBuilder(
+    column: $table.originalId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get copyId => $composableBuilder(
+    column: $table.copyId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get copyName => $composableBuilder(
+    column: $table.copyName,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get conflictId => $composableBuilder(
+    column: $table.conflictId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get dismissedAt => $composableBuilder(
+    column: $table.dismissedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$ConflictCopiesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $ConflictCopiesTableTable> {
+  $$ConflictCopiesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get originalId => $composableBuilder(
+    column: $table.originalId,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get copyId =>
+      $composableBuilder(column: $table.copyId, builder: (column) => column);
+
+  GeneratedColumn<String> get copyName =>
+      $composableBuilder(column: $table.copyName, builder: (column) => column);
+
+  GeneratedColumn<int> get conflictId => $composableBuilder(
+    column: $table.conflictId,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get dismissedAt => $composableBuilder(
+    column: $table.dismissedAt,
+    builder: (column) => column,
+  );
+}
+
+class $$ConflictCopiesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $ConflictCopiesTableTable,
+          ConflictCopiesTableData,
+          $$ConflictCopiesTableTableFilterComposer,
+          $$ConflictCopiesTableTableOrderingComposer,
+          $$ConflictCopiesTableTableAnnotationComposer,
+          $$ConflictCopiesTableTableCreateCompanionBuilder,
+          $$ConflictCopiesTableTableUpdateCompanionBuilder,
+          (
+            ConflictCopiesTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $ConflictCopiesTableTable,
+              ConflictCopiesTableData
+            >,
+          ),
+          ConflictCopiesTableData,
+          PrefetchHooks Function()
+        > {
+  $$ConflictCopiesTableTableTableManager(
+    _$AppDatabase db,
+    $ConflictCopiesTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$ConflictCopiesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$ConflictCopiesTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
+          createComputedFieldComposer: () =>
+              $$ConflictCopiesTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String> originalId = const Value.absent(),
+                Value<String> copyId = const Value.absent(),
+                Value<String> copyName = const Value.absent(),
+                Value<int?> conflictId = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+                Value<DateTime?> dismissedAt = const Value.absent(),
+              }) => ConflictCopiesTableCompanion(
+                id: id,
+                originalId: originalId,
+                copyId: copyId,
+                copyName: copyName,
+                conflictId: conflictId,
+                createdAt: createdAt,
+                dismissedAt: dismissedAt,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                required String originalId,
+                required String copyId,
+                required String copyName,
+                Value<int?> conflictId = const Value.absent(),
+                required DateTime createdAt,
+                Value<DateTime?> dismissedAt = const Value.absent(),
+              }) => ConflictCopiesTableCompanion.insert(
+                id: id,
+                originalId: originalId,
+                copyId: copyId,
+                copyName: copyName,
+                conflictId: conflictId,
+                createdAt: createdAt,
+                dismissedAt: dismissedAt,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$ConflictCopiesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $ConflictCopiesTableTable,
+      ConflictCopiesTableData,
+      $$ConflictCopiesTableTableFilterComposer,
+      $$ConflictCopiesTableTableOrderingComposer,
+      $$ConflictCopiesTableTableAnnotationComposer,
+      $$ConflictCopiesTableTableCreateCompanionBuilder,
+      $$ConflictCopiesTableTableUpdateCompanionBuilder,
+      (
+        ConflictCopiesTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $ConflictCopiesTableTable,
+          ConflictCopiesTableData
+        >,
+      ),
+      ConflictCopiesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$InboxRulesTableTableCreateCompanionBuilder =
+    InboxRulesTableCompanion Function({
+      Value<int> id,
+      Value<String?> namePattern,
+      required String destinationFolderId,
+      required String destinationPath,
+      required int position,
+      required DateTime createdAt,
+    });
+typedef $$InboxRulesTableTableUpdateCompanionBuilder =
+    InboxRulesTableCompanion Function({
+      Value<int> id,
+      Value<String?> namePattern,
+      Value<String> destinationFolderId,
+      Value<String> destinationPath,
+      Value<int> position,
+      Value<DateTime> createdAt,
+    });
+
+class $$InboxRulesTableTableFilterComposer
+    extends Composer<_$AppDatabase, $InboxRulesTableTable> {
+  $$InboxRulesTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get namePattern => $composableBuilder(
+    column: $table.namePattern,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get destinationFolderId => $composableBuilder(
+    column: $table.destinationFolderId,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<String> get destinationPath => $composableBuilder(
+    column: $table.destinationPath,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get position => $composableBuilder(
+    column: $table.position,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$InboxRulesTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $InboxRulesTableTable> {
+  $$InboxRulesTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<int> get id => $composableBuilder(
+    column: $table.id,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get namePattern => $composableBuilder(
+    column: $table.namePattern,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get destinationFolderId => $composableBuilder(
+    column: $table.destinationFolderId,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get destinationPath => $composableBuilder(
+    column: $table.destinationPath,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get position => $composableBuilder(
+    column: $table.position,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
+    column: $table.createdAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$InboxRulesTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $InboxRulesTableTable> {
+  $$InboxRulesTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<int> get id =>
+      $composableBuilder(column: $table.id, builder: (column) => column);
+
+  GeneratedColumn<String> get namePattern => $composableBuilder(
+    column: $table.namePattern,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get destinationFolderId => $composableBuilder(
+    column: $table.destinationFolderId,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<String> get destinationPath => $composableBuilder(
+    column: $table.destinationPath,
+    builder: (column) => column,
+  );
+
+  GeneratedColumn<int> get position =>
+      $composableBuilder(column: $table.position, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get createdAt =>
+      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+}
+
+class $$InboxRulesTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $InboxRulesTableTable,
+          InboxRulesTableData,
+          $$InboxRulesTableTableFilterComposer,
+          $$InboxRulesTableTableOrderingComposer,
+          $$InboxRulesTableTableAnnotationComposer,
+          $$InboxRulesTableTableCreateCompanionBuilder,
+          $$InboxRulesTableTableUpdateCompanionBuilder,
+          (
+            InboxRulesTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $InboxRulesTableTable,
+              InboxRulesTableData
+            >,
+          ),
+          InboxRulesTableData,
+          PrefetchHooks Function()
+        > {
+  $$InboxRulesTableTableTableManager(
+    _$AppDatabase db,
+    $InboxRulesTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$InboxRulesTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$InboxRulesTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$InboxRulesTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String?> namePattern = const Value.absent(),
+                Value<String> destinationFolderId = const Value.absent(),
+                Value<String> destinationPath = const Value.absent(),
+                Value<int> position = const Value.absent(),
+                Value<DateTime> createdAt = const Value.absent(),
+              }) => InboxRulesTableCompanion(
+                id: id,
+                namePattern: namePattern,
+                destinationFolderId: destinationFolderId,
+                destinationPath: destinationPath,
+                position: position,
+                createdAt: createdAt,
+              ),
+          createCompanionCallback:
+              ({
+                Value<int> id = const Value.absent(),
+                Value<String?> namePattern = const Value.absent(),
+                required String destinationFolderId,
+                required String destinationPath,
+                required int position,
+                required DateTime createdAt,
+              }) => InboxRulesTableCompanion.insert(
+                id: id,
+                namePattern: namePattern,
+                destinationFolderId: destinationFolderId,
+                destinationPath: destinationPath,
+                position: position,
+                createdAt: createdAt,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$InboxRulesTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $InboxRulesTableTable,
+      InboxRulesTableData,
+      $$InboxRulesTableTableFilterComposer,
+      $$InboxRulesTableTableOrderingComposer,
+      $$InboxRulesTableTableAnnotationComposer,
+      $$InboxRulesTableTableCreateCompanionBuilder,
+      $$InboxRulesTableTableUpdateCompanionBuilder,
+      (
+        InboxRulesTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $InboxRulesTableTable,
+          InboxRulesTableData
+        >,
+      ),
+      InboxRulesTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$InboxItemsTableTableCreateCompanionBuilder =
+    InboxItemsTableCompanion Function({
+      required String fileId,
+      required String name,
+      required String status,
+      Value<String?> destinationPath,
+      Value<String?> localPath,
+      Value<String?> detail,
+      required DateTime arrivedAt,
+      Value<int> rowid,
+    });
+typedef $$InboxItemsTableTableUpdateCompanionBuilder =
+    InboxItemsTableCompanion Function({
+      Value<String> fileId,
+      Value<String> name,
+      Value<String> status,
+      Value<String?> destinationPath,
+      Value<String?> localPath,
+      Value<String?> detail,
+      Value<DateTime> arrivedAt,
       Value<int> rowid,
     });
 
-class $$FilesTableTableFilterComposer
-    extends Composer<_$AppDatabase, $FilesTableTable> {
-  $$FilesTableTableFilterComposer({
+class $$InboxItemsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $InboxItemsTableTable> {
+  $$InboxItemsTableTableFilterComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnFilters<String> get id => $composableBuilder(
-    column: $table.id,
+  ColumnFilters<String> get fileId => $composableBuilder(
+    column: $table.fileId,
     builder: (column) => ColumnFilters(column),
   );
 
@@ -3781,319 +18533,420 @@ class $$FilesTableTableFilterComposer
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get path => $composableBuilder(
-    column: $table.path,
+  ColumnFilters<String> get status => $composableBuilder(
+    column: $table.status,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<int> get size => $composableBuilder(
-    column: $table.size,
+  ColumnFilters<String> get destinationPath => $composableBuilder(
+    column: $table.destinationPath,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get mimeType => $composableBuilder(
-    column: $table.mimeType,
+  ColumnFilters<String> get localPath => $composableBuilder(
+    column: $table.localPath,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get folderId => $composableBuilder(
-    column: $table.folderId,
+  ColumnFilters<String> get detail => $composableBuilder(
+    column: $table.detail,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get ownerId => $composableBuilder(
-    column: $table.ownerId,
+  ColumnFilters<DateTime> get arrivedAt => $composableBuilder(
+    column: $table.arrivedAt,
     builder: (column) => ColumnFilters(column),
   );
+}
 
-  ColumnFilters<String> get hash => $composableBuilder(
-    column: $table.hash,
-    builder: (column) => ColumnFilters(column),
+class $$InboxItemsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $InboxItemsTableTable> {
+  $$InboxItemsTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get fileId => $composableBuilder(
+    column: $table.fileId,
+    builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnFilters<String> get etag => $composableBuilder(
-    column: $table.etag,
-    builder: (column) => ColumnFilters(column),
+  ColumnOrderings<String> get name => $composableBuilder(
+    column: $table.name,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get status => $composableBuilder(
+    column: $table.status,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get destinationPath => $composableBuilder(
+    column: $table.destinationPath,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get localPath => $composableBuilder(
+    column: $table.localPath,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<String> get detail => $composableBuilder(
+    column: $table.detail,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get arrivedAt => $composableBuilder(
+    column: $table.arrivedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$InboxItemsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $InboxItemsTableTable> {
+  $$InboxItemsTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get fileId =>
+      $composableBuilder(column: $table.fileId, builder: (column) => column);
+
+  GeneratedColumn<String> get name =>
+      $composableBuilder(column: $table.name, builder: (column) => column);
+
+  GeneratedColumn<String> get status =>
+      $composableBuilder(column: $table.status, builder: (column) => column);
+
+  GeneratedColumn<String> get destinationPath => $composableBuilder(
+    column: $table.destinationPath,
+    builder: (column) => column,
   );
 
-  ColumnFilters<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
+  GeneratedColumn<String> get localPath =>
+      $composableBuilder(column: $table.localPath, builder: (column) => column);
+
+  GeneratedColumn<String> get detail =>
+      $composableBuilder(column: $table.detail, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get arrivedAt =>
+      $composableBuilder(column: $table.arrivedAt, builder: (column) => column);
+}
+
+class $$InboxItemsTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $InboxItemsTableTable,
+          InboxItemsTableData,
+          $$InboxItemsTableTableFilterComposer,
+          $$InboxItemsTableTableOrderingComposer,
+          $$InboxItemsTableTableAnnotationComposer,
+          $$InboxItemsTableTableCreateCompanionBuilder,
+          $$InboxItemsTableTableUpdateCompanionBuilder,
+          (
+            InboxItemsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $InboxItemsTableTable,
+              InboxItemsTableData
+            >,
+          ),
+          InboxItemsTableData,
+          PrefetchHooks Function()
+        > {
+  $$InboxItemsTableTableTableManager(
+    _$AppDatabase db,
+    $InboxItemsTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$InboxItemsTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$InboxItemsTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$InboxItemsTableTableAnnotationComposer($db: db, $table: table),
+          updateCompanionCallback:
+              ({
+                Value<String> fileId = const Value.absent(),
+                Value<String> name = const Value.absent(),
+                Value<String> status = const Value.absent(),
+                Value<String?> destinationPath = const Value.absent(),
+                Value<String?> localPath = const Value.absent(),
+                Value<String?> detail = const Value.absent(),
+                Value<DateTime> arrivedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => InboxItemsTableCompanion(
+                fileId: fileId,
+                name: name,
+                status: status,
+                destinationPath: destinationPath,
+                localPath: localPath,
+                detail: detail,
+                arrivedAt: arrivedAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String fileId,
+                required String name,
+                required String status,
+                Value<String?> destinationPath = const Value.absent(),
+                Value<String?> localPath = const Value.absent(),
+                Value<String?> detail = const Value.absent(),
+                required DateTime arrivedAt,
+                Value<int> rowid = const Value.absent(),
+              }) => InboxItemsTableCompanion.insert(
+                fileId: fileId,
+                name: name,
+                status: status,
+                destinationPath: destinationPath,
+                localPath: localPath,
+                detail: detail,
+                arrivedAt: arrivedAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$InboxItemsTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $InboxItemsTableTable,
+      InboxItemsTableData,
+      $$InboxItemsTableTableFilterComposer,
+      $$InboxItemsTableTableOrderingComposer,
+      $$InboxItemsTableTableAnnotationComposer,
+      $$InboxItemsTableTableCreateCompanionBuilder,
+      $$InboxItemsTableTableUpdateCompanionBuilder,
+      (
+        InboxItemsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $InboxItemsTableTable,
+          InboxItemsTableData
+        >,
+      ),
+      InboxItemsTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$SyncStatsTableTableCreateCompanionBuilder =
+    SyncStatsTableCompanion Function({
+      required DateTime day,
+      Value<int> bytesUploaded,
+      Value<int> bytesDownloaded,
+      Value<int> filesSynced,
+      Value<int> errors,
+      Value<int> transferMillis,
+      Value<int> rowid,
+    });
+typedef $$SyncStatsTableTableUpdateCompanionBuilder =
+    SyncStatsTableCompanion Function({
+      Value<DateTime> day,
+      Value<int> bytesUploaded,
+      Value<int> bytesDownloaded,
+      Value<int> filesSynced,
+      Value<int> errors,
+      Value<int> transferMillis,
+      Value<int> rowid,
+    });
+
+class $$SyncStatsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $SyncStatsTableTable> {
+  $$SyncStatsTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<DateTime> get day => $composableBuilder(
+    column: $table.day,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get modifiedAt => $composableBuilder(
-    column: $table.modifiedAt,
+  ColumnFilters<int> get bytesUploaded => $composableBuilder(
+    column: $table.bytesUploaded,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get syncedAt => $composableBuilder(
-    column: $table.syncedAt,
+  ColumnFilters<int> get bytesDownloaded => $composableBuilder(
+    column: $table.bytesDownloaded,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<bool> get isFavorite => $composableBuilder(
-    column: $table.isFavorite,
+  ColumnFilters<int> get filesSynced => $composableBuilder(
+    column: $table.filesSynced,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<bool> get isAvailableOffline => $composableBuilder(
-    column: $table.isAvailableOffline,
+  ColumnFilters<int> get errors => $composableBuilder(
+    column: $table.errors,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get localCachePath => $composableBuilder(
-    column: $table.localCachePath,
+  ColumnFilters<int> get transferMillis => $composableBuilder(
+    column: $table.transferMillis,
     builder: (column) => ColumnFilters(column),
   );
 }
 
-class $$FilesTableTableOrderingComposer
-    extends Composer<_$AppDatabase, $FilesTableTable> {
-  $$FilesTableTableOrderingComposer({
+class $$SyncStatsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $SyncStatsTableTable> {
+  $$SyncStatsTableTableOrderingComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnOrderings<String> get id => $composableBuilder(
-    column: $table.id,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get name => $composableBuilder(
-    column: $table.name,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get path => $composableBuilder(
-    column: $table.path,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<int> get size => $composableBuilder(
-    column: $table.size,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get mimeType => $composableBuilder(
-    column: $table.mimeType,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get folderId => $composableBuilder(
-    column: $table.folderId,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get ownerId => $composableBuilder(
-    column: $table.ownerId,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get hash => $composableBuilder(
-    column: $table.hash,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get etag => $composableBuilder(
-    column: $table.etag,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
+  ColumnOrderings<DateTime> get day => $composableBuilder(
+    column: $table.day,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get modifiedAt => $composableBuilder(
-    column: $table.modifiedAt,
+  ColumnOrderings<int> get bytesUploaded => $composableBuilder(
+    column: $table.bytesUploaded,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get syncedAt => $composableBuilder(
-    column: $table.syncedAt,
+  ColumnOrderings<int> get bytesDownloaded => $composableBuilder(
+    column: $table.bytesDownloaded,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<bool> get isFavorite => $composableBuilder(
-    column: $table.isFavorite,
+  ColumnOrderings<int> get filesSynced => $composableBuilder(
+    column: $table.filesSynced,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<bool> get isAvailableOffline => $composableBuilder(
-    column: $table.isAvailableOffline,
+  ColumnOrderings<int> get errors => $composableBuilder(
+    column: $table.errors,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get localCachePath => $composableBuilder(
-    column: $table.localCachePath,
+  ColumnOrderings<int> get transferMillis => $composableBuilder(
+    column: $table.transferMillis,
     builder: (column) => ColumnOrderings(column),
   );
 }
 
-class $$FilesTableTableAnnotationComposer
-    extends Composer<_$AppDatabase, $FilesTableTable> {
-  $$FilesTableTableAnnotationComposer({
+class $$SyncStatsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $SyncStatsTableTable> {
+  $$SyncStatsTableTableAnnotationComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  GeneratedColumn<String> get id =>
-      $composableBuilder(column: $table.id, builder: (column) => column);
-
-  GeneratedColumn<String> get name =>
-      $composableBuilder(column: $table.name, builder: (column) => column);
-
-  GeneratedColumn<String> get path =>
-      $composableBuilder(column: $table.path, builder: (column) => column);
-
-  GeneratedColumn<int> get size =>
-      $composableBuilder(column: $table.size, builder: (column) => column);
-
-  GeneratedColumn<String> get mimeType =>
-      $composableBuilder(column: $table.mimeType, builder: (column) => column);
-
-  GeneratedColumn<String> get folderId =>
-      $composableBuilder(column: $table.folderId, builder: (column) => column);
-
-  GeneratedColumn<String> get ownerId =>
-      $composableBuilder(column: $table.ownerId, builder: (column) => column);
-
-  GeneratedColumn<String> get hash =>
-      $composableBuilder(column: $table.hash, builder: (column) => column);
-
-  GeneratedColumn<String> get etag =>
-      $composableBuilder(column: $table.etag, builder: (column) => column);
-
-  GeneratedColumn<DateTime> get createdAt =>
-      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+  GeneratedColumn<DateTime> get day =>
+      $composableBuilder(column: $table.day, builder: (column) => column);
 
-  GeneratedColumn<DateTime> get modifiedAt => $composableBuilder(
-    column: $table.modifiedAt,
+  GeneratedColumn<int> get bytesUploaded => $composableBuilder(
+    column: $table.bytesUploaded,
     builder: (column) => column,
   );
 
-  GeneratedColumn<DateTime> get syncedAt =>
-      $composableBuilder(column: $table.syncedAt, builder: (column) => column);
-
-  GeneratedColumn<bool> get isFavorite => $composableBuilder(
-    column: $table.isFavorite,
+  GeneratedColumn<int> get bytesDownloaded => $composableBuilder(
+    column: $table.bytesDownloaded,
     builder: (column) => column,
   );
 
-  GeneratedColumn<bool> get isAvailableOffline => $composableBuilder(
-    column: $table.isAvailableOffline,
+  GeneratedColumn<int> get filesSynced => $composableBuilder(
+    column: $table.filesSynced,
     builder: (column) => column,
   );
 
-  GeneratedColumn<String> get localCachePath => $composableBuilder(
-    column: $table.localCachePath,
+  GeneratedColumn<int> get errors =>
+      $composableBuilder(column: $table.errors, builder: (column) => column);
+
+  GeneratedColumn<int> get transferMillis => $composableBuilder(
+    column: $table.transferMillis,
     builder: (column) => column,
   );
 }
 
-class $$FilesTableTableTableManager
+class $$SyncStatsTableTableTableManager
     extends
         RootTableManager<
           _$AppDatabase,
-          $FilesTableTable,
-          FilesTableData,
-          $$FilesTableTableFilterComposer,
-          $$FilesTableTableOrderingComposer,
-          $$FilesTableTableAnnotationComposer,
-          $$FilesTableTableCreateCompanionBuilder,
-          $$FilesTableTableUpdateCompanionBuilder,
+          $SyncStatsTableTable,
+          SyncStatsTableData,
+          $$SyncStatsTableTableFilterComposer,
+          $$SyncStatsTableTableOrderingComposer,
+          $$SyncStatsTableTableAnnotationComposer,
+          $$SyncStatsTableTableCreateCompanionBuilder,
+          $$SyncStatsTableTableUpdateCompanionBuilder,
           (
-            FilesTableData,
-            BaseReferences<_$AppDatabase, $FilesTableTable, FilesTableData>,
+            SyncStatsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $SyncStatsTableTable,
+              SyncStatsTableData
+            >,
           ),
-          FilesTableData,
+          SyncStatsTableData,
           PrefetchHooks Function()
         > {
-  $$FilesTableTableTableManager(_$AppDatabase db, $FilesTableTable table)
-    : super(
+  $$SyncStatsTableTableTableManager(
+    _$AppDatabase db,
+    $SyncStatsTableTable table,
+  ) : super(
         TableManagerState(
           db: db,
           table: table,
           createFilteringComposer: () =>
-              $$FilesTableTableFilterComposer($db: db, $table: table),
+              $$SyncStatsTableTableFilterComposer($db: db, $table: table),
           createOrderingComposer: () =>
-              $$FilesTableTableOrderingComposer($db: db, $table: table),
+              $$SyncStatsTableTableOrderingComposer($db: db, $table: table),
           createComputedFieldComposer: () =>
-              $$FilesTableTableAnnotationComposer($db: db, $table: table),
+              $$SyncStatsTableTableAnnotationComposer($db: db, $table: table),
           updateCompanionCallback:
               ({
-                Value<String> id = const Value.absent(),
-                Value<String> name = const Value.absent(),
-                Value<String> path = const Value.absent(),
-                Value<int> size = const Value.absent(),
-                Value<String> mimeType = const Value.absent(),
-                Value<String?> folderId = const Value.absent(),
-                Value<String?> ownerId = const Value.absent(),
-                Value<String?> hash = const Value.absent(),
-                Value<String?> etag = const Value.absent(),
-                Value<DateTime> createdAt = const Value.absent(),
-                Value<DateTime> modifiedAt = const Value.absent(),
-                Value<DateTime?> syncedAt = const Value.absent(),
-                Value<bool> isFavorite = const Value.absent(),
-                Value<bool> isAvailableOffline = const Value.absent(),
-                Value<String?> localCachePath = const Value.absent(),
+                Value<DateTime> day = const Value.absent(),
+                Value<int> bytesUploaded = const Value.absent(),
+                Value<int> bytesDownloaded = const Value.absent(),
+                Value<int> filesSynced = const Value.absent(),
+                Value<int> errors = const Value.absent(),
+                Value<int> transferMillis = const Value.absent(),
                 Value<int> rowid = const Value.absent(),
-              }) => FilesTableCompanion(
-                id: id,
-                name: name,
-                path: path,
-                size: size,
-                mimeType: mimeType,
-                folderId: folderId,
-                ownerId: ownerId,
-                hash: hash,
-                etag: etag,
-                createdAt: createdAt,
-                modifiedAt: modifiedAt,
-                syncedAt: syncedAt,
-                isFavorite: isFavorite,
-                isAvailableOffline: isAvailableOffline,
-                localCachePath: localCachePath,
+              }) => SyncStatsTableCompanion(
+                day: day,
+                bytesUploaded: bytesUploaded,
+                bytesDownloaded: bytesDownloaded,
+                filesSynced: filesSynced,
+                errors: errors,
+                transferMillis: transferMillis,
                 rowid: rowid,
               ),
           createCompanionCallback:
               ({
-                required String id,
-                required String name,
-                required String path,
-                required int size,
-                required String mimeType,
-                Value<String?> folderId = const Value.absent(),
-                Value<String?> ownerId = const Value.absent(),
-                Value<String?> hash = const Value.absent(),
-                Value<String?> etag = const Value.absent(),
-                required DateTime createdAt,
-                required DateTime modifiedAt,
-                Value<DateTime?> syncedAt = const Value.absent(),
-                Value<bool> isFavorite = const Value.absent(),
-                Value<bool> isAvailableOffline = const Value.absent(),
-                Value<String?> localCachePath = const Value.absent(),
+                required DateTime day,
+                Value<int> bytesUploaded = const Value.absent(),
+                Value<int> bytesDownloaded = const Value.absent(),
+                Value<int> filesSynced = const Value.absent(),
+                Value<int> errors = const Value.absent(),
+                Value<int> transferMillis = const Value.absent(),
                 Value<int> rowid = const Value.absent(),
-              }) => FilesTableCompanion.insert(
-                id: id,
-                name: name,
-                path: path,
-                size: size,
-                mimeType: mimeType,
-                folderId: folderId,
-                ownerId: ownerId,
-                hash: hash,
-                etag: etag,
-                createdAt: createdAt,
-                modifiedAt: modifiedAt,
-                syncedAt: syncedAt,
-                isFavorite: isFavorite,
-                isAvailableOffline: isAvailableOffline,
-                localCachePath: localCachePath,
+              }) => SyncStatsTableCompanion.insert(
+                day: day,
+                bytesUploaded: bytesUploaded,
+                bytesDownloaded: bytesDownloaded,
+                filesSynced: filesSynced,
+                errors: errors,
+                transferMillis: transferMillis,
                 rowid: rowid,
               ),
           withReferenceMapper: (p0) => p0
@@ -4104,274 +18957,153 @@ class $$FilesTableTableTableManager
       );
 }
 
-typedef $$FilesTableTableProcessedTableManager =
+typedef $$SyncStatsTableTableProcessedTableManager =
     ProcessedTableManager<
       _$AppDatabase,
-      $FilesTableTable,
-      FilesTableData,
-      $$FilesTableTableFilterComposer,
-      $$FilesTableTableOrderingComposer,
-      $$FilesTableTableAnnotationComposer,
-      $$FilesTableTableCreateCompanionBuilder,
-      $$FilesTableTableUpdateCompanionBuilder,
+      $SyncStatsTableTable,
+      SyncStatsTableData,
+      $$SyncStatsTableTableFilterComposer,
+      $$SyncStatsTableTableOrderingComposer,
+      $$SyncStatsTableTableAnnotationComposer,
+      $$SyncStatsTableTableCreateCompanionBuilder,
+      $$SyncStatsTableTableUpdateCompanionBuilder,
       (
-        FilesTableData,
-        BaseReferences<_$AppDatabase, $FilesTableTable, FilesTableData>,
+        SyncStatsTableData,
+        BaseReferences<_$AppDatabase, $SyncStatsTableTable, SyncStatsTableData>,
       ),
-      FilesTableData,
+      SyncStatsTableData,
       PrefetchHooks Function()
-    >;
-typedef $$FoldersTableTableCreateCompanionBuilder =
-    FoldersTableCompanion Function({
-      required String id,
-      required String name,
-      required String path,
-      Value<String?> parentId,
-      Value<String?> ownerId,
-      Value<bool> isRoot,
-      required DateTime createdAt,
-      required DateTime modifiedAt,
-      Value<DateTime?> syncedAt,
-      Value<int> rowid,
-    });
-typedef $$FoldersTableTableUpdateCompanionBuilder =
-    FoldersTableCompanion Function({
-      Value<String> id,
-      Value<String> name,
-      Value<String> path,
-      Value<String?> parentId,
-      Value<String?> ownerId,
-      Value<bool> isRoot,
-      Value<DateTime> createdAt,
-      Value<DateTime> modifiedAt,
-      Value<DateTime?> syncedAt,
+    >;
+typedef $$FolderSyncStatsTableTableCreateCompanionBuilder =
+    FolderSyncStatsTableCompanion Function({
+      required String folderId,
+      required DateTime lastSyncedAt,
+      Value<int> rowid,
+    });
+typedef $$FolderSyncStatsTableTableUpdateCompanionBuilder =
+    FolderSyncStatsTableCompanion Function({
+      Value<String> folderId,
+      Value<DateTime> lastSyncedAt,
       Value<int> rowid,
     });
 
-class $$FoldersTableTableFilterComposer
-    extends Composer<_$AppDatabase, $FoldersTableTable> {
-  $$FoldersTableTableFilterComposer({
+class $$FolderSyncStatsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $FolderSyncStatsTableTable> {
+  $$FolderSyncStatsTableTableFilterComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnFilters<String> get id => $composableBuilder(
-    column: $table.id,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get name => $composableBuilder(
-    column: $table.name,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get path => $composableBuilder(
-    column: $table.path,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get parentId => $composableBuilder(
-    column: $table.parentId,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get ownerId => $composableBuilder(
-    column: $table.ownerId,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<bool> get isRoot => $composableBuilder(
-    column: $table.isRoot,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<DateTime> get modifiedAt => $composableBuilder(
-    column: $table.modifiedAt,
+  ColumnFilters<String> get folderId => $composableBuilder(
+    column: $table.folderId,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get syncedAt => $composableBuilder(
-    column: $table.syncedAt,
+  ColumnFilters<DateTime> get lastSyncedAt => $composableBuilder(
+    column: $table.lastSyncedAt,
     builder: (column) => ColumnFilters(column),
   );
 }
 
-class $$FoldersTableTableOrderingComposer
-    extends Composer<_$AppDatabase, $FoldersTableTable> {
-  $$FoldersTableTableOrderingComposer({
+class $$FolderSyncStatsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $FolderSyncStatsTableTable> {
+  $$FolderSyncStatsTableTableOrderingComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnOrderings<String> get id => $composableBuilder(
-    column: $table.id,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get name => $composableBuilder(
-    column: $table.name,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get path => $composableBuilder(
-    column: $table.path,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get parentId => $composableBuilder(
-    column: $table.parentId,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get ownerId => $composableBuilder(
-    column: $table.ownerId,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<bool> get isRoot => $composableBuilder(
-    column: $table.isRoot,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<DateTime> get modifiedAt => $composableBuilder(
-    column: $table.modifiedAt,
+  ColumnOrderings<String> get folderId => $composableBuilder(
+    column: $table.folderId,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get syncedAt => $composableBuilder(
-    column: $table.syncedAt,
+  ColumnOrderings<DateTime> get lastSyncedAt => $composableBuilder(
+    column: $table.lastSyncedAt,
     builder: (column) => ColumnOrderings(column),
   );
 }
 
-class $$FoldersTableTableAnnotationComposer
-    extends Composer<_$AppDatabase, $FoldersTableTable> {
-  $$FoldersTableTableAnnotationComposer({
+class $$FolderSyncStatsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $FolderSyncStatsTableTable> {
+  $$FolderSyncStatsTableTableAnnotationComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  GeneratedColumn<String> get id =>
-      $composableBuilder(column: $table.id, builder: (column) => column);
-
-  GeneratedColumn<String> get name =>
-      $composableBuilder(column: $table.name, builder: (column) => column);
-
-  GeneratedColumn<String> get path =>
-      $composableBuilder(column: $table.path, builder: (column) => column);
-
-  GeneratedColumn<String> get parentId =>
-      $composableBuilder(column: $table.parentId, builder: (column) => column);
-
-  GeneratedColumn<String> get ownerId =>
-      $composableBuilder(column: $table.ownerId, builder: (column) => column);
-
-  GeneratedColumn<bool> get isRoot =>
-      $composableBuilder(column: $table.isRoot, builder: (column) => column);
-
-  GeneratedColumn<DateTime> get createdAt =>
-      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+  GeneratedColumn<String> get folderId =>
+      $composableBuilder(column: $table.folderId, builder: (column) => column);
 
-  GeneratedColumn<DateTime> get modifiedAt => $composableBuilder(
-    column: $table.modifiedAt,
+  GeneratedColumn<DateTime> get lastSyncedAt => $composableBuilder(
+    column: $table.lastSyncedAt,
     builder: (column) => column,
   );
-
-  GeneratedColumn<DateTime> get syncedAt =>
-      $composableBuilder(column: $table.syncedAt, builder: (column) => column);
 }
 
-class $$FoldersTableTableTableManager
+class $$FolderSyncStatsTableTableTableManager
     extends
         RootTableManager<
           _$AppDatabase,
-          $FoldersTableTable,
-          FoldersTableData,
-          $$FoldersTableTableFilterComposer,
-          $$FoldersTableTableOrderingComposer,
-          $$FoldersTableTableAnnotationComposer,
-          $$FoldersTableTableCreateCompanionBuilder,
-          $$FoldersTableTableUpdateCompanionBuilder,
+          $FolderSyncStatsTableTable,
+          FolderSyncStatsTableData,
+          $$FolderSyncStatsTableTableFilterComposer,
+          $$FolderSyncStatsTableTableOrderingComposer,
+          $$FolderSyncStatsTableTableAnnotationComposer,
+          $$FolderSyncStatsTableTableCreateCompanionBuilder,
+          $$FolderSyncStatsTableTableUpdateCompanionBuilder,
           (
-            FoldersTableData,
-            BaseReferences<_$AppDatabase, $FoldersTableTable, FoldersTableData>,
+            FolderSyncStatsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $FolderSyncStatsTableTable,
+              FolderSyncStatsTableData
+            >,
           ),
-          FoldersTableData,
+          FolderSyncStatsTableData,
           PrefetchHooks Function()
         > {
-  $$FoldersTableTableTableManager(_$AppDatabase db, $FoldersTableTable table)
-    : super(
+  $$FolderSyncStatsTableTableTableManager(
+    _$AppDatabase db,
+    $FolderSyncStatsTableTable table,
+  ) : super(
         TableManagerState(
           db: db,
           table: table,
           createFilteringComposer: () =>
-              $$FoldersTableTableFilterComposer($db: db, $table: table),
+              $$FolderSyncStatsTableTableFilterComposer($db: db, $table: table),
           createOrderingComposer: () =>
-              $$FoldersTableTableOrderingComposer($db: db, $table: table),
+              $$FolderSyncStatsTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
           createComputedFieldComposer: () =>
-              $$FoldersTableTableAnnotationComposer($db: db, $table: table),
+              $$FolderSyncStatsTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
           updateCompanionCallback:
               ({
-                Value<String> id = const Value.absent(),
-                Value<String> name = const Value.absent(),
-                Value<String> path = const Value.absent(),
-                Value<String?> parentId = const Value.absent(),
-                Value<String?> ownerId = const Value.absent(),
-                Value<bool> isRoot = const Value.absent(),
-                Value<DateTime> createdAt = const Value.absent(),
-                Value<DateTime> modifiedAt = const Value.absent(),
-                Value<DateTime?> syncedAt = const Value.absent(),
+                Value<String> folderId = const Value.absent(),
+                Value<DateTime> lastSyncedAt = const Value.absent(),
                 Value<int> rowid = const Value.absent(),
-              }) => FoldersTableCompanion(
-                id: id,
-                name: name,
-                path: path,
-                parentId: parentId,
-                ownerId: ownerId,
-                isRoot: isRoot,
-                createdAt: createdAt,
-                modifiedAt: modifiedAt,
-                syncedAt: syncedAt,
+              }) => FolderSyncStatsTableCompanion(
+                folderId: folderId,
+                lastSyncedAt: lastSyncedAt,
                 rowid: rowid,
               ),
           createCompanionCallback:
               ({
-                required String id,
-                required String name,
-                required String path,
-                Value<String?> parentId = const Value.absent(),
-                Value<String?> ownerId = const Value.absent(),
-                Value<bool> isRoot = const Value.absent(),
-                required DateTime createdAt,
-                required DateTime modifiedAt,
-                Value<DateTime?> syncedAt = const Value.absent(),
+                required String folderId,
+                required DateTime lastSyncedAt,
                 Value<int> rowid = const Value.absent(),
-              }) => FoldersTableCompanion.insert(
-                id: id,
-                name: name,
-                path: path,
-                parentId: parentId,
-                ownerId: ownerId,
-                isRoot: isRoot,
-                createdAt: createdAt,
-                modifiedAt: modifiedAt,
-                syncedAt: syncedAt,
+              }) => FolderSyncStatsTableCompanion.insert(
+                folderId: folderId,
+                lastSyncedAt: lastSyncedAt,
                 rowid: rowid,
               ),
           withReferenceMapper: (p0) => p0
@@ -4382,57 +19114,53 @@ class $$FoldersTableTableTableManager
       );
 }
 
-typedef $$FoldersTableTableProcessedTableManager =
+typedef $$FolderSyncStatsTableTableProcessedTableManager =
     ProcessedTableManager<
       _$AppDatabase,
-      $FoldersTableTable,
-      FoldersTableData,
-      $$FoldersTableTableFilterComposer,
-      $$FoldersTableTableOrderingComposer,
-      $$FoldersTableTableAnnotationComposer,
-      $$FoldersTableTableCreateCompanionBuilder,
-      $$FoldersTableTableUpdateCompanionBuilder,
+      $FolderSyncStatsTableTable,
+      FolderSyncStatsTableData,
+      $$FolderSyncStatsTableTableFilterComposer,
+      $$FolderSyncStatsTableTableOrderingComposer,
+      $$FolderSyncStatsTableTableAnnotationComposer,
+      $$FolderSyncStatsTableTableCreateCompanionBuilder,
+      $$FolderSyncStatsTableTableUpdateCompanionBuilder,
       (
-        FoldersTableData,
-        BaseReferences<_$AppDatabase, $FoldersTableTable, FoldersTableData>,
+        FolderSyncStatsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $FolderSyncStatsTableTable,
+          FolderSyncStatsTableData
+        >,
       ),
-      FoldersTableData,
+      FolderSyncStatsTableData,
       PrefetchHooks Function()
     >;
-typedef $$SyncQueueTableTableCreateCompanionBuilder =
-    SyncQueueTableCompanion Function({
+typedef $$OperationsTableTableCreateCompanionBuilder =
+    OperationsTableCompanion Function({
       Value<int> id,
+      required int syncOpId,
       required String operationType,
-      Value<String> status,
       required String itemId,
-      required String itemType,
       required String payload,
-      Value<int> retryCount,
-      Value<String?> errorMessage,
-      required DateTime createdAt,
-      required DateTime updatedAt,
-      Value<DateTime?> scheduledAt,
-      Value<int> priority,
+      Value<String> state,
+      required DateTime startedAt,
+      Value<DateTime?> finishedAt,
     });
-typedef $$SyncQueueTableTableUpdateCompanionBuilder =
-    SyncQueueTableCompanion Function({
+typedef $$OperationsTableTableUpdateCompanionBuilder =
+    OperationsTableCompanion Function({
       Value<int> id,
+      Value<int> syncOpId,
       Value<String> operationType,
-      Value<String> status,
       Value<String> itemId,
-      Value<String> itemType,
       Value<String> payload,
-      Value<int> retryCount,
-      Value<String?> errorMessage,
-      Value<DateTime> createdAt,
-      Value<DateTime> updatedAt,
-      Value<DateTime?> scheduledAt,
-      Value<int> priority,
+      Value<String> state,
+      Value<DateTime> startedAt,
+      Value<DateTime?> finishedAt,
     });
 
-class $$SyncQueueTableTableFilterComposer
-    extends Composer<_$AppDatabase, $SyncQueueTableTable> {
-  $$SyncQueueTableTableFilterComposer({
+class $$OperationsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $OperationsTableTable> {
+  $$OperationsTableTableFilterComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
@@ -4444,13 +19172,13 @@ class $$SyncQueueTableTableFilterComposer
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get operationType => $composableBuilder(
-    column: $table.operationType,
+  ColumnFilters<int> get syncOpId => $composableBuilder(
+    column: $table.syncOpId,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get status => $composableBuilder(
-    column: $table.status,
+  ColumnFilters<String> get operationType => $composableBuilder(
+    column: $table.operationType,
     builder: (column) => ColumnFilters(column),
   );
 
@@ -4459,50 +19187,30 @@ class $$SyncQueueTableTableFilterComposer
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get itemType => $composableBuilder(
-    column: $table.itemType,
-    builder: (column) => ColumnFilters(column),
-  );
-
   ColumnFilters<String> get payload => $composableBuilder(
     column: $table.payload,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<int> get retryCount => $composableBuilder(
-    column: $table.retryCount,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get errorMessage => $composableBuilder(
-    column: $table.errorMessage,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<DateTime> get updatedAt => $composableBuilder(
-    column: $table.updatedAt,
+  ColumnFilters<String> get state => $composableBuilder(
+    column: $table.state,
     builder: (column) => ColumnFilters(column),
   );
-
-  ColumnFilters<DateTime> get scheduledAt => $composableBuilder(
-    column: $table.scheduledAt,
+
+  ColumnFilters<DateTime> get startedAt => $composableBuilder(
+    column: $table.startedAt,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<int> get priority => $composableBuilder(
-    column: $table.priority,
+  ColumnFilters<DateTime> get finishedAt => $composableBuilder(
+    column: $table.finishedAt,
     builder: (column) => ColumnFilters(column),
   );
 }
 
-class $$SyncQueueTableTableOrderingComposer
-    extends Composer<_$AppDatabase, $SyncQueueTableTable> {
-  $$SyncQueueTableTableOrderingComposer({
+class $$OperationsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $OperationsTableTable> {
+  $$OperationsTableTableOrderingComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
@@ -4514,13 +19222,13 @@ class $$SyncQueueTableTableOrderingComposer
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get operationType => $composableBuilder(
-    column: $table.operationType,
+  ColumnOrderings<int> get syncOpId => $composableBuilder(
+    column: $table.syncOpId,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get status => $composableBuilder(
-    column: $table.status,
+  ColumnOrderings<String> get operationType => $composableBuilder(
+    column: $table.operationType,
     builder: (column) => ColumnOrderings(column),
   );
 
@@ -4529,50 +19237,30 @@ class $$SyncQueueTableTableOrderingComposer
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get itemType => $composableBuilder(
-    column: $table.itemType,
-    builder: (column) => ColumnOrderings(column),
-  );
-
   ColumnOrderings<String> get payload => $composableBuilder(
     column: $table.payload,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<int> get retryCount => $composableBuilder(
-    column: $table.retryCount,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get errorMessage => $composableBuilder(
-    column: $table.errorMessage,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<DateTime> get updatedAt => $composableBuilder(
-    column: $table.updatedAt,
+  ColumnOrderings<String> get state => $composableBuilder(
+    column: $table.state,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get scheduledAt => $composableBuilder(
-    column: $table.scheduledAt,
+  ColumnOrderings<DateTime> get startedAt => $composableBuilder(
+    column: $table.startedAt,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<int> get priority => $composableBuilder(
-    column: $table.priority,
+  ColumnOrderings<DateTime> get finishedAt => $composableBuilder(
+    column: $table.finishedAt,
     builder: (column) => ColumnOrderings(column),
   );
 }
 
-class $$SyncQueueTableTableAnnotationComposer
-    extends Composer<_$AppDatabase, $SyncQueueTableTable> {
-  $$SyncQueueTableTableAnnotationComposer({
+class $$OperationsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $OperationsTableTable> {
+  $$OperationsTableTableAnnotationComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
@@ -4582,138 +19270,106 @@ class $$SyncQueueTableTableAnnotationComposer
   GeneratedColumn<int> get id =>
       $composableBuilder(column: $table.id, builder: (column) => column);
 
+  GeneratedColumn<int> get syncOpId =>
+      $composableBuilder(column: $table.syncOpId, builder: (column) => column);
+
   GeneratedColumn<String> get operationType => $composableBuilder(
     column: $table.operationType,
     builder: (column) => column,
   );
 
-  GeneratedColumn<String> get status =>
-      $composableBuilder(column: $table.status, builder: (column) => column);
-
   GeneratedColumn<String> get itemId =>
       $composableBuilder(column: $table.itemId, builder: (column) => column);
 
-  GeneratedColumn<String> get itemType =>
-      $composableBuilder(column: $table.itemType, builder: (column) => column);
-
   GeneratedColumn<String> get payload =>
       $composableBuilder(column: $table.payload, builder: (column) => column);
 
-  GeneratedColumn<int> get retryCount => $composableBuilder(
-    column: $table.retryCount,
-    builder: (column) => column,
-  );
-
-  GeneratedColumn<String> get errorMessage => $composableBuilder(
-    column: $table.errorMessage,
-    builder: (column) => column,
-  );
-
-  GeneratedColumn<DateTime> get createdAt =>
-      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+  GeneratedColumn<String> get state =>
+      $composableBuilder(column: $table.state, builder: (column) => column);
 
-  GeneratedColumn<DateTime> get updatedAt =>
-      $composableBuilder(column: $table.updatedAt, builder: (column) => column);
+  GeneratedColumn<DateTime> get startedAt =>
+      $composableBuilder(column: $table.startedAt, builder: (column) => column);
 
-  GeneratedColumn<DateTime> get scheduledAt => $composableBuilder(
-    column: $table.scheduledAt,
+  GeneratedColumn<DateTime> get finishedAt => $composableBuilder(
+    column: $table.finishedAt,
     builder: (column) => column,
   );
-
-  GeneratedColumn<int> get priority =>
-      $composableBuilder(column: $table.priority, builder: (column) => column);
 }
 
-class $$SyncQueueTableTableTableManager
+class $$OperationsTableTableTableManager
     extends
         RootTableManager<
           _$AppDatabase,
-          $SyncQueueTableTable,
-          SyncQueueTableData,
-          $$SyncQueueTableTableFilterComposer,
-          $$SyncQueueTableTableOrderingComposer,
-          $$SyncQueueTableTableAnnotationComposer,
-          $$SyncQueueTableTableCreateCompanionBuilder,
-          $$SyncQueueTableTableUpdateCompanionBuilder,
+          $OperationsTableTable,
+          OperationsTableData,
+          $$OperationsTableTableFilterComposer,
+          $$OperationsTableTableOrderingComposer,
+          $$OperationsTableTableAnnotationComposer,
+          $$OperationsTableTableCreateCompanionBuilder,
+          $$OperationsTableTableUpdateCompanionBuilder,
           (
-            SyncQueueTableData,
+            OperationsTableData,
             BaseReferences<
               _$AppDatabase,
-              $SyncQueueTableTable,
-              SyncQueueTableData
+              $OperationsTableTable,
+              OperationsTableData
             >,
           ),
-          SyncQueueTableData,
+          OperationsTableData,
           PrefetchHooks Function()
         > {
-  $$SyncQueueTableTableTableManager(
+  $$OperationsTableTableTableManager(
     _$AppDatabase db,
-    $SyncQueueTableTable table,
+    $OperationsTableTable table,
   ) : super(
         TableManagerState(
           db: db,
           table: table,
           createFilteringComposer: () =>
-              $$SyncQueueTableTableFilterComposer($db: db, $table: table),
+              $$OperationsTableTableFilterComposer($db: db, $table: table),
           createOrderingComposer: () =>
-              $$SyncQueueTableTableOrderingComposer($db: db, $table: table),
+              $$OperationsTableTableOrderingComposer($db: db, $table: table),
           createComputedFieldComposer: () =>
-              $$SyncQueueTableTableAnnotationComposer($db: db, $table: table),
+              $$OperationsTableTableAnnotationComposer($db: db, $table: table),
           updateCompanionCallback:
               ({
                 Value<int> id = const Value.absent(),
+                Value<int> syncOpId = const Value.absent(),
                 Value<String> operationType = const Value.absent(),
-                Value<String> status = const Value.absent(),
                 Value<String> itemId = const Value.absent(),
-                Value<String> itemType = const Value.absent(),
                 Value<String> payload = const Value.absent(),
-                Value<int> retryCount = const Value.absent(),
-                Value<String?> errorMessage = const Value.absent(),
-                Value<DateTime> createdAt = const Value.absent(),
-                Value<DateTime> updatedAt = const Value.absent(),
-                Value<DateTime?> scheduledAt = const Value.absent(),
-                Value<int> priority = const Value.absent(),
-              }) => SyncQueueTableCompanion(
+                Value<String> state = const Value.absent(),
+                Value<DateTime> startedAt = const Value.absent(),
+                Value<DateTime?> finishedAt = const Value.absent(),
+              }) => OperationsTableCompanion(
                 id: id,
+                syncOpId: syncOpId,
                 operationType: operationType,
-                status: status,
                 itemId: itemId,
-                itemType: itemType,
                 payload: payload,
-                retryCount: retryCount,
-                errorMessage: errorMessage,
-                createdAt: createdAt,
-                updatedAt: updatedAt,
-                scheduledAt: scheduledAt,
-                priority: priority,
+                state: state,
+                startedAt: startedAt,
+                finishedAt: finishedAt,
               ),
           createCompanionCallback:
               ({
                 Value<int> id = const Value.absent(),
+                required int syncOpId,
                 required String operationType,
-                Value<String> status = const Value.absent(),
                 required String itemId,
-                required String itemType,
                 required String payload,
-                Value<int> retryCount = const Value.absent(),
-                Value<String?> errorMessage = const Value.absent(),
-                required DateTime createdAt,
-                required DateTime updatedAt,
-                Value<DateTime?> scheduledAt = const Value.absent(),
-                Value<int> priority = const Value.absent(),
-              }) => SyncQueueTableCompanion.insert(
+                Value<String> state = const Value.absent(),
+                required DateTime startedAt,
+                Value<DateTime?> finishedAt = const Value.absent(),
+              }) => OperationsTableCompanion.insert(
                 id: id,
+                syncOpId: syncOpId,
                 operationType: operationType,
-                status: status,
                 itemId: itemId,
-                itemType: itemType,
                 payload: payload,
-                retryCount: retryCount,
-                errorMessage: errorMessage,
-                createdAt: createdAt,
-                updatedAt: updatedAt,
-                scheduledAt: scheduledAt,
-                priority: priority,
+                state: state,
+                startedAt: startedAt,
+                finishedAt: finishedAt,
               ),
           withReferenceMapper: (p0) => p0
               .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
@@ -4723,64 +19379,238 @@ class $$SyncQueueTableTableTableManager
       );
 }
 
-typedef $$SyncQueueTableTableProcessedTableManager =
+typedef $$OperationsTableTableProcessedTableManager =
     ProcessedTableManager<
       _$AppDatabase,
-      $SyncQueueTableTable,
-      SyncQueueTableData,
-      $$SyncQueueTableTableFilterComposer,
-      $$SyncQueueTableTableOrderingComposer,
-      $$SyncQueueTableTableAnnotationComposer,
-      $$SyncQueueTableTableCreateCompanionBuilder,
-      $$SyncQueueTableTableUpdateCompanionBuilder,
+      $OperationsTableTable,
+      OperationsTableData,
+      $$OperationsTableTableFilterComposer,
+      $$OperationsTableTableOrderingComposer,
+      $$OperationsTableTableAnnotationComposer,
+      $$OperationsTableTableCreateCompanionBuilder,
+      $$OperationsTableTableUpdateCompanionBuilder,
       (
-        SyncQueueTableData,
-        BaseReferences<_$AppDatabase, $SyncQueueTableTable, SyncQueueTableData>,
+        OperationsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $OperationsTableTable,
+          OperationsTableData
+        >,
       ),
-      SyncQueueTableData,
+      OperationsTableData,
       PrefetchHooks Function()
     >;
-typedef $$SyncConflictsTableTableCreateCompanionBuilder =
-    SyncConflictsTableCompanion Function({
-      Value<int> id,
+typedef $$UsageCountersTableTableCreateCompanionBuilder =
+    UsageCountersTableCompanion Function({
+      required String feature,
+      Value<int> count,
+      required DateTime lastUsedAt,
+      Value<int> rowid,
+    });
+typedef $$UsageCountersTableTableUpdateCompanionBuilder =
+    UsageCountersTableCompanion Function({
+      Value<String> feature,
+      Value<int> count,
+      Value<DateTime> lastUsedAt,
+      Value<int> rowid,
+    });
+
+class $$UsageCountersTableTableFilterComposer
+    extends Composer<_$AppDatabase, $UsageCountersTableTable> {
+  $$UsageCountersTableTableFilterComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnFilters<String> get feature => $composableBuilder(
+    column: $table.feature,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<int> get count => $composableBuilder(
+    column: $table.count,
+    builder: (column) => ColumnFilters(column),
+  );
+
+  ColumnFilters<DateTime> get lastUsedAt => $composableBuilder(
+    column: $table.lastUsedAt,
+    builder: (column) => ColumnFilters(column),
+  );
+}
+
+class $$UsageCountersTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $UsageCountersTableTable> {
+  $$UsageCountersTableTableOrderingComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  ColumnOrderings<String> get feature => $composableBuilder(
+    column: $table.feature,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<int> get count => $composableBuilder(
+    column: $table.count,
+    builder: (column) => ColumnOrderings(column),
+  );
+
+  ColumnOrderings<DateTime> get lastUsedAt => $composableBuilder(
+    column: $table.lastUsedAt,
+    builder: (column) => ColumnOrderings(column),
+  );
+}
+
+class $$UsageCountersTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $UsageCountersTableTable> {
+  $$UsageCountersTableTableAnnotationComposer({
+    required super.$db,
+    required super.$table,
+    super.joinBuilder,
+    super.$addJoinBuilderToRootComposer,
+    super.$removeJoinBuilderFromRootComposer,
+  });
+  GeneratedColumn<String> get feature =>
+      $composableBuilder(column: $table.feature, builder: (column) => column);
+
+  GeneratedColumn<int> get count =>
+      $composableBuilder(column: $table.count, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get lastUsedAt => $composableBuilder(
+    column: $table.lastUsedAt,
+    builder: (column) => column,
+  );
+}
+
+class $$UsageCountersTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $UsageCountersTableTable,
+          UsageCountersTableData,
+          $$UsageCountersTableTableFilterComposer,
+          $$UsageCountersTableTableOrderingComposer,
+          $$UsageCountersTableTableAnnotationComposer,
+          $$UsageCountersTableTableCreateCompanionBuilder,
+          $$UsageCountersTableTableUpdateCompanionBuilder,
+          (
+            UsageCountersTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $UsageCountersTableTable,
+              UsageCountersTableData
+            >,
+          ),
+          UsageCountersTableData,
+          PrefetchHooks Function()
+        > {
+  $$UsageCountersTableTableTableManager(
+    _$AppDatabase db,
+    $UsageCountersTableTable table,
+  ) : super(
+        TableManagerState(
+          db: db,
+          table: table,
+          createFilteringComposer: () =>
+              $$UsageCountersTableTableFilterComposer($db: db, $table: table),
+          createOrderingComposer: () =>
+              $$UsageCountersTableTableOrderingComposer($db: db, $table: table),
+          createComputedFieldComposer: () =>
+              $$UsageCountersTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
+          updateCompanionCallback:
+              ({
+                Value<String> feature = const Value.absent(),
+                Value<int> count = const Value.absent(),
+                Value<DateTime> lastUsedAt = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => UsageCountersTableCompanion(
+                feature: feature,
+                count: count,
+                lastUsedAt: lastUsedAt,
+                rowid: rowid,
+              ),
+          createCompanionCallback:
+              ({
+                required String feature,
+                Value<int> count = const Value.absent(),
+                required DateTime lastUsedAt,
+                Value<int> rowid = const Value.absent(),
+              }) => UsageCountersTableCompanion.insert(
+                feature: feature,
+                count: count,
+                lastUsedAt: lastUsedAt,
+                rowid: rowid,
+              ),
+          withReferenceMapper: (p0) => p0
+              .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
+              .toList(),
+          prefetchHooksCallback: null,
+        ),
+      );
+}
+
+typedef $$UsageCountersTableTableProcessedTableManager =
+    ProcessedTableManager<
+      _$AppDatabase,
+      $UsageCountersTableTable,
+      UsageCountersTableData,
+      $$UsageCountersTableTableFilterComposer,
+      $$UsageCountersTableTableOrderingComposer,
+      $$UsageCountersTableTableAnnotationComposer,
+      $$UsageCountersTableTableCreateCompanionBuilder,
+      $$UsageCountersTableTableUpdateCompanionBuilder,
+      (
+        UsageCountersTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $UsageCountersTableTable,
+          UsageCountersTableData
+        >,
+      ),
+      UsageCountersTableData,
+      PrefetchHooks Function()
+    >;
+typedef $$SyncErrorsTableTableCreateCompanionBuilder =
+    SyncErrorsTableCompanion Function({
       required String itemId,
       required String itemType,
       required String operationType,
-      Value<String> conflictType,
-      required String payload,
-      Value<String?> errorMessage,
-      Value<String?> resolution,
-      Value<DateTime?> resolvedAt,
-      required DateTime createdAt,
+      required String category,
+      required String message,
+      Value<int> retryCount,
+      required DateTime failedAt,
+      Value<bool> ignored,
+      Value<int> rowid,
     });
-typedef $$SyncConflictsTableTableUpdateCompanionBuilder =
-    SyncConflictsTableCompanion Function({
-      Value<int> id,
+typedef $$SyncErrorsTableTableUpdateCompanionBuilder =
+    SyncErrorsTableCompanion Function({
       Value<String> itemId,
       Value<String> itemType,
       Value<String> operationType,
-      Value<String> conflictType,
-      Value<String> payload,
-      Value<String?> errorMessage,
-      Value<String?> resolution,
-      Value<DateTime?> resolvedAt,
-      Value<DateTime> createdAt,
-    });
-
-class $$SyncConflictsTableTableFilterComposer
-    extends Composer<_$AppDatabase, $SyncConflictsTableTable> {
-  $$SyncConflictsTableTableFilterComposer({
+      Value<String> category,
+      Value<String> message,
+      Value<int> retryCount,
+      Value<DateTime> failedAt,
+      Value<bool> ignored,
+      Value<int> rowid,
+    });
+
+class $$SyncErrorsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $SyncErrorsTableTable> {
+  $$SyncErrorsTableTableFilterComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnFilters<int> get id => $composableBuilder(
-    column: $table.id,
-    builder: (column) => ColumnFilters(column),
-  );
-
   ColumnFilters<String> get itemId => $composableBuilder(
     column: $table.itemId,
     builder: (column) => ColumnFilters(column),
@@ -4796,51 +19626,41 @@ class $$SyncConflictsTableTableFilterComposer
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get conflictType => $composableBuilder(
-    column: $table.conflictType,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get payload => $composableBuilder(
-    column: $table.payload,
+  ColumnFilters<String> get category => $composableBuilder(
+    column: $table.category,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get errorMessage => $composableBuilder(
-    column: $table.errorMessage,
+  ColumnFilters<String> get message => $composableBuilder(
+    column: $table.message,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get resolution => $composableBuilder(
-    column: $table.resolution,
+  ColumnFilters<int> get retryCount => $composableBuilder(
+    column: $table.retryCount,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get resolvedAt => $composableBuilder(
-    column: $table.resolvedAt,
+  ColumnFilters<DateTime> get failedAt => $composableBuilder(
+    column: $table.failedAt,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
+  ColumnFilters<bool> get ignored => $composableBuilder(
+    column: $table.ignored,
     builder: (column) => ColumnFilters(column),
   );
 }
 
-class $$SyncConflictsTableTableOrderingComposer
-    extends Composer<_$AppDatabase, $SyncConflictsTableTable> {
-  $$SyncConflictsTableTableOrderingComposer({
+class $$SyncErrorsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $SyncErrorsTableTable> {
+  $$SyncErrorsTableTableOrderingComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnOrderings<int> get id => $composableBuilder(
-    column: $table.id,
-    builder: (column) => ColumnOrderings(column),
-  );
-
   ColumnOrderings<String> get itemId => $composableBuilder(
     column: $table.itemId,
     builder: (column) => ColumnOrderings(column),
@@ -4856,49 +19676,41 @@ class $$SyncConflictsTableTableOrderingComposer
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get conflictType => $composableBuilder(
-    column: $table.conflictType,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get payload => $composableBuilder(
-    column: $table.payload,
+  ColumnOrderings<String> get category => $composableBuilder(
+    column: $table.category,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get errorMessage => $composableBuilder(
-    column: $table.errorMessage,
+  ColumnOrderings<String> get message => $composableBuilder(
+    column: $table.message,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get resolution => $composableBuilder(
-    column: $table.resolution,
+  ColumnOrderings<int> get retryCount => $composableBuilder(
+    column: $table.retryCount,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get resolvedAt => $composableBuilder(
-    column: $table.resolvedAt,
+  ColumnOrderings<DateTime> get failedAt => $composableBuilder(
+    column: $table.failedAt,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get createdAt => $composableBuilder(
-    column: $table.createdAt,
+  ColumnOrderings<bool> get ignored => $composableBuilder(
+    column: $table.ignored,
     builder: (column) => ColumnOrderings(column),
   );
 }
 
-class $$SyncConflictsTableTableAnnotationComposer
-    extends Composer<_$AppDatabase, $SyncConflictsTableTable> {
-  $$SyncConflictsTableTableAnnotationComposer({
+class $$SyncErrorsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $SyncErrorsTableTable> {
+  $$SyncErrorsTableTableAnnotationComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  GeneratedColumn<int> get id =>
-      $composableBuilder(column: $table.id, builder: (column) => column);
-
   GeneratedColumn<String> get itemId =>
       $composableBuilder(column: $table.itemId, builder: (column) => column);
 
@@ -4910,118 +19722,102 @@ class $$SyncConflictsTableTableAnnotationComposer
     builder: (column) => column,
   );
 
-  GeneratedColumn<String> get conflictType => $composableBuilder(
-    column: $table.conflictType,
-    builder: (column) => column,
-  );
-
-  GeneratedColumn<String> get payload =>
-      $composableBuilder(column: $table.payload, builder: (column) => column);
+  GeneratedColumn<String> get category =>
+      $composableBuilder(column: $table.category, builder: (column) => column);
 
-  GeneratedColumn<String> get errorMessage => $composableBuilder(
-    column: $table.errorMessage,
-    builder: (column) => column,
-  );
+  GeneratedColumn<String> get message =>
+      $composableBuilder(column: $table.message, builder: (column) => column);
 
-  GeneratedColumn<String> get resolution => $composableBuilder(
-    column: $table.resolution,
+  GeneratedColumn<int> get retryCount => $composableBuilder(
+    column: $table.retryCount,
     builder: (column) => column,
   );
 
-  GeneratedColumn<DateTime> get resolvedAt => $composableBuilder(
-    column: $table.resolvedAt,
-    builder: (column) => column,
-  );
+  GeneratedColumn<DateTime> get failedAt =>
+      $composableBuilder(column: $table.failedAt, builder: (column) => column);
 
-  GeneratedColumn<DateTime> get createdAt =>
-      $composableBuilder(column: $table.createdAt, builder: (column) => column);
+  GeneratedColumn<bool> get ignored =>
+      $composableBuilder(column: $table.ignored, builder: (column) => column);
 }
 
-class $$SyncConflictsTableTableTableManager
+class $$SyncErrorsTableTableTableManager
     extends
         RootTableManager<
           _$AppDatabase,
-          $SyncConflictsTableTable,
-          SyncConflictsTableData,
-          $$SyncConflictsTableTableFilterComposer,
-          $$SyncConflictsTableTableOrderingComposer,
-          $$SyncConflictsTableTableAnnotationComposer,
-          $$SyncConflictsTableTableCreateCompanionBuilder,
-          $$SyncConflictsTableTableUpdateCompanionBuilder,
+          $SyncErrorsTableTable,
+          SyncErrorsTableData,
+          $$SyncErrorsTableTableFilterComposer,
+          $$SyncErrorsTableTableOrderingComposer,
+          $$SyncErrorsTableTableAnnotationComposer,
+          $$SyncErrorsTableTableCreateCompanionBuilder,
+          $$SyncErrorsTableTableUpdateCompanionBuilder,
           (
-            SyncConflictsTableData,
+            SyncErrorsTableData,
             BaseReferences<
               _$AppDatabase,
-              $SyncConflictsTableTable,
-              SyncConflictsTableData
+              $SyncErrorsTableTable,
+              SyncErrorsTableData
             >,
           ),
-          SyncConflictsTableData,
+          SyncErrorsTableData,
           PrefetchHooks Function()
         > {
-  $$SyncConflictsTableTableTableManager(
+  $$SyncErrorsTableTableTableManager(
     _$AppDatabase db,
-    $SyncConflictsTableTable table,
+    $SyncErrorsTableTable table,
   ) : super(
         TableManagerState(
           db: db,
           table: table,
           createFilteringComposer: () =>
-              $$SyncConflictsTableTableFilterComposer($db: db, $table: table),
+              $$SyncErrorsTableTableFilterComposer($db: db, $table: table),
           createOrderingComposer: () =>
-              $$SyncConflictsTableTableOrderingComposer($db: db, $table: table),
+              $$SyncErrorsTableTableOrderingComposer($db: db, $table: table),
           createComputedFieldComposer: () =>
-              $$SyncConflictsTableTableAnnotationComposer(
-                $db: db,
-                $table: table,
-              ),
+              $$SyncErrorsTableTableAnnotationComposer($db: db, $table: table),
           updateCompanionCallback:
               ({
-                Value<int> id = const Value.absent(),
                 Value<String> itemId = const Value.absent(),
                 Value<String> itemType = const Value.absent(),
                 Value<String> operationType = const Value.absent(),
-                Value<String> conflictType = const Value.absent(),
-                Value<String> payload = const Value.absent(),
-                Value<String?> errorMessage = const Value.absent(),
-                Value<String?> resolution = const Value.absent(),
-                Value<DateTime?> resolvedAt = const Value.absent(),
-                Value<DateTime> createdAt = const Value.absent(),
-              }) => SyncConflictsTableCompanion(
-                id: id,
+                Value<String> category = const Value.absent(),
+                Value<String> message = const Value.absent(),
+                Value<int> retryCount = const Value.absent(),
+                Value<DateTime> failedAt = const Value.absent(),
+                Value<bool> ignored = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => SyncErrorsTableCompanion(
                 itemId: itemId,
                 itemType: itemType,
                 operationType: operationType,
-                conflictType: conflictType,
-                payload: payload,
-                errorMessage: errorMessage,
-                resolution: resolution,
-                resolvedAt: resolvedAt,
-                createdAt: createdAt,
+                category: category,
+                message: message,
+                retryCount: retryCount,
+                failedAt: failedAt,
+                ignored: ignored,
+                rowid: rowid,
               ),
           createCompanionCallback:
               ({
-                Value<int> id = const Value.absent(),
                 required String itemId,
                 required String itemType,
                 required String operationType,
-                Value<String> conflictType = const Value.absent(),
-                required String payload,
-                Value<String?> errorMessage = const Value.absent(),
-                Value<String?> resolution = const Value.absent(),
-                Value<DateTime?> resolvedAt = const Value.absent(),
-                required DateTime createdAt,
-              }) => SyncConflictsTableCompanion.insert(
-                id: id,
+                required String category,
+                required String message,
+                Value<int> retryCount = const Value.absent(),
+                required DateTime failedAt,
+                Value<bool> ignored = const Value.absent(),
+                Value<int> rowid = const Value.absent(),
+              }) => SyncErrorsTableCompanion.insert(
                 itemId: itemId,
                 itemType: itemType,
                 operationType: operationType,
-                conflictType: conflictType,
-                payload: payload,
-                errorMessage: errorMessage,
-                resolution: resolution,
-                resolvedAt: resolvedAt,
-                createdAt: createdAt,
+                category: category,
+                message: message,
+                retryCount: retryCount,
+                failedAt: failedAt,
+                ignored: ignored,
+                rowid: rowid,
               ),
           withReferenceMapper: (p0) => p0
               .map((e) => (e.readTable(table), BaseReferences(db, table, e)))
@@ -5031,53 +19827,49 @@ class $$SyncConflictsTableTableTableManager
       );
 }
 
-typedef $$SyncConflictsTableTableProcessedTableManager =
+typedef $$SyncErrorsTableTableProcessedTableManager =
     ProcessedTableManager<
       _$AppDatabase,
-      $SyncConflictsTableTable,
-      SyncConflictsTableData,
-      $$SyncConflictsTableTableFilterComposer,
-      $$SyncConflictsTableTableOrderingComposer,
-      $$SyncConflictsTableTableAnnotationComposer,
-      $$SyncConflictsTableTableCreateCompanionBuilder,
-      $$SyncConflictsTableTableUpdateCompanionBuilder,
+      $SyncErrorsTableTable,
+      SyncErrorsTableData,
+      $$SyncErrorsTableTableFilterComposer,
+      $$SyncErrorsTableTableOrderingComposer,
+      $$SyncErrorsTableTableAnnotationComposer,
+      $$SyncErrorsTableTableCreateCompanionBuilder,
+      $$SyncErrorsTableTableUpdateCompanionBuilder,
       (
-        SyncConflictsTableData,
+        SyncErrorsTableData,
         BaseReferences<
           _$AppDatabase,
-          $SyncConflictsTableTable,
-          SyncConflictsTableData
+          $SyncErrorsTableTable,
+          SyncErrorsTableData
         >,
       ),
-      SyncConflictsTableData,
+      SyncErrorsTableData,
       PrefetchHooks Function()
     >;
-typedef $$CachedFilesTableTableCreateCompanionBuilder =
-    CachedFilesTableCompanion Function({
+typedef $$PartialDownloadsTableTableCreateCompanionBuilder =
+    PartialDownloadsTableCompanion Function({
       required String fileId,
-      required String localPath,
-      required int sizeBytes,
-      Value<String?> hash,
-      required DateTime cachedAt,
-      required DateTime lastAccessedAt,
-      Value<bool> isPinned,
+      required String path,
+      required String targetPath,
+      required String etag,
+      required DateTime updatedAt,
       Value<int> rowid,
     });
-typedef $$CachedFilesTableTableUpdateCompanionBuilder =
-    CachedFilesTableCompanion Function({
+typedef $$PartialDownloadsTableTableUpdateCompanionBuilder =
+    PartialDownloadsTableCompanion Function({
       Value<String> fileId,
-      Value<String> localPath,
-      Value<int> sizeBytes,
-      Value<String?> hash,
-      Value<DateTime> cachedAt,
-      Value<DateTime> lastAccessedAt,
-      Value<bool> isPinned,
+      Value<String> path,
+      Value<String> targetPath,
+      Value<String> etag,
+      Value<DateTime> updatedAt,
       Value<int> rowid,
     });
 
-class $$CachedFilesTableTableFilterComposer
-    extends Composer<_$AppDatabase, $CachedFilesTableTable> {
-  $$CachedFilesTableTableFilterComposer({
+class $$PartialDownloadsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $PartialDownloadsTableTable> {
+  $$PartialDownloadsTableTableFilterComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
@@ -5089,40 +19881,30 @@ class $$CachedFilesTableTableFilterComposer
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get localPath => $composableBuilder(
-    column: $table.localPath,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<int> get sizeBytes => $composableBuilder(
-    column: $table.sizeBytes,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get hash => $composableBuilder(
-    column: $table.hash,
+  ColumnFilters<String> get path => $composableBuilder(
+    column: $table.path,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get cachedAt => $composableBuilder(
-    column: $table.cachedAt,
+  ColumnFilters<String> get targetPath => $composableBuilder(
+    column: $table.targetPath,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get lastAccessedAt => $composableBuilder(
-    column: $table.lastAccessedAt,
+  ColumnFilters<String> get etag => $composableBuilder(
+    column: $table.etag,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<bool> get isPinned => $composableBuilder(
-    column: $table.isPinned,
+  ColumnFilters<DateTime> get updatedAt => $composableBuilder(
+    column: $table.updatedAt,
     builder: (column) => ColumnFilters(column),
   );
 }
 
-class $$CachedFilesTableTableOrderingComposer
-    extends Composer<_$AppDatabase, $CachedFilesTableTable> {
-  $$CachedFilesTableTableOrderingComposer({
+class $$PartialDownloadsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $PartialDownloadsTableTable> {
+  $$PartialDownloadsTableTableOrderingComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
@@ -5134,40 +19916,30 @@ class $$CachedFilesTableTableOrderingComposer
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get localPath => $composableBuilder(
-    column: $table.localPath,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<int> get sizeBytes => $composableBuilder(
-    column: $table.sizeBytes,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get hash => $composableBuilder(
-    column: $table.hash,
+  ColumnOrderings<String> get path => $composableBuilder(
+    column: $table.path,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get cachedAt => $composableBuilder(
-    column: $table.cachedAt,
+  ColumnOrderings<String> get targetPath => $composableBuilder(
+    column: $table.targetPath,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get lastAccessedAt => $composableBuilder(
-    column: $table.lastAccessedAt,
+  ColumnOrderings<String> get etag => $composableBuilder(
+    column: $table.etag,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<bool> get isPinned => $composableBuilder(
-    column: $table.isPinned,
+  ColumnOrderings<DateTime> get updatedAt => $composableBuilder(
+    column: $table.updatedAt,
     builder: (column) => ColumnOrderings(column),
   );
 }
 
-class $$CachedFilesTableTableAnnotationComposer
-    extends Composer<_$AppDatabase, $CachedFilesTableTable> {
-  $$CachedFilesTableTableAnnotationComposer({
+class $$PartialDownloadsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $PartialDownloadsTableTable> {
+  $$PartialDownloadsTableTableAnnotationComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
@@ -5177,100 +19949,95 @@ class $$CachedFilesTableTableAnnotationComposer
   GeneratedColumn<String> get fileId =>
       $composableBuilder(column: $table.fileId, builder: (column) => column);
 
-  GeneratedColumn<String> get localPath =>
-      $composableBuilder(column: $table.localPath, builder: (column) => column);
-
-  GeneratedColumn<int> get sizeBytes =>
-      $composableBuilder(column: $table.sizeBytes, builder: (column) => column);
-
-  GeneratedColumn<String> get hash =>
-      $composableBuilder(column: $table.hash, builder: (column) => column);
-
-  GeneratedColumn<DateTime> get cachedAt =>
-      $composableBuilder(column: $table.cachedAt, builder: (column) => column);
+  GeneratedColumn<String> get path =>
+      $composableBuilder(column: $table.path, builder: (column) => column);
 
-  GeneratedColumn<DateTime> get lastAccessedAt => $composableBuilder(
-    column: $table.lastAccessedAt,
+  GeneratedColumn<String> get targetPath => $composableBuilder(
+    column: $table.targetPath,
     builder: (column) => column,
   );
 
-  GeneratedColumn<bool> get isPinned =>
-      $composableBuilder(column: $table.isPinned, builder: (column) => column);
+  GeneratedColumn<String> get etag =>
+      $composableBuilder(column: $table.etag, builder: (column) => column);
+
+  GeneratedColumn<DateTime> get updatedAt =>
+      $composableBuilder(column: $table.updatedAt, builder: (column) => column);
 }
 
-class $$CachedFilesTableTableTableManager
-    extends
-        RootTableManager<
-          _$AppDatabase,
-          $CachedFilesTableTable,
-          CachedFilesTableData,
-          $$CachedFilesTableTableFilterComposer,
-          $$CachedFilesTableTableOrderingComposer,
-          $$CachedFilesTableTableAnnotationComposer,
-          $$CachedFilesTableTableCreateCompanionBuilder,
-          $$CachedFilesTableTableUpdateCompanionBuilder,
+class $$PartialDownloadsTableTableTableManager
+    extends
+        RootTableManager<
+          _$AppDatabase,
+          $PartialDownloadsTableTable,
+          PartialDownloadsTableData,
+          $$PartialDownloadsTableTableFilterComposer,
+          $$PartialDownloadsTableTableOrderingComposer,
+          $$PartialDownloadsTableTableAnnotationComposer,
+          $$PartialDownloadsTableTableCreateCompanionBuilder,
+          $$PartialDownloadsTableTableUpdateCompanionBuilder,
           (
-            CachedFilesTableData,
+            PartialDownloadsTableData,
             BaseReferences<
               _$AppDatabase,
-              $CachedFilesTableTable,
-              CachedFilesTableData
+              $PartialDownloadsTableTable,
+              PartialDownloadsTableData
             >,
           ),
-          CachedFilesTableData,
+          PartialDownloadsTableData,
           PrefetchHooks Function()
         > {
-  $$CachedFilesTableTableTableManager(
+  $$PartialDownloadsTableTableTableManager(
     _$AppDatabase db,
-    $CachedFilesTableTable table,
+    $PartialDownloadsTableTable table,
   ) : super(
         TableManagerState(
           db: db,
           table: table,
           createFilteringComposer: () =>
-              $$CachedFilesTableTableFilterComposer($db: db, $table: table),
+              $$PartialDownloadsTableTableFilterComposer(
+                $db: db,
+                $table: table,
+              ),
           createOrderingComposer: () =>
-              $$CachedFilesTableTableOrderingComposer($db: db, $table: table),
+              $$PartialDownloadsTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
           createComputedFieldComposer: () =>
-              $$CachedFilesTableTableAnnotationComposer($db: db, $table: table),
+              $$PartialDownloadsTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
           updateCompanionCallback:
               ({
                 Value<String> fileId = const Value.absent(),
-                Value<String> localPath = const Value.absent(),
-                Value<int> sizeBytes = const Value.absent(),
-                Value<String?> hash = const Value.absent(),
-                Value<DateTime> cachedAt = const Value.absent(),
-                Value<DateTime> lastAccessedAt = const Value.absent(),
-                Value<bool> isPinned = const Value.absent(),
+                Value<String> path = const Value.absent(),
+                Value<String> targetPath = const Value.absent(),
+                Value<String> etag = const Value.absent(),
+                Value<DateTime> updatedAt = const Value.absent(),
                 Value<int> rowid = const Value.absent(),
-              }) => CachedFilesTableCompanion(
+              }) => PartialDownloadsTableCompanion(
                 fileId: fileId,
-                localPath: localPath,
-                sizeBytes: sizeBytes,
-                hash: hash,
-                cachedAt: cachedAt,
-                lastAccessedAt: lastAccessedAt,
-                isPinned: isPinned,
+                path: path,
+                targetPath: targetPath,
+                etag: etag,
+                updatedAt: updatedAt,
                 rowid: rowid,
               ),
           createCompanionCallback:
               ({
                 required String fileId,
-                required String localPath,
-                required int sizeBytes,
-                Value<String?> hash = const Value.absent(),
-                required DateTime cachedAt,
-                required DateTime lastAccessedAt,
-                Value<bool> isPinned = const Value.absent(),
+                required String path,
+                required String targetPath,
+                required String etag,
+                required DateTime updatedAt,
                 Value<int> rowid = const Value.absent(),
-              }) => CachedFilesTableCompanion.insert(
+              }) => PartialDownloadsTableCompanion.insert(
                 fileId: fileId,
-                localPath: localPath,
-                sizeBytes: sizeBytes,
-                hash: hash,
-                cachedAt: cachedAt,
-                lastAccessedAt: lastAccessedAt,
-                isPinned: isPinned,
+                path: path,
+                targetPath: targetPath,
+                etag: etag,
+                updatedAt: updatedAt,
                 rowid: rowid,
               ),
           withReferenceMapper: (p0) => p0
@@ -5281,244 +20048,233 @@ class $$CachedFilesTableTableTableManager
       );
 }
 
-typedef $$CachedFilesTableTableProcessedTableManager =
+typedef $$PartialDownloadsTableTableProcessedTableManager =
     ProcessedTableManager<
       _$AppDatabase,
-      $CachedFilesTableTable,
-      CachedFilesTableData,
-      $$CachedFilesTableTableFilterComposer,
-      $$CachedFilesTableTableOrderingComposer,
-      $$CachedFilesTableTableAnnotationComposer,
-      $$CachedFilesTableTableCreateCompanionBuilder,
-      $$CachedFilesTableTableUpdateCompanionBuilder,
+      $PartialDownloadsTableTable,
+      PartialDownloadsTableData,
+      $$PartialDownloadsTableTableFilterComposer,
+      $$PartialDownloadsTableTableOrderingComposer,
+      $$PartialDownloadsTableTableAnnotationComposer,
+      $$PartialDownloadsTableTableCreateCompanionBuilder,
+      $$PartialDownloadsTableTableUpdateCompanionBuilder,
       (
-        CachedFilesTableData,
+        PartialDownloadsTableData,
         BaseReferences<
           _$AppDatabase,
-          $CachedFilesTableTable,
-          CachedFilesTableData
+          $PartialDownloadsTableTable,
+          PartialDownloadsTableData
         >,
       ),
-      CachedFilesTableData,
+      PartialDownloadsTableData,
       PrefetchHooks Function()
     >;
-typedef $$UserTableTableCreateCompanionBuilder =
-    UserTableCompanion Function({
-      required String id,
-      required String username,
-      Value<String?> email,
-      Value<String> role,
-      Value<int?> storageQuotaBytes,
-      Value<int?> storageUsedBytes,
-      Value<DateTime?> lastSyncAt,
+typedef $$ContentDigestsTableTableCreateCompanionBuilder =
+    ContentDigestsTableCompanion Function({
+      required String fileId,
+      required String algorithm,
+      required String digest,
+      required int size,
+      Value<String?> etag,
+      required DateTime recordedAt,
       Value<int> rowid,
     });
-typedef $$UserTableTableUpdateCompanionBuilder =
-    UserTableCompanion Function({
-      Value<String> id,
-      Value<String> username,
-      Value<String?> email,
-      Value<String> role,
-      Value<int?> storageQuotaBytes,
-      Value<int?> storageUsedBytes,
-      Value<DateTime?> lastSyncAt,
+typedef $$ContentDigestsTableTableUpdateCompanionBuilder =
+    ContentDigestsTableCompanion Function({
+      Value<String> fileId,
+      Value<String> algorithm,
+      Value<String> digest,
+      Value<int> size,
+      Value<String?> etag,
+      Value<DateTime> recordedAt,
       Value<int> rowid,
     });
 
-class $$UserTableTableFilterComposer
-    extends Composer<_$AppDatabase, $UserTableTable> {
-  $$UserTableTableFilterComposer({
+class $$ContentDigestsTableTableFilterComposer
+    extends Composer<_$AppDatabase, $ContentDigestsTableTable> {
+  $$ContentDigestsTableTableFilterComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnFilters<String> get id => $composableBuilder(
-    column: $table.id,
-    builder: (column) => ColumnFilters(column),
-  );
-
-  ColumnFilters<String> get username => $composableBuilder(
-    column: $table.username,
+  ColumnFilters<String> get fileId => $composableBuilder(
+    column: $table.fileId,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get email => $composableBuilder(
-    column: $table.email,
+  ColumnFilters<String> get algorithm => $composableBuilder(
+    column: $table.algorithm,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<String> get role => $composableBuilder(
-    column: $table.role,
+  ColumnFilters<String> get digest => $composableBuilder(
+    column: $table.digest,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<int> get storageQuotaBytes => $composableBuilder(
-    column: $table.storageQuotaBytes,
+  ColumnFilters<int> get size => $composableBuilder(
+    column: $table.size,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<int> get storageUsedBytes => $composableBuilder(
-    column: $table.storageUsedBytes,
+  ColumnFilters<String> get etag => $composableBuilder(
+    column: $table.etag,
     builder: (column) => ColumnFilters(column),
   );
 
-  ColumnFilters<DateTime> get lastSyncAt => $composableBuilder(
-    column: $table.lastSyncAt,
+  ColumnFilters<DateTime> get recordedAt => $composableBuilder(
+    column: $table.recordedAt,
     builder: (column) => ColumnFilters(column),
   );
 }
 
-class $$UserTableTableOrderingComposer
-    extends Composer<_$AppDatabase, $UserTableTable> {
-  $$UserTableTableOrderingComposer({
+class $$ContentDigestsTableTableOrderingComposer
+    extends Composer<_$AppDatabase, $ContentDigestsTableTable> {
+  $$ContentDigestsTableTableOrderingComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  ColumnOrderings<String> get id => $composableBuilder(
-    column: $table.id,
-    builder: (column) => ColumnOrderings(column),
-  );
-
-  ColumnOrderings<String> get username => $composableBuilder(
-    column: $table.username,
+  ColumnOrderings<String> get fileId => $composableBuilder(
+    column: $table.fileId,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get email => $composableBuilder(
-    column: $table.email,
+  ColumnOrderings<String> get algorithm => $composableBuilder(
+    column: $table.algorithm,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<String> get role => $composableBuilder(
-    column: $table.role,
+  ColumnOrderings<String> get digest => $composableBuilder(
+    column: $table.digest,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<int> get storageQuotaBytes => $composableBuilder(
-    column: $table.storageQuotaBytes,
+  ColumnOrderings<int> get size => $composableBuilder(
+    column: $table.size,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<int> get storageUsedBytes => $composableBuilder(
-    column: $table.storageUsedBytes,
+  ColumnOrderings<String> get etag => $composableBuilder(
+    column: $table.etag,
     builder: (column) => ColumnOrderings(column),
   );
 
-  ColumnOrderings<DateTime> get lastSyncAt => $composableBuilder(
-    column: $table.lastSyncAt,
+  ColumnOrderings<DateTime> get recordedAt => $composableBuilder(
+    column: $table.recordedAt,
     builder: (column) => ColumnOrderings(column),
   );
 }
 
-class $$UserTableTableAnnotationComposer
-    extends Composer<_$AppDatabase, $UserTableTable> {
-  $$UserTableTableAnnotationComposer({
+class $$ContentDigestsTableTableAnnotationComposer
+    extends Composer<_$AppDatabase, $ContentDigestsTableTable> {
+  $$ContentDigestsTableTableAnnotationComposer({
     required super.$db,
     required super.$table,
     super.joinBuilder,
     super.$addJoinBuilderToRootComposer,
     super.$removeJoinBuilderFromRootComposer,
   });
-  GeneratedColumn<String> get id =>
-      $composableBuilder(column: $table.id, builder: (column) => column);
-
-  GeneratedColumn<String> get username =>
-      $composableBuilder(column: $table.username, builder: (column) => column);
+  GeneratedColumn<String> get fileId =>
+      $composableBuilder(column: $table.fileId, builder: (column) => column);
 
-  GeneratedColumn<String> get email =>
-      $composableBuilder(column: $table.email, builder: (column) => column);
+  GeneratedColumn<String> get algorithm =>
+      $composableBuilder(column: $table.algorithm, builder: (column) => column);
 
-  GeneratedColumn<String> get role =>
-      $composableBuilder(column: $table.role, builder: (column) => column);
+  GeneratedColumn<String> get digest =>
+      $composableBuilder(column: $table.digest, builder: (column) => column);
 
-  GeneratedColumn<int> get storageQuotaBytes => $composableBuilder(
-    column: $table.storageQuotaBytes,
-    builder: (column) => column,
-  );
+  GeneratedColumn<int> get size =>
+      $composableBuilder(column: $table.size, builder: (column) => column);
 
-  GeneratedColumn<int> get storageUsedBytes => $composableBuilder(
-    column: $table.storageUsedBytes,
-    builder: (column) => column,
-  );
+  GeneratedColumn<String> get etag =>
+      $composableBuilder(column: $table.etag, builder: (column) => column);
 
-  GeneratedColumn<DateTime> get lastSyncAt => $composableBuilder(
-    column: $table.lastSyncAt,
+  GeneratedColumn<DateTime> get recordedAt => $composableBuilder(
+    column: $table.recordedAt,
     builder: (column) => column,
   );
 }
 
-class $$UserTableTableTableManager
+class $$ContentDigestsTableTableTableManager
     extends
         RootTableManager<
           _$AppDatabase,
-          $UserTableTable,
-          UserTableData,
-          $$UserTableTableFilterComposer,
-          $$UserTableTableOrderingComposer,
-          $$UserTableTableAnnotationComposer,
-          $$UserTableTableCreateCompanionBuilder,
-          $$UserTableTableUpdateCompanionBuilder,
+          $ContentDigestsTableTable,
+          ContentDigestsTableData,
+          $$ContentDigestsTableTableFilterComposer,
+          $$ContentDigestsTableTableOrderingComposer,
+          $$ContentDigestsTableTableAnnotationComposer,
+          $$ContentDigestsTableTableCreateCompanionBuilder,
+          $$ContentDigestsTableTableUpdateCompanionBuilder,
           (
-            UserTableData,
-            BaseReferences<_$AppDatabase, $UserTableTable, UserTableData>,
+            ContentDigestsTableData,
+            BaseReferences<
+              _$AppDatabase,
+              $ContentDigestsTableTable,
+              ContentDigestsTableData
+            >,
           ),
-          UserTableData,
+          ContentDigestsTableData,
           PrefetchHooks Function()
         > {
-  $$UserTableTableTableManager(_$AppDatabase db, $UserTableTable table)
-    : super(
+  $$ContentDigestsTableTableTableManager(
+    _$AppDatabase db,
+    $ContentDigestsTableTable table,
+  ) : super(
         TableManagerState(
           db: db,
           table: table,
           createFilteringComposer: () =>
-              $$UserTableTableFilterComposer($db: db, $table: table),
+              $$ContentDigestsTableTableFilterComposer($db: db, $table: table),
           createOrderingComposer: () =>
-              $$UserTableTableOrderingComposer($db: db, $table: table),
+              $$ContentDigestsTableTableOrderingComposer(
+                $db: db,
+                $table: table,
+              ),
           createComputedFieldComposer: () =>
-              $$UserTableTableAnnotationComposer($db: db, $table: table),
+              $$ContentDigestsTableTableAnnotationComposer(
+                $db: db,
+                $table: table,
+              ),
           updateCompanionCallback:
               ({
-                Value<String> id = const Value.absent(),
-                Value<String> username = const Value.absent(),
-                Value<String?> email = const Value.absent(),
-                Value<String> role = const Value.absent(),
-                Value<int?> storageQuotaBytes = const Value.absent(),
-                Value<int?> storageUsedBytes = const Value.absent(),
-                Value<DateTime?> lastSyncAt = const Value.absent(),
+                Value<String> fileId = const Value.absent(),
+                Value<String> algorithm = const Value.absent(),
+                Value<String> digest = const Value.absent(),
+                Value<int> size = const Value.absent(),
+                Value<String?> etag = const Value.absent(),
+                Value<DateTime> recordedAt = const Value.absent(),
                 Value<int> rowid = const Value.absent(),
-              }) => UserTableCompanion(
-                id: id,
-                username: username,
-                email: email,
-                role: role,
-                storageQuotaBytes: storageQuotaBytes,
-                storageUsedBytes: storageUsedBytes,
-                lastSyncAt: lastSyncAt,
+              }) => ContentDigestsTableCompanion(
+                fileId: fileId,
+                algorithm: algorithm,
+                digest: digest,
+                size: size,
+                etag: etag,
+                recordedAt: recordedAt,
                 rowid: rowid,
               ),
           createCompanionCallback:
               ({
-                required String id,
-                required String username,
-                Value<String?> email = const Value.absent(),
-                Value<String> role = const Value.absent(),
-                Value<int?> storageQuotaBytes = const Value.absent(),
-                Value<int?> storageUsedBytes = const Value.absent(),
-                Value<DateTime?> lastSyncAt = const Value.absent(),
+                required String fileId,
+                required String algorithm,
+                required String digest,
+                required int size,
+                Value<String?> etag = const Value.absent(),
+                required DateTime recordedAt,
                 Value<int> rowid = const Value.absent(),
-              }) => UserTableCompanion.insert(
-                id: id,
-                username: username,
-                email: email,
-                role: role,
-                storageQuotaBytes: storageQuotaBytes,
-                storageUsedBytes: storageUsedBytes,
-                lastSyncAt: lastSyncAt,
+              }) => ContentDigestsTableCompanion.insert(
+                fileId: fileId,
+                algorithm: algorithm,
+                digest: digest,
+                size: size,
+                etag: etag,
+                recordedAt: recordedAt,
                 rowid: rowid,
               ),
           withReferenceMapper: (p0) => p0
@@ -5529,21 +20285,25 @@ class $$UserTableTableTableManager
       );
 }
 
-typedef $$UserTableTableProcessedTableManager =
+typedef $$ContentDigestsTableTableProcessedTableManager =
     ProcessedTableManager<
       _$AppDatabase,
-      $UserTableTable,
-      UserTableData,
-      $$UserTableTableFilterComposer,
-      $$UserTableTableOrderingComposer,
-      $$UserTableTableAnnotationComposer,
-      $$UserTableTableCreateCompanionBuilder,
-      $$UserTableTableUpdateCompanionBuilder,
+      $ContentDigestsTableTable,
+      ContentDigestsTableData,
+      $$ContentDigestsTableTableFilterComposer,
+      $$ContentDigestsTableTableOrderingComposer,
+      $$ContentDigestsTableTableAnnotationComposer,
+      $$ContentDigestsTableTableCreateCompanionBuilder,
+      $$ContentDigestsTableTableUpdateCompanionBuilder,
       (
-        UserTableData,
-        BaseReferences<_$AppDatabase, $UserTableTable, UserTableData>,
+        ContentDigestsTableData,
+        BaseReferences<
+          _$AppDatabase,
+          $ContentDigestsTableTable,
+          ContentDigestsTableData
+        >,
       ),
-      UserTableData,
+      ContentDigestsTableData,
       PrefetchHooks Function()
     >;
 
@@ -5562,4 +20322,46 @@ class $AppDatabaseManager {
       $$CachedFilesTableTableTableManager(_db, _db.cachedFilesTable);
   $$UserTableTableTableManager get userTable =>
       $$UserTableTableTableManager(_db, _db.userTable);
+  $$UploadSessionsTableTableTableManager get uploadSessionsTable =>
+      $$UploadSessionsTableTableTableManager(_db, _db.uploadSessionsTable);
+  $$ActivityLogTableTableTableManager get activityLogTable =>
+      $$ActivityLogTableTableTableManager(_db, _db.activityLogTable);
+  $$FolderScanStateTableTableTableManager get folderScanStateTable =>
+      $$FolderScanStateTableTableTableManager(_db, _db.folderScanStateTable);
+  $$LinkedSharesTableTableTableManager get linkedSharesTable =>
+      $$LinkedSharesTableTableTableManager(_db, _db.linkedSharesTable);
+  $$TransferLedgerTableTableTableManager get transferLedgerTable =>
+      $$TransferLedgerTableTableTableManager(_db, _db.transferLedgerTable);
+  $$FileModesTableTableTableManager get fileModesTable =>
+      $$FileModesTableTableTableManager(_db, _db.fileModesTable);
+  $$DbLeaseTableTableTableManager get dbLeaseTable =>
+      $$DbLeaseTableTableTableManager(_db, _db.dbLeaseTable);
+  $$ConflictRulesTableTableTableManager get conflictRulesTable =>
+      $$ConflictRulesTableTableTableManager(_db, _db.conflictRulesTable);
+  $$CachedListingsTableTableTableManager get cachedListingsTable =>
+      $$CachedListingsTableTableTableManager(_db, _db.cachedListingsTable);
+  $$SyncExclusionsTableTableTableManager get syncExclusionsTable =>
+      $$SyncExclusionsTableTableTableManager(_db, _db.syncExclusionsTable);
+  $$FolderSyncModesTableTableTableManager get folderSyncModesTable =>
+      $$FolderSyncModesTableTableTableManager(_db, _db.folderSyncModesTable);
+  $$ConflictCopiesTableTableTableManager get conflictCopiesTable =>
+      $$ConflictCopiesTableTableTableManager(_db, _db.conflictCopiesTable);
+  $$InboxRulesTableTableTableManager get inboxRulesTable =>
+      $$InboxRulesTableTableTableManager(_db, _db.inboxRulesTable);
+  $$InboxItemsTableTableTableManager get inboxItemsTable =>
+      $$InboxItemsTableTableTableManager(_db, _db.inboxItemsTable);
+  $$SyncStatsTableTableTableManager get syncStatsTable =>
+      $$SyncStatsTableTableTableManager(_db, _db.syncStatsTable);
+  $$FolderSyncStatsTableTableTableManager get folderSyncStatsTable =>
+      $$FolderSyncStatsTableTableTableManager(_db, _db.folderSyncStatsTable);
+  $$OperationsTableTableTableManager get operationsTable =>
+      $$OperationsTableTableTableManager(_db, _db.operationsTable);
+  $$UsageCountersTableTableTableManager get usageCountersTable =>
+      $$UsageCountersTableTableTableManager(_db, _db.usageCountersTable);
+  $$SyncErrorsTableTableTableManager get syncErrorsTable =>
+      $$SyncErrorsTableTableTableManager(_db, _db.syncErrorsTable);
+  $$PartialDownloadsTableTableTableManager get partialDownloadsTable =>
+      $$PartialDownloadsTableTableTableManager(_db, _db.partialDownloadsTable);
+  $$ContentDigestsTableTableTableManager get contentDigestsTable =>
+      $$ContentDigestsTableTableTableManager(_db, _db.contentDigestsTable);
 }
@@ -0,0 +1,193 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:flutter/foundation.dart';
+import 'package:logger/logger.dart';
+import 'package:uuid/uuid.dart';
+
+import 'app_database.dart';
+
+/// Keeps a heartbeat lease on the local database so that a second process
+/// opening the same file (another app instance, a backup agent restoring
+/// it) is noticed instead of silently interleaving writes.
+///
+/// Another instance of the app is kept out before it touches the database
+/// at all by an OS lock on [lockPath], so it never runs schema migrations
+/// on a database in use. The lease row catches other writers.
+class DbLeaseService extends ChangeNotifier {
+  final AppDatabase _db;
+  final Duration heartbeat;
+
+  /// File locked for as long as the lease is held; none when unset.
+  final String? lockPath;
+
+  /// A lease whose heartbeat is older than this belongs to a process that
+  /// exited without releasing it.
+  final Duration staleAfter;
+
+  final String ownerId = const Uuid().v4();
+  final Logger _logger = Logger();
+
+  Timer? _timer;
+  RandomAccessFile? _lock;
+  int? _dataVersion;
+  bool _held = false;
+  DbLeaseTableData? _foreignOwner;
+  bool _foreignWrites = false;
+
+  DbLeaseService(
+    this._db, {
+    this.lockPath,
+    this.heartbeat = const Duration(seconds: 10),
+    this.staleAfter = const Duration(seconds: 30),
+  });
+
+  /// Another live process holding (or having taken over) the lease.
+  DbLeaseTableData? get foreignOwner => _foreignOwner;
+
+  /// Whether some other connection has committed to the database since
+  /// the lease was acquired.
+  bool get foreignWritesDetected => _foreignWrites;
+
+  /// Take the lease. Returns null on success, or the live lease held by
+  /// another process.
+  Future<DbLeaseTableData?> acquire() async {
+    if (!await _lockFile()) {
+      _foreignOwner = await _lockOwner();
+      return _foreignOwner;
+    }
+    final holder = await _db.transaction(() async {
+      final current = await _db.getDbLease();
+      final now = DateTime.now();
+      if (current != null &&
+          current.ownerId != ownerId &&
+          now.difference(current.heartbeatAt) < staleAfter) {
+        return current;
+      }
+      await _db.upsertDbLease(
+        DbLeaseTableCompanion.insert(
+          ownerId: ownerId,
+          pid: pid,
+          hostname: Platform.localHostname,
+          acquiredAt: now,
+          heartbeatAt: now,
+        ),
+      );
+      return null;
+    });
+    _held = holder == null;
+    _foreignOwner = holder;
+    if (_held) {
+      _dataVersion = await _db.dataVersion();
+    } else {
+      await _unlockFile();
+    }
+    return holder;
+  }
+
+  String get _ownerPath => '$lockPath.owner';
+
+  /// Lock [lockPath], noting who holds it. False if another process does.
+  Future<bool> _lockFile() async {
+    final path = lockPath;
+    if (path == null || _lock != null) return true;
+    final file = await File(path).open(mode: FileMode.append);
+    try {
+      await file.lock(FileLock.exclusive);
+    } on FileSystemException {
+      await file.close();
+      return false;
+    }
+    _lock = file;
+    await File(_ownerPath).writeAsString(
+      json.encode({
+        'owner_id': ownerId,
+        'pid': pid,
+        'hostname': Platform.localHostname,
+        'acquired_at': DateTime.now().toIso8601String(),
+      }),
+    );
+    return true;
+  }
+
+  Future<void> _unlockFile() async {
+    final lock = _lock;
+    if (lock == null) return;
+    _lock = null;
+    try {
+      await File(_ownerPath).delete();
+    } on FileSystemException {
+      // Already gone.
+    }
+    await lock.unlock();
+    await lock.close();
+  }
+
+  /// The process holding [lockPath], as far as it noted itself.
+  Future<DbLeaseTableData> _lockOwner() async {
+    Map<String, dynamic> owner;
+    try {
+      owner =
+          json.decode(await File(_ownerPath).readAsString())
+              as Map<String, dynamic>;
+    } catch (_) {
+      owner = const {};
+    }
+    final acquiredAt =
+        DateTime.tryParse(owner['acquired_at'] as String? ?? '') ??
+        DateTime.now();
+    return DbLeaseTableData(
+      id: 1,
+      ownerId: owner['owner_id'] as String? ?? '',
+      pid: owner['pid'] as int? ?? 0,
+      hostname: owner['hostname'] as String? ?? 'this computer',
+      acquiredAt: acquiredAt,
+      heartbeatAt: acquiredAt,
+    );
+  }
+
+  void start() {
+    _timer?.cancel();
+    _timer = Timer.periodic(heartbeat, (_) => check());
+  }
+
+  /// Renew the lease and look for writes from other connections.
+  Future<void> check() async {
+    if (!_held) return;
+    try {
+      if (!await _db.renewDbLease(ownerId)) {
+        _held = false;
+        _foreignOwner = await _db.getDbLease();
+        _logger.w('Database lease taken over by pid ${_foreignOwner?.pid}');
+        notifyListeners();
+        return;
+      }
+      final version = await _db.dataVersion();
+      if (_dataVersion != null && version != _dataVersion && !_foreignWrites) {
+        _foreignWrites = true;
+        _logger.w('Another process wrote to the local database');
+        notifyListeners();
+      }
+      _dataVersion = version;
+    } catch (e) {
+      _logger.e('Database lease check failed', error: e);
+    }
+  }
+
+  Future<void> release() async {
+    _timer?.cancel();
+    _timer = null;
+    if (_held) {
+      _held = false;
+      await _db.releaseDbLease(ownerId);
+    }
+    await _unlockFile();
+  }
+
+  @override
+  void dispose() {
+    _timer?.cancel();
+    super.dispose();
+  }
+}
@@ -0,0 +1,76 @@
+import 'package:drift/drift.dart';
+
+/// Read-only views of the local database for scripts and dashboards.
+///
+/// The internal tables change whenever the app needs them to; these views
+/// do not. Each one carries its version in its name and keeps its columns
+/// and meaning for as long as it exists. An incompatible change adds a new
+/// version next to the old one, and `external_schema` lists the versions
+/// this build provides.
+///
+/// The views are dropped and recreated every time the database opens, so
+/// they always select from the current internal tables. SQLite views
+/// cannot be written to.
+class ExternalViews {
+  ExternalViews._();
+
+  static const _schema = '''
+CREATE VIEW external_schema AS
+SELECT 'files' AS view, 1 AS version, 'external_files_v1' AS name''';
+
+  /// One row per file the app knows of. Times are UTC in ISO 8601.
+  ///
+  /// `state` is `conflict` while a failed change to the file waits for a
+  /// decision, `pending` while changes to it are queued, `synced` once the
+  /// app has a local copy in step with the server, and `remote` otherwise.
+  static const _filesV1 = '''
+CREATE VIEW external_files_v1 AS
+SELECT
+  f.id AS id,
+  f.name AS name,
+  f.path AS path,
+  f.folder_id AS folder_id,
+  f.size AS size,
+  f.mime_type AS mime_type,
+  f.etag AS etag,
+  f.hash AS hash,
+  strftime('%Y-%m-%dT%H:%M:%SZ', f.created_at, 'unixepoch') AS created_at,
+  strftime('%Y-%m-%dT%H:%M:%SZ', f.modified_at, 'unixepoch') AS modified_at,
+  strftime('%Y-%m-%dT%H:%M:%SZ', f.synced_at, 'unixepoch') AS synced_at,
+  f.is_favorite AS favorite,
+  f.is_available_offline AS offline,
+  f.local_cache_path AS local_path,
+  (SELECT COUNT(*) FROM sync_queue q
+    WHERE q.item_id = f.id AND q.item_type = 'file'
+      AND q.status IN ('pending', 'inProgress')) AS pending_operations,
+  CASE
+    WHEN EXISTS (SELECT 1 FROM sync_conflicts c
+      WHERE c.item_id = f.id AND c.item_type = 'file'
+        AND c.resolved_at IS NULL) THEN 'conflict'
+    WHEN EXISTS (SELECT 1 FROM sync_queue q
+      WHERE q.item_id = f.id AND q.item_type = 'file'
+        AND q.status IN ('pending', 'inProgress')) THEN 'pending'
+    WHEN f.synced_at IS NOT NULL THEN 'synced'
+    ELSE 'remote'
+  END AS state
+FROM files f''';
+
+  static const _views = {
+    'external_schema': _schema,
+    'external_files_v1': _filesV1,
+  };
+
+  static Future<void> install(GeneratedDatabase db) async {
+    await drop(db);
+    for (final sql in _views.values) {
+      await db.customStatement(sql);
+    }
+  }
+
+  /// Run before a migration, which may rebuild the tables they read.
+  static Future<void> drop(GeneratedDatabase db) async {
+    for (final name in _views.keys) {
+      await db.customStatement('DROP VIEW IF EXISTS $name');
+    }
+  }
+}
@@ -0,0 +1,20 @@
+import 'package:drift/drift.dart';
+
+/// Append-only record of sync decisions and finished transfers.
+class ActivityLogTable extends Table {
+  @override
+  String get tableName => 'activity_log';
+
+  IntColumn get id => integer().autoIncrement()();
+  // 'sync', 'transfer', 'integrity' or 'storage'
+  TextColumn get category => text()();
+  TextColumn get action => text()(); // operation type, 'upload', 'download'
+  TextColumn get itemId => text().nullable()();
+  TextColumn get itemName => text().nullable()();
+  TextColumn get outcome => text()(); // 'completed', 'retry', 'failed', ...
+  TextColumn get reason => text().nullable()();
+  IntColumn get bytes => integer().nullable()();
+  TextColumn get initiator =>
+      text().withDefault(const Constant('sync'))(); // 'user' or 'sync'
+  DateTimeColumn get createdAt => dateTime()();
+}
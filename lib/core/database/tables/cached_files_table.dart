@@ -1,5 +1,7 @@
 import 'package:drift/drift.dart';
 
+/// Local copies of server files, with what they looked like when they were
+/// downloaded so that later damage or drift can be noticed.
 class CachedFilesTable extends Table {
   @override
   String get tableName => 'cached_files';
@@ -12,6 +14,15 @@ class CachedFilesTable extends Table {
   DateTimeColumn get lastAccessedAt => dateTime()();
   BoolColumn get isPinned => boolean().withDefault(const Constant(false))();
 
+  /// The server version the copy was downloaded from.
+  TextColumn get etag => text().nullable()();
+  /// Text of that version, kept for small text files so that edits on both
+  /// sides can be merged.
+  TextColumn get baseContent => text().nullable()();
+  // 'ok', 'pendingDownload', 'pendingUpload', 'conflict' or 'truncated'
+  TextColumn get state => text().withDefault(const Constant('ok'))();
+  DateTimeColumn get verifiedAt => dateTime().nullable()();
+
   @override
   Set<Column> get primaryKey => {fileId};
 }
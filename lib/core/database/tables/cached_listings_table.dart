@@ -0,0 +1,14 @@
+import 'package:drift/drift.dart';
+
+/// When each folder's listing was last fetched from the server. The root
+/// listing is stored under an empty folder id.
+class CachedListingsTable extends Table {
+  @override
+  String get tableName => 'cached_listings';
+
+  TextColumn get folderId => text()();
+  DateTimeColumn get listedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {folderId};
+}
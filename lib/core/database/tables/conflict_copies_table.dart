@@ -0,0 +1,17 @@
+import 'package:drift/drift.dart';
+
+/// Copies made when a conflict was resolved by keeping both versions,
+/// linked to the file they were copied from. A copy stays outstanding
+/// until the user dismisses it.
+class ConflictCopiesTable extends Table {
+  @override
+  String get tableName => 'conflict_copies';
+
+  IntColumn get id => integer().autoIncrement()();
+  TextColumn get originalId => text()();
+  TextColumn get copyId => text()();
+  TextColumn get copyName => text()();
+  IntColumn get conflictId => integer().nullable()(); // sync_conflicts.id
+  DateTimeColumn get createdAt => dateTime()();
+  DateTimeColumn get dismissedAt => dateTime().nullable()();
+}
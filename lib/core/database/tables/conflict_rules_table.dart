@@ -0,0 +1,15 @@
+import 'package:drift/drift.dart';
+
+/// User-defined rules that settle sync conflicts without asking, e.g.
+/// "*.tmp: keep local" or "/Shared/TeamDocs: keep remote".
+class ConflictRulesTable extends Table {
+  @override
+  String get tableName => 'conflict_rules';
+
+  IntColumn get id => integer().autoIncrement()();
+  TextColumn get pathPrefix => text().nullable()(); // '/Shared/TeamDocs'
+  TextColumn get namePattern => text().nullable()(); // '*.tmp'
+  TextColumn get resolution => text()(); // 'local' or 'remote'
+  IntColumn get position => integer()(); // lower wins
+  DateTimeColumn get createdAt => dateTime()();
+}
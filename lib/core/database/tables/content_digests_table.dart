@@ -0,0 +1,21 @@
+import 'package:drift/drift.dart';
+
+/// Checksums of the content of files this device uploaded, so the same
+/// content is not uploaded again. A digest only holds while the file on
+/// the server still has the [etag] and [size] it was recorded with.
+class ContentDigestsTable extends Table {
+  @override
+  String get tableName => 'content_digests';
+
+  TextColumn get fileId => text()();
+
+  /// A `ChecksumAlgorithm` wire name.
+  TextColumn get algorithm => text()();
+  TextColumn get digest => text()();
+  IntColumn get size => integer()();
+  TextColumn get etag => text().nullable()();
+  DateTimeColumn get recordedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {fileId};
+}
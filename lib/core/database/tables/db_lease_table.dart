@@ -0,0 +1,17 @@
+import 'package:drift/drift.dart';
+
+/// Single-row record of the process that currently owns the database.
+class DbLeaseTable extends Table {
+  @override
+  String get tableName => 'db_lease';
+
+  IntColumn get id => integer().withDefault(const Constant(1))();
+  TextColumn get ownerId => text()();
+  IntColumn get pid => integer()();
+  TextColumn get hostname => text()();
+  DateTimeColumn get acquiredAt => dateTime()();
+  DateTimeColumn get heartbeatAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {id};
+}
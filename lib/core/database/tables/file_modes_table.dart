@@ -0,0 +1,14 @@
+import 'package:drift/drift.dart';
+
+/// Unix permission bits of files uploaded from this device, by remote id.
+class FileModesTable extends Table {
+  @override
+  String get tableName => 'file_modes';
+
+  TextColumn get fileId => text()();
+  IntColumn get mode => integer()();
+  DateTimeColumn get recordedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {fileId};
+}
@@ -21,6 +21,10 @@ class FilesTable extends Table {
       boolean().withDefault(const Constant(false))();
   TextColumn get localCachePath => text().nullable()();
 
+  /// Type of external storage the file lives on, null for the server's own
+  /// storage.
+  TextColumn get mountType => text().nullable()();
+
   @override
   Set<Column> get primaryKey => {id};
 }
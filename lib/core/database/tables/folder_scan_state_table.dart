@@ -0,0 +1,20 @@
+import 'package:drift/drift.dart';
+
+/// Per-folder bookkeeping for adaptive background refreshes.
+class FolderScanStateTable extends Table {
+  @override
+  String get tableName => 'folder_scan_state';
+
+  TextColumn get folderId => text()();
+  TextColumn get fingerprint => text().nullable()(); // hash of the listing
+  IntColumn get intervalSeconds => integer()();
+  IntColumn get changeCount => integer().withDefault(const Constant(0))();
+  DateTimeColumn get lastScannedAt => dateTime().nullable()();
+  DateTimeColumn get nextScanAt => dateTime()();
+
+  /// Where a WebDAV sync-collection report left off, if the server has it.
+  TextColumn get syncToken => text().nullable()();
+
+  @override
+  Set<Column> get primaryKey => {folderId};
+}
@@ -0,0 +1,18 @@
+import 'package:drift/drift.dart';
+
+/// Remote folders that sync in one direction only. Folders below [path]
+/// inherit the [direction] unless they have one of their own.
+class FolderSyncModesTable extends Table {
+  @override
+  String get tableName => 'folder_sync_modes';
+
+  TextColumn get folderId => text()();
+  TextColumn get path => text()();
+
+  /// A [SyncDirection] name.
+  TextColumn get direction => text()();
+  DateTimeColumn get createdAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {folderId};
+}
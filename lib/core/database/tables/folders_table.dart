@@ -14,6 +14,10 @@ class FoldersTable extends Table {
   DateTimeColumn get modifiedAt => dateTime()();
   DateTimeColumn get syncedAt => dateTime().nullable()();
 
+  /// Type of external storage the folder lives on, null for the server's
+  /// own storage.
+  TextColumn get mountType => text().nullable()();
+
   @override
   Set<Column> get primaryKey => {id};
 }
@@ -0,0 +1,19 @@
+import 'package:drift/drift.dart';
+
+/// Files that arrived in the inbox folder and what was done with them, so
+/// each is handled once.
+class InboxItemsTable extends Table {
+  @override
+  String get tableName => 'inbox_items';
+
+  TextColumn get fileId => text()();
+  TextColumn get name => text()();
+  TextColumn get status => text()(); // 'sorted', 'kept', 'flagged', 'failed'
+  TextColumn get destinationPath => text().nullable()();
+  TextColumn get localPath => text().nullable()();
+  TextColumn get detail => text().nullable()();
+  DateTimeColumn get arrivedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {fileId};
+}
@@ -0,0 +1,15 @@
+import 'package:drift/drift.dart';
+
+/// Where files arriving in the inbox folder are moved, e.g. "*.pdf" to
+/// /Documents/Invoices. Rules are tried in [position] order.
+class InboxRulesTable extends Table {
+  @override
+  String get tableName => 'inbox_rules';
+
+  IntColumn get id => integer().autoIncrement()();
+  TextColumn get namePattern => text().nullable()(); // '*.pdf'
+  TextColumn get destinationFolderId => text()();
+  TextColumn get destinationPath => text()(); // for display
+  IntColumn get position => integer()(); // lower wins
+  DateTimeColumn get createdAt => dateTime()();
+}
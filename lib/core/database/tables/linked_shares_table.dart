@@ -0,0 +1,22 @@
+import 'package:drift/drift.dart';
+
+/// Public share links from other users (possibly on other servers) that are
+/// mirrored read-only into a local folder.
+class LinkedSharesTable extends Table {
+  @override
+  String get tableName => 'linked_shares';
+
+  TextColumn get id => text()();
+  TextColumn get serverUrl => text()();
+  TextColumn get token => text()();
+  TextColumn get name => text()();
+  TextColumn get itemType => text()(); // 'file' or 'folder'
+  BoolColumn get passwordProtected =>
+      boolean().withDefault(const Constant(false))();
+  TextColumn get localPath => text().nullable()();
+  DateTimeColumn get lastSyncedAt => dateTime().nullable()();
+  DateTimeColumn get addedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {id};
+}
@@ -0,0 +1,19 @@
+import 'package:drift/drift.dart';
+
+/// Write-ahead journal of queued operations sent to the server. An entry
+/// is written before the request goes out and finished after its outcome
+/// is recorded, so one still `started` after a restart was interrupted.
+class OperationsTable extends Table {
+  @override
+  String get tableName => 'operations';
+
+  IntColumn get id => integer().autoIncrement()();
+  IntColumn get syncOpId => integer()();
+  TextColumn get operationType => text()();
+  TextColumn get itemId => text()();
+  TextColumn get payload => text()(); // JSON-encoded operation data
+  // 'started', 'done' or 'failed'
+  TextColumn get state => text().withDefault(const Constant('started'))();
+  DateTimeColumn get startedAt => dateTime()();
+  DateTimeColumn get finishedAt => dateTime().nullable()();
+}
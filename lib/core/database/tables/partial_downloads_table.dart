@@ -0,0 +1,22 @@
+import 'package:drift/drift.dart';
+
+/// Downloads cut off by a lost connection, so downloading the file again
+/// continues from the bytes already in [path] instead of starting over.
+class PartialDownloadsTable extends Table {
+  @override
+  String get tableName => 'partial_downloads';
+
+  TextColumn get fileId => text()();
+
+  /// The temporary file holding the bytes received so far.
+  TextColumn get path => text()();
+  TextColumn get targetPath => text()();
+
+  /// ETag of the version being downloaded; the rest is only appended if
+  /// the server still has that version.
+  TextColumn get etag => text()();
+  DateTimeColumn get updatedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {fileId};
+}
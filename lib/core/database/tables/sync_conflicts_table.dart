@@ -13,7 +13,16 @@ class SyncConflictsTable extends Table {
   TextColumn get payload => text()(); // JSON original payload
   TextColumn get errorMessage => text().nullable()();
   TextColumn get resolution =>
-      text().nullable()(); // 'local', 'remote', 'manual'
+      text().nullable()(); // a ConflictResolution name, 'restored' or 'manual'
   DateTimeColumn get resolvedAt => dateTime().nullable()();
+
+  /// Who settled the conflict: 'user', 'policy' for the global rule or a
+  /// sync setting, `rule <folder> <pattern>` for a narrower rule, or 'sync'
+  /// when it went away by itself.
+  TextColumn get resolvedBy => text().nullable()();
   DateTimeColumn get createdAt => dateTime()();
+
+  /// When the failed operation was queued, i.e. when the local change was
+  /// made.
+  DateTimeColumn get queuedAt => dateTime().nullable()();
 }
@@ -0,0 +1,23 @@
+import 'package:drift/drift.dart';
+
+/// The last error of each item whose queued changes keep failing. Cleared
+/// once a change to the item goes through. [ignored] items are no longer
+/// synced and stay out of the error list.
+class SyncErrorsTable extends Table {
+  @override
+  String get tableName => 'sync_errors';
+
+  TextColumn get itemId => text()();
+  TextColumn get itemType => text()(); // 'file' or 'folder'
+  TextColumn get operationType => text()();
+
+  /// A `SyncErrorCategory` name.
+  TextColumn get category => text()();
+  TextColumn get message => text()();
+  IntColumn get retryCount => integer().withDefault(const Constant(0))();
+  DateTimeColumn get failedAt => dateTime()();
+  BoolColumn get ignored => boolean().withDefault(const Constant(false))();
+
+  @override
+  Set<Column> get primaryKey => {itemId};
+}
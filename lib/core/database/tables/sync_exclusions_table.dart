@@ -0,0 +1,15 @@
+import 'package:drift/drift.dart';
+
+/// Remote folders the user chose not to keep mirrored locally. Everything
+/// below [path] is excluded along with the folder itself.
+class SyncExclusionsTable extends Table {
+  @override
+  String get tableName => 'sync_exclusions';
+
+  TextColumn get folderId => text()();
+  TextColumn get path => text()();
+  DateTimeColumn get createdAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {folderId};
+}
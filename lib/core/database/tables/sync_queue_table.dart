@@ -29,6 +29,10 @@ class SyncQueueTable extends Table {
   TextColumn get itemType => text()(); // 'file' or 'folder'
   TextColumn get payload => text()(); // JSON-encoded operation data
   IntColumn get retryCount => integer().withDefault(const Constant(0))();
+  // Retries after the server was busy or briefly broken; these only set
+  // the backoff and never make the operation give up.
+  IntColumn get transientRetryCount =>
+      integer().withDefault(const Constant(0))();
   TextColumn get errorMessage => text().nullable()();
   DateTimeColumn get createdAt => dateTime()();
   DateTimeColumn get updatedAt => dateTime()();
@@ -0,0 +1,32 @@
+import 'package:drift/drift.dart';
+
+/// Running totals of sync and transfer work, one row per local day.
+class SyncStatsTable extends Table {
+  @override
+  String get tableName => 'sync_stats';
+
+  /// Local midnight starting the day.
+  DateTimeColumn get day => dateTime()();
+  IntColumn get bytesUploaded => integer().withDefault(const Constant(0))();
+  IntColumn get bytesDownloaded => integer().withDefault(const Constant(0))();
+  IntColumn get filesSynced => integer().withDefault(const Constant(0))();
+  IntColumn get errors => integer().withDefault(const Constant(0))();
+
+  /// Time spent on completed transfers, for the average throughput.
+  IntColumn get transferMillis => integer().withDefault(const Constant(0))();
+
+  @override
+  Set<Column> get primaryKey => {day};
+}
+
+/// When each folder last synced without errors.
+class FolderSyncStatsTable extends Table {
+  @override
+  String get tableName => 'folder_sync_stats';
+
+  TextColumn get folderId => text()();
+  DateTimeColumn get lastSyncedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {folderId};
+}
@@ -0,0 +1,18 @@
+import 'package:drift/drift.dart';
+
+/// What the server reported for each upload, checked again later to catch
+/// content that changed or went missing behind the client's back.
+class TransferLedgerTable extends Table {
+  @override
+  String get tableName => 'transfer_ledger';
+
+  IntColumn get id => integer().autoIncrement()();
+  TextColumn get itemId => text()();
+  TextColumn get itemName => text()();
+  IntColumn get size => integer()(); // bytes sent
+  DateTimeColumn get remoteModifiedAt => dateTime().nullable()();
+  // 'pending', 'verified', 'skipped', 'superseded' or 'mismatch'
+  TextColumn get status => text().withDefault(const Constant('pending'))();
+  DateTimeColumn get uploadedAt => dateTime()();
+  DateTimeColumn get verifiedAt => dateTime().nullable()();
+}
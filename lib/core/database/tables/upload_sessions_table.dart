@@ -0,0 +1,31 @@
+import 'package:drift/drift.dart';
+
+/// Journal of chunked upload sessions opened on the server, so interrupted
+/// uploads can be resumed and orphaned sessions cleaned up.
+class UploadSessionsTable extends Table {
+  @override
+  String get tableName => 'upload_sessions';
+
+  TextColumn get uploadId => text()();
+  TextColumn get localPath => text()();
+  TextColumn get filename => text()();
+  TextColumn get folderId => text().nullable()();
+  TextColumn get contentType => text().nullable()();
+  IntColumn get totalSize => integer()();
+
+  /// When the local file was last modified as the session opened. Only
+  /// that same version of the file may be resumed.
+  DateTimeColumn get localModifiedAt => dateTime().nullable()();
+  IntColumn get chunkSize => integer()();
+  IntColumn get totalChunks => integer()();
+  IntColumn get chunksUploaded => integer().withDefault(const Constant(0))();
+
+  /// Checksum type the session's chunks are sent with. A resumed upload
+  /// keeps it even if the account's choice has changed since.
+  TextColumn get checksumAlgorithm => text().nullable()();
+  DateTimeColumn get createdAt => dateTime()();
+  DateTimeColumn get updatedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {uploadId};
+}
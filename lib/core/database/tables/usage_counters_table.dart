@@ -0,0 +1,15 @@
+import 'package:drift/drift.dart';
+
+/// How often each feature was used, kept only while usage statistics are
+/// turned on. Holds no file names, paths or account details.
+class UsageCountersTable extends Table {
+  @override
+  String get tableName => 'usage_counters';
+
+  TextColumn get feature => text()();
+  IntColumn get count => integer().withDefault(const Constant(0))();
+  DateTimeColumn get lastUsedAt => dateTime()();
+
+  @override
+  Set<Column> get primaryKey => {feature};
+}
@@ -12,6 +12,10 @@ class UserTable extends Table {
   IntColumn get storageUsedBytes => integer().nullable()();
   DateTimeColumn get lastSyncAt => dateTime().nullable()();
 
+  /// Checksum type agreed with the server for this account; empty when the
+  /// server takes none, null until negotiated.
+  TextColumn get checksumAlgorithm => text().nullable()();
+
   @override
   Set<Column> get primaryKey => {id};
 }
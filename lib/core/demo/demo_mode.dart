@@ -0,0 +1,51 @@
+import 'package:drift/native.dart';
+import 'package:flutter_riverpod/misc.dart' show Override;
+
+import '../../providers.dart';
+import '../auth/secure_storage.dart';
+import '../config/constants.dart';
+import '../database/app_database.dart';
+import '../database/db_lease_service.dart';
+import '../network/api_client.dart';
+import 'demo_server.dart';
+
+/// Command-line switch that starts the app against [DemoServer].
+const demoFlag = '--demo';
+
+const _demoServerUrl = 'https://demo.oxicloud.invalid';
+
+/// Provider overrides that run the app without a live server: an in-memory
+/// remote with seeded files and fake latency, an in-memory database and a
+/// pre-authenticated session that never touches the keychain.
+List<Override> demoOverrides({DemoServer? server}) {
+  final demo = server ?? DemoServer()
+    ..startScript();
+  return [
+    secureStorageProvider.overrideWithValue(
+      SecureStorage.memory({
+        Constants.keyServerUrl: _demoServerUrl,
+        Constants.keyAccessToken: 'demo',
+        Constants.keyUserId: 'demo-user',
+      }),
+    ),
+    databaseProvider.overrideWith((ref) {
+      final db = AppDatabase(NativeDatabase.memory());
+      ref.onDispose(db.close);
+      return db;
+    }),
+    // Nothing on disk to lock.
+    dbLeaseProvider.overrideWith((ref) {
+      final lease = DbLeaseService(ref.watch(databaseProvider));
+      ref.onDispose(lease.dispose);
+      return lease;
+    }),
+    apiClientProvider.overrideWith((ref) {
+      final client = ApiClient(
+        config: ref.watch(appConfigProvider),
+        secureStorage: ref.watch(secureStorageProvider),
+      );
+      client.dio.httpClientAdapter = DemoHttpAdapter(demo);
+      return client;
+    }),
+  ];
+}
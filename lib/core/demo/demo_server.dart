@@ -0,0 +1,369 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:math';
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:uuid/uuid.dart';
+
+/// In-memory stand-in for an OxiCloud server, seeded with a small tree.
+///
+/// Only the endpoints the UI needs are implemented; other GETs answer with
+/// an empty list and everything else with 404.
+class DemoServer {
+  static const demoUser = {
+    'id': 'demo-user',
+    'username': 'demo',
+    'email': 'demo@oxicloud.local',
+    'role': 'admin',
+    'storage_quota_bytes': 10 * 1024 * 1024 * 1024,
+    'storage_used_bytes': 0,
+  };
+
+  final _uuid = const Uuid();
+  final Map<String, Map<String, dynamic>> _folders = {};
+  final Map<String, Map<String, dynamic>> _files = {};
+  final Map<String, Uint8List> _content = {};
+  final Set<String> _favorites = {};
+  final List<Map<String, dynamic>> _trash = [];
+  Timer? _script;
+
+  DemoServer() {
+    _seed();
+  }
+
+  /// Every [interval], drop a new file into a seeded folder so the UI has
+  /// remote changes to pick up.
+  void startScript({Duration interval = const Duration(seconds: 45)}) {
+    var n = 0;
+    final inbox = _folders.values.firstWhere((f) => f['name'] == 'Documents');
+    _script?.cancel();
+    _script = Timer.periodic(interval, (_) {
+      n++;
+      _addFile('Scan ${n.toString().padLeft(3, '0')}.pdf', inbox['id']);
+    });
+  }
+
+  void dispose() => _script?.cancel();
+
+  ({int status, Object? body}) handle(
+    String method,
+    String path,
+    Map<String, dynamic> query,
+    Object? data,
+  ) {
+    final seg = path.split('/').where((s) => s.isNotEmpty).toList();
+    final body = data is Map
+        ? data.cast<String, dynamic>()
+        : const <String, dynamic>{};
+
+    switch ((method, seg)) {
+      // --- Auth ---
+      case ('GET', ['auth', 'me']):
+        return (status: 200, body: demoUser);
+      case ('GET', ['auth', 'status']):
+        return (
+          status: 200,
+          body: {'admin_exists': true, 'registration_enabled': false},
+        );
+      case ('POST', ['auth', 'login']):
+        return (
+          status: 200,
+          body: {
+            'user': demoUser,
+            'access_token': 'demo',
+            'refresh_token': 'demo',
+            'expires_in': 86400,
+          },
+        );
+      case ('POST', ['auth', 'logout']):
+        return (status: 204, body: null);
+
+      // --- Folders ---
+      case ('GET', ['folders']):
+        return (status: 200, body: _children(_folders, 'parent_id', null));
+      case ('POST', ['folders']):
+        final siblings = _children(_folders, 'parent_id', body['parent_id']);
+        if (siblings.any((f) => f['name'] == body['name'])) {
+          return (status: 409, body: {'error': 'Folder already exists'});
+        }
+        return (
+          status: 201,
+          body: _addFolder(body['name'] as String, body['parent_id']),
+        );
+      case ('GET', ['folders', final id]):
+        return _found(_folders[id]);
+      case ('GET', ['folders', final id, 'listing' || 'contents']):
+        if (!_folders.containsKey(id)) return (status: 404, body: null);
+        return (
+          status: 200,
+          body: {
+            'folders': _children(_folders, 'parent_id', id),
+            'files': _children(_files, 'folder_id', id),
+          },
+        );
+      case ('PUT', ['folders', final id, 'rename']):
+        return _patch(_folders[id], {'name': body['name']});
+      case ('PUT', ['folders', final id, 'move']):
+        return _patch(_folders[id], {'parent_id': body['parent_id']});
+      case ('DELETE', ['folders', final id]):
+        return _toTrash(_folders.remove(id), 'folder');
+
+      // --- Files ---
+      case ('GET', ['files']):
+        return (
+          status: 200,
+          body: _children(_files, 'folder_id', query['folder_id']),
+        );
+      case ('POST', ['files', 'upload']):
+        final form = data as FormData;
+        final file = form.files.first.value;
+        final folderId = form.fields
+            .where((f) => f.key == 'folder_id')
+            .map((f) => f.value)
+            .firstOrNull;
+        return (
+          status: 201,
+          body: _addFile(file.filename ?? 'upload', folderId, file.length),
+        );
+      case ('GET', ['files', final id, 'metadata']):
+        return _found(_files[id]);
+      case ('GET', ['files', final id]):
+        final bytes = _files.containsKey(id) ? _bytesOf(id) : null;
+        return bytes == null
+            ? (status: 404, body: null)
+            : (status: 200, body: bytes);
+      case ('PUT', ['files', final id, 'rename']):
+        return _patch(_files[id], {'name': body['name']});
+      case ('PUT', ['files', final id, 'move']):
+        return _patch(_files[id], {'folder_id': body['folder_id']});
+      case ('DELETE', ['files', final id]):
+        return _toTrash(_files.remove(id), 'file');
+
+      // --- Favorites / recent / trash ---
+      case ('GET', ['favorites']):
+        return (
+          status: 200,
+          body: _files.values
+              .where((f) => _favorites.contains(f['id']))
+              .toList(),
+        );
+      case ('POST', ['favorites', _, final id]):
+        _favorites.add(id);
+        return (status: 204, body: null);
+      case ('DELETE', ['favorites', _, final id]):
+        _favorites.remove(id);
+        return (status: 204, body: null);
+      case ('GET', ['recent']):
+        final recent = _files.values.toList()
+          ..sort(
+            (a, b) =>
+                (b['modified_at'] as String).compareTo(a['modified_at']),
+          );
+        return (status: 200, body: recent.take(20).toList());
+      case ('GET', ['trash']):
+        return (status: 200, body: _trash);
+      case ('DELETE', ['trash', 'empty']):
+        _trash.clear();
+        return (status: 204, body: null);
+
+      case ('GET', _):
+        return (status: 200, body: const []);
+      default:
+        return (status: 404, body: null);
+    }
+  }
+
+  // --- Helpers ---
+
+  void _seed() {
+    final docs = _addFolder('Documents', null)['id'];
+    final photos = _addFolder('Photos', null)['id'];
+    _addFolder('Music', null);
+    final projects = _addFolder('Projects', null)['id'];
+    final oxi = _addFolder('OxiCloud', projects)['id'];
+
+    _addFile('Welcome.md', null, 0, utf8.encode(_welcome));
+    _addFile('Budget 2025.xlsx', docs, 48 * 1024);
+    _addFile('Contract.pdf', docs, 820 * 1024);
+    _addFile('Meeting notes.txt', docs, 0, utf8.encode('Ship demo mode.\n'));
+    for (var i = 1; i <= 12; i++) {
+      _addFile('IMG_${1000 + i}.jpg', photos, (2 + i % 4) * 1024 * 1024);
+    }
+    _addFile('Roadmap.md', oxi, 0, utf8.encode('# Roadmap\n'));
+    _addFile('architecture.svg', oxi, 36 * 1024);
+    _favorites.add(_files.values.first['id'] as String);
+  }
+
+  Map<String, dynamic> _addFolder(String name, Object? parentId) {
+    final parent = parentId == null ? null : _folders[parentId];
+    final now = DateTime.now().toIso8601String();
+    final folder = <String, dynamic>{
+      'id': _uuid.v4(),
+      'name': name,
+      'path': '${parent?['path'] ?? ''}/$name',
+      'parent_id': parentId,
+      'owner_id': demoUser['id'],
+      'is_root': parentId == null,
+      'created_at': now,
+      'modified_at': now,
+    };
+    _folders[folder['id'] as String] = folder;
+    return folder;
+  }
+
+  Map<String, dynamic> _addFile(
+    String name,
+    Object? folderId, [
+    int size = 0,
+    List<int>? content,
+  ]) {
+    final folder = folderId == null ? null : _folders[folderId];
+    final now = DateTime.now().toIso8601String();
+    final id = _uuid.v4();
+    final file = <String, dynamic>{
+      'id': id,
+      'name': name,
+      'path': '${folder?['path'] ?? ''}/$name',
+      'size': content?.length ?? size,
+      'mime_type': _mimeOf(name),
+      'folder_id': folderId,
+      'owner_id': demoUser['id'],
+      'created_at': now,
+      'modified_at': now,
+    };
+    _files[id] = file;
+    if (content != null) _content[id] = Uint8List.fromList(content);
+    return file;
+  }
+
+  /// Files without seeded content get deterministic filler bytes.
+  Uint8List _bytesOf(String id) {
+    return _content[id] ??= () {
+      final random = Random(id.hashCode);
+      final bytes = Uint8List(_files[id]!['size'] as int);
+      for (var i = 0; i < bytes.length; i++) {
+        bytes[i] = random.nextInt(256);
+      }
+      return bytes;
+    }();
+  }
+
+  ({int status, Object? body}) _found(Map<String, dynamic>? item) =>
+      item == null ? (status: 404, body: null) : (status: 200, body: item);
+
+  ({int status, Object? body}) _patch(
+    Map<String, dynamic>? item,
+    Map<String, dynamic> changes,
+  ) {
+    if (item == null) return (status: 404, body: null);
+    item
+      ..addAll(changes)
+      ..['modified_at'] = DateTime.now().toIso8601String();
+    return (status: 200, body: item);
+  }
+
+  ({int status, Object? body}) _toTrash(
+    Map<String, dynamic>? item,
+    String type,
+  ) {
+    if (item == null) return (status: 404, body: null);
+    _trash.add({
+      'id': item['id'],
+      'name': item['name'],
+      'item_type': type,
+      'original_path': item['path'],
+      'size': item['size'],
+      'deleted_at': DateTime.now().toIso8601String(),
+    });
+    return (status: 204, body: null);
+  }
+
+  List<Map<String, dynamic>> _children(
+    Map<String, Map<String, dynamic>> items,
+    String parentKey,
+    Object? parentId,
+  ) {
+    return items.values.where((i) => i[parentKey] == parentId).toList()
+      ..sort((a, b) => (a['name'] as String).compareTo(b['name']));
+  }
+
+  String _mimeOf(String name) {
+    final ext = name.split('.').last.toLowerCase();
+    return switch (ext) {
+      'jpg' || 'jpeg' => 'image/jpeg',
+      'png' => 'image/png',
+      'svg' => 'image/svg+xml',
+      'pdf' => 'application/pdf',
+      'md' => 'text/markdown',
+      'txt' => 'text/plain',
+      'xlsx' =>
+        'application/vnd.openxmlformats-officedocument.spreadsheetml.sheet',
+      _ => 'application/octet-stream',
+    };
+  }
+
+  static const _welcome = '''
+# Welcome to OxiCloud
+
+You are running the desktop client in demo mode. Nothing you do here
+leaves this machine, and everything is forgotten when the app quits.
+''';
+}
+
+/// Dio adapter that answers every request from a [DemoServer] after a
+/// randomized delay, imitating network latency.
+class DemoHttpAdapter implements HttpClientAdapter {
+  final DemoServer server;
+  final Duration minLatency;
+  final Duration maxLatency;
+  final Random _random = Random();
+
+  DemoHttpAdapter(
+    this.server, {
+    this.minLatency = const Duration(milliseconds: 80),
+    this.maxLatency = const Duration(milliseconds: 400),
+  });
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    final spread = maxLatency.inMilliseconds - minLatency.inMilliseconds;
+    await Future<void>.delayed(
+      minLatency + Duration(milliseconds: _random.nextInt(spread + 1)),
+    );
+
+    final path = options.uri.path.replaceFirst(RegExp(r'^/api'), '');
+    final result = server.handle(
+      options.method.toUpperCase(),
+      path,
+      options.uri.queryParameters,
+      options.data,
+    );
+
+    if (result.body is Uint8List) {
+      final bytes = result.body as Uint8List;
+      return ResponseBody.fromBytes(
+        bytes,
+        result.status,
+        headers: {
+          Headers.contentTypeHeader: ['application/octet-stream'],
+          Headers.contentLengthHeader: ['${bytes.length}'],
+        },
+      );
+    }
+    return ResponseBody.fromString(
+      result.body == null ? '' : json.encode(result.body),
+      result.status,
+      headers: {
+        Headers.contentTypeHeader: [Headers.jsonContentType],
+      },
+    );
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
@@ -1,6 +1,9 @@
+import 'dart:io';
+
 import 'package:dio/dio.dart';
 import '../error/exceptions.dart';
 import '../error/failures.dart';
+import '../network/retry_after.dart';
 
 class ErrorHandler {
   ErrorHandler._();
@@ -22,6 +25,17 @@ class ErrorHandler {
   }
 
   static ServerException mapDioToServerException(DioException error) {
+    if (error.type == DioExceptionType.cancel) {
+      return RequestCancelledException(error.message ?? 'Cancelled');
+    }
+    if (error.type == DioExceptionType.connectionError ||
+        error.type == DioExceptionType.connectionTimeout) {
+      return ServerUnreachableException(error.message ?? 'Server unreachable');
+    }
+    if (error.type == DioExceptionType.unknown &&
+        (error.error is SocketException || error.error is HttpException)) {
+      return ServerUnreachableException(error.message ?? 'Connection lost');
+    }
     final statusCode = error.response?.statusCode;
     final data = error.response?.data;
     final message = _extractMessage(data) ?? error.message ?? 'Unknown error';
@@ -31,11 +45,31 @@ class ErrorHandler {
       403 => ForbiddenException(message),
       404 => NotFoundException(message),
       409 => ConflictException(message),
+      412 => PreconditionFailedException(message),
       507 => QuotaExceededException(message),
-      _ => ServerException(message, statusCode: statusCode, data: data),
+      _ => ServerException(
+        message,
+        statusCode: statusCode,
+        data: data,
+        retryAfter: parseRetryAfter(
+          error.response?.headers.value('retry-after'),
+        ),
+      ),
     };
   }
 
+  /// Whether [e] means the request never got an answer, rather than an
+  /// answer refusing it.
+  static bool isConnectionError(ServerException e) =>
+      e is ServerUnreachableException ||
+      (e.statusCode == null && e is! RequestCancelledException);
+
+  /// Whether [e] is likely to pass by itself: the server could not be
+  /// reached, or was busy or briefly broken.
+  static bool isTransient(ServerException e) =>
+      e is ServerUnreachableException ||
+      const {408, 429, 502, 503, 504}.contains(e.statusCode);
+
   static Failure _mapServerException(ServerException e) {
     return switch (e) {
       UnauthorizedException() => const AuthFailure(),
@@ -1,9 +1,19 @@
+import '../config/config_validator.dart';
+
 class ServerException implements Exception {
   final String message;
   final int? statusCode;
   final dynamic data;
 
-  const ServerException(this.message, {this.statusCode, this.data});
+  /// How long the server asked clients to wait, from `Retry-After`.
+  final Duration? retryAfter;
+
+  const ServerException(
+    this.message, {
+    this.statusCode,
+    this.data,
+    this.retryAfter,
+  });
 
   @override
   String toString() => 'ServerException($statusCode): $message';
@@ -29,11 +39,41 @@ class ConflictException extends ServerException {
     : super(message, statusCode: 409);
 }
 
+/// The item changed on the server since the version an `If-Match` header
+/// referred to.
+class PreconditionFailedException extends ServerException {
+  const PreconditionFailedException([
+    String message = 'Changed on the server',
+  ]) : super(message, statusCode: 412);
+}
+
 class QuotaExceededException extends ServerException {
   const QuotaExceededException([String message = 'Storage quota exceeded'])
     : super(message, statusCode: 507);
 }
 
+/// The server could not be reached at all, so the request never arrived.
+class ServerUnreachableException extends ServerException {
+  const ServerUnreachableException([String message = 'Server unreachable'])
+    : super(message);
+}
+
+/// The request was cancelled on this side before the server answered.
+class RequestCancelledException extends ServerException {
+  const RequestCancelledException([String message = 'Cancelled'])
+    : super(message);
+}
+
+/// A configuration was refused because some settings are invalid.
+class InvalidConfigException implements Exception {
+  final List<ConfigError> errors;
+
+  const InvalidConfigException(this.errors);
+
+  @override
+  String toString() => 'InvalidConfigException: ${errors.join('; ')}';
+}
+
 class CacheException implements Exception {
   final String message;
   const CacheException([this.message = 'Cache error']);
@@ -49,3 +89,76 @@ class NetworkException implements Exception {
   @override
   String toString() => 'NetworkException: $message';
 }
+
+/// A folder publish was abandoned and its staged copy removed; nothing
+/// appeared at the destination.
+class PublishAbortedException implements Exception {
+  final String message;
+  const PublishAbortedException(this.message);
+
+  @override
+  String toString() => 'PublishAbortedException: $message';
+}
+
+/// A bundle's manifest is missing or unreadable, or a segment does not
+/// match it.
+class BundleCorruptException implements Exception {
+  final String message;
+  const BundleCorruptException(this.message);
+
+  @override
+  String toString() => 'BundleCorruptException: $message';
+}
+
+/// A downloaded file does not match the checksum the server keeps of it.
+class ChecksumMismatchException implements Exception {
+  final String path;
+  const ChecksumMismatchException(this.path);
+
+  @override
+  String toString() =>
+      'ChecksumMismatchException: $path does not match the server checksum';
+}
+
+/// Another application kept a file open for longer than uploads wait for
+/// it to be released.
+class FileLockedException implements Exception {
+  final String path;
+  const FileLockedException(this.path);
+
+  @override
+  String toString() => 'FileLockedException: $path is open in another app';
+}
+
+/// The disk a download goes to has less free space than the downloads
+/// under way need.
+class DiskFullException implements Exception {
+  final String path;
+  final int required;
+  final int available;
+
+  const DiskFullException(
+    this.path, {
+    required this.required,
+    required this.available,
+  });
+
+  @override
+  String toString() =>
+      'DiskFullException: $required bytes needed in $path, '
+      '$available available';
+}
+
+/// Thrown when a chunked upload is stopped at a chunk boundary (e.g. on
+/// shutdown). The server session is kept so the upload can be resumed.
+class UploadInterruptedException implements Exception {
+  final String uploadId;
+  final int chunksUploaded;
+
+  const UploadInterruptedException(this.uploadId, this.chunksUploaded);
+
+  @override
+  String toString() =>
+      'UploadInterruptedException: $uploadId stopped after '
+      '$chunksUploaded chunks';
+}
@@ -4,11 +4,17 @@ import 'package:logger/logger.dart';
 import '../auth/secure_storage.dart';
 import '../config/app_config.dart';
 import 'interceptors/auth_interceptor.dart';
+import 'interceptors/chaos_interceptor.dart';
+import 'interceptors/custom_headers_interceptor.dart';
+import 'interceptors/listing_cache_interceptor.dart';
 import 'interceptors/logging_interceptor.dart';
 import 'interceptors/retry_interceptor.dart';
+import 'interceptors/timeout_interceptor.dart';
 
 class ApiClient {
   late final Dio dio;
+  late final TimeoutInterceptor timeouts;
+  final CustomHeadersInterceptor customHeaders = CustomHeadersInterceptor();
   final AppConfig config;
   final SecureStorage secureStorage;
   final Logger _logger = Logger();
@@ -24,6 +30,7 @@ class ApiClient {
         baseUrl: config.apiBaseUrl,
         connectTimeout: config.connectTimeout,
         receiveTimeout: config.receiveTimeout,
+        sendTimeout: config.sendTimeout,
         headers: {
           'Content-Type': 'application/json',
           'Accept': 'application/json',
@@ -31,15 +38,26 @@ class ApiClient {
       ),
     );
 
+    timeouts = TimeoutInterceptor(
+      listingReceiveTimeout: config.listingReceiveTimeout,
+      deadline: config.requestDeadline,
+    );
+
     dio.interceptors.addAll([
+      customHeaders,
+      ListingCacheInterceptor(),
       AuthInterceptor(
         secureStorage: secureStorage,
         dio: dio,
         onTokenRefresh: onTokenRefresh,
         onForceLogout: onForceLogout,
       ),
+      // After the cache, so listings answered from memory are not timed.
+      timeouts,
       RetryInterceptor(dio: dio, maxRetries: config.maxRetries),
-      if (config.isDebug) AppLoggingInterceptor(logger: _logger),
+      if (config.isDebug)
+        AppLoggingInterceptor(logger: _logger, customHeaders: customHeaders),
+      if (ChaosConfig.enabled) ChaosInterceptor(ChaosConfig.fromEnvironment()),
     ]);
   }
 
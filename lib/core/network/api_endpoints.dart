@@ -37,6 +37,11 @@ class ApiEndpoints {
   static String fileMetadata(String id) => '/files/$id/metadata';
   static String fileRename(String id) => '/files/$id/rename';
   static String fileMove(String id) => '/files/$id/move';
+  static String fileVersions(String id) => '/files/$id/versions';
+  static String fileVersion(String id, String versionId) =>
+      '/files/$id/versions/$versionId';
+  static String fileVersionRestore(String id, String versionId) =>
+      '/files/$id/versions/$versionId/restore';
 
   // Chunked uploads
   static const String uploads = '/uploads';
@@ -46,6 +51,7 @@ class ApiEndpoints {
   // Batch
   static const String batchFilesMove = '/batch/files/move';
   static const String batchFilesCopy = '/batch/files/copy';
+  static const String batchFilesUpload = '/batch/files/upload';
   static const String batchFilesDelete = '/batch/files/delete';
   static const String batchFilesGet = '/batch/files/get';
   static const String batchFoldersDelete = '/batch/folders/delete';
@@ -158,6 +164,12 @@ class ApiEndpoints {
   // Version
   static const String version = '/version';
 
+  // WebDAV root, served next to /api rather than below it
+  static const String webdav = '/webdav/';
+
+  // Usage statistics, sent only with the user's consent
+  static const String usageStatistics = '/usage-statistics';
+
   // Admin general settings
   static const String adminGeneral = '/admin/settings/general';
 }
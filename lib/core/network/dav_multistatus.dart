@@ -0,0 +1,283 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:xml/xml_events.dart';
+
+/// Namespaces of the WebDAV properties the app reads.
+class DavNamespace {
+  DavNamespace._();
+
+  static const dav = 'DAV:';
+  static const owncloud = 'http://owncloud.org/ns';
+  static const nextcloud = 'http://nextcloud.org/ns';
+}
+
+/// One resource of a WebDAV multistatus response and the properties the
+/// server found for it.
+class DavResource {
+  /// The resource's path, percent-decoded.
+  final String href;
+
+  /// Property values by `{namespace}name`. A property made of elements
+  /// holds their text separated by spaces, or the names of the empty
+  /// ones, as `resourcetype` does.
+  final Map<String, String> properties;
+
+  /// The status given for the resource as a whole rather than per
+  /// property, as sync-collection reports do for removed members.
+  final int? status;
+
+  const DavResource(this.href, this.properties, {this.status});
+
+  /// Reported by a sync-collection as gone since the token.
+  bool get isRemoved => status == 404;
+
+  String? _dav(String name) => properties['{${DavNamespace.dav}}$name'];
+  String? _oc(String name) => properties['{${DavNamespace.owncloud}}$name'];
+
+  String? get etag => _dav('getetag');
+  String? get contentType => _dav('getcontenttype');
+  int? get contentLength => _int(_dav('getcontentlength'));
+  int? get quotaUsedBytes => _int(_dav('quota-used-bytes'));
+
+  /// Negative on servers without a limit.
+  int? get quotaAvailableBytes => _int(_dav('quota-available-bytes'));
+
+  DateTime? get lastModified {
+    final value = _dav('getlastmodified');
+    if (value == null) return null;
+    try {
+      return HttpDate.parse(value);
+    } on HttpException {
+      return null;
+    }
+  }
+
+  bool get isCollection =>
+      _dav('resourcetype')?.split(' ').contains('{DAV:}collection') ?? false;
+
+  /// The id ownCloud and Nextcloud give the resource.
+  String? get fileId => _oc('fileid');
+
+  /// What the user may do with the resource, if the server says.
+  DavPermissions? get permissions {
+    final value = _oc('permissions');
+    return value == null ? null : DavPermissions(value);
+  }
+
+  /// Checksums the server keeps of the content, by the server's name for
+  /// the algorithm, e.g. `SHA1`.
+  Map<String, String> get checksums => {
+    for (final entry in (_oc('checksums') ?? '').split(' '))
+      if (entry.indexOf(':') case final colon when colon > 0)
+        entry.substring(0, colon): entry.substring(colon + 1).toLowerCase(),
+  };
+
+  /// Size of a file, or of everything in a folder.
+  int? get size => _int(_oc('size'));
+
+  static int? _int(String? value) =>
+      value == null ? null : int.tryParse(value.trim());
+}
+
+/// The ownCloud permission letters of a resource.
+class DavPermissions {
+  final String letters;
+
+  const DavPermissions(this.letters);
+
+  bool get isShared => letters.contains('S');
+  bool get canWrite => letters.contains('W');
+  bool get canDelete => letters.contains('D');
+  bool get canRename => letters.contains('N');
+  bool get canMove => letters.contains('V');
+
+  /// For folders: files and subfolders can be created in it.
+  bool get canAddFiles => letters.contains('C');
+  bool get canAddFolders => letters.contains('K');
+  bool get canReshare => letters.contains('R');
+}
+
+/// A sync-collection report (RFC 6578): the members of a collection that
+/// changed or went away since the token asked with, and the token to ask
+/// with next time.
+class DavSyncReport {
+  final List<DavResource> changes;
+  final String token;
+
+  const DavSyncReport(this.changes, this.token);
+}
+
+/// Reads the multistatus bodies PROPFIND is answered with, event by event,
+/// so a large response is never held as a document tree.
+///
+/// Names are resolved against their namespace declarations, so whatever
+/// prefixes a server picks work, and entities and CDATA are decoded.
+/// Properties only count from `propstat` blocks with a 2xx status; the
+/// rest are the ones the server does not have. Mismatched tags are errors.
+class MultistatusParser {
+  final _resources = <DavResource>[];
+
+  /// Namespace declarations and names of the open elements, innermost
+  /// last.
+  final _scopes = <Map<String, String>>[];
+  final _open = <String>[];
+
+  final _text = StringBuffer();
+  final _children = <String>[];
+  String? _href;
+  int? _status;
+  int? _responseStatus;
+  String? _syncToken;
+  var _found = <String, String>{};
+  var _propstat = <String, String>{};
+
+  static const _response = '{DAV:}response';
+  static const _propstatName = '{DAV:}propstat';
+  static const _prop = '{DAV:}prop';
+  static const _multistatus = '{DAV:}multistatus';
+
+  List<DavResource> get resources => List.unmodifiable(_resources);
+
+  /// The token a sync-collection report ends with.
+  String? get syncToken => _syncToken;
+
+  /// Parse a whole [body]. Throws [FormatException] if it is not XML.
+  static List<DavResource> parse(String body) {
+    final parser = MultistatusParser();
+    try {
+      parseEvents(body, validateNesting: true).forEach(parser.add);
+    } on XmlException catch (e) {
+      throw FormatException(e.message);
+    }
+    return parser.resources;
+  }
+
+  /// Parse a UTF-8 [body] as it arrives. Throws [FormatException] if it
+  /// is not XML.
+  static Future<List<DavResource>> parseStream(Stream<List<int>> body) async =>
+      (await _read(body)).resources;
+
+  /// Parse a UTF-8 sync-collection report as it arrives. Throws
+  /// [FormatException] if it is not XML or has no sync token.
+  static Future<DavSyncReport> parseSyncReport(Stream<List<int>> body) async {
+    final parser = await _read(body);
+    final token = parser.syncToken;
+    if (token == null || token.isEmpty) {
+      throw const FormatException('Report without a sync token');
+    }
+    return DavSyncReport(parser.resources, token);
+  }
+
+  static Future<MultistatusParser> _read(Stream<List<int>> body) async {
+    final parser = MultistatusParser();
+    try {
+      await utf8.decoder
+          .bind(body)
+          .toXmlEvents(validateNesting: true)
+          .flatten()
+          .forEach(parser.add);
+    } on XmlException catch (e) {
+      throw FormatException(e.message);
+    }
+    return parser;
+  }
+
+  void add(XmlEvent event) {
+    switch (event) {
+      case XmlStartElementEvent():
+        _scopes.add({
+          for (final a in event.attributes)
+            if (a.name == 'xmlns')
+              '': a.value
+            else if (a.name.startsWith('xmlns:'))
+              a.name.substring(6): a.value,
+        });
+        final name = _resolve(event.name);
+        _text.clear();
+        if (_open.lastOrNull == _prop) _children.clear();
+        if (event.isSelfClosing) {
+          _close(name);
+          _scopes.removeLast();
+        } else {
+          _open.add(name);
+        }
+      case XmlEndElementEvent():
+        if (_open.isEmpty) return;
+        _close(_open.removeLast());
+        _scopes.removeLast();
+      case XmlTextEvent():
+        _text.write(event.value);
+      case XmlCDATAEvent():
+        _text.write(event.value);
+    }
+  }
+
+  /// Handle the end of element [name], whose parent is now last in
+  /// [_open].
+  void _close(String name) {
+    final parent = _open.lastOrNull;
+    final depth = _open.length;
+    if (parent == _prop) {
+      _propstat[name] = _children.isNotEmpty
+          ? _children.join(' ')
+          : _text.toString().trim();
+      _children.clear();
+    } else if (depth >= 2 && _open[depth - 2] == _prop) {
+      final text = _text.toString().trim();
+      _children.add(text.isEmpty ? name : text);
+    } else if (name == '{DAV:}href' && parent == _response) {
+      _href = _text.toString().trim();
+    } else if (name == '{DAV:}status' && parent == _propstatName) {
+      _status = _statusCode();
+    } else if (name == '{DAV:}status' && parent == _response) {
+      _responseStatus = _statusCode();
+    } else if (name == '{DAV:}sync-token' && parent == _multistatus) {
+      _syncToken = _text.toString().trim();
+    } else if (name == _propstatName) {
+      final status = _status;
+      if (status == null || (status >= 200 && status < 300)) {
+        _found.addAll(_propstat);
+      }
+      _propstat = {};
+      _status = null;
+    } else if (name == _response) {
+      final href = _href;
+      if (href != null) {
+        _resources.add(
+          DavResource(_decode(href), _found, status: _responseStatus),
+        );
+      }
+      _found = {};
+      _href = null;
+      _responseStatus = null;
+    }
+  }
+
+  /// The code of a status line such as `HTTP/1.1 200 OK`.
+  int? _statusCode() {
+    final parts = _text.toString().trim().split(' ');
+    return parts.length > 1 ? int.tryParse(parts[1]) : null;
+  }
+
+  /// [qualified] as `{namespace}name`, or just the name if its prefix is
+  /// not declared.
+  String _resolve(String qualified) {
+    final colon = qualified.indexOf(':');
+    final prefix = colon < 0 ? '' : qualified.substring(0, colon);
+    final local = qualified.substring(colon + 1);
+    for (final scope in _scopes.reversed) {
+      final namespace = scope[prefix];
+      if (namespace != null) return '{$namespace}$local';
+    }
+    return local;
+  }
+
+  static String _decode(String href) {
+    try {
+      return Uri.decodeFull(href);
+    } on ArgumentError {
+      return href;
+    }
+  }
+}
@@ -1,6 +1,6 @@
 import 'package:dio/dio.dart';
+import '../../auth/auth_method.dart';
 import '../../auth/secure_storage.dart';
-import '../../config/constants.dart';
 import '../api_endpoints.dart';
 
 class AuthInterceptor extends Interceptor {
@@ -9,6 +9,14 @@ class AuthInterceptor extends Interceptor {
   final Future<bool> Function()? onTokenRefresh;
   final void Function()? onForceLogout;
 
+  /// Marks a request retried after a refresh, so it is retried only once.
+  static const _retriedKey = 'authRetried';
+
+  /// Set in a request's `extra` when a 401 means the credentials being
+  /// tried are wrong rather than that the session ended, so the caller
+  /// can say so without the user being signed out.
+  static const checkingCredentialsKey = 'authCheckingCredentials';
+
   bool _isRefreshing = false;
   final List<_RetryRequest> _pendingRequests = [];
 
@@ -43,9 +51,11 @@ class AuthInterceptor extends Interceptor {
       return handler.next(options);
     }
 
-    final token = await secureStorage.getAccessToken();
-    if (token != null) {
-      options.headers['Authorization'] = 'Bearer $token';
+    // Bearer for token sessions, Basic for app-password accounts, WebDAV
+    // included.
+    final authorization = await secureStorage.getAuthorization();
+    if (authorization != null) {
+      options.headers['Authorization'] = authorization;
     }
     handler.next(options);
   }
@@ -59,59 +69,71 @@ class AuthInterceptor extends Interceptor {
       return handler.next(err);
     }
 
-    // Don't retry auth endpoints themselves
-    final path = err.requestOptions.path;
-    if (path == ApiEndpoints.refresh || path == ApiEndpoints.login) {
+    // Don't retry auth endpoints themselves, nor a request that already
+    // went out with a fresh token: the server refuses it for another
+    // reason.
+    final options = err.requestOptions;
+    if (options.path == ApiEndpoints.refresh ||
+        options.path == ApiEndpoints.login ||
+        options.extra[_retriedKey] == true ||
+        options.extra[checkingCredentialsKey] == true) {
+      return handler.next(err);
+    }
+
+    // A rejected app password has no token to refresh.
+    if (await secureStorage.getAuthMethod() == AuthMethod.appPassword) {
       onForceLogout?.call();
       return handler.next(err);
     }
 
     if (_isRefreshing) {
       // Queue this request to retry after refresh completes
-      _pendingRequests.add(_RetryRequest(err.requestOptions, handler));
+      _pendingRequests.add(_RetryRequest(err, handler));
       return;
     }
 
     _isRefreshing = true;
-
+    final retries = [_RetryRequest(err, handler)];
+    bool? renewed;
     try {
-      final success = await onTokenRefresh?.call() ?? false;
-      if (success) {
-        // Retry the original request
-        final token = await secureStorage.getAccessToken();
-        err.requestOptions.headers['Authorization'] = 'Bearer $token';
-        final response = await dio.fetch(err.requestOptions);
-        handler.resolve(response);
-
-        // Retry all queued requests
-        for (final pending in _pendingRequests) {
-          pending.options.headers['Authorization'] = 'Bearer $token';
-          final resp = await dio.fetch(pending.options);
-          pending.handler.resolve(resp);
-        }
-      } else {
-        onForceLogout?.call();
-        handler.next(err);
-        for (final pending in _pendingRequests) {
-          pending.handler.next(err);
-        }
-      }
-    } catch (e) {
-      onForceLogout?.call();
-      handler.next(err);
-      for (final pending in _pendingRequests) {
-        pending.handler.next(err);
-      }
+      renewed = await onTokenRefresh?.call() ?? false;
+    } catch (_) {
+      // The server could not be reached; keep the session.
     } finally {
+      retries.addAll(_pendingRequests);
       _pendingRequests.clear();
       _isRefreshing = false;
     }
+
+    if (renewed != true) {
+      if (renewed == false) onForceLogout?.call();
+      for (final retry in retries) {
+        retry.handler.next(retry.error);
+      }
+      return;
+    }
+
+    final authorization = await secureStorage.getAuthorization();
+    await Future.wait([
+      for (final retry in retries) _retry(retry, authorization),
+    ]);
+  }
+
+  Future<void> _retry(_RetryRequest retry, String? authorization) async {
+    final options = retry.error.requestOptions
+      ..headers['Authorization'] = authorization
+      ..extra[_retriedKey] = true;
+    try {
+      retry.handler.resolve(await dio.fetch(options));
+    } on DioException catch (e) {
+      retry.handler.next(e);
+    }
   }
 }
 
 class _RetryRequest {
-  final RequestOptions options;
+  final DioException error;
   final ErrorInterceptorHandler handler;
 
-  _RetryRequest(this.options, this.handler);
+  _RetryRequest(this.error, this.handler);
 }
@@ -0,0 +1,95 @@
+import 'dart:math';
+
+import 'package:dio/dio.dart';
+
+/// Fault rates for [ChaosInterceptor], each the probability per request.
+class ChaosConfig {
+  /// Chaos mode is compiled in only with `--dart-define=OXICLOUD_CHAOS=true`.
+  static const enabled = bool.fromEnvironment('OXICLOUD_CHAOS');
+
+  /// The request never reaches the server.
+  final double timeoutRate;
+
+  /// The server answers 500 without applying the request.
+  final double serverErrorRate;
+
+  /// The server applies the request but the response is lost, as with a
+  /// connection dropped mid-reply or a write that was only partly acked.
+  final double droppedResponseRate;
+
+  /// Fixes the fault sequence so a failing run can be replayed.
+  final int? seed;
+
+  const ChaosConfig({
+    this.timeoutRate = 0.05,
+    this.serverErrorRate = 0.05,
+    this.droppedResponseRate = 0.05,
+    this.seed,
+  });
+
+  factory ChaosConfig.fromEnvironment() {
+    const seed = int.fromEnvironment('OXICLOUD_CHAOS_SEED', defaultValue: -1);
+    return const ChaosConfig(seed: seed < 0 ? null : seed);
+  }
+}
+
+/// Randomly fails requests to exercise the retry and sync-queue paths
+/// against faults a real network produces only occasionally.
+class ChaosInterceptor extends Interceptor {
+  final ChaosConfig config;
+  final Random _random;
+
+  int injected = 0;
+
+  ChaosInterceptor([this.config = const ChaosConfig()])
+    : _random = Random(config.seed);
+
+  static const _dropKey = 'chaos_drop_response';
+
+  @override
+  void onRequest(RequestOptions options, RequestInterceptorHandler handler) {
+    final roll = _random.nextDouble();
+    if (roll < config.timeoutRate) {
+      injected++;
+      return handler.reject(
+        DioException(
+          requestOptions: options,
+          type: DioExceptionType.connectionTimeout,
+          message: 'chaos: injected timeout',
+        ),
+        true,
+      );
+    }
+    if (roll < config.timeoutRate + config.serverErrorRate) {
+      injected++;
+      return handler.reject(
+        DioException(
+          requestOptions: options,
+          type: DioExceptionType.badResponse,
+          response: Response(requestOptions: options, statusCode: 500),
+          message: 'chaos: injected server error',
+        ),
+        true,
+      );
+    }
+    options.extra[_dropKey] =
+        _random.nextDouble() < config.droppedResponseRate;
+    handler.next(options);
+  }
+
+  @override
+  void onResponse(Response response, ResponseInterceptorHandler handler) {
+    if (response.requestOptions.extra.remove(_dropKey) != true) {
+      return handler.next(response);
+    }
+    injected++;
+    handler.reject(
+      DioException(
+        requestOptions: response.requestOptions,
+        type: DioExceptionType.connectionError,
+        message: 'chaos: response dropped after the server applied it',
+      ),
+      true,
+    );
+  }
+}
@@ -0,0 +1,123 @@
+import 'dart:convert';
+
+import 'package:dio/dio.dart';
+
+/// Extra headers and user agent an account sends with every request, for
+/// reverse proxies that only let allow-listed clients through.
+class CustomHeaders {
+  final Map<String, String> headers;
+  final String? userAgent;
+
+  const CustomHeaders({this.headers = const {}, this.userAgent});
+
+  static const none = CustomHeaders();
+
+  bool get isEmpty => headers.isEmpty && (userAgent?.isEmpty ?? true);
+
+  /// Headers the app sets itself and a proxy rule must not override,
+  /// including the ones WebDAV and conditional requests depend on.
+  static const reserved = {
+    'authorization',
+    'connection',
+    'content-length',
+    'content-type',
+    'depth',
+    'destination',
+    'host',
+    'if-match',
+    'if-none-match',
+    'if-range',
+    'overwrite',
+    'range',
+    'transfer-encoding',
+    'user-agent',
+  };
+
+  static final _token = RegExp(r"^[!#$%&'*+\-.^_`|~0-9A-Za-z]+$");
+  static final _control = RegExp(r'[\x00-\x08\x0A-\x1F\x7F]');
+
+  /// Why [name] cannot be used as a header name, or null if it can.
+  static String? validateName(String name) {
+    if (name.isEmpty) return 'Enter a header name';
+    if (!_token.hasMatch(name)) return 'Not a valid header name';
+    if (reserved.contains(name.toLowerCase())) {
+      return 'Set by the app and cannot be overridden';
+    }
+    return null;
+  }
+
+  /// Why [value] cannot be sent as a header value, or null if it can. Line
+  /// breaks in particular would let a value inject headers of its own.
+  static String? validateValue(String value) {
+    if (_control.hasMatch(value)) return 'Must not contain line breaks';
+    return null;
+  }
+
+  /// [headers] as they may be logged: values of custom and credential
+  /// headers are hidden.
+  static Map<String, dynamic> redact(
+    Map<String, dynamic> headers, {
+    Set<String> custom = const {},
+  }) {
+    final hidden = {
+      'authorization',
+      'cookie',
+      'proxy-authorization',
+      for (final name in custom) name.toLowerCase(),
+    };
+    return {
+      for (final MapEntry(:key, :value) in headers.entries)
+        key: hidden.contains(key.toLowerCase()) ? '<redacted>' : value,
+    };
+  }
+
+  CustomHeaders copyWith({Map<String, String>? headers, String? userAgent}) {
+    return CustomHeaders(
+      headers: headers ?? this.headers,
+      userAgent: userAgent ?? this.userAgent,
+    );
+  }
+
+  String encode() => json.encode({'headers': headers, 'userAgent': userAgent});
+
+  static CustomHeaders decode(String? raw) {
+    if (raw == null || raw.isEmpty) return none;
+    try {
+      final map = json.decode(raw) as Map<String, dynamic>;
+      return CustomHeaders(
+        headers: (map['headers'] as Map<String, dynamic>? ?? const {}).map(
+          (k, v) => MapEntry(k, '$v'),
+        ),
+        userAgent: map['userAgent'] as String?,
+      );
+    } catch (_) {
+      return none;
+    }
+  }
+}
+
+/// Adds the account's [CustomHeaders] to every request. Entries that fail
+/// validation are left out, whatever was saved, and headers a request
+/// sets itself are kept.
+class CustomHeadersInterceptor extends Interceptor {
+  CustomHeaders headers;
+
+  CustomHeadersInterceptor([this.headers = CustomHeaders.none]);
+
+  @override
+  void onRequest(RequestOptions options, RequestInterceptorHandler handler) {
+    for (final MapEntry(:key, :value) in headers.headers.entries) {
+      if (CustomHeaders.validateName(key) == null &&
+          CustomHeaders.validateValue(value) == null) {
+        options.headers.putIfAbsent(key, () => value);
+      }
+    }
+    final agent = headers.userAgent;
+    if (agent != null &&
+        agent.isNotEmpty &&
+        CustomHeaders.validateValue(agent) == null) {
+      options.headers.putIfAbsent('User-Agent', () => agent);
+    }
+    handler.next(options);
+  }
+}
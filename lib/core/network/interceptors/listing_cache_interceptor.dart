@@ -0,0 +1,128 @@
+import 'dart:collection';
+
+import 'package:dio/dio.dart';
+
+/// Caches folder and file listings by URL so quickly browsing back and
+/// forth through remote folders doesn't refetch every listing.
+///
+/// A listing younger than [freshFor] is answered from memory. Older ones
+/// are revalidated with `If-None-Match` when the server sent an ETag, and a
+/// `304 Not Modified` is answered from the cache. Any successful request
+/// that is not a GET may have changed a listing, so it clears the cache.
+class ListingCacheInterceptor extends Interceptor {
+  final Duration freshFor;
+  final int maxEntries;
+  final DateTime Function() _clock;
+
+  final LinkedHashMap<String, _CachedListing> _entries = LinkedHashMap();
+
+  ListingCacheInterceptor({
+    this.freshFor = const Duration(seconds: 5),
+    this.maxEntries = 200,
+    DateTime Function()? clock,
+  }) : _clock = clock ?? DateTime.now;
+
+  static const _cachedKey = 'listing_cache_entry';
+
+  static final _listingPath = RegExp(
+    r'^/(folders|files|favorites|recent|trash)$|'
+    r'^/folders/[^/]+/(listing|contents)(/paginated)?$',
+  );
+
+  void clear() => _entries.clear();
+
+  @override
+  void onRequest(RequestOptions options, RequestInterceptorHandler handler) {
+    if (!_cacheable(options)) return handler.next(options);
+
+    final key = options.uri.toString();
+    final entry = _entries[key];
+    if (entry == null) return handler.next(options);
+
+    if (_clock().difference(entry.storedAt) < freshFor) {
+      return handler.resolve(entry.toResponse(options));
+    }
+    if (entry.etag == null) {
+      _entries.remove(key);
+      return handler.next(options);
+    }
+    options.headers['If-None-Match'] = entry.etag;
+    options.extra[_cachedKey] = entry;
+    handler.next(options);
+  }
+
+  @override
+  void onResponse(Response response, ResponseInterceptorHandler handler) {
+    final options = response.requestOptions;
+    if (!_cacheable(options)) {
+      if (options.method.toUpperCase() != 'GET') clear();
+      return handler.next(response);
+    }
+    if (response.statusCode == 200) {
+      _store(
+        options.uri.toString(),
+        _CachedListing(
+          data: response.data,
+          headers: response.headers,
+          etag: response.headers.value('etag'),
+          storedAt: _clock(),
+        ),
+      );
+    }
+    handler.next(response);
+  }
+
+  @override
+  void onError(DioException err, ErrorInterceptorHandler handler) {
+    final entry = err.requestOptions.extra[_cachedKey] as _CachedListing?;
+    if (err.response?.statusCode != 304 || entry == null) {
+      return handler.next(err);
+    }
+    final refreshed = entry.copyWith(storedAt: _clock());
+    _store(err.requestOptions.uri.toString(), refreshed);
+    handler.resolve(refreshed.toResponse(err.requestOptions));
+  }
+
+  bool _cacheable(RequestOptions options) =>
+      options.method.toUpperCase() == 'GET' &&
+      options.responseType == ResponseType.json &&
+      _listingPath.hasMatch(options.path);
+
+  void _store(String key, _CachedListing entry) {
+    _entries
+      ..remove(key)
+      ..[key] = entry;
+    while (_entries.length > maxEntries) {
+      _entries.remove(_entries.keys.first);
+    }
+  }
+}
+
+class _CachedListing {
+  final Object? data;
+  final Headers headers;
+  final String? etag;
+  final DateTime storedAt;
+
+  const _CachedListing({
+    required this.data,
+    required this.headers,
+    required this.etag,
+    required this.storedAt,
+  });
+
+  _CachedListing copyWith({DateTime? storedAt}) => _CachedListing(
+    data: data,
+    headers: headers,
+    etag: etag,
+    storedAt: storedAt ?? this.storedAt,
+  );
+
+  Response<dynamic> toResponse(RequestOptions options) => Response(
+    requestOptions: options,
+    data: data,
+    headers: headers,
+    statusCode: 200,
+    extra: {'from_cache': true},
+  );
+}
@@ -1,14 +1,23 @@
 import 'package:dio/dio.dart';
 import 'package:logger/logger.dart';
 
+import 'custom_headers_interceptor.dart';
+
+/// Logs requests and responses in debug builds. Credentials and the
+/// account's custom header values are redacted.
 class AppLoggingInterceptor extends Interceptor {
   final Logger logger;
+  final CustomHeadersInterceptor? customHeaders;
 
-  AppLoggingInterceptor({required this.logger});
+  AppLoggingInterceptor({required this.logger, this.customHeaders});
 
   @override
   void onRequest(RequestOptions options, RequestInterceptorHandler handler) {
-    logger.d('→ ${options.method} ${options.uri}');
+    final headers = CustomHeaders.redact(
+      options.headers,
+      custom: customHeaders?.headers.headers.keys.toSet() ?? const {},
+    );
+    logger.d('→ ${options.method} ${options.uri} $headers');
     handler.next(options);
   }
 
@@ -1,21 +1,40 @@
+import 'dart:io';
 import 'dart:math';
 import 'package:dio/dio.dart';
 
+import '../retry_after.dart';
+
+/// Retries requests that failed for reasons that tend to pass on their
+/// own: dropped or reset connections, timeouts, rate limiting and gateway
+/// errors. Waits grow exponentially with jitter up to [maxDelay], unless
+/// the server says how long to wait with `Retry-After`.
 class RetryInterceptor extends Interceptor {
   final Dio dio;
   final int maxRetries;
   final Duration baseDelay;
 
+  /// The longest wait between attempts. A `Retry-After` asking for more
+  /// fails the request instead.
+  final Duration maxDelay;
+
+  final Random _random;
+
   RetryInterceptor({
     required this.dio,
     this.maxRetries = 3,
     this.baseDelay = const Duration(seconds: 1),
-  });
+    this.maxDelay = const Duration(seconds: 30),
+    Random? random,
+  }) : _random = random ?? Random();
 
   static const _retryCountKey = 'retry_count';
 
-  // Only retry on network errors and 5xx server errors
-  static const _retryableStatusCodes = {500, 502, 503, 504};
+  // Timeouts, rate limiting and server errors
+  static const _retryableStatusCodes = {408, 429, 500, 502, 503, 504};
+
+  // Replaying these after the server applied them fails or duplicates
+  // the change
+  static const _unsafeMethods = {'POST', 'MOVE', 'COPY', 'LOCK'};
 
   @override
   Future<void> onError(
@@ -23,17 +42,10 @@ class RetryInterceptor extends Interceptor {
     ErrorInterceptorHandler handler,
   ) async {
     final retryCount = (err.requestOptions.extra[_retryCountKey] as int?) ?? 0;
+    final delay = retryCount < maxRetries ? delayFor(err, retryCount) : null;
+    if (delay == null) return handler.next(err);
 
-    if (!_shouldRetry(err) || retryCount >= maxRetries) {
-      return handler.next(err);
-    }
-
-    // Exponential backoff with jitter
-    final delay = baseDelay * pow(2, retryCount);
-    final jitter = Duration(
-      milliseconds: Random().nextInt(delay.inMilliseconds ~/ 2),
-    );
-    await Future.delayed(delay + jitter);
+    await Future.delayed(delay);
 
     err.requestOptions.extra[_retryCountKey] = retryCount + 1;
 
@@ -45,18 +57,45 @@ class RetryInterceptor extends Interceptor {
     }
   }
 
-  bool _shouldRetry(DioException err) {
-    // Network-level errors
-    if (err.type == DioExceptionType.connectionError ||
-        err.type == DioExceptionType.connectionTimeout ||
-        err.type == DioExceptionType.sendTimeout) {
-      return true;
-    }
-    // Server-side retryable errors
+  /// How long to wait before retrying [err] after [retryCount] retries,
+  /// or null if it should not be retried.
+  Duration? delayFor(DioException err, int retryCount) {
+    if (!shouldRetry(err)) return null;
+    final retryAfter = parseRetryAfter(
+      err.response?.headers.value('retry-after'),
+    );
+    if (retryAfter != null) return retryAfter <= maxDelay ? retryAfter : null;
+
+    // Half the delay is fixed and half random, so clients that failed
+    // together don't come back together
+    final backoff = baseDelay * pow(2, retryCount);
+    final capped = backoff < maxDelay ? backoff : maxDelay;
+    final half = capped.inMilliseconds ~/ 2;
+    return Duration(milliseconds: half + _random.nextInt(half + 1));
+  }
+
+  static bool shouldRetry(DioException err) {
     final statusCode = err.response?.statusCode;
-    if (statusCode != null && _retryableStatusCodes.contains(statusCode)) {
+    // Turned away before the server did anything with the request
+    if (err.type == DioExceptionType.connectionTimeout || statusCode == 429) {
       return true;
     }
-    return false;
+    // Anything else may have been applied before the failure; leave
+    // those to the caller
+    if (_unsafeMethods.contains(err.requestOptions.method.toUpperCase())) {
+      return false;
+    }
+    return switch (err.type) {
+      DioExceptionType.connectionError ||
+      DioExceptionType.sendTimeout ||
+      DioExceptionType.receiveTimeout => true,
+      // The connection was reset or closed mid-exchange
+      DioExceptionType.unknown =>
+        err.error is SocketException || err.error is HttpException,
+      DioExceptionType.badResponse => _retryableStatusCodes.contains(
+        statusCode,
+      ),
+      _ => false,
+    };
   }
 }
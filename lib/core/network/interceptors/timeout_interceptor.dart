@@ -0,0 +1,82 @@
+import 'dart:async';
+
+import 'package:dio/dio.dart';
+
+/// Bounds how long a request may take and lets every request in flight be
+/// cancelled at once, so a hung server cannot stall sync or shutdown.
+///
+/// Dio's own timeouts only measure inactivity (time to connect, gap between
+/// received bytes). On top of them, API calls that are not transfers get an
+/// overall [deadline], and listings a shorter [listingReceiveTimeout]
+/// since they are small and block the UI. Uploads and downloads are left to
+/// the inactivity timeouts: a large file can legitimately take hours.
+class TimeoutInterceptor extends Interceptor {
+  final Duration listingReceiveTimeout;
+  final Duration deadline;
+
+  final Set<CancelToken> _inFlight = {};
+
+  TimeoutInterceptor({
+    this.listingReceiveTimeout = const Duration(seconds: 30),
+    this.deadline = const Duration(minutes: 2),
+  });
+
+  static const _timerKey = 'timeout_deadline_timer';
+
+  static final _listingPath = RegExp(
+    r'^/(folders|files|favorites|recent|trash|search)$|'
+    r'^/folders/[^/]+/(listing|contents)(/paginated)?$',
+  );
+
+  /// Cancel every request in flight; callers see a
+  /// [DioExceptionType.cancel] error.
+  void cancelAll([String reason = 'Cancelled']) {
+    for (final token in _inFlight.toList()) {
+      token.cancel(reason);
+    }
+    _inFlight.clear();
+  }
+
+  @override
+  void onRequest(RequestOptions options, RequestInterceptorHandler handler) {
+    final token = options.cancelToken ??= CancelToken();
+    if (token.isCancelled) return handler.next(options);
+    _inFlight.add(token);
+
+    if (!_isTransfer(options)) {
+      if (options.method.toUpperCase() == 'GET' &&
+          _listingPath.hasMatch(options.path)) {
+        options.receiveTimeout = listingReceiveTimeout;
+      }
+      (options.extra[_timerKey] as Timer?)?.cancel();
+      options.extra[_timerKey] = Timer(
+        deadline,
+        () => token.cancel('No reply within ${deadline.inSeconds}s'),
+      );
+    }
+    handler.next(options);
+  }
+
+  @override
+  void onResponse(Response response, ResponseInterceptorHandler handler) {
+    _finish(response.requestOptions);
+    handler.next(response);
+  }
+
+  @override
+  void onError(DioException err, ErrorInterceptorHandler handler) {
+    _finish(err.requestOptions);
+    handler.next(err);
+  }
+
+  void _finish(RequestOptions options) {
+    (options.extra.remove(_timerKey) as Timer?)?.cancel();
+    _inFlight.remove(options.cancelToken);
+  }
+
+  bool _isTransfer(RequestOptions options) =>
+      options.data is FormData ||
+      options.data is Stream ||
+      options.responseType == ResponseType.stream ||
+      options.responseType == ResponseType.bytes;
+}
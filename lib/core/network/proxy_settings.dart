@@ -0,0 +1,143 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'socks5_connector.dart';
+
+/// Where the app's connections go.
+enum ProxyMode {
+  /// The proxy named by the `http_proxy`, `https_proxy` and `no_proxy`
+  /// environment variables, if any. The default.
+  system,
+
+  /// Always connect directly, whatever the environment says.
+  none,
+  manual;
+
+  String get label => switch (this) {
+    none => 'No proxy',
+    system => 'System proxy',
+    manual => 'Manual',
+  };
+
+  static ProxyMode parse(String? value) => ProxyMode.values.firstWhere(
+    (m) => m.name == value,
+    orElse: () => system,
+  );
+}
+
+/// The proxy every connection of the app goes through.
+class ProxySettings {
+  final ProxyMode mode;
+
+  /// For [ProxyMode.manual]: `http://host:port`, which HTTPS goes
+  /// through with `CONNECT`, or `socks5://host:port`.
+  final String url;
+  final String? username;
+  final String? password;
+
+  const ProxySettings({
+    this.mode = ProxyMode.system,
+    this.url = '',
+    this.username,
+    this.password,
+  });
+
+  static const system = ProxySettings();
+
+  static const _schemes = {'http', 'socks5', 'socks5h'};
+
+  /// Why [url] cannot be used as a proxy, or null if it can.
+  static String? validateUrl(String url) {
+    final uri = Uri.tryParse(url);
+    if (uri == null || uri.host.isEmpty) return 'Enter a proxy address';
+    if (!_schemes.contains(uri.scheme)) {
+      return 'Use http:// or socks5://';
+    }
+    if (!uri.hasPort) return 'Include the port';
+    return null;
+  }
+
+  bool get _hasLogin => username?.isNotEmpty ?? false;
+
+  /// Route [client]'s connections. [context] and [onBadCertificate] are
+  /// used for HTTPS through SOCKS, where TLS is set up here.
+  void applyTo(
+    HttpClient client, {
+    SecurityContext? context,
+    bool Function(X509Certificate certificate, String host, int port)?
+    onBadCertificate,
+    Map<String, String>? environment,
+  }) {
+    switch (mode) {
+      case ProxyMode.none:
+        client.findProxy = (_) => 'DIRECT';
+      case ProxyMode.system:
+        client.findProxy = (uri) => HttpClient.findProxyFromEnvironment(
+          uri,
+          environment: environment,
+        );
+      case ProxyMode.manual:
+        final proxy = Uri.parse(url);
+        if (proxy.scheme.startsWith('socks')) {
+          final connector = Socks5Connector(
+            host: proxy.host,
+            port: proxy.port,
+            username: username,
+            password: password,
+          );
+          // The proxy is reached through the factory, never the
+          // environment's.
+          client.findProxy = (_) => 'DIRECT';
+          client.connectionFactory = (uri, _, _) => connector.connect(
+            uri,
+            context: context,
+            onBadCertificate: onBadCertificate == null
+                ? null
+                : (c) => onBadCertificate(c, uri.host, uri.port),
+          );
+          return;
+        }
+        client.findProxy = (_) => 'PROXY ${proxy.host}:${proxy.port}';
+        if (_hasLogin) {
+          // Offer the login once per realm, so a wrong one fails.
+          final offered = <String>{};
+          client.authenticateProxy = (host, port, scheme, realm) async {
+            if (!offered.add('$host:$port/$realm')) return false;
+            final user = username!;
+            final secret = password ?? '';
+            client.addProxyCredentials(
+              host,
+              port,
+              realm ?? '',
+              scheme.toLowerCase() == 'digest'
+                  ? HttpClientDigestCredentials(user, secret)
+                  : HttpClientBasicCredentials(user, secret),
+            );
+            return true;
+          };
+        }
+    }
+  }
+
+  String encode() => json.encode({
+    'mode': mode.name,
+    'url': url,
+    'username': username,
+    'password': password,
+  });
+
+  static ProxySettings decode(String? raw) {
+    if (raw == null || raw.isEmpty) return system;
+    try {
+      final map = json.decode(raw) as Map<String, dynamic>;
+      return ProxySettings(
+        mode: ProxyMode.parse(map['mode'] as String?),
+        url: map['url'] as String? ?? '',
+        username: map['username'] as String?,
+        password: map['password'] as String?,
+      );
+    } catch (_) {
+      return system;
+    }
+  }
+}
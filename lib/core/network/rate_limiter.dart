@@ -0,0 +1,79 @@
+import 'dart:async';
+import 'dart:math';
+
+/// Token bucket shared by every transfer in one direction, so a limit
+/// holds for all of them together rather than per transfer.
+///
+/// The bucket holds up to one second's worth of bytes. A caller that takes
+/// more than is available goes into debt and waits until it is paid off;
+/// later callers wait behind that debt, which keeps concurrent transfers
+/// roughly fair. [bytesPerSecond] can be changed while transfers run.
+class RateLimiter {
+  /// Larger chunks are split so throttled streams move smoothly.
+  static const sliceSize = 64 * 1024;
+
+  final DateTime Function() _clock;
+  int _bytesPerSecond;
+  double _tokens = 0;
+  DateTime? _last;
+
+  RateLimiter({int bytesPerSecond = 0, DateTime Function()? clock})
+    : _bytesPerSecond = bytesPerSecond,
+      _clock = clock ?? DateTime.now;
+
+  /// 0 means unlimited.
+  int get bytesPerSecond => _bytesPerSecond;
+
+  set bytesPerSecond(int value) {
+    _refill();
+    _bytesPerSecond = max(0, value);
+    _tokens = min(_tokens, _bytesPerSecond.toDouble());
+  }
+
+  bool get isLimited => _bytesPerSecond > 0;
+
+  /// How long the caller must wait before sending [bytes].
+  Duration reserve(int bytes) {
+    if (!isLimited) return Duration.zero;
+    _refill();
+    _tokens -= bytes;
+    if (_tokens >= 0) return Duration.zero;
+    return Duration(microseconds: (-_tokens / _bytesPerSecond * 1e6).ceil());
+  }
+
+  /// Wait until [bytes] may be sent.
+  Future<void> take(int bytes) {
+    final wait = reserve(bytes);
+    return wait == Duration.zero ? Future.value() : Future.delayed(wait);
+  }
+
+  /// [source] slowed down to the limit. Reading pauses while it waits, so
+  /// a network source is held back at the socket.
+  Stream<List<int>> throttle(Stream<List<int>> source) async* {
+    await for (final chunk in source) {
+      if (!isLimited) {
+        yield chunk;
+        continue;
+      }
+      for (var i = 0; i < chunk.length; i += sliceSize) {
+        final slice = chunk.length <= sliceSize
+            ? chunk
+            : chunk.sublist(i, min(i + sliceSize, chunk.length));
+        await take(slice.length);
+        yield slice;
+      }
+    }
+  }
+
+  void _refill() {
+    final now = _clock();
+    final last = _last;
+    _last = now;
+    if (last == null || !isLimited) return;
+    final elapsed = now.difference(last).inMicroseconds / 1e6;
+    _tokens = min(
+      _bytesPerSecond.toDouble(),
+      _tokens + elapsed * _bytesPerSecond,
+    );
+  }
+}
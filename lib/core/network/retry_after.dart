@@ -0,0 +1,16 @@
+import 'dart:io';
+import 'dart:math';
+
+/// A `Retry-After` [value] in seconds or as an HTTP date, relative to
+/// [now].
+Duration? parseRetryAfter(String? value, {DateTime? now}) {
+  if (value == null) return null;
+  final seconds = int.tryParse(value.trim());
+  if (seconds != null) return Duration(seconds: max(seconds, 0));
+  try {
+    final wait = HttpDate.parse(value).difference(now ?? DateTime.now());
+    return wait.isNegative ? Duration.zero : wait;
+  } on HttpException {
+    return null;
+  }
+}
@@ -0,0 +1,21 @@
+import 'package:dio/dio.dart';
+
+import '../auth/secure_storage.dart';
+
+/// Connects to servers other than the signed-in account's, such as the
+/// host of a linked share, the way the app connects to its own: through
+/// the app's proxy, trusting the certificates the user trusted for that
+/// server.
+class ServerClientFactory {
+  final SecureStorage _secureStorage;
+
+  ServerClientFactory(this._secureStorage);
+
+  /// A client for the API of the server at [serverUrl].
+  Future<Dio> create(String serverUrl) async {
+    final dio = Dio(BaseOptions(baseUrl: '$serverUrl/api'));
+    final trust = await _secureStorage.getTlsTrust(serverUrl);
+    trust.applyTo(dio, proxy: await _secureStorage.getProxySettings());
+    return dio;
+  }
+}
@@ -0,0 +1,191 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+import 'dart:typed_data';
+
+/// Opens connections through a SOCKS5 proxy (RFC 1928), logging in with
+/// a username and password (RFC 1929) when given. The proxy resolves
+/// host names, as with `socks5h`.
+///
+/// `HttpClient` only takes sockets it can set up TLS on itself, so each
+/// tunnel is handed over through a loopback connection, accepted only
+/// from the socket opened for it.
+class Socks5Connector {
+  final String host;
+  final int port;
+  final String? username;
+  final String? password;
+
+  const Socks5Connector({
+    required this.host,
+    required this.port,
+    this.username,
+    this.password,
+  });
+
+  /// A connection to [uri]'s host through the proxy; for `https`, one
+  /// secured with [context] and [onBadCertificate].
+  Future<ConnectionTask<Socket>> connect(
+    Uri uri, {
+    SecurityContext? context,
+    bool Function(X509Certificate certificate)? onBadCertificate,
+  }) async {
+    final task = await Socket.startConnect(host, port);
+    Future<Socket> open() async {
+      final local = await _handOver(await _tunnel(await task.socket, uri));
+      if (uri.scheme != 'https') return local;
+      return SecureSocket.secure(
+        local,
+        host: uri.host,
+        context: context,
+        onBadCertificate: onBadCertificate,
+      );
+    }
+
+    return ConnectionTask.fromSocket(open(), task.cancel);
+  }
+
+  /// Ask the proxy on [socket] to connect to [uri]. Returns the socket's
+  /// subscription, paused, and whatever already arrived past the reply.
+  Future<(Socket, StreamSubscription<Uint8List>, List<int>)> _tunnel(
+    Socket socket,
+    Uri uri,
+  ) async {
+    final reader = _Reader();
+    final subscription = socket.listen(
+      reader.add,
+      onError: reader.fail,
+      onDone: reader.close,
+    );
+    try {
+      final user = utf8.encode(username ?? '');
+      final pass = utf8.encode(password ?? '');
+      final login = user.isNotEmpty;
+      socket.add([5, if (login) ...[2, 0, 2] else ...[1, 0]]);
+
+      final [_, method] = await reader.read(2);
+      if (method == 2 && login) {
+        socket.add([1, user.length, ...user, pass.length, ...pass]);
+        final [_, status] = await reader.read(2);
+        if (status != 0) throw const SocketException('SOCKS5 login refused');
+      } else if (method != 0) {
+        throw const SocketException('SOCKS5 proxy wants a login');
+      }
+
+      final target = utf8.encode(uri.host);
+      socket.add([
+        5, 1, 0, 3, target.length, ...target, //
+        uri.port >> 8, uri.port & 0xff,
+      ]);
+      final [_, reply, _, type] = await reader.read(4);
+      if (reply != 0) {
+        throw SocketException('SOCKS5 proxy: ${_replies[reply] ?? reply}');
+      }
+      final addressLength = switch (type) {
+        1 => 4,
+        4 => 16,
+        _ => (await reader.read(1)).single,
+      };
+      await reader.read(addressLength + 2);
+      // Hold further bytes until the hand-over takes them.
+      subscription.pause();
+      return (socket, subscription, reader.takeRest());
+    } catch (_) {
+      await subscription.cancel();
+      socket.destroy();
+      rethrow;
+    }
+  }
+
+  /// A plain socket whose bytes are piped to and from [tunnel].
+  static Future<Socket> _handOver(
+    (Socket, StreamSubscription<Uint8List>, List<int>) tunnel,
+  ) async {
+    final (remote, subscription, rest) = tunnel;
+    ServerSocket? server;
+    try {
+      server = await ServerSocket.bind(InternetAddress.loopbackIPv4, 0);
+      final local = await Socket.connect(server.address, server.port);
+      // Any local process can connect to the port; only take our socket.
+      final pipe = await server.firstWhere((accepted) {
+        final ours = accepted.remotePort == local.port;
+        if (!ours) accepted.destroy();
+        return ours;
+      });
+      if (rest.isNotEmpty) pipe.add(rest);
+      subscription
+        ..onData(pipe.add)
+        ..onError((Object _) => pipe.destroy())
+        ..onDone(() => pipe.close())
+        ..resume();
+      pipe.listen(
+        remote.add,
+        onError: (Object _) => remote.destroy(),
+        onDone: () => remote.close(),
+      );
+      return local;
+    } catch (_) {
+      await subscription.cancel();
+      remote.destroy();
+      rethrow;
+    } finally {
+      await server?.close();
+    }
+  }
+
+  static const _replies = {
+    1: 'general failure',
+    2: 'connection not allowed',
+    3: 'network unreachable',
+    4: 'host unreachable',
+    5: 'connection refused',
+    6: 'TTL expired',
+    7: 'command not supported',
+    8: 'address type not supported',
+  };
+}
+
+/// Bytes from the proxy, read in the lengths its replies come in.
+class _Reader {
+  final _buffer = <int>[];
+  Completer<void>? _waiting;
+  Object? _error;
+  bool _closed = false;
+
+  void add(List<int> bytes) {
+    _buffer.addAll(bytes);
+    _wake();
+  }
+
+  void fail(Object error) {
+    _error = error;
+    _wake();
+  }
+
+  void close() {
+    _closed = true;
+    _wake();
+  }
+
+  void _wake() {
+    _waiting?.complete();
+    _waiting = null;
+  }
+
+  Future<List<int>> read(int n) async {
+    while (_buffer.length < n) {
+      if (_error case final error?) throw error;
+      if (_closed) throw const SocketException('SOCKS5 proxy hung up');
+      await (_waiting = Completer()).future;
+    }
+    final bytes = _buffer.sublist(0, n);
+    _buffer.removeRange(0, n);
+    return bytes;
+  }
+
+  List<int> takeRest() {
+    final rest = List.of(_buffer);
+    _buffer.clear();
+    return rest;
+  }
+}
@@ -0,0 +1,182 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:crypto/crypto.dart';
+import 'package:dio/dio.dart';
+import 'package:dio/io.dart';
+
+import 'proxy_settings.dart';
+
+/// Certificates an account trusts beyond the system's, for servers with
+/// self-signed or private-CA certificates.
+class TlsTrust {
+  /// SHA-256 fingerprints of accepted server certificates, lowercase hex.
+  final Set<String> fingerprints;
+
+  /// A CA certificate, in PEM, trusted next to the system's.
+  final String? caPem;
+
+  /// Refuse every certificate not in [fingerprints], even one the system
+  /// trusts. Has no effect until a fingerprint is accepted.
+  final bool pinned;
+
+  const TlsTrust({
+    this.fingerprints = const {},
+    this.caPem,
+    this.pinned = false,
+  });
+
+  static const none = TlsTrust();
+
+  bool get isPinned => pinned && fingerprints.isNotEmpty;
+
+  /// Whether a certificate with [fingerprint] may be used.
+  /// [systemTrusted] is whether its chain checked out.
+  bool accepts(String fingerprint, {required bool systemTrusted}) =>
+      fingerprints.contains(fingerprint) || (systemTrusted && !isPinned);
+
+  static String fingerprintOf(X509Certificate certificate) =>
+      sha256.convert(certificate.der).toString();
+
+  /// Why [pem] cannot be used as a CA certificate, or null if it can.
+  static String? validateCa(String pem) {
+    if (!pem.contains('-----BEGIN CERTIFICATE-----')) {
+      return 'Paste a PEM certificate';
+    }
+    try {
+      SecurityContext(withTrustedRoots: false).setTrustedCertificatesBytes(
+        utf8.encode(pem),
+      );
+      return null;
+    } on TlsException {
+      return 'Not a valid certificate';
+    }
+  }
+
+  /// This trust with the certificate [fingerprint] accepted as well.
+  TlsTrust trust(String fingerprint) =>
+      copyWith(fingerprints: {...fingerprints, fingerprint});
+
+  TlsTrust copyWith({Set<String>? fingerprints, bool? pinned}) {
+    return TlsTrust(
+      fingerprints: fingerprints ?? this.fingerprints,
+      caPem: caPem,
+      pinned: pinned ?? this.pinned,
+    );
+  }
+
+  /// This trust with [caPem] replaced; null removes the CA.
+  TlsTrust withCa(String? caPem) =>
+      TlsTrust(fingerprints: fingerprints, caPem: caPem, pinned: pinned);
+
+  /// Make [dio]'s connections follow this trust, through [proxy].
+  /// [onUntrusted] hears of each certificate refused, so the user can be
+  /// asked about it.
+  void applyTo(
+    Dio dio, {
+    ProxySettings proxy = ProxySettings.system,
+    void Function(UntrustedCertificate certificate)? onUntrusted,
+  }) {
+    dio.httpClientAdapter = IOHttpClientAdapter(
+      createHttpClient: () {
+        final context = SecurityContext(withTrustedRoots: true);
+        if (caPem case final pem?) {
+          try {
+            context.setTrustedCertificatesBytes(utf8.encode(pem));
+          } on TlsException {
+            // Checked by [validateCa] when it was saved.
+          }
+        }
+
+        bool onBadCertificate(
+          X509Certificate certificate,
+          String host,
+          int port,
+        ) {
+          final fingerprint = fingerprintOf(certificate);
+          if (accepts(fingerprint, systemTrusted: false)) return true;
+          onUntrusted?.call(UntrustedCertificate.of(certificate, host, port));
+          return false;
+        }
+
+        final client = HttpClient(context: context)
+          ..badCertificateCallback = onBadCertificate;
+        proxy.applyTo(
+          client,
+          context: context,
+          onBadCertificate: onBadCertificate,
+        );
+        return client;
+      },
+      // Chains the system accepts only reach this when pinned.
+      validateCertificate: isPinned
+          ? (certificate, host, port) {
+              if (certificate == null) return true;
+              final fingerprint = fingerprintOf(certificate);
+              if (accepts(fingerprint, systemTrusted: true)) return true;
+              onUntrusted?.call(
+                UntrustedCertificate.of(certificate, host, port),
+              );
+              return false;
+            }
+          : null,
+    );
+  }
+
+  String encode() => json.encode({
+    'fingerprints': fingerprints.toList(),
+    'caPem': caPem,
+    'pinned': pinned,
+  });
+
+  static TlsTrust decode(String? raw) {
+    if (raw == null || raw.isEmpty) return none;
+    try {
+      final map = json.decode(raw) as Map<String, dynamic>;
+      return TlsTrust(
+        fingerprints: {
+          for (final f in map['fingerprints'] as List<dynamic>? ?? const [])
+            '$f',
+        },
+        caPem: map['caPem'] as String?,
+        pinned: map['pinned'] == true,
+      );
+    } catch (_) {
+      return none;
+    }
+  }
+}
+
+/// A certificate a server presented that nothing trusts, with what the
+/// user needs to decide whether to.
+class UntrustedCertificate {
+  final String host;
+  final int port;
+  final String fingerprint;
+  final String subject;
+  final String issuer;
+  final DateTime validUntil;
+
+  const UntrustedCertificate({
+    required this.host,
+    required this.port,
+    required this.fingerprint,
+    required this.subject,
+    required this.issuer,
+    required this.validUntil,
+  });
+
+  UntrustedCertificate.of(X509Certificate certificate, this.host, this.port)
+    : fingerprint = TlsTrust.fingerprintOf(certificate),
+      subject = certificate.subject,
+      issuer = certificate.issuer,
+      validUntil = certificate.endValidity;
+
+  /// [fingerprint] in colon-separated pairs, as browsers show it.
+  String get displayFingerprint => formatFingerprint(fingerprint);
+
+  static String formatFingerprint(String fingerprint) => [
+    for (var i = 0; i + 1 < fingerprint.length; i += 2)
+      fingerprint.substring(i, i + 2).toUpperCase(),
+  ].join(':');
+}
@@ -0,0 +1,51 @@
+import 'dart:io';
+
+import 'package:logger/logger.dart';
+import 'package:path/path.dart' as p;
+
+import 'platform_info.dart';
+
+/// Marks internal cache directories so that backup tools and desktop
+/// indexers skip them.
+///
+/// Every platform gets a `CACHEDIR.TAG` (honoured by tar, borg, restic and
+/// most Linux backup tools). On macOS the directory is also excluded from
+/// Time Machine, and on Windows it is flagged as not content indexed.
+class BackupExclusion {
+  BackupExclusion._();
+
+  static const _tagName = 'CACHEDIR.TAG';
+  static const _tagContents =
+      'Signature: 8a477f597d28d172789f06886806bc55\n'
+      '# This file is a cache directory tag created by OxiCloud.\n'
+      '# For information about cache directory tags, see:\n'
+      '#\thttps://bford.info/cachedir/\n';
+
+  static final Logger _logger = Logger();
+
+  /// Exclude [dirPath] from backups. Cheap to call repeatedly: the tag file
+  /// records that the directory has already been marked.
+  static Future<void> mark(String dirPath) async {
+    if (!PlatformInfo.isDesktop) return;
+    final tag = File(p.join(dirPath, _tagName));
+    if (tag.existsSync()) return;
+
+    try {
+      await tag.writeAsString(_tagContents);
+      if (PlatformInfo.isMacOS) {
+        await _run('tmutil', ['addexclusion', dirPath]);
+      } else if (PlatformInfo.isWindows) {
+        await _run('attrib', ['+I', dirPath]);
+      }
+    } catch (e) {
+      _logger.w('Could not exclude $dirPath from backups', error: e);
+    }
+  }
+
+  static Future<void> _run(String command, List<String> args) async {
+    final result = await Process.run(command, args);
+    if (result.exitCode != 0) {
+      _logger.w('$command ${args.join(' ')} failed: ${result.stderr}');
+    }
+  }
+}
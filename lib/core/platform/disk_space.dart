@@ -0,0 +1,40 @@
+import 'dart:io';
+
+import '../error/exceptions.dart';
+import '../services/diagnostics_service.dart';
+
+/// Checks free disk space before downloads start, so that a full disk stops
+/// them up front instead of halfway through with a partial file left over.
+class DiskSpace {
+  final Future<int?> Function(String dir) _freeBytes;
+
+  /// Space to leave free on top of what downloads need.
+  final int reserveBytes;
+
+  DiskSpace({
+    Future<int?> Function(String dir)? freeBytes,
+    this.reserveBytes = 100 * 1024 * 1024,
+  }) : _freeBytes = freeBytes ?? dfFreeBytes;
+
+  /// Bytes free on the volume holding [dir], or null if unknown.
+  Future<int?> freeBytes(String dir) => _freeBytes(dir);
+
+  /// Throws [DiskFullException] if the volume holding [dir] cannot take
+  /// [bytes] more. Passes when free space cannot be measured.
+  Future<void> ensureRoom(String dir, int bytes) async {
+    final free = await _freeBytes(dir);
+    if (free == null || bytes + reserveBytes <= free) return;
+    throw DiskFullException(dir, required: bytes, available: free);
+  }
+
+  /// Free space as reported by `df`; null on Windows or if `df` fails.
+  static Future<int?> dfFreeBytes(String dir) async {
+    if (Platform.isWindows) return null;
+    try {
+      final result = await Process.run('df', ['-Pk', dir]);
+      return DiagnosticsService.parseDfAvailable('${result.stdout}');
+    } on ProcessException {
+      return null;
+    }
+  }
+}
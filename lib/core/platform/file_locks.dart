@@ -0,0 +1,60 @@
+import 'dart:async';
+import 'dart:io';
+
+import '../error/exceptions.dart';
+import 'platform_info.dart';
+
+/// Detects files another application holds open, as Windows does for
+/// documents being edited, and waits for them to be released.
+class FileLocks {
+  FileLocks._();
+
+  // ERROR_SHARING_VIOLATION and ERROR_LOCK_VIOLATION.
+  static const _lockErrors = {32, 33};
+
+  /// How long to wait before each new check of a locked file.
+  static const retryDelays = [
+    Duration(seconds: 2),
+    Duration(seconds: 5),
+    Duration(seconds: 15),
+    Duration(seconds: 30),
+    Duration(minutes: 1),
+    Duration(minutes: 2),
+    Duration(minutes: 5),
+  ];
+
+  static bool isLockError(FileSystemException e) =>
+      PlatformInfo.isWindows && _lockErrors.contains(e.osError?.errorCode);
+
+  /// Whether [file] cannot be read because another application has it
+  /// locked.
+  static Future<bool> isLocked(File file) async {
+    try {
+      final raf = await file.open();
+      await raf.close();
+      return false;
+    } on FileSystemException catch (e) {
+      if (isLockError(e)) return true;
+      rethrow;
+    }
+  }
+
+  /// Complete once [file] can be read, checking again after each of
+  /// [delays]. [onWaiting] is called when the file is first found locked.
+  /// Throws [FileLockedException] if it is still locked after the last
+  /// delay.
+  static Future<void> waitUntilReleased(
+    File file, {
+    List<Duration> delays = retryDelays,
+    void Function()? onWaiting,
+    Future<bool> Function(File file) locked = isLocked,
+  }) async {
+    if (!await locked(file)) return;
+    onWaiting?.call();
+    for (final delay in delays) {
+      await Future<void>.delayed(delay);
+      if (!await locked(file)) return;
+    }
+    throw FileLockedException(file.path);
+  }
+}
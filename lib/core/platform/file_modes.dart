@@ -0,0 +1,55 @@
+import 'dart:io';
+
+import 'package:logger/logger.dart';
+
+import '../database/app_database.dart';
+
+/// Keeps Unix permission bits, notably the executable flag, across an
+/// upload and a later download.
+///
+/// The server has nowhere to store them, so the mode of each file uploaded
+/// from this device is kept in the local database by remote id and applied
+/// again when the file is downloaded. Files that came from elsewhere keep
+/// the platform's default permissions.
+class FileModeStore {
+  final AppDatabase _db;
+  final bool enabled;
+  final Logger _logger = Logger();
+
+  FileModeStore(this._db, {this.enabled = true});
+
+  bool get _active => enabled && (Platform.isLinux || Platform.isMacOS);
+
+  /// Record the mode of [localPath], just uploaded as [fileId].
+  Future<void> remember(String fileId, String localPath) async {
+    if (!_active) return;
+    final stat = await FileStat.stat(localPath);
+    if (stat.type == FileSystemEntityType.notFound) return;
+    await _db.upsertFileMode(
+      FileModesTableCompanion.insert(
+        fileId: fileId,
+        mode: sanitize(stat.mode),
+        recordedAt: DateTime.now(),
+      ),
+    );
+  }
+
+  /// Apply the recorded mode of [fileId], if any, to its download at
+  /// [localPath].
+  Future<void> restore(String fileId, String localPath) async {
+    if (!_active) return;
+    final mode = await _db.getFileMode(fileId);
+    if (mode == null) return;
+    final result = await Process.run('chmod', [
+      mode.toRadixString(8),
+      localPath,
+    ]);
+    if (result.exitCode != 0) {
+      _logger.w('Could not restore mode of $localPath: ${result.stderr}');
+    }
+  }
+
+  /// Keep the rwx bits only (no setuid, setgid or sticky) and never hand
+  /// back a file its owner cannot read and write.
+  static int sanitize(int mode) => (mode & 0x1ff) | 0x180; // 0777, 0600
+}
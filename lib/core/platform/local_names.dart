@@ -0,0 +1,47 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as p;
+
+/// Turns file names chosen by someone else, such as a sharing server or
+/// whoever dropped a file in an inbox, into paths that stay in the folder
+/// they are meant for.
+class LocalNames {
+  LocalNames._();
+
+  /// The last segment of [name], split on both `/` and `\`. Null if that
+  /// leaves nothing usable: empty, `.` or `..`.
+  static String? sanitize(String name) {
+    final base = name.split(RegExp(r'[/\\]')).last.trim();
+    if (base.isEmpty || base == '.' || base == '..') return null;
+    return base;
+  }
+
+  /// Whether [path] is inside [dir], not just next to it.
+  static bool isInside(String dir, String path) =>
+      p.isWithin(p.normalize(p.absolute(dir)), p.normalize(p.absolute(path)));
+
+  /// A free path in [dir] for [name], which must already be sanitized:
+  /// `name.ext`, or else `name (2).ext` and up. By default a path is taken
+  /// when something exists there.
+  static String unique(
+    String dir,
+    String name, {
+    bool Function(String path)? isTaken,
+  }) {
+    final taken = isTaken ?? _exists;
+    final stem = p.basenameWithoutExtension(name);
+    final ext = p.extension(name);
+    var path = p.join(dir, name);
+    for (var i = 2; taken(path); i++) {
+      path = p.join(dir, '$stem ($i)$ext');
+    }
+    if (!isInside(dir, path)) {
+      throw ArgumentError.value(name, 'name', 'Leaves $dir');
+    }
+    return path;
+  }
+
+  static bool _exists(String path) =>
+      FileSystemEntity.typeSync(path, followLinks: false) !=
+      FileSystemEntityType.notFound;
+}
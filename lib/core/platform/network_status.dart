@@ -0,0 +1,133 @@
+import 'dart:io';
+
+import 'platform_info.dart';
+
+/// Whether the current connection is charged by the amount of data, and
+/// whether it is a mobile connection abroad.
+class NetworkStatus {
+  final bool metered;
+  final bool roaming;
+
+  const NetworkStatus({this.metered = false, this.roaming = false});
+
+  static const unrestricted = NetworkStatus();
+
+  bool get isCostly => metered || roaming;
+
+  @override
+  bool operator ==(Object other) =>
+      other is NetworkStatus &&
+      other.metered == metered &&
+      other.roaming == roaming;
+
+  @override
+  int get hashCode => Object.hash(metered, roaming);
+}
+
+/// Reads [NetworkStatus] from the operating system: the connection cost
+/// of the internet profile (Windows), NetworkManager and ModemManager
+/// (Linux), or the tell-tale signs of a phone hotspot (macOS, which has no
+/// command line view of "expensive" connections). Other platforms report
+/// nothing.
+class NetworkStatusProbe {
+  /// The subnet iPhones hand out as a personal hotspot.
+  static const iPhoneHotspotPrefix = '172.20.10.';
+
+  Future<NetworkStatus?> read() async {
+    try {
+      if (PlatformInfo.isWindows) return await _readWindows();
+      if (PlatformInfo.isLinux) return await _readLinux();
+      if (PlatformInfo.isMacOS) return await _readMacOS();
+    } on Exception {
+      return null;
+    }
+    return null;
+  }
+
+  Future<NetworkStatus?> _readWindows() async {
+    const script =
+        r'$c = [Windows.Networking.Connectivity.NetworkInformation, '
+        r'Windows.Networking.Connectivity, ContentType=WindowsRuntime]'
+        r'::GetInternetConnectionProfile().GetConnectionCost(); '
+        r'"$($c.NetworkCostType) $($c.Roaming) $($c.OverDataLimit)"';
+    final result = await Process.run('powershell', [
+      '-NoProfile',
+      '-NonInteractive',
+      '-Command',
+      script,
+    ]);
+    if (result.exitCode != 0) return null;
+    return parseWindowsCost('${result.stdout}');
+  }
+
+  Future<NetworkStatus?> _readLinux() async {
+    final nm = await Process.run('busctl', [
+      'get-property',
+      'org.freedesktop.NetworkManager',
+      '/org/freedesktop/NetworkManager',
+      'org.freedesktop.NetworkManager',
+      'Metered',
+    ]);
+    if (nm.exitCode != 0) return null;
+    var roaming = false;
+    try {
+      final mm = await Process.run('mmcli', ['-m', 'any', '-K']);
+      roaming = mm.exitCode == 0 && parseModemRoaming('${mm.stdout}');
+    } on ProcessException {
+      // No ModemManager, so no mobile connection to roam on.
+    }
+    return NetworkStatus(
+      metered: parseNetworkManagerMetered('${nm.stdout}'),
+      roaming: roaming,
+    );
+  }
+
+  Future<NetworkStatus?> _readMacOS() async {
+    final route = await Process.run('route', ['-n', 'get', 'default']);
+    final interface = RegExp(
+      r'interface:\s*(\S+)',
+    ).firstMatch('${route.stdout}')?.group(1);
+    if (interface == null) return null;
+    final address = await Process.run('ipconfig', ['getifaddr', interface]);
+    final packet = await Process.run('ipconfig', ['getpacket', interface]);
+    return NetworkStatus(
+      metered: isPhoneHotspot(
+        address: '${address.stdout}'.trim(),
+        dhcpPacket: '${packet.stdout}',
+      ),
+    );
+  }
+
+  /// `Fixed False False` as printed for the internet connection profile:
+  /// the cost type, whether roaming, and whether over the data limit.
+  static NetworkStatus? parseWindowsCost(String output) {
+    final fields = output.trim().split(RegExp(r'\s+'));
+    if (fields.length < 2) return null;
+    bool flag(int i) => i < fields.length && fields[i] == 'True';
+    final type = fields.first;
+    return NetworkStatus(
+      metered: type == 'Fixed' || type == 'Variable' || flag(2),
+      roaming: flag(1),
+    );
+  }
+
+  /// NetworkManager's `Metered` property as printed by busctl, e.g. `u 4`.
+  /// 1 is metered and 3 guessed metered; the rest are not or unknown.
+  static bool parseNetworkManagerMetered(String output) {
+    final value = int.tryParse(output.trim().split(' ').last);
+    return value == 1 || value == 3;
+  }
+
+  /// Whether any modem in `mmcli -K` output is registered while roaming.
+  static bool parseModemRoaming(String output) =>
+      RegExp(r'registration-state\s*:\s*roaming').hasMatch(output);
+
+  /// iPhones share their connection on [iPhoneHotspotPrefix]; Android
+  /// phones say so in their DHCP answer.
+  static bool isPhoneHotspot({
+    required String address,
+    required String dhcpPacket,
+  }) =>
+      address.startsWith(iPhoneHotspotPrefix) ||
+      dhcpPacket.contains('ANDROID_METERED');
+}
@@ -3,6 +3,8 @@ import 'package:flutter/foundation.dart';
 import 'package:path_provider/path_provider.dart';
 import 'package:path/path.dart' as p;
 
+import 'backup_exclusion.dart';
+
 class PlatformInfo {
   PlatformInfo._();
 
@@ -17,6 +19,9 @@ class PlatformInfo {
   static bool get isWindows => !kIsWeb && Platform.isWindows;
   static bool get isLinux => !kIsWeb && Platform.isLinux;
 
+  /// Name of this device, as used in conflict copy names.
+  static String get deviceName => kIsWeb ? 'web' : Platform.localHostname;
+
   static String get platformName {
     if (kIsWeb) return 'web';
     if (Platform.isAndroid) return 'android';
@@ -55,6 +60,7 @@ class FileSystem {
     if (!dir.existsSync()) {
       await dir.create(recursive: true);
     }
+    await BackupExclusion.mark(dir.path);
     return dir.path;
   }
 
@@ -64,6 +70,17 @@ class FileSystem {
     if (!dir.existsSync()) {
       await dir.create(recursive: true);
     }
+    await BackupExclusion.mark(dir.path);
+    return dir.path;
+  }
+
+  /// Local mirrors of public share links added by the user.
+  static Future<String> get linkedSharesDir async {
+    final downloads = await downloadDir;
+    final dir = Directory(p.join(downloads, 'OxiCloud Shares'));
+    if (!dir.existsSync()) {
+      await dir.create(recursive: true);
+    }
     return dir.path;
   }
 
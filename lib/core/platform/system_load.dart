@@ -0,0 +1,82 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as p;
+
+import 'platform_info.dart';
+
+/// How busy the machine is: the one-minute load average per CPU core, and
+/// whether it is running hot enough to slow itself down.
+class SystemLoad {
+  final double cpuLoad;
+  final bool thermalPressure;
+
+  const SystemLoad({required this.cpuLoad, this.thermalPressure = false});
+}
+
+/// Reads [SystemLoad] from the operating system. Load averages come from
+/// `/proc/loadavg` (Linux) or `sysctl` (macOS); thermal pressure from the
+/// thermal zones (Linux) or `pmset` (macOS). Other platforms report
+/// nothing.
+class SystemLoadProbe {
+  /// Thermal zones at or above this temperature count as hot.
+  static const hotCelsius = 85;
+
+  Future<SystemLoad?> read() async {
+    try {
+      if (PlatformInfo.isLinux) return await _readLinux();
+      if (PlatformInfo.isMacOS) return await _readMacOS();
+    } on Exception {
+      return null;
+    }
+    return null;
+  }
+
+  Future<SystemLoad?> _readLinux() async {
+    final load = parseLoadAverage(
+      await File('/proc/loadavg').readAsString(),
+    );
+    if (load == null) return null;
+
+    var hot = false;
+    final zones = Directory('/sys/class/thermal');
+    if (await zones.exists()) {
+      await for (final zone in zones.list()) {
+        if (!p.basename(zone.path).startsWith('thermal_zone')) continue;
+        final temp = File(p.join(zone.path, 'temp'));
+        if (!await temp.exists()) continue;
+        final milli = int.tryParse((await temp.readAsString()).trim());
+        if (milli != null && milli >= hotCelsius * 1000) hot = true;
+      }
+    }
+    return SystemLoad(cpuLoad: _perCore(load), thermalPressure: hot);
+  }
+
+  Future<SystemLoad?> _readMacOS() async {
+    final sysctl = await Process.run('sysctl', ['-n', 'vm.loadavg']);
+    final load = parseLoadAverage(
+      '${sysctl.stdout}'.replaceAll(RegExp('[{}]'), ''),
+    );
+    if (load == null) return null;
+    final pmset = await Process.run('pmset', ['-g', 'therm']);
+    return SystemLoad(
+      cpuLoad: _perCore(load),
+      thermalPressure: parseSpeedLimit('${pmset.stdout}') < 100,
+    );
+  }
+
+  static double _perCore(double load) => load / Platform.numberOfProcessors;
+
+  /// The one-minute figure of a load average line such as
+  /// `0.52 0.58 0.59 1/467 12345`.
+  static double? parseLoadAverage(String line) {
+    final fields = line.trim().split(RegExp(r'\s+'));
+    return fields.isEmpty ? null : double.tryParse(fields.first);
+  }
+
+  /// `CPU_Speed_Limit` from `pmset -g therm`; 100 when the CPU is not held
+  /// back or the line is missing.
+  static int parseSpeedLimit(String output) {
+    final match = RegExp(r'CPU_Speed_Limit\s*=\s*(\d+)').firstMatch(output);
+    return match == null ? 100 : int.parse(match.group(1)!);
+  }
+}
@@ -0,0 +1,77 @@
+import 'dart:io';
+
+import 'package:logger/logger.dart';
+import 'package:path/path.dart' as p;
+import 'package:uuid/uuid.dart';
+
+/// Hands out the temporary names downloads are written to before they are
+/// renamed into place.
+///
+/// Names are hidden, unique per download even when two files of the same
+/// name land in one folder at once, and live next to their target so the
+/// final rename never crosses file systems. Uploads and folder scans skip
+/// them (see [isTempName]).
+class TempFileAllocator {
+  static const prefix = '.oxicloud-tmp-';
+  static const suffix = '.part';
+
+  final Logger _logger = Logger();
+
+  /// A fresh, unused temporary path for a download that ends up at
+  /// [targetPath]. The file is not created.
+  String allocate(String targetPath) {
+    final dir = p.dirname(targetPath);
+    while (true) {
+      final id = const Uuid().v4().replaceAll('-', '').substring(0, 12);
+      final path = p.join(dir, '$prefix$id$suffix');
+      if (!File(path).existsSync()) return path;
+    }
+  }
+
+  /// Move a finished download from [partial] into place at [targetPath].
+  ///
+  /// The data is flushed to disk first and the rename replaces any file
+  /// already there, so [targetPath] holds either the old file or the whole
+  /// new one, never a truncated copy.
+  Future<void> commit(String partial, String targetPath) async {
+    final raf = await File(partial).open(mode: FileMode.append);
+    try {
+      await raf.flush();
+    } finally {
+      await raf.close();
+    }
+    await File(partial).rename(targetPath);
+  }
+
+  static bool isTempName(String name) =>
+      name.startsWith(prefix) && name.endsWith(suffix);
+
+  /// Delete temporary files left in [dirs] by downloads that never
+  /// finished, once they are older than [olderThan]. Files in [keep], such
+  /// as downloads journaled to be continued, are left alone. Returns how
+  /// many were removed.
+  Future<int> cleanUp(
+    Iterable<String> dirs, {
+    Duration olderThan = const Duration(hours: 24),
+    Set<String> keep = const {},
+  }) async {
+    final cutoff = DateTime.now().subtract(olderThan);
+    var removed = 0;
+    for (final path in dirs) {
+      final dir = Directory(path);
+      if (!await dir.exists()) continue;
+      await for (final entry in dir.list(followLinks: false)) {
+        if (entry is! File || !isTempName(p.basename(entry.path))) continue;
+        if (keep.contains(entry.path)) continue;
+        try {
+          if ((await entry.lastModified()).isAfter(cutoff)) continue;
+          await entry.delete();
+          removed++;
+        } on FileSystemException catch (e) {
+          _logger.w('Could not remove ${entry.path}', error: e);
+        }
+      }
+    }
+    return removed;
+  }
+}
@@ -0,0 +1,337 @@
+import 'dart:async';
+
+import 'package:drift/native.dart';
+import 'package:logger/logger.dart';
+
+import '../../data/datasources/remote/auth_remote_datasource.dart';
+import '../../data/datasources/remote/file_remote_datasource.dart';
+import '../../data/datasources/remote/folder_remote_datasource.dart';
+import '../../data/dtos/auth/auth_dtos.dart';
+import '../../data/repositories/file_repository_impl.dart';
+import '../../data/repositories/folder_repository_impl.dart';
+import '../../domain/entities/file_entity.dart';
+import '../../domain/repositories/file_repository.dart';
+import '../../domain/repositories/folder_repository.dart';
+import '../database/app_database.dart';
+import '../error/exceptions.dart';
+import '../network/connectivity_service.dart';
+import '../network/server_client_factory.dart';
+
+enum MigrationPhase { counting, copying, verifying, done }
+
+/// How far a migration has got.
+class MigrationProgress {
+  final MigrationPhase phase;
+  final int filesTotal;
+  final int bytesTotal;
+  final int filesCopied;
+  final int filesSkipped;
+
+  /// Bytes of the files copied, or skipped because they were there already.
+  final int bytesDone;
+
+  const MigrationProgress({
+    required this.phase,
+    this.filesTotal = 0,
+    this.bytesTotal = 0,
+    this.filesCopied = 0,
+    this.filesSkipped = 0,
+    this.bytesDone = 0,
+  });
+
+  double? get fraction => phase == MigrationPhase.done
+      ? 1.0
+      : bytesTotal == 0
+      ? null
+      : (bytesDone / bytesTotal).clamp(0.0, 1.0);
+}
+
+/// Outcome of a migration. [mismatches] lists the paths that the final
+/// check found missing or different on the target.
+class MigrationReport {
+  final int filesCopied;
+  final int filesSkipped;
+  final int bytesCopied;
+  final List<String> mismatches;
+
+  const MigrationReport({
+    required this.filesCopied,
+    required this.filesSkipped,
+    required this.bytesCopied,
+    required this.mismatches,
+  });
+
+  bool get verified => mismatches.isEmpty;
+}
+
+/// A second account to migrate to, reached through its own connection.
+///
+/// Its listings go to a scratch in-memory database, so the mirror of the
+/// signed-in account never sees them.
+class MigrationTarget {
+  final FileRepository files;
+  final FolderRepository folders;
+  final Future<void> Function() close;
+
+  const MigrationTarget({
+    required this.files,
+    required this.folders,
+    required this.close,
+  });
+
+  /// Sign in to [serverUrl] as [username] and connect to that account
+  /// with a client from [clients].
+  static Future<MigrationTarget> connect(
+    String serverUrl, {
+    required String username,
+    required String password,
+    required ServerClientFactory clients,
+  }) async {
+    final dio = await clients.create(serverUrl);
+    final auth = await AuthRemoteDatasource(
+      dio,
+    ).login(LoginRequestDto(username: username, password: password));
+    dio.options.headers['Authorization'] = 'Bearer ${auth.accessToken}';
+
+    final db = AppDatabase(NativeDatabase.memory());
+    final connectivity = _Online();
+    final fileRemote = FileRemoteDatasource(dio);
+    return MigrationTarget(
+      files: FileRepositoryImpl(
+        remote: fileRemote,
+        db: db,
+        connectivity: connectivity,
+      ),
+      folders: FolderRepositoryImpl(
+        remote: FolderRemoteDatasource(dio),
+        fileRemote: fileRemote,
+        db: db,
+        connectivity: connectivity,
+      ),
+      close: () async {
+        dio.close();
+        await db.close();
+      },
+    );
+  }
+}
+
+/// The target is only used while the user watches the migration, so it is
+/// taken to be reachable; failed requests surface as errors instead.
+class _Online implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+
+  @override
+  Future<bool> checkConnectivity() async => true;
+
+  @override
+  void dispose() {}
+}
+
+/// A source folder, by its path below the folder being migrated.
+typedef _Dir = ({List<String> path, List<FileEntity> files});
+
+/// Copies a folder tree from the signed-in account to another one.
+///
+/// Accounts on different servers, or different accounts on one server,
+/// share no storage the server could copy within, so every file is
+/// streamed through this client: downloaded and uploaded at once, without
+/// touching the disk.
+///
+/// A migration can be run again to resume one that stopped: folders that
+/// exist on the target are reused, and files already there with the same
+/// size are skipped. A file of the same name but a different size is left
+/// alone and reported. Once everything is copied, the target is listed
+/// again and checked against the source by size, and by hash where both
+/// servers report one.
+class AccountMigrationService {
+  final FileRepository _sourceFiles;
+  final FolderRepository _sourceFolders;
+  final FileRepository _targetFiles;
+  final FolderRepository _targetFolders;
+  final Logger _logger = Logger();
+
+  AccountMigrationService({
+    required FileRepository sourceFiles,
+    required FolderRepository sourceFolders,
+    required FileRepository targetFiles,
+    required FolderRepository targetFolders,
+  }) : _sourceFiles = sourceFiles,
+       _sourceFolders = sourceFolders,
+       _targetFiles = targetFiles,
+       _targetFolders = targetFolders;
+
+  /// Copy [sourceFolderId] (null for the whole account) into
+  /// [targetParentId] on the target (null for its root). A folder keeps
+  /// its name; the whole account lands directly in [targetParentId].
+  /// Completing [stop] aborts the file in flight and ends the migration
+  /// with [RequestCancelledException].
+  Future<MigrationReport> migrate({
+    String? sourceFolderId,
+    String? targetParentId,
+    void Function(MigrationProgress progress)? onProgress,
+    Future<void>? stop,
+  }) async {
+    var stopped = false;
+    unawaited(stop?.then((_) => stopped = true));
+
+    onProgress?.call(const MigrationProgress(phase: MigrationPhase.counting));
+    final dirs = <_Dir>[];
+    await _walk(sourceFolderId, const [], dirs);
+    final filesTotal = dirs.fold(0, (n, d) => n + d.files.length);
+    final bytesTotal = dirs.fold(
+      0,
+      (n, d) => n + d.files.fold(0, (m, f) => m + f.size),
+    );
+
+    var copied = 0;
+    var skipped = 0;
+    var bytes = 0;
+    var done = 0;
+    void report(MigrationPhase phase, {int inFlight = 0}) => onProgress?.call(
+      MigrationProgress(
+        phase: phase,
+        filesTotal: filesTotal,
+        bytesTotal: bytesTotal,
+        filesCopied: copied,
+        filesSkipped: skipped,
+        bytesDone: done + inFlight,
+      ),
+    );
+
+    var root = targetParentId;
+    if (sourceFolderId != null) {
+      final name = (await _sourceFolders.getFolder(sourceFolderId)).name;
+      root = await _targetFolder(targetParentId, name);
+    }
+    final targets = <String, String?>{'': root};
+    final mismatches = <String>[];
+
+    report(MigrationPhase.copying);
+    for (final dir in dirs) {
+      if (stopped) throw const RequestCancelledException('Migration stopped');
+      final target = dir.path.isEmpty
+          ? root
+          : await _targetFolder(
+              targets[dir.path.sublist(0, dir.path.length - 1).join('/')],
+              dir.path.last,
+            );
+      targets[dir.path.join('/')] = target;
+
+      final existing = {
+        for (final f in (await _list(_targetFolders, target)).files) f.name: f,
+      };
+      for (final file in dir.files) {
+        if (stopped) {
+          throw const RequestCancelledException('Migration stopped');
+        }
+        final there = existing[file.name];
+        if (there != null) {
+          if (there.size != file.size) {
+            mismatches.add(_pathOf(dir, file));
+          }
+          skipped++;
+          done += file.size;
+        } else {
+          try {
+            await _targetFiles.uploadFile(
+              name: file.name,
+              folderId: target,
+              fileStream: await _sourceFiles.downloadFile(file.id),
+              fileSize: file.size,
+              mimeType: file.mimeType,
+              onProgress: (sent, _) =>
+                  report(MigrationPhase.copying, inFlight: sent),
+              stop: stop,
+            );
+          } catch (_) {
+            if (stopped) {
+              throw const RequestCancelledException('Migration stopped');
+            }
+            rethrow;
+          }
+          copied++;
+          bytes += file.size;
+          done += file.size;
+        }
+        report(MigrationPhase.copying);
+      }
+    }
+
+    report(MigrationPhase.verifying);
+    for (final dir in dirs) {
+      final listing = await _list(_targetFolders, targets[dir.path.join('/')]);
+      final found = {for (final f in listing.files) f.name: f};
+      for (final file in dir.files) {
+        final path = _pathOf(dir, file);
+        if (mismatches.contains(path)) continue;
+        final there = found[file.name];
+        if (there == null ||
+            there.size != file.size ||
+            (there.hash != null &&
+                file.hash != null &&
+                there.hash != file.hash)) {
+          mismatches.add(path);
+        }
+      }
+    }
+    if (mismatches.isNotEmpty) {
+      _logger.w('Migration check failed for ${mismatches.length} files');
+    }
+
+    report(MigrationPhase.done);
+    return MigrationReport(
+      filesCopied: copied,
+      filesSkipped: skipped,
+      bytesCopied: bytes,
+      mismatches: mismatches,
+    );
+  }
+
+  /// List [folderId] and everything below it on the source, parents
+  /// before their children.
+  Future<void> _walk(
+    String? folderId,
+    List<String> path,
+    List<_Dir> dirs,
+  ) async {
+    final contents = await _list(_sourceFolders, folderId);
+    dirs.add((path: path, files: contents.files));
+    for (final folder in contents.folders) {
+      await _walk(folder.id, [...path, folder.name], dirs);
+    }
+  }
+
+  /// The id of the folder [name] in [parentId] on the target, created if
+  /// it does not exist yet.
+  Future<String> _targetFolder(String? parentId, String name) async {
+    final contents = await _list(_targetFolders, parentId);
+    for (final f in contents.folders) {
+      if (f.name == name) return f.id;
+    }
+    return (await _targetFolders.createFolder(
+      name: name,
+      parentId: parentId,
+    )).id;
+  }
+
+  /// A fresh listing of [folderId], or of the root if it is null. One from
+  /// the local mirror would not show what the server has.
+  static Future<FolderContents> _list(
+    FolderRepository folders,
+    String? folderId,
+  ) async {
+    final contents = folderId == null
+        ? await folders.listRootContents()
+        : await folders.listFolderContents(folderId);
+    if (contents.fromCache) throw const ServerUnreachableException();
+    return contents;
+  }
+
+  static String _pathOf(_Dir dir, FileEntity file) =>
+      [...dir.path, file.name].join('/');
+}
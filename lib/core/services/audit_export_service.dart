@@ -0,0 +1,154 @@
+import 'dart:convert';
+import 'dart:io';
+
+import '../database/app_database.dart';
+
+enum AuditExportFormat { csv, jsonl }
+
+/// One line of the audit export.
+class AuditRecord {
+  final DateTime timestamp;
+  final String? user;
+  final String category;
+  final String action;
+  final String? itemId;
+  final String? itemName;
+  final String outcome;
+  final String? reason;
+  final int? bytes;
+  final String initiator;
+
+  const AuditRecord({
+    required this.timestamp,
+    this.user,
+    required this.category,
+    required this.action,
+    this.itemId,
+    this.itemName,
+    required this.outcome,
+    this.reason,
+    this.bytes,
+    required this.initiator,
+  });
+
+  static const csvHeader = [
+    'timestamp',
+    'user',
+    'category',
+    'action',
+    'item_id',
+    'item_name',
+    'outcome',
+    'reason',
+    'bytes',
+    'initiator',
+  ];
+
+  List<Object?> get csvRow => [
+    timestamp.toUtc().toIso8601String(),
+    user,
+    category,
+    action,
+    itemId,
+    itemName,
+    outcome,
+    reason,
+    bytes,
+    initiator,
+  ];
+
+  Map<String, dynamic> toJson() => {
+    for (var i = 0; i < csvHeader.length; i++)
+      if (csvRow[i] != null) csvHeader[i]: csvRow[i],
+  };
+}
+
+/// Read-only export of every recorded sync decision, transfer and conflict
+/// resolution, for users who need an audit trail.
+class AuditExportService {
+  final AppDatabase _db;
+
+  AuditExportService(this._db);
+
+  /// Write all records between [from] and [to] to [path] and return how many
+  /// were written. The format follows the file extension unless [format] is
+  /// given.
+  Future<int> exportAudit(
+    String path, {
+    DateTime? from,
+    DateTime? to,
+    AuditExportFormat? format,
+  }) async {
+    final records = await collect(from: from, to: to);
+    final fmt =
+        format ??
+        (path.toLowerCase().endsWith('.csv')
+            ? AuditExportFormat.csv
+            : AuditExportFormat.jsonl);
+
+    final sink = File(path).openWrite();
+    try {
+      if (fmt == AuditExportFormat.csv) {
+        sink.writeln(_csvLine(AuditRecord.csvHeader));
+        for (final r in records) {
+          sink.writeln(_csvLine(r.csvRow));
+        }
+      } else {
+        for (final r in records) {
+          sink.writeln(json.encode(r.toJson()));
+        }
+      }
+    } finally {
+      await sink.close();
+    }
+    return records.length;
+  }
+
+  /// Merge the activity log and conflict table into one chronological list.
+  Future<List<AuditRecord>> collect({DateTime? from, DateTime? to}) async {
+    final user = (await _db.getCurrentUser())?.username;
+    final activity = await _db.getActivity(from: from, to: to);
+    final conflicts = await _db.getResolvedConflicts(from: from, to: to);
+
+    final records = <AuditRecord>[
+      for (final a in activity)
+        AuditRecord(
+          timestamp: a.createdAt,
+          user: user,
+          category: a.category,
+          action: a.action,
+          itemId: a.itemId,
+          itemName: a.itemName,
+          outcome: a.outcome,
+          reason: a.reason,
+          bytes: a.bytes,
+          initiator: a.initiator,
+        ),
+      for (final c in conflicts)
+        AuditRecord(
+          timestamp: c.resolvedAt!,
+          user: user,
+          category: 'conflict',
+          action: c.operationType,
+          itemId: c.itemId,
+          outcome: 'resolved',
+          reason: c.resolution,
+          // Resolved before who did it was recorded.
+          initiator: c.resolvedBy ?? 'unknown',
+        ),
+    ];
+    records.sort((a, b) => a.timestamp.compareTo(b.timestamp));
+    return records;
+  }
+
+  String _csvLine(List<Object?> values) => values.map(_csvField).join(',');
+
+  String _csvField(Object? value) {
+    if (value == null) return '';
+    final s = value.toString();
+    if (s.contains(RegExp(r'[",\r\n]'))) {
+      return '"${s.replaceAll('"', '""')}"';
+    }
+    return s;
+  }
+}
@@ -0,0 +1,129 @@
+import 'dart:async';
+import 'dart:math';
+
+import '../../domain/entities/file_entity.dart';
+import '../../domain/repositories/file_repository.dart';
+import '../error/exceptions.dart';
+
+/// Uploads many small files with few requests.
+///
+/// Files up to [maxFileBytes] are grouped into batches of at most
+/// [maxFiles] files and [maxBytes] bytes, each sent as one request. A
+/// server without the batch endpoint gets the files one by one instead,
+/// [pipelineDepth] at a time over the shared keep-alive connections; the
+/// endpoint is not tried again until the app restarts.
+class BulkUploadService {
+  final FileRepository _files;
+  final int maxFileBytes;
+  final int maxFiles;
+  final int maxBytes;
+  final int pipelineDepth;
+
+  /// Status codes meaning the server has no batch upload endpoint.
+  static const _unsupported = {404, 405, 501};
+
+  bool _batches = true;
+
+  BulkUploadService({
+    required FileRepository files,
+    this.maxFileBytes = 1024 * 1024,
+    this.maxFiles = 100,
+    this.maxBytes = 16 * 1024 * 1024,
+    this.pipelineDepth = 6,
+  }) : _files = files;
+
+  /// Whether a file of [size] bytes goes into a batch.
+  bool accepts(int size) => maxFileBytes > 0 && size <= maxFileBytes;
+
+  /// Split [files] into batches, keeping their order.
+  List<List<UploadSource>> plan(List<UploadSource> files) {
+    final batches = <List<UploadSource>>[];
+    var batch = <UploadSource>[];
+    var bytes = 0;
+    for (final file in files) {
+      if (batch.length == maxFiles ||
+          (batch.isNotEmpty && bytes + file.size > maxBytes)) {
+        batches.add(batch);
+        batch = [];
+        bytes = 0;
+      }
+      batch.add(file);
+      bytes += file.size;
+    }
+    if (batch.isNotEmpty) batches.add(batch);
+    return batches;
+  }
+
+  /// Upload one batch from [plan] into [folderId]. Files that fail are
+  /// listed with the reason; a failure of the whole request throws.
+  Future<BatchUploadResult> upload(
+    List<UploadSource> files, {
+    required String? folderId,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  }) async {
+    if (_batches) {
+      try {
+        return await _files.uploadFiles(
+          folderId: folderId,
+          files: files,
+          onProgress: onProgress,
+          stop: stop,
+        );
+      } on ServerException catch (e) {
+        if (!_unsupported.contains(e.statusCode)) rethrow;
+        _batches = false;
+      }
+    }
+    return _pipeline(files, folderId, onProgress, stop);
+  }
+
+  Future<BatchUploadResult> _pipeline(
+    List<UploadSource> files,
+    String? folderId,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  ) async {
+    final total = files.fold(0, (sum, f) => sum + f.size);
+    final sent = List.filled(files.length, 0);
+    final uploaded = <FileEntity>[];
+    final failed = <String, String>{};
+    var next = 0;
+
+    Future<void> worker() async {
+      while (next < files.length) {
+        final i = next++;
+        final file = files[i];
+        try {
+          uploaded.add(
+            await _files.uploadFile(
+              name: file.name,
+              folderId: folderId,
+              fileStream: file.open(),
+              fileSize: file.size,
+              mimeType: file.mimeType,
+              onProgress: (bytes, _) {
+                sent[i] = bytes;
+                onProgress?.call(sent.fold(0, (a, b) => a + b), total);
+              },
+              stop: stop,
+            ),
+          );
+        } on RequestCancelledException {
+          rethrow;
+        } on QuotaExceededException {
+          rethrow;
+        } on ServerException catch (e) {
+          failed[file.name] = e.message;
+        } catch (e) {
+          failed[file.name] = '$e';
+        }
+      }
+    }
+
+    await Future.wait([
+      for (var w = 0; w < min(pipelineDepth, files.length); w++) worker(),
+    ]);
+    return (uploaded: uploaded, failed: failed);
+  }
+}
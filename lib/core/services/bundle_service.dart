@@ -0,0 +1,289 @@
+import 'dart:convert';
+import 'dart:io';
+import 'dart:math';
+
+import 'package:crypto/crypto.dart';
+import 'package:path/path.dart' as p;
+
+import '../../domain/entities/file_entity.dart';
+import '../../domain/entities/folder_entity.dart';
+import '../../domain/repositories/file_repository.dart';
+import '../../domain/repositories/folder_repository.dart';
+import '../error/exceptions.dart';
+import '../platform/temp_files.dart';
+
+/// What a bundle holds: the original file's name and size, and the SHA-256
+/// of each of its segments. A null hash marks a segment of zeros, which is
+/// not stored at all.
+class BundleManifest {
+  static const format = 1;
+
+  final String name;
+  final int size;
+  final int segmentSize;
+  final List<String?> segments;
+
+  const BundleManifest({
+    required this.name,
+    required this.size,
+    required this.segmentSize,
+    required this.segments,
+  });
+
+  String encode() => json.encode({
+    'format': format,
+    'name': name,
+    'size': size,
+    'segment_size': segmentSize,
+    'segments': segments,
+  });
+
+  factory BundleManifest.decode(String raw) {
+    final map = json.decode(raw) as Map<String, dynamic>;
+    if (map['format'] != format) {
+      throw BundleCorruptException('Unknown bundle format ${map['format']}');
+    }
+    return BundleManifest(
+      name: map['name'] as String,
+      size: map['size'] as int,
+      segmentSize: map['segment_size'] as int,
+      segments: (map['segments'] as List<dynamic>).cast<String?>(),
+    );
+  }
+}
+
+/// Stores very large files, such as virtual machine disk images, as a
+/// folder of fixed-size segments plus a manifest.
+///
+/// Uploading a changed file again only replaces the segments whose content
+/// changed, and segments that are all zeros (the unused parts of a sparse
+/// image) are not stored. Downloading reassembles the file, checking every
+/// segment against the manifest, and leaves the zero segments as holes.
+///
+/// The manifest is removed while segments are replaced and written back
+/// last, so a bundle without one is incomplete and is not downloaded.
+class BundleService {
+  static const folderSuffix = '.oxibundle';
+  static const manifestName = 'manifest.json';
+
+  final FileRepository _files;
+  final FolderRepository _folders;
+  final TempFileAllocator _tempFiles;
+  final int segmentSize;
+
+  BundleService({
+    required FileRepository files,
+    required FolderRepository folders,
+    TempFileAllocator? tempFiles,
+    this.segmentSize = 64 * 1024 * 1024,
+  }) : _files = files,
+       _folders = folders,
+       _tempFiles = tempFiles ?? TempFileAllocator();
+
+  /// Extensions of the disk images that are uploaded as bundles.
+  static const diskImageExtensions = {
+    '.dmg',
+    '.img',
+    '.iso',
+    '.qcow2',
+    '.sparseimage',
+    '.vdi',
+    '.vhd',
+    '.vhdx',
+    '.vmdk',
+  };
+
+  static bool isBundle(String folderName) => folderName.endsWith(folderSuffix);
+
+  /// Whether a file named [name] of [size] bytes is uploaded as a bundle
+  /// when bundles start at [minSize] bytes; 0 turns them off.
+  static bool shouldBundle(String name, int size, {required int minSize}) =>
+      minSize > 0 &&
+      size >= minSize &&
+      diskImageExtensions.contains(p.extension(name).toLowerCase());
+
+  /// The name of the file a bundle folder holds.
+  static String fileNameOf(String folderName) =>
+      folderName.substring(0, folderName.length - folderSuffix.length);
+
+  static String segmentName(int index) =>
+      'segment-${index.toString().padLeft(6, '0')}';
+
+  static int? _segmentIndex(String name) => name.startsWith('segment-')
+      ? int.tryParse(name.substring('segment-'.length))
+      : null;
+
+  /// Upload [file] as a bundle into [parentId] (null for the root),
+  /// updating the bundle already there if there is one. Returns the bundle
+  /// folder and how many segments were uploaded.
+  Future<({FolderEntity folder, int uploaded})> upload(
+    File file, {
+    String? parentId,
+  }) async {
+    final name = p.basename(file.path);
+    final size = await file.length();
+    final hashes = <String?>[];
+    for (var start = 0; start < size; start += segmentSize) {
+      hashes.add(await _digest(file, start, min(start + segmentSize, size)));
+    }
+
+    final parent = parentId == null
+        ? await _folders.listRootContents()
+        : await _folders.listFolderContents(parentId);
+    final bundleName = '$name$folderSuffix';
+    var folder = parent.folders.where((f) => f.name == bundleName).firstOrNull;
+    final stored = <String, FileEntity>{};
+    BundleManifest? old;
+    if (folder == null) {
+      folder = await _folders.createFolder(
+        name: bundleName,
+        parentId: parentId,
+      );
+    } else {
+      final contents = await _folders.listFolderContents(folder.id);
+      stored.addAll({for (final f in contents.files) f.name: f});
+      final manifest = stored.remove(manifestName);
+      if (manifest != null) {
+        try {
+          old = await _readManifest(manifest.id);
+        } on BundleCorruptException {
+          // Replace every segment.
+        }
+        await _files.deleteFile(manifest.id);
+      }
+    }
+
+    var uploaded = 0;
+    for (var i = 0; i < hashes.length; i++) {
+      final segment = stored.remove(segmentName(i));
+      final unchanged =
+          old != null &&
+          old.segmentSize == segmentSize &&
+          i < old.segments.length &&
+          old.segments[i] == hashes[i] &&
+          (segment != null) == (hashes[i] != null);
+      if (unchanged) continue;
+      if (segment != null) await _files.deleteFile(segment.id);
+      if (hashes[i] == null) continue;
+      final start = i * segmentSize;
+      final end = min(start + segmentSize, size);
+      await _files.uploadFile(
+        name: segmentName(i),
+        folderId: folder.id,
+        fileStream: file.openRead(start, end),
+        fileSize: end - start,
+        mimeType: 'application/octet-stream',
+      );
+      uploaded++;
+    }
+    // Segments past the new end of the file.
+    for (final leftover in stored.values) {
+      if (_segmentIndex(leftover.name) != null) {
+        await _files.deleteFile(leftover.id);
+      }
+    }
+
+    final manifest = utf8.encode(
+      BundleManifest(
+        name: name,
+        size: size,
+        segmentSize: segmentSize,
+        segments: hashes,
+      ).encode(),
+    );
+    await _files.uploadFile(
+      name: manifestName,
+      folderId: folder.id,
+      fileStream: Stream.value(manifest),
+      fileSize: manifest.length,
+      mimeType: 'application/json',
+    );
+    return (folder: folder, uploaded: uploaded);
+  }
+
+  /// Reassemble the bundle in [folderId] at [targetPath], replacing any
+  /// file there once every segment has arrived intact.
+  Future<String> download(String folderId, String targetPath) async {
+    final contents = await _folders.listFolderContents(folderId);
+    final byName = {for (final f in contents.files) f.name: f};
+    final manifestFile = byName[manifestName];
+    if (manifestFile == null) {
+      throw const BundleCorruptException('The bundle has no manifest');
+    }
+    final manifest = await _readManifest(manifestFile.id);
+
+    final partial = _tempFiles.allocate(targetPath);
+    try {
+      final raf = await File(partial).open(mode: FileMode.write);
+      try {
+        for (var i = 0; i < manifest.segments.length; i++) {
+          final expected = manifest.segments[i];
+          if (expected == null) continue;
+          final segment = byName[segmentName(i)];
+          if (segment == null) {
+            throw BundleCorruptException('Segment $i is missing');
+          }
+          await raf.setPosition(i * manifest.segmentSize);
+          final hash = await _hashing((sink) async {
+            await for (final chunk in await _files.downloadFile(segment.id)) {
+              sink.add(chunk);
+              await raf.writeFrom(chunk);
+            }
+          });
+          if (hash != expected) {
+            throw BundleCorruptException('Segment $i does not match');
+          }
+        }
+        // Zero segments at the end still count towards the size.
+        await raf.truncate(manifest.size);
+      } finally {
+        await raf.close();
+      }
+      await _tempFiles.commit(partial, targetPath);
+      return targetPath;
+    } catch (_) {
+      final leftover = File(partial);
+      if (leftover.existsSync()) await leftover.delete();
+      rethrow;
+    }
+  }
+
+  Future<BundleManifest> _readManifest(String fileId) async {
+    final bytes = <int>[];
+    await for (final chunk in await _files.downloadFile(fileId)) {
+      bytes.addAll(chunk);
+    }
+    try {
+      return BundleManifest.decode(utf8.decode(bytes));
+    } on BundleCorruptException {
+      rethrow;
+    } catch (e) {
+      throw BundleCorruptException('Unreadable manifest: $e');
+    }
+  }
+
+  /// The SHA-256 of bytes [start] to [end] of [file], or null if they are
+  /// all zeros.
+  static Future<String?> _digest(File file, int start, int end) async {
+    var zeros = true;
+    final hash = await _hashing((sink) async {
+      await for (final chunk in file.openRead(start, end)) {
+        sink.add(chunk);
+        if (zeros && chunk.any((b) => b != 0)) zeros = false;
+      }
+    });
+    return zeros ? null : hash;
+  }
+
+  static Future<String> _hashing(
+    Future<void> Function(Sink<List<int>> sink) feed,
+  ) async {
+    late Digest digest;
+    final input = sha256.startChunkedConversion(
+      ChunkedConversionSink<Digest>.withCallback((d) => digest = d.single),
+    );
+    await feed(input);
+    input.close();
+    return digest.toString();
+  }
+}
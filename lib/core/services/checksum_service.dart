@@ -0,0 +1,47 @@
+import 'package:logger/logger.dart';
+
+import '../../data/datasources/remote/auth_remote_datasource.dart';
+import '../database/app_database.dart';
+import '../sync/checksum_negotiation.dart';
+
+/// Agrees on a checksum algorithm with the server for the signed-in account
+/// and remembers it on the account's row.
+///
+/// The choice is made the first time it is needed and kept until the
+/// [preferred] algorithm changes; then it is negotiated again on next use.
+class ChecksumService {
+  final AppDatabase _db;
+  final AuthRemoteDatasource _auth;
+  final ChecksumAlgorithm? preferred;
+  final Logger _logger = Logger();
+
+  /// Whether this instance has negotiated with [preferred] already.
+  bool _negotiated = false;
+
+  ChecksumService(this._db, this._auth, {this.preferred});
+
+  /// The algorithm to checksum uploads with, or null to send none.
+  Future<ChecksumAlgorithm?> algorithm() async {
+    final user = await _db.getCurrentUser();
+    if (user == null) return null;
+    final stored = user.checksumAlgorithm;
+    if (stored != null &&
+        (_negotiated || preferred == null || stored == preferred!.wireName)) {
+      return ChecksumAlgorithm.tryParse(stored);
+    }
+    try {
+      final status = await _auth.getStatus();
+      final chosen = ChecksumNegotiator.negotiate(
+        status.checksumAlgorithms,
+        preferred: preferred,
+      );
+      await _db.setChecksumAlgorithm(user.id, chosen?.wireName ?? '');
+      _negotiated = true;
+      return chosen;
+    } catch (e) {
+      // Uploads go ahead unchecked rather than wait for the server.
+      _logger.w('Could not negotiate a checksum algorithm', error: e);
+      return ChecksumAlgorithm.tryParse(stored);
+    }
+  }
+}
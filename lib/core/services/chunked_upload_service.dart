@@ -2,21 +2,62 @@ import 'dart:io';
 import 'dart:math';
 import 'dart:typed_data';
 
+import 'package:drift/drift.dart' show Value;
 import 'package:flutter/foundation.dart';
 
 import '../../data/datasources/remote/chunked_upload_datasource.dart';
+import '../database/app_database.dart';
+import '../error/error_handler.dart';
+import '../error/exceptions.dart';
+import '../sync/checksum_negotiation.dart';
+import 'checksum_service.dart';
 
 /// High-level service that splits large files into chunks and manages the upload.
 class ChunkedUploadService {
   final ChunkedUploadDatasource _datasource;
 
+  /// Journal of open sessions; when set, interrupted uploads can be resumed.
+  final AppDatabase? _db;
+
+  /// Picks the checksum sent with each chunk; without it none is sent.
+  final ChecksumService? _checksums;
+
   /// Files above this size will use chunked upload (10 MB).
   static const int chunkThreshold = 10 * 1024 * 1024;
 
   /// Default chunk size (5 MB).
   static const int defaultChunkSize = 5 * 1024 * 1024;
 
-  ChunkedUploadService(this._datasource);
+  /// Chunk size asked for when a session is opened.
+  final int chunkSize;
+
+  /// Delays before each new try of a chunk the connection dropped. After
+  /// the last, the upload stops and is left to be resumed.
+  final List<Duration> retryDelays;
+
+  bool _stopRequested = false;
+  final Set<String> _active = {};
+
+  ChunkedUploadService(
+    this._datasource, {
+    AppDatabase? db,
+    ChecksumService? checksums,
+    this.chunkSize = defaultChunkSize,
+    this.retryDelays = const [
+      Duration(seconds: 2),
+      Duration(seconds: 10),
+      Duration(seconds: 30),
+    ],
+  }) : _db = db,
+       _checksums = checksums;
+
+  /// Ask running uploads to stop after the chunk currently in flight.
+  /// Interrupted uploads throw [UploadInterruptedException] and keep their
+  /// server session so they can be resumed later.
+  void stop() => _stopRequested = true;
+
+  /// Whether [uploadId] is being uploaded by this process right now.
+  bool isActive(String uploadId) => _active.contains(uploadId);
 
   /// Upload a large file in chunks.
   /// [onProgress] reports 0.0–1.0 progress.
@@ -27,7 +68,12 @@ class ChunkedUploadService {
     String? contentType,
     ValueChanged<double>? onProgress,
   }) async {
-    final totalSize = await file.length();
+    if (_stopRequested) {
+      throw StateError('Upload service is shutting down');
+    }
+    final stat = await file.stat();
+    final totalSize = stat.size;
+    final algorithm = await _checksums?.algorithm();
 
     // 1. Create session
     final session = await _datasource.createSession(
@@ -35,28 +81,96 @@ class ChunkedUploadService {
       folderId: folderId,
       contentType: contentType,
       totalSize: totalSize,
-      chunkSize: defaultChunkSize,
+      chunkSize: chunkSize,
     );
 
-    try {
-      final chunkSize = session.chunkSize;
-      final totalChunks = session.totalChunks;
+    final now = DateTime.now();
+    await _db?.upsertUploadSession(
+      UploadSessionsTableCompanion.insert(
+        uploadId: session.uploadId,
+        localPath: file.path,
+        filename: filename,
+        folderId: Value(folderId),
+        contentType: Value(contentType),
+        totalSize: totalSize,
+        localModifiedAt: Value(stat.modified),
+        chunkSize: session.chunkSize,
+        totalChunks: session.totalChunks,
+        checksumAlgorithm: Value(algorithm?.wireName),
+        createdAt: now,
+        updatedAt: now,
+      ),
+    );
+
+    return _run(
+      file: file,
+      uploadId: session.uploadId,
+      totalSize: totalSize,
+      chunkSize: session.chunkSize,
+      totalChunks: session.totalChunks,
+      algorithm: algorithm,
+      onProgress: onProgress,
+    );
+  }
 
+  /// Continue a journaled upload from the first chunk the server is missing.
+  Future<ChunkedUploadResult> resume(
+    UploadSessionsTableData entry, {
+    ValueChanged<double>? onProgress,
+  }) async {
+    if (_stopRequested) {
+      throw StateError('Upload service is shutting down');
+    }
+    final status = await _datasource.getStatus(entry.uploadId);
+    return _run(
+      file: File(entry.localPath),
+      uploadId: entry.uploadId,
+      totalSize: entry.totalSize,
+      chunkSize: entry.chunkSize,
+      totalChunks: entry.totalChunks,
+      firstChunk: status.chunksComplete,
+      algorithm: ChecksumAlgorithm.tryParse(entry.checksumAlgorithm),
+      onProgress: onProgress,
+    );
+  }
+
+  Future<ChunkedUploadResult> _run({
+    required File file,
+    required String uploadId,
+    required int totalSize,
+    required int chunkSize,
+    required int totalChunks,
+    int firstChunk = 0,
+    ChecksumAlgorithm? algorithm,
+    ValueChanged<double>? onProgress,
+  }) async {
+    _active.add(uploadId);
+    try {
       // 2. Upload each chunk
       final raf = await file.open();
       try {
-        for (var i = 0; i < totalChunks; i++) {
+        for (var i = firstChunk; i < totalChunks; i++) {
+          if (_stopRequested) {
+            throw UploadInterruptedException(uploadId, i);
+          }
           final offset = i * chunkSize;
           final length = min(chunkSize, totalSize - offset);
 
           await raf.setPosition(offset);
           final bytes = await raf.read(length);
 
-          await _datasource.uploadChunk(
-            uploadId: session.uploadId,
-            chunkIndex: i,
-            data: Uint8List.fromList(bytes),
+          await _retrying(
+            uploadId,
+            i,
+            () => _datasource.uploadChunk(
+              uploadId: uploadId,
+              chunkIndex: i,
+              data: Uint8List.fromList(bytes),
+              checksum: algorithm?.digest(bytes),
+              checksumAlgorithm: algorithm?.wireName,
+            ),
           );
+          await _db?.updateUploadSessionProgress(uploadId, i + 1);
 
           onProgress?.call((i + 1) / totalChunks);
         }
@@ -65,13 +179,53 @@ class ChunkedUploadService {
       }
 
       // 3. Complete
-      return await _datasource.complete(session.uploadId);
+      final result = await _retrying(
+        uploadId,
+        totalChunks,
+        () => _datasource.complete(uploadId),
+      );
+      await _db?.deleteUploadSession(uploadId);
+      await _db?.insertLedgerEntry(
+        TransferLedgerTableCompanion.insert(
+          itemId: result.fileId,
+          itemName: result.filename,
+          size: totalSize,
+          uploadedAt: DateTime.now(),
+        ),
+      );
+      return result;
+    } on UploadInterruptedException {
+      rethrow;
     } catch (e) {
       // Cancel on failure
       try {
-        await _datasource.cancel(session.uploadId);
+        await _datasource.cancel(uploadId);
       } catch (_) {}
+      await _db?.deleteUploadSession(uploadId);
       rethrow;
+    } finally {
+      _active.remove(uploadId);
+    }
+  }
+
+  /// Run [request], trying again after each of [retryDelays] while the
+  /// connection is down. Then gives up with [UploadInterruptedException],
+  /// which keeps the session so the upload can be resumed from [chunk].
+  Future<T> _retrying<T>(
+    String uploadId,
+    int chunk,
+    Future<T> Function() request,
+  ) async {
+    for (var attempt = 0; ; attempt++) {
+      try {
+        return await request();
+      } on ServerException catch (e) {
+        if (!ErrorHandler.isConnectionError(e)) rethrow;
+        if (attempt == retryDelays.length || _stopRequested) {
+          throw UploadInterruptedException(uploadId, chunk);
+        }
+      }
+      await Future<void>.delayed(retryDelays[attempt]);
     }
   }
 }
@@ -0,0 +1,137 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+import 'dart:math';
+
+import 'package:logger/logger.dart';
+
+/// A command's answer from the running instance.
+class ControlReply {
+  final bool ok;
+  final String message;
+
+  const ControlReply(this.ok, this.message);
+}
+
+/// Lets a later launch of the app, such as `oxicloud --status`, ask the
+/// instance already running instead of opening the database it holds.
+///
+/// The running instance listens on a loopback port and writes the port
+/// and a random token to [infoPath]; only a process that can read that
+/// file can send commands. Each connection carries one JSON request,
+/// then one JSON reply; each side closes its end when done writing.
+class ControlChannel {
+  final String infoPath;
+
+  /// Handlers by command name. Each returns the message to reply with.
+  final Map<String, Future<String> Function()> commands;
+
+  final Logger _logger = Logger();
+
+  ServerSocket? _server;
+  String? _token;
+
+  ControlChannel({required this.infoPath, required this.commands});
+
+  /// Command-line switches, as `--<command>`.
+  static const switches = ['status', 'sync'];
+
+  /// The command [args] ask for, if any.
+  static String? commandIn(List<String> args) {
+    for (final arg in args) {
+      if (arg.startsWith('--') && switches.contains(arg.substring(2))) {
+        return arg.substring(2);
+      }
+    }
+    return null;
+  }
+
+  Future<void> start() async {
+    if (_server != null) return;
+    final server = await ServerSocket.bind(InternetAddress.loopbackIPv4, 0);
+    final random = Random.secure();
+    final token = [
+      for (var i = 0; i < 16; i++) random.nextInt(256),
+    ].map((b) => b.toRadixString(16).padLeft(2, '0')).join();
+    _server = server;
+    _token = token;
+    server.listen(_serve);
+    // Restrict the file before the token goes in.
+    final info = File(infoPath);
+    await info.writeAsString('');
+    if (!Platform.isWindows) {
+      await Process.run('chmod', ['600', infoPath]);
+    }
+    await info.writeAsString(
+      json.encode({'port': server.port, 'token': token, 'pid': pid}),
+    );
+  }
+
+  Future<void> stop() async {
+    final server = _server;
+    if (server == null) return;
+    _server = null;
+    await server.close();
+    try {
+      await File(infoPath).delete();
+    } on FileSystemException {
+      // Already gone.
+    }
+  }
+
+  Future<void> _serve(Socket client) async {
+    try {
+      final body = await utf8.decoder
+          .bind(client)
+          .join()
+          .timeout(const Duration(seconds: 5));
+      final request = json.decode(body) as Map<String, dynamic>;
+      final ControlReply reply;
+      if (request['token'] != _token) {
+        reply = const ControlReply(false, 'Not allowed');
+      } else if (commands[request['command']] case final handler?) {
+        reply = ControlReply(true, await handler());
+      } else {
+        reply = ControlReply(false, 'Unknown command ${request['command']}');
+      }
+      client.write(json.encode({'ok': reply.ok, 'message': reply.message}));
+      await client.close();
+    } catch (e) {
+      _logger.w('Control request failed', error: e);
+      client.destroy();
+    }
+  }
+
+  /// Send [command] to the instance whose details are in [infoPath]. Null
+  /// if no instance is listening.
+  static Future<ControlReply?> send(
+    String infoPath,
+    String command, {
+    Duration timeout = const Duration(seconds: 10),
+  }) async {
+    final Map<String, dynamic> info;
+    final Socket socket;
+    try {
+      info =
+          json.decode(await File(infoPath).readAsString())
+              as Map<String, dynamic>;
+      socket = await Socket.connect(
+        InternetAddress.loopbackIPv4,
+        info['port'] as int,
+        timeout: timeout,
+      );
+    } on Exception {
+      return null;
+    }
+    try {
+      socket.write(json.encode({'token': info['token'], 'command': command}));
+      final body = utf8.decoder.bind(socket).join();
+      await socket.close();
+      final reply =
+          json.decode(await body.timeout(timeout)) as Map<String, dynamic>;
+      return ControlReply(reply['ok'] as bool, reply['message'] as String);
+    } finally {
+      socket.destroy();
+    }
+  }
+}
@@ -0,0 +1,170 @@
+import 'dart:io';
+
+import 'package:dio/dio.dart';
+
+import '../network/api_endpoints.dart';
+
+enum DiagnosticKind {
+  dnsFailure,
+  authRejected,
+  clockSkew,
+  watchLimit,
+  diskFull,
+}
+
+/// Something [DiagnosticsService] found wrong, with the specifics.
+class DiagnosticFinding {
+  final DiagnosticKind kind;
+  final String detail;
+
+  const DiagnosticFinding(this.kind, this.detail);
+}
+
+/// Looks for the usual reasons the app stops syncing: a server name that
+/// does not resolve, a session the server keeps rejecting, a local clock
+/// far from the server's, a low file-watch limit (Linux) and a nearly
+/// full disk.
+class DiagnosticsService {
+  final Dio _dio;
+  final String serverUrl;
+  final Future<bool> Function() _signedIn;
+  final Future<List<String>> Function() _dataDirs;
+
+  static const maxClockSkew = Duration(minutes: 2);
+  static const minWatches = 65536;
+  static const minFreeBytes = 500 * 1024 * 1024;
+
+  DiagnosticsService({
+    required Dio dio,
+    required this.serverUrl,
+    required Future<bool> Function() signedIn,
+    required Future<List<String>> Function() dataDirs,
+  }) : _dio = dio,
+       _signedIn = signedIn,
+       _dataDirs = dataDirs;
+
+  Future<List<DiagnosticFinding>> run() async {
+    final dns = await _checkDns();
+    final watches = await _checkWatchLimit();
+    return [
+      if (dns != null) dns else ...await _checkServer(),
+      if (watches != null) watches,
+      ...await _checkDisks(),
+    ];
+  }
+
+  Future<DiagnosticFinding?> _checkDns() async {
+    final host = Uri.tryParse(serverUrl)?.host ?? '';
+    if (host.isEmpty || InternetAddress.tryParse(host) != null) return null;
+    try {
+      await InternetAddress.lookup(host);
+      return null;
+    } on SocketException catch (e) {
+      return DiagnosticFinding(
+        DiagnosticKind.dnsFailure,
+        'Could not look up $host: ${e.osError?.message ?? e.message}',
+      );
+    }
+  }
+
+  Future<List<DiagnosticFinding>> _checkServer() async {
+    final findings = <DiagnosticFinding>[];
+    try {
+      final response = await _dio.get(ApiEndpoints.authStatus);
+      final date = response.headers.value(HttpHeaders.dateHeader);
+      if (date != null) {
+        final skew = checkClockSkew(HttpDate.parse(date), DateTime.now());
+        if (skew != null) findings.add(skew);
+      }
+    } catch (_) {
+      // Unreachable servers are reported by the sync status already.
+    }
+    if (await _signedIn()) {
+      try {
+        await _dio.get(ApiEndpoints.me);
+      } on DioException catch (e) {
+        if (e.response?.statusCode == 401) {
+          findings.add(
+            const DiagnosticFinding(
+              DiagnosticKind.authRejected,
+              'The server rejects the saved session.',
+            ),
+          );
+        }
+      }
+    }
+    return findings;
+  }
+
+  Future<DiagnosticFinding?> _checkWatchLimit() async {
+    if (!Platform.isLinux) return null;
+    try {
+      final raw = await File(
+        '/proc/sys/fs/inotify/max_user_watches',
+      ).readAsString();
+      final limit = int.tryParse(raw.trim());
+      return limit == null ? null : checkWatchLimit(limit);
+    } on FileSystemException {
+      return null;
+    }
+  }
+
+  Future<List<DiagnosticFinding>> _checkDisks() async {
+    if (Platform.isWindows) return const [];
+    final findings = <DiagnosticFinding>[];
+    for (final dir in await _dataDirs()) {
+      try {
+        final result = await Process.run('df', ['-Pk', dir]);
+        final free = parseDfAvailable('${result.stdout}');
+        final finding = free == null ? null : checkFreeSpace(dir, free);
+        if (finding != null) findings.add(finding);
+      } on ProcessException {
+        return findings;
+      }
+    }
+    return findings;
+  }
+
+  static DiagnosticFinding? checkClockSkew(DateTime server, DateTime local) {
+    final skew = local.difference(server).abs();
+    if (skew <= maxClockSkew) return null;
+    final direction = local.isAfter(server) ? 'ahead of' : 'behind';
+    return DiagnosticFinding(
+      DiagnosticKind.clockSkew,
+      "This computer's clock is ${_describe(skew)} $direction the server.",
+    );
+  }
+
+  static DiagnosticFinding? checkWatchLimit(int limit) {
+    if (limit >= minWatches) return null;
+    return DiagnosticFinding(
+      DiagnosticKind.watchLimit,
+      'Only $limit files can be watched for changes at once.',
+    );
+  }
+
+  static DiagnosticFinding? checkFreeSpace(String path, int freeBytes) {
+    if (freeBytes >= minFreeBytes) return null;
+    final mb = freeBytes ~/ (1024 * 1024);
+    return DiagnosticFinding(
+      DiagnosticKind.diskFull,
+      'Only $mb MB free on the disk holding $path.',
+    );
+  }
+
+  /// Free bytes from the output of `df -Pk <path>`.
+  static int? parseDfAvailable(String output) {
+    final lines = output.trim().split('\n');
+    if (lines.length < 2) return null;
+    final columns = lines.last.trim().split(RegExp(r'\s+'));
+    if (columns.length < 4) return null;
+    final kb = int.tryParse(columns[3]);
+    return kb == null ? null : kb * 1024;
+  }
+
+  static String _describe(Duration d) {
+    if (d.inDays > 0) return '${d.inDays} days';
+    if (d.inHours > 0) return '${d.inHours} hours';
+    return '${d.inMinutes} minutes';
+  }
+}
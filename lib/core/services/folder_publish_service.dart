@@ -0,0 +1,206 @@
+import 'dart:io';
+
+import 'package:logger/logger.dart';
+import 'package:mime/mime.dart';
+import 'package:path/path.dart' as p;
+import 'package:uuid/uuid.dart';
+
+import '../../domain/entities/file_entity.dart';
+import '../../domain/entities/folder_entity.dart';
+import '../../domain/repositories/file_repository.dart';
+import '../../domain/repositories/folder_repository.dart';
+import '../error/exceptions.dart';
+import '../sync/checksum_negotiation.dart';
+import '../sync/ignore_rules.dart';
+import '../sync/link_policy.dart';
+import '../sync/sync_filter.dart';
+
+class _Staged {
+  final String localPath;
+  final int size;
+  final String digest;
+  final FileEntity remote;
+
+  _Staged(this.localPath, this.size, this.digest, this.remote);
+}
+
+/// Publishes a local folder all at once: the folder appears on the server
+/// complete or not at all.
+///
+/// Everything is uploaded into a hidden staging folder next to the
+/// destination. Each file is then checked: it must not have changed
+/// locally while it was uploaded, its size on the server must match, and
+/// so must its hash where the server reports one (computed with the
+/// negotiated checksum algorithm). Only then is the staging folder renamed
+/// to its final name, in a single request. On any failure the staging
+/// folder is deleted and [PublishAbortedException] is thrown.
+class FolderPublishService {
+  final FileRepository _files;
+  final FolderRepository _folders;
+  final Future<ChecksumAlgorithm?> Function() _algorithm;
+  final IgnoreRuleCache? _ignore;
+  final SyncFilter filter;
+  final LinkResolver links;
+  final Logger _logger = Logger();
+
+  FolderPublishService({
+    required FileRepository files,
+    required FolderRepository folders,
+    Future<ChecksumAlgorithm?> Function()? algorithm,
+    IgnoreRuleCache? ignore,
+    this.filter = SyncFilter.none,
+    this.links = const LinkResolver(LinkPolicy.skip),
+  }) : _files = files,
+       _folders = folders,
+       _algorithm = algorithm ?? (() async => null),
+       _ignore = ignore;
+
+  /// Publish [localDir] into [parentId] (null for the root) as [name],
+  /// which defaults to the folder's own name. Returns the published
+  /// folder. Paths matched by `.oxicloudignore` files and files the
+  /// [filter] ignores are left out; symbolic links are handled as [links]
+  /// says.
+  Future<FolderEntity> publish(
+    String localDir, {
+    String? parentId,
+    String? name,
+  }) async {
+    final target = name ?? p.basename(localDir);
+    final siblings = parentId == null
+        ? await _folders.listRootContents()
+        : await _folders.listFolderContents(parentId);
+    if (siblings.folders.any((f) => f.name == target) ||
+        siblings.files.any((f) => f.name == target)) {
+      throw PublishAbortedException('"$target" already exists');
+    }
+
+    final algorithm = await _algorithm() ?? ChecksumAlgorithm.sha256;
+    final staging = await _folders.createFolder(
+      name: '.$target.publishing-${const Uuid().v4().substring(0, 8)}',
+      parentId: parentId,
+    );
+    try {
+      final staged = <_Staged>[];
+      await _stageTree(
+        Directory(localDir),
+        staging.id,
+        root: localDir,
+        ancestors: {await Directory(localDir).resolveSymbolicLinks()},
+        algorithm: algorithm,
+        staged: staged,
+      );
+      for (final file in staged) {
+        await _verify(file, algorithm);
+      }
+      return await _folders.renameFolder(staging.id, target);
+    } catch (e) {
+      try {
+        await _folders.deleteFolder(staging.id);
+      } catch (cleanup) {
+        _logger.w('Could not remove ${staging.name}', error: cleanup);
+      }
+      if (e is PublishAbortedException) rethrow;
+      throw PublishAbortedException('Publishing "$target" failed: $e');
+    }
+  }
+
+  Future<void> _stageTree(
+    Directory dir,
+    String folderId, {
+    required String root,
+    required Set<String> ancestors,
+    required ChecksumAlgorithm algorithm,
+    required List<_Staged> staged,
+  }) async {
+    final entries = await dir.list(followLinks: false).toList()
+      ..sort((a, b) => a.path.compareTo(b.path));
+    for (var entry in entries) {
+      if (p.basename(entry.path) == IgnoreRuleCache.fileName) continue;
+      final ignored = await _ignore?.isIgnored(
+        root,
+        entry.path,
+        isDirectory: entry is Directory,
+      );
+      if (ignored ?? false) continue;
+
+      var resolved = entry is Directory
+          ? await entry.resolveSymbolicLinks()
+          : null;
+      if (entry is Link) {
+        final (outcome, target) = await links.resolve(entry, ancestors);
+        switch (outcome) {
+          case LinkOutcome.directory:
+            entry = Directory(entry.path);
+            resolved = target;
+          case LinkOutcome.file:
+            entry = File(entry.path);
+          case LinkOutcome.metadata:
+            final data = LinkResolver.metadataOf(target!);
+            await _files.uploadFile(
+              name: p.basename(entry.path),
+              folderId: folderId,
+              fileStream: Stream.value(data),
+              fileSize: data.length,
+              mimeType: LinkPolicy.mimeType,
+            );
+            continue;
+          default:
+            _logger.i('${entry.path}: ${LinkResolver.reason(outcome)}');
+            continue;
+        }
+      }
+
+      if (entry is Directory) {
+        final sub = await _folders.createFolder(
+          name: p.basename(entry.path),
+          parentId: folderId,
+        );
+        await _stageTree(
+          entry,
+          sub.id,
+          root: root,
+          ancestors: {...ancestors, resolved!},
+          algorithm: algorithm,
+          staged: staged,
+        );
+      } else if (entry is File) {
+        final name = p.basename(entry.path);
+        final size = await entry.length();
+        final mimeType = lookupMimeType(entry.path);
+        if (filter.reasonToIgnore(name, size: size, mimeType: mimeType) !=
+            null) {
+          continue;
+        }
+        final digest = await algorithm.digestStream(entry.openRead());
+        final remote = await _files.uploadFile(
+          name: name,
+          folderId: folderId,
+          fileStream: entry.openRead(),
+          fileSize: size,
+          mimeType: mimeType ?? 'application/octet-stream',
+        );
+        staged.add(_Staged(entry.path, size, digest, remote));
+      }
+    }
+  }
+
+  Future<void> _verify(_Staged file, ChecksumAlgorithm algorithm) async {
+    final name = p.basename(file.localPath);
+    final local = File(file.localPath);
+    if (!await local.exists() ||
+        await local.length() != file.size ||
+        await algorithm.digestStream(local.openRead()) != file.digest) {
+      throw PublishAbortedException('$name changed while it was uploaded');
+    }
+    final remote = await _files.getFile(file.remote.id);
+    if (remote.size != file.size) {
+      throw PublishAbortedException(
+        '$name has ${remote.size} bytes on the server, expected ${file.size}',
+      );
+    }
+    final hash = remote.hash;
+    if (hash != null && hash.isNotEmpty && hash.toLowerCase() != file.digest) {
+      throw PublishAbortedException('$name does not match its checksum');
+    }
+  }
+}
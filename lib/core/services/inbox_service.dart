@@ -0,0 +1,285 @@
+import 'dart:async';
+import 'dart:io';
+
+import 'package:drift/drift.dart';
+import 'package:logger/logger.dart';
+
+import '../../domain/entities/file_entity.dart';
+import '../../domain/repositories/file_repository.dart';
+import '../database/app_database.dart';
+import '../platform/local_names.dart';
+import '../platform/temp_files.dart';
+import '../sync/conflict_resolver.dart';
+import '../sync/sync_events.dart';
+import 'virus_scanner.dart';
+
+/// The user's inbox choices. The inbox is off while [folderId] is unset.
+class InboxSettings {
+  final String? folderId;
+  final String? folderPath;
+
+  /// Arrivals are downloaded here before being sorted, if set.
+  final String? downloadDir;
+
+  /// Command that scans each arrival, see [CommandVirusScanner].
+  final String? scanCommand;
+
+  const InboxSettings({
+    this.folderId,
+    this.folderPath,
+    this.downloadDir,
+    this.scanCommand,
+  });
+
+  bool get isEnabled => folderId?.isNotEmpty ?? false;
+
+  InboxSettings copyWith({
+    String? folderId,
+    String? folderPath,
+    String? downloadDir,
+    String? scanCommand,
+  }) {
+    return InboxSettings(
+      folderId: folderId ?? this.folderId,
+      folderPath: folderPath ?? this.folderPath,
+      downloadDir: downloadDir ?? this.downloadDir,
+      scanCommand: scanCommand ?? this.scanCommand,
+    );
+  }
+}
+
+/// Names of the files one inbox pass handled, by outcome.
+class InboxSummary {
+  final List<String> sorted = [];
+  final List<String> kept = [];
+  final List<String> flagged = [];
+  final List<String> failed = [];
+
+  int get total => sorted.length + kept.length + flagged.length + failed.length;
+
+  bool get isEmpty => total == 0;
+
+  /// "3 files arrived in the inbox: 2 sorted, 1 flagged by the scanner".
+  String describe() {
+    final parts = [
+      if (sorted.isNotEmpty) '${sorted.length} sorted',
+      if (kept.isNotEmpty) '${kept.length} left in the inbox',
+      if (flagged.isNotEmpty) '${flagged.length} flagged by the scanner',
+      if (failed.isNotEmpty) '${failed.length} failed',
+    ];
+    final files = total == 1 ? '1 file' : '$total files';
+    return '$files arrived in the inbox: ${parts.join(', ')}';
+  }
+}
+
+/// Matching of inbox rules against a file name (`*` and `?` wildcards); a
+/// rule without a pattern matches everything. The first rule in position
+/// order wins.
+class InboxRuleMatcher {
+  InboxRuleMatcher._();
+
+  static InboxRulesTableData? firstMatch(
+    List<InboxRulesTableData> rules,
+    String name,
+  ) {
+    for (final rule in rules) {
+      final pattern = rule.namePattern;
+      if (pattern == null ||
+          pattern.isEmpty ||
+          ConflictRuleMatcher.globToRegExp(pattern).hasMatch(name)) {
+        return rule;
+      }
+    }
+    return null;
+  }
+}
+
+/// Sorts files that arrive in a designated remote folder, typically the
+/// target of a file-drop share.
+///
+/// Each new arrival is downloaded if a download folder is set, scanned if
+/// a scan command is set, and moved to the destination of the first
+/// matching inbox rule. Files the scanner flags stay in the inbox, and
+/// their local copy is deleted. Each file is handled once; one that failed
+/// is tried again on the next pass. Passes that handled anything publish
+/// an [InboxArrivals] event.
+class InboxService {
+  final FileRepository _files;
+  final AppDatabase _db;
+  final SyncEventBus? _events;
+  final TempFileAllocator _tempFiles;
+  final VirusScanner Function(String command) _scannerFor;
+  final Duration interval;
+  final Logger _logger = Logger();
+
+  InboxSettings settings = const InboxSettings();
+
+  Timer? _timer;
+  bool _running = false;
+
+  InboxService({
+    required FileRepository files,
+    required AppDatabase db,
+    SyncEventBus? events,
+    TempFileAllocator? tempFiles,
+    VirusScanner Function(String command) scannerFor = CommandVirusScanner.new,
+    this.interval = const Duration(minutes: 5),
+  }) : _files = files,
+       _db = db,
+       _events = events,
+       _tempFiles = tempFiles ?? TempFileAllocator(),
+       _scannerFor = scannerFor;
+
+  /// Run a pass now and then every [interval].
+  void start() {
+    _timer?.cancel();
+    _timer = Timer.periodic(interval, (_) => run());
+    run();
+  }
+
+  void stop() {
+    _timer?.cancel();
+    _timer = null;
+  }
+
+  /// Add a rule after the existing ones.
+  Future<void> addRule({
+    String? namePattern,
+    required String folderId,
+    required String folderPath,
+  }) async {
+    final rules = await _db.getInboxRules();
+    await _db.insertInboxRule(
+      InboxRulesTableCompanion.insert(
+        namePattern: Value(namePattern),
+        destinationFolderId: folderId,
+        destinationPath: folderPath,
+        position: rules.isEmpty ? 0 : rules.last.position + 1,
+        createdAt: DateTime.now(),
+      ),
+    );
+  }
+
+  Future<void> deleteRule(int id) => _db.deleteInboxRule(id);
+
+  Future<InboxSummary> run() async {
+    final summary = InboxSummary();
+    if (_running || !settings.isEnabled) return summary;
+    _running = true;
+
+    try {
+      final files = await _files.listFiles(folderId: settings.folderId);
+      final known = await _db.getInboxItemStatuses(files.map((f) => f.id));
+      final rules = await _db.getInboxRules();
+      for (final file in files) {
+        final previous = known[file.id];
+        if (previous != null && previous != 'failed') continue;
+        await _handle(file, rules, summary, failedBefore: previous != null);
+      }
+    } catch (e) {
+      _logger.w('Inbox pass failed', error: e);
+    } finally {
+      _running = false;
+    }
+
+    if (!summary.isEmpty) {
+      _events?.emit(
+        InboxArrivals(
+          sorted: summary.sorted.length,
+          kept: summary.kept.length,
+          flagged: summary.flagged.length,
+          failed: summary.failed.length,
+          message: summary.describe(),
+        ),
+      );
+    }
+    return summary;
+  }
+
+  Future<void> _handle(
+    FileEntity file,
+    List<InboxRulesTableData> rules,
+    InboxSummary summary, {
+    required bool failedBefore,
+  }) async {
+    final command = settings.scanCommand?.trim() ?? '';
+    final scanner = command.isEmpty ? null : _scannerFor(command);
+    final downloadDir = settings.downloadDir ?? '';
+    Directory? scratch;
+    String? localPath;
+
+    Future<void> record(
+      String status, {
+      String? destination,
+      String? detail,
+    }) {
+      return _db.recordInboxItem(
+        InboxItemsTableCompanion.insert(
+          fileId: file.id,
+          name: file.name,
+          status: status,
+          destinationPath: Value(destination),
+          localPath: Value(downloadDir.isEmpty ? null : localPath),
+          detail: Value(detail),
+          arrivedAt: DateTime.now(),
+        ),
+      );
+    }
+
+    try {
+      if (downloadDir.isNotEmpty) {
+        localPath = await _download(file, downloadDir);
+      } else if (scanner != null) {
+        scratch = await Directory.systemTemp.createTemp('oxicloud-inbox');
+        localPath = await _download(file, scratch.path);
+      }
+
+      final path = localPath;
+      if (scanner != null && !await scanner.isClean(path!)) {
+        await File(path).delete();
+        localPath = null;
+        await record('flagged');
+        summary.flagged.add(file.name);
+        return;
+      }
+
+      final rule = InboxRuleMatcher.firstMatch(rules, file.name);
+      if (rule == null) {
+        await record('kept');
+        summary.kept.add(file.name);
+        return;
+      }
+      await _files.moveFile(file.id, rule.destinationFolderId);
+      await record('sorted', destination: rule.destinationPath);
+      summary.sorted.add(file.name);
+    } catch (e) {
+      _logger.w('Handling inbox file ${file.name} failed', error: e);
+      await record('failed', detail: e.toString());
+      // Only report a failure once, not on every retry.
+      if (!failedBefore) summary.failed.add(file.name);
+    } finally {
+      await scratch?.delete(recursive: true);
+    }
+  }
+
+  /// Download [file] into [dir] without overwriting anything there. The
+  /// name was chosen by whoever dropped the file, so only its last
+  /// segment is used.
+  Future<String> _download(FileEntity file, String dir) async {
+    final name = LocalNames.sanitize(file.name);
+    if (name == null) {
+      throw StateError('The name "${file.name}" cannot be used');
+    }
+    final path = LocalNames.unique(dir, name);
+    final partial = _tempFiles.allocate(path);
+    try {
+      await _files.downloadFileToPath(file.id, partial);
+      await _tempFiles.commit(partial, path);
+    } catch (_) {
+      final leftover = File(partial);
+      if (leftover.existsSync()) await leftover.delete();
+      rethrow;
+    }
+    return path;
+  }
+}
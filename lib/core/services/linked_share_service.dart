@@ -0,0 +1,264 @@
+import 'dart:io';
+
+import 'package:drift/drift.dart';
+import 'package:flutter/foundation.dart';
+import 'package:path/path.dart' as p;
+import 'package:uuid/uuid.dart';
+
+import '../../data/datasources/remote/public_share_remote_datasource.dart';
+import '../auth/secure_storage.dart';
+import '../database/app_database.dart';
+import '../network/connectivity_service.dart';
+import '../network/server_client_factory.dart';
+import '../platform/local_names.dart';
+import '../platform/platform_info.dart';
+import '../platform/temp_files.dart';
+
+enum FolderPairState { idle, syncing, paused, error }
+
+/// Summary of one linked share and its local copy, for the status bar and
+/// the shares page.
+class FolderPairStatus {
+  final LinkedSharesTableData share;
+  final FolderPairState state;
+  final DateTime? lastSyncedAt;
+  final int pendingCount;
+  final String? lastError;
+  final DateTime? lastErrorAt;
+
+  const FolderPairStatus({
+    required this.share,
+    required this.state,
+    this.lastSyncedAt,
+    this.pendingCount = 0,
+    this.lastError,
+    this.lastErrorAt,
+  });
+
+  String get name => share.name;
+}
+
+/// Mirrors public share links from other accounts into a local, read-only
+/// folder. No account on the sharing server is needed; only the link (and
+/// its password, when it has one).
+class LinkedShareService extends ChangeNotifier {
+  final AppDatabase _db;
+  final SecureStorage _secureStorage;
+  final ConnectivityService? _connectivity;
+  final TempFileAllocator _tempFiles;
+
+  /// The folder local copies are kept in.
+  final Future<String> Function() _localDir;
+
+  /// Builds a datasource for the server that hosts a share; by default
+  /// one from [ServerClientFactory].
+  final Future<PublicShareRemoteDatasource> Function(String serverUrl)?
+  _remoteFor;
+
+  final Set<String> _queued = {};
+  final Set<String> _syncing = {};
+  final Map<String, ({String message, DateTime at})> _errors = {};
+
+  LinkedShareService({
+    required AppDatabase db,
+    required SecureStorage secureStorage,
+    ConnectivityService? connectivity,
+    TempFileAllocator? tempFiles,
+    Future<PublicShareRemoteDatasource> Function(String serverUrl)? remoteFor,
+    Future<String> Function()? localDir,
+  }) : _db = db,
+       _secureStorage = secureStorage,
+       _connectivity = connectivity,
+       _tempFiles = tempFiles ?? TempFileAllocator(),
+       _localDir = localDir ?? (() => FileSystem.linkedSharesDir),
+       _remoteFor = remoteFor;
+
+  Future<PublicShareRemoteDatasource> _remote(String serverUrl) async {
+    if (_remoteFor case final remoteFor?) return remoteFor(serverUrl);
+    final dio = await ServerClientFactory(_secureStorage).create(serverUrl);
+    return PublicShareRemoteDatasource(dio);
+  }
+
+  /// Split a link like `https://host/s/<token>` into server and token.
+  static ({String serverUrl, String token})? parseLink(String link) {
+    final uri = Uri.tryParse(link.trim());
+    if (uri == null || !uri.hasScheme || uri.host.isEmpty) return null;
+    final segments = uri.pathSegments;
+    final i = segments.lastIndexOf('s');
+    if (i < 0 || i + 1 >= segments.length) return null;
+    final base = segments.sublist(0, i).join('/');
+    return (
+      serverUrl: '${uri.origin}${base.isEmpty ? '' : '/$base'}',
+      token: segments[i + 1],
+    );
+  }
+
+  Future<List<LinkedSharesTableData>> list() => _db.getLinkedShares();
+
+  /// State, last successful sync, queued work and latest error of every
+  /// linked share, in one call.
+  Future<List<FolderPairStatus>> getFolderPairStatus() async {
+    final offline = _connectivity != null && !_connectivity.isOnline;
+    return [
+      for (final share in await _db.getLinkedShares())
+        FolderPairStatus(
+          share: share,
+          state: _syncing.contains(share.id)
+              ? FolderPairState.syncing
+              : offline
+              ? FolderPairState.paused
+              : _errors.containsKey(share.id)
+              ? FolderPairState.error
+              : FolderPairState.idle,
+          lastSyncedAt: share.lastSyncedAt,
+          pendingCount:
+              (_queued.contains(share.id) ? 1 : 0) +
+              (share.lastSyncedAt == null ? 1 : 0),
+          lastError: _errors[share.id]?.message,
+          lastErrorAt: _errors[share.id]?.at,
+        ),
+    ];
+  }
+
+  /// Refresh every linked share in turn. Failures are recorded per share
+  /// rather than aborting the rest.
+  Future<void> syncAll() async {
+    final shares = await _db.getLinkedShares();
+    _queued.addAll(shares.map((s) => s.id));
+    notifyListeners();
+    for (final share in shares) {
+      _queued.remove(share.id);
+      try {
+        await sync(share);
+      } catch (_) {
+        // Already recorded in _errors.
+      }
+    }
+  }
+
+  /// Validate [link], remember it and pull a first copy.
+  Future<LinkedSharesTableData> add(String link, {String? password}) async {
+    final parsed = parseLink(link);
+    if (parsed == null) {
+      throw ArgumentError.value(link, 'link', 'Not a share link');
+    }
+    final remote = await _remote(parsed.serverUrl);
+    final info = await remote.getShareInfo(parsed.token);
+    if (info.passwordProtected) {
+      if (password == null || password.isEmpty) {
+        throw StateError('This share is password-protected');
+      }
+      if (!await remote.verifyPassword(parsed.token, password)) {
+        throw StateError('Incorrect password');
+      }
+    }
+
+    final id = const Uuid().v4();
+    if (password != null && info.passwordProtected) {
+      await _secureStorage.saveSharePassword(id, password);
+    }
+    await _db.upsertLinkedShare(
+      LinkedSharesTableCompanion.insert(
+        id: id,
+        serverUrl: parsed.serverUrl,
+        token: parsed.token,
+        name: info.name,
+        itemType: info.itemType,
+        passwordProtected: Value(info.passwordProtected),
+        addedAt: DateTime.now(),
+      ),
+    );
+    final shares = await _db.getLinkedShares();
+    return sync(shares.firstWhere((s) => s.id == id));
+  }
+
+  /// Download the current content of [share] over the local copy, which is
+  /// then made read-only. Folder shares arrive as a single archive.
+  Future<LinkedSharesTableData> sync(LinkedSharesTableData share) async {
+    _syncing.add(share.id);
+    notifyListeners();
+    try {
+      final updated = await _sync(share);
+      _errors.remove(share.id);
+      return updated;
+    } catch (e) {
+      _errors[share.id] = (message: e.toString(), at: DateTime.now());
+      rethrow;
+    } finally {
+      _syncing.remove(share.id);
+      notifyListeners();
+    }
+  }
+
+  Future<LinkedSharesTableData> _sync(LinkedSharesTableData share) async {
+    final remote = await _remote(share.serverUrl);
+    if (share.passwordProtected) {
+      final password = await _secureStorage.getSharePassword(share.id);
+      if (password == null ||
+          !await remote.verifyPassword(share.token, password)) {
+        throw StateError('The password for ${share.name} is no longer valid');
+      }
+    }
+
+    final target = await _targetFor(share, await _localDir());
+    final partial = _tempFiles.allocate(target);
+
+    try {
+      await remote.download(share.token, partial);
+    } catch (_) {
+      final leftover = File(partial);
+      if (leftover.existsSync()) await leftover.delete();
+      rethrow;
+    }
+    // A read-only file cannot be replaced on Windows.
+    if (File(target).existsSync()) await _setReadOnly(target, false);
+    await _tempFiles.commit(partial, target);
+    await _setReadOnly(target, true);
+
+    final updated = share.copyWith(
+      localPath: Value(target),
+      lastSyncedAt: Value(DateTime.now()),
+    );
+    await _db.upsertLinkedShare(updated.toCompanion(false));
+    return updated;
+  }
+
+  /// Where the copy of [share] goes in [dir]: where it went before, or
+  /// else a name no other share uses. The name comes from the sharing
+  /// server, so only its last segment is kept.
+  Future<String> _targetFor(LinkedSharesTableData share, String dir) async {
+    final kept = share.localPath;
+    if (kept != null && LocalNames.isInside(dir, kept)) return kept;
+    final name = LocalNames.sanitize(share.name);
+    if (name == null) {
+      throw StateError('The share name "${share.name}" cannot be used');
+    }
+    final others = {
+      for (final other in await _db.getLinkedShares())
+        if (other.id != share.id && other.localPath != null)
+          p.normalize(other.localPath!),
+    };
+    return LocalNames.unique(
+      dir,
+      share.itemType == 'folder' ? '$name.zip' : name,
+      isTaken: (path) =>
+          others.contains(p.normalize(path)) || File(path).existsSync(),
+    );
+  }
+
+  /// Forget [share]. The local copy is left in place.
+  Future<void> remove(LinkedSharesTableData share) async {
+    await _db.deleteLinkedShare(share.id);
+    await _secureStorage.deleteSharePassword(share.id);
+    _errors.remove(share.id);
+    notifyListeners();
+  }
+
+  Future<void> _setReadOnly(String path, bool readOnly) async {
+    if (PlatformInfo.isWindows) {
+      await Process.run('attrib', [readOnly ? '+R' : '-R', path]);
+    } else {
+      await Process.run('chmod', [readOnly ? 'a-w' : 'u+w', path]);
+    }
+  }
+}
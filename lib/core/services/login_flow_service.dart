@@ -0,0 +1,51 @@
+import 'dart:async';
+
+import '../../data/datasources/remote/login_flow_remote_datasource.dart';
+import '../error/error_handler.dart';
+import '../error/exceptions.dart';
+import '../platform/platform_info.dart';
+
+/// Nextcloud's Login Flow v2: the user signs in in their browser, and
+/// the server hands this app a login name and an app password.
+class LoginFlowService {
+  final LoginFlowRemoteDatasource _remote;
+  final Duration pollInterval;
+
+  /// How long to wait for the user; the server drops a flow after 20
+  /// minutes.
+  final Duration timeout;
+
+  LoginFlowService(
+    this._remote, {
+    this.pollInterval = const Duration(seconds: 2),
+    this.timeout = const Duration(minutes: 20),
+  });
+
+  /// Start a flow on [serverUrl]. Open [LoginFlowSession.loginUrl] in the
+  /// browser, then [waitForCredentials].
+  Future<LoginFlowSession> start(String serverUrl) => _remote.start(
+    serverUrl,
+    'OxiCloud ${PlatformInfo.platformName} (${PlatformInfo.deviceName})',
+  );
+
+  /// Poll until the user grants access. Returns null once [cancelled]
+  /// says so, and throws [TimeoutException] when the flow expires.
+  /// Connection errors are waited out.
+  Future<LoginFlowCredentials?> waitForCredentials(
+    LoginFlowSession session, {
+    bool Function()? cancelled,
+  }) async {
+    final deadline = DateTime.now().add(timeout);
+    while (DateTime.now().isBefore(deadline)) {
+      if (cancelled?.call() ?? false) return null;
+      try {
+        final credentials = await _remote.poll(session);
+        if (credentials != null) return credentials;
+      } on ServerException catch (e) {
+        if (!ErrorHandler.isConnectionError(e)) rethrow;
+      }
+      await Future<void>.delayed(pollInterval);
+    }
+    throw TimeoutException('The browser login expired', timeout);
+  }
+}
@@ -0,0 +1,131 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as p;
+
+import '../platform/platform_info.dart';
+
+/// An item a bulk operation gave up on or left out, and why.
+class ReportEntry {
+  final String path;
+  final String reason;
+
+  const ReportEntry(this.path, this.reason);
+}
+
+/// What a bulk operation did to each item, so the user can check afterwards
+/// that nothing went missing.
+class OperationReport {
+  final String title;
+  final DateTime startedAt;
+  DateTime? finishedAt;
+
+  final List<String> completed = [];
+  final List<ReportEntry> failed = [];
+  final List<ReportEntry> skipped = [];
+
+  OperationReport(this.title, {DateTime? startedAt})
+    : startedAt = startedAt ?? DateTime.now();
+
+  int get total => completed.length + failed.length + skipped.length;
+
+  void complete(String path) => completed.add(path);
+
+  void fail(String path, Object reason) =>
+      failed.add(ReportEntry(path, reason.toString()));
+
+  void skip(String path, String reason) =>
+      skipped.add(ReportEntry(path, reason));
+
+  void finish([DateTime? at]) => finishedAt ??= at ?? DateTime.now();
+
+  String toMarkdown() {
+    final out = StringBuffer()
+      ..writeln('# $title')
+      ..writeln()
+      ..writeln('- Started: ${_time(startedAt)}');
+    if (finishedAt != null) {
+      final took = finishedAt!.difference(startedAt);
+      out.writeln('- Finished: ${_time(finishedAt!)} (${_duration(took)})');
+    }
+    out
+      ..writeln('- Completed: ${completed.length}')
+      ..writeln('- Failed: ${failed.length}')
+      ..writeln('- Skipped: ${skipped.length}');
+
+    void section(String heading, List<String> lines) {
+      if (lines.isEmpty) return;
+      out
+        ..writeln()
+        ..writeln('## $heading')
+        ..writeln();
+      lines.forEach(out.writeln);
+    }
+
+    section('Failed', [for (final e in failed) '- `${e.path}`: ${e.reason}']);
+    section('Skipped', [
+      for (final e in skipped) '- `${e.path}`: ${e.reason}',
+    ]);
+    section('Completed', [for (final path in completed) '- `$path`']);
+    return out.toString();
+  }
+
+  static String _time(DateTime t) =>
+      t.toLocal().toIso8601String().split('.').first.replaceFirst('T', ' ');
+
+  static String _duration(Duration d) {
+    if (d.inHours > 0) return '${d.inHours}h ${d.inMinutes % 60}m';
+    if (d.inMinutes > 0) return '${d.inMinutes}m ${d.inSeconds % 60}s';
+    return '${d.inSeconds}s';
+  }
+}
+
+/// Saves [OperationReport]s as Markdown files under the app data directory.
+class OperationReportService {
+  final Future<String> Function() _baseDir;
+
+  /// Operations touching fewer items than this are not worth a report.
+  final int threshold;
+
+  OperationReportService({
+    Future<String> Function()? baseDir,
+    this.threshold = 100,
+  }) : _baseDir = baseDir ?? (() => FileSystem.appDataDir);
+
+  Future<Directory> get _dir async {
+    final dir = Directory(p.join(await _baseDir(), 'reports'));
+    if (!dir.existsSync()) await dir.create(recursive: true);
+    return dir;
+  }
+
+  /// Write [report] to disk and return its path.
+  Future<String> save(OperationReport report) async {
+    report.finish();
+    final stamp = report.startedAt
+        .toIso8601String()
+        .split('.')
+        .first
+        .replaceAll(RegExp('[-:]'), '')
+        .replaceFirst('T', '-');
+    final slug = report.title
+        .toLowerCase()
+        .replaceAll(RegExp('[^a-z0-9]+'), '-')
+        .replaceAll(RegExp(r'^-|-$'), '');
+    final file = File(p.join((await _dir).path, '$stamp-$slug.md'));
+    await file.writeAsString(report.toMarkdown());
+    return file.path;
+  }
+
+  /// Save [report] if it covers at least [threshold] items; returns the
+  /// path, or null when nothing was written.
+  Future<String?> saveIfLarge(OperationReport report) async {
+    if (report.total < threshold) return null;
+    return save(report);
+  }
+
+  /// Saved reports, newest first.
+  Future<List<File>> list() async {
+    final files = (await _dir).listSync().whereType<File>().toList()
+      ..sort((a, b) => b.path.compareTo(a.path));
+    return files;
+  }
+}
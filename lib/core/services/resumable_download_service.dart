@@ -0,0 +1,139 @@
+import 'dart:io';
+
+import '../../domain/repositories/file_repository.dart';
+import '../database/app_database.dart';
+import '../error/error_handler.dart';
+import '../error/exceptions.dart';
+import '../network/dav_multistatus.dart';
+import '../platform/temp_files.dart';
+import '../sync/checksum_negotiation.dart';
+import 'webdav_properties_service.dart';
+
+/// Downloads into temporary files that outlive a lost connection.
+///
+/// A dropped download is continued from the bytes already received with a
+/// range request, after each of [retryDelays]. If the connection stays
+/// down, the temporary file is journaled and the next download of the
+/// same file picks it up, even after a restart. The journaled ETag is the
+/// one the server sent with the bytes, and the rest is only appended while
+/// the server still has that version (sent as `If-Range`); otherwise the
+/// file is downloaded from the start.
+///
+/// With [properties], a finished download is checked against a checksum
+/// the server keeps of the file, when it has one this app can compute.
+class ResumableDownloadService {
+  final AppDatabase _db;
+  final FileRepository _files;
+  final TempFileAllocator _tempFiles;
+  final WebDavPropertiesService? _properties;
+  final List<Duration> retryDelays;
+
+  ResumableDownloadService({
+    required AppDatabase db,
+    required FileRepository files,
+    required TempFileAllocator tempFiles,
+    WebDavPropertiesService? properties,
+    this.retryDelays = const [
+      Duration(seconds: 2),
+      Duration(seconds: 10),
+      Duration(seconds: 30),
+    ],
+  }) : _db = db,
+       _files = files,
+       _tempFiles = tempFiles,
+       _properties = properties;
+
+  /// Where to download [fileId] on its way to [targetPath]: the temporary
+  /// file a cut-off download left, if it is still there, or a new one.
+  Future<String> partialFor(String fileId, String targetPath) async {
+    final kept = await _db.getPartialDownload(fileId);
+    if (kept != null) {
+      if (kept.targetPath == targetPath && File(kept.path).existsSync()) {
+        return kept.path;
+      }
+      await _db.deletePartialDownload(fileId);
+    }
+    return _tempFiles.allocate(targetPath);
+  }
+
+  /// Whether [partial] is journaled to be continued later.
+  Future<bool> isKept(String fileId, String partial) async =>
+      (await _db.getPartialDownload(fileId))?.path == partial;
+
+  /// Download [fileId] into [partial], a path from [partialFor]. With
+  /// [resume], or if [partial] was kept, the bytes already there are
+  /// reused. Throws once the connection has stayed down through every
+  /// retry, keeping [partial], and throws [ChecksumMismatchException] if
+  /// the finished download is not what the server has.
+  Future<void> download(
+    String fileId,
+    String partial,
+    String targetPath, {
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+    bool resume = false,
+  }) async {
+    final kept = await _db.getPartialDownload(fileId);
+    var etag = kept?.path == partial
+        ? kept!.etag
+        : (await _db.getFileById(fileId))?.etag;
+    var continuing = resume || kept?.path == partial;
+    for (var attempt = 0; ; attempt++) {
+      try {
+        await _files.downloadFileToPath(
+          fileId,
+          partial,
+          onProgress: onProgress,
+          stop: stop,
+          resume: continuing,
+          ifRange: etag,
+          onEtag: (value) => etag = value,
+        );
+        await _db.deletePartialDownload(fileId);
+        await _verify(fileId, partial);
+        return;
+      } on ServerException catch (e) {
+        // Without an ETag there is no telling later whether the bytes on
+        // disk still belong to the file, so they are not kept.
+        if (!ErrorHandler.isConnectionError(e) || etag == null) {
+          await _db.deletePartialDownload(fileId);
+          rethrow;
+        }
+        if (attempt == retryDelays.length) {
+          await _db.savePartialDownload(
+            fileId: fileId,
+            path: partial,
+            targetPath: targetPath,
+            etag: etag!,
+          );
+          rethrow;
+        }
+      }
+      await Future<void>.delayed(retryDelays[attempt]);
+      continuing = true;
+    }
+  }
+
+  /// Compare [partial] with a checksum the server has of [fileId]. A
+  /// server that cannot say is taken at its word.
+  Future<void> _verify(String fileId, String partial) async {
+    final properties = _properties;
+    final path = (await _db.getFileById(fileId))?.path;
+    if (properties == null || path == null) return;
+    final DavResource? remote;
+    try {
+      remote = await properties.lookup(path);
+    } on ServerException {
+      return;
+    }
+    final checksums = remote?.checksums ?? const {};
+    for (final MapEntry(:key, :value) in checksums.entries) {
+      final algorithm = ChecksumAlgorithm.tryParse(key);
+      if (algorithm == null) continue;
+      if (await algorithm.digestStream(File(partial).openRead()) != value) {
+        throw ChecksumMismatchException(path);
+      }
+      return;
+    }
+  }
+}
@@ -0,0 +1,68 @@
+import 'dart:async';
+
+import 'package:logger/logger.dart';
+
+import '../database/db_lease_service.dart';
+import '../network/interceptors/timeout_interceptor.dart';
+import '../sync/sync_engine.dart';
+import 'chunked_upload_service.dart';
+
+/// Coordinates an orderly exit so that quitting never aborts a write
+/// half-way through.
+///
+/// Order: stop accepting new work, stop uploads at the next chunk boundary,
+/// let the sync operation in flight finish and flush the journal, cancel any
+/// request still hanging, then give up the database lease. Whatever was
+/// interrupted is settled by [SyncEngine.recover] at the next start.
+/// Draining is bounded by [timeout]; the caller disposes the provider
+/// container afterwards, which closes the database.
+class ShutdownService {
+  final SyncEngine _syncEngine;
+  final ChunkedUploadService _uploads;
+  final DbLeaseService? _lease;
+  final TimeoutInterceptor? _requests;
+  final Duration timeout;
+  final Logger _logger = Logger();
+
+  Future<void>? _done;
+
+  static const _persistGrace = Duration(seconds: 2);
+
+  ShutdownService({
+    required SyncEngine syncEngine,
+    required ChunkedUploadService uploads,
+    DbLeaseService? lease,
+    TimeoutInterceptor? requests,
+    this.timeout = const Duration(seconds: 10),
+  }) : _syncEngine = syncEngine,
+       _uploads = uploads,
+       _lease = lease,
+       _requests = requests;
+
+  bool get isShuttingDown => _done != null;
+
+  /// Run the shutdown sequence. Safe to call more than once; later calls
+  /// wait for the first one.
+  Future<void> shutdown() => _done ??= _run();
+
+  Future<void> _run() async {
+    _uploads.stop();
+    try {
+      // The engine drains for [timeout]; the grace lets it persist the
+      // queue afterwards.
+      await _syncEngine
+          .shutdown(timeout: timeout)
+          .timeout(timeout + _persistGrace);
+    } on TimeoutException {
+      _logger.w('Shutdown timed out after ${timeout.inSeconds}s');
+    } catch (e) {
+      _logger.e('Shutdown failed', error: e);
+    }
+    _requests?.cancelAll('Shutting down');
+    try {
+      await _lease?.release();
+    } catch (e) {
+      _logger.e('Could not release database lease', error: e);
+    }
+  }
+}
@@ -0,0 +1,117 @@
+import 'dart:async';
+import 'dart:collection';
+import 'dart:typed_data';
+
+import '../../domain/repositories/file_repository.dart';
+
+class _Request {
+  final String id;
+  final Completer<Uint8List?> completer = Completer();
+
+  _Request(this.id);
+}
+
+/// Loads thumbnails with a small concurrency limit and keeps the most
+/// recently used ones in memory.
+///
+/// Tiles ask for their thumbnail with [load], which jumps ahead of any
+/// prefetch. A page can [prefetch] the thumbnails it is about to show; a
+/// new prefetch (or [cancelPrefetch]) drops whatever the last one had not
+/// started yet, so navigating away stops the requests for the old folder.
+class ThumbnailService {
+  final FileRepository _files;
+  final int maxConcurrent;
+  final int cacheSize;
+  final String size;
+
+  final LinkedHashMap<String, Uint8List> _cache = LinkedHashMap();
+  final Map<String, _Request> _pending = {};
+  final Queue<_Request> _wanted = Queue();
+  final Queue<_Request> _prefetch = Queue();
+  final Set<String> _failed = {};
+  int _running = 0;
+
+  ThumbnailService(
+    this._files, {
+    this.maxConcurrent = 3,
+    this.cacheSize = 500,
+    this.size = '256',
+  });
+
+  Uint8List? cached(String id) {
+    final bytes = _cache.remove(id);
+    if (bytes != null) _cache[id] = bytes;
+    return bytes;
+  }
+
+  /// The thumbnail of [id], or null if the server has none.
+  Future<Uint8List?> load(String id) {
+    final bytes = cached(id);
+    if (bytes != null) return Future.value(bytes);
+    if (_failed.contains(id)) return Future.value();
+
+    final pending = _pending[id];
+    if (pending != null) {
+      // Promote a prefetch that has not started yet.
+      if (_prefetch.remove(pending)) _wanted.add(pending);
+      return pending.completer.future;
+    }
+    final request = _pending[id] = _Request(id);
+    _wanted.add(request);
+    _pump();
+    return request.completer.future;
+  }
+
+  /// Fetch the thumbnails of [ids] in the background, in order, replacing
+  /// any earlier prefetch that has not started.
+  void prefetch(Iterable<String> ids) {
+    cancelPrefetch();
+    for (final id in ids) {
+      if (_cache.containsKey(id) ||
+          _failed.contains(id) ||
+          _pending.containsKey(id)) {
+        continue;
+      }
+      _prefetch.add(_pending[id] = _Request(id));
+    }
+    _pump();
+  }
+
+  void cancelPrefetch() {
+    for (final request in _prefetch) {
+      _pending.remove(request.id);
+      request.completer.complete(null);
+    }
+    _prefetch.clear();
+  }
+
+  void _pump() {
+    while (_running < maxConcurrent) {
+      final _Request request;
+      if (_wanted.isNotEmpty) {
+        request = _wanted.removeFirst();
+      } else if (_prefetch.isNotEmpty) {
+        request = _prefetch.removeFirst();
+      } else {
+        return;
+      }
+      _running++;
+      unawaited(_fetch(request));
+    }
+  }
+
+  Future<void> _fetch(_Request request) async {
+    Uint8List? bytes;
+    try {
+      bytes = await _files.getThumbnail(request.id, size: size);
+      _cache[request.id] = bytes;
+      if (_cache.length > cacheSize) _cache.remove(_cache.keys.first);
+    } catch (_) {
+      _failed.add(request.id);
+    }
+    _pending.remove(request.id);
+    request.completer.complete(bytes);
+    _running--;
+    _pump();
+  }
+}
@@ -0,0 +1,134 @@
+import 'diagnostics_service.dart';
+
+/// Something the help page can do for the user from a step.
+enum TroubleshootingAction { openSettings, relogin, copyCommand, retry }
+
+class TroubleshootingStep {
+  final String text;
+  final TroubleshootingAction? action;
+  final String? actionLabel;
+
+  /// The command to copy for [TroubleshootingAction.copyCommand].
+  final String? command;
+
+  const TroubleshootingStep(
+    this.text, {
+    this.action,
+    this.actionLabel,
+    this.command,
+  });
+}
+
+/// Step-by-step fix for one diagnostic finding.
+class TroubleshootingGuide {
+  final DiagnosticFinding finding;
+  final String title;
+  final List<TroubleshootingStep> steps;
+
+  const TroubleshootingGuide({
+    required this.finding,
+    required this.title,
+    required this.steps,
+  });
+}
+
+/// Runs the diagnostics and turns each finding into a guided fix.
+class TroubleshootingService {
+  final DiagnosticsService _diagnostics;
+
+  TroubleshootingService(this._diagnostics);
+
+  Future<List<TroubleshootingGuide>> run() async {
+    final findings = await _diagnostics.run();
+    return findings.map(guideFor).toList();
+  }
+
+  static const _retry = TroubleshootingStep(
+    'Check again once that is done.',
+    action: TroubleshootingAction.retry,
+    actionLabel: 'Check again',
+  );
+
+  static TroubleshootingGuide guideFor(DiagnosticFinding finding) {
+    return switch (finding.kind) {
+      DiagnosticKind.dnsFailure => TroubleshootingGuide(
+        finding: finding,
+        title: "The server's name cannot be found",
+        steps: const [
+          TroubleshootingStep(
+            'Make sure the server address has no typo.',
+            action: TroubleshootingAction.openSettings,
+            actionLabel: 'Open settings',
+          ),
+          TroubleshootingStep(
+            'Check that this computer is online, and connect to the VPN if '
+            'the server is only reachable through one.',
+          ),
+          _retry,
+        ],
+      ),
+      DiagnosticKind.authRejected => TroubleshootingGuide(
+        finding: finding,
+        title: 'The server keeps rejecting your session',
+        steps: const [
+          TroubleshootingStep(
+            'Sign in again to get a fresh session.',
+            action: TroubleshootingAction.relogin,
+            actionLabel: 'Sign in again',
+          ),
+          TroubleshootingStep(
+            'If you signed in with an app password, check that it was not '
+            'revoked, or ask an administrator whether your account is '
+            'still active.',
+            action: TroubleshootingAction.openSettings,
+            actionLabel: 'Open settings',
+          ),
+        ],
+      ),
+      DiagnosticKind.clockSkew => TroubleshootingGuide(
+        finding: finding,
+        title: "This computer's clock is wrong",
+        steps: const [
+          TroubleshootingStep(
+            'Sessions and file times depend on the clock. Turn on automatic '
+            'date and time in the system settings.',
+          ),
+          TroubleshootingStep(
+            'On Linux, this command turns on network time:',
+            action: TroubleshootingAction.copyCommand,
+            actionLabel: 'Copy command',
+            command: 'sudo timedatectl set-ntp true',
+          ),
+          _retry,
+        ],
+      ),
+      DiagnosticKind.watchLimit => TroubleshootingGuide(
+        finding: finding,
+        title: 'Too few files can be watched for changes',
+        steps: const [
+          TroubleshootingStep(
+            'Linux limits how many files an app may watch. Raise the limit '
+            'permanently with this command:',
+            action: TroubleshootingAction.copyCommand,
+            actionLabel: 'Copy command',
+            command:
+                'echo fs.inotify.max_user_watches=524288 | sudo tee '
+                '/etc/sysctl.d/60-oxicloud.conf && sudo sysctl --system',
+          ),
+          _retry,
+        ],
+      ),
+      DiagnosticKind.diskFull => TroubleshootingGuide(
+        finding: finding,
+        title: 'The disk is almost full',
+        steps: const [
+          TroubleshootingStep(
+            'Downloads and the offline cache stop when the disk is full. '
+            'Free some space, for example by emptying the system trash.',
+          ),
+          _retry,
+        ],
+      ),
+    };
+  }
+}
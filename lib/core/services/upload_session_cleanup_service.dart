@@ -0,0 +1,164 @@
+import 'dart:async';
+import 'dart:io';
+
+import 'package:logger/logger.dart';
+
+import '../../data/datasources/remote/chunked_upload_datasource.dart';
+import '../database/app_database.dart';
+import '../network/connectivity_service.dart';
+import 'chunked_upload_service.dart';
+
+/// Outcome of one reconciliation pass. Resumable uploads are queued to
+/// continue in the background, not finished by the pass.
+typedef UploadCleanupReport = ({int resumed, int deleted, int dropped});
+
+/// Reconciles the account's server-side upload sessions with the local
+/// journal: sessions we can still finish are resumed, stale orphans are
+/// deleted, and journal entries the server no longer knows are dropped.
+///
+/// Besides every [interval], a pass runs when the connection comes back,
+/// so uploads cut off by it continue from their last chunk. Resumed
+/// uploads run one at a time and never hold up a pass.
+class UploadSessionCleanupService {
+  final ChunkedUploadDatasource _datasource;
+  final ChunkedUploadService _uploads;
+  final AppDatabase _db;
+  final ConnectivityService? _connectivity;
+  final Duration staleAge;
+  final Duration interval;
+  final Logger _logger = Logger();
+
+  Timer? _timer;
+  StreamSubscription<bool>? _connectivitySub;
+  bool _running = false;
+
+  final List<UploadSessionsTableData> _resumeQueue = [];
+  Future<void>? _resuming;
+
+  UploadSessionCleanupService({
+    required ChunkedUploadDatasource datasource,
+    required ChunkedUploadService uploads,
+    required AppDatabase db,
+    ConnectivityService? connectivity,
+    this.staleAge = const Duration(hours: 24),
+    this.interval = const Duration(hours: 1),
+  }) : _datasource = datasource,
+       _uploads = uploads,
+       _db = db,
+       _connectivity = connectivity;
+
+  /// Run a pass now and then every [interval].
+  void start() {
+    _timer?.cancel();
+    _timer = Timer.periodic(interval, (_) => run());
+    unawaited(_connectivitySub?.cancel());
+    _connectivitySub = _connectivity?.onConnectivityChanged
+        .where((online) => online)
+        .listen((_) => run());
+    run();
+  }
+
+  void stop() {
+    _resumeQueue.clear();
+    _timer?.cancel();
+    _timer = null;
+    unawaited(_connectivitySub?.cancel());
+    _connectivitySub = null;
+  }
+
+  Future<UploadCleanupReport> run() async {
+    if (_running) return (resumed: 0, deleted: 0, dropped: 0);
+    _running = true;
+    var resumed = 0;
+    var deleted = 0;
+    var dropped = 0;
+
+    try {
+      final remote = await _datasource.listSessions();
+      final journal = {
+        for (final e in await _db.getUploadSessions()) e.uploadId: e,
+      };
+      final now = DateTime.now();
+
+      for (final session in remote) {
+        if (_uploads.isActive(session.uploadId)) continue;
+        final entry = journal.remove(session.uploadId);
+
+        if (entry != null && await _canResume(entry)) {
+          if (_queueResume(entry)) resumed++;
+          continue;
+        }
+
+        // One of ours whose file changed or went away can never finish.
+        // Others may be another device's, and are left until stale.
+        if (entry == null) {
+          final created = session.createdAt;
+          final stale = created == null
+              ? session.expiresAt.isBefore(now)
+              : now.difference(created) >= staleAge;
+          if (!stale) continue;
+        }
+
+        try {
+          await _datasource.cancel(session.uploadId);
+          deleted++;
+        } catch (e) {
+          _logger.w('Deleting upload ${session.uploadId} failed', error: e);
+        }
+        if (entry != null) await _db.deleteUploadSession(entry.uploadId);
+      }
+
+      // Whatever is left in the journal no longer exists on the server.
+      for (final entry in journal.values) {
+        if (_uploads.isActive(entry.uploadId)) continue;
+        await _db.deleteUploadSession(entry.uploadId);
+        dropped++;
+      }
+    } catch (e) {
+      _logger.w('Upload session cleanup failed', error: e);
+    } finally {
+      _running = false;
+    }
+    return (resumed: resumed, deleted: deleted, dropped: dropped);
+  }
+
+  /// Completes once the queued resumes have run.
+  Future<void> get resumesDone => _resuming ?? Future.value();
+
+  /// Queue [entry] to be resumed after those already waiting. False if it
+  /// is queued already.
+  bool _queueResume(UploadSessionsTableData entry) {
+    if (_resumeQueue.any((e) => e.uploadId == entry.uploadId)) return false;
+    _resumeQueue.add(entry);
+    _resuming ??= _drainResumes().whenComplete(() => _resuming = null);
+    return true;
+  }
+
+  Future<void> _drainResumes() async {
+    while (_resumeQueue.isNotEmpty) {
+      final entry = _resumeQueue.first;
+      try {
+        await _uploads.resume(entry);
+      } catch (e) {
+        _logger.w('Resuming upload ${entry.filename} failed', error: e);
+      } finally {
+        _resumeQueue.remove(entry);
+      }
+    }
+  }
+
+  /// The local file must still exist and be the version the session was
+  /// opened with: same size and same modification time. Journal entries
+  /// from before the time was kept cannot be checked and are not resumed.
+  Future<bool> _canResume(UploadSessionsTableData entry) async {
+    if (DateTime.now().difference(entry.createdAt) >= staleAge) return false;
+    final modifiedAt = entry.localModifiedAt;
+    if (modifiedAt == null) return false;
+    final stat = await File(entry.localPath).stat();
+    // The journal keeps times to the second.
+    return stat.type == FileSystemEntityType.file &&
+        stat.size == entry.totalSize &&
+        stat.modified.millisecondsSinceEpoch ~/ 1000 ==
+            modifiedAt.millisecondsSinceEpoch ~/ 1000;
+  }
+}
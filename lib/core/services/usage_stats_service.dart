@@ -0,0 +1,124 @@
+import 'dart:convert';
+
+import 'package:dio/dio.dart';
+import 'package:logger/logger.dart';
+
+import '../auth/secure_storage.dart';
+import '../config/constants.dart';
+import '../database/app_database.dart';
+import '../network/api_endpoints.dart';
+import '../platform/platform_info.dart';
+import '../sync/sync_stats.dart';
+
+/// Whether usage is counted at all, and whether the counts may be sent to
+/// the server. Sending needs both.
+class UsageStatisticsSettings {
+  final bool enabled;
+  final bool upload;
+
+  const UsageStatisticsSettings({this.enabled = false, this.upload = false});
+
+  bool get mayUpload => enabled && upload;
+
+  UsageStatisticsSettings copyWith({bool? enabled, bool? upload}) {
+    return UsageStatisticsSettings(
+      enabled: enabled ?? this.enabled,
+      upload: upload ?? this.upload,
+    );
+  }
+}
+
+/// Names of the features that are counted.
+abstract final class UsageFeature {
+  static const upload = 'upload';
+  static const download = 'download';
+  static const createFolder = 'create_folder';
+  static const publishFolder = 'publish_folder';
+  static const shareLink = 'share_link';
+  static const search = 'search';
+  static const accountMigration = 'account_migration';
+}
+
+/// Counts how often features are used, locally and without names, paths or
+/// account details, and only while the `usage_statistics` setting is on.
+///
+/// Nothing leaves the device unless uploading is turned on as well; then
+/// [report], the same payload the settings page shows, is sent at most
+/// once per [uploadInterval].
+class UsageStatsService {
+  final AppDatabase _db;
+  final SyncStatsCollector _syncStats;
+  final SecureStorage _storage;
+  final Dio _dio;
+  final Logger _logger = Logger();
+
+  UsageStatisticsSettings settings = const UsageStatisticsSettings();
+
+  static const uploadInterval = Duration(days: 7);
+
+  UsageStatsService({
+    required AppDatabase db,
+    required SyncStatsCollector syncStats,
+    required SecureStorage storage,
+    required Dio dio,
+  }) : _db = db,
+       _syncStats = syncStats,
+       _storage = storage,
+       _dio = dio;
+
+  /// Count one use of [feature], one of [UsageFeature].
+  Future<void> count(String feature) async {
+    if (!settings.enabled) return;
+    try {
+      await _db.countUsage(feature, DateTime.now());
+    } catch (e) {
+      _logger.w('Could not count usage of $feature', error: e);
+    }
+  }
+
+  Future<List<UsageCountersTableData>> counters() => _db.getUsageCounters();
+
+  /// Forget everything counted so far.
+  Future<void> clear() => _db.clearUsageCounters();
+
+  /// What an upload would send: the app version and platform, the feature
+  /// counters and the sync totals of the last seven days.
+  Future<Map<String, Object>> report({DateTime? now}) async {
+    final today = now ?? DateTime.now();
+    final sync = await _syncStats.summary(
+      since: today.subtract(const Duration(days: 6)),
+    );
+    return {
+      'app_version': Constants.appVersion,
+      'platform': PlatformInfo.platformName,
+      'features': {for (final c in await counters()) c.feature: c.count},
+      'sync_last_7_days': {
+        'files_synced': sync.filesSynced,
+        'errors': sync.errors,
+        'bytes_uploaded': sync.bytesUploaded,
+        'bytes_downloaded': sync.bytesDownloaded,
+      },
+    };
+  }
+
+  /// [report] as indented JSON, for showing to the user.
+  Future<String> preview() async =>
+      const JsonEncoder.withIndent('  ').convert(await report());
+
+  /// Send [report] if uploading is on and the last one is older than
+  /// [uploadInterval]. Returns whether anything was sent.
+  Future<bool> uploadIfDue({DateTime? now}) async {
+    if (!settings.mayUpload) return false;
+    final at = now ?? DateTime.now();
+    final last = await _storage.getUsageStatisticsSentAt();
+    if (last != null && at.difference(last) < uploadInterval) return false;
+    try {
+      await _dio.post(ApiEndpoints.usageStatistics, data: await report());
+      await _storage.saveUsageStatisticsSentAt(at);
+      return true;
+    } catch (e) {
+      _logger.w('Could not send usage statistics', error: e);
+      return false;
+    }
+  }
+}
@@ -0,0 +1,31 @@
+import 'dart:io';
+
+abstract class VirusScanner {
+  /// False if [path] is infected. Throws if the scan itself failed.
+  Future<bool> isClean(String path);
+}
+
+/// Scans with a command-line scanner such as `clamscan --no-summary`. The
+/// file's path is added as the last argument; exit code 0 means clean and
+/// 1 infected, as with ClamAV. Any other exit code is a failed scan.
+class CommandVirusScanner implements VirusScanner {
+  final String command;
+
+  CommandVirusScanner(this.command);
+
+  @override
+  Future<bool> isClean(String path) async {
+    final parts = command.trim().split(RegExp(r'\s+'));
+    final result = await Process.run(parts.first, [...parts.skip(1), path]);
+    return switch (result.exitCode) {
+      0 => true,
+      1 => false,
+      _ => throw ProcessException(
+        parts.first,
+        parts.skip(1).toList(),
+        '${result.stderr}'.trim(),
+        result.exitCode,
+      ),
+    };
+  }
+}
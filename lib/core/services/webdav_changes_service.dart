@@ -0,0 +1,79 @@
+import 'dart:convert';
+
+import 'package:dio/dio.dart';
+
+import '../error/error_handler.dart';
+import '../network/dav_multistatus.dart';
+
+/// Asks the server which members of a folder changed since a previous
+/// scan with a WebDAV sync-collection report (RFC 6578), instead of
+/// listing the folder again.
+///
+/// Servers without the report are noticed on the first refusal and not
+/// asked again; callers fall back to listing.
+class WebDavChangesService {
+  final Dio _dio;
+  final String _webdavUrl;
+
+  bool _supported = true;
+
+  /// Status codes servers refuse an unknown report with. With a token,
+  /// 403 and 409 also mean the token expired.
+  static const _refusals = {400, 403, 405, 409, 415, 501};
+
+  WebDavChangesService({required Dio dio, required String webdavUrl})
+    : _dio = dio,
+      _webdavUrl = webdavUrl;
+
+  /// False once the server refused a report.
+  bool get isSupported => _supported;
+
+  /// The members of the folder at [path] that changed or went away since
+  /// [token], or all of them without one. Starts over without a token if
+  /// the server no longer knows [token]. Null if the server does not
+  /// support the report or has no such folder; throws [ServerException]
+  /// if it cannot be asked.
+  Future<DavSyncReport?> changesSince(String path, String? token) async {
+    if (!_supported) return null;
+    final segments = [
+      for (final s in path.split('/'))
+        if (s.isNotEmpty) Uri.encodeComponent(s),
+    ];
+    try {
+      final response = await _dio.request<ResponseBody>(
+        '$_webdavUrl${segments.join('/')}',
+        data: _report(token),
+        options: Options(
+          method: 'REPORT',
+          headers: {'Depth': '0', 'Content-Type': 'application/xml'},
+          responseType: ResponseType.stream,
+        ),
+      );
+      return await MultistatusParser.parseSyncReport(response.data!.stream);
+    } on FormatException {
+      // Answered, but not with a report.
+      _supported = false;
+      return null;
+    } on DioException catch (e) {
+      final status = e.response?.statusCode;
+      if (status == 404) return null;
+      if (!_refusals.contains(status)) {
+        throw ErrorHandler.mapDioToServerException(e);
+      }
+      if (token != null) return changesSince(path, null);
+      _supported = false;
+      return null;
+    }
+  }
+
+  static String _report(String? token) {
+    final escape = const HtmlEscape(HtmlEscapeMode.element).convert;
+    final tag = token == null
+        ? '<d:sync-token/>'
+        : '<d:sync-token>${escape(token)}</d:sync-token>';
+    return '<?xml version="1.0" encoding="utf-8"?>'
+        '<d:sync-collection xmlns:d="DAV:">$tag'
+        '<d:sync-level>1</d:sync-level>'
+        '<d:prop><d:getetag/></d:prop></d:sync-collection>';
+  }
+}
@@ -0,0 +1,48 @@
+import 'package:dio/dio.dart';
+
+import '../error/error_handler.dart';
+import '../network/dav_multistatus.dart';
+
+/// Reads the ownCloud properties of single files over WebDAV: the
+/// server's file id, permissions, checksums and size, next to the ETag.
+class WebDavPropertiesService {
+  final Dio _dio;
+  final String _webdavUrl;
+
+  static const _propfind =
+      '<?xml version="1.0" encoding="utf-8"?>'
+      '<d:propfind xmlns:d="DAV:" xmlns:oc="http://owncloud.org/ns">'
+      '<d:prop><d:getetag/><oc:fileid/><oc:permissions/>'
+      '<oc:checksums/><oc:size/></d:prop></d:propfind>';
+
+  WebDavPropertiesService({required Dio dio, required String webdavUrl})
+    : _dio = dio,
+      _webdavUrl = webdavUrl;
+
+  /// The properties of the file at [path], or null if the server has no
+  /// such file. Throws [ServerException] if it cannot be asked.
+  Future<DavResource?> lookup(String path) async {
+    final segments = [
+      for (final s in path.split('/'))
+        if (s.isNotEmpty) Uri.encodeComponent(s),
+    ];
+    try {
+      final response = await _dio.request<ResponseBody>(
+        '$_webdavUrl${segments.join('/')}',
+        data: _propfind,
+        options: Options(
+          method: 'PROPFIND',
+          headers: {'Depth': '0', 'Content-Type': 'application/xml'},
+          responseType: ResponseType.stream,
+        ),
+      );
+      final resources = await MultistatusParser.parseStream(
+        response.data!.stream,
+      );
+      return resources.firstOrNull;
+    } on DioException catch (e) {
+      if (e.response?.statusCode == 404) return null;
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+}
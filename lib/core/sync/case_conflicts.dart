@@ -0,0 +1,165 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:drift/drift.dart';
+import 'package:path/path.dart' as p;
+
+import '../../domain/repositories/folder_repository.dart';
+import '../database/app_database.dart';
+import '../platform/platform_info.dart';
+import 'sync_events.dart';
+
+/// Names that only differ in case, which the default filesystems on macOS
+/// and Windows treat as the same name.
+class CaseConflicts {
+  CaseConflicts._();
+
+  /// Whether local file names ignore case on this platform.
+  static bool get filesystemIgnoresCase =>
+      PlatformInfo.isMacOS || PlatformInfo.isWindows;
+
+  /// Groups of [names] that are equal ignoring case, each in the order the
+  /// names came in. Names without a clash are left out.
+  static List<List<String>> find(Iterable<String> names) {
+    final groups = <String, List<String>>{};
+    for (final name in names) {
+      groups.putIfAbsent(name.toLowerCase(), () => []).add(name);
+    }
+    return [
+      for (final group in groups.values)
+        if (group.length > 1) group,
+    ];
+  }
+
+  /// A name for [name] that clashes with none of [taken], ignoring case:
+  /// `README (2).md`.
+  static String suggestRename(String name, Iterable<String> taken) {
+    final used = {for (final t in taken) t.toLowerCase()};
+    // A leading dot starts a hidden name, not an extension.
+    final dot = name.lastIndexOf('.');
+    final stem = dot <= 0 ? name : name.substring(0, dot);
+    final ext = dot <= 0 ? '' : name.substring(dot);
+    for (var n = 2; ; n++) {
+      final candidate = '$stem ($n)$ext';
+      if (!used.contains(candidate.toLowerCase())) return candidate;
+    }
+  }
+
+  /// The name of an entry in [dir] that matches [name] ignoring case but
+  /// not exactly, i.e. one that saving [name] there would overwrite.
+  static Future<String?> variantOnDisk(String dir, String name) async {
+    final directory = Directory(dir);
+    if (!await directory.exists()) return null;
+    final lower = name.toLowerCase();
+    await for (final entry in directory.list()) {
+      final existing = p.basename(entry.path);
+      if (existing != name && existing.toLowerCase() == lower) return existing;
+    }
+    return null;
+  }
+}
+
+/// Saving [name] would overwrite [existing], which differs only in case.
+class CaseConflictException implements Exception {
+  final String name;
+  final String existing;
+  final String suggestion;
+
+  const CaseConflictException({
+    required this.name,
+    required this.existing,
+    required this.suggestion,
+  });
+
+  @override
+  String toString() =>
+      '$name would overwrite $existing, which differs only in case. '
+      'Rename one of them, for example $name to $suggestion.';
+}
+
+/// Records server items that cannot be stored next to a sibling on this
+/// machine because their names differ only in case.
+///
+/// Of each clashing group, the item created first keeps its name; every
+/// other one is logged as a `case_clash` sync conflict with a suggested new
+/// name, and announced once with [CaseConflictsFound]. The conflict is
+/// resolved as soon as a listing shows the clash gone.
+class CaseConflictDetector {
+  static const conflictType = 'case_clash';
+
+  final AppDatabase _db;
+  final SyncEventBus? _events;
+  final bool ignoresCase;
+
+  CaseConflictDetector({
+    required AppDatabase db,
+    SyncEventBus? events,
+    bool? ignoresCase,
+  }) : _db = db,
+       _events = events,
+       ignoresCase = ignoresCase ?? CaseConflicts.filesystemIgnoresCase;
+
+  /// Check a fresh server listing of [folderId]. Returns the clashes found
+  /// that were not known before.
+  Future<List<CaseClash>> check(
+    String folderId,
+    FolderContents contents,
+  ) async {
+    if (!ignoresCase) return const [];
+    final items = [
+      for (final f in contents.folders)
+        (id: f.id, type: 'folder', name: f.name, createdAt: f.createdAt),
+      for (final f in contents.files)
+        (id: f.id, type: 'file', name: f.name, createdAt: f.createdAt),
+    ]..sort((a, b) => a.createdAt.compareTo(b.createdAt));
+    final names = [for (final i in items) i.name];
+    final keepers = {
+      for (final group in CaseConflicts.find(names))
+        for (final name in group.skip(1)) name: group.first,
+    };
+
+    final open = {
+      for (final c in await _db.getUnresolvedConflicts())
+        if (c.conflictType == conflictType &&
+            json.decode(c.payload)['folder_id'] == folderId)
+          c.itemId: c,
+    };
+    final found = <CaseClash>[];
+    for (final item in items) {
+      final keeper = keepers[item.name];
+      if (keeper == null || open.remove(item.id) != null) continue;
+      final clash = (
+        name: item.name,
+        clashesWith: keeper,
+        suggestion: CaseConflicts.suggestRename(item.name, names),
+      );
+      await _db.insertSyncConflict(
+        SyncConflictsTableCompanion.insert(
+          itemId: item.id,
+          itemType: item.type,
+          operationType: 'download',
+          conflictType: const Value(conflictType),
+          payload: json.encode({
+            'folder_id': folderId,
+            'name': clash.name,
+            'clashes_with': clash.clashesWith,
+            'suggested_name': clash.suggestion,
+          }),
+          errorMessage: Value(
+            'Differs from $keeper only in case; '
+            'rename it to ${clash.suggestion}',
+          ),
+          createdAt: DateTime.now(),
+        ),
+      );
+      found.add(clash);
+    }
+    // Renamed or deleted since.
+    for (final stale in open.values) {
+      await _db.resolveConflict(stale.id, 'manual', resolvedBy: 'sync');
+    }
+
+    if (found.isNotEmpty) _events?.emit(CaseConflictsFound(found));
+    return found;
+  }
+}
@@ -0,0 +1,51 @@
+import 'package:crypto/crypto.dart' as crypto;
+
+/// Checksums this client can compute, weakest first.
+enum ChecksumAlgorithm {
+  md5('md5'),
+  sha1('sha1'),
+  sha256('sha256');
+
+  final String wireName;
+
+  const ChecksumAlgorithm(this.wireName);
+
+  /// Parses a server's spelling of an algorithm ("SHA-256", "sha256").
+  /// Null for algorithms this client cannot compute.
+  static ChecksumAlgorithm? tryParse(String? value) {
+    final name = value?.toLowerCase().replaceAll(RegExp('[-_]'), '');
+    for (final a in values) {
+      if (a.wireName == name) return a;
+    }
+    return null;
+  }
+
+  String digest(List<int> bytes) => _hash.convert(bytes).toString();
+
+  Future<String> digestStream(Stream<List<int>> data) async =>
+      (await _hash.bind(data).first).toString();
+
+  crypto.Hash get _hash => switch (this) {
+    ChecksumAlgorithm.md5 => crypto.md5,
+    ChecksumAlgorithm.sha1 => crypto.sha1,
+    ChecksumAlgorithm.sha256 => crypto.sha256,
+  };
+}
+
+class ChecksumNegotiator {
+  ChecksumNegotiator._();
+
+  /// The algorithm to use with a server that accepts [advertised]:
+  /// [preferred] when the server takes it, otherwise the strongest one both
+  /// sides support. Null when there is none, in which case no checksums are
+  /// sent.
+  static ChecksumAlgorithm? negotiate(
+    Iterable<String> advertised, {
+    ChecksumAlgorithm? preferred,
+  }) {
+    final common = advertised.map(ChecksumAlgorithm.tryParse).nonNulls.toSet();
+    if (preferred != null && common.contains(preferred)) return preferred;
+    if (common.isEmpty) return null;
+    return common.reduce((a, b) => a.index >= b.index ? a : b);
+  }
+}
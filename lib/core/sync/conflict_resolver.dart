@@ -0,0 +1,395 @@
+import 'dart:async';
+import 'dart:convert';
+
+import 'package:drift/drift.dart';
+import 'package:logger/logger.dart';
+
+import '../../domain/repositories/file_repository.dart';
+import '../database/app_database.dart';
+import '../error/exceptions.dart';
+import '../platform/platform_info.dart';
+import 'case_conflicts.dart';
+
+enum ConflictResolution {
+  /// Apply the local change again.
+  local,
+
+  /// Drop the local change and keep the server version.
+  remote,
+
+  /// Keep whichever side changed last.
+  lastWriterWins,
+
+  /// Leave the server version alone and apply the local change to a copy.
+  keepBoth,
+
+  /// Stop looking at further rules and leave the conflict to the user.
+  askUser;
+
+  static ConflictResolution? tryParse(String name) => values.asNameMap()[name];
+}
+
+/// Matching of conflict rules against an item path.
+///
+/// A rule applies when the path lies under its `pathPrefix` and the file
+/// name matches its `namePattern` (`*` and `?` wildcards); a missing field
+/// matches anything. The first applicable rule in position order wins.
+class ConflictRuleMatcher {
+  ConflictRuleMatcher._();
+
+  static ConflictRulesTableData? firstMatch(
+    List<ConflictRulesTableData> rules,
+    String path,
+  ) {
+    for (final rule in rules) {
+      if (matches(rule, path)) return rule;
+    }
+    return null;
+  }
+
+  static bool matches(ConflictRulesTableData rule, String path) {
+    final prefix = rule.pathPrefix;
+    if (prefix != null && prefix.isNotEmpty) {
+      final dir = prefix.endsWith('/')
+          ? prefix.substring(0, prefix.length - 1)
+          : prefix;
+      if (path != dir && !path.startsWith('$dir/')) return false;
+    }
+    final pattern = rule.namePattern;
+    if (pattern != null && pattern.isNotEmpty) {
+      final name = path.substring(path.lastIndexOf('/') + 1);
+      if (!globToRegExp(pattern).hasMatch(name)) return false;
+    }
+    return true;
+  }
+
+  static RegExp globToRegExp(String glob) {
+    final buffer = StringBuffer('^');
+    for (final char in glob.split('')) {
+      buffer.write(switch (char) {
+        '*' => '.*',
+        '?' => '.',
+        _ => RegExp.escape(char),
+      });
+    }
+    buffer.write(r'$');
+    return RegExp(buffer.toString());
+  }
+}
+
+/// Names for the copies made when a conflict keeps both versions:
+/// `report (conflicted copy laptop 2024-05-01).pdf`, then
+/// `report (conflicted copy laptop 2024-05-01 2).pdf` and so on for
+/// further copies that day.
+class ConflictCopyNamer {
+  ConflictCopyNamer._();
+
+  static String name(
+    String original, {
+    required String device,
+    required DateTime at,
+    int number = 1,
+  }) {
+    final date =
+        '${at.year.toString().padLeft(4, '0')}-'
+        '${at.month.toString().padLeft(2, '0')}-'
+        '${at.day.toString().padLeft(2, '0')}';
+    final counter = number > 1 ? ' $number' : '';
+    final suffix = ' (conflicted copy $device $date$counter)';
+    // A leading dot starts a hidden name, not an extension.
+    final dot = original.lastIndexOf('.');
+    if (dot <= 0) return '$original$suffix';
+    return '${original.substring(0, dot)}$suffix${original.substring(dot)}';
+  }
+}
+
+/// Settles new sync conflicts with the user's rules before they are left
+/// for manual resolution, and manages those rules.
+///
+/// "Keep local" puts the failed operation back in the queue; "keep remote"
+/// drops it and leaves the server untouched. "Last writer wins" picks one
+/// of the two by comparing when the change was queued with the server's
+/// modification time. "Keep both" applies a file rename or move to a
+/// conflict copy of the file, recorded so the user can review it later. An
+/// operation is requeued by a rule at most once a day so a permanently
+/// failing one cannot loop; it is pinned to the server's current version,
+/// the one the rule decided to override.
+///
+/// A rule without a folder or pattern acts as the global policy; rules
+/// limited to a folder override it when placed above it. Conflicts no rule
+/// settles are announced on [needsUser].
+class ConflictResolver {
+  final AppDatabase _db;
+  final FileRepository? _files;
+  final String deviceName;
+  final Duration requeueCooldown;
+  final Logger _logger = Logger();
+
+  /// Payload key of the ETag a queued file change is pinned to, as
+  /// the sync engine sets it.
+  static const _ifMatchKey = 'if_match';
+
+  /// Conflict copies tried per conflict before giving up on a free name.
+  static const _copyNames = 10;
+
+  final _needsUser = StreamController<SyncConflictsTableData>.broadcast();
+
+  /// Type of the conflicts recorded when the server's copy changed since
+  /// the version a queued change was pinned to.
+  static const modifiedRemotely = 'modified_remotely';
+
+  ConflictResolver(
+    this._db, {
+    FileRepository? files,
+    String? deviceName,
+    this.requeueCooldown = const Duration(hours: 24),
+  }) : _files = files,
+       deviceName = deviceName ?? PlatformInfo.deviceName;
+
+  /// Conflicts the rules leave for the user to decide.
+  Stream<SyncConflictsTableData> get needsUser => _needsUser.stream;
+
+  void dispose() => _needsUser.close();
+
+  // --- Rules ---
+
+  Future<List<ConflictRulesTableData>> listRules() => _db.getConflictRules();
+
+  Future<int> addRule({
+    String? pathPrefix,
+    String? namePattern,
+    required ConflictResolution resolution,
+  }) async {
+    final rules = await _db.getConflictRules();
+    final last = rules.isEmpty ? -1 : rules.last.position;
+    return _db.insertConflictRule(
+      ConflictRulesTableCompanion.insert(
+        pathPrefix: Value(pathPrefix),
+        namePattern: Value(namePattern),
+        resolution: resolution.name,
+        position: last + 1,
+        createdAt: DateTime.now(),
+      ),
+    );
+  }
+
+  Future<bool> updateRule(ConflictRulesTableData rule) =>
+      _db.updateConflictRule(rule);
+
+  Future<int> deleteRule(int id) => _db.deleteConflictRule(id);
+
+  /// Set precedence: rules are evaluated in the order of [ids].
+  Future<void> reorderRules(List<int> ids) => _db.reorderConflictRules(ids);
+
+  // --- Resolution ---
+
+  /// Apply the first matching rule to conflict [id]. Returns the rule that
+  /// settled it, or null if the user still has to decide.
+  Future<ConflictRulesTableData?> autoResolve(int id) async {
+    final conflict = await _db.getConflictById(id);
+    if (conflict == null || conflict.resolvedAt != null) return null;
+    // Only renaming one of the items on the server settles a case clash.
+    if (conflict.conflictType == CaseConflictDetector.conflictType) {
+      _needsUser.add(conflict);
+      return null;
+    }
+
+    final item = await _itemOf(conflict);
+    final rule = item == null
+        ? null
+        : ConflictRuleMatcher.firstMatch(
+            await _db.getConflictRules(),
+            item.path,
+          );
+    var resolution = rule == null
+        ? null
+        : ConflictResolution.tryParse(rule.resolution);
+    if (resolution == ConflictResolution.lastWriterWins) {
+      final localAt = conflict.queuedAt ?? conflict.createdAt;
+      // The cached copy predates the server edit that caused the conflict.
+      final remoteAt = conflict.conflictType == modifiedRemotely
+          ? await _serverModifiedAt(conflict) ?? item!.modifiedAt
+          : item!.modifiedAt;
+      resolution = remoteAt.isAfter(localAt)
+          ? ConflictResolution.remote
+          : ConflictResolution.local;
+    }
+
+    final settled = switch (resolution) {
+      ConflictResolution.local => await _requeue(conflict),
+      ConflictResolution.remote => true,
+      ConflictResolution.keepBoth => await _keepBoth(conflict),
+      _ => false,
+    };
+    if (!settled) {
+      _needsUser.add(conflict);
+      return null;
+    }
+    await _db.resolveConflict(
+      conflict.id,
+      resolution!.name,
+      resolvedBy: _resolvedBy(rule!),
+    );
+    return rule;
+  }
+
+  /// How the audit trail names [rule]: the global one is the policy.
+  static String _resolvedBy(ConflictRulesTableData rule) {
+    final scope = [
+      rule.pathPrefix,
+      rule.namePattern,
+    ].whereType<String>().where((s) => s.isNotEmpty);
+    return scope.isEmpty ? 'policy' : 'rule ${scope.join(' ')}';
+  }
+
+  Future<bool> _requeue(SyncConflictsTableData conflict) async {
+    final requeuedRecently = await _db.hasConflictResolvedSince(
+      itemId: conflict.itemId,
+      operationType: conflict.operationType,
+      resolution: ConflictResolution.local.name,
+      since: DateTime.now().subtract(requeueCooldown),
+    );
+    if (requeuedRecently) return false;
+
+    final now = DateTime.now();
+    await _db.insertSyncOp(
+      SyncQueueTableCompanion.insert(
+        operationType: conflict.operationType,
+        itemId: conflict.itemId,
+        itemType: conflict.itemType,
+        payload: await _repin(conflict),
+        createdAt: now,
+        updatedAt: now,
+      ),
+    );
+    return true;
+  }
+
+  /// The payload of [conflict], pinned to the file's current ETag instead
+  /// of the one the server refused, or unpinned if that cannot be read.
+  Future<String> _repin(SyncConflictsTableData conflict) async {
+    final payload = json.decode(conflict.payload) as Map<String, dynamic>;
+    if (payload.remove(_ifMatchKey) == null) return conflict.payload;
+    final files = _files;
+    if (files != null && conflict.itemType == 'file') {
+      try {
+        final etag = (await files.getFile(conflict.itemId)).etag;
+        if (etag != null) payload[_ifMatchKey] = etag;
+      } catch (_) {
+        // Unpinned: the rule chose the local change either way.
+      }
+    }
+    return json.encode(payload);
+  }
+
+  /// Apply a file rename or move to a conflict copy, leaving the server's
+  /// file as it is. A delete needs no copy: not deleting keeps both.
+  /// Anything else is left to the user.
+  Future<bool> _keepBoth(SyncConflictsTableData conflict) async {
+    final files = _files;
+    if (files == null || conflict.itemType != 'file') return false;
+    final file = await _db.getFileById(conflict.itemId);
+    final payload = json.decode(conflict.payload) as Map<String, dynamic>;
+    try {
+      switch (conflict.operationType) {
+        case 'rename':
+          final folderId = file?.folderId;
+          if (folderId == null) return false;
+          final name = payload['new_name'] as String;
+          await _copy(files, conflict, folderId, name);
+        case 'move':
+          if (file == null) return false;
+          final targetFolderId = payload['target_folder_id'] as String;
+          await _copy(files, conflict, targetFolderId, file.name);
+        case 'delete' || 'trash':
+          break;
+        default:
+          return false;
+      }
+      return true;
+    } catch (e) {
+      _logger.w('Keeping both versions of ${conflict.itemId} failed: $e');
+      return false;
+    }
+  }
+
+  Future<void> _copy(
+    FileRepository files,
+    SyncConflictsTableData conflict,
+    String folderId,
+    String name,
+  ) async {
+    final now = DateTime.now();
+    final copy = await files.copyFile(conflict.itemId, folderId);
+    String? copyName;
+    try {
+      for (var number = 1; copyName == null; number++) {
+        final candidate = ConflictCopyNamer.name(
+          name,
+          device: deviceName,
+          at: now,
+          number: number,
+        );
+        try {
+          await files.renameFile(copy.id, candidate);
+          copyName = candidate;
+        } on ConflictException {
+          // Taken by an earlier copy.
+          if (number == _copyNames) rethrow;
+        }
+      }
+    } catch (_) {
+      await files.deleteFile(copy.id);
+      rethrow;
+    }
+    await _db.insertConflictCopy(
+      ConflictCopiesTableCompanion.insert(
+        originalId: conflict.itemId,
+        copyId: copy.id,
+        copyName: copyName,
+        conflictId: Value(conflict.id),
+        createdAt: now,
+      ),
+    );
+  }
+
+  // --- Conflict copies ---
+
+  /// Copies made by "keep both" that the user has not dismissed yet.
+  Future<List<ConflictCopiesTableData>> outstandingCopies() =>
+      _db.getOutstandingConflictCopies();
+
+  Future<int> dismissCopy(int id) => _db.dismissConflictCopy(id);
+
+  /// Run the rules over every conflict still waiting for the user.
+  Future<int> resolvePending() async {
+    var resolved = 0;
+    for (final conflict in await _db.getUnresolvedConflicts()) {
+      if (await autoResolve(conflict.id) != null) resolved++;
+    }
+    return resolved;
+  }
+
+  Future<DateTime?> _serverModifiedAt(SyncConflictsTableData conflict) async {
+    final files = _files;
+    if (files == null || conflict.itemType != 'file') return null;
+    try {
+      return (await files.getFile(conflict.itemId)).modifiedAt;
+    } catch (_) {
+      return null;
+    }
+  }
+
+  Future<({String path, DateTime modifiedAt})?> _itemOf(
+    SyncConflictsTableData conflict,
+  ) async {
+    if (conflict.itemType == 'folder') {
+      final folder = await _db.getFolderById(conflict.itemId);
+      return folder == null
+          ? null
+          : (path: folder.path, modifiedAt: folder.modifiedAt);
+    }
+    final file = await _db.getFileById(conflict.itemId);
+    return file == null ? null : (path: file.path, modifiedAt: file.modifiedAt);
+  }
+}
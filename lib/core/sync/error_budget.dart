@@ -0,0 +1,56 @@
+import 'dart:collection';
+import 'dart:math';
+
+/// Tracks how many of an account's recent server requests failed and how
+/// much its sync should slow down because of it.
+///
+/// After every sync pass, [endPass] doubles [backoff] while more than
+/// [maxFailureRate] of the last [window] requests failed, up to
+/// [maxBackoff], and drops it back to 1 once the rate is under budget.
+/// Nothing is decided before [minRequests] outcomes have been seen, and a
+/// pass that made no requests leaves [backoff] as it was.
+class ErrorBudget {
+  final int window;
+  final int minRequests;
+  final double maxFailureRate;
+  final int maxBackoff;
+
+  final Queue<bool> _outcomes = Queue();
+  int _failures = 0;
+  int _backoff = 1;
+  int _sincePass = 0;
+
+  ErrorBudget({
+    this.window = 50,
+    this.minRequests = 10,
+    this.maxFailureRate = 0.3,
+    this.maxBackoff = 16,
+  });
+
+  /// How many sync intervals make up one while backed off; 1 is normal.
+  int get backoff => _backoff;
+
+  bool get isBackedOff => _backoff > 1;
+
+  double get failureRate =>
+      _outcomes.isEmpty ? 0 : _failures / _outcomes.length;
+
+  bool get isExceeded =>
+      _outcomes.length >= minRequests && failureRate > maxFailureRate;
+
+  void record({required bool ok}) {
+    _outcomes.add(ok);
+    _sincePass++;
+    if (!ok) _failures++;
+    if (_outcomes.length > window && !_outcomes.removeFirst()) _failures--;
+  }
+
+  /// Returns true if [backoff] changed.
+  bool endPass() {
+    if (_sincePass == 0) return false;
+    _sincePass = 0;
+    final previous = _backoff;
+    _backoff = isExceeded ? min(_backoff * 2, maxBackoff) : 1;
+    return _backoff != previous;
+  }
+}
@@ -0,0 +1,275 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:math';
+
+import 'package:crypto/crypto.dart';
+import 'package:drift/drift.dart';
+
+import '../../domain/entities/file_entity.dart';
+import '../../domain/repositories/folder_repository.dart';
+import '../database/app_database.dart';
+import '../network/connectivity_service.dart';
+import '../network/dav_multistatus.dart';
+import '../services/webdav_changes_service.dart';
+import 'case_conflicts.dart';
+import 'folder_subscriptions.dart';
+import 'resource_throttle.dart';
+import 'sync_profile.dart';
+
+/// Decides how long to wait before refreshing a folder again.
+///
+/// Folders whose listing changed are rescanned twice as often (down to
+/// [minInterval]); unchanged folders back off twice as slowly, but never
+/// beyond [maxInterval], which acts as the full-rescan deadline.
+class ScanIntervalPolicy {
+  final Duration minInterval;
+  final Duration maxInterval;
+  final Duration initialInterval;
+
+  const ScanIntervalPolicy({
+    this.minInterval = const Duration(minutes: 1),
+    this.maxInterval = const Duration(hours: 6),
+    this.initialInterval = const Duration(minutes: 5),
+  });
+
+  Duration next(Duration current, {required bool changed}) {
+    final seconds = changed ? current.inSeconds ~/ 2 : current.inSeconds * 2;
+    return Duration(
+      seconds: seconds.clamp(minInterval.inSeconds, maxInterval.inSeconds),
+    );
+  }
+}
+
+/// Refreshes cached folder listings in the background, scanning folders that
+/// change often more frequently than ones that rarely do. Folders with
+/// [subscriptions] are additionally polled at the pace their subscribers
+/// asked for. Ticks are skipped while [ResourceThrottle] pauses background
+/// work. Fresh listings are checked for names that differ only in case.
+/// Due folders are listed [concurrency] at a time.
+///
+/// Where the server supports sync-collection reports, a folder is first
+/// asked for its changes since the last scan and only listed when there
+/// are some.
+class FolderScanScheduler {
+  final AppDatabase _db;
+  final FolderRepository _folderRepo;
+  final ConnectivityService _connectivity;
+  final ScanIntervalPolicy policy;
+  final FolderSubscriptions subscriptions;
+  final ResourceThrottle? _throttle;
+  final CaseConflictDetector? _caseConflicts;
+  final WebDavChangesService? _changes;
+
+  /// Upper bound on folders refreshed per tick, to spread the load.
+  final int batchSize;
+
+  /// Folders listed at once within a tick.
+  final int concurrency;
+
+  Timer? _timer;
+  Duration? _tick;
+  Duration? _timerPeriod;
+  bool _running = false;
+
+  FolderScanScheduler({
+    required AppDatabase db,
+    required FolderRepository folderRepo,
+    required ConnectivityService connectivity,
+    FolderSubscriptions? subscriptions,
+    ResourceThrottle? throttle,
+    CaseConflictDetector? caseConflicts,
+    WebDavChangesService? changes,
+    this.policy = const ScanIntervalPolicy(),
+    this.batchSize = 20,
+    this.concurrency = 4,
+  }) : _db = db,
+       _folderRepo = folderRepo,
+       _connectivity = connectivity,
+       subscriptions = subscriptions ?? FolderSubscriptions(),
+       _throttle = throttle,
+       _caseConflicts = caseConflicts,
+       _changes = changes;
+
+  void start({Duration tick = const Duration(minutes: 1)}) {
+    _tick = tick;
+    subscriptions
+      ..removeListener(_restartTimer)
+      ..addListener(_restartTimer);
+    _restartTimer();
+  }
+
+  void stop() {
+    subscriptions.removeListener(_restartTimer);
+    _timer?.cancel();
+    _timer = null;
+    _timerPeriod = null;
+    _tick = null;
+  }
+
+  /// Tick at least as often as the most eager subscriber polls.
+  void _restartTimer() {
+    final tick = _tick;
+    if (tick == null) return;
+    final shortest = subscriptions.shortestInterval;
+    final period = shortest != null && shortest < tick ? shortest : tick;
+    if (_timer != null && _timerPeriod == period) return;
+    _timer?.cancel();
+    _timerPeriod = period;
+    _timer = Timer.periodic(period, (_) {
+      if (_throttle?.isPaused != true) unawaited(runDue());
+    });
+  }
+
+  /// Refresh every folder whose next scan is due. Returns how many changed.
+  Future<int> runDue() async {
+    if (_running || !_connectivity.isOnline) return 0;
+    _running = true;
+    var changed = 0;
+    try {
+      final now = DateTime.now();
+      final states = {
+        for (final s in await _db.getFolderScanStates()) s.folderId: s,
+      };
+      final folders = await _db.getAllFolders();
+      final known = folders.map((f) => f.id).toSet();
+
+      // Forget folders that are no longer cached.
+      for (final id in states.keys.where((id) => !known.contains(id))) {
+        await _db.deleteFolderScanState(id);
+      }
+
+      DateTime dueAt(FoldersTableData f) {
+        final state = states[f.id];
+        if (state == null) return now;
+        final polled = subscriptions.intervalFor(f.id);
+        final last = state.lastScannedAt;
+        final pollAt = polled == null || last == null ? null : last.add(polled);
+        return pollAt != null && pollAt.isBefore(state.nextScanAt)
+            ? pollAt
+            : state.nextScanAt;
+      }
+
+      final due = folders.where((f) => !dueAt(f).isAfter(now)).toList()
+        ..sort((a, b) => dueAt(a).compareTo(dueAt(b)));
+
+      var scanned = 0;
+      var next = 0;
+      Future<void> worker() async {
+        while (next < due.length && _connectivity.isOnline) {
+          final folder = due[next++];
+          if (await _db.isPathExcludedFromSync(folder.path)) continue;
+          if (scanned >= batchSize) return;
+          scanned++;
+          final state = states[folder.id];
+          final early = state != null && now.isBefore(state.nextScanAt);
+          if (await _scan(folder, state, early: early)) {
+            changed++;
+            subscriptions.notifyChanged(folder.id);
+          }
+        }
+      }
+
+      await Future.wait([for (var w = 0; w < concurrency; w++) worker()]);
+    } finally {
+      _running = false;
+    }
+    return changed;
+  }
+
+  /// An [early] scan, run only because a subscriber polls the folder,
+  /// leaves the adaptive schedule alone unless it finds a change.
+  Future<bool> _scan(
+    FoldersTableData folder,
+    FolderScanStateTableData? state, {
+    bool early = false,
+  }) async {
+    final folderId = folder.id;
+    final now = DateTime.now();
+    final current = state == null
+        ? policy.initialInterval
+        : Duration(seconds: state.intervalSeconds);
+
+    // Asked before listing, so changes made in between show up next time.
+    DavSyncReport? report;
+    try {
+      report = await _changes?.changesSince(folder.path, state?.syncToken);
+    } catch (_) {
+      // List the folder as if the server had no reports.
+    }
+    var syncToken = state?.syncToken;
+
+    String? fingerprint;
+    if (report != null &&
+        report.changes.isEmpty &&
+        state?.syncToken != null &&
+        state?.fingerprint != null) {
+      fingerprint = state!.fingerprint;
+      syncToken = report.token;
+      await _db.markFolderSynced(folderId, now);
+    } else {
+      try {
+        final contents = await _folderRepo.listFolderContents(folderId);
+        // A listing served from the local mirror says nothing about the
+        // server; treat it like a failed scan.
+        fingerprint = contents.fromCache
+            ? state?.fingerprint
+            : _fingerprint(contents);
+        if (!contents.fromCache) {
+          syncToken = report?.token ?? syncToken;
+          await _db.markFolderSynced(folderId, now);
+          await _caseConflicts?.check(folderId, contents);
+        }
+      } catch (_) {
+        // Leave the interval alone and retry at the current pace.
+        fingerprint = state?.fingerprint;
+      }
+    }
+
+    final changed =
+        state?.fingerprint != null && fingerprint != state!.fingerprint;
+    if (early && !changed) {
+      await _db.upsertFolderScanState(
+        state!
+            .copyWith(lastScannedAt: Value(now), syncToken: Value(syncToken))
+            .toCompanion(false),
+      );
+      return false;
+    }
+    final interval = state == null
+        ? current
+        : policy.next(current, changed: changed);
+
+    await _db.upsertFolderScanState(
+      FolderScanStateTableCompanion(
+        folderId: Value(folderId),
+        fingerprint: Value(fingerprint),
+        intervalSeconds: Value(interval.inSeconds),
+        changeCount: Value((state?.changeCount ?? 0) + (changed ? 1 : 0)),
+        lastScannedAt: Value(now),
+        nextScanAt: Value(now.add(_jitter(interval))),
+        syncToken: Value(syncToken),
+      ),
+    );
+    return changed;
+  }
+
+  String _fingerprint(FolderContents contents) {
+    final entries = [
+      for (final f in contents.folders)
+        'd:${f.id}:${f.name}:${f.modifiedAt.millisecondsSinceEpoch}',
+      for (final f in contents.files) 'f:${f.id}:${f.name}:${_version(f)}',
+    ]..sort();
+    return sha1.convert(utf8.encode(entries.join('\n'))).toString();
+  }
+
+  /// The ETag when the server sends one and it can be trusted; hash, or
+  /// size and modification time otherwise.
+  String _version(FileEntity f) =>
+      SyncProfile.forMount(f.mountType).versionOf(f);
+
+  /// Spread scans of folders with the same interval over a few seconds.
+  Duration _jitter(Duration interval) {
+    final spread = max(1, interval.inSeconds ~/ 10);
+    return interval + Duration(seconds: Random().nextInt(spread));
+  }
+}
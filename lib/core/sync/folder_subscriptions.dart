@@ -0,0 +1,117 @@
+import 'dart:async';
+
+import 'package:flutter/foundation.dart';
+
+import 'sync_events.dart';
+
+/// Registry of remote folders the UI wants kept fresher than the
+/// background scan pace, e.g. the folder currently open in the browser.
+///
+/// A folder with several subscribers is polled at the shortest of their
+/// intervals. [FolderScanScheduler] consults the registry when picking due
+/// folders and reports changes back through [notifyChanged].
+///
+/// Changes made through the repositories arrive as [FolderChanged] events
+/// on [events]; a burst of them, as a sync cycle produces, reaches each
+/// subscriber once after [settle]. The root (a null folder id) is never
+/// polled, but its subscribers hear about such changes too.
+class FolderSubscriptions extends ChangeNotifier {
+  final List<FolderSubscription> _active = [];
+  final Duration settle;
+
+  StreamSubscription<FolderChanged>? _events;
+  final Set<String?> _changed = {};
+  Timer? _flush;
+
+  FolderSubscriptions({
+    SyncEventBus? events,
+    this.settle = const Duration(milliseconds: 500),
+  }) {
+    _events = events?.on<FolderChanged>().listen(_onFolderChanged);
+  }
+
+  void _onFolderChanged(FolderChanged event) {
+    _changed.add(event.folderId);
+    _flush ??= Timer(settle, () {
+      _flush = null;
+      final changed = _changed.toList();
+      _changed.clear();
+      changed.forEach(notifyChanged);
+    });
+  }
+
+  /// Poll [folderId] at least every [interval] until the returned
+  /// subscription is cancelled. [onChanged] runs when a poll finds the
+  /// listing changed.
+  FolderSubscription subscribe(
+    String? folderId, {
+    Duration interval = const Duration(seconds: 15),
+    VoidCallback? onChanged,
+  }) {
+    final subscription = FolderSubscription._(
+      this,
+      folderId,
+      interval,
+      onChanged,
+    );
+    _active.add(subscription);
+    notifyListeners();
+    return subscription;
+  }
+
+  /// The shortest requested interval for [folderId], or null if nobody
+  /// subscribed to it.
+  Duration? intervalFor(String folderId) {
+    Duration? shortest;
+    for (final s in _active) {
+      if (s.folderId == folderId &&
+          (shortest == null || s.interval < shortest)) {
+        shortest = s.interval;
+      }
+    }
+    return shortest;
+  }
+
+  /// The shortest interval across all polled subscriptions.
+  Duration? get shortestInterval {
+    Duration? shortest;
+    for (final s in _active) {
+      if (s.folderId == null) continue;
+      if (shortest == null || s.interval < shortest) shortest = s.interval;
+    }
+    return shortest;
+  }
+
+  void notifyChanged(String? folderId) {
+    for (final s in _active.toList()) {
+      if (s.folderId == folderId) s.onChanged?.call();
+    }
+  }
+
+  void _remove(FolderSubscription subscription) {
+    if (_active.remove(subscription)) notifyListeners();
+  }
+
+  @override
+  void dispose() {
+    unawaited(_events?.cancel());
+    _flush?.cancel();
+    super.dispose();
+  }
+}
+
+class FolderSubscription {
+  final FolderSubscriptions _registry;
+  final String? folderId;
+  final Duration interval;
+  final VoidCallback? onChanged;
+
+  FolderSubscription._(
+    this._registry,
+    this.folderId,
+    this.interval,
+    this.onChanged,
+  );
+
+  void cancel() => _registry._remove(this);
+}
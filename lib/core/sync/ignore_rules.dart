@@ -0,0 +1,229 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as p;
+
+import '../platform/temp_files.dart';
+
+/// One line of an ignore file, in gitignore syntax.
+class IgnoreRule {
+  final RegExp _pattern;
+  final bool negated;
+  final bool directoryOnly;
+
+  IgnoreRule._(
+    this._pattern, {
+    required this.negated,
+    required this.directoryOnly,
+  });
+
+  /// Parse [line]; null for blank lines and comments.
+  static IgnoreRule? parse(String line) {
+    var glob = line.trimRight();
+    if (glob.isEmpty || glob.startsWith('#')) return null;
+
+    final negated = glob.startsWith('!');
+    if (negated || glob.startsWith(r'\!') || glob.startsWith(r'\#')) {
+      glob = glob.substring(1);
+    }
+    final directoryOnly = glob.endsWith('/');
+    if (directoryOnly) glob = glob.substring(0, glob.length - 1);
+    if (glob.isEmpty) return null;
+
+    // A slash anywhere but the end anchors the pattern to the ignore
+    // file's folder; otherwise it matches a name at any depth.
+    if (glob.startsWith('/')) {
+      glob = glob.substring(1);
+    } else if (!glob.contains('/')) {
+      glob = '**/$glob';
+    }
+    return IgnoreRule._(
+      globToRegExp(glob),
+      negated: negated,
+      directoryOnly: directoryOnly,
+    );
+  }
+
+  /// Whether the rule applies to [path], relative to the ignore file's
+  /// folder and separated by `/`.
+  bool matches(String path, {required bool isDirectory}) =>
+      (isDirectory || !directoryOnly) && _pattern.hasMatch(path);
+
+  /// `*` and `?` stay within one path segment, `**` spans any number of
+  /// them, and `[...]` is a character class (`[!...]` negated).
+  static RegExp globToRegExp(String glob) {
+    final buffer = StringBuffer('^');
+    var i = 0;
+    while (i < glob.length) {
+      final char = glob[i];
+      if (glob.startsWith('**/', i)) {
+        buffer.write('(?:.*/)?');
+        i += 3;
+        continue;
+      }
+      if (glob.startsWith('**', i)) {
+        buffer.write('.*');
+        i += 2;
+        continue;
+      }
+      final close = char == '[' ? glob.indexOf(']', i + 2) : -1;
+      if (close != -1) {
+        var members = glob.substring(i + 1, close).replaceAll(r'\', r'\\');
+        if (members.startsWith('!')) members = '^${members.substring(1)}';
+        buffer.write('[$members]');
+        i = close + 1;
+        continue;
+      }
+      buffer.write(switch (char) {
+        '*' => '[^/]*',
+        '?' => '[^/]',
+        _ => RegExp.escape(char),
+      });
+      i++;
+    }
+    buffer.write(r'$');
+    return RegExp(buffer.toString());
+  }
+}
+
+/// The rules of one ignore file. Later rules override earlier ones.
+class IgnoreRules {
+  final List<IgnoreRule> rules;
+
+  const IgnoreRules(this.rules);
+
+  static const empty = IgnoreRules([]);
+
+  factory IgnoreRules.parse(String content) => IgnoreRules([
+    for (final line in content.split('\n'))
+      ?IgnoreRule.parse(line.replaceAll('\r', '')),
+  ]);
+
+  /// True if [path] is ignored, false if a negated rule re-includes it and
+  /// null if no rule mentions it.
+  bool? decide(String path, {required bool isDirectory}) {
+    bool? ignored;
+    for (final rule in rules) {
+      if (rule.matches(path, isDirectory: isDirectory)) {
+        ignored = !rule.negated;
+      }
+    }
+    return ignored;
+  }
+}
+
+/// Answers whether a local path is excluded by the `.oxicloudignore` files
+/// of the folder it is uploaded from.
+///
+/// Each folder may have its own ignore file; rules in a deeper one take
+/// precedence, and everything inside an ignored folder is ignored too.
+/// Parsed files are cached and read again only when they change on disk.
+///
+/// Before any ignore file, [defaultPatterns] and the user's
+/// [extraPatterns] apply everywhere; an ignore file can re-include what
+/// they exclude with a negated rule.
+class IgnoreRuleCache {
+  static const fileName = '.oxicloudignore';
+
+  /// Editor lock and swap files, system metadata and partial downloads.
+  static const defaultPatterns = [
+    r'~$*',
+    '.~lock.*#',
+    '*.swp',
+    '*.tmp',
+    '*.part',
+    '.DS_Store',
+    'Thumbs.db',
+  ];
+
+  final Map<String, _CachedRules> _byFolder = {};
+  IgnoreRules _defaults;
+
+  IgnoreRuleCache({List<String> extraPatterns = const []})
+    : _defaults = _defaultRules(extraPatterns);
+
+  /// Patterns excluded everywhere in addition to [defaultPatterns].
+  set extraPatterns(List<String> patterns) {
+    _defaults = _defaultRules(patterns);
+  }
+
+  static IgnoreRules _defaultRules(List<String> extra) => IgnoreRules([
+    for (final pattern in [...defaultPatterns, ...extra])
+      ?IgnoreRule.parse(pattern),
+  ]);
+
+  /// The patterns in a comma- or line-separated list.
+  static List<String> parsePatterns(String text) => [
+    for (final pattern in text.split(RegExp(r'[,\n]')))
+      if (pattern.trim().isNotEmpty) pattern.trim(),
+  ];
+
+  Future<IgnoreRules> rulesFor(String folder) async {
+    final file = File(p.join(folder, fileName));
+    final stat = await file.stat();
+    if (stat.type == FileSystemEntityType.notFound) {
+      _byFolder.remove(folder);
+      return IgnoreRules.empty;
+    }
+    final cached = _byFolder[folder];
+    if (cached != null &&
+        cached.modified == stat.modified &&
+        cached.size == stat.size) {
+      return cached.rules;
+    }
+    final rules = IgnoreRules.parse(await file.readAsString());
+    _byFolder[folder] = _CachedRules(rules, stat.modified, stat.size);
+    return rules;
+  }
+
+  /// Whether [path], somewhere below [root], is ignored. Unfinished
+  /// downloads always are.
+  Future<bool> isIgnored(
+    String root,
+    String path, {
+    required bool isDirectory,
+  }) async {
+    if (TempFileAllocator.isTempName(p.basename(path))) return true;
+    final parts = p.split(p.relative(path, from: root));
+    for (var i = 1; i <= parts.length; i++) {
+      final last = i == parts.length;
+      final target = p.joinAll([root, ...parts.take(i)]);
+      if (await _matches(root, target, isDirectory: !last || isDirectory)) {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  Future<bool> _matches(
+    String root,
+    String path, {
+    required bool isDirectory,
+  }) async {
+    final folders = [root];
+    final parent = p.dirname(path);
+    if (parent != root) {
+      for (final part in p.split(p.relative(parent, from: root))) {
+        folders.add(p.join(folders.last, part));
+      }
+    }
+
+    var ignored = _defaults.decide(
+      p.split(p.relative(path, from: root)).join('/'),
+      isDirectory: isDirectory,
+    );
+    for (final folder in folders) {
+      final rules = await rulesFor(folder);
+      final relative = p.split(p.relative(path, from: folder)).join('/');
+      ignored = rules.decide(relative, isDirectory: isDirectory) ?? ignored;
+    }
+    return ignored ?? false;
+  }
+}
+
+class _CachedRules {
+  final IgnoreRules rules;
+  final DateTime modified;
+  final int size;
+
+  const _CachedRules(this.rules, this.modified, this.size);
+}
@@ -0,0 +1,81 @@
+import 'dart:convert';
+import 'dart:io';
+
+/// What uploading a local folder does with symbolic links in it.
+///
+/// Hard links are ordinary files to the file system, so each name is
+/// uploaded as a file of its own whatever the policy.
+enum LinkPolicy {
+  /// Leave links out.
+  skip,
+
+  /// Upload what a link points to under the link's name. Links back into
+  /// a folder above them are left out, so a loop cannot hang the walk.
+  follow,
+
+  /// Upload each link as a small file of type [LinkPolicy.mimeType] that
+  /// holds the path it points to.
+  metadata;
+
+  static const mimeType = 'inode/symlink';
+
+  /// [name] as stored, or [skip] for anything else.
+  static LinkPolicy parse(String? name) =>
+      values.asNameMap()[name] ?? LinkPolicy.skip;
+
+  String get label => switch (this) {
+    skip => 'Skip links',
+    follow => 'Follow links',
+    metadata => 'Upload links as link files',
+  };
+}
+
+enum LinkOutcome { skip, file, directory, metadata, loop, broken }
+
+/// How a folder walk handles one symbolic link under a [LinkPolicy].
+class LinkResolver {
+  final LinkPolicy policy;
+
+  const LinkResolver(this.policy);
+
+  /// Decide what to do with [link]. [ancestors] are the resolved paths of
+  /// the folders above it, the folder the walk started from included; a
+  /// link to one of them is a [LinkOutcome.loop]. [LinkOutcome.directory]
+  /// comes with the folder's resolved path, to add to the ancestors of
+  /// what is below it.
+  Future<(LinkOutcome, String?)> resolve(
+    Link link,
+    Set<String> ancestors,
+  ) async {
+    switch (policy) {
+      case LinkPolicy.skip:
+        return (LinkOutcome.skip, null);
+      case LinkPolicy.metadata:
+        return (LinkOutcome.metadata, await link.target());
+      case LinkPolicy.follow:
+        final String target;
+        try {
+          target = await link.resolveSymbolicLinks();
+        } on FileSystemException {
+          return (LinkOutcome.broken, null);
+        }
+        final type = await FileSystemEntity.type(target);
+        if (type == FileSystemEntityType.directory) {
+          final loop = ancestors.contains(target);
+          return (loop ? LinkOutcome.loop : LinkOutcome.directory, target);
+        }
+        final file = type == FileSystemEntityType.file;
+        return (file ? LinkOutcome.file : LinkOutcome.broken, target);
+    }
+  }
+
+  /// The contents of a link file for [target].
+  static List<int> metadataOf(String target) => utf8.encode(target);
+
+  /// Why a link was not uploaded, for reports.
+  static String reason(LinkOutcome outcome) => switch (outcome) {
+    LinkOutcome.loop => 'Link points back into a folder above it',
+    LinkOutcome.broken => 'Link target is missing',
+    _ => 'Symbolic link skipped',
+  };
+}
@@ -0,0 +1,293 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:drift/drift.dart';
+import 'package:logger/logger.dart';
+import 'package:path/path.dart' as p;
+
+import '../../domain/repositories/file_repository.dart';
+import '../database/app_database.dart';
+import 'checksum_negotiation.dart';
+import 'resource_throttle.dart';
+import 'sync_events.dart';
+import 'text_merge.dart';
+
+/// Outcome of one verification pass over the local copies.
+class LocalCopyReport {
+  int checked = 0;
+  final List<String> pendingDownload = [];
+  final List<String> pendingUpload = [];
+  final List<String> merged = [];
+  final List<String> conflicted = [];
+  final List<String> heldBack = [];
+
+  bool get isClean =>
+      pendingDownload.isEmpty &&
+      pendingUpload.isEmpty &&
+      merged.isEmpty &&
+      conflicted.isEmpty &&
+      heldBack.isEmpty;
+}
+
+/// Periodically re-reads downloaded files to catch copies that changed
+/// behind the app's back.
+///
+/// Every copy [track]ed after a download is checksummed again on each pass
+/// and compared with the checksum taken at download time, and its server
+/// ETag with the one it was downloaded at:
+///
+/// * content changed while the file's modification time did not: silent
+///   damage, flagged `pendingDownload`;
+/// * content changed along with the modification time: edited locally,
+///   flagged `pendingUpload`;
+/// * content intact but the server moved on: outdated, flagged
+///   `pendingDownload`;
+/// * edited locally while the server moved on too: text files are merged
+///   with the server version (see [TextMerge]) and stay `pendingUpload`,
+///   anything that does not merge is flagged `conflict`.
+///
+/// Some editors empty a file before writing it out again, so an edit that
+/// leaves a copy empty or under a tenth of its size (see
+/// [looksTruncated]) is flagged `truncated` and logged instead. It is only
+/// treated as a real edit if it still looks that way once
+/// [truncationWindow] has passed.
+///
+/// Copies that were deleted are forgotten. Newly flagged copies are
+/// published as an [IntegrityDiscrepancies] event. Scheduled passes are
+/// skipped while [ResourceThrottle] pauses background work.
+class LocalCopyVerifier {
+  final AppDatabase _db;
+  final SyncEventBus? _events;
+  final FileRepository? _files;
+  final ResourceThrottle? _throttle;
+  final Duration interval;
+
+  /// Local modification times are compared with the download time, which
+  /// the database keeps to the second.
+  final Duration clockSlack;
+
+  /// How long a suspicious truncation is held back before it counts as an
+  /// edit.
+  final Duration truncationWindow;
+
+  final Logger _logger = Logger();
+  static const _algorithm = ChecksumAlgorithm.sha256;
+  static const _merged = 'merged';
+  static const _truncated = 'truncated';
+
+  Timer? _timer;
+  Timer? _recheck;
+  Future<LocalCopyReport>? _running;
+
+  LocalCopyVerifier({
+    required AppDatabase db,
+    SyncEventBus? events,
+    FileRepository? files,
+    ResourceThrottle? throttle,
+    this.interval = const Duration(hours: 6),
+    this.clockSlack = const Duration(seconds: 2),
+    this.truncationWindow = const Duration(minutes: 5),
+  }) : _db = db,
+       _events = events,
+       _files = files,
+       _throttle = throttle;
+
+  void start() {
+    _timer?.cancel();
+    _timer = Timer.periodic(interval, (_) {
+      if (_throttle?.isPaused != true) unawaited(verifyNow());
+    });
+  }
+
+  void stop() {
+    _timer?.cancel();
+    _timer = null;
+    _recheck?.cancel();
+    _recheck = null;
+  }
+
+  /// Whether shrinking from [before] to [after] bytes looks like a file
+  /// caught halfway through being rewritten rather than a real edit.
+  static bool looksTruncated(int before, int after) =>
+      before > 0 && (after == 0 || after * 10 < before);
+
+  /// Remember [localPath] as the freshly downloaded copy of [fileId], at
+  /// the version the local database knows of.
+  Future<void> track(String fileId, String localPath) async {
+    final local = File(localPath);
+    final remote = await _db.getFileById(fileId);
+    final size = await local.length();
+    final now = DateTime.now();
+    await _db.upsertCachedFile(
+      CachedFilesTableCompanion.insert(
+        fileId: fileId,
+        localPath: localPath,
+        sizeBytes: size,
+        hash: Value(await _algorithm.digestStream(local.openRead())),
+        etag: Value(remote?.etag),
+        baseContent: Value(
+          size <= TextMerge.maxBytes &&
+                  TextMerge.isMergeable(localPath, mimeType: remote?.mimeType)
+              ? await _readText(local)
+              : null,
+        ),
+        cachedAt: now,
+        lastAccessedAt: now,
+        state: const Value('ok'),
+      ),
+    );
+  }
+
+  static Future<String?> _readText(File file) async {
+    try {
+      return await file.readAsString();
+    } on FileSystemException {
+      return null;
+    }
+  }
+
+  /// Check every tracked copy now. A pass already under way is joined
+  /// rather than started twice.
+  Future<LocalCopyReport> verifyNow() {
+    return _running ??= _verify().whenComplete(() => _running = null);
+  }
+
+  Future<LocalCopyReport> _verify() async {
+    final report = LocalCopyReport();
+    for (final copy in await _db.getCachedFiles()) {
+      try {
+        var state = await _check(copy);
+        if (state == null) continue;
+        report.checked++;
+        final name = p.basename(copy.localPath);
+        if (state == _merged) {
+          report.merged.add(name);
+          state = 'pendingUpload';
+        }
+        // A held copy keeps the time it was first flagged.
+        if (state == copy.state && state == _truncated) continue;
+        await _db.setCachedFileState(copy.fileId, state, DateTime.now());
+        if (state == copy.state) continue;
+        if (state == 'pendingDownload') report.pendingDownload.add(name);
+        if (state == 'pendingUpload') report.pendingUpload.add(name);
+        if (state == 'conflict') report.conflicted.add(name);
+        if (state == _truncated) report.heldBack.add(name);
+      } catch (e) {
+        _logger.w('Could not verify ${copy.localPath}', error: e);
+      }
+    }
+
+    if (!report.isClean) {
+      _events?.emit(
+        IntegrityDiscrepancies(
+          checked: report.checked,
+          pendingDownload: report.pendingDownload,
+          pendingUpload: report.pendingUpload,
+          merged: report.merged,
+          conflicted: report.conflicted,
+          heldBack: report.heldBack,
+        ),
+      );
+    }
+    return report;
+  }
+
+  /// The copy's new state, or null once it is gone.
+  Future<String?> _check(CachedFilesTableData copy) async {
+    final local = File(copy.localPath);
+    if (!await local.exists()) {
+      await _db.deleteCachedFile(copy.fileId);
+      return null;
+    }
+    if (copy.state == 'conflict') return 'conflict';
+
+    final remote = await _db.getFileById(copy.fileId);
+    final etag = remote?.etag;
+    final serverMoved = etag != null && copy.etag != null && etag != copy.etag;
+
+    final size = await local.length();
+    final changed =
+        size != copy.sizeBytes ||
+        await _algorithm.digestStream(local.openRead()) != copy.hash;
+    if (changed) {
+      // A merged copy is newer than its base without a newer mtime.
+      final edited =
+          copy.state == 'pendingUpload' ||
+          copy.state == _truncated ||
+          (await local.lastModified()).isAfter(copy.cachedAt.add(clockSlack));
+      if (!edited) return 'pendingDownload';
+      if (await _holdTruncation(copy, size)) return _truncated;
+      if (!serverMoved) return 'pendingUpload';
+      return await _merge(copy, local, etag) ? _merged : 'conflict';
+    }
+
+    return serverMoved ? 'pendingDownload' : 'ok';
+  }
+
+  /// Whether a local edit that left the copy [size] bytes long should
+  /// still be held back as a suspicious truncation. Flagging and
+  /// confirming one are both recorded in the activity log.
+  Future<bool> _holdTruncation(CachedFilesTableData copy, int size) async {
+    if (!looksTruncated(copy.sizeBytes, size)) return false;
+    final since = copy.state == _truncated ? copy.verifiedAt : null;
+    if (since != null && DateTime.now().difference(since) < truncationWindow) {
+      return true;
+    }
+    await _db.insertActivity(
+      ActivityLogTableCompanion.insert(
+        category: 'integrity',
+        action: 'truncation',
+        itemId: Value(copy.fileId),
+        itemName: Value(p.basename(copy.localPath)),
+        outcome: since == null ? 'held' : 'confirmed',
+        reason: Value('Shrank from ${copy.sizeBytes} to $size bytes'),
+        bytes: Value(size),
+        createdAt: DateTime.now(),
+      ),
+    );
+    if (since != null) return false;
+    // Look again once the window is up, unless passes are not scheduled.
+    if (_timer != null) {
+      _recheck?.cancel();
+      _recheck = Timer(truncationWindow, () => unawaited(verifyNow()));
+    }
+    return true;
+  }
+
+  /// Merge the server's version of a locally edited text file into the
+  /// local copy, which then counts as an edit of that version.
+  Future<bool> _merge(
+    CachedFilesTableData copy,
+    File local,
+    String etag,
+  ) async {
+    final files = _files;
+    final base = copy.baseContent;
+    if (files == null || base == null) return false;
+    try {
+      final remote = await utf8.decodeStream(
+        await files.downloadFile(copy.fileId),
+      );
+      final merged = TextMerge.merge(base, await local.readAsString(), remote);
+      if (merged == null) return false;
+      await local.writeAsString(merged);
+
+      final bytes = utf8.encode(remote);
+      await _db.upsertCachedFile(
+        copy
+            .copyWith(
+              sizeBytes: bytes.length,
+              hash: Value(_algorithm.digest(bytes)),
+              etag: Value(etag),
+              baseContent: Value(remote),
+              cachedAt: DateTime.now(),
+            )
+            .toCompanion(false),
+      );
+      return true;
+    } on FormatException {
+      return false;
+    }
+  }
+}
@@ -0,0 +1,111 @@
+import 'dart:async';
+
+import '../network/connectivity_service.dart';
+import '../platform/network_status.dart';
+import 'resource_throttle.dart';
+import 'sync_events.dart';
+
+/// What sync does on a connection charged by the amount of data.
+enum MeteredAction {
+  /// Sync as usual.
+  ignore,
+
+  /// One transfer at a time; roaming still pauses.
+  reduce,
+
+  /// Only transfers the user pushed to the front run.
+  pause;
+
+  static MeteredAction parse(String? name) => MeteredAction.values.firstWhere(
+    (a) => a.name == name,
+    orElse: () => MeteredAction.pause,
+  );
+
+  String get label => switch (this) {
+    MeteredAction.ignore => 'Sync as usual',
+    MeteredAction.reduce => 'Slow down',
+    MeteredAction.pause => 'Pause',
+  };
+}
+
+/// Watches whether the connection is metered or roaming and holds back
+/// sync through the [ResourceThrottle] while it is.
+///
+/// The network is checked every [interval] and whenever connectivity
+/// changes, as long as [action] is not [MeteredAction.ignore].
+class MeteredNetworkMonitor {
+  final NetworkStatusProbe _probe;
+  final ResourceThrottle _throttle;
+  final ConnectivityService? _connectivity;
+  final SyncEventBus? _events;
+  final Duration interval;
+
+  MeteredAction _action = MeteredAction.ignore;
+  NetworkStatus _status = NetworkStatus.unrestricted;
+  Timer? _timer;
+  StreamSubscription<bool>? _connectivitySub;
+
+  MeteredNetworkMonitor({
+    required ResourceThrottle throttle,
+    NetworkStatusProbe? probe,
+    ConnectivityService? connectivity,
+    SyncEventBus? events,
+    this.interval = const Duration(minutes: 1),
+  }) : _throttle = throttle,
+       _probe = probe ?? NetworkStatusProbe(),
+       _connectivity = connectivity,
+       _events = events;
+
+  NetworkStatus get status => _status;
+
+  MeteredAction get action => _action;
+
+  set action(MeteredAction value) {
+    if (value == _action) return;
+    _action = value;
+    _timer?.cancel();
+    _timer = null;
+    unawaited(_connectivitySub?.cancel());
+    _connectivitySub = null;
+    if (value != MeteredAction.ignore) {
+      _timer = Timer.periodic(interval, (_) => sample());
+      _connectivitySub = _connectivity?.onConnectivityChanged.listen(
+        (_) => sample(),
+      );
+      unawaited(sample());
+    }
+    _apply();
+  }
+
+  Future<void> sample() async {
+    if (_action == MeteredAction.ignore) return;
+    final status = await _probe.read() ?? NetworkStatus.unrestricted;
+    if (_action == MeteredAction.ignore || status == _status) return;
+    _status = status;
+    _apply();
+  }
+
+  void _apply() {
+    final level = levelFor(_action, _status);
+    if (level == _throttle.networkLevel) return;
+    _throttle.networkLevel = level;
+    _events?.emit(MeteredNetworkChanged(status: _status, level: level));
+  }
+
+  static ThrottleLevel levelFor(MeteredAction action, NetworkStatus status) {
+    if (action == MeteredAction.ignore || !status.isCostly) {
+      return ThrottleLevel.normal;
+    }
+    if (status.roaming || action == MeteredAction.pause) {
+      return ThrottleLevel.paused;
+    }
+    return ThrottleLevel.reduced;
+  }
+
+  void stop() {
+    _timer?.cancel();
+    _timer = null;
+    unawaited(_connectivitySub?.cancel());
+    _connectivitySub = null;
+  }
+}
@@ -0,0 +1,55 @@
+import 'package:drift/drift.dart' show Value;
+
+import '../database/app_database.dart';
+import 'sync_direction.dart';
+import 'sync_ordering.dart';
+
+/// Decides which deletions sync carries from one side to the other.
+///
+/// Backup folders, the ones set to [SyncDirection.uploadOnly], keep
+/// everything on both sides: deletions queued locally are not sent to the
+/// server, and local copies of files deleted on the server stay. Each
+/// deletion held back is written to the activity log instead.
+class PropagationPolicy {
+  final AppDatabase _db;
+
+  PropagationPolicy(this._db);
+
+  static const _remoteDelete = 'remoteDelete';
+
+  /// Whether [path] lies in a backup folder.
+  Future<bool> isBackup(String path) async {
+    final mode = await _db.getFolderSyncModeForPath(path);
+    return SyncDirection.parse(mode?.direction) == SyncDirection.uploadOnly;
+  }
+
+  /// Why the queued [op] must not reach the server, or null to apply it.
+  Future<String?> withhold(SyncQueueTableData op) async {
+    if (!SyncOrdering.isDeletion(op.operationType)) return null;
+    final path = op.itemType == 'folder'
+        ? (await _db.getFolderById(op.itemId))?.path
+        : (await _db.getFileById(op.itemId))?.path;
+    if (path == null || !await isBackup(path)) return null;
+    return 'Deleted in a backup folder; kept on the server';
+  }
+
+  /// Log the local copies in [folderId] that are kept although the server
+  /// no longer lists them, once per file. [listed] are the ids it lists.
+  Future<void> logRemoteDeletes(String folderId, Set<String> listed) async {
+    for (final file in await _db.getFilesInFolder(folderId)) {
+      if (listed.contains(file.id) || !file.isAvailableOffline) continue;
+      if (await _db.hasActivity(file.id, _remoteDelete)) continue;
+      await _db.insertActivity(
+        ActivityLogTableCompanion.insert(
+          category: 'sync',
+          action: _remoteDelete,
+          itemId: Value(file.id),
+          itemName: Value(file.name),
+          outcome: 'withheld',
+          reason: const Value('Deleted on the server; kept in a backup folder'),
+          createdAt: DateTime.now(),
+        ),
+      );
+    }
+  }
+}
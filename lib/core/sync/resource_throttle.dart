@@ -0,0 +1,107 @@
+import 'dart:async';
+
+import 'package:flutter/foundation.dart';
+
+import '../platform/system_load.dart';
+
+enum ThrottleLevel {
+  /// Work runs as configured.
+  normal,
+
+  /// One transfer at a time.
+  reduced,
+
+  /// Only transfers the user pushed to the front run; background scans
+  /// and checks wait.
+  paused,
+}
+
+/// Holds back background work while the machine is busy or hot, or the
+/// network costs money, and lets it go again once things calm down.
+///
+/// The system load is sampled every [interval]. Thresholds are per CPU
+/// core, and a level is only left once the load drops clearly below the
+/// threshold that set it, so the work does not flap on and off.
+class ResourceThrottle extends ChangeNotifier {
+  final SystemLoadProbe _probe;
+  final Duration interval;
+
+  static const reduceAt = 0.9;
+  static const pauseAt = 1.5;
+
+  /// How far below a threshold the load has to fall to go back a level.
+  static const margin = 0.2;
+
+  bool _enabled = false;
+  ThrottleLevel _level = ThrottleLevel.normal;
+  ThrottleLevel _networkLevel = ThrottleLevel.normal;
+  Timer? _timer;
+
+  ResourceThrottle({
+    SystemLoadProbe? probe,
+    this.interval = const Duration(seconds: 30),
+  }) : _probe = probe ?? SystemLoadProbe();
+
+  /// The stricter of the load and network levels.
+  ThrottleLevel get level =>
+      _networkLevel.index > _level.index ? _networkLevel : _level;
+
+  bool get isPaused => level == ThrottleLevel.paused;
+
+  /// How far the network connection alone holds work back.
+  ThrottleLevel get networkLevel => _networkLevel;
+
+  set networkLevel(ThrottleLevel value) {
+    if (value == _networkLevel) return;
+    _networkLevel = value;
+    notifyListeners();
+  }
+
+  bool get enabled => _enabled;
+
+  /// Turning throttling off releases any work held back right away.
+  set enabled(bool value) {
+    if (value == _enabled) return;
+    _enabled = value;
+    _timer?.cancel();
+    _timer = null;
+    if (value) {
+      _timer = Timer.periodic(interval, (_) => sample());
+      unawaited(sample());
+    } else {
+      _set(ThrottleLevel.normal);
+    }
+  }
+
+  Future<void> sample() async {
+    if (!_enabled) return;
+    final load = await _probe.read();
+    if (load != null && _enabled) _set(levelFor(_level, load));
+  }
+
+  void _set(ThrottleLevel level) {
+    if (level == _level) return;
+    _level = level;
+    notifyListeners();
+  }
+
+  static ThrottleLevel levelFor(ThrottleLevel current, SystemLoad load) {
+    if (load.thermalPressure || load.cpuLoad >= pauseAt) {
+      return ThrottleLevel.paused;
+    }
+    if (current == ThrottleLevel.paused && load.cpuLoad > pauseAt - margin) {
+      return ThrottleLevel.paused;
+    }
+    if (load.cpuLoad >= reduceAt) return ThrottleLevel.reduced;
+    if (current != ThrottleLevel.normal && load.cpuLoad > reduceAt - margin) {
+      return ThrottleLevel.reduced;
+    }
+    return ThrottleLevel.normal;
+  }
+
+  @override
+  void dispose() {
+    _timer?.cancel();
+    super.dispose();
+  }
+}
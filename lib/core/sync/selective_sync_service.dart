@@ -0,0 +1,32 @@
+import '../../domain/entities/folder_entity.dart';
+import '../database/app_database.dart';
+
+/// Keeps chosen remote folders out of the local mirror.
+///
+/// Excluded folders are still browsable while online, but their listings
+/// are not stored, the background scanner skips them and whatever was
+/// mirrored below them is dropped when they are excluded.
+class SelectiveSyncService {
+  final AppDatabase _db;
+
+  SelectiveSyncService(this._db);
+
+  Future<List<SyncExclusionsTableData>> list() => _db.getSyncExclusions();
+
+  Future<void> exclude(FolderEntity folder) async {
+    await _db.upsertSyncExclusion(
+      SyncExclusionsTableCompanion.insert(
+        folderId: folder.id,
+        path: folder.path,
+        createdAt: DateTime.now(),
+      ),
+    );
+    await _db.pruneMirror(folder.id, folder.path);
+  }
+
+  /// Mirror [folderId] again. Its contents come back the next time it is
+  /// listed.
+  Future<void> include(String folderId) async {
+    await _db.deleteSyncExclusion(folderId);
+  }
+}
@@ -0,0 +1,151 @@
+import 'dart:collection';
+import 'dart:math';
+
+import 'package:flutter/foundation.dart';
+import 'package:logger/logger.dart';
+
+import '../../domain/repositories/folder_repository.dart';
+import '../database/app_database.dart';
+import 'sync_events.dart';
+import 'sync_models.dart';
+
+/// Lists every folder of an account that has nothing mirrored yet, so a
+/// big account is complete locally before the background scanner takes
+/// over, and reports how far it got as [progress] and as
+/// [SetupScanProgressed] events. Folders excluded from sync are skipped.
+///
+/// The trees below the top-level folders are walked [concurrency] at a
+/// time. Each listing is stored as soon as it arrives, so the folders
+/// already listed can be browsed and synced while the rest is scanned.
+class SetupScan extends ChangeNotifier {
+  final AppDatabase _db;
+  final FolderRepository _folderRepo;
+  final SyncEventBus? _events;
+  final Duration notifyInterval;
+  final int concurrency;
+  final Logger _logger = Logger();
+
+  SetupProgress? _progress;
+  Future<SetupProgress?>? _running;
+  DateTime? _lastNotify;
+
+  SetupScan({
+    required AppDatabase db,
+    required FolderRepository folderRepo,
+    SyncEventBus? events,
+    this.notifyInterval = const Duration(milliseconds: 250),
+    this.concurrency = 4,
+  }) : _db = db,
+       _folderRepo = folderRepo,
+       _events = events;
+
+  /// Progress of the scan under way or last run, or null if none ran.
+  SetupProgress? get progress => _progress;
+
+  bool get isRunning => _running != null;
+
+  /// Scan the account unless folders of it are mirrored already.
+  Future<SetupProgress?> runIfNeeded() async {
+    if (_running == null && (await _db.getAllFolders()).isNotEmpty) {
+      return null;
+    }
+    return run();
+  }
+
+  /// Scan the account now. A scan already under way is joined rather than
+  /// started twice. Completes with null if the server cannot be reached.
+  Future<SetupProgress?> run() {
+    return _running ??= _scan().whenComplete(() => _running = null);
+  }
+
+  Future<SetupProgress?> _scan() async {
+    final storageUsed = (await _db.getCurrentUser())?.storageUsedBytes;
+    var discovered = 0;
+    var scanned = 0;
+    var files = 0;
+    var bytes = 0;
+
+    void report({bool done = false}) {
+      _progress = SetupProgress(
+        foldersDiscovered: discovered,
+        foldersScanned: scanned,
+        filesCounted: files,
+        bytesCounted: bytes,
+        estimatedTotalFiles: storageUsed == null || bytes == 0
+            ? null
+            : max(files, (storageUsed * files / bytes).round()),
+        done: done,
+      );
+      final now = DateTime.now();
+      if (!done &&
+          _lastNotify != null &&
+          now.difference(_lastNotify!) < notifyInterval) {
+        return;
+      }
+      _lastNotify = now;
+      _events?.emit(SetupScanProgressed(_progress!));
+      notifyListeners();
+    }
+
+    // Count a listing and return the subfolders to list next; null if it
+    // came from the local mirror because the server is out of reach.
+    Future<List<String>?> add(FolderContents contents) async {
+      if (contents.fromCache) return null;
+      final subfolders = <String>[];
+      for (final folder in contents.folders) {
+        if (await _db.isPathExcludedFromSync(folder.path)) continue;
+        discovered++;
+        subfolders.add(folder.id);
+      }
+      files += contents.files.length;
+      bytes += contents.files.fold(0, (sum, f) => sum + f.size);
+      return subfolders;
+    }
+
+    var unreachable = false;
+
+    // List the tree below one top-level folder, a folder at a time.
+    Future<void> walk(String topLevel) async {
+      final queue = Queue.of([topLevel]);
+      while (queue.isNotEmpty && !unreachable) {
+        final id = queue.removeFirst();
+        try {
+          final subfolders = await add(
+            await _folderRepo.listFolderContents(id),
+          );
+          if (subfolders == null) {
+            unreachable = true;
+            return;
+          }
+          queue.addAll(subfolders);
+        } catch (e) {
+          _logger.w('Could not list folder $id during setup', error: e);
+        }
+        scanned++;
+        report();
+      }
+    }
+
+    try {
+      final topLevel = await add(await _folderRepo.listRootContents());
+      if (topLevel == null) return null;
+      report();
+      var next = 0;
+      Future<void> worker() async {
+        while (next < topLevel.length && !unreachable) {
+          await walk(topLevel[next++]);
+        }
+      }
+
+      await Future.wait([
+        for (var w = 0; w < min(concurrency, topLevel.length); w++) worker(),
+      ]);
+      if (unreachable) return null;
+    } catch (e) {
+      _logger.w('Setup scan stopped', error: e);
+      return null;
+    }
+    report(done: true);
+    return _progress;
+  }
+}
@@ -0,0 +1,151 @@
+import 'dart:async';
+import 'dart:io';
+
+import 'package:drift/drift.dart';
+import 'package:logger/logger.dart';
+import 'package:path/path.dart' as p;
+
+import '../database/app_database.dart';
+import '../platform/disk_space.dart';
+import 'resource_throttle.dart';
+
+typedef _Candidate = ({CachedFilesTableData copy, DateTime used});
+
+/// When local copies are removed to free disk space.
+class StorageOptimizerSettings {
+  final bool enabled;
+
+  /// Copies not opened for this many days may be removed.
+  final int unusedDays;
+
+  /// Copies are removed while a disk has less than this many bytes free.
+  final int minFreeBytes;
+
+  const StorageOptimizerSettings({
+    this.enabled = false,
+    this.unusedDays = 30,
+    this.minFreeBytes = 10 * 1024 * 1024 * 1024,
+  });
+
+  StorageOptimizerSettings copyWith({
+    bool? enabled,
+    int? unusedDays,
+    int? minFreeBytes,
+  }) {
+    return StorageOptimizerSettings(
+      enabled: enabled ?? this.enabled,
+      unusedDays: unusedDays ?? this.unusedDays,
+      minFreeBytes: minFreeBytes ?? this.minFreeBytes,
+    );
+  }
+}
+
+/// Makes files online-only again when the disk runs low.
+///
+/// On each pass, every disk holding local copies with less than
+/// [StorageOptimizerSettings.minFreeBytes] free has copies removed, least
+/// recently used first, until it is back above the threshold or no copy
+/// qualifies. A copy qualifies once it has not been opened for
+/// [StorageOptimizerSettings.unusedDays], going by the later of its
+/// recorded access time and the file system's. Pinned copies, files kept
+/// available offline and copies with local edits or conflicts are never
+/// removed. Every removal is written to the activity log.
+class StorageOptimizer {
+  final AppDatabase _db;
+  final Future<int?> Function(String dir) _freeSpace;
+  final ResourceThrottle? _throttle;
+  final Duration interval;
+  final Logger _logger = Logger();
+
+  StorageOptimizerSettings settings = const StorageOptimizerSettings();
+
+  Timer? _timer;
+  Future<List<String>>? _running;
+
+  StorageOptimizer({
+    required AppDatabase db,
+    Future<int?> Function(String dir)? freeSpace,
+    ResourceThrottle? throttle,
+    this.interval = const Duration(hours: 1),
+  }) : _db = db,
+       _freeSpace = freeSpace ?? DiskSpace.dfFreeBytes,
+       _throttle = throttle;
+
+  void start() {
+    _timer?.cancel();
+    _timer = Timer.periodic(interval, (_) {
+      if (_throttle?.isPaused != true) unawaited(runNow());
+    });
+  }
+
+  void stop() {
+    _timer?.cancel();
+    _timer = null;
+  }
+
+  /// Run a pass now, returning the names of the files made online-only. A
+  /// pass already under way is joined rather than started twice.
+  Future<List<String>> runNow({DateTime? now}) {
+    return _running ??= _run(
+      now ?? DateTime.now(),
+    ).whenComplete(() => _running = null);
+  }
+
+  Future<List<String>> _run(DateTime now) async {
+    final removed = <String>[];
+    if (!settings.enabled) return removed;
+    final cutoff = now.subtract(Duration(days: settings.unusedDays));
+
+    final byDir = <String, List<_Candidate>>{};
+    for (final copy in await _db.getCachedFiles()) {
+      if (copy.isPinned || copy.state != 'ok') continue;
+      final file = File(copy.localPath);
+      if (!await file.exists()) continue;
+      final remote = await _db.getFileById(copy.fileId);
+      if (remote?.isAvailableOffline ?? false) continue;
+      final accessed = (await file.stat()).accessed;
+      final used = accessed.isAfter(copy.lastAccessedAt)
+          ? accessed
+          : copy.lastAccessedAt;
+      if (used.isAfter(cutoff)) continue;
+      byDir
+          .putIfAbsent(p.dirname(copy.localPath), () => [])
+          .add((copy: copy, used: used));
+    }
+
+    for (final MapEntry(key: dir, value: candidates) in byDir.entries) {
+      final measured = await _freeSpace(dir);
+      if (measured == null) continue;
+      var free = measured;
+      candidates.sort((a, b) => a.used.compareTo(b.used));
+      for (final (:copy, :used) in candidates) {
+        if (free >= settings.minFreeBytes) break;
+        try {
+          await File(copy.localPath).delete();
+        } on FileSystemException catch (e) {
+          _logger.w('Could not remove ${copy.localPath}', error: e);
+          continue;
+        }
+        await _db.deleteCachedFile(copy.fileId);
+        final name = p.basename(copy.localPath);
+        await _db.insertActivity(
+          ActivityLogTableCompanion.insert(
+            category: 'storage',
+            action: 'dehydrate',
+            itemId: Value(copy.fileId),
+            itemName: Value(name),
+            outcome: 'completed',
+            reason: Value(
+              'Not opened since ${used.toIso8601String().substring(0, 10)}',
+            ),
+            bytes: Value(copy.sizeBytes),
+            createdAt: now,
+          ),
+        );
+        removed.add(name);
+        free += copy.sizeBytes;
+      }
+    }
+    return removed;
+  }
+}
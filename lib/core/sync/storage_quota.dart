@@ -0,0 +1,209 @@
+import 'dart:math';
+
+import 'package:dio/dio.dart';
+import 'package:drift/drift.dart' show Value;
+import 'package:flutter/foundation.dart';
+import 'package:logger/logger.dart';
+
+import '../../domain/entities/user_entity.dart';
+import '../database/app_database.dart';
+import '../error/exceptions.dart';
+import '../network/dav_multistatus.dart';
+import 'sync_events.dart';
+
+/// Space used on the server and what is left of the account's quota.
+class StorageQuota {
+  final int usedBytes;
+
+  /// Null when the server sets no limit, or does not say.
+  final int? availableBytes;
+
+  const StorageQuota({required this.usedBytes, this.availableBytes});
+
+  int? get totalBytes =>
+      availableBytes == null ? null : usedBytes + availableBytes!;
+
+  /// Share of the quota in use, or null without a limit.
+  double? get fraction {
+    final total = totalBytes;
+    if (total == null || total == 0) return null;
+    return (usedBytes / total).clamp(0.0, 1.0);
+  }
+
+  bool get isFull => availableBytes != null && availableBytes! <= 0;
+
+  bool fits(int bytes) => availableBytes == null || bytes <= availableBytes!;
+
+  /// The quota reported in a PROPFIND response, or null if it has none.
+  static StorageQuota? parse(String xml) {
+    try {
+      return fromResource(MultistatusParser.parse(xml).firstOrNull);
+    } on FormatException {
+      return null;
+    }
+  }
+
+  /// The quota among the properties of [resource], or null if it has
+  /// none. Negative available bytes are how servers say there is no limit.
+  static StorageQuota? fromResource(DavResource? resource) {
+    final used = resource?.quotaUsedBytes;
+    if (used == null) return null;
+    final available = resource!.quotaAvailableBytes;
+    return StorageQuota(
+      usedBytes: used,
+      availableBytes: available == null || available < 0 ? null : available,
+    );
+  }
+}
+
+/// Keeps track of the account's storage quota, so uploads that cannot fit
+/// are stopped before they start rather than failing at the end.
+///
+/// The quota comes from a PROPFIND on the WebDAV root and is kept with the
+/// user in the database, so it is known before the first refresh. Uploads
+/// call [ensureRoom]; a server answering 507 anyway is recorded with
+/// [markFull]. Either way the user is told once with a
+/// [ServerStorageFull] event until space turns up again.
+class StorageQuotaService extends ChangeNotifier {
+  final Dio _dio;
+  final AppDatabase _db;
+  final String _webdavUrl;
+  final Future<UserEntity> Function() _currentUser;
+  final SyncEventBus? _events;
+  final Logger _logger = Logger();
+
+  StorageQuota? _quota;
+  bool _reported = false;
+
+  static const _propfind =
+      '<?xml version="1.0" encoding="utf-8"?>'
+      '<d:propfind xmlns:d="DAV:"><d:prop>'
+      '<d:quota-available-bytes/><d:quota-used-bytes/>'
+      '</d:prop></d:propfind>';
+
+  StorageQuotaService({
+    required Dio dio,
+    required AppDatabase db,
+    required String webdavUrl,
+    required Future<UserEntity> Function() currentUser,
+    SyncEventBus? events,
+  }) : _dio = dio,
+       _db = db,
+       _webdavUrl = webdavUrl,
+       _currentUser = currentUser,
+       _events = events;
+
+  /// The last known quota, or null before it was first fetched.
+  StorageQuota? get quota => _quota;
+
+  /// Pick up the quota stored last time.
+  Future<void> load() async {
+    final user = await _db.getCurrentUser();
+    final used = user?.storageUsedBytes;
+    if (used == null) return;
+    final total = user!.storageQuotaBytes;
+    _set(
+      StorageQuota(
+        usedBytes: used,
+        availableBytes: total == null ? null : max(0, total - used),
+      ),
+    );
+  }
+
+  /// Ask the server for the current quota and store it. Keeps the last
+  /// known one if the server cannot be reached or does not report it.
+  Future<StorageQuota?> refresh() async {
+    try {
+      final response = await _dio.request<ResponseBody>(
+        _webdavUrl,
+        data: _propfind,
+        options: Options(
+          method: 'PROPFIND',
+          headers: {'Depth': '0', 'Content-Type': 'application/xml'},
+          responseType: ResponseType.stream,
+        ),
+      );
+      final resources = await MultistatusParser.parseStream(
+        response.data!.stream,
+      );
+      final quota = StorageQuota.fromResource(resources.firstOrNull);
+      if (quota == null) return _quota;
+      await _store(quota);
+      _set(quota);
+    } catch (e) {
+      _logger.w('Could not fetch the storage quota', error: e);
+    }
+    return _quota;
+  }
+
+  /// Throw [QuotaExceededException] if [bytes] for [name] are known not to
+  /// fit in what is left on the server.
+  void ensureRoom(String name, int bytes) {
+    final quota = _quota;
+    if (quota == null || quota.fits(bytes)) return;
+    _report(bytes);
+    throw QuotaExceededException(
+      'Not enough space on the server for "$name": '
+      '${_mb(bytes)} MB needed, ${_mb(quota.availableBytes!)} MB left',
+    );
+  }
+
+  /// Count [bytes] just uploaded against the quota until the next refresh.
+  void used(int bytes) {
+    final quota = _quota;
+    if (quota == null) return;
+    _set(
+      StorageQuota(
+        usedBytes: quota.usedBytes + bytes,
+        availableBytes: quota.availableBytes == null
+            ? null
+            : max(0, quota.availableBytes! - bytes),
+      ),
+    );
+  }
+
+  /// The server refused an upload of [bytes] for lack of space.
+  void markFull(int bytes) {
+    _set(
+      StorageQuota(usedBytes: _quota?.usedBytes ?? 0, availableBytes: 0),
+    );
+    _report(bytes);
+  }
+
+  void _set(StorageQuota quota) {
+    _quota = quota;
+    if (!quota.isFull) _reported = false;
+    notifyListeners();
+  }
+
+  void _report(int bytes) {
+    if (_reported) return;
+    _reported = true;
+    _events?.emit(
+      ServerStorageFull(
+        required: bytes,
+        available: _quota?.availableBytes ?? 0,
+      ),
+    );
+  }
+
+  Future<void> _store(StorageQuota quota) async {
+    if (await _db.getCurrentUser() == null) {
+      final user = await _currentUser();
+      await _db.upsertUser(
+        UserTableCompanion.insert(
+          id: user.id,
+          username: user.username,
+          email: Value(user.email),
+          role: Value(user.role),
+        ),
+      );
+    }
+    await _db.setStorageQuota(
+      usedBytes: quota.usedBytes,
+      quotaBytes: quota.totalBytes,
+    );
+  }
+
+  static int _mb(int bytes) => (bytes / (1024 * 1024)).ceil();
+}
@@ -0,0 +1,73 @@
+import '../../domain/entities/folder_entity.dart';
+import '../database/app_database.dart';
+import 'sync_filter.dart';
+
+/// Which way changes flow for a folder.
+enum SyncDirection {
+  twoWay,
+
+  /// A backup: local files go up, and deletions on either side are kept
+  /// from the other; see [PropagationPolicy].
+  uploadOnly,
+
+  /// A mirror: the server's content comes down, but nothing is uploaded.
+  downloadOnly;
+
+  bool get allowsUpload => this != downloadOnly;
+
+  String get label => switch (this) {
+    twoWay => 'Two-way',
+    uploadOnly => 'Upload only (backup)',
+    downloadOnly => 'Download only (mirror)',
+  };
+
+  static SyncDirection parse(String? value) => SyncDirection.values.firstWhere(
+    (d) => d.name == value,
+    orElse: () => twoWay,
+  );
+}
+
+/// Per-folder sync directions, inherited by everything below a folder.
+class SyncDirectionService {
+  final AppDatabase _db;
+
+  SyncDirectionService(this._db);
+
+  Future<List<FolderSyncModesTableData>> list() => _db.getFolderSyncModes();
+
+  /// Set [folder]'s direction. Two-way is only stored where it overrides
+  /// a folder above.
+  Future<void> set(FolderEntity folder, SyncDirection direction) async {
+    await _db.deleteFolderSyncMode(folder.id);
+    if (await forPath(folder.path) == direction) return;
+    await _db.upsertFolderSyncMode(
+      FolderSyncModesTableCompanion.insert(
+        folderId: folder.id,
+        path: folder.path,
+        direction: direction.name,
+        createdAt: DateTime.now(),
+      ),
+    );
+  }
+
+  Future<SyncDirection> forPath(String path) async {
+    final mode = await _db.getFolderSyncModeForPath(path);
+    return SyncDirection.parse(mode?.direction);
+  }
+
+  /// The direction of folder [folderId]; the root always syncs both ways.
+  Future<SyncDirection> forFolder(String? folderId) async {
+    if (folderId == null) return SyncDirection.twoWay;
+    final folder = await _db.getFolderById(folderId);
+    if (folder == null) return SyncDirection.twoWay;
+    return forPath(folder.path);
+  }
+
+  /// Throws [SyncFilteredException] if [name] may not be uploaded into
+  /// [folderId].
+  Future<void> ensureUploadAllowed(String name, String? folderId) async {
+    if (!(await forFolder(folderId)).allowsUpload) {
+      throw SyncFilteredException(name, 'The folder is download-only');
+    }
+  }
+}
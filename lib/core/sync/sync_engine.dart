@@ -9,8 +9,17 @@ import '../../domain/repositories/file_repository.dart';
 import '../../domain/repositories/folder_repository.dart';
 import '../../domain/repositories/trash_repository.dart';
 import '../database/app_database.dart';
+import '../error/error_handler.dart';
+import '../error/exceptions.dart';
 import '../network/connectivity_service.dart';
+import 'conflict_resolver.dart';
+import 'error_budget.dart';
+import 'propagation_policy.dart';
+import 'sync_errors.dart';
+import 'sync_events.dart';
 import 'sync_models.dart';
+import 'sync_ordering.dart';
+import 'transfer_ledger_verifier.dart';
 
 class SyncEngine extends ChangeNotifier {
   final AppDatabase _db;
@@ -19,16 +28,81 @@ class SyncEngine extends ChangeNotifier {
   final FolderRepository _folderRepo;
   final FavoritesRepository _favoritesRepo;
   final TrashRepository _trashRepo;
+  final TransferLedgerVerifier? _ledgerVerifier;
+  final ConflictResolver? _conflictResolver;
+  final SyncEventBus? _events;
+
+  /// Holds back deletions in backup folders.
+  final PropagationPolicy? _propagation;
+
+  /// When an edit hits an item deleted on the server, restore the item
+  /// from the trash and apply the edit instead of dropping it.
+  final bool restoreDeletedOnEdit;
+
+  /// Share of failed requests above which an account syncs less often.
+  final double maxFailureRate;
+
+  /// Delay before an operation that failed is tried again.
+  final SyncRetryPolicy retryPolicy;
+
+  /// Delay between checks for a server that could not be reached.
+  final SyncRetryPolicy probePolicy;
+
+  /// Completes if the server answers; used to find out when an unreachable
+  /// server is back. Without it, a whole sync pass is the check.
+  final Future<void> Function()? _probe;
   StreamSubscription<bool>? _connectivitySub;
 
+  static const _ifMatchKey = 'if_match';
+
+  /// Most operations run in one sync pass, so a long queue does not keep
+  /// the engine busy indefinitely.
+  static const _passSize = 10;
+
+  /// Operations that change an item rather than create or delete it.
+  static const _editOperations = {
+    'rename',
+    'move',
+    'renameFolder',
+    'moveFolder',
+  };
+
+  static const _operationTypes = {
+    'delete',
+    'rename',
+    'move',
+    'createFolder',
+    'deleteFolder',
+    'renameFolder',
+    'moveFolder',
+    'favorite',
+    'unfavorite',
+    'trash',
+    'restore',
+  };
+
   SyncStatus _status = SyncStatus.idle;
   SyncStatus get status => _status;
 
   int _pendingCount = 0;
   int get pendingCount => _pendingCount;
 
+  /// Items with an error in the [SyncErrorCenter], as of the last pass.
+  int _failedCount = 0;
+  int get failedCount => _failedCount;
+
   Timer? _syncTimer;
   bool _isSyncing = false;
+  bool _shuttingDown = false;
+  Completer<void>? _syncDone;
+  int _cycleCompleted = 0;
+  int _cycleFailed = 0;
+  final Map<String, ErrorBudget> _budgets = {};
+  ErrorBudget? _budget;
+  int _skippedTicks = 0;
+  bool _serverUnreachable = false;
+  Timer? _probeTimer;
+  int _probeAttempt = 0;
 
   SyncEngine({
     required AppDatabase db,
@@ -37,12 +111,26 @@ class SyncEngine extends ChangeNotifier {
     required FolderRepository folderRepo,
     required FavoritesRepository favoritesRepo,
     required TrashRepository trashRepo,
+    TransferLedgerVerifier? ledgerVerifier,
+    ConflictResolver? conflictResolver,
+    SyncEventBus? events,
+    PropagationPolicy? propagation,
+    this.restoreDeletedOnEdit = true,
+    this.maxFailureRate = 0.3,
+    this.retryPolicy = const SyncRetryPolicy(),
+    this.probePolicy = const SyncRetryPolicy(max: Duration(minutes: 5)),
+    Future<void> Function()? probe,
   }) : _db = db,
        _connectivity = connectivity,
        _fileRepo = fileRepo,
        _folderRepo = folderRepo,
        _favoritesRepo = favoritesRepo,
-       _trashRepo = trashRepo {
+       _trashRepo = trashRepo,
+       _ledgerVerifier = ledgerVerifier,
+       _conflictResolver = conflictResolver,
+       _events = events,
+       _propagation = propagation,
+       _probe = probe {
     _connectivitySub = _connectivity.onConnectivityChanged.listen(
       (_) => _onConnectivityChanged(),
     );
@@ -50,78 +138,354 @@ class SyncEngine extends ChangeNotifier {
 
   void start({Duration interval = const Duration(seconds: 30)}) {
     _syncTimer?.cancel();
-    _syncTimer = Timer.periodic(interval, (_) => sync());
-    sync();
+    _syncTimer = Timer.periodic(interval, (_) => _onTick());
+    // Ops left 'inProgress' by a crash would otherwise never be retried.
+    recover().then((_) => sync());
+  }
+
+  /// Settle the operations a crash or kill interrupted, as the journal
+  /// recorded them: one the server turns out to have applied already is
+  /// completed instead of sent again, the rest go back in the queue.
+  Future<void> recover() async {
+    for (final entry in await _db.getUnfinishedOperations()) {
+      final op = await _db.getSyncOp(entry.syncOpId);
+      final applied =
+          op != null &&
+          op.status == 'inProgress' &&
+          _connectivity.isOnline &&
+          await _isApplied(op);
+      await _db.finishOperation(entry.id, applied ? 'done' : 'failed');
+      if (!applied) continue;
+      await _db.updateSyncOpStatus(op.id, 'completed');
+      await _logDecision(op, 'completed', reason: 'applied before a restart');
+    }
+    await _db.resetInProgressSyncOps();
+    await _db.pruneOperations(DateTime.now().subtract(const Duration(days: 7)));
+  }
+
+  /// Whether the server already shows the change [op] asks for. Only
+  /// deletions, renames and moves can be told apart; anything else is
+  /// assumed not to have gone through.
+  Future<bool> _isApplied(SyncQueueTableData op) async {
+    final payload = json.decode(op.payload) as Map<String, dynamic>;
+    final folder = op.itemType == 'folder';
+    try {
+      switch (op.operationType) {
+        case 'delete' || 'deleteFolder' || 'trash':
+          await (folder
+              ? _folderRepo.getFolder(op.itemId)
+              : _fileRepo.getFile(op.itemId));
+          return false;
+        case 'rename':
+          final file = await _fileRepo.getFile(op.itemId);
+          return file.name == payload['new_name'];
+        case 'move':
+          final file = await _fileRepo.getFile(op.itemId);
+          return file.folderId == payload['target_folder_id'];
+        case 'renameFolder':
+          final f = await _folderRepo.getFolder(op.itemId);
+          return f.name == payload['new_name'];
+        case 'moveFolder':
+          final f = await _folderRepo.getFolder(op.itemId);
+          return f.parentId == payload['new_parent_id'];
+        default:
+          return false;
+      }
+    } on NotFoundException {
+      return SyncOrdering.isDeletion(op.operationType);
+    } catch (_) {
+      return false;
+    }
+  }
+
+  /// The error budget of the signed-in account, once it has synced.
+  ErrorBudget? get errorBudget => _budget;
+
+  /// While the account's requests keep failing, only every
+  /// [ErrorBudget.backoff]th tick syncs. Syncs the user triggers are not
+  /// held back.
+  void _onTick() {
+    if (_serverUnreachable) return;
+    final backoff = _budget?.backoff ?? 1;
+    if (++_skippedTicks < backoff) return;
+    _skippedTicks = 0;
+    unawaited(sync());
   }
 
   void stop() {
     _syncTimer?.cancel();
     _syncTimer = null;
+    _probeTimer?.cancel();
+    _probeTimer = null;
+  }
+
+  /// True from the time a request could not reach the server until a
+  /// check or sync pass gets through again. Queued operations wait, and
+  /// do not use up their retries, in the meantime.
+  bool get serverUnreachable => _serverUnreachable;
+
+  /// Stop accepting new work, let the operation in flight finish (bounded
+  /// by [timeout]) and flush the journal to disk. An operation still
+  /// running is left in progress for [recover] to settle at the next
+  /// start.
+  Future<void> shutdown({
+    Duration timeout = const Duration(seconds: 10),
+  }) async {
+    if (_shuttingDown) return;
+    _shuttingDown = true;
+    stop();
+
+    final inFlight = _syncDone?.future;
+    if (inFlight != null) {
+      await inFlight.timeout(timeout, onTimeout: () {});
+    }
+    await _db.flushJournal();
   }
 
   Future<void> sync() async {
+    if (_shuttingDown) return;
     if (_isSyncing || !_connectivity.isOnline) {
       _setStatus(_connectivity.isOnline ? SyncStatus.idle : SyncStatus.offline);
       return;
     }
 
     _isSyncing = true;
+    _syncDone = Completer<void>();
+    _cycleCompleted = 0;
+    _cycleFailed = 0;
+    _serverUnreachable = false;
+    _probeTimer?.cancel();
+    _probeTimer = null;
     _setStatus(SyncStatus.syncing);
 
     try {
-      final pendingOps = await _db.getPendingSyncOps();
-      _pendingCount = pendingOps.length;
+      // Budgets are per account, so signing in elsewhere starts afresh.
+      final account = (await _db.getCurrentUser())?.id ?? '';
+      _budget = _budgets.putIfAbsent(
+        account,
+        () => ErrorBudget(maxFailureRate: maxFailureRate),
+      );
+      _pendingCount = (await _db.getPendingSyncOps()).length;
       notifyListeners();
 
-      for (final op in pendingOps) {
-        if (!_connectivity.isOnline) break;
+      // Pick before each operation, so one bumped while the pass runs goes
+      // next once nothing it depends on is left.
+      final attempted = <int>{};
+      while (attempted.length < _passSize &&
+          _connectivity.isOnline &&
+          !_serverUnreachable &&
+          !_shuttingDown) {
+        final due = await _db.dueSyncOps(
+          skip: attempted,
+          dueBy: DateTime.now(),
+        );
+        final op = SyncOrdering.next(due, paths: await _deletedPaths(due));
+        if (op == null) break;
+        attempted.add(op.id);
         await _processOp(op);
       }
 
+      if (_serverUnreachable) {
+        _scheduleProbe();
+        _setStatus(SyncStatus.offline);
+        return;
+      }
+      _probeAttempt = 0;
+
+      if (!_shuttingDown) await _ledgerVerifier?.verify();
+
       _setStatus(SyncStatus.idle);
     } catch (e) {
       _setStatus(SyncStatus.error);
     } finally {
       _isSyncing = false;
-      _pendingCount = (await _db.getPendingSyncOps()).length;
-      notifyListeners();
+      _syncDone?.complete();
+      _syncDone = null;
+      final budget = _budget;
+      if (budget != null && budget.endPass()) {
+        _skippedTicks = 0;
+        _events?.emit(
+          SyncBackoffChanged(
+            backoff: budget.backoff,
+            failureRate: budget.failureRate,
+          ),
+        );
+      }
+      if (!_shuttingDown) {
+        _pendingCount = (await _db.getPendingSyncOps()).length;
+        _failedCount = (await _db.getSyncErrors()).length;
+        notifyListeners();
+      }
+      _events?.emit(
+        SyncCycleFinished(
+          completed: _cycleCompleted,
+          failed: _cycleFailed,
+          remaining: _pendingCount,
+        ),
+      );
+    }
+  }
+
+  /// Dry run: what the next sync pass would do with each pending operation,
+  /// in order, without contacting the server or changing the queue.
+  ///
+  /// A file operation pinned to an ETag is reported as a conflict when the
+  /// cached copy of the file shows a newer version on the server; the
+  /// prediction is only as fresh as the local cache.
+  Future<List<PlannedSyncAction>> plan() async {
+    final actions = <PlannedSyncAction>[];
+    final pending = await _db.getPendingSyncOps();
+    final ordered = SyncOrdering.sort(
+      pending,
+      paths: await _deletedPaths(pending),
+    );
+    for (final op in ordered) {
+      final file = op.itemType == 'file'
+          ? await _db.getFileById(op.itemId)
+          : null;
+      final folder = op.itemType == 'folder'
+          ? await _db.getFolderById(op.itemId)
+          : null;
+      final payload = _tryDecode(op.payload);
+      final ifMatch = payload?[_ifMatchKey] as String?;
+      final etag = file?.etag;
+
+      var outcome = PlannedOutcome.apply;
+      String? reason;
+      if (!_operationTypes.contains(op.operationType)) {
+        outcome = PlannedOutcome.skip;
+        reason = 'Unknown operation';
+      } else if (payload == null) {
+        outcome = PlannedOutcome.skip;
+        reason = 'Unreadable payload';
+      } else if (ifMatch != null && etag != null && etag != ifMatch) {
+        outcome = PlannedOutcome.conflict;
+        reason = 'Changed on the server since it was queued';
+      } else if (await _propagation?.withhold(op) case final withheld?) {
+        outcome = PlannedOutcome.skip;
+        reason = withheld;
+      }
+
+      actions.add(
+        PlannedSyncAction(
+          opId: op.id,
+          operationType: op.operationType,
+          itemType: op.itemType,
+          itemId: op.itemId,
+          path: file?.path ?? folder?.path,
+          outcome: outcome,
+          reason: reason,
+        ),
+      );
+    }
+    return actions;
+  }
+
+  /// Paths of the items [ops] delete, for deleting bottom-up.
+  Future<Map<String, String>> _deletedPaths(
+    List<SyncQueueTableData> ops,
+  ) async {
+    final paths = <String, String>{};
+    for (final op in ops) {
+      if (!SyncOrdering.isDeletion(op.operationType)) continue;
+      final path = op.itemType == 'folder'
+          ? (await _db.getFolderById(op.itemId))?.path
+          : (await _db.getFileById(op.itemId))?.path;
+      if (path != null) paths[op.itemId] = path;
+    }
+    return paths;
+  }
+
+  static Map<String, dynamic>? _tryDecode(String payload) {
+    try {
+      return json.decode(payload) as Map<String, dynamic>;
+    } catch (_) {
+      return null;
     }
   }
 
   Future<void> enqueue(SyncTask task) async {
+    if (await _db.isSyncItemIgnored(task.entityId)) return;
     final now = DateTime.now();
+    final payload = {...?task.payload};
+    final file = task.entityType == 'file'
+        ? await _db.getFileById(task.entityId)
+        : null;
+    // Pin file changes to the version the user saw, so they are not applied
+    // over an edit made on the server in the meantime. ETags from external
+    // storage change on their own and would only cause false conflicts.
+    final etag = file?.mountType == null ? file?.etag : null;
+    if (etag != null && !payload.containsKey(_ifMatchKey)) {
+      payload[_ifMatchKey] = etag;
+    }
     await _db.insertSyncOp(
       SyncQueueTableCompanion.insert(
         operationType: task.operation.name,
         itemId: task.entityId,
         itemType: task.entityType,
-        payload: task.payload?.toString() ?? '{}',
+        payload: json.encode(payload),
         createdAt: task.createdAt,
         updatedAt: now,
+        priority: Value(
+          SyncPriority.of(userInitiated: task.userInitiated, size: file?.size),
+        ),
       ),
     );
     _pendingCount++;
     notifyListeners();
-    if (_connectivity.isOnline) {
+    _events?.emit(
+      ItemQueued(
+        itemId: task.entityId,
+        itemType: task.entityType,
+        operation: task.operation.name,
+      ),
+    );
+    if (_connectivity.isOnline && !_shuttingDown) {
       await sync();
     }
   }
 
+  /// Move the queued operations on [itemId] to the front of the queue.
+  /// Returns false if nothing is queued for it.
+  Future<bool> bump(String itemId) async {
+    final changed = await _db.setSyncOpPriority(itemId, SyncPriority.bumped);
+    if (changed == 0) return false;
+    notifyListeners();
+    if (_connectivity.isOnline && !_shuttingDown) unawaited(sync());
+    return true;
+  }
+
   Future<void> _processOp(SyncQueueTableData op) async {
+    int? entry;
     try {
       await _db.updateSyncOpStatus(op.id, 'inProgress');
+      entry = await _db.startOperation(op);
       final payload = json.decode(op.payload) as Map<String, dynamic>;
+      final ifMatch = payload[_ifMatchKey] as String?;
+
+      // Settled without touching the server; only the log records it.
+      final withheld = await _propagation?.withhold(op);
+      if (withheld != null) {
+        await _db.updateSyncOpStatus(op.id, 'completed');
+        await _db.finishOperation(entry, 'done');
+        await _logDecision(op, 'withheld', reason: withheld);
+        return;
+      }
 
       switch (op.operationType) {
         // --- File operations ---
         case 'delete':
-          await _fileRepo.deleteFile(op.itemId);
+          await _fileRepo.deleteFile(op.itemId, ifMatch: ifMatch);
         case 'rename':
           final newName = payload['new_name'] as String;
-          await _fileRepo.renameFile(op.itemId, newName);
+          await _fileRepo.renameFile(op.itemId, newName, ifMatch: ifMatch);
         case 'move':
           final targetFolderId = payload['target_folder_id'] as String;
-          await _fileRepo.moveFile(op.itemId, targetFolderId);
+          await _fileRepo.moveFile(
+            op.itemId,
+            targetFolderId,
+            ifMatch: ifMatch,
+          );
 
         // --- Folder operations ---
         case 'createFolder':
@@ -146,7 +510,7 @@ class SyncEngine extends ChangeNotifier {
         // --- Trash ---
         case 'trash':
           if (op.itemType == 'file') {
-            await _fileRepo.deleteFile(op.itemId);
+            await _fileRepo.deleteFile(op.itemId, ifMatch: ifMatch);
           } else {
             await _folderRepo.deleteFolder(op.itemId);
           }
@@ -154,40 +518,222 @@ class SyncEngine extends ChangeNotifier {
           await _trashRepo.restoreItem(op.itemId);
 
         default:
-          await _db.updateSyncOpStatus(
-            op.id,
-            'failed',
-            errorMessage: 'Unknown operation: ${op.operationType}',
-          );
+          final reason = 'Unknown operation: ${op.operationType}';
+          await _db.finishOperation(entry, 'failed');
+          await _db.updateSyncOpStatus(op.id, 'failed', errorMessage: reason);
+          await _recordError(op, SyncErrorCategory.unknown, reason);
+          await _logDecision(op, 'failed', reason: reason);
+          _failed(op, reason);
           return;
       }
 
       await _db.updateSyncOpStatus(op.id, 'completed');
+      await _db.finishOperation(entry, 'done');
+      await _db.clearSyncError(op.itemId);
+      await _logDecision(op, 'completed');
+      _budget?.record(ok: true);
+      _completed(op);
     } catch (e) {
+      // Cut off by shutdown: whether the server applied it is only known
+      // at the next start, so leave it in progress with its entry open.
+      if (_shuttingDown && e is RequestCancelledException) return;
+      if (entry != null) {
+        await _db.finishOperation(
+          entry,
+          _alreadyApplied(op, e) ? 'done' : 'failed',
+        );
+      }
+      if (e is ServerUnreachableException) {
+        // Not the operation's fault: leave it queued and wait for the
+        // server instead of spending its retries.
+        await _db.updateSyncOpStatus(
+          op.id,
+          'pending',
+          errorMessage: e.toString(),
+        );
+        _serverUnreachable = true;
+        return;
+      }
+      if (e is ServerException && ErrorHandler.isTransient(e)) {
+        // The server is busy or briefly broken and already had its
+        // retries; try again later without marking the item failed or
+        // spending its own retries, waiting as long as the server asks.
+        _budget?.record(ok: false);
+        final delay =
+            e.retryAfter ?? retryPolicy.delayFor(op.transientRetryCount + 1);
+        await _db.scheduleSyncOpRetry(
+          op.id,
+          DateTime.now().add(delay),
+          errorMessage: e.toString(),
+          transient: true,
+        );
+        await _logDecision(op, 'retry', reason: e.toString());
+        return;
+      }
+      if (_alreadyApplied(op, e)) {
+        await _db.updateSyncOpStatus(op.id, 'completed');
+        await _db.clearSyncError(op.itemId);
+        await _logDecision(op, 'completed', reason: 'already applied');
+        _budget?.record(ok: true);
+        _completed(op);
+        return;
+      }
+      _budget?.record(ok: false);
+      await _recordError(op, SyncErrorCategory.of(e), e.toString());
+      final deletedRemotely =
+          e is NotFoundException &&
+          _editOperations.contains(op.operationType);
+      if (deletedRemotely && restoreDeletedOnEdit && await _restore(op)) {
+        return;
+      }
+      // A file changed or deleted on the server won't come back by retrying;
+      // let the user (or their rules) decide right away.
       final newRetryCount = op.retryCount + 1;
-      if (newRetryCount >= 5) {
+      if (newRetryCount >= 5 ||
+          deletedRemotely ||
+          e is PreconditionFailedException) {
         await _db.updateSyncOpStatus(
           op.id,
           'failed',
           errorMessage: e.toString(),
         );
-        // Record as sync conflict for user resolution
-        await _db.insertSyncConflict(
+        final conflictType = deletedRemotely
+            ? 'deleted_remotely'
+            : e is PreconditionFailedException
+            ? ConflictResolver.modifiedRemotely
+            : 'retry_exhausted';
+        // Record as sync conflict; the user's rules get the first say
+        final conflictId = await _db.insertSyncConflict(
           SyncConflictsTableCompanion.insert(
             itemId: op.itemId,
             itemType: op.itemType,
             operationType: op.operationType,
+            conflictType: Value(conflictType),
             payload: op.payload,
             errorMessage: Value(e.toString()),
             createdAt: DateTime.now(),
+            queuedAt: Value(op.createdAt),
           ),
         );
+        await _logDecision(op, 'conflict', reason: e.toString());
+        _failed(op, e.toString());
+        await _conflictResolver?.autoResolve(conflictId);
       } else {
-        await _db.incrementSyncOpRetry(op.id);
+        await _db.scheduleSyncOpRetry(
+          op.id,
+          DateTime.now().add(retryPolicy.delayFor(newRetryCount)),
+          errorMessage: e.toString(),
+        );
+        await _logDecision(op, 'retry', reason: e.toString());
       }
     }
   }
 
+  /// The item [op] edits was deleted on the server while the edit waited in
+  /// the queue. Restore it from the trash, note that in the conflict log and
+  /// put the edit back in the queue. False if the item is not in the trash.
+  Future<bool> _restore(SyncQueueTableData op) async {
+    try {
+      await _trashRepo.restoreItem(op.itemId);
+    } catch (_) {
+      return false;
+    }
+    const reason = 'Deleted on the server; restored from the trash';
+    final conflictId = await _db.insertSyncConflict(
+      SyncConflictsTableCompanion.insert(
+        itemId: op.itemId,
+        itemType: op.itemType,
+        operationType: op.operationType,
+        conflictType: const Value('deleted_remotely'),
+        payload: op.payload,
+        errorMessage: const Value(reason),
+        createdAt: DateTime.now(),
+        queuedAt: Value(op.createdAt),
+      ),
+    );
+    await _db.resolveConflict(conflictId, 'restored', resolvedBy: 'policy');
+    await _db.updateSyncOpStatus(op.id, 'pending');
+    await _logDecision(op, 'restored', reason: reason);
+    return true;
+  }
+
+  Future<void> _recordError(
+    SyncQueueTableData op,
+    SyncErrorCategory category,
+    String message,
+  ) {
+    return _db.recordSyncError(
+      itemId: op.itemId,
+      itemType: op.itemType,
+      operationType: op.operationType,
+      category: category.name,
+      message: message,
+    );
+  }
+
+  void _completed(SyncQueueTableData op) {
+    _cycleCompleted++;
+    _events?.emit(ItemCompleted(itemId: op.itemId, action: op.operationType));
+  }
+
+  void _failed(SyncQueueTableData op, String reason) {
+    _cycleFailed++;
+    _events?.emit(
+      ItemFailed(itemId: op.itemId, action: op.operationType, reason: reason),
+    );
+  }
+
+  /// A replayed operation can find the server already in the state it asks
+  /// for when an earlier attempt went through but its reply was lost.
+  static bool _alreadyApplied(SyncQueueTableData op, Object error) {
+    return switch (op.operationType) {
+      'delete' || 'deleteFolder' || 'trash' => error is NotFoundException,
+      'createFolder' => error is ConflictException,
+      _ => false,
+    };
+  }
+
+  Future<void> _logDecision(
+    SyncQueueTableData op,
+    String outcome, {
+    String? reason,
+  }) {
+    return _db.insertActivity(
+      ActivityLogTableCompanion.insert(
+        category: 'sync',
+        action: op.operationType,
+        itemId: Value(op.itemId),
+        outcome: outcome,
+        reason: Value(reason),
+        createdAt: DateTime.now(),
+      ),
+    );
+  }
+
+  void _scheduleProbe() {
+    // Only a started engine syncs on its own.
+    if (_shuttingDown || _syncTimer == null) return;
+    _probeTimer?.cancel();
+    _probeTimer = Timer(
+      probePolicy.delayFor(++_probeAttempt),
+      () => unawaited(_runProbe()),
+    );
+  }
+
+  /// Check whether the server is back and drain the queue if it is. A
+  /// lost network is left to the connectivity listener.
+  Future<void> _runProbe() async {
+    _probeTimer = null;
+    if (_shuttingDown || !_connectivity.isOnline) return;
+    try {
+      await _probe?.call();
+    } catch (_) {
+      _scheduleProbe();
+      return;
+    }
+    await sync();
+  }
+
   void _onConnectivityChanged() {
     if (_connectivity.isOnline) {
       sync();
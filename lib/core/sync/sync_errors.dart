@@ -0,0 +1,80 @@
+import 'dart:io';
+
+import '../database/app_database.dart';
+import '../error/exceptions.dart';
+
+/// What kind of problem stopped a queued change, for grouping errors and
+/// suggesting what to do about them.
+enum SyncErrorCategory {
+  auth,
+  permission,
+  notFound,
+  conflict,
+  quota,
+  server,
+  local,
+  unknown;
+
+  static SyncErrorCategory of(Object error) => switch (error) {
+    UnauthorizedException() => auth,
+    ForbiddenException() => permission,
+    NotFoundException() => notFound,
+    ConflictException() || PreconditionFailedException() => conflict,
+    QuotaExceededException() => quota,
+    ServerException() => server,
+    FileSystemException() => local,
+    _ => unknown,
+  };
+
+  /// [name] as stored, or [unknown] for names from a later version.
+  static SyncErrorCategory parse(String name) =>
+      values.asNameMap()[name] ?? unknown;
+
+  String get label => switch (this) {
+    auth => 'Signed out',
+    permission => 'No permission',
+    notFound => 'Missing on the server',
+    conflict => 'Changed on the server',
+    quota => 'Server storage full',
+    server => 'Server error',
+    local => 'Local file problem',
+    unknown => 'Error',
+  };
+}
+
+/// The items whose queued changes failed, and what the user can do about
+/// them: retry one or all, or stop syncing an item for good.
+///
+/// `SyncEngine` records an error on every failed attempt and clears it
+/// once a change to the item goes through.
+class SyncErrorCenter {
+  final AppDatabase _db;
+  final Future<void> Function() _sync;
+
+  SyncErrorCenter({
+    required AppDatabase db,
+    required Future<void> Function() sync,
+  }) : _db = db,
+       _sync = sync;
+
+  Future<List<SyncErrorsTableData>> failed() => _db.getSyncErrors();
+
+  Future<List<SyncErrorsTableData>> ignored() =>
+      _db.getSyncErrors(ignored: true);
+
+  /// Run the changes queued for [itemId] again now, with fresh retries.
+  Future<void> retry(String itemId) async {
+    await _db.retrySyncOps(itemId);
+    await _sync();
+  }
+
+  Future<void> retryAll() async {
+    await _db.retrySyncOps();
+    await _sync();
+  }
+
+  /// Drop the changes queued for [itemId] and queue no new ones.
+  Future<void> ignore(String itemId) => _db.ignoreSyncItem(itemId);
+
+  Future<void> unignore(String itemId) => _db.unignoreSyncItem(itemId);
+}
@@ -0,0 +1,300 @@
+import 'dart:async';
+
+import '../network/tls_trust.dart';
+import '../platform/network_status.dart';
+import 'resource_throttle.dart';
+import 'sync_models.dart';
+
+/// Something that happened while syncing or transferring, published on the
+/// [SyncEventBus] so the UI can show live progress without polling.
+sealed class SyncEvent {
+  final DateTime at;
+
+  SyncEvent({DateTime? at}) : at = at ?? DateTime.now();
+}
+
+/// An operation was added to the sync queue.
+class ItemQueued extends SyncEvent {
+  final String itemId;
+  final String itemType;
+  final String operation;
+
+  ItemQueued({
+    required this.itemId,
+    required this.itemType,
+    required this.operation,
+    super.at,
+  });
+}
+
+/// An upload or download moved forward. [total] is 0 when the size is not
+/// known yet.
+class TransferProgressed extends SyncEvent {
+  final String transferId;
+  final String name;
+  final int bytes;
+  final int total;
+
+  TransferProgressed({
+    required this.transferId,
+    required this.name,
+    required this.bytes,
+    required this.total,
+    super.at,
+  });
+}
+
+/// The listing of [folderId] (null for the root) changed through this
+/// app: something was added to, removed from, renamed in or moved out of
+/// it.
+class FolderChanged extends SyncEvent {
+  final String? folderId;
+
+  FolderChanged({required this.folderId, super.at});
+}
+
+/// A queued operation or a transfer finished. For a transfer, [itemId] is
+/// the transfer id, [action] its direction, and [bytes] and [elapsed] how
+/// much it moved in how long.
+class ItemCompleted extends SyncEvent {
+  final String itemId;
+  final String action;
+  final String? name;
+  final int? bytes;
+  final Duration? elapsed;
+
+  ItemCompleted({
+    required this.itemId,
+    required this.action,
+    this.name,
+    this.bytes,
+    this.elapsed,
+    super.at,
+  });
+}
+
+/// A queued operation or a transfer gave up. A queued operation that will
+/// be retried does not count.
+class ItemFailed extends SyncEvent {
+  final String itemId;
+  final String action;
+  final String? name;
+  final String? reason;
+
+  ItemFailed({
+    required this.itemId,
+    required this.action,
+    this.name,
+    this.reason,
+    super.at,
+  });
+}
+
+/// A sync pass over the queue ended. [remaining] is what is still queued,
+/// including operations waiting for a retry.
+class SyncCycleFinished extends SyncEvent {
+  final int completed;
+  final int failed;
+  final int remaining;
+
+  SyncCycleFinished({
+    required this.completed,
+    required this.failed,
+    required this.remaining,
+    super.at,
+  });
+}
+
+/// The signed-in account's sync slowed down because too many of its
+/// requests failed, or went back to normal. [backoff] is how many sync
+/// intervals now make up one; 1 means normal.
+class SyncBackoffChanged extends SyncEvent {
+  final int backoff;
+  final double failureRate;
+
+  SyncBackoffChanged({
+    required this.backoff,
+    required this.failureRate,
+    super.at,
+  });
+}
+
+/// New files in the inbox folder were handled. [message] sums it up for
+/// the user.
+class InboxArrivals extends SyncEvent {
+  final int sorted;
+  final int kept;
+  final int flagged;
+  final int failed;
+  final String message;
+
+  InboxArrivals({
+    required this.sorted,
+    required this.kept,
+    required this.flagged,
+    required this.failed,
+    required this.message,
+    super.at,
+  });
+}
+
+/// A check of local copies found some that no longer match what was
+/// downloaded. [pendingDownload] names the damaged or outdated ones,
+/// [pendingUpload] the ones edited locally since, of which [merged] took in
+/// the server's changes too. [conflicted] were edited on both sides and
+/// could not be merged.
+class IntegrityDiscrepancies extends SyncEvent {
+  final int checked;
+  final List<String> pendingDownload;
+  final List<String> pendingUpload;
+  final List<String> merged;
+  final List<String> conflicted;
+
+  /// Copies emptied or cut short locally, held back from upload for now.
+  final List<String> heldBack;
+
+  IntegrityDiscrepancies({
+    required this.checked,
+    required this.pendingDownload,
+    required this.pendingUpload,
+    this.merged = const [],
+    this.conflicted = const [],
+    this.heldBack = const [],
+    super.at,
+  });
+
+  String get message {
+    final count =
+        pendingDownload.length +
+        pendingUpload.length +
+        conflicted.length +
+        heldBack.length;
+    final copies = count == 1 ? '1 local copy' : '$count local copies';
+    return [
+      '$copies no longer match the server',
+      if (conflicted.isNotEmpty) '${conflicted.length} with conflicting edits',
+      if (heldBack.isNotEmpty)
+        '${heldBack.length} held back after being emptied or truncated',
+    ].join(', ');
+  }
+}
+
+/// The first listing of an account made progress.
+class SetupScanProgressed extends SyncEvent {
+  final SetupProgress progress;
+
+  SetupScanProgressed(this.progress, {super.at});
+}
+
+/// Downloads need more space than the disk holding [path] has left.
+/// [paused] downloads were stopped and can be resumed once space is freed.
+class DiskFull extends SyncEvent {
+  final String path;
+  final int required;
+  final int available;
+  final int paused;
+
+  DiskFull({
+    required this.path,
+    required this.required,
+    required this.available,
+    this.paused = 0,
+    super.at,
+  });
+
+  String get message {
+    final missing = ((required - available) / (1024 * 1024)).ceil();
+    final resume = paused == 0
+        ? ''
+        : paused == 1
+        ? ', then resume the paused download'
+        : ', then resume the $paused paused downloads';
+    return 'Not enough disk space for downloads in $path. '
+        'Free up at least $missing MB$resume.';
+  }
+}
+
+/// The account's storage on the server is full, or has less than
+/// [required] bytes left, so uploads are held back.
+class ServerStorageFull extends SyncEvent {
+  final int required;
+  final int available;
+
+  ServerStorageFull({required this.required, this.available = 0, super.at});
+
+  String get message {
+    if (available == 0) {
+      return 'Your storage on the server is full. Uploads are stopped until '
+          'you free up space.';
+    }
+    final missing = ((required - available) / (1024 * 1024)).ceil();
+    return 'Not enough storage left on the server. Free up at least '
+        '$missing MB to upload.';
+  }
+}
+
+/// Sync was held back or let go because the connection became metered or
+/// roaming, or stopped being so.
+class MeteredNetworkChanged extends SyncEvent {
+  final NetworkStatus status;
+  final ThrottleLevel level;
+
+  MeteredNetworkChanged({required this.status, required this.level, super.at});
+
+  String get message {
+    final why = status.roaming ? 'roaming' : 'on a metered connection';
+    return switch (level) {
+      ThrottleLevel.paused => 'Sync is paused while $why.',
+      ThrottleLevel.reduced => 'Sync is slowed down while $why.',
+      ThrottleLevel.normal => 'Sync resumed.',
+    };
+  }
+}
+
+/// A server presented a certificate that is not trusted; requests to it
+/// fail until the user accepts it.
+class UntrustedCertificateSeen extends SyncEvent {
+  final UntrustedCertificate certificate;
+
+  UntrustedCertificateSeen(this.certificate, {super.at});
+}
+
+/// A server item named `name` that cannot be stored next to `clashesWith`
+/// on this machine, and a name that would fit.
+typedef CaseClash = ({String name, String clashesWith, String suggestion});
+
+/// Server items were found that differ from a sibling only in case, so
+/// this machine cannot store both. Each comes with a suggested new name.
+class CaseConflictsFound extends SyncEvent {
+  final List<CaseClash> clashes;
+
+  CaseConflictsFound(this.clashes, {super.at});
+
+  String get message {
+    if (clashes.length > 1) {
+      return '${clashes.length} items differ from another only in case and '
+          'cannot be stored side by side. Rename them on the server.';
+    }
+    final c = clashes.single;
+    return '${c.name} and ${c.clashesWith} differ only in case. '
+        'Rename ${c.name}, for example to ${c.suggestion}.';
+  }
+}
+
+/// Broadcasts [SyncEvent]s to any number of listeners. Events emitted while
+/// nobody listens are dropped.
+class SyncEventBus {
+  final StreamController<SyncEvent> _controller =
+      StreamController<SyncEvent>.broadcast();
+
+  Stream<SyncEvent> get events => _controller.stream;
+
+  /// Only the events of type [T].
+  Stream<T> on<T extends SyncEvent>() => events.where((e) => e is T).cast<T>();
+
+  void emit(SyncEvent event) {
+    if (!_controller.isClosed) _controller.add(event);
+  }
+
+  Future<void> dispose() => _controller.close();
+}
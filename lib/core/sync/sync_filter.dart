@@ -0,0 +1,101 @@
+import 'package:path/path.dart' as p;
+
+/// Which files take part in syncing, by size and type.
+///
+/// Types are file extensions (`iso`, `.iso`) or MIME types, where `*`
+/// stands for any subtype (`video/*`). A file matching [exclude] is
+/// ignored; when [include] is not empty, so is any file matching none of
+/// it. Files above [maxFileSizeBytes] are ignored too, unless it is 0.
+class SyncFilter {
+  final int maxFileSizeBytes;
+  final List<String> include;
+  final List<String> exclude;
+
+  const SyncFilter({
+    this.maxFileSizeBytes = 0,
+    this.include = const [],
+    this.exclude = const [],
+  });
+
+  static const none = SyncFilter();
+
+  bool get isEmpty =>
+      maxFileSizeBytes == 0 && include.isEmpty && exclude.isEmpty;
+
+  /// Why a file named [name] is left out of syncing, or null if it syncs.
+  String? reasonToIgnore(String name, {required int size, String? mimeType}) {
+    if (maxFileSizeBytes > 0 && size > maxFileSizeBytes) {
+      return 'Larger than the ${_formatSize(maxFileSizeBytes)} limit';
+    }
+    final excluded = _firstMatch(exclude, name, mimeType);
+    if (excluded != null) return 'Excluded type ($excluded)';
+    if (include.isNotEmpty && _firstMatch(include, name, mimeType) == null) {
+      return 'Not one of the included types';
+    }
+    return null;
+  }
+
+  static String? _firstMatch(
+    List<String> types,
+    String name,
+    String? mimeType,
+  ) {
+    final ext = p.extension(name).toLowerCase();
+    final mime = mimeType?.toLowerCase();
+    for (final type in types) {
+      final t = type.trim().toLowerCase();
+      if (t.isEmpty) continue;
+      if (t.contains('/')) {
+        if (mime == null) continue;
+        final matches = t.endsWith('/*')
+            ? mime.startsWith(t.substring(0, t.length - 1))
+            : mime == t;
+        if (matches) return type;
+      } else if (ext.isNotEmpty && ext == (t.startsWith('.') ? t : '.$t')) {
+        return type;
+      }
+    }
+    return null;
+  }
+
+  /// The types in a comma- or space-separated list such as `iso, video/*`.
+  static List<String> parseTypes(String text) => [
+    for (final t in text.split(RegExp(r'[,\s]+')))
+      if (t.isNotEmpty) t,
+  ];
+
+  SyncFilter copyWith({
+    int? maxFileSizeBytes,
+    List<String>? include,
+    List<String>? exclude,
+  }) {
+    return SyncFilter(
+      maxFileSizeBytes: maxFileSizeBytes ?? this.maxFileSizeBytes,
+      include: include ?? this.include,
+      exclude: exclude ?? this.exclude,
+    );
+  }
+
+  static String _formatSize(int bytes) {
+    const units = ['bytes', 'KB', 'MB', 'GB', 'TB'];
+    var value = bytes.toDouble();
+    var unit = 0;
+    while (value >= 1024 && unit < units.length - 1) {
+      value /= 1024;
+      unit++;
+    }
+    final digits = unit == 0 || value == value.roundToDouble() ? 0 : 1;
+    return '${value.toStringAsFixed(digits)} ${units[unit]}';
+  }
+}
+
+/// [name] was not transferred because the [SyncFilter] ignores it.
+class SyncFilteredException implements Exception {
+  final String name;
+  final String reason;
+
+  const SyncFilteredException(this.name, this.reason);
+
+  @override
+  String toString() => '$name is ignored: $reason';
+}
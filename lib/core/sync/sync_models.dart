@@ -2,6 +2,75 @@ enum SyncStatus { idle, syncing, error, offline }
 
 enum SyncOperation { upload, download, delete, rename, move }
 
+enum PlannedOutcome { apply, conflict, skip }
+
+/// Order of the sync queue: the highest priority runs first, then the
+/// oldest. Operations the user asked for go before background ones, and
+/// small files before large ones within each.
+class SyncPriority {
+  SyncPriority._();
+
+  /// Moved to the front with `SyncEngine.bump`.
+  static const bumped = 100;
+
+  static const smallFileThreshold = 8 * 1024 * 1024;
+
+  /// [size] is null for folders and files not in the local cache; those are
+  /// treated as small.
+  static int of({required bool userInitiated, int? size}) {
+    final small = size == null || size <= smallFileThreshold;
+    return (userInitiated ? 20 : 0) + (small ? 10 : 0);
+  }
+}
+
+/// How long to wait before trying again: [base] after the first failure,
+/// doubling with each further one up to [max].
+class SyncRetryPolicy {
+  final Duration base;
+  final Duration max;
+
+  const SyncRetryPolicy({
+    this.base = const Duration(seconds: 5),
+    this.max = const Duration(hours: 1),
+  });
+
+  Duration delayFor(int attempt) {
+    if (attempt <= 1) return base < max ? base : max;
+    final factor = 1 << (attempt - 1).clamp(0, 30);
+    final delay = base * factor;
+    return delay < max ? delay : max;
+  }
+}
+
+/// What a sync pass would do with one queued operation; see
+/// `SyncEngine.plan`.
+class PlannedSyncAction {
+  final int opId;
+  final String operationType;
+  final String itemType;
+  final String itemId;
+
+  /// Cached path of the item, if it is known locally.
+  final String? path;
+  final PlannedOutcome outcome;
+  final String? reason;
+
+  const PlannedSyncAction({
+    required this.opId,
+    required this.operationType,
+    required this.itemType,
+    required this.itemId,
+    this.path,
+    required this.outcome,
+    this.reason,
+  });
+
+  @override
+  String toString() =>
+      '$operationType $itemType ${path ?? itemId}: ${outcome.name}'
+      '${reason == null ? '' : ' ($reason)'}';
+}
+
 class SyncTask {
   final String id;
   final SyncOperation operation;
@@ -11,6 +80,9 @@ class SyncTask {
   final int retryCount;
   final DateTime createdAt;
 
+  /// False for work the app queues on its own; it waits behind the user's.
+  final bool userInitiated;
+
   const SyncTask({
     required this.id,
     required this.operation,
@@ -19,5 +91,40 @@ class SyncTask {
     this.payload,
     this.retryCount = 0,
     required this.createdAt,
+    this.userInitiated = true,
   });
 }
+
+/// How far the first full listing of an account has got.
+class SetupProgress {
+  final int foldersDiscovered;
+  final int foldersScanned;
+  final int filesCounted;
+  final int bytesCounted;
+
+  /// Files the account is expected to hold, extrapolated from the files
+  /// counted so far and the account's storage use. Null while unknown.
+  final int? estimatedTotalFiles;
+  final bool done;
+
+  const SetupProgress({
+    required this.foldersDiscovered,
+    required this.foldersScanned,
+    required this.filesCounted,
+    required this.bytesCounted,
+    this.estimatedTotalFiles,
+    this.done = false,
+  });
+
+  /// Share of the account listed so far, from the estimated total if there
+  /// is one and from the folders still to list otherwise.
+  double? get fraction {
+    if (done) return 1.0;
+    final total = estimatedTotalFiles;
+    if (total != null && total > 0) {
+      return (filesCounted / total).clamp(0.0, 0.99);
+    }
+    if (foldersDiscovered == 0) return null;
+    return (foldersScanned / foldersDiscovered).clamp(0.0, 0.99);
+  }
+}
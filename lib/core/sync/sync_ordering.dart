@@ -0,0 +1,89 @@
+import 'dart:convert';
+
+import '../database/app_database.dart';
+
+/// Dependencies between queued operations, so that they reach the server in
+/// an order it can apply.
+///
+/// Operations go in stages: creations and restores first, then renames and
+/// moves, then other updates such as favorites, and deletions last. Within
+/// a stage, a folder is created before folders created inside it, and
+/// deletions run bottom-up so a folder goes after what it contains.
+/// Priority only decides between operations that do not depend on each
+/// other.
+class SyncOrdering {
+  SyncOrdering._();
+
+  static int stageOf(String operationType) => switch (operationType) {
+    'createFolder' || 'restore' => 0,
+    'rename' || 'move' || 'renameFolder' || 'moveFolder' => 1,
+    'delete' || 'trash' || 'deleteFolder' => 3,
+    _ => 2,
+  };
+
+  static bool isDeletion(String operationType) => stageOf(operationType) == 3;
+
+  /// The first of [ops], which are in priority order, that no other
+  /// operation in [ops] has to precede. [paths] gives the path of the items
+  /// deleted, by item id.
+  static SyncQueueTableData? next(
+    List<SyncQueueTableData> ops, {
+    Map<String, String> paths = const {},
+  }) {
+    for (final op in ops) {
+      if (!ops.any((other) => mustPrecede(other, op, paths))) return op;
+    }
+    // Only a cycle gets here; fall back to priority order.
+    return ops.isEmpty ? null : ops.first;
+  }
+
+  /// [ops] in the order [next] would pick them.
+  static List<SyncQueueTableData> sort(
+    List<SyncQueueTableData> ops, {
+    Map<String, String> paths = const {},
+  }) {
+    final left = [...ops];
+    final sorted = <SyncQueueTableData>[];
+    while (left.isNotEmpty) {
+      final op = next(left, paths: paths)!;
+      left.remove(op);
+      sorted.add(op);
+    }
+    return sorted;
+  }
+
+  /// Whether [a] has to reach the server before [b].
+  static bool mustPrecede(
+    SyncQueueTableData a,
+    SyncQueueTableData b,
+    Map<String, String> paths,
+  ) {
+    if (identical(a, b)) return false;
+    final stageA = stageOf(a.operationType);
+    final stageB = stageOf(b.operationType);
+    if (stageA != stageB) return stageA < stageB;
+
+    if (a.operationType == 'createFolder') {
+      return _referencedFolders(b).contains(a.itemId);
+    }
+    if (stageA == 3) {
+      final pathA = paths[a.itemId];
+      final pathB = paths[b.itemId];
+      return pathA != null && pathB != null && pathA.startsWith('$pathB/');
+    }
+    return false;
+  }
+
+  /// Folders [op] puts something into.
+  static Set<String> _referencedFolders(SyncQueueTableData op) {
+    try {
+      final payload = json.decode(op.payload) as Map<String, dynamic>;
+      return {
+        for (final key in ['parent_id', 'target_folder_id', 'new_parent_id'])
+          if (payload[key] is String) payload[key] as String,
+      };
+    } catch (_) {
+      return const {};
+    }
+  }
+}
@@ -0,0 +1,39 @@
+import '../../domain/entities/file_entity.dart';
+
+/// How carefully sync treats an item, depending on the storage it lives on.
+///
+/// External storages mounted into the account, such as SMB shares or S3
+/// buckets, often report ETags that change while the content does not, and
+/// may list a folder as empty while the backend is briefly unreachable.
+/// Items on them are compared by hash, or size and modification time, not
+/// by ETag, and files that vanish from a listing keep their local copy
+/// unless [propagateDeletes] is set.
+class SyncProfile {
+  final bool trustEtags;
+  final bool propagateDeletes;
+
+  const SyncProfile({this.trustEtags = true, this.propagateDeletes = true});
+
+  static const standard = SyncProfile();
+
+  /// [standard] for the server's own storage, the conservative profile for
+  /// a [mountType] of external storage.
+  static SyncProfile forMount(
+    String? mountType, {
+    bool propagateExternalDeletes = false,
+  }) {
+    if (mountType == null) return standard;
+    return SyncProfile(
+      trustEtags: false,
+      propagateDeletes: propagateExternalDeletes,
+    );
+  }
+
+  /// A string that changes whenever [file]'s content does.
+  String versionOf(FileEntity file) {
+    final etag = file.etag;
+    if (trustEtags && etag != null) return etag;
+    return file.hash ??
+        '${file.size}:${file.modifiedAt.millisecondsSinceEpoch}';
+  }
+}
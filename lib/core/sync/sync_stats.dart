@@ -0,0 +1,132 @@
+import 'dart:async';
+
+import 'package:logger/logger.dart';
+
+import '../database/app_database.dart';
+import 'sync_events.dart';
+
+/// Totals over a range of days; see [SyncStatsCollector.summary].
+class SyncStatsSummary {
+  final int bytesUploaded;
+  final int bytesDownloaded;
+  final int filesSynced;
+  final int errors;
+  final Duration transferTime;
+
+  const SyncStatsSummary({
+    this.bytesUploaded = 0,
+    this.bytesDownloaded = 0,
+    this.filesSynced = 0,
+    this.errors = 0,
+    this.transferTime = Duration.zero,
+  });
+
+  factory SyncStatsSummary.of(Iterable<SyncStatsTableData> days) {
+    var summary = const SyncStatsSummary();
+    for (final d in days) {
+      summary = SyncStatsSummary(
+        bytesUploaded: summary.bytesUploaded + d.bytesUploaded,
+        bytesDownloaded: summary.bytesDownloaded + d.bytesDownloaded,
+        filesSynced: summary.filesSynced + d.filesSynced,
+        errors: summary.errors + d.errors,
+        transferTime:
+            summary.transferTime + Duration(milliseconds: d.transferMillis),
+      );
+    }
+    return summary;
+  }
+
+  /// Average speed of the completed transfers, in bytes per second.
+  double get averageBytesPerSecond {
+    final ms = transferTime.inMilliseconds;
+    if (ms == 0) return 0;
+    return (bytesUploaded + bytesDownloaded) * 1000 / ms;
+  }
+}
+
+/// Keeps daily totals of what syncing did, from the [SyncEventBus], and
+/// when each folder last synced.
+///
+/// Completed transfers add their bytes and duration; they and applied
+/// queued changes count as synced files. Failures count as errors, except
+/// transfers the user cancelled. Folders are marked synced when a change
+/// in them is applied; `FolderScanScheduler` does the same for successful
+/// refreshes.
+class SyncStatsCollector {
+  final AppDatabase _db;
+  final SyncEventBus _events;
+  final Logger _logger = Logger();
+  StreamSubscription<SyncEvent>? _sub;
+
+  SyncStatsCollector({required AppDatabase db, required SyncEventBus events})
+    : _db = db,
+      _events = events;
+
+  void start() {
+    _sub ??= _events.events.listen(record);
+  }
+
+  Future<void> stop() async {
+    await _sub?.cancel();
+    _sub = null;
+  }
+
+  static const _transfers = {'upload', 'download'};
+
+  Future<void> record(SyncEvent event) async {
+    final day = DateTime(event.at.year, event.at.month, event.at.day);
+    try {
+      switch (event) {
+        case ItemCompleted(:final action) when _transfers.contains(action):
+          final bytes = event.bytes ?? 0;
+          await _db.addSyncStats(
+            day,
+            bytesUploaded: action == 'upload' ? bytes : 0,
+            bytesDownloaded: action == 'download' ? bytes : 0,
+            filesSynced: 1,
+            transferMillis: event.elapsed?.inMilliseconds ?? 0,
+          );
+        case ItemCompleted():
+          await _db.addSyncStats(day, filesSynced: 1);
+          final folderId = await _folderOf(event.itemId);
+          if (folderId != null) await _db.markFolderSynced(folderId, event.at);
+        case ItemFailed(:final reason) when reason != 'Cancelled':
+          await _db.addSyncStats(day, errors: 1);
+        default:
+          break;
+      }
+    } catch (e) {
+      _logger.w('Could not record sync statistics', error: e);
+    }
+  }
+
+  /// The folder a queued change applied to lives in, or the folder itself.
+  Future<String?> _folderOf(String itemId) async {
+    final file = await _db.getFileById(itemId);
+    if (file != null) return file.folderId;
+    return (await _db.getFolderById(itemId))?.id;
+  }
+
+  /// Totals from [since] (a day, counted whole) to now; everything kept
+  /// when null.
+  Future<SyncStatsSummary> summary({DateTime? since}) async {
+    final from = since == null
+        ? null
+        : DateTime(since.year, since.month, since.day);
+    return SyncStatsSummary.of(await _db.getSyncStats(since: from));
+  }
+
+  /// Daily totals from [since] on, oldest first, for charts.
+  Future<List<SyncStatsTableData>> daily({DateTime? since}) =>
+      _db.getSyncStats(since: since);
+
+  /// Each folder's path and when it last synced, most recent first.
+  Future<List<({String path, DateTime lastSyncedAt})>> folders() async {
+    final paths = {for (final f in await _db.getAllFolders()) f.id: f.path};
+    return [
+      for (final s in await _db.getFolderSyncStats())
+        if (paths[s.folderId] != null)
+          (path: paths[s.folderId]!, lastSyncedAt: s.lastSyncedAt),
+    ];
+  }
+}
@@ -0,0 +1,160 @@
+import 'dart:math';
+
+import 'package:path/path.dart' as p;
+
+/// Line-based three-way merge of text files changed on both sides.
+///
+/// Changes to different parts of the file are combined; changes that touch
+/// the same or adjacent lines only merge when both sides made the same
+/// edit. Anything else is a conflict and left alone.
+class TextMerge {
+  TextMerge._();
+
+  /// Base content is only kept for files up to this size.
+  static const maxBytes = 512 * 1024;
+
+  /// Above this many compared line pairs a merge is not attempted.
+  static const _maxCells = 4000000;
+
+  static const _extensions = {'.txt', '.md', '.markdown'};
+
+  static bool isMergeable(String name, {String? mimeType}) {
+    if (mimeType != null && mimeType.startsWith('text/')) return true;
+    return _extensions.contains(p.extension(name).toLowerCase());
+  }
+
+  /// [local] and [remote] combined relative to [base], or null if they
+  /// conflict.
+  static String? merge(String base, String local, String remote) {
+    if (local == remote || remote == base) return local;
+    if (local == base) return remote;
+
+    final lines = base.split('\n');
+    final ours = _diff(lines, local.split('\n'), 0);
+    final theirs = _diff(lines, remote.split('\n'), 1);
+    if (ours == null || theirs == null) return null;
+    final hunks = [...ours, ...theirs]
+      ..sort((a, b) {
+        final byStart = a.start.compareTo(b.start);
+        return byStart != 0 ? byStart : a.end.compareTo(b.end);
+      });
+
+    final out = <String>[];
+    var pos = 0;
+    var k = 0;
+    while (k < hunks.length) {
+      final start = hunks[k].start;
+      var end = hunks[k].end;
+      final group = [hunks[k++]];
+      while (k < hunks.length && hunks[k].start <= end) {
+        end = max(end, hunks[k].end);
+        group.add(hunks[k++]);
+      }
+      out.addAll(lines.sublist(pos, start));
+
+      final mine = _apply(lines, start, end, group, 0);
+      final yours = _apply(lines, start, end, group, 1);
+      if (group.every((h) => h.side == 0)) {
+        out.addAll(mine);
+      } else if (group.every((h) => h.side == 1)) {
+        out.addAll(yours);
+      } else if (mine.join('\n') == yours.join('\n')) {
+        out.addAll(mine);
+      } else {
+        return null;
+      }
+      pos = end;
+    }
+    out.addAll(lines.sublist(pos));
+    return out.join('\n');
+  }
+
+  /// Lines `[start, end)` of [base] with one side's hunks applied.
+  static List<String> _apply(
+    List<String> base,
+    int start,
+    int end,
+    List<_Hunk> group,
+    int side,
+  ) {
+    final result = <String>[];
+    var pos = start;
+    for (final hunk in group.where((h) => h.side == side)) {
+      result
+        ..addAll(base.sublist(pos, hunk.start))
+        ..addAll(hunk.lines);
+      pos = hunk.end;
+    }
+    return result..addAll(base.sublist(pos, end));
+  }
+
+  /// The edits turning [base] into [other], from a longest common
+  /// subsequence of lines. Null when the files are too large to compare.
+  static List<_Hunk>? _diff(List<String> base, List<String> other, int side) {
+    var prefix = 0;
+    while (prefix < base.length &&
+        prefix < other.length &&
+        base[prefix] == other[prefix]) {
+      prefix++;
+    }
+    var suffix = 0;
+    while (suffix < base.length - prefix &&
+        suffix < other.length - prefix &&
+        base[base.length - 1 - suffix] == other[other.length - 1 - suffix]) {
+      suffix++;
+    }
+    final a = base.sublist(prefix, base.length - suffix);
+    final b = other.sublist(prefix, other.length - suffix);
+    if ((a.length + 1) * (b.length + 1) > _maxCells) return null;
+
+    // lcs[i * width + j]: common lines of a[i..] and b[j..].
+    final width = b.length + 1;
+    final lcs = List<int>.filled((a.length + 1) * width, 0);
+    for (var i = a.length - 1; i >= 0; i--) {
+      for (var j = b.length - 1; j >= 0; j--) {
+        lcs[i * width + j] = a[i] == b[j]
+            ? lcs[(i + 1) * width + j + 1] + 1
+            : max(lcs[(i + 1) * width + j], lcs[i * width + j + 1]);
+      }
+    }
+
+    final hunks = <_Hunk>[];
+    int? start;
+    final added = <String>[];
+    void flush(int i) {
+      if (start == null) return;
+      hunks.add(_Hunk(prefix + start!, prefix + i, [...added], side));
+      start = null;
+      added.clear();
+    }
+
+    var i = 0;
+    var j = 0;
+    while (i < a.length || j < b.length) {
+      if (i < a.length && j < b.length && a[i] == b[j]) {
+        flush(i);
+        i++;
+        j++;
+      } else if (j == b.length ||
+          (i < a.length &&
+              lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])) {
+        start ??= i;
+        i++;
+      } else {
+        start ??= i;
+        added.add(b[j++]);
+      }
+    }
+    flush(i);
+    return hunks;
+  }
+}
+
+class _Hunk {
+  final int start;
+  final int end;
+  final List<String> lines;
+  final int side;
+
+  const _Hunk(this.start, this.end, this.lines, this.side);
+}
@@ -0,0 +1,62 @@
+import 'dart:collection';
+
+/// Rolling-window throughput estimator.
+///
+/// Callers feed the cumulative number of bytes transferred; the estimator
+/// keeps the samples from the last [window] and derives a speed from them,
+/// smoothed with an exponential moving average so the UI does not flicker
+/// on every chunk.
+class ThroughputEstimator {
+  final Duration window;
+
+  /// Weight of the newest window measurement (0–1). Lower is smoother.
+  final double smoothing;
+
+  final DateTime Function() _clock;
+  final Queue<({DateTime at, int bytes})> _samples = Queue();
+  double? _smoothed;
+
+  ThroughputEstimator({
+    this.window = const Duration(seconds: 10),
+    this.smoothing = 0.3,
+    DateTime Function()? clock,
+  }) : _clock = clock ?? DateTime.now;
+
+  /// Record the cumulative byte count at the current time.
+  void addSample(int totalBytes) {
+    final now = _clock();
+    _samples.addLast((at: now, bytes: totalBytes));
+
+    // Keep one sample older than the window so the rate spans all of it.
+    while (_samples.length > 2 &&
+        now.difference(_samples.elementAt(1).at) >= window) {
+      _samples.removeFirst();
+    }
+
+    final first = _samples.first;
+    final elapsedMs = now.difference(first.at).inMilliseconds;
+    if (elapsedMs <= 0) return;
+
+    final rate = (totalBytes - first.bytes) * 1000 / elapsedMs;
+    _smoothed = _smoothed == null
+        ? rate
+        : smoothing * rate + (1 - smoothing) * _smoothed!;
+  }
+
+  /// Smoothed speed in bytes per second (0 until two samples exist).
+  double get bytesPerSecond => _smoothed ?? 0;
+
+  /// Estimated time to transfer [remainingBytes] at the current speed.
+  /// Returns null while the speed is still unknown.
+  Duration? eta(int remainingBytes) {
+    if (remainingBytes <= 0) return Duration.zero;
+    final speed = bytesPerSecond;
+    if (speed <= 0) return null;
+    return Duration(milliseconds: (remainingBytes / speed * 1000).round());
+  }
+
+  void reset() {
+    _samples.clear();
+    _smoothed = null;
+  }
+}
@@ -0,0 +1,93 @@
+import 'dart:async';
+
+import '../error/exceptions.dart';
+import 'transfer_monitor.dart';
+
+/// Starts (or restarts) a transfer. Completing [stop] must abort it; with
+/// [resume], bytes kept from an earlier attempt may be reused.
+typedef TransferStarter<T> =
+    Future<T> Function(Future<void> stop, {required bool resume});
+
+class _Control {
+  Completer<void> stop = Completer();
+  Completer<void> resumed = Completer();
+  bool paused = false;
+  bool cancelled = false;
+}
+
+/// Pauses, resumes and cancels single uploads and downloads.
+///
+/// Transfers are addressed by their id in the [TransferMonitor]: the file
+/// id for downloads, the local path for uploads. Pausing aborts the request
+/// in flight and resuming starts it again; a download then continues from
+/// the bytes already on disk if the server supports ranges, while a plain
+/// upload starts over. A paused upload keeps its slot in the upload queue.
+class TransferController {
+  final TransferMonitor _monitor;
+  final Map<String, _Control> _controls = {};
+
+  TransferController(this._monitor);
+
+  bool isPaused(String id) => _controls[id]?.paused ?? false;
+
+  /// Run the transfer [id] under control. Throws
+  /// [RequestCancelledException] if it is cancelled.
+  Future<T> run<T>(String id, TransferStarter<T> start) async {
+    final control = _controls[id] = _Control();
+    try {
+      var resume = false;
+      while (true) {
+        try {
+          return await start(control.stop.future, resume: resume);
+        } catch (_) {
+          if (control.cancelled) {
+            throw const RequestCancelledException('Cancelled by the user');
+          }
+          // Whatever the aborted request threw, a pause caused it.
+          if (!control.paused) rethrow;
+        }
+        await control.resumed.future;
+        if (control.cancelled) {
+          throw const RequestCancelledException('Cancelled by the user');
+        }
+        control
+          ..stop = Completer()
+          ..resumed = Completer();
+        resume = true;
+      }
+    } finally {
+      if (identical(_controls[id], control)) _controls.remove(id);
+    }
+  }
+
+  /// Returns false if [id] is not running.
+  bool pause(String id) {
+    final control = _controls[id];
+    if (control == null || control.paused || control.cancelled) return false;
+    control.paused = true;
+    control.stop.complete();
+    _monitor.pause(id);
+    return true;
+  }
+
+  /// Returns false if [id] is not paused.
+  bool resume(String id) {
+    final control = _controls[id];
+    if (control == null || !control.paused || control.cancelled) return false;
+    control.paused = false;
+    control.resumed.complete();
+    _monitor.resume(id);
+    return true;
+  }
+
+  /// Returns false if [id] is not running or paused.
+  bool cancel(String id) {
+    final control = _controls[id];
+    if (control == null || control.cancelled) return false;
+    control.cancelled = true;
+    if (!control.stop.isCompleted) control.stop.complete();
+    if (!control.resumed.isCompleted) control.resumed.complete();
+    _monitor.cancel(id);
+    return true;
+  }
+}
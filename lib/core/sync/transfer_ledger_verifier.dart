@@ -0,0 +1,105 @@
+import 'dart:math';
+
+import 'package:drift/drift.dart';
+
+import '../../domain/repositories/file_repository.dart';
+import '../database/app_database.dart';
+import '../error/exceptions.dart';
+import '../network/connectivity_service.dart';
+
+/// Re-checks recent uploads against fresh server metadata and records any
+/// discrepancy as an integrity incident in the activity log.
+///
+/// A file whose server modification time moved on since the upload was
+/// changed legitimately and is marked superseded rather than flagged.
+class TransferLedgerVerifier {
+  final AppDatabase _db;
+  final FileRepository _fileRepo;
+  final ConnectivityService _connectivity;
+
+  /// Fraction of uploads that are checked; 1.0 verifies every one.
+  final double sampleRate;
+
+  /// Upper bound on server lookups per run.
+  final int batchSize;
+
+  /// Server clocks drift; edits within this window of the upload are
+  /// attributed to the upload itself.
+  final Duration clockSlack;
+
+  final Random _random;
+  bool _running = false;
+
+  TransferLedgerVerifier({
+    required AppDatabase db,
+    required FileRepository fileRepo,
+    required ConnectivityService connectivity,
+    this.sampleRate = 1.0,
+    this.batchSize = 50,
+    this.clockSlack = const Duration(minutes: 5),
+    Random? random,
+  }) : _db = db,
+       _fileRepo = fileRepo,
+       _connectivity = connectivity,
+       _random = random ?? Random();
+
+  /// Check pending ledger entries. Returns how many incidents were found.
+  Future<int> verify() async {
+    if (_running || !_connectivity.isOnline) return 0;
+    _running = true;
+    var incidents = 0;
+    try {
+      final pending = await _db.getPendingLedgerEntries(limit: batchSize);
+      for (final entry in pending) {
+        if (!_connectivity.isOnline) break;
+        if (_random.nextDouble() >= sampleRate) {
+          await _db.updateLedgerStatus(entry.id, 'skipped');
+          continue;
+        }
+        if (await _check(entry)) incidents++;
+      }
+    } finally {
+      _running = false;
+    }
+    return incidents;
+  }
+
+  Future<bool> _check(TransferLedgerTableData entry) async {
+    String? problem;
+    var status = 'verified';
+    try {
+      final file = await _fileRepo.getFile(entry.itemId);
+      final since = entry.remoteModifiedAt ?? entry.uploadedAt.add(clockSlack);
+      if (file.modifiedAt.isAfter(since)) {
+        status = 'superseded';
+      } else if (file.size != entry.size) {
+        problem = 'Uploaded ${entry.size} bytes, server reports ${file.size}';
+      }
+    } on NotFoundException {
+      // Deleted or moved to trash since; nothing left to compare.
+      status = 'superseded';
+    } catch (_) {
+      // Transient failure: leave the entry pending for the next run.
+      return false;
+    }
+
+    if (problem == null) {
+      await _db.updateLedgerStatus(entry.id, status);
+      return false;
+    }
+    await _db.updateLedgerStatus(entry.id, 'mismatch');
+    await _db.insertActivity(
+      ActivityLogTableCompanion.insert(
+        category: 'integrity',
+        action: 'verify',
+        itemId: Value(entry.itemId),
+        itemName: Value(entry.itemName),
+        outcome: 'mismatch',
+        reason: Value(problem),
+        bytes: Value(entry.size),
+        createdAt: DateTime.now(),
+      ),
+    );
+    return true;
+  }
+}
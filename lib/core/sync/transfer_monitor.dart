@@ -0,0 +1,272 @@
+import 'dart:math';
+
+import 'package:flutter/foundation.dart';
+import 'package:uuid/uuid.dart';
+
+import 'sync_events.dart';
+import 'throughput_estimator.dart';
+
+enum TransferDirection { upload, download }
+
+/// [waiting] transfers have not started because another application has
+/// the file open.
+enum TransferState { active, paused, waiting, completed, failed, cancelled }
+
+/// Snapshot of a single transfer, with smoothed speed and ETA.
+class TransferProgress {
+  final String id;
+  final String name;
+  final TransferDirection direction;
+  final TransferState state;
+  final int bytesTransferred;
+  final int totalBytes;
+  final double bytesPerSecond;
+
+  /// Null while the speed is unknown, or the size is (0 or -1).
+  final Duration? eta;
+
+  const TransferProgress({
+    required this.id,
+    required this.name,
+    required this.direction,
+    required this.state,
+    required this.bytesTransferred,
+    required this.totalBytes,
+    required this.bytesPerSecond,
+    this.eta,
+  });
+
+  double get fraction =>
+      totalBytes > 0 ? (bytesTransferred / totalBytes).clamp(0.0, 1.0) : 0.0;
+}
+
+class _Transfer {
+  final String name;
+  final TransferDirection direction;
+  final ThroughputEstimator estimator;
+  final DateTime startedAt;
+  int totalBytes;
+  int bytesTransferred = 0;
+  TransferState state = TransferState.active;
+
+  _Transfer(
+    this.name,
+    this.direction,
+    this.totalBytes,
+    this.estimator,
+    this.startedAt,
+  );
+}
+
+/// Tracks in-flight uploads and downloads and derives per-transfer and
+/// overall (account-wide) throughput, so the UI never has to compute speed
+/// from raw byte counts.
+class TransferMonitor extends ChangeNotifier {
+  final Map<String, _Transfer> _transfers = {};
+  final ThroughputEstimator _overall;
+  final DateTime Function() _clock;
+  final Duration notifyInterval;
+
+  /// Called once when a transfer completes or fails.
+  final void Function(TransferProgress progress)? onFinished;
+
+  /// Receives progress, completion and failure of every transfer.
+  final SyncEventBus? events;
+
+  int _overallBytes = 0;
+  DateTime? _lastNotify;
+
+  TransferMonitor({
+    DateTime Function()? clock,
+    this.notifyInterval = const Duration(milliseconds: 250),
+    this.onFinished,
+    this.events,
+  }) : _clock = clock ?? DateTime.now,
+       _overall = ThroughputEstimator(clock: clock);
+
+  /// Register a new transfer and return its id.
+  String begin({
+    required String name,
+    required TransferDirection direction,
+    required int totalBytes,
+    String? id,
+  }) {
+    final transferId = id ?? const Uuid().v4();
+    if (activeCount == 0) {
+      // A new batch starts: forget completed transfers from the last one.
+      _transfers.removeWhere((_, t) => t.state == TransferState.completed);
+      _overall
+        ..reset()
+        ..addSample(_overallBytes);
+    }
+    final transfer = _Transfer(
+      name,
+      direction,
+      totalBytes,
+      ThroughputEstimator(clock: _clock),
+      _clock(),
+    )..estimator.addSample(0);
+    _transfers[transferId] = transfer;
+    notifyListeners();
+    return transferId;
+  }
+
+  /// Report the cumulative bytes transferred for [id].
+  void update(String id, int bytesTransferred, {int? totalBytes}) {
+    final t = _transfers[id];
+    if (t == null || t.state != TransferState.active) return;
+    if (totalBytes != null && totalBytes > 0) t.totalBytes = totalBytes;
+
+    final delta = bytesTransferred - t.bytesTransferred;
+    t.bytesTransferred = bytesTransferred;
+    t.estimator.addSample(bytesTransferred);
+    if (delta > 0) {
+      _overallBytes += delta;
+      _overall.addSample(_overallBytes);
+    }
+    _notifyThrottled(id, t);
+  }
+
+  void complete(String id) => _finish(id, TransferState.completed);
+
+  /// Mark [id] as failed; [reason] is passed on with the [ItemFailed] event.
+  void fail(String id, {String? reason}) =>
+      _finish(id, TransferState.failed, reason: reason);
+
+  void cancel(String id) => _finish(id, TransferState.cancelled);
+
+  /// Mark [id] as paused; it keeps its progress but no longer counts
+  /// towards speed and ETA.
+  void pause(String id) {
+    final t = _transfers[id];
+    if (t == null || t.state != TransferState.active) return;
+    t.state = TransferState.paused;
+    notifyListeners();
+  }
+
+  /// Mark [id] as waiting for another application to release its file.
+  void waitForRelease(String id) {
+    final t = _transfers[id];
+    if (t == null || t.state != TransferState.active) return;
+    t.state = TransferState.waiting;
+    notifyListeners();
+  }
+
+  void resume(String id) {
+    final t = _transfers[id];
+    if (t == null ||
+        (t.state != TransferState.paused && t.state != TransferState.waiting)) {
+      return;
+    }
+    t.state = TransferState.active;
+    t.estimator
+      ..reset()
+      ..addSample(t.bytesTransferred);
+    notifyListeners();
+  }
+
+  /// Drop finished transfers from the list.
+  void clearFinished() {
+    _transfers.removeWhere((_, t) => !_isOpen(t));
+    notifyListeners();
+  }
+
+  TransferProgress? progressOf(String id) {
+    final t = _transfers[id];
+    return t == null ? null : _snapshot(id, t);
+  }
+
+  List<TransferProgress> get transfers =>
+      _transfers.entries.map((e) => _snapshot(e.key, e.value)).toList();
+
+  int get activeCount =>
+      _transfers.values.where((t) => t.state == TransferState.active).length;
+
+  int get waitingCount =>
+      _transfers.values.where((t) => t.state == TransferState.waiting).length;
+
+  /// Bytes still to come for downloads that have not finished.
+  int get pendingDownloadBytes => _transfers.values
+      .where((t) => t.direction == TransferDirection.download && _isOpen(t))
+      .fold(0, (sum, t) => sum + max(0, t.totalBytes - t.bytesTransferred));
+
+  /// Smoothed speed across all active transfers, in bytes per second.
+  double get bytesPerSecond =>
+      activeCount == 0 ? 0.0 : _overall.bytesPerSecond;
+
+  /// Time left for every active transfer to finish at the overall speed.
+  /// Null while any of them is of unknown size.
+  Duration? get eta {
+    final active = _transfers.values.where(
+      (t) => t.state == TransferState.active,
+    );
+    if (active.isEmpty || active.any((t) => t.totalBytes <= 0)) return null;
+    final remaining = active.fold<int>(
+      0,
+      (sum, t) => sum + (t.totalBytes - t.bytesTransferred),
+    );
+    return _overall.eta(remaining);
+  }
+
+  void _finish(String id, TransferState state, {String? reason}) {
+    final t = _transfers[id];
+    if (t == null || !_isOpen(t)) return;
+    t.state = state;
+    if (state == TransferState.completed) t.bytesTransferred = t.totalBytes;
+    onFinished?.call(_snapshot(id, t));
+    events?.emit(
+      state == TransferState.completed
+          ? ItemCompleted(
+              itemId: id,
+              action: t.direction.name,
+              name: t.name,
+              bytes: t.bytesTransferred,
+              elapsed: _clock().difference(t.startedAt),
+            )
+          : ItemFailed(
+              itemId: id,
+              action: t.direction.name,
+              name: t.name,
+              reason: state == TransferState.cancelled ? 'Cancelled' : reason,
+            ),
+    );
+    notifyListeners();
+  }
+
+  static bool _isOpen(_Transfer t) =>
+      t.state == TransferState.active ||
+      t.state == TransferState.paused ||
+      t.state == TransferState.waiting;
+
+  void _notifyThrottled(String id, _Transfer t) {
+    final now = _clock();
+    if (_lastNotify == null || now.difference(_lastNotify!) >= notifyInterval) {
+      _lastNotify = now;
+      events?.emit(
+        TransferProgressed(
+          transferId: id,
+          name: t.name,
+          bytes: t.bytesTransferred,
+          total: t.totalBytes,
+        ),
+      );
+      notifyListeners();
+    }
+  }
+
+  TransferProgress _snapshot(String id, _Transfer t) {
+    final active = t.state == TransferState.active;
+    return TransferProgress(
+      id: id,
+      name: t.name,
+      direction: t.direction,
+      state: t.state,
+      bytesTransferred: t.bytesTransferred,
+      totalBytes: t.totalBytes,
+      bytesPerSecond: active ? t.estimator.bytesPerSecond : 0.0,
+      eta: active && t.totalBytes > 0
+          ? t.estimator.eta(t.totalBytes - t.bytesTransferred)
+          : null,
+    );
+  }
+}
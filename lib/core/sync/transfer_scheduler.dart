@@ -0,0 +1,179 @@
+import 'dart:async';
+import 'dart:collection';
+
+import 'package:flutter/foundation.dart';
+
+/// Which transfers go first when more are queued than can run at once.
+enum TransferPriorityPolicy { fifo, smallFirst, largeFirst }
+
+/// A transfer waiting for a free slot.
+class QueuedTransfer {
+  final String id;
+  final String name;
+  final int size;
+  final bool boosted;
+
+  const QueuedTransfer({
+    required this.id,
+    required this.name,
+    required this.size,
+    this.boosted = false,
+  });
+}
+
+class _Job {
+  final QueuedTransfer transfer;
+  final int seq;
+  final Future<void> Function() start;
+
+  _Job(this.transfer, this.seq, this.start);
+
+  _Job boosted() => _Job(
+    QueuedTransfer(
+      id: transfer.id,
+      name: transfer.name,
+      size: transfer.size,
+      boosted: true,
+    ),
+    seq,
+    start,
+  );
+}
+
+/// Runs at most [maxConcurrent] transfers at a time from three lanes:
+/// boosted items, small files and large files.
+///
+/// Boosted items always go next. Otherwise the lane favoured by [policy]
+/// gets [laneWeight] turns for every turn of the other one, so a steady
+/// stream of small files cannot starve a large one (or the reverse).
+///
+/// While [throttle]d, fewer transfers start; beyond the throttled limit
+/// only boosted ones do.
+class TransferScheduler extends ChangeNotifier {
+  final int maxConcurrent;
+  TransferPriorityPolicy policy;
+
+  /// Files up to this size go in the small lane.
+  final int smallFileThreshold;
+  final int laneWeight;
+
+  final Queue<_Job> _boosted = Queue();
+  final Queue<_Job> _small = Queue();
+  final Queue<_Job> _large = Queue();
+  int _running = 0;
+  int _seq = 0;
+  int _preferredTurns = 0;
+  int? _throttledTo;
+
+  TransferScheduler({
+    this.maxConcurrent = 3,
+    this.policy = TransferPriorityPolicy.fifo,
+    this.smallFileThreshold = 8 * 1024 * 1024,
+    this.laneWeight = 4,
+  });
+
+  int get runningCount => _running;
+
+  /// Run at most [limit] transfers besides boosted ones; null lifts the
+  /// limit. Transfers already running are not interrupted.
+  void throttle(int? limit) {
+    if (limit == _throttledTo) return;
+    _throttledTo = limit;
+    _pump();
+    notifyListeners();
+  }
+
+  /// Waiting transfers, boosted ones first.
+  List<QueuedTransfer> get queued => [
+    for (final job in _boosted) job.transfer,
+    for (final job in [..._small, ..._large]..sort(_bySeq)) job.transfer,
+  ];
+
+  /// Queue [run] and complete with its result once it has had a slot.
+  Future<T> schedule<T>({
+    required String id,
+    required String name,
+    required int size,
+    required Future<T> Function() run,
+  }) {
+    final completer = Completer<T>();
+    final job = _Job(
+      QueuedTransfer(id: id, name: name, size: size),
+      _seq++,
+      () async {
+        try {
+          completer.complete(await run());
+        } catch (e, st) {
+          completer.completeError(e, st);
+        }
+      },
+    );
+    (size <= smallFileThreshold ? _small : _large).add(job);
+    _pump();
+    notifyListeners();
+    return completer.future;
+  }
+
+  /// Move a queued transfer to the front. Returns false if [id] is not
+  /// waiting (already running, finished or unknown).
+  bool boost(String id) {
+    for (final lane in [_small, _large]) {
+      final job = lane.where((j) => j.transfer.id == id).firstOrNull;
+      if (job != null) {
+        lane.remove(job);
+        _boosted.add(job.boosted());
+        _pump();
+        notifyListeners();
+        return true;
+      }
+    }
+    return false;
+  }
+
+  void _pump() {
+    while (_running < maxConcurrent) {
+      final limit = _throttledTo;
+      final job = limit == null || _running < limit
+          ? _next()
+          : _boosted.isEmpty
+          ? null
+          : _boosted.removeFirst();
+      if (job == null) return;
+      _running++;
+      job.start().whenComplete(() {
+        _running--;
+        _pump();
+        notifyListeners();
+      });
+    }
+  }
+
+  _Job? _next() {
+    if (_boosted.isNotEmpty) return _boosted.removeFirst();
+    if (_small.isEmpty && _large.isEmpty) return null;
+    if (_small.isEmpty) return _large.removeFirst();
+    if (_large.isEmpty) return _small.removeFirst();
+
+    switch (policy) {
+      case TransferPriorityPolicy.fifo:
+        return _bySeq(_small.first, _large.first) < 0
+            ? _small.removeFirst()
+            : _large.removeFirst();
+      case TransferPriorityPolicy.smallFirst:
+        return _weighted(_small, _large);
+      case TransferPriorityPolicy.largeFirst:
+        return _weighted(_large, _small);
+    }
+  }
+
+  _Job _weighted(Queue<_Job> preferred, Queue<_Job> other) {
+    if (_preferredTurns < laneWeight) {
+      _preferredTurns++;
+      return preferred.removeFirst();
+    }
+    _preferredTurns = 0;
+    return other.removeFirst();
+  }
+
+  static int _bySeq(_Job a, _Job b) => a.seq.compareTo(b.seq);
+}
@@ -0,0 +1,148 @@
+import 'dart:io';
+
+import 'package:drift/drift.dart' show Value;
+
+import '../../domain/entities/file_entity.dart';
+import '../../domain/repositories/file_repository.dart';
+import '../database/app_database.dart';
+import '../services/checksum_service.dart';
+import 'checksum_negotiation.dart';
+
+/// The checksum of a local file's content.
+typedef ContentDigest = ({ChecksumAlgorithm algorithm, String value, int size});
+
+/// How an upload was avoided.
+enum DedupOutcome {
+  /// The file with that name in the target folder already has the content.
+  unchanged,
+
+  /// The content was copied on the server from another file.
+  copied,
+}
+
+/// Avoids uploading content the server already has.
+///
+/// Uploaded files are checksummed with the algorithm agreed with the
+/// server and the digest recorded against the server file. Before the next
+/// upload, a file whose content matches the one of the same name in the
+/// target folder is skipped, e.g. after a touch-only change, and content
+/// found elsewhere in the account is copied on the server, e.g. after a
+/// local move.
+class UploadDeduplicator {
+  final AppDatabase _db;
+  final FileRepository _files;
+  final ChecksumService? _checksums;
+
+  UploadDeduplicator({
+    required AppDatabase db,
+    required FileRepository files,
+    ChecksumService? checksums,
+  }) : _db = db,
+       _files = files,
+       _checksums = checksums;
+
+  /// The digest of [file], or null when no checksum is agreed with the
+  /// server.
+  Future<ContentDigest?> digestOf(File file) async {
+    final algorithm = await _checksums?.algorithm();
+    if (algorithm == null) return null;
+    return (
+      algorithm: algorithm,
+      value: await algorithm.digestStream(file.openRead()),
+      size: await file.length(),
+    );
+  }
+
+  /// Place content with [digest] at [name] in [folderId] without uploading
+  /// it, if the server has it. Returns null when it has to be uploaded.
+  Future<(DedupOutcome, FileEntity)?> avoidUpload(
+    ContentDigest digest, {
+    required String name,
+    required String? folderId,
+  }) async {
+    final existing = (await _db.getFilesInFolder(
+      folderId,
+    )).where((f) => f.name == name).firstOrNull;
+    if (existing != null) {
+      // Same name, other content: a real change to upload.
+      if (!await _holds(existing, digest)) return null;
+      return (DedupOutcome.unchanged, _entity(existing));
+    }
+    if (folderId == null) return null;
+
+    for (final entry in await _db.getContentDigests(
+      digest.algorithm.wireName,
+      digest.value,
+    )) {
+      final source = await _db.getFileById(entry.fileId);
+      if (source == null || !await _holds(source, digest)) continue;
+      var copy = await _files.copyFile(source.id, folderId);
+      if (copy.name != name) copy = await _files.renameFile(copy.id, name);
+      await remember(copy.id, digest, etag: copy.etag);
+      return (DedupOutcome.copied, copy);
+    }
+    return null;
+  }
+
+  /// Record that the server file [fileId], at [etag], holds the content
+  /// with [digest].
+  Future<void> remember(
+    String fileId,
+    ContentDigest digest, {
+    required String? etag,
+  }) {
+    return _db.recordContentDigest(
+      ContentDigestsTableCompanion.insert(
+        fileId: fileId,
+        algorithm: digest.algorithm.wireName,
+        digest: digest.value,
+        size: digest.size,
+        etag: Value(etag),
+        recordedAt: DateTime.now(),
+      ),
+    );
+  }
+
+  /// Whether the recorded digest of [file] is [digest] and still current.
+  /// The cached listing may be behind, so a match is confirmed with the
+  /// server's ETag; if that cannot be had, the content is uploaded. Digests
+  /// outdated by a change on the server are dropped.
+  Future<bool> _holds(FilesTableData file, ContentDigest digest) async {
+    final entry = await _db.getContentDigest(file.id);
+    if (entry == null) return false;
+    if (entry.etag != file.etag || entry.size != file.size) {
+      await _db.deleteContentDigest(file.id);
+      return false;
+    }
+    if (entry.algorithm != digest.algorithm.wireName ||
+        entry.digest != digest.value ||
+        entry.size != digest.size) {
+      return false;
+    }
+    final String? etag;
+    try {
+      etag = (await _files.getFile(file.id)).etag;
+    } on Exception {
+      return false;
+    }
+    if (etag != entry.etag) {
+      await _db.deleteContentDigest(file.id);
+      return false;
+    }
+    return true;
+  }
+
+  static FileEntity _entity(FilesTableData f) => FileEntity(
+    id: f.id,
+    name: f.name,
+    path: f.path,
+    size: f.size,
+    mimeType: f.mimeType,
+    folderId: f.folderId,
+    ownerId: f.ownerId,
+    hash: f.hash,
+    etag: f.etag,
+    createdAt: f.createdAt,
+    modifiedAt: f.modifiedAt,
+  );
+}
@@ -2,6 +2,7 @@ import 'package:dio/dio.dart';
 
 import '../../../core/error/error_handler.dart';
 import '../../../core/network/api_endpoints.dart';
+import '../../../core/network/interceptors/auth_interceptor.dart';
 import '../../dtos/auth/auth_dtos.dart';
 
 class AuthRemoteDatasource {
@@ -60,9 +61,18 @@ class AuthRemoteDatasource {
     }
   }
 
-  Future<UserResponseDto> getCurrentUser() async {
+  /// With [checkingCredentials], a 401 is only thrown, not treated as the
+  /// end of the session.
+  Future<UserResponseDto> getCurrentUser({
+    bool checkingCredentials = false,
+  }) async {
     try {
-      final response = await _dio.get(ApiEndpoints.me);
+      final response = await _dio.get(
+        ApiEndpoints.me,
+        options: checkingCredentials
+            ? Options(extra: {AuthInterceptor.checkingCredentialsKey: true})
+            : null,
+      );
       return UserResponseDto.fromJson(response.data as Map<String, dynamic>);
     } on DioException catch (e) {
       throw ErrorHandler.mapDioToServerException(e);
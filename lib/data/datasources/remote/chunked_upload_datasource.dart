@@ -3,6 +3,7 @@ import 'package:dio/dio.dart';
 
 import '../../../core/error/error_handler.dart';
 import '../../../core/network/api_endpoints.dart';
+import '../../../core/network/rate_limiter.dart';
 
 /// Upload session metadata returned by the server.
 class UploadSession {
@@ -10,12 +11,14 @@ class UploadSession {
   final int chunkSize;
   final int totalChunks;
   final DateTime expiresAt;
+  final DateTime? createdAt;
 
   const UploadSession({
     required this.uploadId,
     required this.chunkSize,
     required this.totalChunks,
     required this.expiresAt,
+    this.createdAt,
   });
 
   factory UploadSession.fromJson(Map<String, dynamic> json) {
@@ -24,6 +27,9 @@ class UploadSession {
       chunkSize: json['chunk_size'] as int,
       totalChunks: json['total_chunks'] as int,
       expiresAt: DateTime.parse(json['expires_at'] as String),
+      createdAt: json['created_at'] != null
+          ? DateTime.parse(json['created_at'] as String)
+          : null,
     );
   }
 }
@@ -55,7 +61,11 @@ class ChunkedUploadResult {
 class ChunkedUploadDatasource {
   final Dio _dio;
 
-  ChunkedUploadDatasource(this._dio);
+  /// Bandwidth limit shared with every other upload.
+  final RateLimiter? _uploadLimit;
+
+  ChunkedUploadDatasource(this._dio, {RateLimiter? uploadLimit})
+    : _uploadLimit = uploadLimit;
 
   /// Create a new upload session.
   Future<UploadSession> createSession({
@@ -82,20 +92,37 @@ class ChunkedUploadDatasource {
     }
   }
 
-  /// Upload a single chunk.
+  /// List the account's open upload sessions.
+  Future<List<UploadSession>> listSessions() async {
+    try {
+      final response = await _dio.get(ApiEndpoints.uploads);
+      final list = response.data as List<dynamic>;
+      return list
+          .map((e) => UploadSession.fromJson(e as Map<String, dynamic>))
+          .toList();
+    } on DioException catch (e) {
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+
+  /// Upload a single chunk. The server verifies [checksum], computed with
+  /// [checksumAlgorithm], before accepting it.
   Future<void> uploadChunk({
     required String uploadId,
     required int chunkIndex,
     required Uint8List data,
     String? checksum,
+    String? checksumAlgorithm,
   }) async {
     try {
       await _dio.patch(
         ApiEndpoints.uploadById(uploadId),
-        data: Stream.fromIterable([data]),
+        data: _uploadLimit?.throttle(Stream.value(data)) ?? Stream.value(data),
         queryParameters: {
           'chunk_index': chunkIndex,
           if (checksum != null) 'checksum': checksum,
+          if (checksumAlgorithm != null)
+            'checksum_algorithm': checksumAlgorithm,
         },
         options: Options(
           headers: {
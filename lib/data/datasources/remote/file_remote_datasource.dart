@@ -1,14 +1,27 @@
+import 'dart:io';
 import 'dart:typed_data';
 import 'package:dio/dio.dart';
 
 import '../../../core/error/error_handler.dart';
+import '../../../core/error/exceptions.dart';
 import '../../../core/network/api_endpoints.dart';
+import '../../../core/network/rate_limiter.dart';
+import '../../../domain/repositories/file_repository.dart';
 import '../../dtos/files/file_dto.dart';
 
 class FileRemoteDatasource {
   final Dio _dio;
 
-  FileRemoteDatasource(this._dio);
+  /// Bandwidth limits shared with every other transfer.
+  final RateLimiter? _uploadLimit;
+  final RateLimiter? _downloadLimit;
+
+  FileRemoteDatasource(
+    this._dio, {
+    RateLimiter? uploadLimit,
+    RateLimiter? downloadLimit,
+  }) : _uploadLimit = uploadLimit,
+       _downloadLimit = downloadLimit;
 
   Future<List<FileResponseDto>> listFiles({String? folderId}) async {
     try {
@@ -40,11 +53,13 @@ class FileRemoteDatasource {
     required Stream<List<int>> fileStream,
     required int fileSize,
     required String mimeType,
+    ProgressCallback? onSendProgress,
+    CancelToken? cancelToken,
   }) async {
     try {
       final formData = FormData.fromMap({
         'file': MultipartFile.fromStream(
-          () => fileStream,
+          () => _uploadLimit?.throttle(fileStream) ?? fileStream,
           fileSize,
           filename: name,
           contentType: DioMediaType.parse(mimeType),
@@ -55,6 +70,8 @@ class FileRemoteDatasource {
         ApiEndpoints.fileUpload,
         data: formData,
         options: Options(headers: {'Content-Type': 'multipart/form-data'}),
+        onSendProgress: onSendProgress,
+        cancelToken: cancelToken,
       );
       return FileResponseDto.fromJson(response.data as Map<String, dynamic>);
     } on DioException catch (e) {
@@ -62,6 +79,54 @@ class FileRemoteDatasource {
     }
   }
 
+  /// Upload [files] into [folderId] as one multipart request. Returns the
+  /// stored files and, by name, why the others failed.
+  Future<({List<FileResponseDto> uploaded, Map<String, String> failed})>
+  uploadFiles({
+    required String? folderId,
+    required List<UploadSource> files,
+    ProgressCallback? onSendProgress,
+    CancelToken? cancelToken,
+  }) async {
+    try {
+      final formData = FormData()
+        ..files.addAll([
+          for (final file in files)
+            MapEntry(
+              'files',
+              MultipartFile.fromStream(
+                () => _uploadLimit?.throttle(file.open()) ?? file.open(),
+                file.size,
+                filename: file.name,
+                contentType: DioMediaType.parse(file.mimeType),
+              ),
+            ),
+        ]);
+      if (folderId != null) {
+        formData.fields.add(MapEntry('folder_id', folderId));
+      }
+      final response = await _dio.post(
+        ApiEndpoints.batchFilesUpload,
+        data: formData,
+        options: Options(headers: {'Content-Type': 'multipart/form-data'}),
+        onSendProgress: onSendProgress,
+        cancelToken: cancelToken,
+      );
+      final data = response.data as Map<String, dynamic>;
+      return (
+        uploaded: (data['successful'] as List<dynamic>? ?? const [])
+            .map((e) => FileResponseDto.fromJson(e as Map<String, dynamic>))
+            .toList(),
+        failed: {
+          for (final e in data['failed'] as List<dynamic>? ?? const [])
+            (e as Map<String, dynamic>)['id'] as String: e['error'] as String,
+        },
+      );
+    } on DioException catch (e) {
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+
   Future<ResponseBody> downloadFile(String id) async {
     try {
       final response = await _dio.get<ResponseBody>(
@@ -74,27 +139,100 @@ class FileRemoteDatasource {
     }
   }
 
-  Future<void> downloadFileToPath(String id, String savePath) async {
+  /// Download a file to [savePath]. With [resume], a partial file already
+  /// at [savePath] is continued from where it stopped if the server honours
+  /// the range request, and downloaded again from the start otherwise.
+  /// With [ifRange], it is only continued if the file still has that ETag.
+  /// A partial file that already holds the whole file is left as it is.
+  /// [onEtag] gets the response's ETag once the server answers.
+  Future<void> downloadFileToPath(
+    String id,
+    String savePath, {
+    ProgressCallback? onReceiveProgress,
+    CancelToken? cancelToken,
+    bool resume = false,
+    String? ifRange,
+    void Function(String? etag)? onEtag,
+  }) async {
+    final file = File(savePath);
+    final offset = resume && file.existsSync() ? file.lengthSync() : 0;
     try {
-      await _dio.download(ApiEndpoints.fileById(id), savePath);
+      final response = await _dio.get<ResponseBody>(
+        ApiEndpoints.fileById(id),
+        options: Options(
+          responseType: ResponseType.stream,
+          headers: {
+            if (offset > 0) 'Range': 'bytes=$offset-',
+            if (offset > 0 && ifRange != null) 'If-Range': ifRange,
+          },
+        ),
+        cancelToken: cancelToken,
+      );
+      onEtag?.call(response.headers.value('etag'));
+      final partial = offset > 0 && response.statusCode == 206;
+      final length = int.tryParse(
+        response.headers.value(Headers.contentLengthHeader) ?? '',
+      );
+      var received = partial ? offset : 0;
+      final total = length == null ? -1 : received + length;
+      final sink = file.openWrite(
+        mode: partial ? FileMode.append : FileMode.write,
+      );
+      try {
+        final body = response.data!.stream;
+        await for (final chunk in _downloadLimit?.throttle(body) ?? body) {
+          sink.add(chunk);
+          received += chunk.length;
+          onReceiveProgress?.call(received, total);
+        }
+      } finally {
+        await sink.close();
+      }
     } on DioException catch (e) {
+      final response = e.response;
+      if (offset > 0 && response?.statusCode == 416) {
+        // Nothing left past [offset]: done if that is the whole file,
+        // otherwise the partial file is not this file and starts over.
+        final range = response!.headers.value('content-range') ?? '';
+        final size = int.tryParse(range.split('/').last);
+        if (size == offset) {
+          onReceiveProgress?.call(offset, offset);
+          return;
+        }
+        return downloadFileToPath(
+          id,
+          savePath,
+          onReceiveProgress: onReceiveProgress,
+          cancelToken: cancelToken,
+          ifRange: ifRange,
+          onEtag: onEtag,
+        );
+      }
       throw ErrorHandler.mapDioToServerException(e);
     }
   }
 
-  Future<void> deleteFile(String id) async {
+  Future<void> deleteFile(String id, {String? ifMatch}) async {
     try {
-      await _dio.delete(ApiEndpoints.fileById(id));
+      await _dio.delete(
+        ApiEndpoints.fileById(id),
+        options: _conditional(ifMatch),
+      );
     } on DioException catch (e) {
       throw ErrorHandler.mapDioToServerException(e);
     }
   }
 
-  Future<FileResponseDto> renameFile(String id, String newName) async {
+  Future<FileResponseDto> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async {
     try {
       final response = await _dio.put(
         ApiEndpoints.fileRename(id),
         data: {'name': newName},
+        options: _conditional(ifMatch),
       );
       return FileResponseDto.fromJson(response.data as Map<String, dynamic>);
     } on DioException catch (e) {
@@ -102,11 +240,16 @@ class FileRemoteDatasource {
     }
   }
 
-  Future<FileResponseDto> moveFile(String id, String targetFolderId) async {
+  Future<FileResponseDto> moveFile(
+    String id,
+    String targetFolderId, {
+    String? ifMatch,
+  }) async {
     try {
       final response = await _dio.put(
         ApiEndpoints.fileMove(id),
         data: {'folder_id': targetFolderId},
+        options: _conditional(ifMatch),
       );
       return FileResponseDto.fromJson(response.data as Map<String, dynamic>);
     } on DioException catch (e) {
@@ -114,6 +257,31 @@ class FileRemoteDatasource {
     }
   }
 
+  /// Copy a file into [targetFolderId] through the batch endpoint.
+  Future<FileResponseDto> copyFile(String id, String targetFolderId) async {
+    try {
+      final response = await _dio.post(
+        ApiEndpoints.batchFilesCopy,
+        data: {
+          'file_ids': [id],
+          'target_folder_id': targetFolderId,
+        },
+      );
+      final data = response.data as Map<String, dynamic>;
+      final copied = data['successful'] as List<dynamic>? ?? const [];
+      if (copied.isEmpty) {
+        final failed = data['failed'] as List<dynamic>? ?? const [];
+        final reason = failed.isEmpty
+            ? 'Copy failed'
+            : (failed.first as Map<String, dynamic>)['error'] as String;
+        throw ServerException(reason);
+      }
+      return FileResponseDto.fromJson(copied.first as Map<String, dynamic>);
+    } on DioException catch (e) {
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+
   Future<Uint8List> getThumbnail(String id, {String size = '256'}) async {
     try {
       final response = await _dio.get<List<int>>(
@@ -125,4 +293,9 @@ class FileRemoteDatasource {
       throw ErrorHandler.mapDioToServerException(e);
     }
   }
+
+  /// Only apply the change if the file still has the ETag [ifMatch]; the
+  /// server answers 412 otherwise.
+  Options? _conditional(String? ifMatch) =>
+      ifMatch == null ? null : Options(headers: {'If-Match': ifMatch});
 }
@@ -0,0 +1,86 @@
+import 'package:dio/dio.dart';
+
+import '../../../core/error/error_handler.dart';
+
+/// A started Login Flow v2: the page the user signs in on, and where to
+/// poll for the result.
+class LoginFlowSession {
+  final String loginUrl;
+  final String pollEndpoint;
+  final String token;
+
+  const LoginFlowSession({
+    required this.loginUrl,
+    required this.pollEndpoint,
+    required this.token,
+  });
+
+  factory LoginFlowSession.fromJson(Map<String, dynamic> json) {
+    final poll = json['poll'] as Map<String, dynamic>;
+    return LoginFlowSession(
+      loginUrl: json['login'] as String,
+      pollEndpoint: poll['endpoint'] as String,
+      token: poll['token'] as String,
+    );
+  }
+}
+
+/// What the server hands over once the user grants access.
+class LoginFlowCredentials {
+  final String server;
+  final String loginName;
+  final String appPassword;
+
+  const LoginFlowCredentials({
+    required this.server,
+    required this.loginName,
+    required this.appPassword,
+  });
+
+  factory LoginFlowCredentials.fromJson(Map<String, dynamic> json) {
+    return LoginFlowCredentials(
+      server: json['server'] as String,
+      loginName: json['loginName'] as String,
+      appPassword: json['appPassword'] as String,
+    );
+  }
+}
+
+/// Nextcloud's Login Flow v2, served outside the `/api` base URL.
+class LoginFlowRemoteDatasource {
+  final Dio _dio;
+
+  LoginFlowRemoteDatasource(this._dio);
+
+  /// Start a flow on [serverUrl]. The server names the app password
+  /// after the `User-Agent`, so [deviceName] is sent as one.
+  Future<LoginFlowSession> start(String serverUrl, String deviceName) async {
+    try {
+      final response = await _dio.post(
+        '$serverUrl/index.php/login/v2',
+        options: Options(headers: {'User-Agent': deviceName}),
+      );
+      return LoginFlowSession.fromJson(response.data as Map<String, dynamic>);
+    } on DioException catch (e) {
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+
+  /// The credentials, or null while the user has not granted access yet.
+  Future<LoginFlowCredentials?> poll(LoginFlowSession session) async {
+    try {
+      final response = await _dio.post(
+        session.pollEndpoint,
+        data: {'token': session.token},
+        options: Options(contentType: Headers.formUrlEncodedContentType),
+      );
+      return LoginFlowCredentials.fromJson(
+        response.data as Map<String, dynamic>,
+      );
+    } on DioException catch (e) {
+      // 404 until the user is done in the browser
+      if (e.response?.statusCode == 404) return null;
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+}
@@ -0,0 +1,67 @@
+import 'dart:io';
+
+import 'package:dio/dio.dart';
+
+import '../../../core/error/error_handler.dart';
+import '../../../core/network/api_endpoints.dart';
+import '../../../core/network/rate_limiter.dart';
+import '../../dtos/files/file_dto.dart';
+import '../../dtos/versions/version_dtos.dart';
+
+class VersionRemoteDatasource {
+  final Dio _dio;
+  final RateLimiter? _downloadLimit;
+
+  VersionRemoteDatasource(this._dio, {RateLimiter? downloadLimit})
+    : _downloadLimit = downloadLimit;
+
+  Future<List<FileVersionResponseDto>> listVersions(String fileId) async {
+    try {
+      final response = await _dio.get(ApiEndpoints.fileVersions(fileId));
+      final list = response.data as List<dynamic>;
+      return list
+          .map(
+            (e) => FileVersionResponseDto.fromJson(e as Map<String, dynamic>),
+          )
+          .toList();
+    } on DioException catch (e) {
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+
+  Future<void> downloadVersionToPath(
+    String fileId,
+    String versionId,
+    String savePath,
+  ) async {
+    try {
+      final response = await _dio.get<ResponseBody>(
+        ApiEndpoints.fileVersion(fileId, versionId),
+        options: Options(responseType: ResponseType.stream),
+      );
+      final sink = File(savePath).openWrite();
+      try {
+        final body = response.data!.stream;
+        await sink.addStream(_downloadLimit?.throttle(body) ?? body);
+      } finally {
+        await sink.close();
+      }
+    } on DioException catch (e) {
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+
+  Future<FileResponseDto> restoreVersion(
+    String fileId,
+    String versionId,
+  ) async {
+    try {
+      final response = await _dio.post(
+        ApiEndpoints.fileVersionRestore(fileId, versionId),
+      );
+      return FileResponseDto.fromJson(response.data as Map<String, dynamic>);
+    } on DioException catch (e) {
+      throw ErrorHandler.mapDioToServerException(e);
+    }
+  }
+}
@@ -121,9 +121,13 @@ class AuthStatusDto {
   final bool adminExists;
   final bool registrationEnabled;
 
+  /// Checksum types the server verifies on upload, e.g. `sha256`.
+  final List<String> checksumAlgorithms;
+
   const AuthStatusDto({
     required this.adminExists,
     this.registrationEnabled = true,
+    this.checksumAlgorithms = const [],
   });
 
   factory AuthStatusDto.fromJson(Map<String, dynamic> json) =>
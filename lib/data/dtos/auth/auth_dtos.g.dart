@@ -110,10 +110,16 @@ AuthStatusDto _$AuthStatusDtoFromJson(Map<String, dynamic> json) =>
     AuthStatusDto(
       adminExists: json['admin_exists'] as bool,
       registrationEnabled: json['registration_enabled'] as bool? ?? true,
+      checksumAlgorithms:
+          (json['checksum_algorithms'] as List<dynamic>?)
+              ?.map((e) => e as String)
+              .toList() ??
+          const [],
     );
 
 Map<String, dynamic> _$AuthStatusDtoToJson(AuthStatusDto instance) =>
     <String, dynamic>{
       'admin_exists': instance.adminExists,
       'registration_enabled': instance.registrationEnabled,
+      'checksum_algorithms': instance.checksumAlgorithms,
     };
@@ -11,10 +11,12 @@ class FileResponseDto {
   final String mimeType;
   final String? folderId;
   final String? ownerId;
+  final String? etag;
   final DateTime? createdAt;
   final DateTime? modifiedAt;
   final String? sizeFormatted;
   final String? iconClass;
+  final String? mountType;
 
   const FileResponseDto({
     required this.id,
@@ -24,10 +26,12 @@ class FileResponseDto {
     required this.mimeType,
     this.folderId,
     this.ownerId,
+    this.etag,
     this.createdAt,
     this.modifiedAt,
     this.sizeFormatted,
     this.iconClass,
+    this.mountType,
   });
 
   factory FileResponseDto.fromJson(Map<String, dynamic> json) =>
@@ -15,6 +15,7 @@ FileResponseDto _$FileResponseDtoFromJson(Map<String, dynamic> json) =>
       mimeType: json['mime_type'] as String,
       folderId: json['folder_id'] as String?,
       ownerId: json['owner_id'] as String?,
+      etag: json['etag'] as String?,
       createdAt: json['created_at'] == null
           ? null
           : DateTime.parse(json['created_at'] as String),
@@ -23,6 +24,7 @@ FileResponseDto _$FileResponseDtoFromJson(Map<String, dynamic> json) =>
           : DateTime.parse(json['modified_at'] as String),
       sizeFormatted: json['size_formatted'] as String?,
       iconClass: json['icon_class'] as String?,
+      mountType: json['mount_type'] as String?,
     );
 
 Map<String, dynamic> _$FileResponseDtoToJson(FileResponseDto instance) =>
@@ -34,8 +36,10 @@ Map<String, dynamic> _$FileResponseDtoToJson(FileResponseDto instance) =>
       'mime_type': instance.mimeType,
       'folder_id': instance.folderId,
       'owner_id': instance.ownerId,
+      'etag': instance.etag,
       'created_at': instance.createdAt?.toIso8601String(),
       'modified_at': instance.modifiedAt?.toIso8601String(),
       'size_formatted': instance.sizeFormatted,
       'icon_class': instance.iconClass,
+      'mount_type': instance.mountType,
     };
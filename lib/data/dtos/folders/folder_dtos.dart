@@ -13,6 +13,9 @@ class FolderResponseDto {
   final DateTime? createdAt;
   final DateTime? modifiedAt;
 
+  /// Set for folders on external storage, e.g. `smb` or `s3`.
+  final String? mountType;
+
   const FolderResponseDto({
     required this.id,
     required this.name,
@@ -22,6 +25,7 @@ class FolderResponseDto {
     this.isRoot,
     this.createdAt,
     this.modifiedAt,
+    this.mountType,
   });
 
   factory FolderResponseDto.fromJson(Map<String, dynamic> json) =>
@@ -20,6 +20,7 @@ FolderResponseDto _$FolderResponseDtoFromJson(Map<String, dynamic> json) =>
       modifiedAt: json['modified_at'] == null
           ? null
           : DateTime.parse(json['modified_at'] as String),
+      mountType: json['mount_type'] as String?,
     );
 
 Map<String, dynamic> _$FolderResponseDtoToJson(FolderResponseDto instance) =>
@@ -32,6 +33,7 @@ Map<String, dynamic> _$FolderResponseDtoToJson(FolderResponseDto instance) =>
       'is_root': instance.isRoot,
       'created_at': instance.createdAt?.toIso8601String(),
       'modified_at': instance.modifiedAt?.toIso8601String(),
+      'mount_type': instance.mountType,
     };
 
 CreateFolderRequestDto _$CreateFolderRequestDtoFromJson(
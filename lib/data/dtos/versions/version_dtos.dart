@@ -0,0 +1,26 @@
+import 'package:json_annotation/json_annotation.dart';
+
+part 'version_dtos.g.dart';
+
+@JsonSerializable(fieldRename: FieldRename.snake)
+class FileVersionResponseDto {
+  final String id;
+  final String fileId;
+  final int size;
+  final DateTime modifiedAt;
+  final String? etag;
+  final String? modifiedBy;
+
+  const FileVersionResponseDto({
+    required this.id,
+    required this.fileId,
+    required this.size,
+    required this.modifiedAt,
+    this.etag,
+    this.modifiedBy,
+  });
+
+  factory FileVersionResponseDto.fromJson(Map<String, dynamic> json) =>
+      _$FileVersionResponseDtoFromJson(json);
+  Map<String, dynamic> toJson() => _$FileVersionResponseDtoToJson(this);
+}
@@ -0,0 +1,29 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+
+part of 'version_dtos.dart';
+
+// **************************************************************************
+// JsonSerializableGenerator
+// **************************************************************************
+
+FileVersionResponseDto _$FileVersionResponseDtoFromJson(
+  Map<String, dynamic> json,
+) => FileVersionResponseDto(
+  id: json['id'] as String,
+  fileId: json['file_id'] as String,
+  size: (json['size'] as num).toInt(),
+  modifiedAt: DateTime.parse(json['modified_at'] as String),
+  etag: json['etag'] as String?,
+  modifiedBy: json['modified_by'] as String?,
+);
+
+Map<String, dynamic> _$FileVersionResponseDtoToJson(
+  FileVersionResponseDto instance,
+) => <String, dynamic>{
+  'id': instance.id,
+  'file_id': instance.fileId,
+  'size': instance.size,
+  'modified_at': instance.modifiedAt.toIso8601String(),
+  'etag': instance.etag,
+  'modified_by': instance.modifiedBy,
+};
@@ -13,8 +13,10 @@ class FileMapper {
       mimeType: dto.mimeType,
       folderId: dto.folderId,
       ownerId: dto.ownerId,
+      etag: dto.etag,
       createdAt: dto.createdAt ?? DateTime.now(),
       modifiedAt: dto.modifiedAt ?? DateTime.now(),
+      mountType: dto.mountType,
     );
   }
 
@@ -31,8 +33,10 @@ class FileMapper {
       mimeType: entity.mimeType,
       folderId: entity.folderId,
       ownerId: entity.ownerId,
+      etag: entity.etag,
       createdAt: entity.createdAt,
       modifiedAt: entity.modifiedAt,
+      mountType: entity.mountType,
     );
   }
 }
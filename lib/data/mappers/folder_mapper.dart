@@ -14,6 +14,7 @@ class FolderMapper {
       isRoot: dto.isRoot ?? false,
       createdAt: dto.createdAt ?? DateTime.now(),
       modifiedAt: dto.modifiedAt ?? DateTime.now(),
+      mountType: dto.mountType,
     );
   }
 
@@ -0,0 +1,25 @@
+import '../../domain/entities/file_version_entity.dart';
+import '../dtos/versions/version_dtos.dart';
+
+class VersionMapper {
+  VersionMapper._();
+
+  static FileVersionEntity fromDto(FileVersionResponseDto dto) {
+    return FileVersionEntity(
+      id: dto.id,
+      fileId: dto.fileId,
+      size: dto.size,
+      modifiedAt: dto.modifiedAt,
+      etag: dto.etag,
+      modifiedBy: dto.modifiedBy,
+    );
+  }
+
+  /// Newest first, whatever order the server answered in.
+  static List<FileVersionEntity> fromDtoList(
+    List<FileVersionResponseDto> dtos,
+  ) {
+    return dtos.map(fromDto).toList()
+      ..sort((a, b) => b.modifiedAt.compareTo(a.modifiedAt));
+  }
+}
@@ -1,7 +1,12 @@
+import 'package:logger/logger.dart';
+
+import '../../core/auth/auth_method.dart';
 import '../../core/auth/secure_storage.dart';
+import '../../core/platform/platform_info.dart';
 import '../../domain/entities/auth_tokens_entity.dart';
 import '../../domain/entities/user_entity.dart';
 import '../../domain/repositories/auth_repository.dart';
+import '../datasources/remote/app_password_remote_datasource.dart';
 import '../datasources/remote/auth_remote_datasource.dart';
 import '../dtos/auth/auth_dtos.dart';
 import '../mappers/auth_mapper.dart';
@@ -9,12 +14,16 @@ import '../mappers/auth_mapper.dart';
 class AuthRepositoryImpl implements AuthRepository {
   final AuthRemoteDatasource _remote;
   final SecureStorage _secureStorage;
+  final AppPasswordRemoteDatasource? _appPasswords;
+  final Logger _logger = Logger();
 
   AuthRepositoryImpl({
     required AuthRemoteDatasource remote,
     required SecureStorage secureStorage,
+    AppPasswordRemoteDatasource? appPasswords,
   }) : _remote = remote,
-       _secureStorage = secureStorage;
+       _secureStorage = secureStorage,
+       _appPasswords = appPasswords;
 
   @override
   Future<({bool adminExists, bool registrationEnabled})> getStatus() async {
@@ -53,9 +62,33 @@ class AuthRepositoryImpl implements AuthRepository {
     );
     final result = AuthMapper.authResponseFromDto(dto);
     await _persistTokens(result.$2, result.$1);
+    await _secureStorage.saveUsername(username);
+    await _switchToAppPassword();
     return result;
   }
 
+  @override
+  Future<UserEntity> loginWithAppPassword({
+    required String username,
+    required String appPassword,
+  }) async {
+    await _secureStorage.clearSession();
+    // No id: the user made it and is the one to revoke it.
+    await _secureStorage.saveAppPassword('', appPassword);
+    await _secureStorage.saveUsername(username);
+    await _secureStorage.saveAuthMethod(AuthMethod.appPassword);
+    try {
+      final user = AuthMapper.userFromDto(
+        await _remote.getCurrentUser(checkingCredentials: true),
+      );
+      await _secureStorage.saveUserId(user.id);
+      return user;
+    } catch (_) {
+      await _secureStorage.clearSession();
+      rethrow;
+    }
+  }
+
   @override
   Future<(UserEntity, AuthTokens)> register({
     required String username,
@@ -104,13 +137,53 @@ class AuthRepositoryImpl implements AuthRepository {
   @override
   Future<void> logout() async {
     try {
-      await _remote.logout();
+      await _revokeAppPassword();
+      // App-password accounts have no server session to end.
+      if (await _secureStorage.getAuthMethod() == AuthMethod.token) {
+        await _remote.logout();
+      }
     } finally {
       await _secureStorage.clearSession();
     }
   }
 
+  /// Give this device its own app password and sign in with it from now
+  /// on, so the device can be revoked on its own. The session the login
+  /// opened is ended. Servers without app passwords keep the session.
+  Future<void> _switchToAppPassword() async {
+    final appPasswords = _appPasswords;
+    if (appPasswords == null) return;
+    final AppPasswordCreateResult created;
+    try {
+      await _revokeAppPassword();
+      created = await appPasswords.create(PlatformInfo.deviceName);
+    } catch (e) {
+      _logger.w('Could not provision app password', error: e);
+      return;
+    }
+    try {
+      await _remote.logout();
+    } catch (e) {
+      _logger.w('Could not end the login session', error: e);
+    }
+    await _secureStorage.saveAppPassword(created.id, created.password);
+    await _secureStorage.saveAuthMethod(AuthMethod.appPassword);
+    await _secureStorage.clearTokens();
+  }
+
+  Future<void> _revokeAppPassword() async {
+    final appPasswords = _appPasswords;
+    final current = await _secureStorage.getAppPassword();
+    if (appPasswords == null || current == null || current.id.isEmpty) return;
+    try {
+      await appPasswords.revoke(current.id);
+    } catch (e) {
+      _logger.w('Could not revoke app password ${current.id}', error: e);
+    }
+  }
+
   Future<void> _persistTokens(AuthTokens tokens, UserEntity user) async {
+    await _secureStorage.saveAuthMethod(AuthMethod.token);
     await _secureStorage.saveAccessToken(tokens.accessToken);
     await _secureStorage.saveRefreshToken(tokens.refreshToken);
     await _secureStorage.saveTokenExpiry(tokens.expiresAt);
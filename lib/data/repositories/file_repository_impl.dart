@@ -1,11 +1,14 @@
+import 'dart:async';
 import 'dart:typed_data';
 
 import '../../core/database/app_database.dart';
 import '../../core/network/connectivity_service.dart';
+import '../../core/sync/sync_events.dart';
 import '../../domain/entities/file_entity.dart';
 import '../../domain/repositories/file_repository.dart';
 import '../datasources/remote/file_remote_datasource.dart';
 import '../mappers/file_mapper.dart';
+import 'package:dio/dio.dart' show CancelToken;
 import 'package:drift/drift.dart';
 
 class FileRepositoryImpl implements FileRepository {
@@ -13,13 +16,22 @@ class FileRepositoryImpl implements FileRepository {
   final AppDatabase _db;
   final ConnectivityService _connectivity;
 
+  /// Told which folders changed, see [FolderChanged].
+  final SyncEventBus? _events;
+
   FileRepositoryImpl({
     required FileRemoteDatasource remote,
     required AppDatabase db,
     required ConnectivityService connectivity,
+    SyncEventBus? events,
   }) : _remote = remote,
        _db = db,
-       _connectivity = connectivity;
+       _connectivity = connectivity,
+       _events = events;
+
+  void _changed(String? folderId) {
+    _events?.emit(FolderChanged(folderId: folderId));
+  }
 
   @override
   Future<List<FileEntity>> listFiles({String? folderId}) async {
@@ -61,6 +73,8 @@ class FileRepositoryImpl implements FileRepository {
     required Stream<List<int>> fileStream,
     required int fileSize,
     required String mimeType,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
   }) async {
     final dto = await _remote.uploadFile(
       name: name,
@@ -68,12 +82,55 @@ class FileRepositoryImpl implements FileRepository {
       fileStream: fileStream,
       fileSize: fileSize,
       mimeType: mimeType,
+      onSendProgress: onProgress,
+      cancelToken: _cancelTokenFor(stop),
     );
     final entity = FileMapper.fromDto(dto);
     await _db.upsertFile(_entityToCompanion(entity));
+    await _db.insertLedgerEntry(
+      TransferLedgerTableCompanion.insert(
+        itemId: entity.id,
+        itemName: entity.name,
+        size: fileSize,
+        remoteModifiedAt: Value(entity.modifiedAt),
+        uploadedAt: DateTime.now(),
+      ),
+    );
+    _changed(folderId);
     return entity;
   }
 
+  @override
+  Future<BatchUploadResult> uploadFiles({
+    required String? folderId,
+    required List<UploadSource> files,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  }) async {
+    final result = await _remote.uploadFiles(
+      folderId: folderId,
+      files: files,
+      onSendProgress: onProgress,
+      cancelToken: _cancelTokenFor(stop),
+    );
+    final sizes = {for (final f in files) f.name: f.size};
+    final uploaded = FileMapper.fromDtoList(result.uploaded);
+    for (final entity in uploaded) {
+      await _db.upsertFile(_entityToCompanion(entity));
+      await _db.insertLedgerEntry(
+        TransferLedgerTableCompanion.insert(
+          itemId: entity.id,
+          itemName: entity.name,
+          size: sizes[entity.name] ?? entity.size,
+          remoteModifiedAt: Value(entity.modifiedAt),
+          uploadedAt: DateTime.now(),
+        ),
+      );
+    }
+    if (uploaded.isNotEmpty) _changed(folderId);
+    return (uploaded: uploaded, failed: result.failed);
+  }
+
   @override
   Future<Stream<List<int>>> downloadFile(String id) async {
     final body = await _remote.downloadFile(id);
@@ -81,32 +138,75 @@ class FileRepositoryImpl implements FileRepository {
   }
 
   @override
-  Future<String> downloadFileToPath(String id, String localPath) async {
-    await _remote.downloadFileToPath(id, localPath);
+  Future<String> downloadFileToPath(
+    String id,
+    String localPath, {
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+    bool resume = false,
+    String? ifRange,
+    void Function(String? etag)? onEtag,
+  }) async {
+    await _remote.downloadFileToPath(
+      id,
+      localPath,
+      onReceiveProgress: onProgress,
+      cancelToken: _cancelTokenFor(stop),
+      resume: resume,
+      ifRange: ifRange,
+      onEtag: onEtag,
+    );
     return localPath;
   }
 
   @override
-  Future<void> deleteFile(String id) async {
+  Future<void> deleteFile(String id, {String? ifMatch}) async {
+    final cached = await _db.getFileById(id);
     if (_connectivity.isOnline) {
-      await _remote.deleteFile(id);
+      await _remote.deleteFile(id, ifMatch: ifMatch);
     }
     await _db.deleteFileById(id);
+    if (cached != null) _changed(cached.folderId);
   }
 
   @override
-  Future<FileEntity> renameFile(String id, String newName) async {
-    final dto = await _remote.renameFile(id, newName);
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async {
+    final dto = await _remote.renameFile(id, newName, ifMatch: ifMatch);
     final entity = FileMapper.fromDto(dto);
     await _db.upsertFile(_entityToCompanion(entity));
+    _changed(entity.folderId);
     return entity;
   }
 
   @override
-  Future<FileEntity> moveFile(String id, String targetFolderId) async {
-    final dto = await _remote.moveFile(id, targetFolderId);
+  Future<FileEntity> moveFile(
+    String id,
+    String targetFolderId, {
+    String? ifMatch,
+  }) async {
+    final cached = await _db.getFileById(id);
+    final dto = await _remote.moveFile(
+      id,
+      targetFolderId,
+      ifMatch: ifMatch,
+    );
     final entity = FileMapper.fromDto(dto);
     await _db.upsertFile(_entityToCompanion(entity));
+    if (cached != null) _changed(cached.folderId);
+    _changed(targetFolderId);
+    return entity;
+  }
+
+  @override
+  Future<FileEntity> copyFile(String id, String targetFolderId) async {
+    final dto = await _remote.copyFile(id, targetFolderId);
+    final entity = FileMapper.fromDto(dto);
+    await _db.upsertFile(_entityToCompanion(entity));
+    _changed(targetFolderId);
     return entity;
   }
 
@@ -138,9 +238,17 @@ class FileRepositoryImpl implements FileRepository {
       isFavorite: row.isFavorite,
       isAvailableOffline: row.isAvailableOffline,
       localCachePath: row.localCachePath,
+      mountType: row.mountType,
     );
   }
 
+  static CancelToken? _cancelTokenFor(Future<void>? stop) {
+    if (stop == null) return null;
+    final token = CancelToken();
+    unawaited(stop.then((_) => token.cancel('Stopped')));
+    return token;
+  }
+
   FilesTableCompanion _entityToCompanion(FileEntity e) {
     return FilesTableCompanion(
       id: Value(e.id),
@@ -157,6 +265,7 @@ class FileRepositoryImpl implements FileRepository {
       isFavorite: Value(e.isFavorite),
       isAvailableOffline: Value(e.isAvailableOffline),
       localCachePath: Value(e.localCachePath),
+      mountType: Value.absentIfNull(e.mountType),
     );
   }
 }
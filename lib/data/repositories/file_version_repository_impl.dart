@@ -0,0 +1,35 @@
+import '../../domain/entities/file_entity.dart';
+import '../../domain/entities/file_version_entity.dart';
+import '../../domain/repositories/file_version_repository.dart';
+import '../datasources/remote/version_remote_datasource.dart';
+import '../mappers/file_mapper.dart';
+import '../mappers/version_mapper.dart';
+
+class FileVersionRepositoryImpl implements FileVersionRepository {
+  final VersionRemoteDatasource _remote;
+
+  FileVersionRepositoryImpl({required VersionRemoteDatasource remote})
+    : _remote = remote;
+
+  @override
+  Future<List<FileVersionEntity>> listVersions(String fileId) async {
+    final dtos = await _remote.listVersions(fileId);
+    return VersionMapper.fromDtoList(dtos);
+  }
+
+  @override
+  Future<String> downloadVersionToPath(
+    String fileId,
+    String versionId,
+    String localPath,
+  ) async {
+    await _remote.downloadVersionToPath(fileId, versionId, localPath);
+    return localPath;
+  }
+
+  @override
+  Future<FileEntity> restoreVersion(String fileId, String versionId) async {
+    final dto = await _remote.restoreVersion(fileId, versionId);
+    return FileMapper.fromDto(dto);
+  }
+}
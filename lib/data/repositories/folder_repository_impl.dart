@@ -1,8 +1,12 @@
 import '../../core/database/app_database.dart';
 import '../../core/network/connectivity_service.dart';
+import '../../core/sync/propagation_policy.dart';
+import '../../core/sync/sync_events.dart';
+import '../../core/sync/sync_profile.dart';
 import '../../domain/entities/file_entity.dart';
 import '../../domain/entities/folder_entity.dart';
 import '../../domain/repositories/folder_repository.dart';
+import '../datasources/remote/file_remote_datasource.dart';
 import '../datasources/remote/folder_remote_datasource.dart';
 import '../dtos/folders/folder_dtos.dart';
 import '../mappers/file_mapper.dart';
@@ -11,16 +15,38 @@ import 'package:drift/drift.dart';
 
 class FolderRepositoryImpl implements FolderRepository {
   final FolderRemoteDatasource _remote;
+  final FileRemoteDatasource _fileRemote;
   final AppDatabase _db;
   final ConnectivityService _connectivity;
 
+  /// Let files vanish from the local copy of external storage when they
+  /// vanish from its listing, see [SyncProfile].
+  final bool _propagateExternalDeletes;
+
+  /// Told which folders changed, see [FolderChanged].
+  final SyncEventBus? _events;
+
+  /// Keeps deletions out of backup folders.
+  final PropagationPolicy _propagation;
+
   FolderRepositoryImpl({
     required FolderRemoteDatasource remote,
+    required FileRemoteDatasource fileRemote,
     required AppDatabase db,
     required ConnectivityService connectivity,
+    bool propagateExternalDeletes = false,
+    SyncEventBus? events,
   }) : _remote = remote,
+       _fileRemote = fileRemote,
        _db = db,
-       _connectivity = connectivity;
+       _connectivity = connectivity,
+       _propagateExternalDeletes = propagateExternalDeletes,
+       _events = events,
+       _propagation = PropagationPolicy(db);
+
+  void _changed(String? folderId) {
+    _events?.emit(FolderChanged(folderId: folderId));
+  }
 
   @override
   Future<List<FolderEntity>> listRootFolders() async {
@@ -37,14 +63,39 @@ class FolderRepositoryImpl implements FolderRepository {
     return _getLocalFolders(null);
   }
 
+  @override
+  Future<FolderContents> listRootContents() async {
+    if (_connectivity.isOnline) {
+      try {
+        final folders = FolderMapper.fromDtoList(
+          await _remote.listRootFolders(),
+        );
+        final files = FileMapper.fromDtoList(await _fileRemote.listFiles());
+        await _storeListing(null, folders, files);
+        return FolderContents(folders: folders, files: files);
+      } catch (_) {
+        return _getLocalContents(null);
+      }
+    }
+    return _getLocalContents(null);
+  }
+
   @override
   Future<FolderContents> listFolderContents(String folderId) async {
     if (_connectivity.isOnline) {
       try {
         final result = await _remote.listFolderContents(folderId);
-        final folders = FolderMapper.fromDtoList(result.folders);
-        final files = FileMapper.fromDtoList(result.files);
-        await _cacheFolders(folders);
+        // Items on external storage don't always say so themselves.
+        final mount = (await _db.getFolderById(folderId))?.mountType;
+        final folders = [
+          for (final f in FolderMapper.fromDtoList(result.folders))
+            f.copyWith(mountType: f.mountType ?? mount),
+        ];
+        final files = [
+          for (final f in FileMapper.fromDtoList(result.files))
+            f.copyWith(mountType: f.mountType ?? mount),
+        ];
+        await _storeListing(folderId, folders, files, mountType: mount);
         return FolderContents(folders: folders, files: files);
       } catch (_) {
         return _getLocalContents(folderId);
@@ -76,6 +127,7 @@ class FolderRepositoryImpl implements FolderRepository {
     );
     final entity = FolderMapper.fromDto(dto);
     await _db.upsertFolder(_entityToCompanion(entity));
+    _changed(parentId);
     return entity;
   }
 
@@ -84,23 +136,29 @@ class FolderRepositoryImpl implements FolderRepository {
     final dto = await _remote.renameFolder(id, newName);
     final entity = FolderMapper.fromDto(dto);
     await _db.upsertFolder(_entityToCompanion(entity));
+    _changed(entity.parentId);
     return entity;
   }
 
   @override
   Future<FolderEntity> moveFolder(String id, String? newParentId) async {
+    final cached = await _db.getFolderById(id);
     final dto = await _remote.moveFolder(id, newParentId);
     final entity = FolderMapper.fromDto(dto);
     await _db.upsertFolder(_entityToCompanion(entity));
+    if (cached != null) _changed(cached.parentId);
+    _changed(newParentId);
     return entity;
   }
 
   @override
   Future<void> deleteFolder(String id) async {
+    final cached = await _db.getFolderById(id);
     if (_connectivity.isOnline) {
       await _remote.deleteFolder(id);
     }
     await _db.deleteFolderById(id);
+    if (cached != null) _changed(cached.parentId);
   }
 
   @override
@@ -111,10 +169,12 @@ class FolderRepositoryImpl implements FolderRepository {
 
   // --- Private helpers ---
 
-  Future<FolderContents> _getLocalContents(String folderId) async {
+  Future<FolderContents> _getLocalContents(String? folderId) async {
     final folderRows = await _db.getFoldersInParent(folderId);
     final fileRows = await _db.getFilesInFolder(folderId);
     return FolderContents(
+      fromCache: true,
+      listedAt: await _db.getListedAt(folderId),
       folders: folderRows.map(_dataToEntity).toList(),
       files: fileRows
           .map(
@@ -130,6 +190,7 @@ class FolderRepositoryImpl implements FolderRepository {
               isFavorite: r.isFavorite,
               isAvailableOffline: r.isAvailableOffline,
               localCachePath: r.localCachePath,
+              mountType: r.mountType,
             ),
           )
           .toList(),
@@ -146,6 +207,37 @@ class FolderRepositoryImpl implements FolderRepository {
     await _db.upsertFolders(companions);
   }
 
+  Future<void> _storeListing(
+    String? folderId,
+    List<FolderEntity> folders,
+    List<FileEntity> files, {
+    String? mountType,
+  }) async {
+    var backup = false;
+    if (folderId != null) {
+      final folder = await _db.getFolderById(folderId);
+      if (folder != null) {
+        if (await _db.isPathExcludedFromSync(folder.path)) return;
+        backup = await _propagation.isBackup(folder.path);
+      }
+    }
+    if (backup) {
+      await _propagation.logRemoteDeletes(folderId!, {
+        for (final f in files) f.id,
+      });
+    }
+    final profile = SyncProfile.forMount(
+      mountType,
+      propagateExternalDeletes: _propagateExternalDeletes,
+    );
+    await _db.storeListing(
+      folderId,
+      folders.map(_entityToCompanion).toList(),
+      files.map(_fileToCompanion).toList(),
+      keepOfflineFiles: !profile.propagateDeletes || backup,
+    );
+  }
+
   FolderEntity _dataToEntity(FoldersTableData row) {
     return FolderEntity(
       id: row.id,
@@ -156,6 +248,7 @@ class FolderRepositoryImpl implements FolderRepository {
       isRoot: row.isRoot,
       createdAt: row.createdAt,
       modifiedAt: row.modifiedAt,
+      mountType: row.mountType,
     );
   }
 
@@ -169,6 +262,27 @@ class FolderRepositoryImpl implements FolderRepository {
       isRoot: Value(e.isRoot),
       createdAt: Value(e.createdAt),
       modifiedAt: Value(e.modifiedAt),
+      mountType: Value.absentIfNull(e.mountType),
+    );
+  }
+
+  /// Leaves the offline flags alone so mirroring a listing keeps files the
+  /// user made available offline.
+  FilesTableCompanion _fileToCompanion(FileEntity e) {
+    return FilesTableCompanion(
+      id: Value(e.id),
+      name: Value(e.name),
+      path: Value(e.path),
+      size: Value(e.size),
+      mimeType: Value(e.mimeType),
+      folderId: Value(e.folderId),
+      ownerId: Value(e.ownerId),
+      hash: Value(e.hash),
+      etag: Value(e.etag),
+      createdAt: Value(e.createdAt),
+      modifiedAt: Value(e.modifiedAt),
+      isFavorite: Value(e.isFavorite),
+      mountType: Value.absentIfNull(e.mountType),
     );
   }
 }
@@ -0,0 +1,78 @@
+import '../../core/auth/secure_storage.dart';
+import '../../domain/entities/session_entity.dart';
+import '../../domain/repositories/auth_repository.dart';
+import '../../domain/repositories/session_repository.dart';
+import '../datasources/remote/app_password_remote_datasource.dart';
+import '../datasources/remote/device_auth_remote_datasource.dart';
+
+class SessionRepositoryImpl implements SessionRepository {
+  final DeviceAuthRemoteDatasource _devices;
+  final AppPasswordRemoteDatasource _appPasswords;
+  final SecureStorage _secureStorage;
+  final AuthRepository _auth;
+
+  SessionRepositoryImpl({
+    required DeviceAuthRemoteDatasource devices,
+    required AppPasswordRemoteDatasource appPasswords,
+    required SecureStorage secureStorage,
+    required AuthRepository auth,
+  }) : _devices = devices,
+       _appPasswords = appPasswords,
+       _secureStorage = secureStorage,
+       _auth = auth;
+
+  @override
+  Future<List<SessionEntity>> listSessions() async {
+    final results = await Future.wait([
+      _devices.listDevices(),
+      _appPasswords.list(),
+    ]);
+    final devices = results[0] as List<DeviceInfo>;
+    final passwords = results[1] as List<AppPasswordDto>;
+    final currentId = (await _secureStorage.getAppPassword())?.id;
+
+    final sessions = [
+      for (final d in devices)
+        SessionEntity(
+          id: d.id,
+          kind: SessionKind.device,
+          name: d.name,
+          platform: d.platform,
+          createdAt: DateTime.tryParse(d.createdAt),
+          lastUsed: d.lastUsed != null ? DateTime.tryParse(d.lastUsed!) : null,
+        ),
+      for (final p in passwords)
+        SessionEntity(
+          id: p.id,
+          kind: SessionKind.appPassword,
+          name: p.name,
+          createdAt: DateTime.tryParse(p.createdAt),
+          lastUsed: p.lastUsed != null ? DateTime.tryParse(p.lastUsed!) : null,
+          isCurrent: p.id == currentId,
+        ),
+    ];
+    sessions.sort((a, b) {
+      if (a.isCurrent != b.isCurrent) return a.isCurrent ? -1 : 1;
+      final at = a.lastUsed ?? a.createdAt;
+      final bt = b.lastUsed ?? b.createdAt;
+      if (at == null || bt == null) return at == null ? 1 : -1;
+      return bt.compareTo(at);
+    });
+    return sessions;
+  }
+
+  @override
+  Future<void> revokeSession(SessionEntity session) async {
+    if (session.isCurrent) {
+      // Logout revokes this device's app password as part of signing out.
+      await _auth.logout();
+      return;
+    }
+    switch (session.kind) {
+      case SessionKind.device:
+        await _devices.revokeDevice(session.id);
+      case SessionKind.appPassword:
+        await _appPasswords.revoke(session.id);
+    }
+  }
+}
@@ -1,4 +1,5 @@
 import '../../domain/entities/share_entity.dart';
+import '../../domain/entities/share_template.dart';
 import '../../domain/repositories/share_repository.dart';
 import '../datasources/remote/share_remote_datasource.dart';
 import '../dtos/shares/share_dtos.dart';
@@ -18,7 +19,19 @@ class ShareRepositoryImpl implements ShareRepository {
     String? password,
     DateTime? expiresAt,
     SharePermissions? permissions,
+    ShareTemplate? template,
   }) async {
+    if (template != null) {
+      if (template.requirePassword && (password?.isEmpty ?? true)) {
+        throw ArgumentError.value(
+          password,
+          'password',
+          'The "${template.name}" template requires a password',
+        );
+      }
+      expiresAt ??= template.expiresAt(DateTime.now());
+      permissions ??= template.permissions;
+    }
     final dto = CreateShareRequestDto(
       itemId: itemId,
       itemType: itemType,
@@ -16,6 +16,10 @@ class FileEntity extends Equatable {
   final bool isAvailableOffline;
   final String? localCachePath;
 
+  /// Type of external storage the file lives on (e.g. `smb`, `s3`), null
+  /// for the server's own storage.
+  final String? mountType;
+
   const FileEntity({
     required this.id,
     required this.name,
@@ -31,6 +35,7 @@ class FileEntity extends Equatable {
     this.isFavorite = false,
     this.isAvailableOffline = false,
     this.localCachePath,
+    this.mountType,
   });
 
   String get sizeFormatted {
@@ -51,6 +56,7 @@ class FileEntity extends Equatable {
   bool get isVideo => mimeType.startsWith('video/');
   bool get isAudio => mimeType.startsWith('audio/');
   bool get isPdf => mimeType == 'application/pdf';
+  bool get isExternalStorage => mountType != null;
 
   FileEntity copyWith({
     String? name,
@@ -58,6 +64,7 @@ class FileEntity extends Equatable {
     bool? isFavorite,
     bool? isAvailableOffline,
     String? localCachePath,
+    String? mountType,
   }) {
     return FileEntity(
       id: id,
@@ -74,6 +81,7 @@ class FileEntity extends Equatable {
       isFavorite: isFavorite ?? this.isFavorite,
       isAvailableOffline: isAvailableOffline ?? this.isAvailableOffline,
       localCachePath: localCachePath ?? this.localCachePath,
+      mountType: mountType ?? this.mountType,
     );
   }
 
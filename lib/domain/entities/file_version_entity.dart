@@ -0,0 +1,23 @@
+import 'package:equatable/equatable.dart';
+
+/// An earlier state of a file that the server kept when it was overwritten.
+class FileVersionEntity extends Equatable {
+  final String id;
+  final String fileId;
+  final int size;
+  final DateTime modifiedAt;
+  final String? etag;
+  final String? modifiedBy;
+
+  const FileVersionEntity({
+    required this.id,
+    required this.fileId,
+    required this.size,
+    required this.modifiedAt,
+    this.etag,
+    this.modifiedBy,
+  });
+
+  @override
+  List<Object?> get props => [id, fileId, modifiedAt];
+}
@@ -10,6 +10,10 @@ class FolderEntity extends Equatable {
   final DateTime createdAt;
   final DateTime modifiedAt;
 
+  /// Type of external storage the folder lives on (e.g. `smb`, `s3`), null
+  /// for the server's own storage.
+  final String? mountType;
+
   const FolderEntity({
     required this.id,
     required this.name,
@@ -19,9 +23,12 @@ class FolderEntity extends Equatable {
     this.isRoot = false,
     required this.createdAt,
     required this.modifiedAt,
+    this.mountType,
   });
 
-  FolderEntity copyWith({String? name, String? parentId}) {
+  bool get isExternalStorage => mountType != null;
+
+  FolderEntity copyWith({String? name, String? parentId, String? mountType}) {
     return FolderEntity(
       id: id,
       name: name ?? this.name,
@@ -31,6 +38,7 @@ class FolderEntity extends Equatable {
       isRoot: isRoot,
       createdAt: createdAt,
       modifiedAt: modifiedAt,
+      mountType: mountType ?? this.mountType,
     );
   }
 
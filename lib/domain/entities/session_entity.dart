@@ -0,0 +1,30 @@
+import 'package:equatable/equatable.dart';
+
+enum SessionKind { device, appPassword }
+
+/// A client connected to the account: a device authorized through the
+/// device flow, or an app password.
+class SessionEntity extends Equatable {
+  final String id;
+  final SessionKind kind;
+  final String name;
+  final String? platform;
+  final DateTime? createdAt;
+  final DateTime? lastUsed;
+
+  /// Whether this is the session of the app that is running.
+  final bool isCurrent;
+
+  const SessionEntity({
+    required this.id,
+    required this.kind,
+    required this.name,
+    this.platform,
+    this.createdAt,
+    this.lastUsed,
+    this.isCurrent = false,
+  });
+
+  @override
+  List<Object?> get props => [id, kind, name, isCurrent];
+}
@@ -0,0 +1,119 @@
+import 'dart:convert';
+
+import 'share_entity.dart';
+
+/// Settings applied to a new share link, such as "expires in 7 days,
+/// password required, download only".
+class ShareTemplate {
+  final String name;
+
+  /// Days until links made from this template expire, or null for never.
+  final int? expiresInDays;
+  final bool requirePassword;
+  final bool downloadOnly;
+
+  const ShareTemplate({
+    required this.name,
+    this.expiresInDays,
+    this.requirePassword = false,
+    this.downloadOnly = true,
+  });
+
+  DateTime? expiresAt(DateTime now) =>
+      expiresInDays == null ? null : now.add(Duration(days: expiresInDays!));
+
+  SharePermissions get permissions => SharePermissions(write: !downloadOnly);
+
+  /// A short description, e.g. "7 days · password · download only".
+  String get summary => [
+    if (expiresInDays case final days?)
+      days == 1 ? '1 day' : '$days days'
+    else
+      'No expiry',
+    if (requirePassword) 'password',
+    if (downloadOnly) 'download only' else 'editable',
+  ].join(' · ');
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'expires_in_days': expiresInDays,
+    'require_password': requirePassword,
+    'download_only': downloadOnly,
+  };
+
+  factory ShareTemplate.fromJson(Map<String, dynamic> json) => ShareTemplate(
+    name: json['name'] as String,
+    expiresInDays: json['expires_in_days'] as int?,
+    requirePassword: json['require_password'] as bool? ?? false,
+    downloadOnly: json['download_only'] as bool? ?? true,
+  );
+}
+
+/// The user's share templates and which one new links get by default.
+///
+/// [perAccount] is true when these templates belong to the current account
+/// only rather than to every account on this device.
+class ShareTemplates {
+  final List<ShareTemplate> templates;
+  final String defaultName;
+  final bool perAccount;
+
+  const ShareTemplates({
+    required this.templates,
+    required this.defaultName,
+    this.perAccount = false,
+  });
+
+  static const builtIn = ShareTemplates(
+    templates: [
+      ShareTemplate(name: 'Standard'),
+      ShareTemplate(
+        name: 'Private',
+        expiresInDays: 7,
+        requirePassword: true,
+      ),
+    ],
+    defaultName: 'Standard',
+  );
+
+  /// The default template, or the first one if it has been removed.
+  ShareTemplate? get defaultTemplate =>
+      templates.where((t) => t.name == defaultName).firstOrNull ??
+      templates.firstOrNull;
+
+  ShareTemplates copyWith({
+    List<ShareTemplate>? templates,
+    String? defaultName,
+    bool? perAccount,
+  }) {
+    return ShareTemplates(
+      templates: templates ?? this.templates,
+      defaultName: defaultName ?? this.defaultName,
+      perAccount: perAccount ?? this.perAccount,
+    );
+  }
+
+  String encode() => json.encode({
+    'default': defaultName,
+    'templates': [for (final t in templates) t.toJson()],
+  });
+
+  /// The templates saved as [raw], or null if there are none or they
+  /// cannot be read.
+  static ShareTemplates? decode(String? raw, {bool perAccount = false}) {
+    if (raw == null || raw.isEmpty) return null;
+    try {
+      final map = json.decode(raw) as Map<String, dynamic>;
+      return ShareTemplates(
+        templates: [
+          for (final t in map['templates'] as List<dynamic>)
+            ShareTemplate.fromJson(t as Map<String, dynamic>),
+        ],
+        defaultName: map['default'] as String? ?? '',
+        perAccount: perAccount,
+      );
+    } catch (_) {
+      return null;
+    }
+  }
+}
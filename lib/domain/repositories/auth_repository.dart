@@ -18,6 +18,13 @@ abstract class AuthRepository {
     required String password,
   });
 
+  /// Login with a username and an app password created on the server.
+  /// Requests then use HTTP Basic instead of a bearer token.
+  Future<UserEntity> loginWithAppPassword({
+    required String username,
+    required String appPassword,
+  });
+
   /// Register a new user.
   Future<(UserEntity, AuthTokens)> register({
     required String username,
@@ -1,6 +1,24 @@
 import 'dart:typed_data';
 import '../entities/file_entity.dart';
 
+/// Reports cumulative bytes transferred out of [total] (-1 when unknown).
+typedef TransferProgressCallback = void Function(int transferred, int total);
+
+/// One file of a batch upload. [open] is called each time the content is
+/// sent, so a batch retried file by file can read it again.
+typedef UploadSource = ({
+  String name,
+  int size,
+  String mimeType,
+  Stream<List<int>> Function() open,
+});
+
+/// The files a batch upload stored, and why each other one failed, by name.
+typedef BatchUploadResult = ({
+  List<FileEntity> uploaded,
+  Map<String, String> failed,
+});
+
 abstract class FileRepository {
   /// List files in a folder (null = root).
   Future<List<FileEntity>> listFiles({String? folderId});
@@ -8,29 +26,62 @@ abstract class FileRepository {
   /// Get file metadata by ID.
   Future<FileEntity> getFile(String id);
 
-  /// Upload a file (simple upload for small files).
+  /// Upload a file (simple upload for small files). Completing [stop]
+  /// aborts the upload.
   Future<FileEntity> uploadFile({
     required String name,
     required String? folderId,
     required Stream<List<int>> fileStream,
     required int fileSize,
     required String mimeType,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  });
+
+  /// Upload several small files into [folderId] in one request. Throws a
+  /// `ServerException` with status 404, 405 or 501 if the server does not
+  /// take batch uploads.
+  Future<BatchUploadResult> uploadFiles({
+    required String? folderId,
+    required List<UploadSource> files,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
   });
 
   /// Download a file. Returns bytes stream.
   Future<Stream<List<int>>> downloadFile(String id);
 
-  /// Download file to a local path.
-  Future<String> downloadFileToPath(String id, String localPath);
+  /// Download file to a local path. Completing [stop] aborts the download;
+  /// with [resume], a partial file left at [localPath] is continued where
+  /// the server allows it, and with [ifRange] only if the file still has
+  /// that ETag. [onEtag] gets the ETag of the version being sent, if the
+  /// server gave one.
+  Future<String> downloadFileToPath(
+    String id,
+    String localPath, {
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+    bool resume = false,
+    String? ifRange,
+    void Function(String? etag)? onEtag,
+  });
+
+  /// Delete a file (soft-delete to trash). With [ifMatch], only if the file
+  /// still has that ETag on the server.
+  Future<void> deleteFile(String id, {String? ifMatch});
 
-  /// Delete a file (soft-delete to trash).
-  Future<void> deleteFile(String id);
+  /// Rename a file, conditionally on [ifMatch] if given.
+  Future<FileEntity> renameFile(String id, String newName, {String? ifMatch});
 
-  /// Rename a file.
-  Future<FileEntity> renameFile(String id, String newName);
+  /// Move a file to another folder, conditionally on [ifMatch] if given.
+  Future<FileEntity> moveFile(
+    String id,
+    String targetFolderId, {
+    String? ifMatch,
+  });
 
-  /// Move a file to another folder.
-  Future<FileEntity> moveFile(String id, String targetFolderId);
+  /// Copy a file into another (or the same) folder.
+  Future<FileEntity> copyFile(String id, String targetFolderId);
 
   /// Get thumbnail bytes.
   Future<Uint8List> getThumbnail(String id, {String size = '256'});
@@ -0,0 +1,18 @@
+import '../entities/file_entity.dart';
+import '../entities/file_version_entity.dart';
+
+abstract class FileVersionRepository {
+  /// Earlier versions of a file, newest first.
+  Future<List<FileVersionEntity>> listVersions(String fileId);
+
+  /// Download a version to a local path. Returns the path.
+  Future<String> downloadVersionToPath(
+    String fileId,
+    String versionId,
+    String localPath,
+  );
+
+  /// Make a version the file's current content. The content it replaces
+  /// becomes a version in turn.
+  Future<FileEntity> restoreVersion(String fileId, String versionId);
+}
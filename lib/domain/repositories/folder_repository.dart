@@ -5,7 +5,20 @@ class FolderContents {
   final List<FolderEntity> folders;
   final List<FileEntity> files;
 
-  const FolderContents({required this.folders, required this.files});
+  /// Whether the server could not be reached and the listing was served
+  /// from the local mirror instead.
+  final bool fromCache;
+
+  /// When the mirrored listing was last fetched from the server, or null if
+  /// it never was.
+  final DateTime? listedAt;
+
+  const FolderContents({
+    required this.folders,
+    required this.files,
+    this.fromCache = false,
+    this.listedAt,
+  });
 
   int get totalCount => folders.length + files.length;
   bool get isEmpty => folders.isEmpty && files.isEmpty;
@@ -15,6 +28,9 @@ abstract class FolderRepository {
   /// List root folders.
   Future<List<FolderEntity>> listRootFolders();
 
+  /// List root folders and the files next to them.
+  Future<FolderContents> listRootContents();
+
   /// List subfolders and files in a folder.
   Future<FolderContents> listFolderContents(String folderId);
 
@@ -0,0 +1,10 @@
+import '../entities/session_entity.dart';
+
+abstract class SessionRepository {
+  /// List every device and app password with access to the account,
+  /// most recently used first.
+  Future<List<SessionEntity>> listSessions();
+
+  /// Revoke a session. Revoking the current one signs this device out.
+  Future<void> revokeSession(SessionEntity session);
+}
@@ -1,7 +1,10 @@
 import '../entities/share_entity.dart';
+import '../entities/share_template.dart';
 
 abstract class ShareRepository {
-  /// Create a share link.
+  /// Create a share link. Expiry and permissions not given are taken from
+  /// [template]; throws [ArgumentError] if it requires a password and none
+  /// is given.
   Future<ShareEntity> createShare({
     required String itemId,
     required String itemType,
@@ -9,6 +12,7 @@ abstract class ShareRepository {
     String? password,
     DateTime? expiresAt,
     SharePermissions? permissions,
+    ShareTemplate? template,
   });
 
   /// List all shares for the current user.
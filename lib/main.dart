@@ -1,27 +1,145 @@
+import 'dart:async';
+import 'dart:io';
+
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 
 import 'app.dart';
+import 'core/demo/demo_mode.dart';
+import 'core/platform/backup_exclusion.dart';
+import 'core/platform/platform_info.dart';
+import 'core/services/control_channel.dart';
 import 'providers.dart';
 
-void main() async {
+void main(List<String> args) async {
   WidgetsFlutterBinding.ensureInitialized();
 
-  final container = ProviderContainer();
+  final demo = args.contains(demoFlag);
+  final container = ProviderContainer(
+    overrides: demo ? demoOverrides() : const [],
+  );
+
+  // Command-line use goes through the running instance, never the database
+  final command = ControlChannel.commandIn(args);
+  if (command != null) {
+    exit(await _sendCommand(container, command));
+  }
 
   // Load saved server URL from secure storage
   await container.read(appConfigProvider.notifier).loadSavedConfig();
+  await container.read(bandwidthLimitsProvider.notifier).load();
+  await container.read(inboxSettingsProvider.notifier).load();
+  await container.read(throttleOnLoadProvider.notifier).load();
+  await container.read(meteredActionProvider.notifier).load();
+  await container.read(syncTypeFiltersProvider.notifier).load();
+  await container.read(syncExcludePatternsProvider.notifier).load();
+  await container.read(linkPolicyProvider.notifier).load();
+  await container.read(storageOptimizerSettingsProvider.notifier).load();
+  await container.read(customHeadersProvider.notifier).load();
+  await container.read(tlsTrustProvider.notifier).load();
+  await container.read(proxySettingsProvider.notifier).load();
+  await container.read(shareTemplatesProvider.notifier).load();
+  await container.read(usageStatisticsSettingsProvider.notifier).load();
+
+  // Refuse to share the database with another running instance
+  await container.read(dbPathProvider.future);
+  final lease = container.read(dbLeaseProvider);
+  final holder = await lease.acquire();
+  if (holder != null) {
+    container.dispose();
+    runApp(AlreadyRunningApp(pid: holder.pid, hostname: holder.hostname));
+    return;
+  }
+  lease.start();
+
+  // Keep caches out of OS backups and search indexes
+  await BackupExclusion.mark(await FileSystem.cacheDir);
+
+  // Remove downloads that were interrupted by a crash or a forced quit,
+  // but not those journaled to be continued
+  final partials = await container.read(databaseProvider).getPartialDownloads();
+  await container
+      .read(tempFileAllocatorProvider)
+      .cleanUp(
+        [await FileSystem.downloadDir, await FileSystem.linkedSharesDir],
+        keep: {for (final partial in partials) partial.path},
+      );
+
+  // Record sync statistics from the first event on
+  container.read(syncStatsCollectorProvider).start();
 
   // Check initial connectivity
   await container.read(connectivityProvider).checkConnectivity();
 
+  // Hold back sync on metered or roaming connections
+  container.read(meteredNetworkProvider);
+
   // Start sync engine only if server is configured
   final config = container.read(appConfigProvider);
   if (config.hasServer) {
     container.read(syncEngineProvider).start();
+    container.read(uploadSessionCleanupProvider).start();
+    container.read(folderScanSchedulerProvider).start();
+    container.read(inboxServiceProvider).start();
+    container.read(localCopyVerifierProvider).start();
+    container.read(storageOptimizerProvider).start();
+    unawaited(container.read(usageStatsProvider).uploadIfDue());
+    final quota = container.read(storageQuotaProvider);
+    unawaited(quota.load().then((_) => quota.refresh()));
+  }
+
+  // Answer --status and --sync from later launches
+  if (!demo) {
+    await container.read(controlInfoPathProvider.future);
+    await container.read(controlChannelProvider).start();
   }
 
+  _registerShutdownHooks(container);
+
   runApp(
     UncontrolledProviderScope(container: container, child: const OxiCloudApp()),
   );
 }
+
+/// Hand [command] to the running instance and print its answer. Returns the
+/// exit code.
+Future<int> _sendCommand(ProviderContainer container, String command) async {
+  final infoPath = await container.read(controlInfoPathProvider.future);
+  final reply = await ControlChannel.send(infoPath, command);
+  if (reply == null) {
+    stderr.writeln('OxiCloud is not running');
+    return 1;
+  }
+  (reply.ok ? stdout : stderr).writeln(reply.message);
+  return reply.ok ? 0 : 1;
+}
+
+/// Listens for the window closing for as long as the app runs.
+AppLifecycleListener? _exitListener;
+
+/// Run the shutdown sequence on window close and on SIGTERM/SIGINT, so queued
+/// operations and the database are flushed instead of aborted mid-write.
+void _registerShutdownHooks(ProviderContainer container) {
+  Future<void> shutdown() async {
+    await container.read(shutdownServiceProvider).shutdown();
+    container.dispose();
+    _exitListener?.dispose();
+    _exitListener = null;
+  }
+
+  _exitListener = AppLifecycleListener(
+    onExitRequested: () async {
+      await shutdown();
+      return AppExitResponse.exit;
+    },
+  );
+
+  if (PlatformInfo.isMacOS || PlatformInfo.isLinux) {
+    for (final signal in [ProcessSignal.sigterm, ProcessSignal.sigint]) {
+      signal.watch().listen((_) async {
+        await shutdown();
+        exit(0);
+      });
+    }
+  }
+}
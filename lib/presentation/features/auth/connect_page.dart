@@ -4,6 +4,11 @@ import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:go_router/go_router.dart';
 
 import '../../../../providers.dart';
+import '../../../core/config/config_validator.dart';
+import '../../../core/error/exceptions.dart';
+import '../../../core/network/tls_trust.dart';
+import '../../widgets/trust_certificate_dialog.dart';
+import '../settings/proxy_settings_dialog.dart';
 
 class ConnectPage extends ConsumerStatefulWidget {
   const ConnectPage({super.key});
@@ -31,6 +36,7 @@ class _ConnectPageState extends ConsumerState<ConnectPage> {
       _error = null;
     });
 
+    UntrustedCertificate? untrusted;
     try {
       var url = _urlCtrl.text.trim();
       if (url.endsWith('/')) url = url.substring(0, url.length - 1);
@@ -42,7 +48,26 @@ class _ConnectPageState extends ConsumerState<ConnectPage> {
           receiveTimeout: const Duration(seconds: 10),
         ),
       );
-      await testDio.get('$url/api/version');
+      final secureStorage = ref.read(secureStorageProvider);
+      final trust = await secureStorage.getTlsTrust(url);
+      trust.applyTo(
+        testDio,
+        proxy: ref.read(proxySettingsProvider),
+        onUntrusted: (c) => untrusted = c,
+      );
+      try {
+        await testDio.get('$url/api/version');
+      } on DioException {
+        // A self-signed server: ask about its certificate, then retry.
+        final certificate = untrusted;
+        if (certificate == null || !mounted) rethrow;
+        if (!await TrustCertificateDialog.show(context, certificate)) rethrow;
+        await secureStorage.saveTlsTrust(
+          url,
+          trust.trust(certificate.fingerprint),
+        );
+        return _connect();
+      }
 
       // Server is valid — save and proceed
       await ref.read(appConfigProvider.notifier).setServerUrl(url);
@@ -64,7 +89,9 @@ class _ConnectPageState extends ConsumerState<ConnectPage> {
       }
     } on DioException catch (e) {
       setState(() {
-        if (e.type == DioExceptionType.connectionTimeout ||
+        if (untrusted != null) {
+          _error = 'The server certificate is not trusted.';
+        } else if (e.type == DioExceptionType.connectionTimeout ||
             e.type == DioExceptionType.connectionError) {
           _error = 'Cannot reach server. Check URL and network.';
         } else if (e.response?.statusCode == 404) {
@@ -73,6 +100,8 @@ class _ConnectPageState extends ConsumerState<ConnectPage> {
           _error = 'Connection failed: ${e.message}';
         }
       });
+    } on InvalidConfigException catch (e) {
+      setState(() => _error = e.errors.map((err) => err.message).join('\n'));
     } catch (e) {
       setState(() => _error = 'Unexpected error: $e');
     } finally {
@@ -121,21 +150,8 @@ class _ConnectPageState extends ConsumerState<ConnectPage> {
                       textInputAction: TextInputAction.done,
                       autofocus: true,
                       onFieldSubmitted: (_) => _connect(),
-                      validator: (v) {
-                        if (v == null || v.trim().isEmpty) {
-                          return 'Server URL is required';
-                        }
-                        final url = v.trim();
-                        if (!url.startsWith('http://') &&
-                            !url.startsWith('https://')) {
-                          return 'URL must start with http:// or https://';
-                        }
-                        final uri = Uri.tryParse(url);
-                        if (uri == null || !uri.hasAuthority) {
-                          return 'Invalid URL';
-                        }
-                        return null;
-                      },
+                      validator: (v) =>
+                          ConfigValidator.validateServerUrl(v ?? ''),
                     ),
                     if (_error != null) ...[
                       const SizedBox(height: 16),
@@ -176,6 +192,14 @@ class _ConnectPageState extends ConsumerState<ConnectPage> {
                         label: Text(_loading ? 'Connecting…' : 'Connect'),
                       ),
                     ),
+                    const SizedBox(height: 8),
+                    TextButton.icon(
+                      onPressed: _loading
+                          ? null
+                          : () => ProxySettingsDialog.show(context),
+                      icon: const Icon(Icons.lan_outlined, size: 18),
+                      label: const Text('Proxy settings…'),
+                    ),
                   ],
                 ),
               ),
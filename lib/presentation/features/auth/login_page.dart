@@ -4,6 +4,7 @@ import 'package:go_router/go_router.dart';
 import 'package:url_launcher/url_launcher.dart';
 
 import '../../../../providers.dart';
+import '../../../core/error/exceptions.dart';
 import '../../../data/datasources/remote/oidc_remote_datasource.dart';
 
 class LoginPage extends ConsumerStatefulWidget {
@@ -19,6 +20,8 @@ class _LoginPageState extends ConsumerState<LoginPage> {
   final _passwordCtrl = TextEditingController();
   bool _loading = false;
   bool _obscure = true;
+  bool _useAppPassword = false;
+  bool _waitingForBrowser = false;
   String? _error;
   OidcProviderInfo? _oidcInfo;
 
@@ -52,17 +55,75 @@ class _LoginPageState extends ConsumerState<LoginPage> {
     });
 
     try {
+      final auth = ref.read(authRepositoryProvider);
+      if (_useAppPassword) {
+        await auth.loginWithAppPassword(
+          username: _usernameCtrl.text.trim(),
+          appPassword: _passwordCtrl.text.trim(),
+        );
+      } else {
+        await auth.login(
+          username: _usernameCtrl.text.trim(),
+          password: _passwordCtrl.text,
+        );
+      }
+      if (mounted) context.go('/files');
+    } on UnauthorizedException {
+      setState(
+        () => _error = _useAppPassword
+            ? 'Wrong username or app password'
+            : 'Wrong username or password',
+      );
+    } catch (e) {
+      setState(() => _error = e.toString());
+    } finally {
+      if (mounted) setState(() => _loading = false);
+    }
+  }
+
+  /// Login Flow v2: sign in in the browser, which hands back an app
+  /// password.
+  Future<void> _browserLogin() async {
+    setState(() {
+      _loading = true;
+      _waitingForBrowser = true;
+      _error = null;
+    });
+    try {
+      final flow = ref.read(loginFlowServiceProvider);
+      final serverUrl = ref.read(appConfigProvider).serverUrl;
+      final session = await flow.start(serverUrl);
+      await launchUrl(
+        Uri.parse(session.loginUrl),
+        mode: LaunchMode.externalApplication,
+      );
+      final credentials = await flow.waitForCredentials(
+        session,
+        cancelled: () => !mounted || !_waitingForBrowser,
+      );
+      if (credentials == null) return;
+      // The server may name a canonical URL other than the one typed in.
+      if (credentials.server != serverUrl) {
+        await ref
+            .read(appConfigProvider.notifier)
+            .setServerUrl(credentials.server);
+      }
       await ref
           .read(authRepositoryProvider)
-          .login(
-            username: _usernameCtrl.text.trim(),
-            password: _passwordCtrl.text,
+          .loginWithAppPassword(
+            username: credentials.loginName,
+            appPassword: credentials.appPassword,
           );
       if (mounted) context.go('/files');
     } catch (e) {
-      setState(() => _error = e.toString());
+      if (mounted) setState(() => _error = e.toString());
     } finally {
-      if (mounted) setState(() => _loading = false);
+      if (mounted) {
+        setState(() {
+          _loading = false;
+          _waitingForBrowser = false;
+        });
+      }
     }
   }
 
@@ -125,8 +186,14 @@ class _LoginPageState extends ConsumerState<LoginPage> {
                     TextFormField(
                       controller: _passwordCtrl,
                       decoration: InputDecoration(
-                        labelText: 'Password',
-                        prefixIcon: const Icon(Icons.lock_outline),
+                        labelText: _useAppPassword
+                            ? 'App password'
+                            : 'Password',
+                        prefixIcon: Icon(
+                          _useAppPassword
+                              ? Icons.key_outlined
+                              : Icons.lock_outline,
+                        ),
                         suffixIcon: IconButton(
                           icon: Icon(
                             _obscure ? Icons.visibility_off : Icons.visibility,
@@ -140,6 +207,18 @@ class _LoginPageState extends ConsumerState<LoginPage> {
                       validator: (v) =>
                           v == null || v.isEmpty ? 'Required' : null,
                     ),
+                    const SizedBox(height: 8),
+                    SwitchListTile(
+                      contentPadding: EdgeInsets.zero,
+                      title: const Text('Use an app password'),
+                      subtitle: const Text(
+                        'Created in the web interface under Security',
+                      ),
+                      value: _useAppPassword,
+                      onChanged: _loading
+                          ? null
+                          : (v) => setState(() => _useAppPassword = v),
+                    ),
                     if (_error != null) ...[
                       const SizedBox(height: 16),
                       Text(
@@ -188,6 +267,25 @@ class _LoginPageState extends ConsumerState<LoginPage> {
                         ),
                       ),
                     ],
+                    // --- Browser Login (Login Flow v2) ---
+                    const SizedBox(height: 12),
+                    SizedBox(
+                      width: double.infinity,
+                      child: _waitingForBrowser
+                          ? OutlinedButton.icon(
+                              onPressed: () =>
+                                  setState(() => _waitingForBrowser = false),
+                              icon: const Icon(Icons.close),
+                              label: const Text(
+                                'Waiting for the browser… Cancel',
+                              ),
+                            )
+                          : OutlinedButton.icon(
+                              onPressed: _loading ? null : _browserLogin,
+                              icon: const Icon(Icons.open_in_browser),
+                              label: const Text('Login with browser'),
+                            ),
+                    ),
                     // --- Device Login ---
                     const SizedBox(height: 16),
                     TextButton(
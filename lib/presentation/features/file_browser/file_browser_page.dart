@@ -1,15 +1,36 @@
+import 'dart:async';
 import 'dart:io';
+import 'dart:typed_data';
 import 'package:file_picker/file_picker.dart';
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:go_router/go_router.dart';
 import 'package:mime/mime.dart';
+import 'package:path/path.dart' as p;
 import 'package:path_provider/path_provider.dart';
 import 'package:open_filex/open_filex.dart';
 
 import '../../../../providers.dart';
 import '../../../../domain/entities/file_entity.dart';
 import '../../../../domain/entities/folder_entity.dart';
+import '../../../../domain/repositories/file_repository.dart';
+import '../../../../domain/repositories/folder_repository.dart';
+import '../../../core/error/exceptions.dart';
+import '../../../core/platform/file_locks.dart';
+import '../../../core/services/bulk_upload_service.dart';
+import '../../../core/services/bundle_service.dart';
+import '../../../core/services/chunked_upload_service.dart';
+import '../../../core/services/operation_report_service.dart';
+import '../../../core/services/usage_stats_service.dart';
+import '../../../core/sync/case_conflicts.dart';
+import '../../../core/sync/folder_subscriptions.dart';
+import '../../../core/sync/ignore_rules.dart';
+import '../../../core/sync/link_policy.dart';
+import '../../../core/sync/sync_direction.dart';
+import '../../../core/sync/sync_filter.dart';
+import '../../../core/sync/sync_events.dart';
+import '../../../core/sync/transfer_monitor.dart';
+import '../../../core/sync/upload_dedup.dart';
 import '../../../core/theme/responsive.dart';
 import '../../widgets/breadcrumb_bar.dart';
 import '../../widgets/context_menu.dart';
@@ -20,6 +41,9 @@ import '../../shell/adaptive_shell.dart';
 import '../../shell/desktop/desktop_toolbar.dart';
 import '../../shell/desktop/drag_drop_overlay.dart';
 import '../../shell/mobile/mobile_upload_sheet.dart';
+import '../settings/account_migration_dialog.dart';
+import '../shares/share_link_dialog.dart';
+import 'file_versions_dialog.dart';
 
 // --- State ---
 
@@ -31,6 +55,11 @@ class FileBrowserState {
   final String? currentFolderId;
   final List<({String id, String name})> breadcrumbs;
 
+  /// The listing came from the local mirror because the server was
+  /// unreachable; [listedAt] is when it was last fetched, if ever.
+  final bool fromCache;
+  final DateTime? listedAt;
+
   const FileBrowserState({
     this.folders = const [],
     this.files = const [],
@@ -38,6 +67,8 @@ class FileBrowserState {
     this.error,
     this.currentFolderId,
     this.breadcrumbs = const [],
+    this.fromCache = false,
+    this.listedAt,
   });
 
   FileBrowserState copyWith({
@@ -55,6 +86,8 @@ class FileBrowserState {
       error: error,
       currentFolderId: currentFolderId ?? this.currentFolderId,
       breadcrumbs: breadcrumbs ?? this.breadcrumbs,
+      fromCache: fromCache,
+      listedAt: listedAt,
     );
   }
 }
@@ -62,10 +95,32 @@ class FileBrowserState {
 // --- Notifier ---
 
 class FileBrowserNotifier extends Notifier<FileBrowserState> {
+  /// Folders opened while only the local mirror was available. They are
+  /// refreshed when the connection comes back.
+  final Set<String?> _staleFolders = {};
+
+  /// Keeps the open folder polled more often than the background scan.
+  FolderSubscription? _watch;
+
+  /// Thumbnails fetched ahead for a newly opened folder; roughly what fits
+  /// on screen.
+  static const _thumbnailPrefetch = 48;
+
   @override
-  FileBrowserState build() => const FileBrowserState();
+  FileBrowserState build() {
+    final connectivity = ref.read(connectivityProvider);
+    final sub = connectivity.onConnectivityChanged.listen((online) {
+      if (online) _refreshStale();
+    });
+    ref.onDispose(sub.cancel);
+    ref.onDispose(() => _watch?.cancel());
+    ref.onDispose(ref.read(thumbnailServiceProvider).cancelPrefetch);
+    return const FileBrowserState();
+  }
 
   Future<void> loadFolder(String? folderId) async {
+    final watch = _watch;
+    if (watch == null || watch.folderId != folderId) _watchFolder(folderId);
     state = state.copyWith(
       loading: true,
       error: null,
@@ -73,33 +128,84 @@ class FileBrowserNotifier extends Notifier<FileBrowserState> {
     );
 
     try {
-      if (folderId == null) {
-        final folders = await ref
-            .read(folderRepositoryProvider)
-            .listRootFolders();
-        final files = await ref.read(fileRepositoryProvider).listFiles();
-        state = state.copyWith(
-          folders: folders,
-          files: files,
-          loading: false,
-          breadcrumbs: [],
-        );
-      } else {
-        final contents = await ref
-            .read(folderRepositoryProvider)
-            .listFolderContents(folderId);
-        state = state.copyWith(
-          folders: contents.folders,
-          files: contents.files,
-          loading: false,
-        );
-      }
+      await _show(folderId);
     } catch (e) {
       state = state.copyWith(loading: false, error: e.toString());
     }
   }
 
+  Future<void> _show(String? folderId) async {
+    final contents = await _list(folderId);
+    state = FileBrowserState(
+      folders: contents.folders,
+      files: contents.files,
+      currentFolderId: folderId,
+      breadcrumbs: folderId == null ? const [] : state.breadcrumbs,
+      fromCache: contents.fromCache,
+      listedAt: contents.listedAt,
+    );
+    final thumbnails = ref.read(thumbnailServiceProvider);
+    if (contents.fromCache) {
+      thumbnails.cancelPrefetch();
+    } else {
+      thumbnails.prefetch(
+        contents.files
+            .where((f) => f.isImage)
+            .take(_thumbnailPrefetch)
+            .map((f) => f.id),
+      );
+    }
+  }
+
+  void _watchFolder(String? folderId) {
+    _watch?.cancel();
+    // The root is not polled by the scan scheduler, but still hears about
+    // changes made by background sync.
+    _watch = ref
+        .read(folderSubscriptionsProvider)
+        .subscribe(
+          folderId,
+          interval: ref.read(appConfigProvider).openFolderPollInterval,
+          onChanged: () async {
+            if (state.currentFolderId != folderId || state.loading) return;
+            try {
+              await _show(folderId);
+            } catch (_) {
+              // The next poll tries again.
+            }
+          },
+        );
+  }
+
+  Future<FolderContents> _list(String? folderId) async {
+    final repo = ref.read(folderRepositoryProvider);
+    final contents = folderId == null
+        ? await repo.listRootContents()
+        : await repo.listFolderContents(folderId);
+    if (contents.fromCache) {
+      _staleFolders.add(folderId);
+    } else {
+      _staleFolders.remove(folderId);
+    }
+    return contents;
+  }
+
+  /// Refetch every folder visited offline so the mirror is current, then
+  /// reload the open one if it is still showing a cached listing.
+  Future<void> _refreshStale() async {
+    for (final folderId in _staleFolders.toList()) {
+      if (folderId == state.currentFolderId) continue;
+      try {
+        await _list(folderId);
+      } catch (_) {
+        // Stays queued for the next reconnect.
+      }
+    }
+    if (state.fromCache) await loadFolder(state.currentFolderId);
+  }
+
   Future<void> createFolder(String name) async {
+    unawaited(ref.read(usageStatsProvider).count(UsageFeature.createFolder));
     try {
       await ref
           .read(folderRepositoryProvider)
@@ -120,49 +226,679 @@ class FileBrowserNotifier extends Notifier<FileBrowserState> {
     await loadFolder(state.currentFolderId);
   }
 
-  /// Upload a file from its local path.
+  /// Upload a file from its local path. The upload waits in the upload
+  /// scheduler's queue until a slot is free.
   Future<void> uploadFileFromPath(String filePath) async {
+    unawaited(ref.read(usageStatsProvider).count(UsageFeature.upload));
+    await _uploadFile(filePath, state.currentFolderId);
+    await loadFolder(state.currentFolderId);
+  }
+
+  /// Throws [SyncFilteredException] for files the sync filter ignores and
+  /// for download-only folders.
+  Future<void> _uploadFile(String filePath, String? folderId) async {
     final file = File(filePath);
     final name = file.uri.pathSegments.last;
     final size = await file.length();
-    final mimeType = lookupMimeType(filePath) ?? 'application/octet-stream';
+    final ignored = ref
+        .read(syncFilterProvider)
+        .reasonToIgnore(name, size: size, mimeType: lookupMimeType(filePath));
+    if (ignored != null) throw SyncFilteredException(name, ignored);
+    await ref.read(syncDirectionProvider).ensureUploadAllowed(name, folderId);
+    final config = ref.read(appConfigProvider);
+    final bundle = BundleService.shouldBundle(
+      name,
+      size,
+      minSize: config.bundleMinSizeBytes,
+    );
+    final chunked = size > config.chunkThresholdBytes;
+    await _waitUntilReleased(file, name, size);
 
     await ref
-        .read(fileRepositoryProvider)
-        .uploadFile(
+        .read(uploadSchedulerProvider)
+        .schedule(
+          id: filePath,
           name: name,
-          folderId: state.currentFolderId,
-          fileStream: file.openRead(),
-          fileSize: size,
-          mimeType: mimeType,
+          size: size,
+          run: () {
+            // Checked when the upload's turn comes, so the ones queued
+            // behind an upload that filled the account stop too.
+            ref.read(storageQuotaProvider).ensureRoom(name, size);
+            if (bundle) return _uploadBundle(file, name, size, folderId);
+            if (chunked) return _uploadChunked(file, name, size, folderId);
+            return _upload(file, name, size, folderId);
+          },
         );
-    await loadFolder(state.currentFolderId);
+  }
+
+  /// Wait while another application keeps [file] locked, listing the upload
+  /// as waiting in the meantime.
+  Future<void> _waitUntilReleased(File file, String name, int size) async {
+    final monitor = ref.read(transferMonitorProvider);
+    String? transferId;
+    try {
+      await FileLocks.waitUntilReleased(
+        file,
+        onWaiting: () {
+          transferId = monitor.begin(
+            id: file.path,
+            name: name,
+            direction: TransferDirection.upload,
+            totalBytes: size,
+          );
+          monitor.waitForRelease(transferId!);
+        },
+      );
+      if (transferId != null) monitor.resume(transferId!);
+    } on FileLockedException {
+      if (transferId != null) monitor.fail(transferId!);
+      rethrow;
+    }
+  }
+
+  /// Upload a large disk image as a bundle; see [BundleService].
+  Future<void> _uploadBundle(
+    File file,
+    String name,
+    int size,
+    String? folderId,
+  ) async {
+    final monitor = ref.read(transferMonitorProvider);
+    final transferId = monitor.begin(
+      id: file.path,
+      name: name,
+      direction: TransferDirection.upload,
+      totalBytes: size,
+    );
+    try {
+      await ref.read(bundleServiceProvider).upload(file, parentId: folderId);
+      monitor.complete(transferId);
+    } catch (_) {
+      monitor.fail(transferId);
+      rethrow;
+    }
+  }
+
+  /// Upload a large file in chunks; see [ChunkedUploadService]. If the
+  /// connection drops for good, the upload stays journaled and continues
+  /// from its last chunk once the server is reachable again.
+  Future<void> _uploadChunked(
+    File file,
+    String name,
+    int size,
+    String? folderId,
+  ) async {
+    final monitor = ref.read(transferMonitorProvider);
+    final transferId = monitor.begin(
+      id: file.path,
+      name: name,
+      direction: TransferDirection.upload,
+      totalBytes: size,
+    );
+    final dedup = ref.read(uploadDeduplicatorProvider);
+    try {
+      final digest = await dedup.digestOf(file);
+      if (digest != null &&
+          await _placeExisting(file, digest, name, folderId, transferId)) {
+        return;
+      }
+      final result = await ref
+          .read(chunkedUploadServiceProvider)
+          .uploadFile(
+            file: file,
+            filename: name,
+            folderId: folderId,
+            contentType: lookupMimeType(file.path),
+            onProgress: (done) =>
+                monitor.update(transferId, (done * size).round()),
+          );
+      await ref.read(fileModeStoreProvider).remember(result.fileId, file.path);
+      if (digest != null) {
+        final uploaded = await ref
+            .read(fileRepositoryProvider)
+            .getFile(result.fileId);
+        await dedup.remember(uploaded.id, digest, etag: uploaded.etag);
+      }
+      ref.read(storageQuotaProvider).used(size);
+      monitor.complete(transferId);
+    } on UploadInterruptedException {
+      monitor.fail(
+        transferId,
+        reason: 'Connection lost; the upload resumes once it is back',
+      );
+      rethrow;
+    } on QuotaExceededException catch (e) {
+      ref.read(storageQuotaProvider).markFull(size);
+      monitor.fail(transferId, reason: e.message);
+      rethrow;
+    } catch (_) {
+      monitor.fail(transferId);
+      rethrow;
+    }
+  }
+
+  Future<void> _upload(
+    File file,
+    String name,
+    int size,
+    String? folderId,
+  ) async {
+    final mimeType = lookupMimeType(file.path) ?? 'application/octet-stream';
+    final monitor = ref.read(transferMonitorProvider);
+    final transferId = monitor.begin(
+      id: file.path,
+      name: name,
+      direction: TransferDirection.upload,
+      totalBytes: size,
+    );
+    final dedup = ref.read(uploadDeduplicatorProvider);
+    try {
+      final digest = await dedup.digestOf(file);
+      if (digest != null &&
+          await _placeExisting(file, digest, name, folderId, transferId)) {
+        return;
+      }
+      final uploaded = await ref
+          .read(transferControllerProvider)
+          .run(
+            transferId,
+            (stop, {required resume}) => ref
+                .read(fileRepositoryProvider)
+                .uploadFile(
+                  name: name,
+                  folderId: folderId,
+                  fileStream: file.openRead(),
+                  fileSize: size,
+                  mimeType: mimeType,
+                  onProgress: (sent, _) => monitor.update(transferId, sent),
+                  stop: stop,
+                ),
+          );
+      await ref.read(fileModeStoreProvider).remember(uploaded.id, file.path);
+      if (digest != null) {
+        await dedup.remember(uploaded.id, digest, etag: uploaded.etag);
+      }
+      ref.read(storageQuotaProvider).used(size);
+      monitor.complete(transferId);
+    } on QuotaExceededException catch (e) {
+      ref.read(storageQuotaProvider).markFull(size);
+      monitor.fail(transferId, reason: e.message);
+      rethrow;
+    } catch (_) {
+      monitor.fail(transferId);
+      rethrow;
+    }
+  }
+
+  /// Put [file] in place from content the server already has, if it has
+  /// it; see [UploadDeduplicator]. Returns false if it has to be uploaded.
+  Future<bool> _placeExisting(
+    File file,
+    ContentDigest digest,
+    String name,
+    String? folderId,
+    String transferId,
+  ) async {
+    final avoided = await ref
+        .read(uploadDeduplicatorProvider)
+        .avoidUpload(digest, name: name, folderId: folderId);
+    if (avoided == null) return false;
+    final (outcome, placed) = avoided;
+    await ref.read(fileModeStoreProvider).remember(placed.id, file.path);
+    if (outcome == DedupOutcome.copied) {
+      ref.read(storageQuotaProvider).used(digest.size);
+    }
+    ref.read(transferMonitorProvider).complete(transferId);
+    return true;
   }
 
   /// Upload multiple files by path. Returns count of successful uploads.
-  Future<int> uploadFilesFromPaths(List<String> paths) async {
-    int success = 0;
-    for (final path in paths) {
+  /// Each outcome is recorded in [report] if one is given.
+  Future<int> uploadFilesFromPaths(
+    List<String> paths, {
+    OperationReport? report,
+  }) async {
+    final results = await Future.wait(
+      paths.map((path) async {
+        try {
+          await uploadFileFromPath(path);
+          report?.complete(path);
+          return true;
+        } on RequestCancelledException {
+          report?.skip(path, 'Cancelled');
+          return false;
+        } on SyncFilteredException catch (e) {
+          report?.skip(path, e.reason);
+          state = state.copyWith(error: e.toString());
+          return false;
+        } on QuotaExceededException catch (e) {
+          report?.fail(path, e);
+          state = state.copyWith(error: e.message);
+          return false;
+        } catch (e) {
+          report?.fail(path, e);
+          state = state.copyWith(
+            error: 'Failed to upload ${File(path).uri.pathSegments.last}: $e',
+          );
+          return false;
+        }
+      }),
+    );
+    unawaited(ref.read(storageQuotaProvider).refresh());
+    return results.where((ok) => ok).length;
+  }
+
+  /// Upload a local folder into the current one, recreating its whole tree
+  /// on the server with empty subfolders included. Paths matched by the
+  /// folder's `.oxicloudignore` files are left out, and symbolic links are
+  /// handled as the link policy says. Returns the number of
+  /// files uploaded; each file's outcome is recorded in [report] if one is
+  /// given.
+  Future<int> uploadFolderFromPath(
+    String dirPath, {
+    OperationReport? report,
+  }) async {
+    await ref
+        .read(syncDirectionProvider)
+        .ensureUploadAllowed(p.basename(dirPath), state.currentFolderId);
+    final uploaded = await _uploadTree(
+      Directory(dirPath),
+      state.currentFolderId,
+      root: dirPath,
+      ancestors: {await Directory(dirPath).resolveSymbolicLinks()},
+      report: report,
+    );
+    unawaited(ref.read(storageQuotaProvider).refresh());
+    await loadFolder(state.currentFolderId);
+    return uploaded;
+  }
+
+  /// Upload a local folder into the current one so that it only appears
+  /// on the server once all of it is there; see [FolderPublishService].
+  Future<void> publishFolderFromPath(String dirPath) async {
+    unawaited(ref.read(usageStatsProvider).count(UsageFeature.publishFolder));
+    await ref
+        .read(syncDirectionProvider)
+        .ensureUploadAllowed(p.basename(dirPath), state.currentFolderId);
+    await ref
+        .read(folderPublishServiceProvider)
+        .publish(dirPath, parentId: state.currentFolderId);
+    await loadFolder(state.currentFolderId);
+  }
+
+  Future<int> _uploadTree(
+    Directory dir,
+    String? parentId, {
+    required String root,
+    required Set<String> ancestors,
+    OperationReport? report,
+  }) async {
+    final ignore = ref.read(ignoreRuleCacheProvider);
+    final links = LinkResolver(ref.read(linkPolicyProvider));
+    final bulk = ref.read(bulkUploadServiceProvider);
+    final folder = await ref
+        .read(folderRepositoryProvider)
+        .createFolder(name: p.basename(dir.path), parentId: parentId);
+    final entries = await dir.list(followLinks: false).toList()
+      ..sort((a, b) => a.path.compareTo(b.path));
+
+    var uploaded = 0;
+    final small = <File>[];
+    for (var entry in entries) {
+      if (p.basename(entry.path) == IgnoreRuleCache.fileName) continue;
+      if (await ignore.isIgnored(
+        root,
+        entry.path,
+        isDirectory: entry is Directory,
+      )) {
+        report?.skip(entry.path, 'Matched an ignore rule');
+        continue;
+      }
+      var resolved = entry is Directory
+          ? await entry.resolveSymbolicLinks()
+          : null;
+      if (entry is Link) {
+        final (outcome, target) = await links.resolve(entry, ancestors);
+        switch (outcome) {
+          case LinkOutcome.directory:
+            entry = Directory(entry.path);
+            resolved = target;
+          case LinkOutcome.file:
+            entry = File(entry.path);
+          case LinkOutcome.metadata:
+            try {
+              await _uploadLink(entry, target!, folder.id);
+              report?.complete(entry.path);
+              uploaded++;
+            } catch (e) {
+              report?.fail(entry.path, e);
+            }
+            continue;
+          default:
+            report?.skip(entry.path, LinkResolver.reason(outcome));
+            continue;
+        }
+      }
+      if (entry is Directory) {
+        uploaded += await _uploadTree(
+          entry,
+          folder.id,
+          root: root,
+          ancestors: {...ancestors, resolved!},
+          report: report,
+        );
+      } else if (entry is File) {
+        if (bulk.accepts(await entry.length())) {
+          small.add(entry);
+        } else if (await _uploadTreeFile(entry, folder.id, report)) {
+          uploaded++;
+        }
+      }
+    }
+    return uploaded + await _uploadSmallFiles(small, folder.id, report);
+  }
+
+  /// Upload one file of a folder upload, recording the outcome.
+  Future<bool> _uploadTreeFile(
+    File file,
+    String folderId,
+    OperationReport? report,
+  ) async {
+    try {
+      await _uploadFile(file.path, folderId);
+      report?.complete(file.path);
+      return true;
+    } on RequestCancelledException {
+      report?.skip(file.path, 'Cancelled');
+    } on SyncFilteredException catch (e) {
+      report?.skip(file.path, e.reason);
+      state = state.copyWith(error: e.toString());
+    } on QuotaExceededException catch (e) {
+      report?.fail(file.path, e);
+      state = state.copyWith(error: e.message);
+    } catch (e) {
+      report?.fail(file.path, e);
+      state = state.copyWith(
+        error: 'Failed to upload ${p.basename(file.path)}: $e',
+      );
+    }
+    return false;
+  }
+
+  /// Upload the small files of one folder a batch at a time; see
+  /// [BulkUploadService]. Locked files take the single upload path, which
+  /// waits for them. Returns the number of files uploaded.
+  Future<int> _uploadSmallFiles(
+    List<File> files,
+    String folderId,
+    OperationReport? report,
+  ) async {
+    final filter = ref.read(syncFilterProvider);
+    final bulk = ref.read(bulkUploadServiceProvider);
+    final paths = <String, String>{};
+    final sources = <UploadSource>[];
+    final folderName = files.isEmpty
+        ? ''
+        : p.basename(p.dirname(files.first.path));
+    var uploaded = 0;
+    for (final file in files) {
+      final name = p.basename(file.path);
+      final size = await file.length();
+      final mimeType = lookupMimeType(file.path);
+      final ignored = filter.reasonToIgnore(
+        name,
+        size: size,
+        mimeType: mimeType,
+      );
+      if (ignored != null) {
+        report?.skip(file.path, ignored);
+        state = state.copyWith(
+          error: SyncFilteredException(name, ignored).toString(),
+        );
+      } else if (await FileLocks.isLocked(file)) {
+        if (await _uploadTreeFile(file, folderId, report)) uploaded++;
+      } else {
+        paths[name] = file.path;
+        sources.add((
+          name: name,
+          size: size,
+          mimeType: mimeType ?? 'application/octet-stream',
+          open: file.openRead,
+        ));
+      }
+    }
+
+    for (final batch in bulk.plan(sources)) {
+      final size = batch.fold(0, (sum, f) => sum + f.size);
+      final name = batch.length == 1
+          ? batch.single.name
+          : '${batch.length} files from $folderName';
       try {
-        await uploadFileFromPath(path);
-        success++;
+        final result = await ref
+            .read(uploadSchedulerProvider)
+            .schedule(
+              id: paths[batch.first.name]!,
+              name: name,
+              size: size,
+              run: () {
+                ref.read(storageQuotaProvider).ensureRoom(name, size);
+                return _uploadBatch(batch, name, size, folderId);
+              },
+            );
+        for (final file in result.uploaded) {
+          final path = paths[file.name];
+          if (path != null) {
+            await ref.read(fileModeStoreProvider).remember(file.id, path);
+            report?.complete(path);
+          }
+          uploaded++;
+        }
+        result.failed.forEach((file, reason) {
+          report?.fail(paths[file] ?? file, reason);
+          state = state.copyWith(error: 'Failed to upload $file: $reason');
+        });
+      } on RequestCancelledException {
+        for (final file in batch) {
+          report?.skip(paths[file.name]!, 'Cancelled');
+        }
       } catch (e) {
+        for (final file in batch) {
+          report?.fail(paths[file.name]!, e);
+        }
         state = state.copyWith(
-          error: 'Failed to upload ${File(path).uri.pathSegments.last}: $e',
+          error: e is QuotaExceededException
+              ? e.message
+              : 'Failed to upload $name: $e',
         );
       }
     }
-    return success;
+    return uploaded;
   }
 
-  /// Download a file to the local downloads directory and return the path.
-  Future<String> downloadFileToLocal(String fileId, String fileName) async {
+  /// Send one batch of small files, listed as a single transfer.
+  Future<BatchUploadResult> _uploadBatch(
+    List<UploadSource> batch,
+    String name,
+    int size,
+    String folderId,
+  ) async {
+    final monitor = ref.read(transferMonitorProvider);
+    final transferId = monitor.begin(
+      name: name,
+      direction: TransferDirection.upload,
+      totalBytes: size,
+    );
+    try {
+      final result = await ref
+          .read(transferControllerProvider)
+          .run(
+            transferId,
+            (stop, {required resume}) => ref
+                .read(bulkUploadServiceProvider)
+                .upload(
+                  batch,
+                  folderId: folderId,
+                  onProgress: (sent, _) => monitor.update(transferId, sent),
+                  stop: stop,
+                ),
+          );
+      ref
+          .read(storageQuotaProvider)
+          .used(result.uploaded.fold(0, (sum, f) => sum + f.size));
+      if (result.uploaded.isEmpty) {
+        monitor.fail(transferId, reason: result.failed.values.firstOrNull);
+      } else {
+        monitor.complete(transferId);
+      }
+      return result;
+    } on QuotaExceededException catch (e) {
+      ref.read(storageQuotaProvider).markFull(size);
+      monitor.fail(transferId, reason: e.message);
+      rethrow;
+    } catch (_) {
+      monitor.fail(transferId);
+      rethrow;
+    }
+  }
+
+  /// Upload [link] as a link file holding [target]; see [LinkPolicy].
+  Future<void> _uploadLink(Link link, String target, String folderId) async {
+    final data = LinkResolver.metadataOf(target);
+    await ref
+        .read(fileRepositoryProvider)
+        .uploadFile(
+          name: p.basename(link.path),
+          folderId: folderId,
+          fileStream: Stream.value(data),
+          fileSize: data.length,
+          mimeType: LinkPolicy.mimeType,
+        );
+  }
+
+  /// Reassemble the file held by [bundle] in the local downloads directory
+  /// and return its path.
+  Future<String> downloadBundleToLocal(FolderEntity bundle) async {
+    final dir = await getDownloadsDirectory() ?? await getTemporaryDirectory();
+    final name = BundleService.fileNameOf(bundle.name);
+    final savePath = p.join(dir.path, name);
+    final monitor = ref.read(transferMonitorProvider);
+    final transferId = monitor.begin(
+      id: bundle.id,
+      name: name,
+      direction: TransferDirection.download,
+      totalBytes: 0,
+    );
+    try {
+      await ref.read(bundleServiceProvider).download(bundle.id, savePath);
+      monitor.complete(transferId);
+    } catch (_) {
+      monitor.fail(transferId);
+      rethrow;
+    }
+    return savePath;
+  }
+
+  /// Download a file of [size] bytes to the local downloads directory and
+  /// return the path. Throws [DiskFullException] without starting if it
+  /// does not fit next to the downloads already under way.
+  Future<String> downloadFileToLocal(
+    String fileId,
+    String fileName, {
+    int size = 0,
+  }) async {
     final dir = await getDownloadsDirectory() ?? await getTemporaryDirectory();
     final savePath = '${dir.path}/$fileName';
-    await ref.read(fileRepositoryProvider).downloadFileToPath(fileId, savePath);
+    await _ensureRoom(dir.path, size);
+    if (CaseConflicts.filesystemIgnoresCase) {
+      final existing = await CaseConflicts.variantOnDisk(dir.path, fileName);
+      if (existing != null) {
+        throw CaseConflictException(
+          name: fileName,
+          existing: existing,
+          suggestion: CaseConflicts.suggestRename(fileName, [existing]),
+        );
+      }
+    }
+    unawaited(ref.read(usageStatsProvider).count(UsageFeature.download));
+    final downloads = ref.read(resumableDownloadProvider);
+    final partial = await downloads.partialFor(fileId, savePath);
+    final monitor = ref.read(transferMonitorProvider);
+    final transferId = monitor.begin(
+      id: fileId,
+      name: fileName,
+      direction: TransferDirection.download,
+      totalBytes: size,
+    );
+    try {
+      await ref
+          .read(transferControllerProvider)
+          .run(
+            transferId,
+            (stop, {required resume}) => downloads.download(
+              fileId,
+              partial,
+              savePath,
+              onProgress: (received, total) =>
+                  monitor.update(transferId, received, totalBytes: total),
+              stop: stop,
+              resume: resume,
+            ),
+          );
+      await ref.read(tempFileAllocatorProvider).commit(partial, savePath);
+      await ref.read(fileModeStoreProvider).restore(fileId, savePath);
+      await ref.read(localCopyVerifierProvider).track(fileId, savePath);
+      monitor.complete(transferId);
+    } catch (_) {
+      if (await downloads.isKept(fileId, partial)) {
+        monitor.fail(
+          transferId,
+          reason: 'Connection lost; downloading again continues from here',
+        );
+        rethrow;
+      }
+      monitor.fail(transferId);
+      final leftover = File(partial);
+      if (leftover.existsSync()) await leftover.delete();
+      rethrow;
+    }
     return savePath;
   }
 
+  /// Check that [dir] has room for [bytes] more on top of the downloads
+  /// under way. If it has not even room for those, they are paused rather
+  /// than left to fail halfway. Either way a [DiskFull] event tells the
+  /// user how much space to free.
+  Future<void> _ensureRoom(String dir, int bytes) async {
+    final monitor = ref.read(transferMonitorProvider);
+    final pending = monitor.pendingDownloadBytes;
+    try {
+      await ref.read(diskSpaceProvider).ensureRoom(dir, pending + bytes);
+    } on DiskFullException catch (e) {
+      var paused = 0;
+      final space = ref.read(diskSpaceProvider);
+      if (pending + space.reserveBytes > e.available) {
+        final controller = ref.read(transferControllerProvider);
+        for (final t in monitor.transfers) {
+          if (t.direction == TransferDirection.download &&
+              controller.pause(t.id)) {
+            paused++;
+          }
+        }
+      }
+      ref
+          .read(syncEventBusProvider)
+          .emit(
+            DiskFull(
+              path: dir,
+              required: e.required + space.reserveBytes,
+              available: e.available,
+              paused: paused,
+            ),
+          );
+      rethrow;
+    }
+  }
+
   /// Toggle favorite status for a file.
   Future<void> toggleFavorite(FileEntity file) async {
     final favRepo = ref.read(favoritesRepositoryProvider);
@@ -198,6 +934,9 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
     Future.microtask(
       () => ref.read(fileBrowserProvider.notifier).loadFolder(widget.folderId),
     );
+    // A newly added account is listed in full once, with progress shown in
+    // the status bar.
+    unawaited(ref.read(setupScanProvider).runIfNeeded());
   }
 
   @override
@@ -231,6 +970,14 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
       body = Center(child: Text('Error: ${state.error}'));
     } else {
       body = _buildContent(state, isDesktop);
+      if (state.fromCache) {
+        body = Column(
+          children: [
+            _StaleListingBanner(listedAt: state.listedAt),
+            Expanded(child: body),
+          ],
+        );
+      }
     }
 
     // Desktop: wrap with toolbar + drag-drop
@@ -244,6 +991,7 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
                 .loadFolder(widget.folderId),
             onNewFolder: () => _createFolder(context),
             onUpload: _uploadFiles,
+            onPublishFolder: _publishFolder,
           ),
           Expanded(
             child: DragDropOverlay(
@@ -295,9 +1043,14 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
       );
     }
 
+    final excluded = ref.watch(syncExclusionsProvider).value ?? const {};
+    final shared = ref.watch(sharedItemIdsProvider).value ?? const {};
+    final filter = ref.watch(syncFilterProvider);
     final folderWidgets = state.folders.map(
       (f) => _FolderTile(
         folder: f,
+        excluded: excluded.contains(f.id),
+        shared: shared.contains(f.id),
         onTap: () => context.go('/files/${f.id}'),
         onContextMenu: (pos) => _showFolderContextMenu(context, f, pos),
       ),
@@ -305,6 +1058,12 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
     final fileWidgets = state.files.map(
       (f) => _FileTile(
         file: f,
+        shared: shared.contains(f.id),
+        ignoredReason: filter.reasonToIgnore(
+          f.name,
+          size: f.size,
+          mimeType: f.mimeType,
+        ),
         onContextMenu: (pos) => _showFileContextMenu(context, f, pos),
       ),
     );
@@ -381,43 +1140,158 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
     }
   }
 
+  void _publishFolder() async {
+    final dir = await FilePicker.getDirectoryPath();
+    if (dir == null) return;
+    final name = p.basename(dir);
+    if (mounted) {
+      ScaffoldMessenger.of(context).showSnackBar(
+        SnackBar(content: Text('Publishing $name…')),
+      );
+    }
+    try {
+      await ref.read(fileBrowserProvider.notifier).publishFolderFromPath(dir);
+      if (mounted) {
+        ScaffoldMessenger.of(context).hideCurrentSnackBar();
+        ScaffoldMessenger.of(
+          context,
+        ).showSnackBar(SnackBar(content: Text('$name published')));
+      }
+    } catch (e) {
+      if (mounted) {
+        ScaffoldMessenger.of(context).hideCurrentSnackBar();
+        ScaffoldMessenger.of(context).showSnackBar(
+          SnackBar(content: Text('$name was not published: $e')),
+        );
+      }
+    }
+  }
+
   void _handleDroppedFiles(List<String> paths) async {
     if (paths.isEmpty) return;
 
-    // Filter out directories — only upload files
-    final filePaths = <String>[];
-    for (final p in paths) {
-      if (FileSystemEntity.isFileSync(p)) {
-        filePaths.add(p);
-      }
-    }
-    if (filePaths.isEmpty) return;
+    final filePaths = paths.where(FileSystemEntity.isFileSync).toList();
+    final dirPaths = paths.where(FileSystemEntity.isDirectorySync).toList();
+    final total = filePaths.length + dirPaths.length;
+    if (total == 0) return;
 
     if (mounted) {
       ScaffoldMessenger.of(context).showSnackBar(
-        SnackBar(
-          content: Text('Uploading ${filePaths.length} dropped file(s)…'),
-        ),
+        SnackBar(content: Text('Uploading $total dropped item(s)…')),
       );
     }
 
-    final count = await ref
-        .read(fileBrowserProvider.notifier)
-        .uploadFilesFromPaths(filePaths);
+    final notifier = ref.read(fileBrowserProvider.notifier);
+    final report = OperationReport('Upload of $total dropped item(s)');
+    var count = await notifier.uploadFilesFromPaths(filePaths, report: report);
+    for (final dir in dirPaths) {
+      try {
+        count += await notifier.uploadFolderFromPath(dir, report: report);
+      } catch (e) {
+        report.fail(dir, e);
+        if (mounted) {
+          ScaffoldMessenger.of(context).showSnackBar(
+            SnackBar(content: Text('Upload of ${p.basename(dir)} failed: $e')),
+          );
+        }
+      }
+    }
+
+    // A report that cannot be written must not hide the upload result.
+    final reportPath = await ref
+        .read(operationReportServiceProvider)
+        .saveIfLarge(report)
+        .catchError((_) => null);
+    if (reportPath != null) ref.invalidate(operationReportsProvider);
 
     if (mounted) {
       ScaffoldMessenger.of(context).hideCurrentSnackBar();
       ScaffoldMessenger.of(context).showSnackBar(
-        SnackBar(content: Text('$count file(s) uploaded successfully')),
+        SnackBar(
+          content: Text('$count file(s) uploaded successfully'),
+          action: reportPath == null
+              ? null
+              : SnackBarAction(
+                  label: 'Report',
+                  onPressed: () => OpenFilex.open(reportPath),
+                ),
+        ),
       );
     }
   }
 
+  Future<void> _downloadBundle(
+    BuildContext context,
+    FolderEntity bundle,
+  ) async {
+    final messenger = ScaffoldMessenger.of(context);
+    final name = BundleService.fileNameOf(bundle.name);
+    messenger.showSnackBar(SnackBar(content: Text('Downloading "$name"…')));
+    try {
+      final path = await ref
+          .read(fileBrowserProvider.notifier)
+          .downloadBundleToLocal(bundle);
+      messenger
+        ..hideCurrentSnackBar()
+        ..showSnackBar(
+          SnackBar(
+            content: Text('Saved to $path'),
+            action: SnackBarAction(
+              label: 'Open',
+              onPressed: () => OpenFilex.open(path),
+            ),
+          ),
+        );
+    } catch (e) {
+      messenger
+        ..hideCurrentSnackBar()
+        ..showSnackBar(SnackBar(content: Text('Download failed: $e')));
+    }
+  }
+
+  /// Let the user pick which way [folder] syncs; its subfolders follow.
+  Future<void> _chooseSyncDirection(
+    BuildContext context,
+    FolderEntity folder,
+  ) async {
+    final current = await ref.read(syncDirectionProvider).forPath(folder.path);
+    if (!context.mounted) return;
+    final chosen = await showDialog<SyncDirection>(
+      context: context,
+      builder: (context) => SimpleDialog(
+        title: Text('Sync ${folder.name}'),
+        children: [
+          for (final direction in SyncDirection.values)
+            SimpleDialogOption(
+              onPressed: () => Navigator.pop(context, direction),
+              child: Row(
+                children: [
+                  Icon(
+                    direction == current
+                        ? Icons.radio_button_checked
+                        : Icons.radio_button_unchecked,
+                    size: 18,
+                  ),
+                  const SizedBox(width: 12),
+                  Text(direction.label),
+                ],
+              ),
+            ),
+        ],
+      ),
+    );
+    if (chosen == null || chosen == current) return;
+    await ref.read(syncDirectionProvider).set(folder, chosen);
+  }
+
   void _showFolderContextMenu(
     BuildContext context,
     FolderEntity folder,
     Offset pos,
   ) {
+    final excluded =
+        ref.read(syncExclusionsProvider).value?.contains(folder.id) ?? false;
+    final inbox = ref.read(inboxSettingsProvider);
     AppContextMenu.show(
       context: context,
       position: pos,
@@ -427,6 +1301,31 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
           label: 'Open',
           onTap: () => context.go('/files/${folder.id}'),
         ),
+        if (BundleService.isBundle(folder.name))
+          ContextMenuItem(
+            icon: Icons.download,
+            label: 'Download ${BundleService.fileNameOf(folder.name)}',
+            onTap: () => _downloadBundle(context, folder),
+          ),
+        ContextMenuItem(
+          icon: Icons.link,
+          label: 'Share link…',
+          onTap: () => ShareLinkDialog.show(
+            context,
+            itemId: folder.id,
+            itemType: 'folder',
+            itemName: folder.name,
+          ),
+        ),
+        ContextMenuItem(
+          icon: Icons.drive_file_move_outline,
+          label: 'Copy to another account…',
+          onTap: () => AccountMigrationDialog.show(
+            context,
+            folderId: folder.id,
+            folderName: folder.name,
+          ),
+        ),
         ContextMenuItem(
           icon: Icons.edit,
           label: 'Rename',
@@ -446,6 +1345,61 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
             }
           },
         ),
+        if (excluded)
+          ContextMenuItem(
+            icon: Icons.sync,
+            label: 'Include in sync',
+            onTap: () async {
+              await ref.read(selectiveSyncProvider).include(folder.id);
+              ref.invalidate(syncExclusionsProvider);
+            },
+          )
+        else
+          ContextMenuItem(
+            icon: Icons.sync_disabled,
+            label: 'Exclude from sync',
+            onTap: () async {
+              await ref.read(selectiveSyncProvider).exclude(folder);
+              ref.invalidate(syncExclusionsProvider);
+            },
+          ),
+        ContextMenuItem(
+          icon: Icons.swap_vert,
+          label: 'Sync direction…',
+          onTap: () => _chooseSyncDirection(context, folder),
+        ),
+        if (inbox.folderId != folder.id)
+          ContextMenuItem(
+            icon: Icons.move_to_inbox_outlined,
+            label: 'Use as inbox',
+            onTap: () => ref
+                .read(inboxSettingsProvider.notifier)
+                .set(
+                  inbox.copyWith(folderId: folder.id, folderPath: folder.path),
+                ),
+          ),
+        if (inbox.isEnabled && inbox.folderId != folder.id)
+          ContextMenuItem(
+            icon: Icons.drive_file_move_outline,
+            label: 'Sort inbox files here…',
+            onTap: () async {
+              final pattern = await AppDialogs.showTextInput(
+                context: context,
+                title: 'Sort into "${folder.name}"',
+                hint: 'File name pattern, e.g. *.pdf (empty for all)',
+                confirmLabel: 'Add rule',
+              );
+              if (pattern == null) return;
+              await ref
+                  .read(inboxServiceProvider)
+                  .addRule(
+                    namePattern: pattern.trim().isEmpty ? null : pattern.trim(),
+                    folderId: folder.id,
+                    folderPath: folder.path,
+                  );
+              ref.invalidate(inboxRulesProvider);
+            },
+          ),
         ContextMenuItem(
           icon: Icons.delete,
           label: 'Delete',
@@ -466,6 +1420,33 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
     );
   }
 
+  /// Pause, resume and cancel entries while [fileId] is downloading.
+  List<ContextMenuItem> _transferItems(String fileId) {
+    final state = ref.read(transferMonitorProvider).progressOf(fileId)?.state;
+    final controller = ref.read(transferControllerProvider);
+    return [
+      if (state == TransferState.active)
+        ContextMenuItem(
+          icon: Icons.pause,
+          label: 'Pause download',
+          onTap: () => controller.pause(fileId),
+        ),
+      if (state == TransferState.paused)
+        ContextMenuItem(
+          icon: Icons.play_arrow,
+          label: 'Resume download',
+          onTap: () => controller.resume(fileId),
+        ),
+      if (state == TransferState.active || state == TransferState.paused)
+        ContextMenuItem(
+          icon: Icons.close,
+          label: 'Cancel download',
+          onTap: () => controller.cancel(fileId),
+          isDanger: true,
+        ),
+    ];
+  }
+
   void _showFileContextMenu(BuildContext context, FileEntity file, Offset pos) {
     AppContextMenu.show(
       context: context,
@@ -481,7 +1462,7 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
               );
               final path = await ref
                   .read(fileBrowserProvider.notifier)
-                  .downloadFileToLocal(file.id, file.name);
+                  .downloadFileToLocal(file.id, file.name, size: file.size);
               if (!context.mounted) return;
               ScaffoldMessenger.of(context).hideCurrentSnackBar();
               ScaffoldMessenger.of(context).showSnackBar(
@@ -493,6 +1474,15 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
                   ),
                 ),
               );
+            } on RequestCancelledException {
+              if (context.mounted) {
+                ScaffoldMessenger.of(context).hideCurrentSnackBar();
+              }
+            } on DiskFullException {
+              // The DiskFull event explains what to do.
+              if (context.mounted) {
+                ScaffoldMessenger.of(context).hideCurrentSnackBar();
+              }
             } catch (e) {
               if (context.mounted) {
                 ScaffoldMessenger.of(context).hideCurrentSnackBar();
@@ -503,6 +1493,17 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
             }
           },
         ),
+        ..._transferItems(file.id),
+        ContextMenuItem(
+          icon: Icons.link,
+          label: 'Share link…',
+          onTap: () => ShareLinkDialog.show(
+            context,
+            itemId: file.id,
+            itemType: 'file',
+            itemName: file.name,
+          ),
+        ),
         ContextMenuItem(
           icon: Icons.edit,
           label: 'Rename',
@@ -520,6 +1521,17 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
             }
           },
         ),
+        ContextMenuItem(
+          icon: Icons.history,
+          label: 'Versions…',
+          onTap: () async {
+            if (await FileVersionsDialog.show(context, file)) {
+              ref
+                  .read(fileBrowserProvider.notifier)
+                  .loadFolder(widget.folderId);
+            }
+          },
+        ),
         ContextMenuItem(
           icon: file.isFavorite ? Icons.star : Icons.star_outline,
           label: file.isFavorite ? 'Remove favorite' : 'Add to favorites',
@@ -568,11 +1580,15 @@ class _FileBrowserPageState extends ConsumerState<FileBrowserPage> {
 
 class _FolderTile extends StatelessWidget {
   final FolderEntity folder;
+  final bool excluded;
+  final bool shared;
   final VoidCallback onTap;
   final void Function(Offset) onContextMenu;
 
   const _FolderTile({
     required this.folder,
+    this.excluded = false,
+    this.shared = false,
     required this.onTap,
     required this.onContextMenu,
   });
@@ -591,7 +1607,25 @@ class _FolderTile extends StatelessWidget {
             child: Column(
               mainAxisAlignment: MainAxisAlignment.center,
               children: [
-                Icon(Icons.folder, size: 48, color: Colors.amber.shade700),
+                _SharedBadge(
+                  shared: shared,
+                  child: Badge(
+                    isLabelVisible: excluded,
+                    label: const Icon(Icons.sync_disabled, size: 10),
+                    child: Tooltip(
+                      message: folder.isExternalStorage
+                          ? 'External storage (${folder.mountType})'
+                          : '',
+                      child: Icon(
+                        folder.isExternalStorage
+                            ? Icons.folder_special
+                            : Icons.folder,
+                        size: 48,
+                        color: Colors.amber.shade700,
+                      ),
+                    ),
+                  ),
+                ),
                 const SizedBox(height: 8),
                 Text(
                   folder.name,
@@ -610,9 +1644,18 @@ class _FolderTile extends StatelessWidget {
 
 class _FileTile extends StatelessWidget {
   final FileEntity file;
+  final bool shared;
+
+  /// Why the sync filter ignores the file, if it does.
+  final String? ignoredReason;
   final void Function(Offset) onContextMenu;
 
-  const _FileTile({required this.file, required this.onContextMenu});
+  const _FileTile({
+    required this.file,
+    this.shared = false,
+    this.ignoredReason,
+    required this.onContextMenu,
+  });
 
   @override
   Widget build(BuildContext context) {
@@ -625,10 +1668,18 @@ class _FileTile extends StatelessWidget {
           child: Column(
             mainAxisAlignment: MainAxisAlignment.center,
             children: [
-              FileIcon(
-                mimeType: file.mimeType,
-                extension: file.extension,
-                size: 48,
+              _SharedBadge(
+                shared: shared,
+                child: Badge(
+                  isLabelVisible: ignoredReason != null,
+                  label: const Icon(Icons.block, size: 10),
+                  child: Tooltip(
+                    message: ignoredReason == null
+                        ? ''
+                        : 'Ignored: $ignoredReason',
+                    child: _FileThumbnail(file: file),
+                  ),
+                ),
               ),
               const SizedBox(height: 8),
               Text(
@@ -648,3 +1699,127 @@ class _FileTile extends StatelessWidget {
     );
   }
 }
+
+/// The file's thumbnail for images, its type icon otherwise.
+class _FileThumbnail extends ConsumerStatefulWidget {
+  final FileEntity file;
+
+  const _FileThumbnail({required this.file});
+
+  @override
+  ConsumerState<_FileThumbnail> createState() => _FileThumbnailState();
+}
+
+class _FileThumbnailState extends ConsumerState<_FileThumbnail> {
+  Uint8List? _bytes;
+
+  @override
+  void initState() {
+    super.initState();
+    _load();
+  }
+
+  @override
+  void didUpdateWidget(_FileThumbnail old) {
+    super.didUpdateWidget(old);
+    if (old.file.id != widget.file.id) {
+      _bytes = null;
+      _load();
+    }
+  }
+
+  Future<void> _load() async {
+    if (!widget.file.isImage) return;
+    final thumbnails = ref.read(thumbnailServiceProvider);
+    final id = widget.file.id;
+    _bytes = thumbnails.cached(id);
+    if (_bytes != null) return;
+    final bytes = await thumbnails.load(id);
+    if (mounted && bytes != null && widget.file.id == id) {
+      setState(() => _bytes = bytes);
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final bytes = _bytes;
+    if (bytes == null) {
+      return FileIcon(
+        mimeType: widget.file.mimeType,
+        extension: widget.file.extension,
+        size: 48,
+      );
+    }
+    return ClipRRect(
+      borderRadius: BorderRadius.circular(4),
+      child: Image.memory(bytes, width: 48, height: 48, fit: BoxFit.cover),
+    );
+  }
+}
+
+/// Marks an item that has a share link with a small link badge.
+class _SharedBadge extends StatelessWidget {
+  final bool shared;
+  final Widget child;
+
+  const _SharedBadge({required this.shared, required this.child});
+
+  @override
+  Widget build(BuildContext context) {
+    return Badge(
+      isLabelVisible: shared,
+      alignment: AlignmentDirectional.bottomEnd,
+      backgroundColor: Theme.of(context).colorScheme.primary,
+      label: const Icon(Icons.link, size: 10, color: Colors.white),
+      child: child,
+    );
+  }
+}
+
+class _StaleListingBanner extends StatelessWidget {
+  final DateTime? listedAt;
+
+  const _StaleListingBanner({required this.listedAt});
+
+  @override
+  Widget build(BuildContext context) {
+    final colors = Theme.of(context).colorScheme;
+    final listedAt = this.listedAt;
+    final message = listedAt == null
+        ? 'Offline. This folder has not been loaded yet.'
+        : 'Offline. Showing the listing from ${_formatAge(listedAt)}; '
+              'it refreshes when the server is reachable again.';
+
+    return Material(
+      color: colors.secondaryContainer,
+      child: Padding(
+        padding: const EdgeInsets.symmetric(horizontal: 16, vertical: 8),
+        child: Row(
+          children: [
+            Icon(
+              Icons.cloud_off,
+              size: 18,
+              color: colors.onSecondaryContainer,
+            ),
+            const SizedBox(width: 12),
+            Expanded(
+              child: Text(
+                message,
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                  color: colors.onSecondaryContainer,
+                ),
+              ),
+            ),
+          ],
+        ),
+      ),
+    );
+  }
+
+  static String _formatAge(DateTime dt) {
+    final diff = DateTime.now().difference(dt);
+    if (diff.inDays > 0) return '${diff.inDays}d ago';
+    if (diff.inHours > 0) return '${diff.inHours}h ago';
+    return '${diff.inMinutes}m ago';
+  }
+}
@@ -0,0 +1,201 @@
+import 'dart:io';
+
+import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:open_filex/open_filex.dart';
+import 'package:path/path.dart' as p;
+import 'package:path_provider/path_provider.dart';
+
+import '../../../../providers.dart';
+import '../../../../domain/entities/file_entity.dart';
+import '../../../../domain/entities/file_version_entity.dart';
+import '../../widgets/dialogs.dart';
+
+/// Lists the earlier versions the server kept of [file], each of which can
+/// be downloaded or restored. Pops with true after a restore.
+class FileVersionsDialog extends ConsumerStatefulWidget {
+  final FileEntity file;
+
+  const FileVersionsDialog({super.key, required this.file});
+
+  static Future<bool> show(BuildContext context, FileEntity file) async {
+    final restored = await showDialog<bool>(
+      context: context,
+      builder: (_) => FileVersionsDialog(file: file),
+    );
+    return restored ?? false;
+  }
+
+  @override
+  ConsumerState<FileVersionsDialog> createState() => _FileVersionsDialogState();
+}
+
+class _FileVersionsDialogState extends ConsumerState<FileVersionsDialog> {
+  late Future<List<FileVersionEntity>> _versions;
+  String? _busyId;
+
+  @override
+  void initState() {
+    super.initState();
+    _versions = ref
+        .read(fileVersionRepositoryProvider)
+        .listVersions(widget.file.id);
+  }
+
+  Future<void> _download(FileVersionEntity version) async {
+    setState(() => _busyId = version.id);
+    try {
+      final dir =
+          await getDownloadsDirectory() ?? await getTemporaryDirectory();
+      final name = widget.file.name;
+      final stamp = _formatDate(version.modifiedAt).replaceAll(':', '-');
+      final path = p.join(
+        dir.path,
+        '${p.basenameWithoutExtension(name)} ($stamp)${p.extension(name)}',
+      );
+      final tempFiles = ref.read(tempFileAllocatorProvider);
+      final partial = tempFiles.allocate(path);
+      try {
+        await ref
+            .read(fileVersionRepositoryProvider)
+            .downloadVersionToPath(widget.file.id, version.id, partial);
+        await tempFiles.commit(partial, path);
+      } catch (_) {
+        final leftover = File(partial);
+        if (leftover.existsSync()) await leftover.delete();
+        rethrow;
+      }
+      if (!mounted) return;
+      ScaffoldMessenger.of(context).showSnackBar(
+        SnackBar(
+          content: Text('Saved to $path'),
+          action: SnackBarAction(
+            label: 'Open',
+            onPressed: () => OpenFilex.open(path),
+          ),
+        ),
+      );
+    } catch (e) {
+      if (mounted) {
+        AppDialogs.showSnack(context, 'Download failed: $e', isError: true);
+      }
+    } finally {
+      if (mounted) setState(() => _busyId = null);
+    }
+  }
+
+  Future<void> _restore(FileVersionEntity version) async {
+    final confirm = await AppDialogs.showConfirm(
+      context: context,
+      title: 'Restore this version?',
+      message:
+          'The version from ${_formatDate(version.modifiedAt)} becomes the '
+          'current content of "${widget.file.name}". The current content is '
+          'kept as a version.',
+      confirmLabel: 'Restore',
+    );
+    if (!confirm || !mounted) return;
+    setState(() => _busyId = version.id);
+    try {
+      await ref
+          .read(fileVersionRepositoryProvider)
+          .restoreVersion(widget.file.id, version.id);
+      if (mounted) Navigator.pop(context, true);
+    } catch (e) {
+      if (!mounted) return;
+      setState(() => _busyId = null);
+      AppDialogs.showSnack(context, 'Restore failed: $e', isError: true);
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text('Versions of "${widget.file.name}"'),
+      content: SizedBox(
+        width: 480,
+        height: 360,
+        child: FutureBuilder<List<FileVersionEntity>>(
+          future: _versions,
+          builder: (context, snapshot) {
+            if (snapshot.hasError) {
+              return Center(
+                child: Text('Could not load versions: ${snapshot.error}'),
+              );
+            }
+            final versions = snapshot.data;
+            if (versions == null) {
+              return const Center(child: CircularProgressIndicator());
+            }
+            if (versions.isEmpty) {
+              return const Center(
+                child: Text('The server has no earlier versions of this file.'),
+              );
+            }
+            return ListView.builder(
+              itemCount: versions.length,
+              itemBuilder: (context, i) => _tile(versions[i]),
+            );
+          },
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.pop(context, false),
+          child: const Text('Close'),
+        ),
+      ],
+    );
+  }
+
+  Widget _tile(FileVersionEntity version) {
+    final by = version.modifiedBy;
+    final busy = _busyId != null;
+    return ListTile(
+      leading: const Icon(Icons.history),
+      title: Text(_formatDate(version.modifiedAt)),
+      subtitle: Text(
+        by == null
+            ? _formatSize(version.size)
+            : '${_formatSize(version.size)} · $by',
+      ),
+      trailing: _busyId == version.id
+          ? const SizedBox(
+              width: 20,
+              height: 20,
+              child: CircularProgressIndicator(strokeWidth: 2),
+            )
+          : Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                IconButton(
+                  icon: const Icon(Icons.download),
+                  tooltip: 'Download',
+                  onPressed: busy ? null : () => _download(version),
+                ),
+                IconButton(
+                  icon: const Icon(Icons.restore),
+                  tooltip: 'Restore',
+                  onPressed: busy ? null : () => _restore(version),
+                ),
+              ],
+            ),
+    );
+  }
+
+  static String _formatDate(DateTime dt) {
+    final local = dt.toLocal();
+    String two(int n) => n.toString().padLeft(2, '0');
+    return '${local.year}-${two(local.month)}-${two(local.day)} '
+        '${two(local.hour)}:${two(local.minute)}';
+  }
+
+  static String _formatSize(int bytes) {
+    if (bytes < 1024) return '$bytes B';
+    if (bytes < 1024 * 1024) return '${(bytes / 1024).toStringAsFixed(1)} KB';
+    if (bytes < 1024 * 1024 * 1024) {
+      return '${(bytes / (1024 * 1024)).toStringAsFixed(1)} MB';
+    }
+    return '${(bytes / (1024 * 1024 * 1024)).toStringAsFixed(1)} GB';
+  }
+}
@@ -0,0 +1,139 @@
+import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:go_router/go_router.dart';
+
+import '../../../../providers.dart';
+import '../../../core/services/troubleshooting_service.dart';
+import '../../shell/adaptive_shell.dart';
+import '../../widgets/empty_state.dart';
+
+/// Runs the diagnostics and walks the user through fixing what they find.
+class HelpPage extends ConsumerStatefulWidget {
+  const HelpPage({super.key});
+
+  @override
+  ConsumerState<HelpPage> createState() => _HelpPageState();
+}
+
+class _HelpPageState extends ConsumerState<HelpPage> {
+  late Future<List<TroubleshootingGuide>> _guides;
+
+  @override
+  void initState() {
+    super.initState();
+    _guides = ref.read(troubleshootingServiceProvider).run();
+  }
+
+  void _check() {
+    setState(() => _guides = ref.read(troubleshootingServiceProvider).run());
+  }
+
+  Future<void> _perform(TroubleshootingStep step) async {
+    switch (step.action) {
+      case TroubleshootingAction.openSettings:
+        context.go('/settings');
+      case TroubleshootingAction.relogin:
+        await ref.read(authRepositoryProvider).logout();
+        if (mounted) context.go('/login');
+      case TroubleshootingAction.copyCommand:
+        await Clipboard.setData(ClipboardData(text: step.command ?? ''));
+        if (!mounted) return;
+        ScaffoldMessenger.of(context).showSnackBar(
+          const SnackBar(content: Text('Command copied to the clipboard')),
+        );
+      case TroubleshootingAction.retry:
+        _check();
+      case null:
+        break;
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AdaptiveShell(
+      currentPath: '/help',
+      title: 'Help',
+      mobileActions: [
+        IconButton(
+          icon: const Icon(Icons.refresh),
+          tooltip: 'Check again',
+          onPressed: _check,
+        ),
+      ],
+      child: FutureBuilder<List<TroubleshootingGuide>>(
+        future: _guides,
+        builder: (context, snapshot) {
+          if (snapshot.connectionState != ConnectionState.done) {
+            return const Center(child: CircularProgressIndicator());
+          }
+          if (snapshot.hasError) {
+            return Center(child: Text('Error: ${snapshot.error}'));
+          }
+          final guides = snapshot.data!;
+          if (guides.isEmpty) {
+            return EmptyState(
+              icon: Icons.check_circle_outline,
+              title: 'No problems found',
+              subtitle: 'Name lookup, session, clock and disk space look fine',
+              action: OutlinedButton(
+                onPressed: _check,
+                child: const Text('Check again'),
+              ),
+            );
+          }
+          return ListView(
+            padding: const EdgeInsets.all(16),
+            children: [
+              for (final guide in guides)
+                _GuideCard(guide: guide, onAction: _perform),
+            ],
+          );
+        },
+      ),
+    );
+  }
+}
+
+class _GuideCard extends StatelessWidget {
+  final TroubleshootingGuide guide;
+  final Future<void> Function(TroubleshootingStep) onAction;
+
+  const _GuideCard({required this.guide, required this.onAction});
+
+  @override
+  Widget build(BuildContext context) {
+    final theme = Theme.of(context);
+    return Card(
+      child: ExpansionTile(
+        initiallyExpanded: true,
+        leading: Icon(
+          Icons.report_problem_outlined,
+          color: theme.colorScheme.error,
+        ),
+        title: Text(guide.title),
+        subtitle: Text(guide.finding.detail),
+        childrenPadding: const EdgeInsets.fromLTRB(16, 0, 16, 16),
+        children: [
+          for (final (i, step) in guide.steps.indexed)
+            ListTile(
+              leading: CircleAvatar(radius: 12, child: Text('${i + 1}')),
+              title: Text(step.text),
+              subtitle: step.command == null
+                  ? null
+                  : SelectableText(
+                      step.command!,
+                      style: const TextStyle(fontFamily: 'monospace'),
+                    ),
+              trailing: step.action == null
+                  ? null
+                  : OutlinedButton(
+                      onPressed: () => onAction(step),
+                      child: Text(step.actionLabel ?? 'Fix'),
+                    ),
+            ),
+        ],
+      ),
+    );
+  }
+}
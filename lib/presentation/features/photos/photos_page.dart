@@ -2,7 +2,6 @@ import 'dart:typed_data';
 
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
-import 'package:flutter_riverpod/legacy.dart';
 
 import '../../../../domain/entities/file_entity.dart';
 import '../../../../providers.dart';
@@ -92,11 +91,6 @@ final photosProvider = NotifierProvider<PhotosNotifier, PhotosState>(
   PhotosNotifier.new,
 );
 
-// Thumbnail cache provider — caches loaded thumbnails by file id
-final _thumbnailCacheProvider = StateProvider<Map<String, Uint8List>>(
-  (ref) => {},
-);
-
 // --- Page ---
 
 class PhotosPage extends ConsumerStatefulWidget {
@@ -270,27 +264,21 @@ class _PhotoThumbnailState extends ConsumerState<_PhotoThumbnail> {
   }
 
   Future<void> _loadThumbnail() async {
-    final cache = ref.read(_thumbnailCacheProvider);
-    final cached = cache[widget.photo.id];
+    final thumbnails = ref.read(thumbnailServiceProvider);
+    final cached = thumbnails.cached(widget.photo.id);
     if (cached != null) {
       setState(() => _bytes = cached);
       return;
     }
 
     setState(() => _loading = true);
-    try {
-      final bytes = await ref
-          .read(fileRepositoryProvider)
-          .getThumbnail(widget.photo.id, size: '256');
-      ref
-          .read(_thumbnailCacheProvider.notifier)
-          .update((state) => {...state, widget.photo.id: bytes});
-      if (mounted) setState(() => _bytes = bytes);
-    } catch (_) {
-      if (mounted) setState(() => _failed = true);
-    } finally {
-      if (mounted) setState(() => _loading = false);
-    }
+    final bytes = await thumbnails.load(widget.photo.id);
+    if (!mounted) return;
+    setState(() {
+      _bytes = bytes;
+      _failed = bytes == null;
+      _loading = false;
+    });
   }
 
   @override
@@ -1,3 +1,5 @@
+import 'dart:io';
+
 import 'package:file_picker/file_picker.dart';
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
@@ -90,15 +92,20 @@ class _PublicSharePageState extends ConsumerState<PublicSharePage> {
     if (savePath == null) return;
 
     setState(() => _downloading = true);
+    final tempFiles = ref.read(tempFileAllocatorProvider);
+    final partial = tempFiles.allocate(savePath);
     try {
       final ds = ref.read(publicShareRemoteDatasourceProvider);
-      await ds.download(widget.token, savePath);
+      await ds.download(widget.token, partial);
+      await tempFiles.commit(partial, savePath);
       if (mounted) {
         ScaffoldMessenger.of(
           context,
         ).showSnackBar(const SnackBar(content: Text('Download complete')));
       }
     } catch (e) {
+      final leftover = File(partial);
+      if (leftover.existsSync()) await leftover.delete();
       if (mounted) {
         ScaffoldMessenger.of(
           context,
@@ -1,8 +1,11 @@
+import 'dart:async';
+
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 
 import '../../../../domain/entities/search_result_entity.dart';
 import '../../../../domain/repositories/search_repository.dart';
+import '../../../core/services/usage_stats_service.dart';
 import '../../../../providers.dart';
 import '../../shell/adaptive_shell.dart';
 import '../../widgets/empty_state.dart';
@@ -54,6 +57,7 @@ class SearchNotifier extends Notifier<SearchState> {
       return;
     }
     state = state.copyWith(loading: true, error: null, query: query);
+    unawaited(ref.read(usageStatsProvider).count(UsageFeature.search));
     try {
       final results = await ref.read(searchRepositoryProvider).search(query);
       state = state.copyWith(results: results, loading: false);
@@ -0,0 +1,196 @@
+import 'dart:async';
+
+import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+import '../../../core/error/exceptions.dart';
+import '../../../core/services/account_migration_service.dart';
+import '../../../core/services/usage_stats_service.dart';
+import '../../../../providers.dart';
+
+/// Copies a folder, or the whole account, to another account: asks where
+/// to, then shows progress and the result of the final check. Running it
+/// again for the same target resumes where it stopped.
+class AccountMigrationDialog extends ConsumerStatefulWidget {
+  /// The folder to copy, or null for the whole account.
+  final String? folderId;
+  final String? folderName;
+
+  const AccountMigrationDialog({super.key, this.folderId, this.folderName});
+
+  static Future<void> show(
+    BuildContext context, {
+    String? folderId,
+    String? folderName,
+  }) {
+    return showDialog<void>(
+      context: context,
+      barrierDismissible: false,
+      builder: (_) =>
+          AccountMigrationDialog(folderId: folderId, folderName: folderName),
+    );
+  }
+
+  @override
+  ConsumerState<AccountMigrationDialog> createState() =>
+      _AccountMigrationDialogState();
+}
+
+class _AccountMigrationDialogState
+    extends ConsumerState<AccountMigrationDialog> {
+  final _server = TextEditingController(text: 'https://');
+  final _username = TextEditingController();
+  final _password = TextEditingController();
+  Completer<void>? _stop;
+  MigrationProgress? _progress;
+  MigrationReport? _report;
+  String? _error;
+
+  @override
+  void dispose() {
+    _server.dispose();
+    _username.dispose();
+    _password.dispose();
+    super.dispose();
+  }
+
+  Future<void> _start() async {
+    final stop = _stop = Completer<void>();
+    setState(() {
+      _error = null;
+      _report = null;
+      _progress = const MigrationProgress(phase: MigrationPhase.counting);
+    });
+    unawaited(
+      ref.read(usageStatsProvider).count(UsageFeature.accountMigration),
+    );
+    MigrationTarget? target;
+    try {
+      target = await MigrationTarget.connect(
+        _server.text.trim().replaceAll(RegExp(r'/+$'), ''),
+        username: _username.text.trim(),
+        password: _password.text,
+        clients: ref.read(serverClientFactoryProvider),
+      );
+      final report = await AccountMigrationService(
+        sourceFiles: ref.read(fileRepositoryProvider),
+        sourceFolders: ref.read(folderRepositoryProvider),
+        targetFiles: target.files,
+        targetFolders: target.folders,
+      ).migrate(
+        sourceFolderId: widget.folderId,
+        stop: stop.future,
+        onProgress: (p) {
+          if (mounted) setState(() => _progress = p);
+        },
+      );
+      if (mounted) setState(() => _report = report);
+    } on RequestCancelledException {
+      if (mounted) setState(() => _error = 'Stopped. Start again to resume.');
+    } catch (e) {
+      if (mounted) setState(() => _error = 'Migration failed: $e');
+    } finally {
+      await target?.close();
+      _stop = null;
+      if (mounted) setState(() {});
+    }
+  }
+
+  String get _subject => widget.folderName == null
+      ? 'everything in this account'
+      : '"${widget.folderName}"';
+
+  @override
+  Widget build(BuildContext context) {
+    final running = _stop != null;
+    final progress = _progress;
+    final report = _report;
+    final theme = Theme.of(context);
+
+    return AlertDialog(
+      title: const Text('Copy to another account'),
+      content: SizedBox(
+        width: 440,
+        child: Column(
+          mainAxisSize: MainAxisSize.min,
+          crossAxisAlignment: CrossAxisAlignment.start,
+          children: [
+            Text(
+              'Copies $_subject to the account below. Files already there '
+              'are skipped, so a stopped copy can be resumed.',
+              style: theme.textTheme.bodySmall,
+            ),
+            const SizedBox(height: 16),
+            TextField(
+              controller: _server,
+              enabled: !running,
+              decoration: const InputDecoration(labelText: 'Server'),
+            ),
+            TextField(
+              controller: _username,
+              enabled: !running,
+              decoration: const InputDecoration(labelText: 'Username'),
+            ),
+            TextField(
+              controller: _password,
+              enabled: !running,
+              obscureText: true,
+              decoration: const InputDecoration(labelText: 'Password'),
+            ),
+            if (progress != null && (running || report != null)) ...[
+              const SizedBox(height: 16),
+              LinearProgressIndicator(value: progress.fraction),
+              const SizedBox(height: 8),
+              Text(switch (progress.phase) {
+                MigrationPhase.counting => 'Listing files…',
+                MigrationPhase.copying =>
+                  'Copied ${progress.filesCopied + progress.filesSkipped} '
+                      'of ${progress.filesTotal} files',
+                MigrationPhase.verifying => 'Checking the copies…',
+                MigrationPhase.done => 'Done',
+              }),
+            ],
+            if (report != null) ...[
+              const SizedBox(height: 8),
+              Text(
+                report.verified
+                    ? '${report.filesCopied} files copied, '
+                          '${report.filesSkipped} already there. '
+                          'Every file checked out.'
+                    : '${report.mismatches.length} files are missing or '
+                          'differ on the target: '
+                          '${report.mismatches.take(5).join(', ')}',
+                style: report.verified
+                    ? null
+                    : TextStyle(color: theme.colorScheme.error),
+              ),
+            ],
+            if (_error != null) ...[
+              const SizedBox(height: 8),
+              Text(_error!, style: TextStyle(color: theme.colorScheme.error)),
+            ],
+          ],
+        ),
+      ),
+      actions: [
+        if (running)
+          TextButton(
+            onPressed: _stop!.isCompleted
+                ? null
+                : () => setState(() => _stop!.complete()),
+            child: const Text('Stop'),
+          )
+        else ...[
+          TextButton(
+            onPressed: () => Navigator.pop(context),
+            child: const Text('Close'),
+          ),
+          FilledButton(
+            onPressed: _start,
+            child: Text(report == null ? 'Start' : 'Run again'),
+          ),
+        ],
+      ],
+    );
+  }
+}
@@ -0,0 +1,121 @@
+import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+import '../../../core/network/proxy_settings.dart';
+import '../../../../providers.dart';
+
+/// Edits the proxy every connection goes through. Reachable before
+/// connecting, for networks where the server is only reachable through
+/// one.
+class ProxySettingsDialog extends ConsumerStatefulWidget {
+  const ProxySettingsDialog({super.key});
+
+  static Future<void> show(BuildContext context) => showDialog<void>(
+    context: context,
+    builder: (_) => const ProxySettingsDialog(),
+  );
+
+  @override
+  ConsumerState<ProxySettingsDialog> createState() =>
+      _ProxySettingsDialogState();
+}
+
+class _ProxySettingsDialogState extends ConsumerState<ProxySettingsDialog> {
+  final _formKey = GlobalKey<FormState>();
+  late final ProxySettings _saved = ref.read(proxySettingsProvider);
+  late ProxyMode _mode = _saved.mode;
+  late final _url = TextEditingController(text: _saved.url);
+  late final _username = TextEditingController(text: _saved.username);
+  late final _password = TextEditingController(text: _saved.password);
+
+  @override
+  void dispose() {
+    _url.dispose();
+    _username.dispose();
+    _password.dispose();
+    super.dispose();
+  }
+
+  Future<void> _save() async {
+    if (!_formKey.currentState!.validate()) return;
+    final username = _username.text.trim();
+    await ref
+        .read(proxySettingsProvider.notifier)
+        .set(
+          ProxySettings(
+            mode: _mode,
+            url: _url.text.trim(),
+            username: username.isEmpty ? null : username,
+            password: username.isEmpty ? null : _password.text,
+          ),
+        );
+    if (mounted) Navigator.pop(context);
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final manual = _mode == ProxyMode.manual;
+    return AlertDialog(
+      title: const Text('Proxy'),
+      content: SizedBox(
+        width: 420,
+        child: Form(
+          key: _formKey,
+          child: Column(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              DropdownButtonFormField<ProxyMode>(
+                initialValue: _mode,
+                decoration: InputDecoration(
+                  labelText: 'Connect',
+                  helperText: _mode == ProxyMode.system
+                      ? 'Uses http_proxy, https_proxy and no_proxy'
+                      : null,
+                ),
+                items: [
+                  for (final mode in ProxyMode.values)
+                    DropdownMenuItem(value: mode, child: Text(mode.label)),
+                ],
+                onChanged: (v) {
+                  if (v != null) setState(() => _mode = v);
+                },
+              ),
+              if (manual) ...[
+                const SizedBox(height: 16),
+                TextFormField(
+                  controller: _url,
+                  decoration: const InputDecoration(
+                    labelText: 'Proxy address',
+                    hintText: 'http://proxy.example.com:3128',
+                    helperText: 'http:// or socks5://',
+                  ),
+                  validator: (v) => ProxySettings.validateUrl(v?.trim() ?? ''),
+                ),
+                const SizedBox(height: 16),
+                TextFormField(
+                  controller: _username,
+                  decoration: const InputDecoration(
+                    labelText: 'Username',
+                    helperText: 'Leave empty if the proxy needs no login',
+                  ),
+                ),
+                TextFormField(
+                  controller: _password,
+                  obscureText: true,
+                  decoration: const InputDecoration(labelText: 'Password'),
+                ),
+              ],
+            ],
+          ),
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.pop(context),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(onPressed: _save, child: const Text('Save')),
+      ],
+    );
+  }
+}
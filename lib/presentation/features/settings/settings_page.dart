@@ -1,11 +1,26 @@
+import 'package:file_picker/file_picker.dart';
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:go_router/go_router.dart';
 
 import '../../../../providers.dart';
+import '../../../core/database/app_database.dart';
+import '../../../core/network/interceptors/custom_headers_interceptor.dart';
+import '../../../core/network/proxy_settings.dart';
+import '../../../core/network/tls_trust.dart';
+import '../../../core/services/inbox_service.dart';
+import '../../../core/sync/conflict_resolver.dart';
+import '../../../core/sync/ignore_rules.dart';
+import '../../../core/sync/link_policy.dart';
+import '../../../core/sync/metered_network.dart';
+import '../../../core/sync/sync_filter.dart';
 import '../../../data/datasources/remote/app_password_remote_datasource.dart';
-import '../../../data/datasources/remote/device_auth_remote_datasource.dart';
+import '../../../domain/entities/session_entity.dart';
+import '../../../domain/repositories/session_repository.dart';
 import '../../shell/adaptive_shell.dart';
+import 'account_migration_dialog.dart';
+import 'proxy_settings_dialog.dart';
 
 // --- App Passwords State ---
 
@@ -86,7 +101,7 @@ final appPasswordsProvider =
 // --- Devices State ---
 
 class DevicesState {
-  final List<DeviceInfo> devices;
+  final List<SessionEntity> devices;
   final bool loading;
   final String? error;
 
@@ -97,7 +112,7 @@ class DevicesState {
   });
 
   DevicesState copyWith({
-    List<DeviceInfo>? devices,
+    List<SessionEntity>? devices,
     bool? loading,
     String? error,
   }) {
@@ -113,29 +128,73 @@ class DevicesNotifier extends Notifier<DevicesState> {
   @override
   DevicesState build() => const DevicesState();
 
-  DeviceAuthRemoteDatasource get _ds =>
-      ref.read(deviceAuthRemoteDatasourceProvider);
+  SessionRepository get _repo => ref.read(sessionRepositoryProvider);
 
   Future<void> load() async {
     state = state.copyWith(loading: true, error: null);
     try {
-      final devices = await _ds.listDevices();
+      final devices = await _repo.listSessions();
       state = state.copyWith(devices: devices, loading: false);
     } catch (e) {
       state = state.copyWith(loading: false, error: e.toString());
     }
   }
 
-  Future<void> revoke(String id) async {
+  Future<void> revoke(SessionEntity session) async {
     try {
-      await _ds.revokeDevice(id);
-      await load();
+      await _repo.revokeSession(session);
+      if (!session.isCurrent) await load();
     } catch (e) {
       state = state.copyWith(error: e.toString());
     }
   }
 }
 
+// --- Conflict Rules ---
+
+class ConflictRulesNotifier extends Notifier<List<ConflictRulesTableData>> {
+  @override
+  List<ConflictRulesTableData> build() => const [];
+
+  ConflictResolver get _resolver => ref.read(conflictResolverProvider);
+
+  Future<void> load() async {
+    state = await _resolver.listRules();
+  }
+
+  Future<void> add({
+    String? pathPrefix,
+    String? namePattern,
+    required ConflictResolution resolution,
+  }) async {
+    await _resolver.addRule(
+      pathPrefix: pathPrefix,
+      namePattern: namePattern,
+      resolution: resolution,
+    );
+    await load();
+    await _resolver.resolvePending();
+  }
+
+  Future<void> delete(int id) async {
+    await _resolver.deleteRule(id);
+    await load();
+  }
+
+  Future<void> move(int oldIndex, int newIndex) async {
+    final ids = state.map((r) => r.id).toList();
+    final id = ids.removeAt(oldIndex);
+    ids.insert(newIndex > oldIndex ? newIndex - 1 : newIndex, id);
+    await _resolver.reorderRules(ids);
+    await load();
+  }
+}
+
+final conflictRulesProvider =
+    NotifierProvider<ConflictRulesNotifier, List<ConflictRulesTableData>>(
+      ConflictRulesNotifier.new,
+    );
+
 final devicesProvider = NotifierProvider<DevicesNotifier, DevicesState>(
   DevicesNotifier.new,
 );
@@ -156,10 +215,12 @@ class _SettingsPageState extends ConsumerState<SettingsPage>
   @override
   void initState() {
     super.initState();
-    _tabCtrl = TabController(length: 2, vsync: this);
+    _tabCtrl = TabController(length: 7, vsync: this);
     Future.microtask(() {
       ref.read(appPasswordsProvider.notifier).load();
       ref.read(devicesProvider.notifier).load();
+      ref.read(conflictRulesProvider.notifier).load();
+      ref.invalidate(usageCountersProvider);
     });
   }
 
@@ -181,12 +242,25 @@ class _SettingsPageState extends ConsumerState<SettingsPage>
             tabs: const [
               Tab(text: 'App Passwords', icon: Icon(Icons.key_outlined)),
               Tab(text: 'Devices', icon: Icon(Icons.devices_outlined)),
+              Tab(text: 'Conflict Rules', icon: Icon(Icons.rule)),
+              Tab(text: 'Transfers', icon: Icon(Icons.speed)),
+              Tab(text: 'Inbox', icon: Icon(Icons.move_to_inbox_outlined)),
+              Tab(text: 'Connection', icon: Icon(Icons.lan_outlined)),
+              Tab(text: 'Usage', icon: Icon(Icons.insights_outlined)),
             ],
           ),
           Expanded(
             child: TabBarView(
               controller: _tabCtrl,
-              children: const [_AppPasswordsTab(), _DevicesTab()],
+              children: const [
+                _AppPasswordsTab(),
+                _DevicesTab(),
+                _ConflictRulesTab(),
+                _TransfersTab(),
+                _InboxTab(),
+                _ConnectionTab(),
+                _UsageTab(),
+              ],
             ),
           ),
         ],
@@ -365,7 +439,7 @@ class _DevicesTab extends ConsumerWidget {
             children: [
               Expanded(
                 child: Text(
-                  'Authorized Devices',
+                  'Connected Devices',
                   style: theme.textTheme.titleMedium,
                 ),
               ),
@@ -388,7 +462,7 @@ class _DevicesTab extends ConsumerWidget {
           child: state.loading && state.devices.isEmpty
               ? const Center(child: CircularProgressIndicator())
               : state.devices.isEmpty
-              ? const Center(child: Text('No authorized devices'))
+              ? const Center(child: Text('No connected devices'))
               : ListView.builder(
                   padding: const EdgeInsets.symmetric(horizontal: 16),
                   itemCount: state.devices.length,
@@ -396,19 +470,29 @@ class _DevicesTab extends ConsumerWidget {
                     final d = state.devices[i];
                     return Card(
                       child: ListTile(
-                        leading: Icon(_platformIcon(d.platform)),
-                        title: Text(d.name),
+                        leading: Icon(
+                          d.kind == SessionKind.appPassword
+                              ? Icons.key
+                              : _platformIcon(d.platform),
+                        ),
+                        title: Text(
+                          d.isCurrent ? '${d.name} (this device)' : d.name,
+                        ),
                         subtitle: Text(
                           [
                             if (d.platform != null) d.platform!,
-                            'Added ${d.createdAt}',
+                            if (d.kind == SessionKind.appPassword)
+                              'App password',
+                            if (d.createdAt != null)
+                              'Added ${_formatDate(d.createdAt!)}',
+                            if (d.lastUsed != null)
+                              'Last used ${_formatDate(d.lastUsed!)}',
                           ].join(' • '),
                         ),
                         trailing: IconButton(
                           icon: const Icon(Icons.link_off),
-                          tooltip: 'Revoke',
-                          onPressed: () =>
-                              ref.read(devicesProvider.notifier).revoke(d.id),
+                          tooltip: d.isCurrent ? 'Sign out' : 'Revoke',
+                          onPressed: () => _revoke(context, ref, d),
                         ),
                       ),
                     );
@@ -419,6 +503,43 @@ class _DevicesTab extends ConsumerWidget {
     );
   }
 
+  Future<void> _revoke(
+    BuildContext context,
+    WidgetRef ref,
+    SessionEntity session,
+  ) async {
+    if (session.isCurrent) {
+      final confirmed = await showDialog<bool>(
+        context: context,
+        builder: (ctx) => AlertDialog(
+          title: const Text('Sign out this device?'),
+          content: const Text(
+            'Revoking the session of this device signs you out.',
+          ),
+          actions: [
+            TextButton(
+              onPressed: () => Navigator.pop(ctx),
+              child: const Text('Cancel'),
+            ),
+            FilledButton(
+              onPressed: () => Navigator.pop(ctx, true),
+              child: const Text('Sign out'),
+            ),
+          ],
+        ),
+      );
+      if (confirmed != true) return;
+    }
+    await ref.read(devicesProvider.notifier).revoke(session);
+    if (session.isCurrent && context.mounted) context.go('/login');
+  }
+
+  String _formatDate(DateTime d) {
+    final local = d.toLocal();
+    return '${local.year}-${local.month.toString().padLeft(2, '0')}-'
+        '${local.day.toString().padLeft(2, '0')}';
+  }
+
   IconData _platformIcon(String? platform) {
     switch (platform?.toLowerCase()) {
       case 'windows':
@@ -436,3 +557,1010 @@ class _DevicesTab extends ConsumerWidget {
     }
   }
 }
+
+// --- Conflict Rules Tab ---
+
+class _ConflictRulesTab extends ConsumerWidget {
+  const _ConflictRulesTab();
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final rules = ref.watch(conflictRulesProvider);
+    final theme = Theme.of(context);
+
+    return Column(
+      children: [
+        Padding(
+          padding: const EdgeInsets.all(16),
+          child: Row(
+            children: [
+              Expanded(
+                child: Text(
+                  'Conflict Rules',
+                  style: theme.textTheme.titleMedium,
+                ),
+              ),
+              FilledButton.icon(
+                onPressed: () => _showAddDialog(context, ref),
+                icon: const Icon(Icons.add),
+                label: const Text('New'),
+              ),
+            ],
+          ),
+        ),
+        Padding(
+          padding: const EdgeInsets.symmetric(horizontal: 16),
+          child: Text(
+            'Rules are tried from top to bottom before you are asked about '
+            'a conflict. A rule for everything at the bottom sets the '
+            'default. Drag to change their order.',
+            style: theme.textTheme.bodySmall,
+          ),
+        ),
+        Expanded(
+          child: rules.isEmpty
+              ? const Center(child: Text('No conflict rules'))
+              : ReorderableListView.builder(
+                  padding: const EdgeInsets.all(16),
+                  itemCount: rules.length,
+                  onReorder: (from, to) =>
+                      ref.read(conflictRulesProvider.notifier).move(from, to),
+                  itemBuilder: (context, i) {
+                    final r = rules[i];
+                    final resolution = ConflictResolution.tryParse(
+                      r.resolution,
+                    );
+                    return Card(
+                      key: ValueKey(r.id),
+                      child: ListTile(
+                        leading: Icon(_icon(resolution)),
+                        title: Text(_describe(r)),
+                        subtitle: Text(_label(resolution)),
+                        trailing: IconButton(
+                          icon: const Icon(Icons.delete_outline),
+                          tooltip: 'Delete',
+                          onPressed: () => ref
+                              .read(conflictRulesProvider.notifier)
+                              .delete(r.id),
+                        ),
+                      ),
+                    );
+                  },
+                ),
+        ),
+      ],
+    );
+  }
+
+  static IconData _icon(ConflictResolution? resolution) =>
+      switch (resolution) {
+        ConflictResolution.local => Icons.computer,
+        ConflictResolution.remote => Icons.cloud_outlined,
+        ConflictResolution.lastWriterWins => Icons.schedule,
+        ConflictResolution.keepBoth => Icons.file_copy_outlined,
+        ConflictResolution.askUser || null => Icons.help_outline,
+      };
+
+  static String _label(ConflictResolution? resolution) =>
+      switch (resolution) {
+        ConflictResolution.local => 'Keep local change',
+        ConflictResolution.remote => 'Keep server version',
+        ConflictResolution.lastWriterWins => 'Keep the latest change',
+        ConflictResolution.keepBoth => 'Keep both',
+        ConflictResolution.askUser || null => 'Ask me',
+      };
+
+  String _describe(ConflictRulesTableData r) {
+    final parts = [
+      if (r.namePattern?.isNotEmpty ?? false) r.namePattern!,
+      if (r.pathPrefix?.isNotEmpty ?? false) 'in ${r.pathPrefix}',
+    ];
+    return parts.isEmpty ? 'Everything' : parts.join(' ');
+  }
+
+  Future<void> _showAddDialog(BuildContext context, WidgetRef ref) async {
+    final patternCtrl = TextEditingController();
+    final folderCtrl = TextEditingController();
+    var resolution = ConflictResolution.local;
+    final result = await showDialog<bool>(
+      context: context,
+      builder: (ctx) => StatefulBuilder(
+        builder: (ctx, setState) => AlertDialog(
+          title: const Text('New Conflict Rule'),
+          content: Column(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              TextField(
+                controller: patternCtrl,
+                autofocus: true,
+                decoration: const InputDecoration(
+                  labelText: 'File name pattern',
+                  hintText: '*.tmp',
+                ),
+              ),
+              TextField(
+                controller: folderCtrl,
+                decoration: const InputDecoration(
+                  labelText: 'Only in folder',
+                  hintText: '/Shared/TeamDocs',
+                ),
+              ),
+              const SizedBox(height: 16),
+              DropdownButtonFormField<ConflictResolution>(
+                initialValue: resolution,
+                decoration: const InputDecoration(labelText: 'Resolution'),
+                items: [
+                  for (final r in ConflictResolution.values)
+                    DropdownMenuItem(value: r, child: Text(_label(r))),
+                ],
+                onChanged: (r) => setState(() => resolution = r ?? resolution),
+              ),
+            ],
+          ),
+          actions: [
+            TextButton(
+              onPressed: () => Navigator.pop(ctx),
+              child: const Text('Cancel'),
+            ),
+            FilledButton(
+              onPressed: () => Navigator.pop(ctx, true),
+              child: const Text('Create'),
+            ),
+          ],
+        ),
+      ),
+    );
+    if (result != true) return;
+    final pattern = patternCtrl.text.trim();
+    final folder = folderCtrl.text.trim();
+    await ref
+        .read(conflictRulesProvider.notifier)
+        .add(
+          namePattern: pattern.isEmpty ? null : pattern,
+          pathPrefix: folder.isEmpty ? null : folder,
+          resolution: resolution,
+        );
+  }
+}
+
+// --- Transfers Tab ---
+
+class _TransfersTab extends ConsumerWidget {
+  const _TransfersTab();
+
+  static const _choices = [0, 128, 512, 1024, 5120, 10240];
+  static const _unusedDayChoices = [7, 14, 30, 60, 90];
+  static const _minFreeChoices = [
+    1024 * 1024 * 1024,
+    5 * 1024 * 1024 * 1024,
+    10 * 1024 * 1024 * 1024,
+    20 * 1024 * 1024 * 1024,
+    50 * 1024 * 1024 * 1024,
+  ];
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final limits = ref.watch(bandwidthLimitsProvider);
+    final notifier = ref.read(bandwidthLimitsProvider.notifier);
+    final filters = ref.watch(syncTypeFiltersProvider);
+    final filtersNotifier = ref.read(syncTypeFiltersProvider.notifier);
+    final excludePatterns = ref.watch(syncExcludePatternsProvider);
+    final optimizer = ref.watch(storageOptimizerSettingsProvider);
+    final optimizerNotifier = ref.read(
+      storageOptimizerSettingsProvider.notifier,
+    );
+    final theme = Theme.of(context);
+
+    return ListView(
+      padding: const EdgeInsets.all(16),
+      children: [
+        Text('Bandwidth', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          'Limits apply to all transfers together and take effect '
+          'immediately, including for transfers already running.',
+          style: theme.textTheme.bodySmall,
+        ),
+        const SizedBox(height: 16),
+        _limitField(
+          label: 'Upload limit',
+          value: limits.uploadKBps,
+          onChanged: (v) => notifier.set(uploadKBps: v),
+        ),
+        const SizedBox(height: 16),
+        _limitField(
+          label: 'Download limit',
+          value: limits.downloadKBps,
+          onChanged: (v) => notifier.set(downloadKBps: v),
+        ),
+        const SizedBox(height: 16),
+        SwitchListTile(
+          contentPadding: EdgeInsets.zero,
+          title: const Text('Ease off when the computer is busy'),
+          subtitle: const Text(
+            'Run fewer transfers and pause background checks while the '
+            'processor is under heavy load or running hot.',
+          ),
+          value: ref.watch(throttleOnLoadProvider),
+          onChanged: ref.read(throttleOnLoadProvider.notifier).set,
+        ),
+        const SizedBox(height: 16),
+        DropdownButtonFormField<MeteredAction>(
+          initialValue: ref.watch(meteredActionProvider),
+          decoration: const InputDecoration(
+            labelText: 'On metered or roaming connections',
+            helperText:
+                'Mobile data, phone hotspots and connections marked as '
+                'metered. Roaming always pauses unless set to sync as usual.',
+            helperMaxLines: 2,
+          ),
+          items: [
+            for (final action in MeteredAction.values)
+              DropdownMenuItem(value: action, child: Text(action.label)),
+          ],
+          onChanged: (v) {
+            if (v != null) ref.read(meteredActionProvider.notifier).set(v);
+          },
+        ),
+        const SizedBox(height: 32),
+        Text('File types', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          'Extensions such as iso or MIME types such as video/*, separated '
+          'by commas. Ignored files are marked in the file list and skipped '
+          'when uploading.',
+          style: theme.textTheme.bodySmall,
+        ),
+        const SizedBox(height: 16),
+        _typesField(
+          label: 'Only sync these types',
+          hint: 'Everything',
+          types: filters.include,
+          onSaved: (types) => filtersNotifier.set(include: types),
+        ),
+        const SizedBox(height: 16),
+        _typesField(
+          label: 'Never sync these types',
+          hint: 'Nothing',
+          types: filters.exclude,
+          onSaved: (types) => filtersNotifier.set(exclude: types),
+        ),
+        const SizedBox(height: 16),
+        TextFormField(
+          key: ValueKey('patterns:${excludePatterns.join(',')}'),
+          initialValue: excludePatterns.join(', '),
+          decoration: InputDecoration(
+            labelText: 'Also skip these names',
+            hintText: 'e.g. *.bak, .idea',
+            helperText:
+                'Always skipped: '
+                '${IgnoreRuleCache.defaultPatterns.join(', ')}. '
+                'Press Enter to save',
+            helperMaxLines: 2,
+          ),
+          onFieldSubmitted: (text) => ref
+              .read(syncExcludePatternsProvider.notifier)
+              .set(IgnoreRuleCache.parsePatterns(text)),
+        ),
+        const SizedBox(height: 16),
+        DropdownButtonFormField<LinkPolicy>(
+          initialValue: ref.watch(linkPolicyProvider),
+          decoration: const InputDecoration(
+            labelText: 'Symbolic links in uploaded folders',
+            helperText:
+                'Followed links that lead back into a folder above them '
+                'are skipped',
+          ),
+          items: [
+            for (final policy in LinkPolicy.values)
+              DropdownMenuItem(value: policy, child: Text(policy.label)),
+          ],
+          onChanged: (v) {
+            if (v != null) ref.read(linkPolicyProvider.notifier).set(v);
+          },
+        ),
+        const SizedBox(height: 32),
+        Text('Local copies', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          'Downloaded files are checked every few hours for damage, local '
+          'edits and newer versions on the server.',
+          style: theme.textTheme.bodySmall,
+        ),
+        const SizedBox(height: 16),
+        Align(
+          alignment: Alignment.centerLeft,
+          child: OutlinedButton.icon(
+            icon: const Icon(Icons.fact_check_outlined),
+            label: const Text('Verify now'),
+            onPressed: () => _verify(context, ref),
+          ),
+        ),
+        const SizedBox(height: 16),
+        SwitchListTile(
+          contentPadding: EdgeInsets.zero,
+          title: const Text('Optimize storage'),
+          subtitle: const Text(
+            'When the disk runs low, remove local copies that have not been '
+            'opened for a while. They stay on the server and every removal '
+            'is recorded in the activity log.',
+          ),
+          value: optimizer.enabled,
+          onChanged: (v) =>
+              optimizerNotifier.set(optimizer.copyWith(enabled: v)),
+        ),
+        if (optimizer.enabled) ...[
+          const SizedBox(height: 8),
+          DropdownButtonFormField<int>(
+            initialValue: optimizer.unusedDays,
+            decoration: const InputDecoration(labelText: 'Not opened for'),
+            items: [
+              for (final days in {..._unusedDayChoices, optimizer.unusedDays})
+                DropdownMenuItem(value: days, child: Text('$days days')),
+            ],
+            onChanged: (v) {
+              if (v != null) {
+                optimizerNotifier.set(optimizer.copyWith(unusedDays: v));
+              }
+            },
+          ),
+          const SizedBox(height: 16),
+          DropdownButtonFormField<int>(
+            initialValue: optimizer.minFreeBytes,
+            decoration: const InputDecoration(
+              labelText: 'Keep at least this much free',
+            ),
+            items: [
+              for (final bytes in {..._minFreeChoices, optimizer.minFreeBytes})
+                DropdownMenuItem(value: bytes, child: Text(_formatSize(bytes))),
+            ],
+            onChanged: (v) {
+              if (v != null) {
+                optimizerNotifier.set(optimizer.copyWith(minFreeBytes: v));
+              }
+            },
+          ),
+        ],
+        const SizedBox(height: 32),
+        Text('Last 7 days', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 8),
+        if (ref.watch(weeklySyncStatsProvider).value case final stats?) ...[
+          _statRow('Uploaded', _formatSize(stats.bytesUploaded)),
+          _statRow('Downloaded', _formatSize(stats.bytesDownloaded)),
+          _statRow('Files synced', '${stats.filesSynced}'),
+          _statRow('Errors', '${stats.errors}'),
+          _statRow(
+            'Average speed',
+            '${_formatSize(stats.averageBytesPerSecond.round())}/s',
+          ),
+        ],
+      ],
+    );
+  }
+
+  Widget _statRow(String label, String value) {
+    return Padding(
+      padding: const EdgeInsets.symmetric(vertical: 2),
+      child: Row(
+        children: [
+          Expanded(child: Text(label)),
+          Text(value),
+        ],
+      ),
+    );
+  }
+
+  static String _formatSize(int bytes) {
+    if (bytes < 1024) return '$bytes B';
+    if (bytes < 1024 * 1024) return '${(bytes / 1024).toStringAsFixed(1)} KB';
+    if (bytes < 1024 * 1024 * 1024) {
+      return '${(bytes / (1024 * 1024)).toStringAsFixed(1)} MB';
+    }
+    return '${(bytes / (1024 * 1024 * 1024)).toStringAsFixed(1)} GB';
+  }
+
+  Future<void> _verify(BuildContext context, WidgetRef ref) async {
+    final report = await ref.read(localCopyVerifierProvider).verifyNow();
+    if (!context.mounted || !report.isClean) return;
+    final copies = report.checked == 1
+        ? '1 local copy'
+        : '${report.checked} local copies';
+    ScaffoldMessenger.of(
+      context,
+    ).showSnackBar(SnackBar(content: Text('Checked $copies, all intact')));
+  }
+
+  Widget _typesField({
+    required String label,
+    required String hint,
+    required List<String> types,
+    required ValueChanged<List<String>> onSaved,
+  }) {
+    return TextFormField(
+      key: ValueKey('$label:${types.join(',')}'),
+      initialValue: types.join(', '),
+      decoration: InputDecoration(
+        labelText: label,
+        hintText: hint,
+        helperText: 'Press Enter to save',
+      ),
+      onFieldSubmitted: (text) => onSaved(SyncFilter.parseTypes(text)),
+    );
+  }
+
+  Widget _limitField({
+    required String label,
+    required int value,
+    required ValueChanged<int> onChanged,
+  }) {
+    return DropdownButtonFormField<int>(
+      initialValue: value,
+      decoration: InputDecoration(labelText: label),
+      items: [
+        for (final kbps in {..._choices, value})
+          DropdownMenuItem(value: kbps, child: Text(_label(kbps))),
+      ],
+      onChanged: (v) {
+        if (v != null) onChanged(v);
+      },
+    );
+  }
+
+  static String _label(int kbps) {
+    if (kbps == 0) return 'Unlimited';
+    if (kbps >= 1024 && kbps % 1024 == 0) return '${kbps ~/ 1024} MB/s';
+    return '$kbps KB/s';
+  }
+}
+
+// --- Inbox Tab ---
+
+class _InboxTab extends ConsumerWidget {
+  const _InboxTab();
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final settings = ref.watch(inboxSettingsProvider);
+    final rules = ref.watch(inboxRulesProvider).value ?? const [];
+    final items = ref.watch(inboxItemsProvider).value ?? const [];
+    final theme = Theme.of(context);
+
+    Future<void> save(InboxSettings next) =>
+        ref.read(inboxSettingsProvider.notifier).set(next);
+
+    return ListView(
+      padding: const EdgeInsets.all(16),
+      children: [
+        Row(
+          children: [
+            Expanded(child: Text('Inbox', style: theme.textTheme.titleMedium)),
+            FilledButton.icon(
+              onPressed: settings.isEnabled
+                  ? () async {
+                      await ref.read(inboxServiceProvider).run();
+                      ref.invalidate(inboxItemsProvider);
+                    }
+                  : null,
+              icon: const Icon(Icons.refresh),
+              label: const Text('Check now'),
+            ),
+          ],
+        ),
+        const SizedBox(height: 4),
+        Text(
+          'New files in the inbox folder, such as uploads to a file-drop '
+          'share, are downloaded, scanned and moved by the rules below. '
+          'Pick the folder with "Use as inbox" in the file browser.',
+          style: theme.textTheme.bodySmall,
+        ),
+        ListTile(
+          leading: const Icon(Icons.move_to_inbox_outlined),
+          title: const Text('Inbox folder'),
+          subtitle: Text(
+            settings.isEnabled ? settings.folderPath ?? '' : 'Not set',
+          ),
+          trailing: settings.isEnabled
+              ? TextButton(
+                  onPressed: () =>
+                      save(settings.copyWith(folderId: '', folderPath: '')),
+                  child: const Text('Turn off'),
+                )
+              : null,
+        ),
+        ListTile(
+          leading: const Icon(Icons.download_outlined),
+          title: const Text('Download arrivals to'),
+          subtitle: Text(
+            settings.downloadDir?.isNotEmpty ?? false
+                ? settings.downloadDir!
+                : 'Do not download',
+          ),
+          trailing: Row(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              if (settings.downloadDir?.isNotEmpty ?? false)
+                IconButton(
+                  icon: const Icon(Icons.clear),
+                  tooltip: 'Do not download',
+                  onPressed: () => save(settings.copyWith(downloadDir: '')),
+                ),
+              TextButton(
+                onPressed: () async {
+                  final dir = await FilePicker.getDirectoryPath();
+                  if (dir == null) return;
+                  await save(settings.copyWith(downloadDir: dir));
+                },
+                child: const Text('Choose'),
+              ),
+            ],
+          ),
+        ),
+        Padding(
+          padding: const EdgeInsets.symmetric(horizontal: 16),
+          child: TextFormField(
+            key: ValueKey(settings.scanCommand),
+            initialValue: settings.scanCommand,
+            decoration: const InputDecoration(
+              labelText: 'Virus scan command',
+              hintText: 'clamscan --no-summary',
+              helperText:
+                  'Run on each arrival with its path added. Files it flags '
+                  'stay in the inbox.',
+            ),
+            onFieldSubmitted: (v) => save(settings.copyWith(scanCommand: v)),
+          ),
+        ),
+        const SizedBox(height: 24),
+        Text('Sorting rules', style: theme.textTheme.titleSmall),
+        Text(
+          'Tried from top to bottom; files no rule matches stay in the '
+          'inbox. Add rules with "Sort inbox files here" on a folder.',
+          style: theme.textTheme.bodySmall,
+        ),
+        if (rules.isEmpty)
+          const Padding(
+            padding: EdgeInsets.all(16),
+            child: Text('No sorting rules'),
+          ),
+        for (final r in rules)
+          ListTile(
+            leading: const Icon(Icons.drive_file_move_outline),
+            title: Text(
+              r.namePattern?.isNotEmpty ?? false
+                  ? r.namePattern!
+                  : 'Everything',
+            ),
+            subtitle: Text('to ${r.destinationPath}'),
+            trailing: IconButton(
+              icon: const Icon(Icons.delete_outline),
+              tooltip: 'Delete',
+              onPressed: () async {
+                await ref.read(inboxServiceProvider).deleteRule(r.id);
+                ref.invalidate(inboxRulesProvider);
+              },
+            ),
+          ),
+        const SizedBox(height: 24),
+        Text('Recent arrivals', style: theme.textTheme.titleSmall),
+        if (items.isEmpty)
+          const Padding(
+            padding: EdgeInsets.all(16),
+            child: Text('Nothing has arrived yet'),
+          ),
+        for (final i in items)
+          ListTile(
+            dense: true,
+            leading: Icon(_icon(i.status)),
+            title: Text(i.name),
+            subtitle: Text(_describe(i)),
+          ),
+      ],
+    );
+  }
+
+  static IconData _icon(String status) => switch (status) {
+    'sorted' => Icons.drive_file_move_outline,
+    'flagged' => Icons.gpp_bad_outlined,
+    'failed' => Icons.error_outline,
+    _ => Icons.inbox_outlined,
+  };
+
+  static String _describe(InboxItemsTableData i) => switch (i.status) {
+    'sorted' => 'Moved to ${i.destinationPath}',
+    'flagged' => 'Flagged by the virus scanner',
+    'failed' => 'Failed: ${i.detail ?? 'unknown error'}',
+    _ => 'Left in the inbox',
+  };
+}
+
+// --- Connection Tab ---
+
+class _ConnectionTab extends ConsumerWidget {
+  const _ConnectionTab();
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final custom = ref.watch(customHeadersProvider);
+    final trust = ref.watch(tlsTrustProvider);
+    final proxy = ref.watch(proxySettingsProvider);
+    final theme = Theme.of(context);
+
+    Future<void> save(CustomHeaders next) async {
+      try {
+        await ref.read(customHeadersProvider.notifier).set(next);
+      } on ArgumentError catch (e) {
+        if (!context.mounted) return;
+        ScaffoldMessenger.of(
+          context,
+        ).showSnackBar(SnackBar(content: Text('${e.message}')));
+      }
+    }
+
+    Future<void> saveTrust(TlsTrust next) async {
+      try {
+        await ref.read(tlsTrustProvider.notifier).set(next);
+      } on ArgumentError catch (e) {
+        if (!context.mounted) return;
+        ScaffoldMessenger.of(
+          context,
+        ).showSnackBar(SnackBar(content: Text('${e.message}')));
+      }
+    }
+
+    return ListView(
+      padding: const EdgeInsets.all(16),
+      children: [
+        Text('Request headers', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          'Sent with every request to this server, for reverse proxies that '
+          'only let known clients through. Values are hidden in logs.',
+          style: theme.textTheme.bodySmall,
+        ),
+        const SizedBox(height: 16),
+        TextFormField(
+          key: ValueKey(custom.userAgent),
+          initialValue: custom.userAgent,
+          decoration: const InputDecoration(
+            labelText: 'User agent',
+            helperText: 'Leave empty for the default',
+          ),
+          validator: (v) => CustomHeaders.validateValue(v ?? ''),
+          autovalidateMode: AutovalidateMode.onUserInteraction,
+          onFieldSubmitted: (v) => save(custom.copyWith(userAgent: v.trim())),
+        ),
+        const SizedBox(height: 16),
+        for (final name in custom.headers.keys)
+          ListTile(
+            contentPadding: EdgeInsets.zero,
+            leading: const Icon(Icons.label_outline),
+            title: Text(name),
+            subtitle: const Text('••••••••'),
+            trailing: IconButton(
+              icon: const Icon(Icons.delete_outline),
+              tooltip: 'Remove',
+              onPressed: () => save(
+                custom.copyWith(headers: {...custom.headers}..remove(name)),
+              ),
+            ),
+          ),
+        Align(
+          alignment: Alignment.centerLeft,
+          child: OutlinedButton.icon(
+            icon: const Icon(Icons.add),
+            label: const Text('Add header'),
+            onPressed: () async {
+              final header = await _showAddDialog(context);
+              if (header == null) return;
+              await save(
+                custom.copyWith(
+                  headers: {...custom.headers, header.$1: header.$2},
+                ),
+              );
+            },
+          ),
+        ),
+        const Divider(height: 32),
+        Text('Proxy', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          switch (proxy.mode) {
+            ProxyMode.manual => 'Connecting through ${proxy.url}.',
+            ProxyMode.system => 'Using the proxy from the environment.',
+            ProxyMode.none => 'Connecting directly.',
+          },
+          style: theme.textTheme.bodySmall,
+        ),
+        const SizedBox(height: 8),
+        Align(
+          alignment: Alignment.centerLeft,
+          child: OutlinedButton.icon(
+            icon: const Icon(Icons.lan_outlined),
+            label: const Text('Proxy settings…'),
+            onPressed: () => ProxySettingsDialog.show(context),
+          ),
+        ),
+        const Divider(height: 32),
+        Text('Certificates', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          'For servers with a self-signed certificate or one from a private '
+          'authority. Certificates you accept when connecting are listed '
+          'here.',
+          style: theme.textTheme.bodySmall,
+        ),
+        const SizedBox(height: 8),
+        for (final fingerprint in trust.fingerprints)
+          ListTile(
+            contentPadding: EdgeInsets.zero,
+            leading: const Icon(Icons.verified_outlined),
+            title: Text(
+              UntrustedCertificate.formatFingerprint(fingerprint),
+              style: const TextStyle(fontFamily: 'monospace', fontSize: 12),
+            ),
+            subtitle: const Text('Trusted certificate (SHA-256)'),
+            trailing: IconButton(
+              icon: const Icon(Icons.delete_outline),
+              tooltip: 'Remove',
+              onPressed: () => saveTrust(
+                trust.copyWith(
+                  fingerprints: {...trust.fingerprints}..remove(fingerprint),
+                ),
+              ),
+            ),
+          ),
+        SwitchListTile(
+          contentPadding: EdgeInsets.zero,
+          title: const Text('Pin trusted certificates'),
+          subtitle: const Text(
+            'Refuse any other certificate, even one the system trusts',
+          ),
+          value: trust.pinned,
+          onChanged: trust.fingerprints.isEmpty
+              ? null
+              : (v) => saveTrust(trust.copyWith(pinned: v)),
+        ),
+        if (trust.caPem != null)
+          ListTile(
+            contentPadding: EdgeInsets.zero,
+            leading: const Icon(Icons.account_balance_outlined),
+            title: const Text('Custom certificate authority'),
+            trailing: IconButton(
+              icon: const Icon(Icons.delete_outline),
+              tooltip: 'Remove',
+              onPressed: () => saveTrust(trust.withCa(null)),
+            ),
+          ),
+        Align(
+          alignment: Alignment.centerLeft,
+          child: OutlinedButton.icon(
+            icon: const Icon(Icons.add),
+            label: Text(
+              trust.caPem == null
+                  ? 'Add certificate authority…'
+                  : 'Replace certificate authority…',
+            ),
+            onPressed: () async {
+              final pem = await _showCaDialog(context);
+              if (pem == null) return;
+              await saveTrust(trust.withCa(pem));
+            },
+          ),
+        ),
+        const Divider(height: 32),
+        Text('Move to another account', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          'Copy every file of this account to an account on another server, '
+          'or another account on this one. To copy a single folder, use its '
+          'menu in Files.',
+          style: theme.textTheme.bodySmall,
+        ),
+        const SizedBox(height: 8),
+        Align(
+          alignment: Alignment.centerLeft,
+          child: OutlinedButton.icon(
+            icon: const Icon(Icons.drive_file_move_outline),
+            label: const Text('Copy to another account…'),
+            onPressed: () => AccountMigrationDialog.show(context),
+          ),
+        ),
+      ],
+    );
+  }
+
+  Future<String?> _showCaDialog(BuildContext context) {
+    final formKey = GlobalKey<FormState>();
+    final pemCtrl = TextEditingController();
+    return showDialog<String>(
+      context: context,
+      builder: (ctx) => AlertDialog(
+        title: const Text('Certificate Authority'),
+        content: SizedBox(
+          width: 480,
+          child: Form(
+            key: formKey,
+            child: TextFormField(
+              controller: pemCtrl,
+              autofocus: true,
+              maxLines: 10,
+              style: const TextStyle(fontFamily: 'monospace', fontSize: 12),
+              decoration: const InputDecoration(
+                hintText: '-----BEGIN CERTIFICATE-----',
+                border: OutlineInputBorder(),
+              ),
+              validator: (v) => TlsTrust.validateCa(v?.trim() ?? ''),
+            ),
+          ),
+        ),
+        actions: [
+          TextButton(
+            onPressed: () => Navigator.pop(ctx),
+            child: const Text('Cancel'),
+          ),
+          FilledButton(
+            onPressed: () {
+              if (!formKey.currentState!.validate()) return;
+              Navigator.pop(ctx, pemCtrl.text.trim());
+            },
+            child: const Text('Trust'),
+          ),
+        ],
+      ),
+    );
+  }
+
+  Future<(String, String)?> _showAddDialog(BuildContext context) {
+    final formKey = GlobalKey<FormState>();
+    final nameCtrl = TextEditingController();
+    final valueCtrl = TextEditingController();
+    return showDialog<(String, String)>(
+      context: context,
+      builder: (ctx) => AlertDialog(
+        title: const Text('Add Header'),
+        content: Form(
+          key: formKey,
+          child: Column(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              TextFormField(
+                controller: nameCtrl,
+                autofocus: true,
+                decoration: const InputDecoration(
+                  labelText: 'Name',
+                  hintText: 'X-Proxy-Token',
+                ),
+                validator: (v) => CustomHeaders.validateName(v?.trim() ?? ''),
+              ),
+              TextFormField(
+                controller: valueCtrl,
+                obscureText: true,
+                decoration: const InputDecoration(labelText: 'Value'),
+                validator: (v) => CustomHeaders.validateValue(v ?? ''),
+              ),
+            ],
+          ),
+        ),
+        actions: [
+          TextButton(
+            onPressed: () => Navigator.pop(ctx),
+            child: const Text('Cancel'),
+          ),
+          FilledButton(
+            onPressed: () {
+              if (!formKey.currentState!.validate()) return;
+              Navigator.pop(ctx, (nameCtrl.text.trim(), valueCtrl.text));
+            },
+            child: const Text('Add'),
+          ),
+        ],
+      ),
+    );
+  }
+}
+
+// --- Usage Tab ---
+
+class _UsageTab extends ConsumerWidget {
+  const _UsageTab();
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final settings = ref.watch(usageStatisticsSettingsProvider);
+    final notifier = ref.read(usageStatisticsSettingsProvider.notifier);
+    final counters = ref.watch(usageCountersProvider).value ?? const [];
+    final theme = Theme.of(context);
+
+    return ListView(
+      padding: const EdgeInsets.all(16),
+      children: [
+        Text('Usage statistics', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 4),
+        Text(
+          'Counts how often features are used, on this device only. No file '
+          'names, paths or account details are recorded.',
+          style: theme.textTheme.bodySmall,
+        ),
+        SwitchListTile(
+          contentPadding: EdgeInsets.zero,
+          title: const Text('Count feature usage'),
+          value: settings.enabled,
+          onChanged: (v) => notifier.set(
+            settings.copyWith(enabled: v, upload: v && settings.upload),
+          ),
+        ),
+        SwitchListTile(
+          contentPadding: EdgeInsets.zero,
+          title: const Text('Send the counts to the server'),
+          subtitle: const Text(
+            'Once a week, to help improve the app. Check below what would '
+            'be sent.',
+          ),
+          value: settings.mayUpload,
+          onChanged: settings.enabled
+              ? (v) => notifier.set(settings.copyWith(upload: v))
+              : null,
+        ),
+        const SizedBox(height: 8),
+        Wrap(
+          spacing: 8,
+          children: [
+            OutlinedButton.icon(
+              icon: const Icon(Icons.visibility_outlined),
+              label: const Text('Show what would be sent'),
+              onPressed: () => _showReport(context, ref),
+            ),
+            TextButton(
+              onPressed: counters.isEmpty
+                  ? null
+                  : () async {
+                      await ref.read(usageStatsProvider).clear();
+                      ref.invalidate(usageCountersProvider);
+                    },
+              child: const Text('Reset counts'),
+            ),
+          ],
+        ),
+        const SizedBox(height: 32),
+        Text('Features used', style: theme.textTheme.titleMedium),
+        const SizedBox(height: 8),
+        if (counters.isEmpty)
+          Text('Nothing counted yet', style: theme.textTheme.bodySmall)
+        else
+          for (final c in counters)
+            Padding(
+              padding: const EdgeInsets.symmetric(vertical: 2),
+              child: Row(
+                children: [
+                  Expanded(child: Text(c.feature.replaceAll('_', ' '))),
+                  Text('${c.count}'),
+                ],
+              ),
+            ),
+      ],
+    );
+  }
+
+  Future<void> _showReport(BuildContext context, WidgetRef ref) async {
+    final json = await ref.read(usageStatsProvider).preview();
+    if (!context.mounted) return;
+    await showDialog<void>(
+      context: context,
+      builder: (ctx) => AlertDialog(
+        title: const Text('Usage report'),
+        content: SingleChildScrollView(
+          child: SelectableText(
+            json,
+            style: const TextStyle(fontFamily: 'monospace'),
+          ),
+        ),
+        actions: [
+          TextButton(
+            onPressed: () => Navigator.pop(ctx),
+            child: const Text('Close'),
+          ),
+        ],
+      ),
+    );
+  }
+}
@@ -0,0 +1,158 @@
+import 'dart:async';
+
+import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+import '../../../../domain/entities/share_template.dart';
+import '../../../core/services/usage_stats_service.dart';
+import '../../../../providers.dart';
+
+/// Creates a share link for an item from one of the user's templates, the
+/// default one preselected, and copies the link to the clipboard.
+class ShareLinkDialog extends ConsumerStatefulWidget {
+  final String itemId;
+  final String itemType;
+  final String itemName;
+
+  const ShareLinkDialog({
+    super.key,
+    required this.itemId,
+    required this.itemType,
+    required this.itemName,
+  });
+
+  static Future<void> show(
+    BuildContext context, {
+    required String itemId,
+    required String itemType,
+    required String itemName,
+  }) {
+    return showDialog<void>(
+      context: context,
+      builder: (_) => ShareLinkDialog(
+        itemId: itemId,
+        itemType: itemType,
+        itemName: itemName,
+      ),
+    );
+  }
+
+  @override
+  ConsumerState<ShareLinkDialog> createState() => _ShareLinkDialogState();
+}
+
+class _ShareLinkDialogState extends ConsumerState<ShareLinkDialog> {
+  final _password = TextEditingController();
+  ShareTemplate? _template;
+  bool _busy = false;
+  String? _error;
+
+  @override
+  void initState() {
+    super.initState();
+    _template = ref.read(shareTemplatesProvider).defaultTemplate;
+  }
+
+  @override
+  void dispose() {
+    _password.dispose();
+    super.dispose();
+  }
+
+  Future<void> _create() async {
+    setState(() {
+      _busy = true;
+      _error = null;
+    });
+    try {
+      final share = await ref
+          .read(shareRepositoryProvider)
+          .createShare(
+            itemId: widget.itemId,
+            itemType: widget.itemType,
+            itemName: widget.itemName,
+            password: _password.text.isEmpty ? null : _password.text,
+            template: _template,
+          );
+      ref.invalidate(sharedItemIdsProvider);
+      unawaited(ref.read(usageStatsProvider).count(UsageFeature.shareLink));
+      await Clipboard.setData(ClipboardData(text: share.url));
+      if (!mounted) return;
+      final messenger = ScaffoldMessenger.of(context);
+      Navigator.pop(context);
+      messenger.showSnackBar(
+        const SnackBar(
+          content: Text('Link copied to clipboard'),
+          behavior: SnackBarBehavior.floating,
+        ),
+      );
+    } on ArgumentError catch (e) {
+      if (!mounted) return;
+      setState(() {
+        _busy = false;
+        _error = '${e.message}';
+      });
+    } catch (e) {
+      if (!mounted) return;
+      setState(() {
+        _busy = false;
+        _error = 'Could not create the link: $e';
+      });
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final templates = ref.watch(shareTemplatesProvider).templates;
+    final template = _template;
+
+    return AlertDialog(
+      title: Text('Share "${widget.itemName}"'),
+      content: SizedBox(
+        width: 400,
+        child: Column(
+          mainAxisSize: MainAxisSize.min,
+          crossAxisAlignment: CrossAxisAlignment.start,
+          children: [
+            if (templates.isNotEmpty)
+              DropdownButtonFormField<ShareTemplate>(
+                initialValue: template,
+                decoration: InputDecoration(
+                  labelText: 'Template',
+                  helperText: template?.summary,
+                ),
+                items: [
+                  for (final t in templates)
+                    DropdownMenuItem(value: t, child: Text(t.name)),
+                ],
+                onChanged: (t) => setState(() => _template = t),
+              ),
+            const SizedBox(height: 16),
+            TextField(
+              controller: _password,
+              obscureText: true,
+              decoration: InputDecoration(
+                labelText: template?.requirePassword ?? false
+                    ? 'Password (required)'
+                    : 'Password (optional)',
+                errorText: _error,
+              ),
+              onSubmitted: (_) => _busy ? null : _create(),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.pop(context),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _busy ? null : _create,
+          child: const Text('Create link'),
+        ),
+      ],
+    );
+  }
+}
@@ -0,0 +1,214 @@
+import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+import '../../../../domain/entities/share_template.dart';
+import '../../../../providers.dart';
+
+/// Adds, edits and removes share templates and picks the default one.
+/// Changes are saved as they are made.
+class ShareTemplatesDialog extends ConsumerWidget {
+  const ShareTemplatesDialog({super.key});
+
+  static Future<void> show(BuildContext context) => showDialog<void>(
+    context: context,
+    builder: (_) => const ShareTemplatesDialog(),
+  );
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final current = ref.watch(shareTemplatesProvider);
+    final notifier = ref.read(shareTemplatesProvider.notifier);
+    final defaultName = current.defaultTemplate?.name;
+
+    Future<void> edit([ShareTemplate? template]) async {
+      final taken = {
+        for (final t in current.templates)
+          if (t != template) t.name,
+      };
+      final edited = await showDialog<ShareTemplate>(
+        context: context,
+        builder: (_) => _TemplateEditor(template: template, taken: taken),
+      );
+      if (edited == null) return;
+      final templates = [
+        for (final t in current.templates) t == template ? edited : t,
+        if (template == null) edited,
+      ];
+      await notifier.set(
+        current.copyWith(
+          templates: templates,
+          defaultName: template?.name == defaultName ? edited.name : null,
+        ),
+      );
+    }
+
+    return AlertDialog(
+      title: const Text('Share templates'),
+      content: SizedBox(
+        width: 480,
+        child: Column(
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            for (final t in current.templates)
+              ListTile(
+                contentPadding: EdgeInsets.zero,
+                leading: IconButton(
+                  icon: Icon(
+                    t.name == defaultName ? Icons.star : Icons.star_border,
+                  ),
+                  tooltip: t.name == defaultName
+                      ? 'Default for new links'
+                      : 'Make default',
+                  onPressed: () =>
+                      notifier.set(current.copyWith(defaultName: t.name)),
+                ),
+                title: Text(t.name),
+                subtitle: Text(t.summary),
+                onTap: () => edit(t),
+                trailing: IconButton(
+                  icon: const Icon(Icons.delete_outline),
+                  tooltip: 'Delete template',
+                  onPressed: () => notifier.set(
+                    current.copyWith(
+                      templates: [
+                        for (final other in current.templates)
+                          if (other != t) other,
+                      ],
+                    ),
+                  ),
+                ),
+              ),
+            Align(
+              alignment: Alignment.centerLeft,
+              child: TextButton.icon(
+                icon: const Icon(Icons.add),
+                label: const Text('Add template'),
+                onPressed: edit,
+              ),
+            ),
+            SwitchListTile(
+              contentPadding: EdgeInsets.zero,
+              title: const Text('Only for this account'),
+              subtitle: const Text(
+                'Keep these templates for the current server instead of '
+                'sharing them with every account on this device.',
+              ),
+              value: current.perAccount,
+              onChanged: (v) => notifier.set(current.copyWith(perAccount: v)),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.pop(context),
+          child: const Text('Close'),
+        ),
+      ],
+    );
+  }
+}
+
+class _TemplateEditor extends StatefulWidget {
+  final ShareTemplate? template;
+  final Set<String> taken;
+
+  const _TemplateEditor({required this.template, required this.taken});
+
+  @override
+  State<_TemplateEditor> createState() => _TemplateEditorState();
+}
+
+class _TemplateEditorState extends State<_TemplateEditor> {
+  static const _expiryChoices = <int?>[null, 1, 7, 30, 90];
+
+  late final _name = TextEditingController(text: widget.template?.name);
+  late int? _expiresInDays = widget.template?.expiresInDays;
+  late bool _requirePassword = widget.template?.requirePassword ?? false;
+  late bool _downloadOnly = widget.template?.downloadOnly ?? true;
+  String? _error;
+
+  @override
+  void dispose() {
+    _name.dispose();
+    super.dispose();
+  }
+
+  void _save() {
+    final name = _name.text.trim();
+    final error = name.isEmpty
+        ? 'Enter a name'
+        : widget.taken.contains(name)
+        ? 'A template with this name exists'
+        : null;
+    if (error != null) {
+      setState(() => _error = error);
+      return;
+    }
+    Navigator.pop(
+      context,
+      ShareTemplate(
+        name: name,
+        expiresInDays: _expiresInDays,
+        requirePassword: _requirePassword,
+        downloadOnly: _downloadOnly,
+      ),
+    );
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text(widget.template == null ? 'New template' : 'Edit template'),
+      content: Column(
+        mainAxisSize: MainAxisSize.min,
+        children: [
+          TextField(
+            controller: _name,
+            autofocus: true,
+            decoration: InputDecoration(labelText: 'Name', errorText: _error),
+          ),
+          const SizedBox(height: 16),
+          DropdownButtonFormField<int?>(
+            initialValue: _expiresInDays,
+            decoration: const InputDecoration(labelText: 'Links expire'),
+            items: [
+              for (final days in {..._expiryChoices, _expiresInDays})
+                DropdownMenuItem(
+                  value: days,
+                  child: Text(
+                    days == null
+                        ? 'Never'
+                        : days == 1
+                        ? 'After 1 day'
+                        : 'After $days days',
+                  ),
+                ),
+            ],
+            onChanged: (v) => setState(() => _expiresInDays = v),
+          ),
+          SwitchListTile(
+            contentPadding: EdgeInsets.zero,
+            title: const Text('Require a password'),
+            value: _requirePassword,
+            onChanged: (v) => setState(() => _requirePassword = v),
+          ),
+          SwitchListTile(
+            contentPadding: EdgeInsets.zero,
+            title: const Text('Download only'),
+            subtitle: const Text('Recipients cannot change the shared item'),
+            value: _downloadOnly,
+            onChanged: (v) => setState(() => _downloadOnly = v),
+          ),
+        ],
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.pop(context),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(onPressed: _save, child: const Text('Save')),
+      ],
+    );
+  }
+}
@@ -2,11 +2,13 @@ import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 
+import '../../../../core/services/linked_share_service.dart';
 import '../../../../domain/entities/share_entity.dart';
 import '../../../../providers.dart';
 import '../../shell/adaptive_shell.dart';
 import '../../widgets/dialogs.dart';
 import '../../widgets/empty_state.dart';
+import 'share_templates_dialog.dart';
 
 // --- State ---
 
@@ -48,6 +50,7 @@ class SharesNotifier extends Notifier<SharesState> {
 
   Future<void> deleteShare(String id) async {
     await ref.read(shareRepositoryProvider).deleteShare(id);
+    ref.invalidate(sharedItemIdsProvider);
     await load();
   }
 }
@@ -75,13 +78,14 @@ class _SharesPageState extends ConsumerState<SharesPage> {
   @override
   Widget build(BuildContext context) {
     final state = ref.watch(sharesProvider);
+    final linked = ref.watch(folderPairStatusProvider).value ?? const [];
 
     Widget body;
     if (state.loading) {
       body = const Center(child: CircularProgressIndicator());
     } else if (state.error != null) {
       body = Center(child: Text('Error: ${state.error}'));
-    } else if (state.shares.isEmpty) {
+    } else if (state.shares.isEmpty && linked.isEmpty) {
       body = const EmptyState(
         icon: Icons.share_outlined,
         title: 'No shares yet',
@@ -89,25 +93,32 @@ class _SharesPageState extends ConsumerState<SharesPage> {
       );
     } else {
       body = ListView.builder(
-        itemCount: state.shares.length,
-        itemBuilder: (_, i) => _ShareTile(
-          share: state.shares[i],
-          onCopyLink: () {
-            Clipboard.setData(ClipboardData(text: state.shares[i].url));
-            AppDialogs.showSnack(context, 'Link copied to clipboard');
-          },
-          onDelete: () async {
-            final confirm = await AppDialogs.showConfirm(
-              context: context,
-              title: 'Delete share?',
-              message: 'The link will no longer work. This cannot be undone.',
-              isDanger: true,
-            );
-            if (confirm) {
-              ref.read(sharesProvider.notifier).deleteShare(state.shares[i].id);
-            }
-          },
-        ),
+        itemCount: state.shares.length + (linked.isEmpty ? 0 : 1),
+        itemBuilder: (_, i) {
+          if (i == state.shares.length) {
+            return _LinkedSharesSection(shares: linked);
+          }
+          return _ShareTile(
+            share: state.shares[i],
+            onCopyLink: () {
+              Clipboard.setData(ClipboardData(text: state.shares[i].url));
+              AppDialogs.showSnack(context, 'Link copied to clipboard');
+            },
+            onDelete: () async {
+              final confirm = await AppDialogs.showConfirm(
+                context: context,
+                title: 'Delete share?',
+                message: 'The link will no longer work. This cannot be undone.',
+                isDanger: true,
+              );
+              if (confirm) {
+                ref
+                    .read(sharesProvider.notifier)
+                    .deleteShare(state.shares[i].id);
+              }
+            },
+          );
+        },
       );
     }
 
@@ -116,6 +127,16 @@ class _SharesPageState extends ConsumerState<SharesPage> {
       title: 'Shares',
       itemCount: state.shares.length,
       mobileActions: [
+        IconButton(
+          icon: const Icon(Icons.tune),
+          tooltip: 'Share templates',
+          onPressed: () => ShareTemplatesDialog.show(context),
+        ),
+        IconButton(
+          icon: const Icon(Icons.add_link),
+          tooltip: 'Add share link',
+          onPressed: () => _addLink(context),
+        ),
         IconButton(
           icon: const Icon(Icons.refresh),
           onPressed: () => ref.read(sharesProvider.notifier).load(),
@@ -124,6 +145,146 @@ class _SharesPageState extends ConsumerState<SharesPage> {
       child: body,
     );
   }
+
+  Future<void> _addLink(BuildContext context) async {
+    final linkCtrl = TextEditingController();
+    final passwordCtrl = TextEditingController();
+    final ok = await showDialog<bool>(
+      context: context,
+      builder: (ctx) => AlertDialog(
+        title: const Text('Add share link'),
+        content: Column(
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            TextField(
+              controller: linkCtrl,
+              autofocus: true,
+              decoration: const InputDecoration(
+                labelText: 'Link',
+                hintText: 'https://cloud.example.com/s/…',
+              ),
+            ),
+            TextField(
+              controller: passwordCtrl,
+              obscureText: true,
+              decoration: const InputDecoration(
+                labelText: 'Password (if the link has one)',
+              ),
+            ),
+          ],
+        ),
+        actions: [
+          TextButton(
+            onPressed: () => Navigator.pop(ctx),
+            child: const Text('Cancel'),
+          ),
+          FilledButton(
+            onPressed: () => Navigator.pop(ctx, true),
+            child: const Text('Add'),
+          ),
+        ],
+      ),
+    );
+    if (ok != true || linkCtrl.text.trim().isEmpty) return;
+    try {
+      await ref
+          .read(linkedShareServiceProvider)
+          .add(
+            linkCtrl.text,
+            password: passwordCtrl.text.isEmpty ? null : passwordCtrl.text,
+          );
+      if (context.mounted) {
+        AppDialogs.showSnack(context, 'Share link added');
+      }
+    } catch (e) {
+      if (context.mounted) {
+        AppDialogs.showSnack(context, 'Could not add link: $e');
+      }
+    }
+  }
+}
+
+class _LinkedSharesSection extends ConsumerWidget {
+  final List<FolderPairStatus> shares;
+
+  const _LinkedSharesSection({required this.shares});
+
+  @override
+  Widget build(BuildContext context, WidgetRef ref) {
+    final theme = Theme.of(context);
+    final service = ref.read(linkedShareServiceProvider);
+
+    return Column(
+      crossAxisAlignment: CrossAxisAlignment.start,
+      children: [
+        Padding(
+          padding: const EdgeInsets.fromLTRB(16, 16, 16, 4),
+          child: Text('Linked from others', style: theme.textTheme.titleSmall),
+        ),
+        for (final FolderPairStatus(:share, :state, :lastError) in shares)
+          ListTile(
+            leading: Icon(
+              share.itemType == 'folder'
+                  ? Icons.folder_shared_outlined
+                  : Icons.file_present_outlined,
+            ),
+            title: Text(
+              share.name,
+              maxLines: 1,
+              overflow: TextOverflow.ellipsis,
+            ),
+            subtitle: Text(
+              [
+                Uri.parse(share.serverUrl).host,
+                'Read-only',
+                if (state == FolderPairState.syncing)
+                  'Updating…'
+                else if (state == FolderPairState.error)
+                  lastError ?? 'Update failed'
+                else if (share.lastSyncedAt != null)
+                  'Updated ${_formatDate(share.lastSyncedAt!)}',
+              ].join(' · '),
+              maxLines: 1,
+              overflow: TextOverflow.ellipsis,
+            ),
+            trailing: Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                IconButton(
+                  icon: const Icon(Icons.sync),
+                  tooltip: 'Update local copy',
+                  onPressed: state == FolderPairState.syncing
+                      ? null
+                      : () async {
+                          try {
+                            await service.sync(share);
+                          } catch (e) {
+                            if (context.mounted) {
+                              AppDialogs.showSnack(
+                                context,
+                                'Update failed: $e',
+                              );
+                            }
+                          }
+                        },
+                ),
+                IconButton(
+                  icon: const Icon(Icons.link_off),
+                  tooltip: 'Remove link',
+                  onPressed: () => service.remove(share),
+                ),
+              ],
+            ),
+          ),
+      ],
+    );
+  }
+
+  String _formatDate(DateTime d) {
+    final local = d.toLocal();
+    return '${local.year}-${local.month.toString().padLeft(2, '0')}-'
+        '${local.day.toString().padLeft(2, '0')}';
+  }
 }
 
 class _ShareTile extends StatelessWidget {
@@ -4,6 +4,7 @@ import 'package:go_router/go_router.dart';
 
 import '../../../core/sync/sync_engine.dart';
 import '../../../providers.dart';
+import '../../widgets/sync_errors_dialog.dart';
 import '../../widgets/sync_status_indicator.dart';
 
 class DesktopSidebar extends ConsumerWidget {
@@ -124,6 +125,13 @@ class DesktopSidebar extends ConsumerWidget {
                   isSelected: currentPath == '/settings',
                   onTap: () => context.go('/settings'),
                 ),
+                _NavItem(
+                  icon: Icons.help_outline,
+                  selectedIcon: Icons.help,
+                  label: 'Help',
+                  isSelected: currentPath == '/help',
+                  onTap: () => context.go('/help'),
+                ),
               ],
             ),
           ),
@@ -137,6 +145,9 @@ class DesktopSidebar extends ConsumerWidget {
               builder: (context, _) => SyncStatusIndicator(
                 status: syncEngine.status,
                 pendingCount: syncEngine.pendingCount,
+                backoff: syncEngine.errorBudget?.backoff ?? 1,
+                failedCount: syncEngine.failedCount,
+                onShowFailed: () => SyncErrorsDialog.show(context),
               ),
             ),
           ),
@@ -1,8 +1,15 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:go_router/go_router.dart';
 
 import '../../../providers.dart';
+import '../../widgets/folder_pairs_indicator.dart';
+import '../../widgets/quota_indicator.dart';
+import '../../widgets/queued_transfers_button.dart';
+import '../../widgets/setup_scan_indicator.dart';
+import '../../widgets/sync_errors_dialog.dart';
 import '../../widgets/sync_status_indicator.dart';
+import '../../widgets/transfer_speed_indicator.dart';
 
 class DesktopStatusBar extends ConsumerWidget {
   final int? itemCount;
@@ -14,6 +21,7 @@ class DesktopStatusBar extends ConsumerWidget {
   Widget build(BuildContext context, WidgetRef ref) {
     final theme = Theme.of(context);
     final syncEngine = ref.watch(syncEngineProvider);
+    final lease = ref.watch(dbLeaseProvider);
 
     return Container(
       height: 28,
@@ -36,11 +44,47 @@ class DesktopStatusBar extends ConsumerWidget {
               ),
             ),
           const Spacer(),
+          ListenableBuilder(
+            listenable: lease,
+            builder: (context, _) {
+              if (lease.foreignOwner == null && !lease.foreignWritesDetected) {
+                return const SizedBox.shrink();
+              }
+              return Padding(
+                padding: const EdgeInsets.only(right: 12),
+                child: Tooltip(
+                  message: lease.foreignOwner != null
+                      ? 'Another process (pid ${lease.foreignOwner!.pid}) '
+                            'took over the local database'
+                      : 'Another process is writing to the local database',
+                  child: Icon(
+                    Icons.warning_amber,
+                    size: 16,
+                    color: theme.colorScheme.error,
+                  ),
+                ),
+              );
+            },
+          ),
+          FolderPairsIndicator(
+            pairs: ref.watch(folderPairStatusProvider).value ?? const [],
+            onOpen: () => context.go('/shares'),
+          ),
+          const SizedBox(width: 12),
+          SetupScanIndicator(scan: ref.watch(setupScanProvider)),
+          QuotaIndicator(service: ref.watch(storageQuotaProvider)),
+          QueuedTransfersButton(scheduler: ref.watch(uploadSchedulerProvider)),
+          const SizedBox(width: 12),
+          TransferSpeedIndicator(monitor: ref.watch(transferMonitorProvider)),
+          const SizedBox(width: 12),
           ListenableBuilder(
             listenable: syncEngine,
             builder: (context, _) => SyncStatusIndicator(
               status: syncEngine.status,
               pendingCount: syncEngine.pendingCount,
+              backoff: syncEngine.errorBudget?.backoff ?? 1,
+              failedCount: syncEngine.failedCount,
+              onShowFailed: () => SyncErrorsDialog.show(context),
             ),
           ),
         ],
@@ -10,6 +10,7 @@ class DesktopToolbar extends ConsumerWidget {
   final VoidCallback? onRefresh;
   final VoidCallback? onNewFolder;
   final VoidCallback? onUpload;
+  final VoidCallback? onPublishFolder;
   final ValueChanged<String>? onSearch;
 
   const DesktopToolbar({
@@ -18,6 +19,7 @@ class DesktopToolbar extends ConsumerWidget {
     this.onRefresh,
     this.onNewFolder,
     this.onUpload,
+    this.onPublishFolder,
     this.onSearch,
   });
 
@@ -69,6 +71,12 @@ class DesktopToolbar extends ConsumerWidget {
             tooltip: 'Upload files',
             onPressed: onUpload,
           ),
+          if (onPublishFolder != null)
+            IconButton(
+              icon: const Icon(Icons.drive_folder_upload_outlined, size: 20),
+              tooltip: 'Publish folder (all at once)',
+              onPressed: onPublishFolder,
+            ),
           IconButton(
             icon: const Icon(Icons.refresh, size: 20),
             tooltip: 'Refresh',
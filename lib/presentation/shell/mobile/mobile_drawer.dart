@@ -93,6 +93,14 @@ class MobileDrawer extends ConsumerWidget {
                       context.go('/settings');
                     },
                   ),
+                  ListTile(
+                    leading: const Icon(Icons.help_outline),
+                    title: const Text('Help'),
+                    onTap: () {
+                      Navigator.pop(context);
+                      context.go('/help');
+                    },
+                  ),
                 ],
               ),
             ),
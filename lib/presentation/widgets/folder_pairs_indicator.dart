@@ -0,0 +1,92 @@
+import 'package:flutter/material.dart';
+
+import '../../core/services/linked_share_service.dart';
+import '../../core/theme/app_colors.dart';
+
+/// Status-bar summary of linked shares. Clicking it lists each one with
+/// its state and when it was last brought up to date.
+class FolderPairsIndicator extends StatelessWidget {
+  final List<FolderPairStatus> pairs;
+  final VoidCallback? onOpen;
+
+  const FolderPairsIndicator({super.key, required this.pairs, this.onOpen});
+
+  @override
+  Widget build(BuildContext context) {
+    if (pairs.isEmpty) return const SizedBox.shrink();
+
+    final theme = Theme.of(context);
+    final failing = pairs.where((p) => p.state == FolderPairState.error);
+    final color = failing.isEmpty ? theme.hintColor : AppColors.syncError;
+
+    return PopupMenuButton<void>(
+      tooltip: 'Linked shares',
+      itemBuilder: (_) => [
+        for (final pair in pairs)
+          PopupMenuItem<void>(
+            onTap: onOpen,
+            child: ListTile(
+              dense: true,
+              contentPadding: EdgeInsets.zero,
+              leading: Icon(_iconFor(pair.state), size: 18),
+              title: Text(
+                pair.name,
+                maxLines: 1,
+                overflow: TextOverflow.ellipsis,
+              ),
+              subtitle: Text(
+                _describe(pair),
+                maxLines: 1,
+                overflow: TextOverflow.ellipsis,
+              ),
+            ),
+          ),
+      ],
+      child: Row(
+        mainAxisSize: MainAxisSize.min,
+        children: [
+          Icon(Icons.folder_shared_outlined, size: 16, color: color),
+          const SizedBox(width: 4),
+          Text(
+            failing.isEmpty
+                ? '${pairs.length} linked'
+                : '${failing.length} of ${pairs.length} failing',
+            style: theme.textTheme.bodySmall?.copyWith(color: color),
+          ),
+        ],
+      ),
+    );
+  }
+
+  IconData _iconFor(FolderPairState state) => switch (state) {
+    FolderPairState.idle => Icons.cloud_done_outlined,
+    FolderPairState.syncing => Icons.sync,
+    FolderPairState.paused => Icons.pause_circle_outline,
+    FolderPairState.error => Icons.error_outline,
+  };
+
+  String _describe(FolderPairStatus pair) {
+    switch (pair.state) {
+      case FolderPairState.syncing:
+        return 'Updating…';
+      case FolderPairState.error:
+        return pair.lastError ?? 'Update failed';
+      case FolderPairState.paused:
+      case FolderPairState.idle:
+        final last = pair.lastSyncedAt;
+        final since = last == null ? 'never' : _ago(last);
+        final pending = pair.pendingCount > 0 ? ' · pending' : '';
+        return pair.state == FolderPairState.paused
+            ? 'Paused (offline) · last updated $since'
+            : 'Updated $since$pending';
+    }
+  }
+
+  String _ago(DateTime t) {
+    final d = DateTime.now().difference(t);
+    if (d.inMinutes < 1) return 'just now';
+    if (d.inHours < 1) return '${d.inMinutes}m ago';
+    if (d.inDays < 1) return '${d.inHours}h ago';
+    return '${d.inDays}d ago';
+  }
+}
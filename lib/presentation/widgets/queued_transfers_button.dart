@@ -0,0 +1,58 @@
+import 'package:flutter/material.dart';
+
+import '../../core/sync/transfer_scheduler.dart';
+
+/// "4 queued" label for the desktop status bar. Clicking it lists the
+/// waiting transfers; picking one moves it to the front of the queue.
+class QueuedTransfersButton extends StatelessWidget {
+  final TransferScheduler scheduler;
+
+  const QueuedTransfersButton({super.key, required this.scheduler});
+
+  @override
+  Widget build(BuildContext context) {
+    return ListenableBuilder(
+      listenable: scheduler,
+      builder: (context, _) {
+        final queued = scheduler.queued;
+        if (queued.isEmpty) return const SizedBox.shrink();
+
+        final theme = Theme.of(context);
+        return PopupMenuButton<String>(
+          tooltip: 'Queued uploads',
+          onSelected: scheduler.boost,
+          itemBuilder: (_) => [
+            for (final t in queued)
+              PopupMenuItem<String>(
+                value: t.id,
+                enabled: !t.boosted,
+                child: Row(
+                  children: [
+                    Icon(t.boosted ? Icons.bolt : Icons.schedule, size: 18),
+                    const SizedBox(width: 8),
+                    Expanded(
+                      child: Text(
+                        t.name,
+                        maxLines: 1,
+                        overflow: TextOverflow.ellipsis,
+                      ),
+                    ),
+                    if (!t.boosted) ...[
+                      const SizedBox(width: 12),
+                      Text('Upload now', style: theme.textTheme.labelSmall),
+                    ],
+                  ],
+                ),
+              ),
+          ],
+          child: Text(
+            '${queued.length} queued',
+            style: theme.textTheme.bodySmall?.copyWith(
+              color: theme.hintColor,
+            ),
+          ),
+        );
+      },
+    );
+  }
+}
@@ -0,0 +1,70 @@
+import 'package:flutter/material.dart';
+
+import '../../core/sync/storage_quota.dart';
+
+/// Server storage used for the desktop status bar, turning to the error
+/// colour once the account is nearly full. Renders nothing until the
+/// quota is known.
+class QuotaIndicator extends StatelessWidget {
+  final StorageQuotaService service;
+
+  const QuotaIndicator({super.key, required this.service});
+
+  static const _warnAt = 0.9;
+
+  @override
+  Widget build(BuildContext context) {
+    return ListenableBuilder(
+      listenable: service,
+      builder: (context, _) {
+        final quota = service.quota;
+        if (quota == null) return const SizedBox.shrink();
+
+        final theme = Theme.of(context);
+        final fraction = quota.fraction;
+        final warn = quota.isFull || (fraction ?? 0) >= _warnAt;
+        final color = warn ? theme.colorScheme.error : theme.hintColor;
+        final total = quota.totalBytes;
+        return Padding(
+          padding: const EdgeInsets.only(right: 12),
+          child: Tooltip(
+            message: quota.isFull
+                ? 'Server storage is full; uploads are stopped'
+                : total == null
+                ? 'No storage limit on the server'
+                : '${_format(quota.availableBytes!)} left on the server',
+            child: Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                Icon(Icons.cloud_outlined, size: 14, color: color),
+                const SizedBox(width: 4),
+                if (fraction != null) ...[
+                  SizedBox(
+                    width: 48,
+                    child: LinearProgressIndicator(
+                      value: fraction,
+                      color: warn ? theme.colorScheme.error : null,
+                    ),
+                  ),
+                  const SizedBox(width: 6),
+                ],
+                Text(
+                  total == null
+                      ? _format(quota.usedBytes)
+                      : '${_format(quota.usedBytes)} of ${_format(total)}',
+                  style: theme.textTheme.bodySmall?.copyWith(color: color),
+                ),
+              ],
+            ),
+          ),
+        );
+      },
+    );
+  }
+
+  static String _format(int bytes) {
+    const gb = 1024 * 1024 * 1024;
+    if (bytes >= gb) return '${(bytes / gb).toStringAsFixed(1)} GB';
+    return '${(bytes / (1024 * 1024)).toStringAsFixed(0)} MB';
+  }
+}
@@ -0,0 +1,54 @@
+import 'package:flutter/material.dart';
+
+import '../../core/sync/setup_scan.dart';
+
+/// Progress bar for the desktop status bar while a newly added account is
+/// listed for the first time. Renders nothing once the listing is done.
+class SetupScanIndicator extends StatelessWidget {
+  final SetupScan scan;
+
+  const SetupScanIndicator({super.key, required this.scan});
+
+  @override
+  Widget build(BuildContext context) {
+    return ListenableBuilder(
+      listenable: scan,
+      builder: (context, _) {
+        final progress = scan.progress;
+        if (!scan.isRunning || progress == null || progress.done) {
+          return const SizedBox.shrink();
+        }
+
+        final theme = Theme.of(context);
+        final estimate = progress.estimatedTotalFiles;
+        return Padding(
+          padding: const EdgeInsets.only(right: 12),
+          child: Tooltip(
+            message: estimate == null
+                ? 'Listing your files for the first time'
+                : 'Listing your files for the first time, about $estimate '
+                      'in total',
+            child: Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                SizedBox(
+                  width: 80,
+                  child: LinearProgressIndicator(value: progress.fraction),
+                ),
+                const SizedBox(width: 8),
+                Text(
+                  '${progress.filesCounted} files in '
+                  '${progress.foldersScanned} of '
+                  '${progress.foldersDiscovered} folders',
+                  style: theme.textTheme.bodySmall?.copyWith(
+                    color: theme.hintColor,
+                  ),
+                ),
+              ],
+            ),
+          ),
+        );
+      },
+    );
+  }
+}
@@ -0,0 +1,128 @@
+import 'package:flutter/material.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+
+import '../../core/database/app_database.dart';
+import '../../core/sync/sync_errors.dart';
+import '../../providers.dart';
+
+/// Lists the items whose changes could not be synced, with their last
+/// error, and lets the user retry them or stop syncing an item.
+class SyncErrorsDialog extends ConsumerStatefulWidget {
+  const SyncErrorsDialog({super.key});
+
+  static Future<void> show(BuildContext context) => showDialog<void>(
+    context: context,
+    builder: (_) => const SyncErrorsDialog(),
+  );
+
+  @override
+  ConsumerState<SyncErrorsDialog> createState() => _SyncErrorsDialogState();
+}
+
+class _SyncErrorsDialogState extends ConsumerState<SyncErrorsDialog> {
+  late Future<List<(SyncErrorsTableData, String)>> _errors = _load();
+  bool _busy = false;
+
+  Future<List<(SyncErrorsTableData, String)>> _load() async {
+    final db = ref.read(databaseProvider);
+    final errors = await ref.read(syncErrorCenterProvider).failed();
+    return [
+      for (final e in errors)
+        (
+          e,
+          (e.itemType == 'folder'
+                  ? (await db.getFolderById(e.itemId))?.path
+                  : (await db.getFileById(e.itemId))?.path) ??
+              e.itemId,
+        ),
+    ];
+  }
+
+  Future<void> _run(Future<void> Function() action) async {
+    setState(() => _busy = true);
+    try {
+      await action();
+    } finally {
+      if (mounted) {
+        setState(() {
+          _busy = false;
+          _errors = _load();
+        });
+      }
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final center = ref.read(syncErrorCenterProvider);
+    final theme = Theme.of(context);
+
+    return AlertDialog(
+      title: const Text('Sync errors'),
+      content: SizedBox(
+        width: 520,
+        child: FutureBuilder(
+          future: _errors,
+          builder: (context, snapshot) {
+            final errors = snapshot.data;
+            if (errors == null) {
+              return const SizedBox(
+                height: 80,
+                child: Center(child: CircularProgressIndicator()),
+              );
+            }
+            if (errors.isEmpty) return const Text('Everything is in sync.');
+            return ListView(
+              shrinkWrap: true,
+              children: [
+                for (final (e, path) in errors)
+                  ListTile(
+                    contentPadding: EdgeInsets.zero,
+                    title: Text(path, overflow: TextOverflow.ellipsis),
+                    subtitle: Text(
+                      '${SyncErrorCategory.parse(e.category).label} · '
+                      '${e.operationType} · tried ${e.retryCount}x\n'
+                      '${e.message}',
+                      maxLines: 3,
+                      overflow: TextOverflow.ellipsis,
+                      style: theme.textTheme.bodySmall,
+                    ),
+                    isThreeLine: true,
+                    trailing: Row(
+                      mainAxisSize: MainAxisSize.min,
+                      children: [
+                        IconButton(
+                          icon: const Icon(Icons.refresh),
+                          tooltip: 'Retry',
+                          onPressed: _busy
+                              ? null
+                              : () => _run(() => center.retry(e.itemId)),
+                        ),
+                        IconButton(
+                          icon: const Icon(Icons.block),
+                          tooltip: 'Stop syncing this item',
+                          onPressed: _busy
+                              ? null
+                              : () => _run(() => center.ignore(e.itemId)),
+                        ),
+                      ],
+                    ),
+                  ),
+              ],
+            );
+          },
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.pop(context),
+          child: const Text('Close'),
+        ),
+        FilledButton(
+          onPressed: _busy ? null : () => _run(center.retryAll),
+          child: const Text('Retry all'),
+        ),
+      ],
+    );
+  }
+}
@@ -7,41 +7,72 @@ class SyncStatusIndicator extends StatelessWidget {
   final SyncStatus status;
   final int pendingCount;
 
+  /// Above 1 while sync runs less often because requests keep failing.
+  final int backoff;
+
+  /// Items whose changes failed; see `SyncErrorCenter`.
+  final int failedCount;
+
+  /// Opens the failed items, if any.
+  final VoidCallback? onShowFailed;
+
   const SyncStatusIndicator({
     super.key,
     required this.status,
     this.pendingCount = 0,
+    this.backoff = 1,
+    this.failedCount = 0,
+    this.onShowFailed,
   });
 
   @override
   Widget build(BuildContext context) {
     final (icon, color, label) = _resolve();
+    var message = pendingCount > 0 ? '$label ($pendingCount pending)' : label;
+    if (failedCount > 0) {
+      message +=
+          '\n${failedCount == 1 ? '1 item' : '$failedCount items'} could not '
+          'be synced. Click for details.';
+    }
+    if (backoff > 1) {
+      message +=
+          '\nMany requests to the server are failing, so sync runs '
+          '${backoff}x less often until they succeed again.';
+    }
+    final row = Row(
+      mainAxisSize: MainAxisSize.min,
+      children: [
+        if (status == SyncStatus.syncing)
+          SizedBox(
+            width: 16,
+            height: 16,
+            child: CircularProgressIndicator(strokeWidth: 2, color: color),
+          )
+        else
+          Icon(icon, size: 16, color: color),
+        const SizedBox(width: 4),
+        Text(
+          label,
+          style: Theme.of(context).textTheme.bodySmall!.copyWith(color: color),
+        ),
+      ],
+    );
     return Tooltip(
-      message: pendingCount > 0 ? '$label ($pendingCount pending)' : label,
-      child: Row(
-        mainAxisSize: MainAxisSize.min,
-        children: [
-          if (status == SyncStatus.syncing)
-            SizedBox(
-              width: 16,
-              height: 16,
-              child: CircularProgressIndicator(strokeWidth: 2, color: color),
-            )
-          else
-            Icon(icon, size: 16, color: color),
-          const SizedBox(width: 4),
-          Text(
-            label,
-            style: Theme.of(
-              context,
-            ).textTheme.bodySmall!.copyWith(color: color),
-          ),
-        ],
-      ),
+      message: message,
+      child: failedCount > 0 && onShowFailed != null
+          ? InkWell(onTap: onShowFailed, child: row)
+          : row,
     );
   }
 
   (IconData, Color, String) _resolve() {
+    final settled = status == SyncStatus.idle || status == SyncStatus.error;
+    if (backoff > 1 && settled) {
+      return (Icons.hourglass_bottom, AppColors.syncError, 'Sync slowed');
+    }
+    if (failedCount > 0 && settled) {
+      return (Icons.error_outline, AppColors.syncError, '$failedCount failed');
+    }
     return switch (status) {
       SyncStatus.idle => (Icons.cloud_done, AppColors.syncIdle, 'Synced'),
       SyncStatus.syncing => (Icons.sync, AppColors.syncing, 'Syncing…'),
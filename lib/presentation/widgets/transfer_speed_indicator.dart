@@ -0,0 +1,85 @@
+import 'package:flutter/material.dart';
+
+import '../../core/sync/transfer_monitor.dart';
+
+/// Compact "1.2 MB/s · 3m left" label for the desktop status bar, or a
+/// note about uploads waiting for files other applications have open.
+/// Renders nothing while no transfer is active or waiting.
+class TransferSpeedIndicator extends StatelessWidget {
+  final TransferMonitor monitor;
+
+  const TransferSpeedIndicator({super.key, required this.monitor});
+
+  @override
+  Widget build(BuildContext context) {
+    return ListenableBuilder(
+      listenable: monitor,
+      builder: (context, _) {
+        final active = monitor.activeCount;
+        final waiting = monitor.waitingCount;
+        final theme = Theme.of(context);
+        if (active == 0 && waiting > 0) {
+          return Tooltip(
+            message:
+                'Another application has the file open. The upload starts '
+                'once it is closed.',
+            child: Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                Icon(Icons.lock_clock, size: 16, color: theme.hintColor),
+                const SizedBox(width: 4),
+                Text(
+                  waiting == 1
+                      ? 'Waiting for a file to be released'
+                      : 'Waiting for $waiting files to be released',
+                  style: theme.textTheme.bodySmall?.copyWith(
+                    color: theme.hintColor,
+                  ),
+                ),
+              ],
+            ),
+          );
+        }
+        if (active == 0) return const SizedBox.shrink();
+
+        final eta = monitor.eta;
+        final speed = '${_formatBytes(monitor.bytesPerSecond.round())}/s';
+        final label = eta == null ? speed : '$speed · ${_formatEta(eta)} left';
+
+        return Tooltip(
+          message: active == 1
+              ? '1 transfer in progress'
+              : '$active transfers in progress',
+          child: Row(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              Icon(Icons.swap_vert, size: 16, color: theme.hintColor),
+              const SizedBox(width: 4),
+              Text(
+                label,
+                style: theme.textTheme.bodySmall?.copyWith(
+                  color: theme.hintColor,
+                ),
+              ),
+            ],
+          ),
+        );
+      },
+    );
+  }
+
+  String _formatBytes(int bytes) {
+    if (bytes < 1024) return '$bytes B';
+    if (bytes < 1024 * 1024) return '${(bytes / 1024).toStringAsFixed(1)} KB';
+    if (bytes < 1024 * 1024 * 1024) {
+      return '${(bytes / (1024 * 1024)).toStringAsFixed(1)} MB';
+    }
+    return '${(bytes / (1024 * 1024 * 1024)).toStringAsFixed(1)} GB';
+  }
+
+  String _formatEta(Duration eta) {
+    if (eta.inHours > 0) return '${eta.inHours}h ${eta.inMinutes % 60}m';
+    if (eta.inMinutes > 0) return '${eta.inMinutes}m ${eta.inSeconds % 60}s';
+    return '${eta.inSeconds}s';
+  }
+}
@@ -0,0 +1,71 @@
+import 'package:flutter/material.dart';
+
+import '../../core/network/tls_trust.dart';
+
+/// Shows a certificate nothing trusts and asks whether to trust it for
+/// this account.
+class TrustCertificateDialog extends StatelessWidget {
+  final UntrustedCertificate certificate;
+
+  const TrustCertificateDialog({super.key, required this.certificate});
+
+  /// Whether the user chose to trust [certificate].
+  static Future<bool> show(
+    BuildContext context,
+    UntrustedCertificate certificate,
+  ) async =>
+      await showDialog<bool>(
+        context: context,
+        builder: (_) => TrustCertificateDialog(certificate: certificate),
+      ) ??
+      false;
+
+  @override
+  Widget build(BuildContext context) {
+    final theme = Theme.of(context);
+    final c = certificate;
+    return AlertDialog(
+      icon: const Icon(Icons.gpp_maybe_outlined),
+      title: const Text('Untrusted certificate'),
+      content: SizedBox(
+        width: 420,
+        child: Column(
+          mainAxisSize: MainAxisSize.min,
+          crossAxisAlignment: CrossAxisAlignment.start,
+          children: [
+            Text(
+              '${c.host} presented a certificate that is self-signed or '
+              'from an unknown authority. Only trust it if the fingerprint '
+              'matches the one your server administrator gave you.',
+            ),
+            const SizedBox(height: 16),
+            Text('Issued to', style: theme.textTheme.labelMedium),
+            Text(c.subject),
+            const SizedBox(height: 8),
+            Text('Issued by', style: theme.textTheme.labelMedium),
+            Text(c.issuer),
+            const SizedBox(height: 8),
+            Text('Valid until', style: theme.textTheme.labelMedium),
+            Text('${c.validUntil.toLocal()}'.split('.').first),
+            const SizedBox(height: 8),
+            Text('SHA-256 fingerprint', style: theme.textTheme.labelMedium),
+            SelectableText(
+              c.displayFingerprint,
+              style: const TextStyle(fontFamily: 'monospace'),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.pop(context, false),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: () => Navigator.pop(context, true),
+          child: const Text('Trust'),
+        ),
+      ],
+    );
+  }
+}
@@ -1,13 +1,75 @@
+import 'dart:async';
+import 'dart:io';
+
 import 'package:dio/dio.dart';
+import 'package:drift/drift.dart' show Value;
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:path_provider/path_provider.dart';
 
 import 'core/auth/secure_storage.dart';
+import 'core/auth/token_refresher.dart';
 import 'core/config/app_config.dart';
+import 'core/config/config_validator.dart';
 import 'core/database/app_database.dart';
+import 'core/database/db_lease_service.dart';
+import 'core/error/exceptions.dart';
 import 'core/network/api_client.dart';
+import 'core/network/api_endpoints.dart';
 import 'core/network/connectivity_service.dart';
+import 'core/network/interceptors/custom_headers_interceptor.dart';
+import 'core/network/proxy_settings.dart';
+import 'core/network/rate_limiter.dart';
+import 'core/network/server_client_factory.dart';
+import 'core/network/tls_trust.dart';
+import 'core/platform/disk_space.dart';
+import 'core/platform/file_modes.dart';
+import 'core/platform/platform_info.dart';
+import 'core/platform/temp_files.dart';
+import 'core/services/audit_export_service.dart';
+import 'core/services/bundle_service.dart';
+import 'core/services/bulk_upload_service.dart';
+import 'core/services/control_channel.dart';
+import 'core/services/checksum_service.dart';
+import 'core/services/chunked_upload_service.dart';
+import 'core/services/diagnostics_service.dart';
+import 'core/services/folder_publish_service.dart';
+import 'core/services/inbox_service.dart';
+import 'core/services/linked_share_service.dart';
+import 'core/services/login_flow_service.dart';
+import 'core/services/operation_report_service.dart';
+import 'core/services/resumable_download_service.dart';
+import 'core/services/shutdown_service.dart';
+import 'core/services/thumbnail_service.dart';
+import 'core/services/troubleshooting_service.dart';
+import 'core/services/upload_session_cleanup_service.dart';
+import 'core/services/usage_stats_service.dart';
+import 'core/services/webdav_changes_service.dart';
+import 'core/services/webdav_properties_service.dart';
+import 'core/sync/case_conflicts.dart';
+import 'core/sync/conflict_resolver.dart';
+import 'core/sync/folder_scan_scheduler.dart';
+import 'core/sync/folder_subscriptions.dart';
+import 'core/sync/ignore_rules.dart';
+import 'core/sync/link_policy.dart';
+import 'core/sync/metered_network.dart';
+import 'core/sync/local_copy_verifier.dart';
+import 'core/sync/resource_throttle.dart';
+import 'core/sync/propagation_policy.dart';
+import 'core/sync/selective_sync_service.dart';
+import 'core/sync/setup_scan.dart';
+import 'core/sync/sync_direction.dart';
 import 'core/sync/sync_engine.dart';
+import 'core/sync/sync_errors.dart';
+import 'core/sync/sync_events.dart';
+import 'core/sync/sync_filter.dart';
+import 'core/sync/storage_optimizer.dart';
+import 'core/sync/storage_quota.dart';
+import 'core/sync/sync_stats.dart';
+import 'core/sync/transfer_controller.dart';
+import 'core/sync/transfer_ledger_verifier.dart';
+import 'core/sync/transfer_monitor.dart';
+import 'core/sync/transfer_scheduler.dart';
+import 'core/sync/upload_dedup.dart';
 import 'data/datasources/remote/admin_remote_datasource.dart';
 import 'data/datasources/remote/app_password_remote_datasource.dart';
 import 'data/datasources/remote/auth_remote_datasource.dart';
@@ -19,6 +81,7 @@ import 'data/datasources/remote/favorites_remote_datasource.dart';
 import 'data/datasources/remote/file_remote_datasource.dart';
 import 'data/datasources/remote/folder_remote_datasource.dart';
 import 'data/datasources/remote/i18n_remote_datasource.dart';
+import 'data/datasources/remote/login_flow_remote_datasource.dart';
 import 'data/datasources/remote/oidc_remote_datasource.dart';
 import 'data/datasources/remote/playlist_remote_datasource.dart';
 import 'data/datasources/remote/recent_remote_datasource.dart';
@@ -27,22 +90,28 @@ import 'data/datasources/remote/share_remote_datasource.dart';
 import 'data/datasources/remote/photos_remote_datasource.dart';
 import 'data/datasources/remote/public_share_remote_datasource.dart';
 import 'data/datasources/remote/trash_remote_datasource.dart';
+import 'data/datasources/remote/version_remote_datasource.dart';
 import 'data/repositories/auth_repository_impl.dart';
 import 'data/repositories/favorites_repository_impl.dart';
 import 'data/repositories/file_repository_impl.dart';
+import 'data/repositories/file_version_repository_impl.dart';
 import 'data/repositories/folder_repository_impl.dart';
 import 'data/repositories/photos_repository_impl.dart';
 import 'data/repositories/recent_repository_impl.dart';
 import 'data/repositories/search_repository_impl.dart';
+import 'data/repositories/session_repository_impl.dart';
 import 'data/repositories/share_repository_impl.dart';
 import 'data/repositories/trash_repository_impl.dart';
+import 'domain/entities/share_template.dart';
 import 'domain/repositories/auth_repository.dart';
 import 'domain/repositories/favorites_repository.dart';
 import 'domain/repositories/file_repository.dart';
+import 'domain/repositories/file_version_repository.dart';
 import 'domain/repositories/folder_repository.dart';
 import 'domain/repositories/photos_repository.dart';
 import 'domain/repositories/recent_repository.dart';
 import 'domain/repositories/search_repository.dart';
+import 'domain/repositories/session_repository.dart';
 import 'domain/repositories/share_repository.dart';
 import 'domain/repositories/trash_repository.dart';
 
@@ -68,12 +137,17 @@ class AppConfigNotifier extends Notifier<AppConfig> {
   }
 
   /// Update the server URL, persist it, and rebuild dependent providers.
+  /// Throws [InvalidConfigException] without saving anything if the
+  /// resulting configuration is invalid.
   Future<void> setServerUrl(String url) async {
     final normalized = url.trimRight().endsWith('/')
         ? url.trimRight().substring(0, url.trimRight().length - 1)
         : url.trimRight();
+    final next = state.copyWith(serverUrl: normalized);
+    final errors = ConfigValidator.validate(next);
+    if (errors.isNotEmpty) throw InvalidConfigException(errors);
     await _secureStorage.saveServerUrl(normalized);
-    state = state.copyWith(serverUrl: normalized);
+    state = next;
   }
 }
 
@@ -81,16 +155,311 @@ final appConfigProvider = NotifierProvider<AppConfigNotifier, AppConfig>(
   AppConfigNotifier.new,
 );
 
+typedef BandwidthLimits = ({int uploadKBps, int downloadKBps});
+
+/// Upload and download limits in KB/s, 0 meaning unlimited. Changes apply
+/// to transfers already running.
+class BandwidthLimitsNotifier extends Notifier<BandwidthLimits> {
+  @override
+  BandwidthLimits build() => (uploadKBps: 0, downloadKBps: 0);
+
+  /// Load the saved limits. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getBandwidthLimits();
+  }
+
+  Future<void> set({int? uploadKBps, int? downloadKBps}) async {
+    final next = (
+      uploadKBps: uploadKBps ?? state.uploadKBps,
+      downloadKBps: downloadKBps ?? state.downloadKBps,
+    );
+    await ref
+        .read(secureStorageProvider)
+        .saveBandwidthLimits(
+          uploadKBps: next.uploadKBps,
+          downloadKBps: next.downloadKBps,
+        );
+    state = next;
+  }
+}
+
+final bandwidthLimitsProvider =
+    NotifierProvider<BandwidthLimitsNotifier, BandwidthLimits>(
+      BandwidthLimitsNotifier.new,
+    );
+
+/// Shared by every upload, so the limit holds across concurrent transfers.
+final uploadLimiterProvider = Provider<RateLimiter>((ref) {
+  final limiter = RateLimiter();
+  ref.listen(
+    bandwidthLimitsProvider,
+    (_, limits) => limiter.bytesPerSecond = limits.uploadKBps * 1024,
+    fireImmediately: true,
+  );
+  return limiter;
+});
+
+final downloadLimiterProvider = Provider<RateLimiter>((ref) {
+  final limiter = RateLimiter();
+  ref.listen(
+    bandwidthLimitsProvider,
+    (_, limits) => limiter.bytesPerSecond = limits.downloadKBps * 1024,
+    fireImmediately: true,
+  );
+  return limiter;
+});
+
+/// Extra headers and user agent for the current server's account. Follows
+/// the server URL: each server keeps its own.
+class CustomHeadersNotifier extends Notifier<CustomHeaders> {
+  @override
+  CustomHeaders build() {
+    ref.listen(
+      appConfigProvider.select((c) => c.serverUrl),
+      (_, _) => load(),
+    );
+    return CustomHeaders.none;
+  }
+
+  /// Load the saved headers. Call once at startup.
+  Future<void> load() async {
+    final serverUrl = ref.read(appConfigProvider).serverUrl;
+    state = serverUrl.isEmpty
+        ? CustomHeaders.none
+        : await ref.read(secureStorageProvider).getCustomHeaders(serverUrl);
+  }
+
+  /// Throws [ArgumentError] for a header that fails validation.
+  Future<void> set(CustomHeaders headers) async {
+    for (final MapEntry(:key, :value) in headers.headers.entries) {
+      final error =
+          CustomHeaders.validateName(key) ?? CustomHeaders.validateValue(value);
+      if (error != null) throw ArgumentError.value(key, 'header', error);
+    }
+    final agentError = CustomHeaders.validateValue(headers.userAgent ?? '');
+    if (agentError != null) {
+      throw ArgumentError.value(headers.userAgent, 'userAgent', agentError);
+    }
+    final serverUrl = ref.read(appConfigProvider).serverUrl;
+    await ref.read(secureStorageProvider).saveCustomHeaders(serverUrl, headers);
+    state = headers;
+  }
+}
+
+final customHeadersProvider =
+    NotifierProvider<CustomHeadersNotifier, CustomHeaders>(
+      CustomHeadersNotifier.new,
+    );
+
+/// Certificates the current server's account trusts beyond the system's.
+/// Follows the server URL like [CustomHeadersNotifier].
+class TlsTrustNotifier extends Notifier<TlsTrust> {
+  @override
+  TlsTrust build() {
+    ref.listen(
+      appConfigProvider.select((c) => c.serverUrl),
+      (_, _) => load(),
+    );
+    return TlsTrust.none;
+  }
+
+  /// Load the saved trust. Call once at startup.
+  Future<void> load() async {
+    final serverUrl = ref.read(appConfigProvider).serverUrl;
+    state = serverUrl.isEmpty
+        ? TlsTrust.none
+        : await ref.read(secureStorageProvider).getTlsTrust(serverUrl);
+  }
+
+  /// Throws [ArgumentError] for a CA certificate that cannot be used.
+  Future<void> set(TlsTrust trust) async {
+    if (trust.caPem case final pem?) {
+      final error = TlsTrust.validateCa(pem);
+      if (error != null) throw ArgumentError.value(pem, 'caPem', error);
+    }
+    final serverUrl = ref.read(appConfigProvider).serverUrl;
+    await ref.read(secureStorageProvider).saveTlsTrust(serverUrl, trust);
+    state = trust;
+  }
+
+  Future<void> trust(String fingerprint) => set(state.trust(fingerprint));
+}
+
+final tlsTrustProvider = NotifierProvider<TlsTrustNotifier, TlsTrust>(
+  TlsTrustNotifier.new,
+);
+
+/// The proxy every connection goes through, login and WebDAV included.
+class ProxySettingsNotifier extends Notifier<ProxySettings> {
+  @override
+  ProxySettings build() => ProxySettings.system;
+
+  /// Load the saved settings. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getProxySettings();
+  }
+
+  /// Throws [ArgumentError] for a manual proxy address that cannot be
+  /// used.
+  Future<void> set(ProxySettings settings) async {
+    if (settings.mode == ProxyMode.manual) {
+      final error = ProxySettings.validateUrl(settings.url);
+      if (error != null) throw ArgumentError.value(settings.url, 'url', error);
+    }
+    await ref.read(secureStorageProvider).saveProxySettings(settings);
+    state = settings;
+  }
+}
+
+final proxySettingsProvider =
+    NotifierProvider<ProxySettingsNotifier, ProxySettings>(
+      ProxySettingsNotifier.new,
+    );
+
+/// Whether background work is held back while the machine is busy or hot.
+class ThrottleOnLoadNotifier extends Notifier<bool> {
+  @override
+  bool build() => false;
+
+  /// Load the saved choice. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getThrottleOnLoad();
+  }
+
+  Future<void> set(bool enabled) async {
+    await ref.read(secureStorageProvider).saveThrottleOnLoad(enabled);
+    state = enabled;
+  }
+}
+
+final throttleOnLoadProvider = NotifierProvider<ThrottleOnLoadNotifier, bool>(
+  ThrottleOnLoadNotifier.new,
+);
+
+/// What sync does on metered or roaming connections.
+class MeteredActionNotifier extends Notifier<MeteredAction> {
+  @override
+  MeteredAction build() => MeteredAction.pause;
+
+  /// Load the saved choice. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getMeteredAction();
+  }
+
+  Future<void> set(MeteredAction action) async {
+    await ref.read(secureStorageProvider).saveMeteredAction(action);
+    state = action;
+  }
+}
+
+final meteredActionProvider =
+    NotifierProvider<MeteredActionNotifier, MeteredAction>(
+      MeteredActionNotifier.new,
+    );
+
+/// What uploading a local folder does with symbolic links in it.
+class LinkPolicyNotifier extends Notifier<LinkPolicy> {
+  @override
+  LinkPolicy build() => LinkPolicy.skip;
+
+  /// Load the saved choice. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getLinkPolicy();
+  }
+
+  Future<void> set(LinkPolicy policy) async {
+    await ref.read(secureStorageProvider).saveLinkPolicy(policy);
+    state = policy;
+  }
+}
+
+final linkPolicyProvider = NotifierProvider<LinkPolicyNotifier, LinkPolicy>(
+  LinkPolicyNotifier.new,
+);
+
+/// File types the user included in or excluded from syncing.
+class SyncTypeFiltersNotifier extends Notifier<SyncFilter> {
+  @override
+  SyncFilter build() => SyncFilter.none;
+
+  /// Load the saved filters. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getSyncTypeFilters();
+  }
+
+  Future<void> set({List<String>? include, List<String>? exclude}) async {
+    final next = state.copyWith(include: include, exclude: exclude);
+    await ref.read(secureStorageProvider).saveSyncTypeFilters(next);
+    state = next;
+  }
+}
+
+final syncTypeFiltersProvider =
+    NotifierProvider<SyncTypeFiltersNotifier, SyncFilter>(
+      SyncTypeFiltersNotifier.new,
+    );
+
+/// The user's type filters with the configured size limit.
+final syncFilterProvider = Provider<SyncFilter>((ref) {
+  return ref
+      .watch(syncTypeFiltersProvider)
+      .copyWith(
+        maxFileSizeBytes: ref.watch(appConfigProvider).maxFileSizeBytes,
+      );
+});
+
+final resourceThrottleProvider = Provider<ResourceThrottle>((ref) {
+  final throttle = ResourceThrottle();
+  ref.listen(
+    throttleOnLoadProvider,
+    (_, enabled) => throttle.enabled = enabled,
+    fireImmediately: true,
+  );
+  ref.onDispose(throttle.dispose);
+  return throttle;
+});
+
+final meteredNetworkProvider = Provider<MeteredNetworkMonitor>((ref) {
+  final monitor = MeteredNetworkMonitor(
+    throttle: ref.watch(resourceThrottleProvider),
+    connectivity: ref.watch(connectivityProvider),
+    events: ref.watch(syncEventBusProvider),
+  );
+  ref.listen(
+    meteredActionProvider,
+    (_, action) => monitor.action = action,
+    fireImmediately: true,
+  );
+  ref.onDispose(monitor.stop);
+  return monitor;
+});
+
 final secureStorageProvider = Provider<SecureStorage>((ref) {
   return SecureStorage();
 });
 
+final serverClientFactoryProvider = Provider<ServerClientFactory>((ref) {
+  return ServerClientFactory(ref.watch(secureStorageProvider));
+});
+
 final connectivityProvider = Provider<ConnectivityService>((ref) {
   final service = ConnectivityService();
   ref.onDispose(service.dispose);
   return service;
 });
 
+/// Per-pair status of linked shares; refreshes whenever the service reports
+/// a change.
+final folderPairStatusProvider = FutureProvider<List<FolderPairStatus>>((
+  ref,
+) {
+  final service = ref.watch(linkedShareServiceProvider);
+  void refresh() => ref.invalidateSelf();
+  service.addListener(refresh);
+  ref.onDispose(() => service.removeListener(refresh));
+  return service.getFolderPairStatus();
+});
+
 /// Provides the application support directory path for the database.
 final dbPathProvider = FutureProvider<String>((ref) async {
   final dir = await getApplicationSupportDirectory();
@@ -105,11 +474,138 @@ final databaseProvider = Provider<AppDatabase>((ref) {
   return db;
 });
 
-final dioProvider = Provider<Dio>((ref) {
+/// Read only once [dbPathProvider] has resolved, so the lock sits next to
+/// the database.
+final dbLeaseProvider = Provider<DbLeaseService>((ref) {
+  final dir = ref.watch(dbPathProvider).requireValue;
+  final lease = DbLeaseService(
+    ref.watch(databaseProvider),
+    lockPath: '$dir${Platform.pathSeparator}oxicloud.db.lock',
+  );
+  ref.onDispose(lease.dispose);
+  return lease;
+});
+
+final apiClientProvider = Provider<ApiClient>((ref) {
   final config = ref.watch(appConfigProvider);
   final secureStorage = ref.watch(secureStorageProvider);
-  final client = ApiClient(config: config, secureStorage: secureStorage);
-  return client.dio;
+  late final TokenRefresher refresher;
+  final client = ApiClient(
+    config: config,
+    secureStorage: secureStorage,
+    onTokenRefresh: () => refresher.refresh(),
+    onForceLogout: () => ref.read(sessionEndedProvider.notifier).end(),
+  );
+  refresher = TokenRefresher(
+    secureStorage: secureStorage,
+    remote: AuthRemoteDatasource(client.dio),
+  );
+  ref.listen(
+    customHeadersProvider,
+    (_, headers) => client.customHeaders.headers = headers,
+    fireImmediately: true,
+  );
+  // Ask about each refused certificate once.
+  final prompted = <String>{};
+  void connect() => ref
+      .read(tlsTrustProvider)
+      .applyTo(
+        client.dio,
+        proxy: ref.read(proxySettingsProvider),
+        onUntrusted: (certificate) {
+          if (!prompted.add(certificate.fingerprint)) return;
+          ref
+              .read(syncEventBusProvider)
+              .emit(UntrustedCertificateSeen(certificate));
+        },
+      );
+  ref.listen(tlsTrustProvider, (_, _) => connect());
+  ref.listen(proxySettingsProvider, (_, _) => connect());
+  connect();
+  return client;
+});
+
+/// Counts the sessions the server ended, e.g. when the refresh token
+/// is refused, so the router can send the user back to the login page.
+class SessionEndedNotifier extends Notifier<int> {
+  @override
+  int build() => 0;
+
+  Future<void> end() async {
+    await ref.read(secureStorageProvider).clearSession();
+    state++;
+  }
+}
+
+final sessionEndedProvider = NotifierProvider<SessionEndedNotifier, int>(
+  SessionEndedNotifier.new,
+);
+
+final dioProvider = Provider<Dio>((ref) {
+  return ref.watch(apiClientProvider).dio;
+});
+
+final conflictResolverProvider = Provider<ConflictResolver>((ref) {
+  final resolver = ConflictResolver(
+    ref.watch(databaseProvider),
+    files: ref.watch(fileRepositoryProvider),
+  );
+  ref.onDispose(resolver.dispose);
+  return resolver;
+});
+
+/// Conflict copies waiting for the user to review them.
+final conflictCopiesProvider = FutureProvider<List<ConflictCopiesTableData>>((
+  ref,
+) {
+  return ref.watch(conflictResolverProvider).outstandingCopies();
+});
+
+final selectiveSyncProvider = Provider<SelectiveSyncService>((ref) {
+  return SelectiveSyncService(ref.watch(databaseProvider));
+});
+
+/// Ids of the folders excluded from the local mirror.
+final syncExclusionsProvider = FutureProvider<Set<String>>((ref) async {
+  final exclusions = await ref.watch(selectiveSyncProvider).list();
+  return {for (final e in exclusions) e.folderId};
+});
+
+final syncDirectionProvider = Provider<SyncDirectionService>((ref) {
+  return SyncDirectionService(ref.watch(databaseProvider));
+});
+
+/// Name patterns the user skips in addition to
+/// [IgnoreRuleCache.defaultPatterns].
+class SyncExcludePatternsNotifier extends Notifier<List<String>> {
+  @override
+  List<String> build() => const [];
+
+  /// Load the saved patterns. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getSyncExcludePatterns();
+  }
+
+  Future<void> set(List<String> patterns) async {
+    await ref.read(secureStorageProvider).saveSyncExcludePatterns(patterns);
+    state = patterns;
+  }
+}
+
+final syncExcludePatternsProvider =
+    NotifierProvider<SyncExcludePatternsNotifier, List<String>>(
+      SyncExcludePatternsNotifier.new,
+    );
+
+/// Parsed `.oxicloudignore` files, shared so each is read once per change.
+final ignoreRuleCacheProvider = Provider<IgnoreRuleCache>((ref) {
+  final cache = IgnoreRuleCache();
+  ref.listen(
+    syncExcludePatternsProvider,
+    (_, patterns) => cache.extraPatterns = patterns,
+    fireImmediately: true,
+  );
+  return cache;
 });
 
 final syncEngineProvider = Provider<SyncEngine>((ref) {
@@ -120,11 +616,323 @@ final syncEngineProvider = Provider<SyncEngine>((ref) {
     folderRepo: ref.watch(folderRepositoryProvider),
     favoritesRepo: ref.watch(favoritesRepositoryProvider),
     trashRepo: ref.watch(trashRepositoryProvider),
+    ledgerVerifier: TransferLedgerVerifier(
+      db: ref.watch(databaseProvider),
+      fileRepo: ref.watch(fileRepositoryProvider),
+      connectivity: ref.watch(connectivityProvider),
+      sampleRate: ref.watch(appConfigProvider).ledgerSampleRate,
+    ),
+    conflictResolver: ref.watch(conflictResolverProvider),
+    events: ref.watch(syncEventBusProvider),
+    propagation: PropagationPolicy(ref.watch(databaseProvider)),
+    restoreDeletedOnEdit: ref.watch(appConfigProvider).restoreDeletedOnEdit,
+    maxFailureRate: ref.watch(appConfigProvider).maxSyncFailureRate,
+    probe: () => ref.read(authRemoteProvider).getStatus(),
   );
   ref.onDispose(engine.dispose);
   return engine;
 });
 
+final syncErrorCenterProvider = Provider<SyncErrorCenter>((ref) {
+  return SyncErrorCenter(
+    db: ref.watch(databaseProvider),
+    sync: ref.watch(syncEngineProvider).sync,
+  );
+});
+
+final folderSubscriptionsProvider = Provider<FolderSubscriptions>((ref) {
+  final subscriptions = FolderSubscriptions(
+    events: ref.watch(syncEventBusProvider),
+  );
+  ref.onDispose(subscriptions.dispose);
+  return subscriptions;
+});
+
+final caseConflictDetectorProvider = Provider<CaseConflictDetector>((ref) {
+  return CaseConflictDetector(
+    db: ref.watch(databaseProvider),
+    events: ref.watch(syncEventBusProvider),
+  );
+});
+
+final folderScanSchedulerProvider = Provider<FolderScanScheduler>((ref) {
+  final config = ref.watch(appConfigProvider);
+  final scheduler = FolderScanScheduler(
+    db: ref.watch(databaseProvider),
+    folderRepo: ref.watch(folderRepositoryProvider),
+    connectivity: ref.watch(connectivityProvider),
+    subscriptions: ref.watch(folderSubscriptionsProvider),
+    policy: ScanIntervalPolicy(maxInterval: config.fullRescanInterval),
+    throttle: ref.watch(resourceThrottleProvider),
+    caseConflicts: ref.watch(caseConflictDetectorProvider),
+    changes: ref.watch(webDavChangesProvider),
+    concurrency: config.listingConcurrency,
+  );
+  ref.onDispose(scheduler.stop);
+  return scheduler;
+});
+
+final webDavChangesProvider = Provider<WebDavChangesService>((ref) {
+  return WebDavChangesService(
+    dio: ref.watch(dioProvider),
+    webdavUrl:
+        '${ref.watch(appConfigProvider).serverUrl}${ApiEndpoints.webdav}',
+  );
+});
+
+final setupScanProvider = Provider<SetupScan>((ref) {
+  return SetupScan(
+    db: ref.watch(databaseProvider),
+    folderRepo: ref.watch(folderRepositoryProvider),
+    events: ref.watch(syncEventBusProvider),
+    concurrency: ref.watch(appConfigProvider).listingConcurrency,
+  );
+});
+
+final storageQuotaProvider = Provider<StorageQuotaService>((ref) {
+  final quota = StorageQuotaService(
+    dio: ref.watch(dioProvider),
+    db: ref.watch(databaseProvider),
+    webdavUrl:
+        '${ref.watch(appConfigProvider).serverUrl}${ApiEndpoints.webdav}',
+    currentUser: ref.watch(authRepositoryProvider).getCurrentUser,
+    events: ref.watch(syncEventBusProvider),
+  );
+  ref.onDispose(quota.dispose);
+  return quota;
+});
+
+final localCopyVerifierProvider = Provider<LocalCopyVerifier>((ref) {
+  final verifier = LocalCopyVerifier(
+    db: ref.watch(databaseProvider),
+    events: ref.watch(syncEventBusProvider),
+    files: ref.watch(fileRepositoryProvider),
+    throttle: ref.watch(resourceThrottleProvider),
+  );
+  ref.onDispose(verifier.stop);
+  return verifier;
+});
+
+/// When local copies are removed to free disk space.
+class StorageOptimizerSettingsNotifier
+    extends Notifier<StorageOptimizerSettings> {
+  @override
+  StorageOptimizerSettings build() => const StorageOptimizerSettings();
+
+  /// Load the saved settings. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getStorageOptimizer();
+  }
+
+  Future<void> set(StorageOptimizerSettings settings) async {
+    await ref.read(secureStorageProvider).saveStorageOptimizer(settings);
+    state = settings;
+  }
+}
+
+final storageOptimizerSettingsProvider =
+    NotifierProvider<
+      StorageOptimizerSettingsNotifier,
+      StorageOptimizerSettings
+    >(StorageOptimizerSettingsNotifier.new);
+
+final storageOptimizerProvider = Provider<StorageOptimizer>((ref) {
+  final optimizer = StorageOptimizer(
+    db: ref.watch(databaseProvider),
+    freeSpace: ref.watch(diskSpaceProvider).freeBytes,
+    throttle: ref.watch(resourceThrottleProvider),
+  );
+  ref.listen(
+    storageOptimizerSettingsProvider,
+    (_, settings) => optimizer.settings = settings,
+    fireImmediately: true,
+  );
+  ref.onDispose(optimizer.stop);
+  return optimizer;
+});
+
+final syncStatsCollectorProvider = Provider<SyncStatsCollector>((ref) {
+  final collector = SyncStatsCollector(
+    db: ref.watch(databaseProvider),
+    events: ref.watch(syncEventBusProvider),
+  );
+  ref.onDispose(collector.stop);
+  return collector;
+});
+
+/// What syncing did over the last seven days, today included.
+final weeklySyncStatsProvider = FutureProvider<SyncStatsSummary>((ref) {
+  final today = DateTime.now();
+  return ref
+      .watch(syncStatsCollectorProvider)
+      .summary(since: today.subtract(const Duration(days: 6)));
+});
+
+class UsageStatisticsSettingsNotifier
+    extends Notifier<UsageStatisticsSettings> {
+  @override
+  UsageStatisticsSettings build() => const UsageStatisticsSettings();
+
+  /// Load the saved settings. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getUsageStatistics();
+  }
+
+  Future<void> set(UsageStatisticsSettings settings) async {
+    await ref.read(secureStorageProvider).saveUsageStatistics(settings);
+    state = settings;
+  }
+}
+
+final usageStatisticsSettingsProvider =
+    NotifierProvider<
+      UsageStatisticsSettingsNotifier,
+      UsageStatisticsSettings
+    >(UsageStatisticsSettingsNotifier.new);
+
+final usageStatsProvider = Provider<UsageStatsService>((ref) {
+  final service = UsageStatsService(
+    db: ref.watch(databaseProvider),
+    syncStats: ref.watch(syncStatsCollectorProvider),
+    storage: ref.watch(secureStorageProvider),
+    dio: ref.watch(dioProvider),
+  );
+  ref.listen(
+    usageStatisticsSettingsProvider,
+    (_, settings) => service.settings = settings,
+    fireImmediately: true,
+  );
+  return service;
+});
+
+/// Everything counted while usage statistics are on, most used first.
+final usageCountersProvider = FutureProvider<List<UsageCountersTableData>>((
+  ref,
+) {
+  ref.watch(usageStatisticsSettingsProvider);
+  return ref.watch(usageStatsProvider).counters();
+});
+
+final auditExportServiceProvider = Provider<AuditExportService>((ref) {
+  return AuditExportService(ref.watch(databaseProvider));
+});
+
+final troubleshootingServiceProvider = Provider<TroubleshootingService>((ref) {
+  final secureStorage = ref.watch(secureStorageProvider);
+  final diagnostics = DiagnosticsService(
+    dio: ref.watch(dioProvider),
+    serverUrl: ref.watch(appConfigProvider).serverUrl,
+    signedIn: secureStorage.isSignedIn,
+    dataDirs: () async => {
+      await FileSystem.downloadDir,
+      await FileSystem.cacheDir,
+    }.toList(),
+  );
+  return TroubleshootingService(diagnostics);
+});
+
+final operationReportServiceProvider = Provider<OperationReportService>((ref) {
+  return OperationReportService(
+    threshold: ref.watch(appConfigProvider).operationReportThreshold,
+  );
+});
+
+/// Reports left by past bulk operations, newest first.
+final operationReportsProvider = FutureProvider<List<File>>((ref) {
+  return ref.watch(operationReportServiceProvider).list();
+});
+
+final thumbnailServiceProvider = Provider<ThumbnailService>((ref) {
+  return ThumbnailService(ref.watch(fileRepositoryProvider));
+});
+
+/// Where the running instance leaves the details of its [ControlChannel].
+final controlInfoPathProvider = FutureProvider<String>((ref) async {
+  final dir = await ref.watch(dbPathProvider.future);
+  return '$dir${Platform.pathSeparator}control.json';
+});
+
+/// Answers `--status` and `--sync` from later launches of the app.
+final controlChannelProvider = Provider<ControlChannel>((ref) {
+  final engine = ref.watch(syncEngineProvider);
+  final channel = ControlChannel(
+    infoPath: ref.watch(controlInfoPathProvider).requireValue,
+    commands: {
+      'status': () async =>
+          '${engine.status.name}, ${engine.pendingCount} pending',
+      'sync': () async {
+        unawaited(engine.sync());
+        return 'Sync started';
+      },
+    },
+  );
+  ref.onDispose(channel.stop);
+  return channel;
+});
+
+final shutdownServiceProvider = Provider<ShutdownService>((ref) {
+  return ShutdownService(
+    syncEngine: ref.watch(syncEngineProvider),
+    uploads: ref.watch(chunkedUploadServiceProvider),
+    lease: ref.watch(dbLeaseProvider),
+    requests: ref.watch(apiClientProvider).timeouts,
+  );
+});
+
+final uploadSchedulerProvider = Provider<TransferScheduler>((ref) {
+  final config = ref.watch(appConfigProvider);
+  final scheduler = TransferScheduler(
+    maxConcurrent: config.maxConcurrentUploads,
+    policy: config.transferPriority,
+  );
+  final throttle = ref.watch(resourceThrottleProvider);
+  void applyThrottle() => scheduler.throttle(switch (throttle.level) {
+    ThrottleLevel.normal => null,
+    ThrottleLevel.reduced => 1,
+    ThrottleLevel.paused => 0,
+  });
+  applyThrottle();
+  throttle.addListener(applyThrottle);
+  ref.onDispose(() => throttle.removeListener(applyThrottle));
+  ref.onDispose(scheduler.dispose);
+  return scheduler;
+});
+
+final syncEventBusProvider = Provider<SyncEventBus>((ref) {
+  final bus = SyncEventBus();
+  ref.onDispose(bus.dispose);
+  return bus;
+});
+
+/// Live sync and transfer events, for progress indicators.
+final syncEventsProvider = StreamProvider<SyncEvent>((ref) {
+  return ref.watch(syncEventBusProvider).events;
+});
+
+final transferMonitorProvider = Provider<TransferMonitor>((ref) {
+  final db = ref.watch(databaseProvider);
+  final monitor = TransferMonitor(
+    events: ref.watch(syncEventBusProvider),
+    onFinished: (t) => db.insertActivity(
+      ActivityLogTableCompanion.insert(
+        category: 'transfer',
+        action: t.direction.name,
+        itemName: Value(t.name),
+        outcome: t.state.name,
+        bytes: Value(t.bytesTransferred),
+        initiator: const Value('user'),
+        createdAt: DateTime.now(),
+      ),
+    ),
+  );
+  ref.onDispose(monitor.dispose);
+  return monitor;
+});
+
+final transferControllerProvider = Provider<TransferController>((ref) {
+  return TransferController(ref.watch(transferMonitorProvider));
+});
+
 // --- Datasources ---
 
 final authRemoteProvider = Provider<AuthRemoteDatasource>((ref) {
@@ -132,7 +940,11 @@ final authRemoteProvider = Provider<AuthRemoteDatasource>((ref) {
 });
 
 final fileRemoteProvider = Provider<FileRemoteDatasource>((ref) {
-  return FileRemoteDatasource(ref.watch(dioProvider));
+  return FileRemoteDatasource(
+    ref.watch(dioProvider),
+    uploadLimit: ref.watch(uploadLimiterProvider),
+    downloadLimit: ref.watch(downloadLimiterProvider),
+  );
 });
 
 final folderRemoteProvider = Provider<FolderRemoteDatasource>((ref) {
@@ -159,12 +971,20 @@ final shareRemoteProvider = Provider<ShareRemoteDatasource>((ref) {
   return ShareRemoteDatasource(ref.watch(dioProvider));
 });
 
+final versionRemoteProvider = Provider<VersionRemoteDatasource>((ref) {
+  return VersionRemoteDatasource(
+    ref.watch(dioProvider),
+    downloadLimit: ref.watch(downloadLimiterProvider),
+  );
+});
+
 // --- Repositories ---
 
 final authRepositoryProvider = Provider<AuthRepository>((ref) {
   return AuthRepositoryImpl(
     remote: ref.watch(authRemoteProvider),
     secureStorage: ref.watch(secureStorageProvider),
+    appPasswords: ref.watch(appPasswordRemoteDatasourceProvider),
   );
 });
 
@@ -173,17 +993,27 @@ final fileRepositoryProvider = Provider<FileRepository>((ref) {
     remote: ref.watch(fileRemoteProvider),
     db: ref.watch(databaseProvider),
     connectivity: ref.watch(connectivityProvider),
+    events: ref.watch(syncEventBusProvider),
   );
 });
 
 final folderRepositoryProvider = Provider<FolderRepository>((ref) {
   return FolderRepositoryImpl(
     remote: ref.watch(folderRemoteProvider),
+    fileRemote: ref.watch(fileRemoteProvider),
     db: ref.watch(databaseProvider),
     connectivity: ref.watch(connectivityProvider),
+    propagateExternalDeletes: ref
+        .watch(appConfigProvider)
+        .propagateExternalDeletes,
+    events: ref.watch(syncEventBusProvider),
   );
 });
 
+final fileVersionRepositoryProvider = Provider<FileVersionRepository>((ref) {
+  return FileVersionRepositoryImpl(remote: ref.watch(versionRemoteProvider));
+});
+
 final trashRepositoryProvider = Provider<TrashRepository>((ref) {
   return TrashRepositoryImpl(remote: ref.watch(trashRemoteProvider));
 });
@@ -204,6 +1034,55 @@ final shareRepositoryProvider = Provider<ShareRepository>((ref) {
   return ShareRepositoryImpl(remote: ref.watch(shareRemoteProvider));
 });
 
+/// Share link templates for the current account: its own if it has any,
+/// otherwise the ones shared by every account. Follows the server URL.
+class ShareTemplatesNotifier extends Notifier<ShareTemplates> {
+  @override
+  ShareTemplates build() {
+    ref.listen(
+      appConfigProvider.select((c) => c.serverUrl),
+      (_, _) => load(),
+    );
+    return ShareTemplates.builtIn;
+  }
+
+  /// Load the saved templates. Call once at startup.
+  Future<void> load() async {
+    final serverUrl = ref.read(appConfigProvider).serverUrl;
+    state = await ref.read(secureStorageProvider).getShareTemplates(serverUrl);
+  }
+
+  /// Save [templates] for the current account if they are
+  /// [ShareTemplates.perAccount], otherwise for every account.
+  Future<void> set(ShareTemplates templates) async {
+    final storage = ref.read(secureStorageProvider);
+    final serverUrl = ref.read(appConfigProvider).serverUrl;
+    if (templates.perAccount) {
+      await storage.saveShareTemplates(templates, serverUrl: serverUrl);
+    } else {
+      await storage.clearShareTemplates(serverUrl);
+      await storage.saveShareTemplates(templates);
+    }
+    state = templates;
+  }
+}
+
+final shareTemplatesProvider =
+    NotifierProvider<ShareTemplatesNotifier, ShareTemplates>(
+      ShareTemplatesNotifier.new,
+    );
+
+/// Ids of the files and folders that have a share link, for the badges in
+/// file lists. Empty when the shares cannot be fetched.
+final sharedItemIdsProvider = FutureProvider<Set<String>>((ref) async {
+  try {
+    final shares = await ref.watch(shareRepositoryProvider).listShares();
+    return {for (final s in shares) s.itemId};
+  } catch (_) {
+    return const {};
+  }
+});
+
 final photosRemoteProvider = Provider<PhotosRemoteDatasource>((ref) {
   return PhotosRemoteDatasource(ref.watch(dioProvider));
 });
@@ -217,7 +1096,159 @@ final photosRepositoryProvider = Provider<PhotosRepository>((ref) {
 final chunkedUploadDatasourceProvider = Provider<ChunkedUploadDatasource>((
   ref,
 ) {
-  return ChunkedUploadDatasource(ref.watch(dioProvider));
+  return ChunkedUploadDatasource(
+    ref.watch(dioProvider),
+    uploadLimit: ref.watch(uploadLimiterProvider),
+  );
+});
+
+final fileModeStoreProvider = Provider<FileModeStore>((ref) {
+  return FileModeStore(
+    ref.watch(databaseProvider),
+    enabled: ref.watch(appConfigProvider).preserveFileModes,
+  );
+});
+
+final tempFileAllocatorProvider = Provider<TempFileAllocator>((ref) {
+  return TempFileAllocator();
+});
+
+final diskSpaceProvider = Provider<DiskSpace>((ref) {
+  return DiskSpace();
+});
+
+final checksumServiceProvider = Provider<ChecksumService>((ref) {
+  return ChecksumService(
+    ref.watch(databaseProvider),
+    ref.watch(authRemoteProvider),
+    preferred: ref.watch(appConfigProvider).preferredChecksum,
+  );
+});
+
+final chunkedUploadServiceProvider = Provider<ChunkedUploadService>((ref) {
+  return ChunkedUploadService(
+    ref.watch(chunkedUploadDatasourceProvider),
+    db: ref.watch(databaseProvider),
+    checksums: ref.watch(checksumServiceProvider),
+    chunkSize: ref.watch(appConfigProvider).chunkSizeBytes,
+  );
+});
+
+final uploadDeduplicatorProvider = Provider<UploadDeduplicator>((ref) {
+  return UploadDeduplicator(
+    db: ref.watch(databaseProvider),
+    files: ref.watch(fileRepositoryProvider),
+    checksums: ref.watch(checksumServiceProvider),
+  );
+});
+
+final webDavPropertiesProvider = Provider<WebDavPropertiesService>((ref) {
+  return WebDavPropertiesService(
+    dio: ref.watch(dioProvider),
+    webdavUrl:
+        '${ref.watch(appConfigProvider).serverUrl}${ApiEndpoints.webdav}',
+  );
+});
+
+final resumableDownloadProvider = Provider<ResumableDownloadService>((ref) {
+  return ResumableDownloadService(
+    db: ref.watch(databaseProvider),
+    files: ref.watch(fileRepositoryProvider),
+    tempFiles: ref.watch(tempFileAllocatorProvider),
+    properties: ref.watch(webDavPropertiesProvider),
+  );
+});
+
+final bundleServiceProvider = Provider<BundleService>((ref) {
+  return BundleService(
+    files: ref.watch(fileRepositoryProvider),
+    folders: ref.watch(folderRepositoryProvider),
+    tempFiles: ref.watch(tempFileAllocatorProvider),
+  );
+});
+
+final bulkUploadServiceProvider = Provider<BulkUploadService>((ref) {
+  final config = ref.watch(appConfigProvider);
+  return BulkUploadService(
+    files: ref.watch(fileRepositoryProvider),
+    maxFileBytes: config.bulkUploadMaxFileBytes,
+    maxFiles: config.bulkUploadMaxFiles,
+    maxBytes: config.bulkUploadMaxBytes,
+    pipelineDepth: config.uploadPipelineDepth,
+  );
+});
+
+final folderPublishServiceProvider = Provider<FolderPublishService>((ref) {
+  return FolderPublishService(
+    files: ref.watch(fileRepositoryProvider),
+    folders: ref.watch(folderRepositoryProvider),
+    algorithm: ref.watch(checksumServiceProvider).algorithm,
+    ignore: ref.watch(ignoreRuleCacheProvider),
+    filter: ref.watch(syncFilterProvider),
+    links: LinkResolver(ref.watch(linkPolicyProvider)),
+  );
+});
+
+final uploadSessionCleanupProvider = Provider<UploadSessionCleanupService>((
+  ref,
+) {
+  final config = ref.watch(appConfigProvider);
+  final service = UploadSessionCleanupService(
+    datasource: ref.watch(chunkedUploadDatasourceProvider),
+    uploads: ref.watch(chunkedUploadServiceProvider),
+    db: ref.watch(databaseProvider),
+    connectivity: ref.watch(connectivityProvider),
+    staleAge: config.staleUploadAge,
+    interval: config.uploadCleanupInterval,
+  );
+  ref.onDispose(service.stop);
+  return service;
+});
+
+// --- Inbox ---
+
+class InboxSettingsNotifier extends Notifier<InboxSettings> {
+  @override
+  InboxSettings build() => const InboxSettings();
+
+  /// Load the saved settings. Call once at startup.
+  Future<void> load() async {
+    state = await ref.read(secureStorageProvider).getInboxSettings();
+  }
+
+  Future<void> set(InboxSettings settings) async {
+    await ref.read(secureStorageProvider).saveInboxSettings(settings);
+    state = settings;
+  }
+}
+
+final inboxSettingsProvider =
+    NotifierProvider<InboxSettingsNotifier, InboxSettings>(
+      InboxSettingsNotifier.new,
+    );
+
+final inboxServiceProvider = Provider<InboxService>((ref) {
+  final service = InboxService(
+    files: ref.watch(fileRepositoryProvider),
+    db: ref.watch(databaseProvider),
+    events: ref.watch(syncEventBusProvider),
+    tempFiles: ref.watch(tempFileAllocatorProvider),
+  );
+  ref.listen(
+    inboxSettingsProvider,
+    (_, settings) => service.settings = settings,
+    fireImmediately: true,
+  );
+  ref.onDispose(service.stop);
+  return service;
+});
+
+final inboxRulesProvider = FutureProvider<List<InboxRulesTableData>>((ref) {
+  return ref.watch(databaseProvider).getInboxRules();
+});
+
+final inboxItemsProvider = FutureProvider<List<InboxItemsTableData>>((ref) {
+  return ref.watch(databaseProvider).getRecentInboxItems();
 });
 
 // --- Batch ---
@@ -232,6 +1263,12 @@ final oidcRemoteDatasourceProvider = Provider<OidcRemoteDatasource>((ref) {
   return OidcRemoteDatasource(ref.watch(dioProvider));
 });
 
+// --- Login Flow v2 ---
+
+final loginFlowServiceProvider = Provider<LoginFlowService>((ref) {
+  return LoginFlowService(LoginFlowRemoteDatasource(ref.watch(dioProvider)));
+});
+
 // --- Dedup ---
 
 final dedupRemoteDatasourceProvider = Provider<DedupRemoteDatasource>((ref) {
@@ -267,6 +1304,15 @@ final deviceAuthRemoteDatasourceProvider = Provider<DeviceAuthRemoteDatasource>(
   },
 );
 
+final sessionRepositoryProvider = Provider<SessionRepository>((ref) {
+  return SessionRepositoryImpl(
+    devices: ref.watch(deviceAuthRemoteDatasourceProvider),
+    appPasswords: ref.watch(appPasswordRemoteDatasourceProvider),
+    secureStorage: ref.watch(secureStorageProvider),
+    auth: ref.watch(authRepositoryProvider),
+  );
+});
+
 // --- i18n ---
 
 final i18nRemoteDatasourceProvider = Provider<I18nRemoteDatasource>((ref) {
@@ -279,3 +1325,14 @@ final publicShareRemoteDatasourceProvider =
     Provider<PublicShareRemoteDatasource>((ref) {
       return PublicShareRemoteDatasource(ref.watch(dioProvider));
     });
+
+final linkedShareServiceProvider = Provider<LinkedShareService>((ref) {
+  final service = LinkedShareService(
+    db: ref.watch(databaseProvider),
+    secureStorage: ref.watch(secureStorageProvider),
+    connectivity: ref.watch(connectivityProvider),
+    tempFiles: ref.watch(tempFileAllocatorProvider),
+  );
+  ref.onDispose(service.dispose);
+  return service;
+});
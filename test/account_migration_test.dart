@@ -0,0 +1,150 @@
+import 'dart:convert';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/services/account_migration_service.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/entities/folder_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+
+/// An account keeping files and folders in memory.
+class _Account extends Fake implements FileRepository, FolderRepository {
+  final folders = <FolderEntity>[];
+  final files = <FileEntity>[];
+  final content = <String, List<int>>{};
+  var uploads = 0;
+  var _ids = 0;
+
+  FolderContents _contents(String? folderId) => FolderContents(
+    folders: [
+      for (final f in folders)
+        if (f.parentId == folderId) f,
+    ],
+    files: [
+      for (final f in files)
+        if (f.folderId == folderId) f,
+    ],
+  );
+
+  @override
+  Future<FolderContents> listRootContents() async => _contents(null);
+
+  @override
+  Future<FolderContents> listFolderContents(String folderId) async =>
+      _contents(folderId);
+
+  @override
+  Future<FolderEntity> getFolder(String id) async =>
+      folders.firstWhere((f) => f.id == id);
+
+  @override
+  Future<FolderEntity> createFolder({
+    required String name,
+    String? parentId,
+  }) async {
+    final folder = FolderEntity(
+      id: 'd${_ids++}',
+      name: name,
+      path: '/$name',
+      parentId: parentId,
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+    folders.add(folder);
+    return folder;
+  }
+
+  @override
+  Future<FileEntity> uploadFile({
+    required String name,
+    required String? folderId,
+    required Stream<List<int>> fileStream,
+    required int fileSize,
+    required String mimeType,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  }) async {
+    final bytes = [await for (final chunk in fileStream) ...chunk];
+    final file = FileEntity(
+      id: 'f${_ids++}',
+      name: name,
+      path: '/$name',
+      size: bytes.length,
+      mimeType: mimeType,
+      folderId: folderId,
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+    content[file.id] = bytes;
+    files.add(file);
+    uploads++;
+    return file;
+  }
+
+  @override
+  Future<Stream<List<int>>> downloadFile(String id) async =>
+      Stream.value(content[id]!);
+
+  Future<void> put(String name, String text, {String? folderId}) =>
+      uploadFile(
+        name: name,
+        folderId: folderId,
+        fileStream: Stream.value(utf8.encode(text)),
+        fileSize: text.length,
+        mimeType: 'text/plain',
+      );
+}
+
+void main() {
+  test('a folder tree is copied, resumed and checked', () async {
+    final source = _Account();
+    final target = _Account();
+    final docs = await source.createFolder(name: 'Docs');
+    final sub = await source.createFolder(name: 'Sub', parentId: docs.id);
+    await source.put('a.txt', 'alpha', folderId: docs.id);
+    await source.put('b.txt', 'beta', folderId: sub.id);
+
+    // An earlier run that stopped after the first file.
+    final copied = await target.createFolder(name: 'Docs');
+    await target.put('a.txt', 'alpha', folderId: copied.id);
+
+    final migration = AccountMigrationService(
+      sourceFiles: source,
+      sourceFolders: source,
+      targetFiles: target,
+      targetFolders: target,
+    );
+    final phases = <MigrationPhase>{};
+    final report = await migration.migrate(
+      sourceFolderId: docs.id,
+      onProgress: (p) => phases.add(p.phase),
+    );
+
+    expect(report.filesCopied, 1);
+    expect(report.filesSkipped, 1);
+    expect(report.verified, isTrue);
+    expect(phases, MigrationPhase.values.toSet());
+    expect(target.folders.where((f) => f.name == 'Docs'), hasLength(1));
+    final b = target.files.singleWhere((f) => f.name == 'b.txt');
+    expect(utf8.decode(target.content[b.id]!), 'beta');
+    expect(target.folders.singleWhere((f) => f.id == b.folderId).name, 'Sub');
+  });
+
+  test('files that differ on the target are reported, not replaced', () async {
+    final source = _Account();
+    final target = _Account();
+    await source.put('a.txt', 'alpha');
+    await target.put('a.txt', 'something else');
+
+    final report = await AccountMigrationService(
+      sourceFiles: source,
+      sourceFolders: source,
+      targetFiles: target,
+      targetFolders: target,
+    ).migrate();
+
+    expect(report.mismatches, ['a.txt']);
+    expect(target.uploads, 1);
+  });
+}
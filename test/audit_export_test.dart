@@ -0,0 +1,159 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/services/audit_export_service.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/core/sync/sync_models.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/favorites_repository.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/domain/repositories/trash_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+}
+
+/// Refuses every rename.
+class _Files extends Fake implements FileRepository {
+  @override
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async => throw const ForbiddenException();
+}
+
+class _Folders extends Fake implements FolderRepository {}
+
+class _Favorites extends Fake implements FavoritesRepository {}
+
+class _Trash extends Fake implements TrashRepository {}
+
+void main() {
+  late AppDatabase db;
+  late Directory dir;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    dir = await Directory.systemTemp.createTemp('audit');
+    addTearDown(() async {
+      await db.close();
+      await dir.delete(recursive: true);
+    });
+  });
+
+  Future<void> log(String itemId, DateTime at, {String? reason}) {
+    return db.insertActivity(
+      ActivityLogTableCompanion.insert(
+        category: 'transfer',
+        action: 'upload',
+        itemId: Value(itemId),
+        outcome: 'completed',
+        reason: Value(reason),
+        bytes: const Value(5),
+        createdAt: at,
+      ),
+    );
+  }
+
+  test('sync decisions are logged', () async {
+    final engine = SyncEngine(
+      db: db,
+      connectivity: _Online(),
+      fileRepo: _Files(),
+      folderRepo: _Folders(),
+      favoritesRepo: _Favorites(),
+      trashRepo: _Trash(),
+    );
+    addTearDown(engine.dispose);
+    await engine.enqueue(
+      SyncTask(
+        id: 'r',
+        operation: SyncOperation.rename,
+        entityType: 'file',
+        entityId: 'f1',
+        payload: const {'new_name': 'b.txt'},
+        createdAt: DateTime.now(),
+      ),
+    );
+    await engine.sync();
+
+    final activity = await db.getActivity();
+    expect(activity.single.category, 'sync');
+    expect(activity.single.action, 'rename');
+    expect(activity.single.itemId, 'f1');
+    expect(activity.single.outcome, 'retry');
+  });
+
+  test('activity and resolved conflicts merge in time order', () async {
+    final now = DateTime.now();
+    await db.upsertUser(UserTableCompanion.insert(id: 'u1', username: 'ana'));
+    await log('early', now.subtract(const Duration(days: 1)));
+    await log('late', now.add(const Duration(hours: 1)));
+    final conflict = await db.insertSyncConflict(
+      SyncConflictsTableCompanion.insert(
+        itemId: 'c1',
+        itemType: 'file',
+        operationType: 'rename',
+        payload: '{}',
+        createdAt: now,
+      ),
+    );
+    await db.insertSyncConflict(
+      SyncConflictsTableCompanion.insert(
+        itemId: 'open',
+        itemType: 'file',
+        operationType: 'rename',
+        payload: '{}',
+        createdAt: now,
+      ),
+    );
+    await db.resolveConflict(conflict, 'keepLocal');
+
+    final records = await AuditExportService(db).collect();
+    expect(records.map((r) => r.itemId), ['early', 'c1', 'late']);
+    expect(records[1].category, 'conflict');
+    expect(records[1].reason, 'keepLocal');
+    expect(records[1].initiator, 'user');
+    expect(records.every((r) => r.user == 'ana'), isTrue);
+
+    final since = await AuditExportService(db).collect(from: now);
+    expect(since.map((r) => r.itemId), ['c1', 'late']);
+  });
+
+  test('the format follows the file extension', () async {
+    await log('f1', DateTime.utc(2026, 3, 2), reason: 'said "hi", twice');
+    final audit = AuditExportService(db);
+
+    final csv = p.join(dir.path, 'audit.csv');
+    expect(await audit.exportAudit(csv), 1);
+    final lines = File(csv).readAsLinesSync();
+    expect(lines.first, AuditRecord.csvHeader.join(','));
+    expect(
+      lines.last,
+      '2026-03-02T00:00:00.000Z,,transfer,upload,f1,,completed,'
+      '"said ""hi"", twice",5,sync',
+    );
+
+    final jsonl = p.join(dir.path, 'audit.jsonl');
+    await audit.exportAudit(jsonl);
+    final record =
+        json.decode(File(jsonl).readAsLinesSync().single)
+            as Map<String, dynamic>;
+    expect(record['item_id'], 'f1');
+    expect(record.containsKey('user'), isFalse);
+  });
+}
@@ -0,0 +1,132 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/auth/auth_method.dart';
+import 'package:oxicloud/core/auth/secure_storage.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/platform/platform_info.dart';
+import 'package:oxicloud/data/datasources/remote/app_password_remote_datasource.dart';
+import 'package:oxicloud/data/datasources/remote/auth_remote_datasource.dart';
+import 'package:oxicloud/data/dtos/auth/auth_dtos.dart';
+import 'package:oxicloud/data/repositories/auth_repository_impl.dart';
+
+/// Accepts only the app password `secret` kept in [storage].
+class _Auth extends Fake implements AuthRemoteDatasource {
+  final SecureStorage storage;
+  var logouts = 0;
+  final checks = <bool>[];
+
+  _Auth(this.storage);
+
+  @override
+  Future<AuthResponseDto> login(LoginRequestDto dto) async => AuthResponseDto(
+    user: UserResponseDto(id: 'u1', username: dto.username),
+    accessToken: 'access',
+    refreshToken: 'refresh',
+    expiresIn: 3600,
+  );
+
+  @override
+  Future<void> logout() async {
+    logouts++;
+  }
+
+  @override
+  Future<UserResponseDto> getCurrentUser({
+    bool checkingCredentials = false,
+  }) async {
+    checks.add(checkingCredentials);
+    if ((await storage.getAppPassword())?.password != 'secret') {
+      throw const UnauthorizedException();
+    }
+    return const UserResponseDto(id: 'u1', username: 'ana');
+  }
+}
+
+class _AppPasswords extends Fake implements AppPasswordRemoteDatasource {
+  final bool supported;
+  final created = <String>[];
+  final revoked = <String>[];
+
+  _AppPasswords({this.supported = true});
+
+  @override
+  Future<AppPasswordCreateResult> create(String name) async {
+    if (!supported) throw const NotFoundException();
+    created.add(name);
+    return AppPasswordCreateResult(id: 'ap1', name: name, password: 'secret');
+  }
+
+  @override
+  Future<void> revoke(String id) async => revoked.add(id);
+}
+
+void main() {
+  late SecureStorage storage;
+  late _Auth remote;
+
+  setUp(() {
+    storage = SecureStorage.memory();
+    remote = _Auth(storage);
+  });
+
+  test('login switches the device to its own app password', () async {
+    final appPasswords = _AppPasswords();
+    final repo = AuthRepositoryImpl(
+      remote: remote,
+      secureStorage: storage,
+      appPasswords: appPasswords,
+    );
+
+    await repo.login(username: 'ana', password: 'pw');
+    expect(appPasswords.created, [PlatformInfo.deviceName]);
+    expect(await storage.getAuthMethod(), AuthMethod.appPassword);
+    expect(
+      await storage.getAuthorization(),
+      basicAuthorization('ana', 'secret'),
+    );
+    expect(await storage.getAccessToken(), isNull);
+    expect(await storage.getRefreshToken(), isNull);
+    expect(remote.logouts, 1, reason: 'the login session is ended');
+
+    await repo.logout();
+    expect(appPasswords.revoked, ['ap1']);
+    expect(remote.logouts, 1);
+    expect(await storage.isSignedIn(), isFalse);
+  });
+
+  test('servers without app passwords keep the session', () async {
+    final repo = AuthRepositoryImpl(
+      remote: remote,
+      secureStorage: storage,
+      appPasswords: _AppPasswords(supported: false),
+    );
+
+    await repo.login(username: 'ana', password: 'pw');
+    expect(await storage.getAuthMethod(), AuthMethod.token);
+    expect(await storage.getAuthorization(), 'Bearer access');
+    expect(await storage.getAppPassword(), isNull);
+    expect(remote.logouts, 0);
+  });
+
+  test('a mistyped app password is reported, not signed out', () async {
+    final repo = AuthRepositoryImpl(
+      remote: remote,
+      secureStorage: storage,
+      appPasswords: _AppPasswords(),
+    );
+
+    await expectLater(
+      repo.loginWithAppPassword(username: 'ana', appPassword: 'typo'),
+      throwsA(isA<UnauthorizedException>()),
+    );
+    expect(remote.checks, [true]);
+    expect(await storage.isSignedIn(), isFalse);
+
+    final user = await repo.loginWithAppPassword(
+      username: 'ana',
+      appPassword: 'secret',
+    );
+    expect(user.id, 'u1');
+    expect(await storage.getAuthMethod(), AuthMethod.appPassword);
+  });
+}
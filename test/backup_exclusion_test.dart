@@ -0,0 +1,34 @@
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/platform/backup_exclusion.dart';
+
+void main() {
+  late Directory dir;
+
+  setUp(() async {
+    dir = await Directory.systemTemp.createTemp('cache');
+    addTearDown(() => dir.delete(recursive: true));
+  });
+
+  test('the cache directory gets a standard tag once', () async {
+    final tag = File(p.join(dir.path, 'CACHEDIR.TAG'));
+    await BackupExclusion.mark(dir.path);
+    expect(
+      tag.readAsLinesSync().first,
+      'Signature: 8a477f597d28d172789f06886806bc55',
+    );
+
+    // Marked already: left alone.
+    tag.writeAsStringSync('kept');
+    await BackupExclusion.mark(dir.path);
+    expect(tag.readAsStringSync(), 'kept');
+  });
+
+  test('a missing directory is only logged', () async {
+    await BackupExclusion.mark(p.join(dir.path, 'missing'));
+    expect(Directory(p.join(dir.path, 'missing')).existsSync(), isFalse);
+  });
+}
@@ -0,0 +1,20 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import '../benchmark/harness.dart';
+
+void main() {
+  test('throughput is work per second', () {
+    expect(rateOf(50, const Duration(milliseconds: 500)), 100);
+  });
+
+  test('only drops beyond the tolerance fail', () {
+    expect(regression('scan', 81, baseline: 100), isNull);
+    expect(regression('scan', 150, baseline: 100), isNull);
+    expect(regression('scan', 79, baseline: 100), contains('scan regressed'));
+    expect(regression('scan', 60, baseline: 100, tolerance: 0.5), isNull);
+  });
+
+  test('without a baseline nothing fails', () {
+    expect(regression('scan', 1), isNull);
+  });
+}
@@ -0,0 +1,90 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/services/bulk_upload_service.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+UploadSource _source(String name, int size) => (
+  name: name,
+  size: size,
+  mimeType: 'text/plain',
+  open: () => Stream.value(List.filled(size, 0)),
+);
+
+/// A server without the batch endpoint that refuses files named `bad*`.
+class _Files extends Fake implements FileRepository {
+  var batchRequests = 0;
+  final single = <String>[];
+
+  @override
+  Future<BatchUploadResult> uploadFiles({
+    required String? folderId,
+    required List<UploadSource> files,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  }) async {
+    batchRequests++;
+    throw const ServerException('Not found', statusCode: 404);
+  }
+
+  @override
+  Future<FileEntity> uploadFile({
+    required String name,
+    required String? folderId,
+    required Stream<List<int>> fileStream,
+    required int fileSize,
+    required String mimeType,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  }) async {
+    single.add(name);
+    if (name.startsWith('bad')) throw const ServerException('Refused');
+    return FileEntity(
+      id: name,
+      name: name,
+      path: '/$name',
+      size: fileSize,
+      mimeType: mimeType,
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+  }
+}
+
+void main() {
+  test('batches are capped by file count and bytes', () {
+    final bulk = BulkUploadService(
+      files: _Files(),
+      maxFileBytes: 100,
+      maxFiles: 3,
+      maxBytes: 150,
+    );
+    final batches = bulk.plan([
+      for (var i = 0; i < 5; i++) _source('f$i', 10),
+      _source('big', 100),
+      _source('last', 60),
+    ]);
+    expect(batches.map((b) => b.map((f) => f.name).toList()).toList(), [
+      ['f0', 'f1', 'f2'],
+      ['f3', 'f4', 'big'],
+      ['last'],
+    ]);
+    expect(bulk.accepts(100), isTrue);
+    expect(bulk.accepts(101), isFalse);
+  });
+
+  test('servers without batches get the files one by one', () async {
+    final files = _Files();
+    final bulk = BulkUploadService(files: files, pipelineDepth: 2);
+    final batch = [_source('a', 1), _source('bad', 1), _source('c', 1)];
+
+    final result = await bulk.upload(batch, folderId: 'd1');
+    expect(result.uploaded.map((f) => f.name), unorderedEquals(['a', 'c']));
+    expect(result.failed, {'bad': 'Refused'});
+
+    await bulk.upload(batch, folderId: 'd1');
+    expect(files.batchRequests, 1);
+    expect(files.single, hasLength(6));
+  });
+}
@@ -0,0 +1,147 @@
+import 'dart:io';
+import 'dart:typed_data';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/services/bundle_service.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/entities/folder_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+
+/// A server keeping files and folders in memory.
+class _Server extends Fake implements FileRepository, FolderRepository {
+  final folders = <FolderEntity>[];
+  final files = <FileEntity>[];
+  final content = <String, List<int>>{};
+  var uploads = 0;
+  var _ids = 0;
+
+  FolderContents _contents(String? folderId) => FolderContents(
+    folders: [
+      for (final f in folders)
+        if (f.parentId == folderId) f,
+    ],
+    files: [
+      for (final f in files)
+        if (f.folderId == folderId) f,
+    ],
+  );
+
+  @override
+  Future<FolderContents> listRootContents() async => _contents(null);
+
+  @override
+  Future<FolderContents> listFolderContents(String folderId) async =>
+      _contents(folderId);
+
+  @override
+  Future<FolderEntity> createFolder({
+    required String name,
+    String? parentId,
+  }) async {
+    final folder = FolderEntity(
+      id: 'd${_ids++}',
+      name: name,
+      path: '/$name',
+      parentId: parentId,
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+    folders.add(folder);
+    return folder;
+  }
+
+  @override
+  Future<FileEntity> uploadFile({
+    required String name,
+    required String? folderId,
+    required Stream<List<int>> fileStream,
+    required int fileSize,
+    required String mimeType,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  }) async {
+    final file = FileEntity(
+      id: 'f${_ids++}',
+      name: name,
+      path: '/$name',
+      size: fileSize,
+      mimeType: mimeType,
+      folderId: folderId,
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+    content[file.id] = [await for (final chunk in fileStream) ...chunk];
+    files.add(file);
+    uploads++;
+    return file;
+  }
+
+  @override
+  Future<Stream<List<int>>> downloadFile(String id) async =>
+      Stream.value(content[id]!);
+
+  @override
+  Future<void> deleteFile(String id, {String? ifMatch}) async {
+    files.removeWhere((f) => f.id == id);
+    content.remove(id);
+  }
+}
+
+void main() {
+  late Directory dir;
+  late _Server server;
+  late BundleService bundles;
+
+  setUp(() async {
+    dir = await Directory.systemTemp.createTemp('bundle_test');
+    server = _Server();
+    bundles = BundleService(files: server, folders: server, segmentSize: 4);
+  });
+  tearDown(() => dir.delete(recursive: true));
+
+  Future<File> image(List<int> bytes) async {
+    final file = File('${dir.path}/disk.img');
+    await file.writeAsBytes(bytes);
+    return file;
+  }
+
+  test('zero segments are skipped and restored as zeros', () async {
+    final bytes = [1, 2, 3, 4, 0, 0, 0, 0, 5, 6];
+    final first = await bundles.upload(await image(bytes));
+    expect(first.folder.name, 'disk.img.oxibundle');
+    expect(first.uploaded, 2);
+
+    final target = '${dir.path}/restored.img';
+    await bundles.download(first.folder.id, target);
+    expect(await File(target).readAsBytes(), Uint8List.fromList(bytes));
+  });
+
+  test('only changed segments are uploaded again', () async {
+    await bundles.upload(await image([1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    server.uploads = 0;
+
+    final second = await bundles.upload(
+      await image([1, 2, 3, 4, 5, 6, 7, 0]),
+    );
+    // The changed second segment and the manifest; the third is gone.
+    expect(second.uploaded, 1);
+    expect(server.uploads, 2);
+    expect(
+      server.files.map((f) => f.name),
+      unorderedEquals(['segment-000000', 'segment-000001', 'manifest.json']),
+    );
+
+    final target = '${dir.path}/restored.img';
+    await bundles.download(second.folder.id, target);
+    expect(await File(target).readAsBytes(), [1, 2, 3, 4, 5, 6, 7, 0]);
+  });
+
+  test('only large disk images are bundled', () {
+    expect(BundleService.shouldBundle('vm.VMDK', 10, minSize: 5), isTrue);
+    expect(BundleService.shouldBundle('vm.vmdk', 1, minSize: 5), isFalse);
+    expect(BundleService.shouldBundle('movie.mp4', 10, minSize: 5), isFalse);
+    expect(BundleService.shouldBundle('vm.vmdk', 10, minSize: 0), isFalse);
+  });
+}
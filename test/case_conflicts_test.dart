@@ -0,0 +1,88 @@
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/case_conflicts.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+
+FileEntity _file(String id, String name, DateTime createdAt) => FileEntity(
+  id: id,
+  name: name,
+  path: '/Docs/$name',
+  size: 1,
+  mimeType: 'text/plain',
+  createdAt: createdAt,
+  modifiedAt: createdAt,
+);
+
+void main() {
+  test('names equal ignoring case are grouped', () {
+    expect(CaseConflicts.find(['Readme.md', 'a.txt', 'README.md']), [
+      ['Readme.md', 'README.md'],
+    ]);
+    expect(CaseConflicts.find(['a', 'b']), isEmpty);
+  });
+
+  test('suggested names avoid every sibling', () {
+    expect(
+      CaseConflicts.suggestRename('README.md', ['Readme.md', 'readme (2).md']),
+      'README (3).md',
+    );
+    expect(CaseConflicts.suggestRename('.env', ['.ENV']), '.env (2)');
+  });
+
+  group('detector', () {
+    late AppDatabase db;
+    late CaseConflictDetector detector;
+
+    setUp(() {
+      db = AppDatabase(NativeDatabase.memory());
+      detector = CaseConflictDetector(db: db, ignoresCase: true);
+    });
+    tearDown(() => db.close());
+
+    test('the newer item of a clash is conflicted until renamed', () async {
+      final old = _file('f1', 'Readme.md', DateTime(2026));
+      final clashing = FolderContents(
+        folders: const [],
+        files: [_file('f2', 'README.md', DateTime(2026, 2)), old],
+      );
+
+      final found = await detector.check('docs', clashing);
+      expect(found.single.name, 'README.md');
+      expect(found.single.clashesWith, 'Readme.md');
+      expect(found.single.suggestion, 'README (2).md');
+      final conflict = (await db.getUnresolvedConflicts()).single;
+      expect(conflict.itemId, 'f2');
+      expect(conflict.conflictType, CaseConflictDetector.conflictType);
+
+      // Known clashes are not reported again.
+      expect(await detector.check('docs', clashing), isEmpty);
+
+      await detector.check(
+        'docs',
+        FolderContents(
+          folders: const [],
+          files: [_file('f2', 'README (2).md', DateTime(2026, 2)), old],
+        ),
+      );
+      expect(await db.getUnresolvedConflicts(), isEmpty);
+    });
+
+    test('case-sensitive systems have no clashes', () async {
+      final sensitive = CaseConflictDetector(db: db, ignoresCase: false);
+      final found = await sensitive.check(
+        'docs',
+        FolderContents(
+          folders: const [],
+          files: [
+            _file('f1', 'a.txt', DateTime(2026)),
+            _file('f2', 'A.txt', DateTime(2026)),
+          ],
+        ),
+      );
+      expect(found, isEmpty);
+    });
+  });
+}
@@ -0,0 +1,207 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:math';
+
+import 'package:dio/dio.dart';
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/demo/demo_server.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/network/interceptors/chaos_interceptor.dart';
+import 'package:oxicloud/core/network/interceptors/retry_interceptor.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/core/sync/sync_models.dart';
+import 'package:oxicloud/data/datasources/remote/favorites_remote_datasource.dart';
+import 'package:oxicloud/data/datasources/remote/file_remote_datasource.dart';
+import 'package:oxicloud/data/datasources/remote/folder_remote_datasource.dart';
+import 'package:oxicloud/data/datasources/remote/trash_remote_datasource.dart';
+import 'package:oxicloud/data/repositories/favorites_repository_impl.dart';
+import 'package:oxicloud/data/repositories/file_repository_impl.dart';
+import 'package:oxicloud/data/repositories/folder_repository_impl.dart';
+import 'package:oxicloud/data/repositories/trash_repository_impl.dart';
+
+class _AlwaysOnline implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+
+  @override
+  Future<bool> checkConnectivity() async => true;
+
+  @override
+  void dispose() {}
+}
+
+/// Database that sometimes "crashes" between the server call and the
+/// journal write marking the operation done: the write never completes,
+/// as if the process had died there.
+class _CrashingDatabase extends AppDatabase {
+  final Random _random;
+  final double crashRate;
+  bool crashed = false;
+
+  _CrashingDatabase(this._random, this.crashRate)
+    : super(NativeDatabase.memory());
+
+  @override
+  Future<void> updateSyncOpStatus(
+    int id,
+    String status, {
+    String? errorMessage,
+  }) {
+    if (status == 'completed' && _random.nextDouble() < crashRate) {
+      crashed = true;
+      return Completer<void>().future;
+    }
+    return super.updateSyncOpStatus(id, status, errorMessage: errorMessage);
+  }
+}
+
+void main() {
+  for (final seed in [1, 7, 42]) {
+    test(
+      'queued operations apply exactly once under faults (seed $seed)',
+      () => _runChaos(seed),
+    );
+  }
+}
+
+/// Queue creates, deletes and renames, then drain the queue through a
+/// faulty network, restarting the engine whenever it crashes, and check
+/// the server ends up with each change applied exactly once.
+Future<void> _runChaos(int seed) async {
+  final server = DemoServer();
+  final chaos = ChaosInterceptor(
+    ChaosConfig(
+      timeoutRate: 0.1,
+      serverErrorRate: 0.1,
+      droppedResponseRate: 0.15,
+      seed: seed,
+    ),
+  );
+  final dio = Dio(BaseOptions(baseUrl: 'http://chaos.test/api'))
+    ..httpClientAdapter = DemoHttpAdapter(
+      server,
+      minLatency: Duration.zero,
+      maxLatency: Duration.zero,
+    );
+  dio.interceptors.addAll([
+    RetryInterceptor(
+      dio: dio,
+      maxRetries: 2,
+      baseDelay: const Duration(milliseconds: 2),
+    ),
+    chaos,
+  ]);
+  final db = _CrashingDatabase(Random(seed), 0.15);
+  addTearDown(db.close);
+  final connectivity = _AlwaysOnline();
+
+  List<Map<String, dynamic>> list(String path, [String? key]) {
+    final body = server.handle('GET', path, const {}, null).body;
+    final items = key == null ? body : (body as Map)[key];
+    return (items as List).cast<Map<String, dynamic>>();
+  }
+
+  final rootFiles = list('/files');
+  final photos = list('/folders').firstWhere((f) => f['name'] == 'Photos');
+  final photoFiles = list('/folders/${photos['id']}/listing', 'files');
+
+  Future<void> enqueue(String type, String itemId, Object payload) {
+    final now = DateTime.now();
+    return db.insertSyncOp(
+      SyncQueueTableCompanion.insert(
+        operationType: type,
+        itemId: itemId,
+        itemType: type.contains('Folder') ? 'folder' : 'file',
+        payload: json.encode(payload),
+        createdAt: now,
+        updatedAt: now,
+      ),
+    );
+  }
+
+  for (var i = 0; i < 20; i++) {
+    await enqueue('createFolder', 'new-$i', {'name': 'chaos-$i'});
+  }
+  final deleted = [rootFiles.first, ...photoFiles.take(5)];
+  for (final file in deleted) {
+    await enqueue('delete', file['id'] as String, {});
+  }
+  final renamed = photoFiles.skip(5).take(5).toList();
+  for (final file in renamed) {
+    await enqueue('rename', file['id'] as String, {
+      'new_name': 'renamed-${file['name']}',
+    });
+  }
+
+  // Each round is a fresh process: interrupted operations go back in
+  // the queue and a new engine picks them up.
+  var rounds = 0;
+  var crashes = 0;
+  while (true) {
+    await db.resetInProgressSyncOps();
+    if ((await db.getPendingSyncOps()).isEmpty) break;
+    expect(rounds++, lessThan(500), reason: 'queue never drained');
+
+    db.crashed = false;
+    final engine = SyncEngine(
+      db: db,
+      connectivity: connectivity,
+      fileRepo: FileRepositoryImpl(
+        remote: FileRemoteDatasource(dio),
+        db: db,
+        connectivity: connectivity,
+      ),
+      folderRepo: FolderRepositoryImpl(
+        remote: FolderRemoteDatasource(dio),
+        fileRemote: FileRemoteDatasource(dio),
+        db: db,
+        connectivity: connectivity,
+      ),
+      favoritesRepo: FavoritesRepositoryImpl(
+        remote: FavoritesRemoteDatasource(dio),
+      ),
+      trashRepo: TrashRepositoryImpl(remote: TrashRemoteDatasource(dio)),
+      retryPolicy: const SyncRetryPolicy(base: Duration.zero),
+    );
+    var finished = false;
+    unawaited(engine.sync().whenComplete(() => finished = true));
+    while (!finished && !db.crashed) {
+      await Future<void>.delayed(const Duration(milliseconds: 1));
+    }
+    if (db.crashed) crashes++;
+    engine.stop();
+  }
+
+  expect(chaos.injected, greaterThan(0));
+  printOnFailure('$rounds rounds, $crashes crashes');
+
+  final rootFolders = list('/folders');
+  for (var i = 0; i < 20; i++) {
+    expect(
+      rootFolders.where((f) => f['name'] == 'chaos-$i'),
+      hasLength(1),
+      reason: 'chaos-$i',
+    );
+  }
+
+  final trash = list('/trash');
+  for (final file in deleted) {
+    expect(
+      trash.where((t) => t['id'] == file['id']),
+      hasLength(1),
+      reason: '${file['name']}',
+    );
+  }
+
+  final remaining = list('/folders/${photos['id']}/listing', 'files');
+  for (final file in renamed) {
+    final now = remaining.firstWhere((f) => f['id'] == file['id']);
+    expect(now['name'], 'renamed-${file['name']}');
+  }
+}
@@ -0,0 +1,42 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/checksum_negotiation.dart';
+
+void main() {
+  test('the strongest algorithm both sides support wins', () {
+    expect(
+      ChecksumNegotiator.negotiate(['MD5', 'SHA-1', 'blake2b']),
+      ChecksumAlgorithm.sha1,
+    );
+    expect(
+      ChecksumNegotiator.negotiate(['sha256', 'md5']),
+      ChecksumAlgorithm.sha256,
+    );
+    expect(ChecksumNegotiator.negotiate(['blake2b']), isNull);
+    expect(ChecksumNegotiator.negotiate(const []), isNull);
+  });
+
+  test('a preference the server accepts beats a stronger algorithm', () {
+    expect(
+      ChecksumNegotiator.negotiate(
+        ['sha256', 'md5'],
+        preferred: ChecksumAlgorithm.md5,
+      ),
+      ChecksumAlgorithm.md5,
+    );
+    expect(
+      ChecksumNegotiator.negotiate(
+        ['sha256'],
+        preferred: ChecksumAlgorithm.sha1,
+      ),
+      ChecksumAlgorithm.sha256,
+    );
+  });
+
+  test('digests match the usual hex encoding', () {
+    expect(
+      ChecksumAlgorithm.sha1.digest('abc'.codeUnits),
+      'a9993e364706816aba3e25717850c26c9cd0d89d',
+    );
+  });
+}
@@ -0,0 +1,118 @@
+import 'dart:io';
+import 'dart:typed_data';
+
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/services/chunked_upload_service.dart';
+import 'package:oxicloud/data/datasources/remote/chunked_upload_datasource.dart';
+
+/// A server whose connection drops while [dropping] chunks are sent.
+class _Server extends Fake implements ChunkedUploadDatasource {
+  final received = <int>{};
+  final dropping = <int>{};
+  var cancelled = false;
+
+  @override
+  Future<UploadSession> createSession({
+    required String filename,
+    String? folderId,
+    String? contentType,
+    required int totalSize,
+    int? chunkSize,
+  }) async => UploadSession(
+    uploadId: 'u1',
+    chunkSize: chunkSize!,
+    totalChunks: (totalSize / chunkSize).ceil(),
+    expiresAt: DateTime(2100),
+  );
+
+  @override
+  Future<void> uploadChunk({
+    required String uploadId,
+    required int chunkIndex,
+    required Uint8List data,
+    String? checksum,
+    String? checksumAlgorithm,
+  }) async {
+    if (dropping.remove(chunkIndex)) throw const ServerUnreachableException();
+    received.add(chunkIndex);
+  }
+
+  @override
+  Future<({int offset, int total, int chunksComplete, int chunksTotal})>
+  getStatus(String uploadId) async => (
+    offset: 0,
+    total: 0,
+    chunksComplete: received.length,
+    chunksTotal: 3,
+  );
+
+  @override
+  Future<ChunkedUploadResult> complete(String uploadId) async =>
+      const ChunkedUploadResult(
+        fileId: 'f1',
+        filename: 'big.bin',
+        size: 30,
+        path: '/big.bin',
+      );
+
+  @override
+  Future<void> cancel(String uploadId) async => cancelled = true;
+}
+
+void main() {
+  late AppDatabase db;
+  late File file;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    final dir = await Directory.systemTemp.createTemp('chunked');
+    file = File(p.join(dir.path, 'big.bin'))
+      ..writeAsBytesSync(List.filled(30, 1));
+    addTearDown(() async {
+      await db.close();
+      await dir.delete(recursive: true);
+    });
+  });
+
+  test('a dropped chunk is tried again', () async {
+    final server = _Server()..dropping.add(1);
+    final uploads = ChunkedUploadService(
+      server,
+      db: db,
+      chunkSize: 10,
+      retryDelays: const [Duration.zero],
+    );
+
+    final result = await uploads.uploadFile(file: file, filename: 'big.bin');
+    expect(result.fileId, 'f1');
+    expect(server.received, {0, 1, 2});
+    expect(await db.getUploadSessions(), isEmpty);
+  });
+
+  test('uploads cut off keep their session and resume', () async {
+    final server = _Server()..dropping.add(1);
+    final uploads = ChunkedUploadService(
+      server,
+      db: db,
+      chunkSize: 10,
+      retryDelays: const [],
+    );
+
+    await expectLater(
+      uploads.uploadFile(file: file, filename: 'big.bin'),
+      throwsA(isA<UploadInterruptedException>()),
+    );
+    expect(server.cancelled, isFalse);
+    final entry = (await db.getUploadSessions()).single;
+    expect(entry.chunksUploaded, 1);
+
+    await uploads.resume(entry);
+    expect(server.received, {0, 1, 2});
+    expect(await db.getUploadSessions(), isEmpty);
+  });
+}
@@ -0,0 +1,125 @@
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/sync/conflict_resolver.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/core/sync/sync_models.dart';
+import 'package:oxicloud/data/datasources/remote/file_remote_datasource.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/favorites_repository.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/domain/repositories/trash_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+}
+
+/// The file was edited on the server: every conditional change fails.
+class _Files extends Fake implements FileRepository {
+  final ifMatches = <String?>[];
+
+  @override
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async {
+    ifMatches.add(ifMatch);
+    throw const PreconditionFailedException();
+  }
+}
+
+class _Folders extends Fake implements FolderRepository {}
+
+class _Favorites extends Fake implements FavoritesRepository {}
+
+class _Trash extends Fake implements TrashRepository {}
+
+/// Answers 412 and keeps the headers it was sent.
+class _Server implements HttpClientAdapter {
+  final headers = <Map<String, dynamic>>[];
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    headers.add(options.headers);
+    return ResponseBody.fromString('', 412);
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  test('a queued change is pinned to the cached version', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    await db.upsertFile(
+      FilesTableCompanion.insert(
+        id: 'f1',
+        name: 'a.txt',
+        path: '/a.txt',
+        size: 1,
+        mimeType: 'text/plain',
+        etag: const Value('"v1"'),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+    final files = _Files();
+    final engine = SyncEngine(
+      db: db,
+      connectivity: _Online(),
+      fileRepo: files,
+      folderRepo: _Folders(),
+      favoritesRepo: _Favorites(),
+      trashRepo: _Trash(),
+    );
+    addTearDown(engine.dispose);
+
+    await engine.enqueue(
+      SyncTask(
+        id: 'r',
+        operation: SyncOperation.rename,
+        entityType: 'file',
+        entityId: 'f1',
+        payload: const {'new_name': 'b.txt'},
+        createdAt: DateTime.now(),
+      ),
+    );
+    await engine.sync();
+
+    expect(files.ifMatches, ['"v1"']);
+    // Changed on the server: the user decides at once, without retries.
+    expect((await db.getSyncOp(1))!.status, 'failed');
+    final conflict = (await db.getUnresolvedConflicts()).single;
+    expect(conflict.itemId, 'f1');
+    expect(conflict.conflictType, ConflictResolver.modifiedRemotely);
+  });
+
+  test('If-Match is sent and a 412 reads as a precondition failure', () async {
+    final server = _Server();
+    final remote = FileRemoteDatasource(Dio()..httpClientAdapter = server);
+
+    await expectLater(
+      remote.deleteFile('f1', ifMatch: '"v1"'),
+      throwsA(isA<PreconditionFailedException>()),
+    );
+    expect(server.headers.single['If-Match'], '"v1"');
+  });
+}
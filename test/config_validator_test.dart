@@ -0,0 +1,49 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/config/app_config.dart';
+import 'package:oxicloud/core/config/config_validator.dart';
+
+void main() {
+  List<String> fields(AppConfig config) =>
+      ConfigValidator.validate(config).map((e) => e.field).toList();
+
+  test('defaults are valid, with or without a server', () {
+    expect(fields(const AppConfig(serverUrl: '')), isEmpty);
+    expect(
+      fields(const AppConfig(serverUrl: 'https://cloud.example.com')),
+      isEmpty,
+    );
+  });
+
+  test('server URLs need an http scheme and a host', () {
+    expect(ConfigValidator.validateServerUrl(''), isNotNull);
+    expect(ConfigValidator.validateServerUrl('cloud.example.com'), isNotNull);
+    expect(ConfigValidator.validateServerUrl('https://'), isNotNull);
+    expect(ConfigValidator.validateServerUrl('https://a.b/?x=1'), isNotNull);
+    expect(ConfigValidator.validateServerUrl('http://10.0.0.2:8086'), isNull);
+  });
+
+  test('out of range and inconsistent values are reported per field', () {
+    expect(
+      fields(
+        const AppConfig(
+          serverUrl: '',
+          chunkSizeBytes: 1024,
+          maxConcurrentUploads: 0,
+          ledgerSampleRate: 1.5,
+        ),
+      ),
+      ['chunkSizeBytes', 'maxConcurrentUploads', 'ledgerSampleRate'],
+    );
+    expect(
+      fields(
+        const AppConfig(
+          serverUrl: '',
+          chunkThresholdBytes: 1024 * 1024,
+          openFolderPollInterval: Duration(hours: 7),
+        ),
+      ),
+      ['chunkThresholdBytes', 'openFolderPollInterval'],
+    );
+  });
+}
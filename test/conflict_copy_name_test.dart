@@ -0,0 +1,27 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/conflict_resolver.dart';
+
+void main() {
+  String name(String original) => ConflictCopyNamer.name(
+    original,
+    device: 'laptop',
+    at: DateTime(2024, 5, 1, 13, 45),
+  );
+
+  test('the marker goes before the extension', () {
+    expect(
+      name('report.pdf'),
+      'report (conflicted copy laptop 2024-05-01).pdf',
+    );
+    expect(
+      name('archive.tar.gz'),
+      'archive.tar (conflicted copy laptop 2024-05-01).gz',
+    );
+  });
+
+  test('names without an extension get the marker at the end', () {
+    expect(name('Makefile'), 'Makefile (conflicted copy laptop 2024-05-01)');
+    expect(name('.env'), '.env (conflicted copy laptop 2024-05-01)');
+  });
+}
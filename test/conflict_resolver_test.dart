@@ -0,0 +1,251 @@
+import 'dart:convert';
+
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/sync/conflict_resolver.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+FileEntity _file(String id, String name, {String? etag, DateTime? at}) =>
+    FileEntity(
+      id: id,
+      name: name,
+      path: '/Docs/$name',
+      size: 1,
+      mimeType: 'text/plain',
+      folderId: 'd1',
+      etag: etag,
+      createdAt: DateTime(2026),
+      modifiedAt: at ?? DateTime(2026),
+    );
+
+/// The server's `f1` was last edited at [modifiedAt] and is now `"v2"`.
+/// Names in [taken] are in use in its folder.
+class _Files extends Fake implements FileRepository {
+  var modifiedAt = DateTime(2026, 3);
+  var offline = false;
+  final taken = <String>{};
+  final copiedTo = <String>[];
+  final deleted = <String>[];
+
+  @override
+  Future<FileEntity> getFile(String id) async {
+    if (offline) throw const ServerUnreachableException();
+    return _file(id, 'a.txt', etag: '"v2"', at: modifiedAt);
+  }
+
+  @override
+  Future<FileEntity> copyFile(String id, String targetFolderId) async {
+    copiedTo.add(targetFolderId);
+    return _file('copy${copiedTo.length}', 'a.txt');
+  }
+
+  @override
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async {
+    if (!taken.add(newName)) throw const ConflictException();
+    return _file(id, newName);
+  }
+
+  @override
+  Future<void> deleteFile(String id, {String? ifMatch}) async =>
+      deleted.add(id);
+}
+
+void main() {
+  late AppDatabase db;
+  late _Files files;
+  late ConflictResolver resolver;
+  late List<int> asked;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    files = _Files();
+    resolver = ConflictResolver(db, files: files, deviceName: 'laptop');
+    asked = [];
+    resolver.needsUser.listen((c) => asked.add(c.id));
+    addTearDown(() async {
+      resolver.dispose();
+      await db.close();
+    });
+    // Cached before the server edit.
+    await db.upsertFile(
+      FilesTableCompanion.insert(
+        id: 'f1',
+        name: 'a.txt',
+        path: '/Docs/a.txt',
+        size: 1,
+        mimeType: 'text/plain',
+        folderId: const Value('d1'),
+        etag: const Value('"v1"'),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+  });
+
+  /// Record a conflict over [operationType] of `f1`, queued in February.
+  Future<int> conflict(
+    String operationType,
+    Map<String, dynamic> payload, {
+    String type = ConflictResolver.modifiedRemotely,
+  }) => db.insertSyncConflict(
+    SyncConflictsTableCompanion.insert(
+      itemId: 'f1',
+      itemType: 'file',
+      operationType: operationType,
+      conflictType: Value(type),
+      payload: json.encode(payload),
+      createdAt: DateTime.now(),
+      queuedAt: Value(DateTime(2026, 2)),
+    ),
+  );
+
+  Future<String?> resolutionOf(int id) async =>
+      (await db.getConflictById(id))!.resolution;
+
+  const rename = {'new_name': 'b.txt', 'if_match': '"v1"'};
+
+  test('last writer wins weighs the server edit against the queue', () async {
+    await resolver.addRule(resolution: ConflictResolution.lastWriterWins);
+
+    // Edited on the server in March, after the change queued in February.
+    final edited = await conflict('rename', rename);
+    expect(await resolver.autoResolve(edited), isNotNull);
+    expect(await resolutionOf(edited), 'remote');
+    expect(await db.getPendingSyncOps(), isEmpty);
+
+    // A network failure has no server edit: the cached January copy is
+    // older than the local change.
+    final failed = await conflict('move', {
+      'target_folder_id': 'd2',
+    }, type: 'retry_exhausted');
+    await resolver.autoResolve(failed);
+    expect(await resolutionOf(failed), 'local');
+    expect((await db.getPendingSyncOps()).single.operationType, 'move');
+  });
+
+  test('a kept local change is pinned to the server version', () async {
+    await resolver.addRule(resolution: ConflictResolution.local);
+
+    await resolver.autoResolve(await conflict('rename', rename));
+    files.offline = true;
+    await resolver.autoResolve(
+      await conflict('move', {'target_folder_id': 'd2', 'if_match': '"v1"'}),
+    );
+
+    final payloads = {
+      for (final op in await db.getPendingSyncOps())
+        op.operationType: json.decode(op.payload),
+    };
+    expect(payloads['rename'], {'new_name': 'b.txt', 'if_match': '"v2"'});
+    expect(payloads['move'], {'target_folder_id': 'd2'});
+  });
+
+  test('an operation is requeued at most once a day', () async {
+    await resolver.addRule(resolution: ConflictResolution.local);
+
+    await resolver.autoResolve(await conflict('rename', rename));
+    final again = await conflict('rename', rename);
+    expect(await resolver.autoResolve(again), isNull);
+    await pumpEventQueue();
+
+    expect(await resolutionOf(again), isNull);
+    expect(asked, [again]);
+    expect(await db.getPendingSyncOps(), hasLength(1));
+  });
+
+  test('the audit trail names what settled a conflict', () async {
+    await resolver.addRule(
+      pathPrefix: '/Docs',
+      namePattern: '*.txt',
+      resolution: ConflictResolution.remote,
+    );
+    await resolver.addRule(resolution: ConflictResolution.remote);
+
+    final scoped = await conflict('rename', rename);
+    await resolver.autoResolve(scoped);
+    expect((await db.getConflictById(scoped))!.resolvedBy, 'rule /Docs *.txt');
+
+    await resolver.deleteRule((await resolver.listRules()).first.id);
+    final global = await conflict('rename', rename);
+    await resolver.autoResolve(global);
+    expect((await db.getConflictById(global))!.resolvedBy, 'policy');
+  });
+
+  test('ask me leaves the conflict to the user', () async {
+    await resolver.addRule(resolution: ConflictResolution.askUser);
+    await resolver.addRule(resolution: ConflictResolution.remote);
+
+    final id = await conflict('rename', rename);
+    expect(await resolver.autoResolve(id), isNull);
+    await pumpEventQueue();
+    expect(await resolutionOf(id), isNull);
+    expect(asked, [id]);
+  });
+
+  group('keep both', () {
+    final copyName = ConflictCopyNamer.name(
+      'b.txt',
+      device: 'laptop',
+      at: DateTime.now(),
+    );
+
+    setUp(() => resolver.addRule(resolution: ConflictResolution.keepBoth));
+
+    test('a rename goes to a copy in the same folder', () async {
+      final id = await conflict('rename', rename);
+      await resolver.autoResolve(id);
+
+      expect(files.copiedTo, ['d1']);
+      final copy = (await resolver.outstandingCopies()).single;
+      expect(copy.copyName, copyName);
+      expect(copy.originalId, 'f1');
+      expect(copy.conflictId, id);
+      expect(await resolutionOf(id), 'keepBoth');
+      expect(await db.getPendingSyncOps(), isEmpty);
+    });
+
+    test('a taken copy name gets a number', () async {
+      files.taken.add(copyName);
+      await resolver.autoResolve(await conflict('rename', rename));
+
+      expect(
+        (await resolver.outstandingCopies()).single.copyName,
+        ConflictCopyNamer.name(
+          'b.txt',
+          device: 'laptop',
+          at: DateTime.now(),
+          number: 2,
+        ),
+      );
+      expect(files.deleted, isEmpty);
+    });
+
+    test('without a free name the copy is removed', () async {
+      for (var n = 1; n <= 10; n++) {
+        files.taken.add(
+          ConflictCopyNamer.name(
+            'b.txt',
+            device: 'laptop',
+            at: DateTime.now(),
+            number: n,
+          ),
+        );
+      }
+      final id = await conflict('rename', rename);
+      expect(await resolver.autoResolve(id), isNull);
+
+      expect(files.deleted, ['copy1']);
+      expect(await resolver.outstandingCopies(), isEmpty);
+      expect(await resolutionOf(id), isNull);
+    });
+  });
+}
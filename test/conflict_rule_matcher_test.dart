@@ -0,0 +1,54 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/conflict_resolver.dart';
+
+void main() {
+  var nextId = 0;
+  ConflictRulesTableData rule({
+    String? pathPrefix,
+    String? namePattern,
+    String resolution = 'local',
+  }) {
+    final id = ++nextId;
+    return ConflictRulesTableData(
+      id: id,
+      pathPrefix: pathPrefix,
+      namePattern: namePattern,
+      resolution: resolution,
+      position: id,
+      createdAt: DateTime(2024),
+    );
+  }
+
+  test('name patterns match the file name only', () {
+    final tmp = rule(namePattern: '*.tmp');
+    expect(ConflictRuleMatcher.matches(tmp, '/Docs/a.tmp'), isTrue);
+    expect(ConflictRuleMatcher.matches(tmp, '/Docs/a.tmp.txt'), isFalse);
+    expect(ConflictRuleMatcher.matches(tmp, '/x.tmp/a.txt'), isFalse);
+    expect(
+      ConflictRuleMatcher.matches(rule(namePattern: 'v?.md'), '/v2.md'),
+      isTrue,
+    );
+  });
+
+  test('path prefixes match whole folder names', () {
+    final team = rule(pathPrefix: '/Shared/TeamDocs/');
+    expect(ConflictRuleMatcher.matches(team, '/Shared/TeamDocs'), isTrue);
+    expect(ConflictRuleMatcher.matches(team, '/Shared/TeamDocs/a'), isTrue);
+    expect(ConflictRuleMatcher.matches(team, '/Shared/TeamDocs2/a'), isFalse);
+  });
+
+  test('the first matching rule wins', () {
+    final rules = [
+      rule(pathPrefix: '/Shared', namePattern: '*.tmp', resolution: 'remote'),
+      rule(namePattern: '*.tmp'),
+      rule(),
+    ];
+    ConflictRulesTableData? match(String path) =>
+        ConflictRuleMatcher.firstMatch(rules, path);
+    expect(match('/Shared/a.tmp'), same(rules[0]));
+    expect(match('/Home/a.tmp'), same(rules[1]));
+    expect(match('/Home/a.txt'), same(rules[2]));
+  });
+}
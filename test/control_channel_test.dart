@@ -0,0 +1,57 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/services/control_channel.dart';
+
+void main() {
+  late Directory dir;
+  late String infoPath;
+  late ControlChannel channel;
+
+  setUp(() async {
+    dir = Directory.systemTemp.createTempSync('oxicloud-control');
+    infoPath = p.join(dir.path, 'control.json');
+    channel = ControlChannel(
+      infoPath: infoPath,
+      commands: {'status': () async => 'idle, 0 pending'},
+    );
+    await channel.start();
+    addTearDown(() async {
+      await channel.stop();
+      dir.deleteSync(recursive: true);
+    });
+  });
+
+  test('switches select a command', () {
+    expect(ControlChannel.commandIn(['--status']), 'status');
+    expect(ControlChannel.commandIn(['--demo', '--sync']), 'sync');
+    expect(ControlChannel.commandIn(['--demo']), isNull);
+  });
+
+  test('the running instance answers', () async {
+    final reply = await ControlChannel.send(infoPath, 'status');
+    expect(reply?.ok, isTrue);
+    expect(reply?.message, 'idle, 0 pending');
+
+    final unknown = await ControlChannel.send(infoPath, 'sync');
+    expect(unknown?.ok, isFalse);
+  });
+
+  test('requests without the token are refused', () async {
+    final info =
+        json.decode(File(infoPath).readAsStringSync()) as Map<String, dynamic>;
+    File(infoPath).writeAsStringSync(
+      json.encode({...info, 'token': 'guess'}),
+    );
+    final reply = await ControlChannel.send(infoPath, 'status');
+    expect(reply?.ok, isFalse);
+  });
+
+  test('nothing answers once stopped', () async {
+    await channel.stop();
+    expect(await ControlChannel.send(infoPath, 'status'), isNull);
+  });
+}
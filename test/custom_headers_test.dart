@@ -0,0 +1,98 @@
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/interceptors/custom_headers_interceptor.dart';
+
+/// Answers every request with an empty 200, remembering its headers.
+class _RecordingAdapter implements HttpClientAdapter {
+  Map<String, dynamic> headers = {};
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    headers = options.headers;
+    return ResponseBody.fromString('', 200);
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  test('header names and values are validated', () {
+    expect(CustomHeaders.validateName('X-Proxy-Token'), isNull);
+    expect(CustomHeaders.validateName('X Token'), isNotNull);
+    expect(CustomHeaders.validateName('authorization'), isNotNull);
+    expect(CustomHeaders.validateValue('abc def'), isNull);
+    expect(CustomHeaders.validateValue('abc\r\nX-Evil: 1'), isNotNull);
+  });
+
+  test('every request carries the headers, minus invalid ones', () async {
+    final adapter = _RecordingAdapter();
+    final dio = Dio()
+      ..httpClientAdapter = adapter
+      ..interceptors.add(
+        CustomHeadersInterceptor(
+          const CustomHeaders(
+            headers: {'X-Proxy-Token': 'secret', 'X-Bad': 'a\nb'},
+            userAgent: 'OxiCloud/1.0',
+          ),
+        ),
+      );
+
+    await dio.get('https://cloud.test/api/files');
+    expect(adapter.headers['X-Proxy-Token'], 'secret');
+    expect(adapter.headers['User-Agent'], 'OxiCloud/1.0');
+    expect(adapter.headers.containsKey('X-Bad'), isFalse);
+  });
+
+  test('headers a request sets itself are kept', () async {
+    final adapter = _RecordingAdapter();
+    final dio = Dio()
+      ..httpClientAdapter = adapter
+      ..interceptors.add(
+        CustomHeadersInterceptor(
+          const CustomHeaders(
+            headers: {'X-Proxy-Token': 'secret', 'If-Match': '"stale"'},
+            userAgent: 'OxiCloud/1.0',
+          ),
+        ),
+      );
+
+    await dio.get(
+      'https://cloud.test/api/files',
+      options: Options(
+        headers: {'x-proxy-token': 'mine', 'User-Agent': 'laptop'},
+      ),
+    );
+    expect(adapter.headers['X-Proxy-Token'], 'mine');
+    expect(adapter.headers['User-Agent'], 'laptop');
+    expect(adapter.headers.containsKey('If-Match'), isFalse);
+    expect(CustomHeaders.validateName('Range'), isNotNull);
+  });
+
+  test('logged headers hide credentials and custom values', () {
+    final redacted = CustomHeaders.redact(
+      {'Authorization': 'Bearer t', 'X-Proxy-Token': 's', 'Accept': 'json'},
+      custom: {'x-proxy-token'},
+    );
+    expect(redacted, {
+      'Authorization': '<redacted>',
+      'X-Proxy-Token': '<redacted>',
+      'Accept': 'json',
+    });
+  });
+
+  test('saved headers round-trip and bad data is ignored', () {
+    const saved = CustomHeaders(headers: {'X-A': '1'}, userAgent: 'ua');
+    final loaded = CustomHeaders.decode(saved.encode());
+    expect(loaded.headers, {'X-A': '1'});
+    expect(loaded.userAgent, 'ua');
+    expect(CustomHeaders.decode('not json').isEmpty, isTrue);
+  });
+}
@@ -0,0 +1,174 @@
+import 'dart:convert';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/dav_multistatus.dart';
+
+const _nextcloud = '''<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:" xmlns:s="http://sabredav.org/ns"
+    xmlns:oc="http://owncloud.org/ns" xmlns:nc="http://nextcloud.org/ns">
+  <d:response>
+    <d:href>/remote.php/dav/files/me/Photos/</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:resourcetype><d:collection/></d:resourcetype>
+        <d:getetag>&quot;65f1a2&quot;</d:getetag>
+        <d:quota-used-bytes>300</d:quota-used-bytes>
+        <d:quota-available-bytes>-3</d:quota-available-bytes>
+        <oc:fileid>42</oc:fileid>
+        <oc:permissions>RGDNVCK</oc:permissions>
+        <oc:size>1024</oc:size>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+    <d:propstat>
+      <d:prop><d:getcontentlength/></d:prop>
+      <d:status>HTTP/1.1 404 Not Found</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/remote.php/dav/files/me/Photos/Tom%20%26%20Jerry.jpg</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:resourcetype/>
+        <d:getcontentlength>1024</d:getcontentlength>
+        <d:getcontenttype>image/jpeg</d:getcontenttype>
+        <d:getlastmodified>Fri, 16 Oct 2026 10:00:00 GMT</d:getlastmodified>
+        <nc:has-preview>true</nc:has-preview>
+        <oc:checksums>
+          <oc:checksum>SHA1:A94A8FE5 MD5:098F6BCD</oc:checksum>
+        </oc:checksums>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+  </d:response>
+</d:multistatus>''';
+
+/// ownCloud declares DAV: as the default namespace.
+const _owncloud = '''<?xml version="1.0" encoding="utf-8"?>
+<multistatus xmlns="DAV:" xmlns:x1="http://owncloud.org/ns">
+  <response>
+    <href>/remote.php/webdav/a&amp;b.txt</href>
+    <propstat>
+      <prop>
+        <getetag><![CDATA["v<1>"]]></getetag>
+        <x1:fileid>00000007ocabc</x1:fileid>
+      </prop>
+      <status>HTTP/1.1 200 OK</status>
+    </propstat>
+  </response>
+</multistatus>''';
+
+/// Apache mod_dav picks its own prefixes and redeclares them per element.
+const _generic = '''<?xml version="1.0" encoding="utf-8"?>
+<D:multistatus xmlns:D="DAV:" xmlns:ns0="DAV:">
+<D:response xmlns:lp1="DAV:" xmlns:lp2="http://apache.org/dav/props/">
+<D:href>/dav/report.pdf</D:href>
+<D:propstat>
+<D:prop>
+<lp1:getcontentlength>2048</lp1:getcontentlength>
+<lp1:getetag>"800-5f3a"</lp1:getetag>
+<lp2:executable>F</lp2:executable>
+<D:getcontenttype>application/pdf</D:getcontenttype>
+</D:prop>
+<D:status>HTTP/1.1 200 OK</D:status>
+</D:propstat>
+</D:response>
+</D:multistatus>''';
+
+/// A sync-collection report: one member changed, one went away.
+const _report = '''<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:">
+  <d:response>
+    <d:href>/webdav/Docs/a.txt</d:href>
+    <d:propstat>
+      <d:prop><d:getetag>"v2"</d:getetag></d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/webdav/Docs/b.txt</d:href>
+    <d:status>HTTP/1.1 404 Not Found</d:status>
+  </d:response>
+  <d:sync-token>http://sabre.io/ns/sync/7</d:sync-token>
+</d:multistatus>''';
+
+void main() {
+  test('Nextcloud responses', () {
+    final [folder, file] = MultistatusParser.parse(_nextcloud);
+    expect(folder.href, '/remote.php/dav/files/me/Photos/');
+    expect(folder.isCollection, isTrue);
+    expect(folder.etag, '"65f1a2"');
+    expect(folder.quotaUsedBytes, 300);
+    expect(folder.quotaAvailableBytes, -3);
+    expect(folder.fileId, '42');
+    expect(folder.contentLength, isNull);
+    expect(folder.size, 1024);
+    expect(folder.permissions!.canAddFiles, isTrue);
+    expect(folder.permissions!.canWrite, isFalse);
+    expect(folder.checksums, isEmpty);
+
+    expect(file.href, '/remote.php/dav/files/me/Photos/Tom & Jerry.jpg');
+    expect(file.isCollection, isFalse);
+    expect(file.contentLength, 1024);
+    expect(file.contentType, 'image/jpeg');
+    expect(file.lastModified, DateTime.utc(2026, 10, 16, 10));
+    expect(file.properties['{${DavNamespace.nextcloud}}has-preview'], 'true');
+    expect(file.checksums, {'SHA1': 'a94a8fe5', 'MD5': '098f6bcd'});
+  });
+
+  test('ownCloud responses', () {
+    final file = MultistatusParser.parse(_owncloud).single;
+    expect(file.href, '/remote.php/webdav/a&b.txt');
+    expect(file.etag, '"v<1>"');
+    expect(file.fileId, '00000007ocabc');
+  });
+
+  test('generic DAV responses', () {
+    final file = MultistatusParser.parse(_generic).single;
+    expect(file.href, '/dav/report.pdf');
+    expect(file.contentLength, 2048);
+    expect(file.etag, '"800-5f3a"');
+    expect(file.contentType, 'application/pdf');
+    expect(file.properties['{http://apache.org/dav/props/}executable'], 'F');
+  });
+
+  test('streamed bodies split anywhere parse the same', () async {
+    final bytes = utf8.encode(_nextcloud);
+    final chunks = [
+      for (var i = 0; i < bytes.length; i += 7)
+        bytes.sublist(i, i + 7 > bytes.length ? bytes.length : i + 7),
+    ];
+    final streamed = await MultistatusParser.parseStream(
+      Stream.fromIterable(chunks),
+    );
+    expect(
+      [for (final r in streamed) r.properties],
+      [for (final r in MultistatusParser.parse(_nextcloud)) r.properties],
+    );
+  });
+
+  test('sync-collection reports', () async {
+    final report = await MultistatusParser.parseSyncReport(
+      Stream.value(utf8.encode(_report)),
+    );
+    expect(report.token, 'http://sabre.io/ns/sync/7');
+    final [changed, removed] = report.changes;
+    expect(changed.etag, '"v2"');
+    expect(changed.isRemoved, isFalse);
+    expect(removed.href, '/webdav/Docs/b.txt');
+    expect(removed.isRemoved, isTrue);
+
+    await expectLater(
+      MultistatusParser.parseSyncReport(Stream.value(utf8.encode(_owncloud))),
+      throwsFormatException,
+    );
+  });
+
+  test('malformed bodies are format errors', () {
+    expect(
+      () => MultistatusParser.parse('<d:response></d:multistatus>'),
+      throwsFormatException,
+    );
+  });
+}
@@ -0,0 +1,94 @@
+import 'dart:io';
+
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/database/db_lease_service.dart';
+
+void main() {
+  late Directory dir;
+  late AppDatabase first;
+  late AppDatabase second;
+
+  setUp(() async {
+    dir = Directory.systemTemp.createTempSync('oxicloud-lease');
+    final file = File(p.join(dir.path, 'oxicloud.db'));
+    // Two connections to one file, as two processes would have.
+    first = AppDatabase(NativeDatabase(file));
+    await first.getDbLease();
+    second = AppDatabase(NativeDatabase(file));
+    addTearDown(() async {
+      await first.close();
+      await second.close();
+      dir.deleteSync(recursive: true);
+    });
+  });
+
+  test('a live lease keeps a second process out', () async {
+    final lease = DbLeaseService(first);
+    expect(await lease.acquire(), isNull);
+
+    final other = DbLeaseService(second);
+    final holder = await other.acquire();
+    expect(holder?.ownerId, lease.ownerId);
+    expect(other.foreignOwner?.pid, pid);
+
+    await lease.release();
+    expect(await other.acquire(), isNull);
+  });
+
+  test('a stale lease is taken over and its holder finds out', () async {
+    final lease = DbLeaseService(first);
+    expect(await lease.acquire(), isNull);
+
+    final other = DbLeaseService(second, staleAfter: Duration.zero);
+    expect(await other.acquire(), isNull);
+
+    var notified = false;
+    lease.addListener(() => notified = true);
+    await lease.check();
+    expect(notified, isTrue);
+    expect(lease.foreignOwner?.ownerId, other.ownerId);
+
+    // The old holder no longer renews or releases the new lease.
+    await lease.release();
+    expect((await second.getDbLease())?.ownerId, other.ownerId);
+  });
+
+  test('writes from another connection are detected', () async {
+    final lease = DbLeaseService(first);
+    expect(await lease.acquire(), isNull);
+
+    // Renewing the lease is a write of our own.
+    await lease.check();
+    await lease.check();
+    expect(lease.foreignWritesDetected, isFalse);
+
+    await second.upsertDbLease(
+      DbLeaseTableCompanion.insert(
+        ownerId: lease.ownerId,
+        pid: pid,
+        hostname: 'elsewhere',
+        acquiredAt: DateTime.now(),
+        heartbeatAt: DateTime.now(),
+      ),
+    );
+    await lease.check();
+    expect(lease.foreignWritesDetected, isTrue);
+  });
+
+  test('the lock file names its owner while held', () async {
+    final lockPath = p.join(dir.path, 'oxicloud.db.lock');
+    final lease = DbLeaseService(first, lockPath: lockPath);
+    expect(await lease.acquire(), isNull);
+    expect(
+      File('$lockPath.owner').readAsStringSync(),
+      contains(lease.ownerId),
+    );
+
+    await lease.release();
+    expect(File('$lockPath.owner').existsSync(), isFalse);
+  });
+}
@@ -0,0 +1,95 @@
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/demo/demo_server.dart';
+
+void main() {
+  late DemoServer server;
+
+  setUp(() {
+    server = DemoServer();
+    addTearDown(server.dispose);
+  });
+
+  ({int status, Object? body}) call(
+    String method,
+    String path, {
+    Map<String, dynamic> query = const {},
+    Object? data,
+  }) => server.handle(method, path, query, data);
+
+  List<Map<String, dynamic>> rootFolders() =>
+      (call('GET', '/folders').body! as List).cast<Map<String, dynamic>>();
+
+  String folderId(String name) =>
+      rootFolders().firstWhere((f) => f['name'] == name)['id'] as String;
+
+  test('starts with a seeded tree', () {
+    expect(rootFolders().map((f) => f['name']), [
+      'Documents',
+      'Music',
+      'Photos',
+      'Projects',
+    ]);
+    final docs = folderId('Documents');
+    final listing = call('GET', '/folders/$docs/listing').body! as Map;
+    expect(listing['files'] as List, hasLength(3));
+  });
+
+  test('folders keep unique names and deletions go to the trash', () {
+    final taken = call('POST', '/folders', data: {'name': 'Music'});
+    expect(taken.status, 409);
+
+    final id = folderId('Music');
+    final renamed = call('PUT', '/folders/$id/rename', data: {'name': 'Audio'});
+    expect(renamed.status, 200);
+    expect(call('DELETE', '/folders/$id').status, 204);
+    expect(rootFolders().map((f) => f['name']), isNot(contains('Audio')));
+    final trash = call('GET', '/trash').body! as List;
+    expect((trash.single as Map)['name'], 'Audio');
+  });
+
+  test('unknown endpoints answer empty or not found', () {
+    expect(call('GET', '/shares').body, isEmpty);
+    expect(call('POST', '/shares').status, 404);
+  });
+
+  test('the script adds files over time', () async {
+    server.startScript(interval: const Duration(milliseconds: 10));
+    await Future<void>.delayed(const Duration(milliseconds: 35));
+    server.dispose();
+    final docs = folderId('Documents');
+    final files = call('GET', '/files', query: {'folder_id': docs}).body!;
+    expect((files as List).map((f) => f['name']), contains('Scan 001.pdf'));
+  });
+
+  test('the adapter serves JSON and file content through Dio', () async {
+    final dio = Dio(BaseOptions(baseUrl: 'https://demo.invalid/api'))
+      ..httpClientAdapter = DemoHttpAdapter(
+        server,
+        minLatency: Duration.zero,
+        maxLatency: Duration.zero,
+      );
+
+    final me = await dio.get<Map<String, dynamic>>('/auth/me');
+    expect(me.data?['username'], 'demo');
+
+    final files = await dio.get<List<dynamic>>('/files');
+    final welcome = (files.data!.single as Map)['id'] as String;
+    final content = await dio.get<List<int>>(
+      '/files/$welcome',
+      options: Options(responseType: ResponseType.bytes),
+    );
+    expect(
+      String.fromCharCodes(Uint8List.fromList(content.data!)),
+      startsWith('# Welcome to OxiCloud'),
+    );
+
+    await expectLater(
+      dio.get<void>('/files/missing/metadata'),
+      throwsA(isA<DioException>()),
+    );
+  });
+}
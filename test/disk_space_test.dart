@@ -0,0 +1,69 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/platform/disk_space.dart';
+import 'package:oxicloud/core/sync/sync_events.dart';
+import 'package:oxicloud/core/sync/transfer_monitor.dart';
+
+void main() {
+  test('downloads that do not fit with the reserve are refused', () async {
+    int? free = 1000;
+    final space = DiskSpace(freeBytes: (_) async => free, reserveBytes: 100);
+
+    await space.ensureRoom('/data', 900);
+    await expectLater(
+      space.ensureRoom('/data', 901),
+      throwsA(
+        isA<DiskFullException>()
+            .having((e) => e.required, 'required', 901)
+            .having((e) => e.available, 'available', 1000),
+      ),
+    );
+
+    free = null;
+    await space.ensureRoom('/data', 1 << 40);
+  });
+
+  test('pending download bytes count open downloads only', () {
+    final monitor = TransferMonitor();
+    final a = monitor.begin(
+      name: 'a',
+      direction: TransferDirection.download,
+      totalBytes: 100,
+    );
+    monitor.update(a, 40);
+    final b = monitor.begin(
+      name: 'b',
+      direction: TransferDirection.download,
+      totalBytes: 50,
+    );
+    monitor.pause(b);
+    monitor.begin(
+      name: 'c',
+      direction: TransferDirection.upload,
+      totalBytes: 500,
+    );
+    final d = monitor.begin(
+      name: 'd',
+      direction: TransferDirection.download,
+      totalBytes: 70,
+    );
+    monitor.complete(d);
+
+    expect(monitor.pendingDownloadBytes, 110);
+  });
+
+  test('disk full message says how much to free', () {
+    final event = DiskFull(
+      path: '/home/me/Downloads',
+      required: 5 * 1024 * 1024,
+      available: 2 * 1024 * 1024 + 1,
+      paused: 2,
+    );
+    expect(
+      event.message,
+      'Not enough disk space for downloads in /home/me/Downloads. '
+      'Free up at least 3 MB, then resume the 2 paused downloads.',
+    );
+  });
+}
@@ -0,0 +1,42 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/error_budget.dart';
+
+void main() {
+  test('backs off while over budget and recovers once under it', () {
+    final budget = ErrorBudget(window: 10, minRequests: 10, maxBackoff: 4);
+    for (var i = 0; i < 10; i++) {
+      budget.record(ok: i.isEven);
+    }
+    expect(budget.failureRate, 0.5);
+
+    expect(budget.endPass(), isTrue);
+    expect(budget.backoff, 2);
+    budget.record(ok: false);
+    budget.endPass();
+    budget.record(ok: false);
+    budget.endPass();
+    expect(budget.backoff, 4);
+
+    for (var i = 0; i < 8; i++) {
+      budget.record(ok: true);
+    }
+    expect(budget.endPass(), isTrue);
+    expect(budget.backoff, 1);
+  });
+
+  test('decides nothing on too few requests or an idle pass', () {
+    final budget = ErrorBudget(minRequests: 5);
+    budget.record(ok: false);
+    expect(budget.endPass(), isFalse);
+    expect(budget.isBackedOff, isFalse);
+
+    for (var i = 0; i < 4; i++) {
+      budget.record(ok: false);
+    }
+    budget.endPass();
+    expect(budget.backoff, 2);
+    expect(budget.endPass(), isFalse);
+    expect(budget.backoff, 2);
+  });
+}
@@ -0,0 +1,62 @@
+import 'package:drift/drift.dart';
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+
+void main() {
+  late AppDatabase db;
+
+  setUp(() => db = AppDatabase(NativeDatabase.memory()));
+  tearDown(() => db.close());
+
+  FilesTableCompanion file(String id, {DateTime? syncedAt}) {
+    return FilesTableCompanion.insert(
+      id: id,
+      name: '$id.txt',
+      path: '/$id.txt',
+      size: 10,
+      mimeType: 'text/plain',
+      createdAt: DateTime.utc(2026),
+      modifiedAt: DateTime.utc(2026, 1, 2, 3, 4, 5),
+      syncedAt: Value(syncedAt),
+    );
+  }
+
+  test('files are listed with their sync state', () async {
+    await db.upsertFiles([
+      file('a', syncedAt: DateTime.utc(2026)),
+      file('b', syncedAt: DateTime.utc(2026)),
+      file('c'),
+    ]);
+    await db.insertSyncOp(
+      SyncQueueTableCompanion.insert(
+        operationType: 'rename',
+        itemId: 'b',
+        itemType: 'file',
+        payload: '{}',
+        createdAt: DateTime.now(),
+        updatedAt: DateTime.now(),
+      ),
+    );
+
+    final rows = await db
+        .customSelect('SELECT * FROM external_files_v1 ORDER BY id')
+        .get();
+    expect(rows.map((r) => r.read<String>('state')), [
+      'synced',
+      'pending',
+      'remote',
+    ]);
+    expect(rows[1].read<int>('pending_operations'), 1);
+    expect(rows[0].read<String>('modified_at'), '2026-01-02T03:04:05Z');
+  });
+
+  test('the schema view lists the files view', () async {
+    final row = await db
+        .customSelect('SELECT * FROM external_schema')
+        .getSingle();
+    expect(row.read<String>('name'), 'external_files_v1');
+    expect(row.read<int>('version'), 1);
+  });
+}
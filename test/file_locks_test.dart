@@ -0,0 +1,56 @@
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/platform/file_locks.dart';
+import 'package:oxicloud/core/sync/transfer_monitor.dart';
+
+void main() {
+  final file = File('report.docx');
+  const delays = [Duration.zero, Duration.zero, Duration.zero];
+
+  test('uploads wait until the file is released', () async {
+    var checks = 0;
+    var waited = 0;
+    await FileLocks.waitUntilReleased(
+      file,
+      delays: delays,
+      onWaiting: () => waited++,
+      locked: (_) async => ++checks < 3,
+    );
+    expect(checks, 3);
+    expect(waited, 1);
+  });
+
+  test('a file that stays locked gives up after the last retry', () async {
+    var checks = 0;
+    await expectLater(
+      FileLocks.waitUntilReleased(
+        file,
+        delays: delays,
+        locked: (_) async {
+          checks++;
+          return true;
+        },
+      ),
+      throwsA(isA<FileLockedException>()),
+    );
+    expect(checks, 4);
+  });
+
+  test('waiting transfers are not counted as active', () {
+    final monitor = TransferMonitor();
+    final id = monitor.begin(
+      name: 'report.docx',
+      direction: TransferDirection.upload,
+      totalBytes: 10,
+    );
+    monitor.waitForRelease(id);
+    expect(monitor.activeCount, 0);
+    expect(monitor.waitingCount, 1);
+
+    monitor.resume(id);
+    expect(monitor.progressOf(id)!.state, TransferState.active);
+  });
+}
@@ -0,0 +1,61 @@
+import 'dart:io';
+
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/platform/file_modes.dart';
+
+void main() {
+  late AppDatabase db;
+  late Directory dir;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    dir = await Directory.systemTemp.createTemp('modes');
+    addTearDown(() async {
+      await db.close();
+      await dir.delete(recursive: true);
+    });
+  });
+
+  /// A new file at [name] with [mode].
+  Future<String> create(String name, String mode) async {
+    final path = p.join(dir.path, name);
+    await File(path).writeAsString('#!/bin/sh\n');
+    await Process.run('chmod', [mode, path]);
+    return path;
+  }
+
+  int modeOf(String path) => File(path).statSync().mode & 0x1ff;
+
+  test('only permission bits are kept, owner read-write always', () {
+    expect(FileModeStore.sanitize(0x81ed), 0x1ed); // 0100755 -> 0755
+    expect(FileModeStore.sanitize(0x924), 0x1a4); // 04444 -> 0644
+    expect(FileModeStore.sanitize(0), 0x180); // 0 -> 0600
+  });
+
+  test(
+    'a file uploaded from here gets its mode back on download',
+    () async {
+      final store = FileModeStore(db);
+      await store.remember('f1', await create('run.sh', '750'));
+
+      final download = await create('download.sh', '644');
+      await store.restore('f1', download);
+      expect(modeOf(download), 0x1e8); // 0750
+
+      final other = await create('other.sh', '644');
+      await store.restore('f2', other);
+      expect(modeOf(other), 0x1a4, reason: 'not uploaded from here');
+    },
+    skip: Platform.isWindows,
+  );
+
+  test('nothing is recorded when turned off', () async {
+    final store = FileModeStore(db, enabled: false);
+    await store.remember('f1', await create('run.sh', '755'));
+    expect(await db.getFileMode('f1'), isNull);
+  });
+}
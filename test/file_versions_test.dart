@@ -0,0 +1,111 @@
+import 'dart:convert';
+import 'dart:io';
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/data/datasources/remote/version_remote_datasource.dart';
+import 'package:oxicloud/data/repositories/file_version_repository_impl.dart';
+
+/// Keeps two versions of `f1`, listed oldest first, and nothing else.
+class _Server implements HttpClientAdapter {
+  final requests = <String>[];
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    final request = '${options.method} ${options.path}';
+    requests.add(request);
+    return switch (request) {
+      'GET /files/f1/versions' => _json([
+        {
+          'id': 'v1',
+          'file_id': 'f1',
+          'size': 3,
+          'modified_at': '2026-01-01T00:00:00Z',
+        },
+        {
+          'id': 'v2',
+          'file_id': 'f1',
+          'size': 5,
+          'modified_at': '2026-02-01T00:00:00Z',
+          'etag': '"e2"',
+          'modified_by': 'ana',
+        },
+      ]),
+      'GET /files/f1/versions/v1' => ResponseBody.fromString('old', 200),
+      'POST /files/f1/versions/v1/restore' => _json({
+        'id': 'f1',
+        'name': 'a.txt',
+        'path': '/a.txt',
+        'size': 3,
+        'mime_type': 'text/plain',
+        'etag': '"e3"',
+      }),
+      _ => ResponseBody.fromString('', 404),
+    };
+  }
+
+  ResponseBody _json(Object body) => ResponseBody.fromString(
+    json.encode(body),
+    200,
+    headers: {
+      Headers.contentTypeHeader: [Headers.jsonContentType],
+    },
+  );
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  late _Server server;
+  late FileVersionRepositoryImpl repo;
+
+  setUp(() {
+    server = _Server();
+    repo = FileVersionRepositoryImpl(
+      remote: VersionRemoteDatasource(Dio()..httpClientAdapter = server),
+    );
+  });
+
+  test('versions are listed newest first', () async {
+    final versions = await repo.listVersions('f1');
+    expect(versions.map((v) => v.id), ['v2', 'v1']);
+    expect(versions.first.size, 5);
+    expect(versions.first.etag, '"e2"');
+    expect(versions.first.modifiedBy, 'ana');
+    expect(versions.last.modifiedAt, DateTime.utc(2026));
+    expect(versions.last.modifiedBy, isNull);
+  });
+
+  test('a version downloads to the given path', () async {
+    final dir = Directory.systemTemp.createTempSync('oxicloud-versions');
+    addTearDown(() => dir.deleteSync(recursive: true));
+    final target = p.join(dir.path, 'a (v1).txt');
+
+    expect(await repo.downloadVersionToPath('f1', 'v1', target), target);
+    expect(File(target).readAsStringSync(), 'old');
+  });
+
+  test('restoring answers with the file as it now is', () async {
+    final file = await repo.restoreVersion('f1', 'v1');
+    expect(server.requests, ['POST /files/f1/versions/v1/restore']);
+    expect(file.id, 'f1');
+    expect(file.size, 3);
+    expect(file.etag, '"e3"');
+  });
+
+  test('an unknown version is not found', () async {
+    await expectLater(
+      repo.restoreVersion('f1', 'gone'),
+      throwsA(isA<NotFoundException>()),
+    );
+  });
+}
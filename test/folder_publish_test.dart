@@ -0,0 +1,149 @@
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/services/folder_publish_service.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/entities/folder_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+
+FolderEntity _folder(String id, String name, String? parentId) => FolderEntity(
+  id: id,
+  name: name,
+  path: '/$name',
+  parentId: parentId,
+  createdAt: DateTime(2026),
+  modifiedAt: DateTime(2026),
+);
+
+class _Folders extends Fake implements FolderRepository {
+  final folders = <String, FolderEntity>{};
+  final deleted = <String>[];
+
+  @override
+  Future<FolderContents> listRootContents() async => FolderContents(
+    folders: folders.values.where((f) => f.parentId == null).toList(),
+    files: const [],
+  );
+
+  @override
+  Future<FolderEntity> createFolder({
+    required String name,
+    String? parentId,
+  }) async {
+    final id = 'd${folders.length}';
+    return folders[id] = _folder(id, name, parentId);
+  }
+
+  @override
+  Future<FolderEntity> renameFolder(String id, String newName) async {
+    return folders[id] = _folder(id, newName, folders[id]!.parentId);
+  }
+
+  @override
+  Future<void> deleteFolder(String id) async {
+    deleted.add(id);
+    folders.remove(id);
+  }
+}
+
+class _Files extends Fake implements FileRepository {
+  final uploaded = <String, FileEntity>{};
+
+  /// Size the server reports for every file, if set.
+  int? reportedSize;
+
+  @override
+  Future<FileEntity> uploadFile({
+    required String name,
+    required String? folderId,
+    required Stream<List<int>> fileStream,
+    required int fileSize,
+    required String mimeType,
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+  }) async {
+    await fileStream.drain<void>();
+    final id = 'f${uploaded.length}';
+    return uploaded[id] = FileEntity(
+      id: id,
+      name: name,
+      path: '/$name',
+      size: fileSize,
+      mimeType: mimeType,
+      folderId: folderId,
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+  }
+
+  @override
+  Future<FileEntity> getFile(String id) async {
+    final file = uploaded[id]!;
+    final size = reportedSize;
+    if (size == null) return file;
+    return FileEntity(
+      id: id,
+      name: file.name,
+      path: file.path,
+      size: size,
+      mimeType: file.mimeType,
+      createdAt: file.createdAt,
+      modifiedAt: file.modifiedAt,
+    );
+  }
+}
+
+void main() {
+  late Directory local;
+  late _Folders folders;
+  late _Files files;
+  late FolderPublishService service;
+
+  setUp(() async {
+    local = await Directory.systemTemp.createTemp('publish_test');
+    final dir = await Directory(p.join(local.path, 'site', 'css')).create(
+      recursive: true,
+    );
+    await File(p.join(local.path, 'site', 'index.html')).writeAsString('hi');
+    await File(p.join(dir.path, 'main.css')).writeAsString('body {}');
+    folders = _Folders();
+    files = _Files();
+    service = FolderPublishService(files: files, folders: folders);
+  });
+  tearDown(() => local.delete(recursive: true));
+
+  test('the folder gets its name only once everything is uploaded', () async {
+    final published = await service.publish(p.join(local.path, 'site'));
+
+    expect(published.name, 'site');
+    expect(files.uploaded, hasLength(2));
+    expect(folders.deleted, isEmpty);
+    final roots = folders.folders.values.where((f) => f.parentId == null);
+    expect(roots.map((f) => f.name), ['site']);
+  });
+
+  test('a failed check removes the staged copy', () async {
+    files.reportedSize = 1;
+
+    await expectLater(
+      service.publish(p.join(local.path, 'site')),
+      throwsA(isA<PublishAbortedException>()),
+    );
+    expect(folders.deleted, ['d0']);
+    expect(folders.folders.values.map((f) => f.name), isNot(contains('site')));
+  });
+
+  test('an existing folder of the same name is left alone', () async {
+    await folders.createFolder(name: 'site');
+
+    await expectLater(
+      service.publish(p.join(local.path, 'site')),
+      throwsA(isA<PublishAbortedException>()),
+    );
+    expect(files.uploaded, isEmpty);
+  });
+}
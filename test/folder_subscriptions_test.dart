@@ -0,0 +1,57 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/folder_subscriptions.dart';
+import 'package:oxicloud/core/sync/sync_events.dart';
+
+void main() {
+  test('a folder is polled at its most eager subscriber\'s interval', () {
+    final registry = FolderSubscriptions();
+    final slow = registry.subscribe(
+      'a',
+      interval: const Duration(minutes: 10),
+    );
+    final fast = registry.subscribe('a');
+    registry.subscribe('b', interval: const Duration(seconds: 5));
+
+    expect(registry.intervalFor('a'), const Duration(seconds: 15));
+    expect(registry.shortestInterval, const Duration(seconds: 5));
+    expect(registry.intervalFor('c'), isNull);
+
+    fast.cancel();
+    expect(registry.intervalFor('a'), const Duration(minutes: 10));
+    slow.cancel();
+    expect(registry.intervalFor('a'), isNull);
+  });
+
+  test('changes reach only the folder\'s subscribers', () {
+    final registry = FolderSubscriptions();
+    final seen = <String>[];
+    registry
+      ..subscribe('a', onChanged: () => seen.add('a'))
+      ..subscribe('b', onChanged: () => seen.add('b'));
+
+    registry.notifyChanged('a');
+    expect(seen, ['a']);
+  });
+
+  test('a burst of folder changes refreshes each view once', () async {
+    final events = SyncEventBus();
+    final registry = FolderSubscriptions(events: events, settle: Duration.zero);
+    final seen = <String>[];
+    registry
+      ..subscribe(null, onChanged: () => seen.add('root'))
+      ..subscribe('a', onChanged: () => seen.add('a'));
+    expect(registry.shortestInterval, const Duration(seconds: 15));
+
+    events
+      ..emit(FolderChanged(folderId: 'a'))
+      ..emit(FolderChanged(folderId: 'a'))
+      ..emit(FolderChanged(folderId: null))
+      ..emit(FolderChanged(folderId: 'b'));
+    await Future<void>.delayed(const Duration(milliseconds: 10));
+    expect(seen, ['a', 'root']);
+
+    registry.dispose();
+    await events.dispose();
+  });
+}
@@ -0,0 +1,97 @@
+import 'dart:io';
+
+import 'package:drift/native.dart';
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/auth/secure_storage.dart';
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/sync/storage_quota.dart';
+import 'package:oxicloud/domain/entities/folder_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/presentation/features/file_browser/file_browser_page.dart';
+import 'package:oxicloud/providers.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+
+  @override
+  void dispose() {}
+}
+
+/// Records each folder created as `parent/name`.
+class _Folders extends Fake implements FolderRepository {
+  final created = <String>[];
+
+  @override
+  Future<FolderEntity> createFolder({
+    required String name,
+    String? parentId,
+  }) async {
+    created.add('${parentId ?? ''}/$name');
+    return FolderEntity(
+      id: name,
+      name: name,
+      path: '/$name',
+      parentId: parentId,
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+  }
+
+  @override
+  Future<FolderContents> listRootContents() async =>
+      const FolderContents(folders: [], files: []);
+}
+
+class _Files extends Fake implements FileRepository {}
+
+class _Quota extends Fake implements StorageQuotaService {
+  @override
+  Future<StorageQuota?> refresh() async => null;
+}
+
+void main() {
+  test('a dropped folder is recreated with its empty subfolders', () async {
+    final dir = await Directory.systemTemp.createTemp('tree');
+    addTearDown(() => dir.delete(recursive: true));
+    final root = Directory(p.join(dir.path, 'Project'));
+    Directory(p.join(root.path, 'docs')).createSync(recursive: true);
+    Directory(p.join(root.path, 'src', 'empty')).createSync(recursive: true);
+
+    final folders = _Folders();
+    final container = ProviderContainer(
+      overrides: [
+        secureStorageProvider.overrideWithValue(SecureStorage.memory()),
+        databaseProvider.overrideWith((ref) {
+          final db = AppDatabase(NativeDatabase.memory());
+          ref.onDispose(db.close);
+          return db;
+        }),
+        connectivityProvider.overrideWithValue(_Online()),
+        folderRepositoryProvider.overrideWithValue(folders),
+        fileRepositoryProvider.overrideWithValue(_Files()),
+        storageQuotaProvider.overrideWithValue(_Quota()),
+      ],
+    );
+    addTearDown(container.dispose);
+
+    final uploaded = await container
+        .read(fileBrowserProvider.notifier)
+        .uploadFolderFromPath(root.path);
+    expect(uploaded, 0);
+    expect(folders.created, [
+      '/Project',
+      'Project/docs',
+      'Project/src',
+      'src/empty',
+    ]);
+  });
+}
@@ -0,0 +1,95 @@
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/sync/ignore_rules.dart';
+
+void main() {
+  bool? decide(String rules, String path, {bool isDirectory = false}) =>
+      IgnoreRules.parse(rules).decide(path, isDirectory: isDirectory);
+
+  test('unanchored patterns match names at any depth', () {
+    expect(decide('*.tmp', 'a.tmp'), isTrue);
+    expect(decide('*.tmp', 'deep/er/a.tmp'), isTrue);
+    expect(decide('*.tmp', 'a.tmp.txt'), isNull);
+    expect(decide('# comment\n\n*.tmp', 'x.txt'), isNull);
+  });
+
+  test('patterns with a slash are anchored to the ignore file', () {
+    expect(decide('/build', 'build', isDirectory: true), isTrue);
+    expect(decide('/build', 'src/build', isDirectory: true), isNull);
+    expect(decide('docs/*.pdf', 'docs/a.pdf'), isTrue);
+    expect(decide('docs/*.pdf', 'docs/sub/a.pdf'), isNull);
+    expect(decide('docs/**/*.pdf', 'docs/sub/a.pdf'), isTrue);
+  });
+
+  test('trailing slash only matches directories', () {
+    expect(decide('cache/', 'cache', isDirectory: true), isTrue);
+    expect(decide('cache/', 'cache'), isNull);
+  });
+
+  test('the last matching rule wins and negation re-includes', () {
+    expect(decide('*.log\n!keep.log', 'keep.log'), isFalse);
+    expect(decide('*.log\n!keep.log', 'other.log'), isTrue);
+    expect(decide('!keep.log\n*.log', 'keep.log'), isTrue);
+  });
+
+  test('character classes and single-character wildcards', () {
+    expect(decide('v[0-9].md', 'v2.md'), isTrue);
+    expect(decide('v[!0-9].md', 'v2.md'), isNull);
+    expect(decide('?.txt', 'ab.txt'), isNull);
+  });
+
+  test('cache honours nested ignore files and picks up edits', () async {
+    final root = await Directory.systemTemp.createTemp('ignore_rules');
+    addTearDown(() => root.delete(recursive: true));
+    final sub = await Directory(p.join(root.path, 'sub')).create();
+    final rootIgnore = File(p.join(root.path, IgnoreRuleCache.fileName));
+    await rootIgnore.writeAsString('*.log\nbuild/\n');
+    await File(
+      p.join(sub.path, IgnoreRuleCache.fileName),
+    ).writeAsString('!keep.log\n');
+
+    final cache = IgnoreRuleCache();
+    Future<bool> ignored(String path, {bool isDirectory = false}) =>
+        cache.isIgnored(
+          root.path,
+          p.join(root.path, path),
+          isDirectory: isDirectory,
+        );
+
+    expect(await ignored('a.log'), isTrue);
+    expect(await ignored('sub/a.log'), isTrue);
+    expect(await ignored('sub/keep.log'), isFalse);
+    expect(await ignored('build/out.bin'), isTrue);
+    expect(await ignored('a.txt'), isFalse);
+
+    await rootIgnore.writeAsString('*.txt\n');
+    expect(await ignored('a.txt'), isTrue);
+    expect(await ignored('a.log'), isFalse);
+  });
+
+  test('editor and system junk is skipped unless re-included', () async {
+    final root = await Directory.systemTemp.createTemp('ignore_rules');
+    addTearDown(() => root.delete(recursive: true));
+    final cache = IgnoreRuleCache(extraPatterns: ['*.bak']);
+    Future<bool> ignored(String path) =>
+        cache.isIgnored(root.path, p.join(root.path, path), isDirectory: false);
+
+    expect(await ignored(r'docs/~$report.docx'), isTrue);
+    expect(await ignored('.notes.txt.swp'), isTrue);
+    expect(await ignored('Thumbs.db'), isTrue);
+    expect(await ignored('old.bak'), isTrue);
+    expect(await ignored('report.docx'), isFalse);
+
+    await File(
+      p.join(root.path, IgnoreRuleCache.fileName),
+    ).writeAsString('!keep.tmp\n');
+    expect(await ignored('keep.tmp'), isFalse);
+    expect(await ignored('other.tmp'), isTrue);
+
+    cache.extraPatterns = const [];
+    expect(await ignored('old.bak'), isFalse);
+  });
+}
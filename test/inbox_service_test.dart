@@ -0,0 +1,128 @@
+import 'dart:io';
+
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/services/inbox_service.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+class _Files extends Fake implements FileRepository {
+  final inbox = <FileEntity>[];
+  final moved = <String, String>{};
+
+  @override
+  Future<List<FileEntity>> listFiles({String? folderId}) async => [...inbox];
+
+  @override
+  Future<FileEntity> moveFile(
+    String id,
+    String targetFolderId, {
+    String? ifMatch,
+  }) async {
+    moved[id] = targetFolderId;
+    final file = inbox.firstWhere((f) => f.id == id);
+    inbox.remove(file);
+    return file;
+  }
+
+  @override
+  Future<String> downloadFileToPath(
+    String id,
+    String localPath, {
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+    bool resume = false,
+    String? ifRange,
+    void Function(String? etag)? onEtag,
+  }) async {
+    await File(localPath).writeAsString(id);
+    return localPath;
+  }
+}
+
+FileEntity _file(String id, String name) => FileEntity(
+  id: id,
+  name: name,
+  path: '/Inbox/$name',
+  size: 1,
+  mimeType: 'application/octet-stream',
+  createdAt: DateTime(2026),
+  modifiedAt: DateTime(2026),
+);
+
+void main() {
+  late AppDatabase db;
+  late _Files files;
+  late InboxService service;
+
+  setUp(() {
+    db = AppDatabase(NativeDatabase.memory());
+    files = _Files();
+    service = InboxService(files: files, db: db)
+      ..settings = const InboxSettings(folderId: 'inbox', folderPath: '/Inbox');
+  });
+  tearDown(() => db.close());
+
+  test('arrivals are sorted by the first matching rule, once', () async {
+    await service.addRule(
+      namePattern: '*.pdf',
+      folderId: 'docs',
+      folderPath: '/Docs',
+    );
+    await service.addRule(
+      namePattern: 'IMG_*',
+      folderId: 'photos',
+      folderPath: '/Photos',
+    );
+    files.inbox.addAll([
+      _file('1', 'invoice.pdf'),
+      _file('2', 'IMG_0001.jpg'),
+      _file('3', 'notes.txt'),
+    ]);
+
+    final summary = await service.run();
+    expect(files.moved, {'1': 'docs', '2': 'photos'});
+    expect(summary.sorted, ['invoice.pdf', 'IMG_0001.jpg']);
+    expect(summary.kept, ['notes.txt']);
+    expect(
+      summary.describe(),
+      '3 files arrived in the inbox: 2 sorted, 1 left in the inbox',
+    );
+
+    expect((await service.run()).isEmpty, isTrue);
+  });
+
+  test('downloads keep to the download folder', () async {
+    final root = Directory.systemTemp.createTempSync('oxicloud-inbox');
+    addTearDown(() => root.deleteSync(recursive: true));
+    final dir = p.join(root.path, 'in');
+    Directory(dir).createSync();
+    File(p.join(dir, 'a.txt')).writeAsStringSync('mine');
+    service.settings = InboxSettings(
+      folderId: 'inbox',
+      folderPath: '/Inbox',
+      downloadDir: dir,
+    );
+    files.inbox.addAll([
+      _file('1', '../../evil.sh'),
+      _file('2', 'a.txt'),
+      _file('3', '..'),
+    ]);
+
+    final summary = await service.run();
+    expect(File(p.join(dir, 'evil.sh')).readAsStringSync(), '1');
+    expect(File(p.join(dir, 'a (2).txt')).readAsStringSync(), '2');
+    expect(File(p.join(dir, 'a.txt')).readAsStringSync(), 'mine');
+    expect(File(p.join(root.path, 'evil.sh')).existsSync(), isFalse);
+    expect(summary.failed, ['..']);
+  });
+
+  test('nothing happens while the inbox is off', () async {
+    files.inbox.add(_file('1', 'a.pdf'));
+    service.settings = const InboxSettings();
+    expect((await service.run()).isEmpty, isTrue);
+  });
+}
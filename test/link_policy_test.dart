@@ -0,0 +1,65 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/sync/link_policy.dart';
+
+void main() {
+  late Directory root;
+  late String resolvedRoot;
+
+  setUp(() async {
+    root = await Directory.systemTemp.createTemp('link_policy');
+    resolvedRoot = await root.resolveSymbolicLinks();
+    await File(p.join(root.path, 'a.txt')).writeAsString('a');
+    await Directory(p.join(root.path, 'sub')).create();
+  });
+  tearDown(() => root.delete(recursive: true));
+
+  Future<Link> link(String name, String target) =>
+      Link(p.join(root.path, name)).create(target);
+
+  test('following links leaves out loops and broken links', () async {
+    const follow = LinkResolver(LinkPolicy.follow);
+    final ancestors = {resolvedRoot};
+
+    final (file, _) = await follow.resolve(await link('f', 'a.txt'), ancestors);
+    expect(file, LinkOutcome.file);
+
+    final (dir, target) = await follow.resolve(
+      await link('d', 'sub'),
+      ancestors,
+    );
+    expect(dir, LinkOutcome.directory);
+    expect(target, p.join(resolvedRoot, 'sub'));
+
+    final (loop, _) = await follow.resolve(
+      await Link(p.join(root.path, 'sub', 'up')).create('..'),
+      {...ancestors, target!},
+    );
+    expect(loop, LinkOutcome.loop);
+
+    final (broken, _) = await follow.resolve(
+      await link('gone', 'missing.txt'),
+      ancestors,
+    );
+    expect(broken, LinkOutcome.broken);
+  });
+
+  test('links are skipped or kept as their target path', () async {
+    final l = await link('f', 'a.txt');
+    final (skipped, _) = await const LinkResolver(
+      LinkPolicy.skip,
+    ).resolve(l, const {});
+    expect(skipped, LinkOutcome.skip);
+
+    final (kept, target) = await const LinkResolver(
+      LinkPolicy.metadata,
+    ).resolve(l, const {});
+    expect(kept, LinkOutcome.metadata);
+    expect(utf8.decode(LinkResolver.metadataOf(target!)), 'a.txt');
+    expect(LinkPolicy.parse('nonsense'), LinkPolicy.skip);
+  });
+}
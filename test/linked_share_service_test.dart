@@ -0,0 +1,142 @@
+import 'dart:io';
+
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/auth/secure_storage.dart';
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/platform/local_names.dart';
+import 'package:oxicloud/core/services/linked_share_service.dart';
+import 'package:oxicloud/data/datasources/remote/public_share_remote_datasource.dart';
+
+/// Downloads of the shares in [failing] are refused.
+class _Remote extends Fake implements PublicShareRemoteDatasource {
+  final Map<String, PublicShareInfo> shares;
+  final Set<String> failing;
+
+  _Remote(this.shares, this.failing);
+
+  @override
+  Future<PublicShareInfo> getShareInfo(String token) async => shares[token]!;
+
+  @override
+  Future<void> download(String token, String savePath) async {
+    if (failing.contains(token)) throw const NotFoundException();
+    await File(savePath).writeAsString(token);
+  }
+}
+
+class _Connectivity extends Fake implements ConnectivityService {
+  @override
+  bool isOnline = true;
+}
+
+PublicShareInfo _info(String name, {String itemType = 'file'}) =>
+    PublicShareInfo(
+      id: name,
+      itemType: itemType,
+      name: name,
+      passwordProtected: false,
+    );
+
+void main() {
+  test('names are reduced to their last segment', () {
+    expect(LocalNames.sanitize('../../.bashrc'), '.bashrc');
+    expect(LocalNames.sanitize(r'C:\Users\me\a.txt'), 'a.txt');
+    expect(LocalNames.sanitize('/etc/passwd'), 'passwd');
+    for (final name in ['', '.', '..', 'a/..', 'dir/']) {
+      expect(LocalNames.sanitize(name), isNull, reason: name);
+    }
+  });
+
+  group('linked shares', () {
+    late AppDatabase db;
+    late Directory root;
+    late String dir;
+    late LinkedShareService service;
+    late Set<String> failing;
+    late _Connectivity connectivity;
+
+    setUp(() {
+      db = AppDatabase(NativeDatabase.memory());
+      root = Directory.systemTemp.createTempSync('oxicloud-shares');
+      dir = p.join(root.path, 'shares');
+      Directory(dir).createSync();
+      addTearDown(() async {
+        await db.close();
+        await Process.run('chmod', ['-R', 'u+w', root.path]);
+        root.deleteSync(recursive: true);
+      });
+      failing = {};
+      connectivity = _Connectivity();
+      service = LinkedShareService(
+        db: db,
+        secureStorage: SecureStorage.memory(),
+        connectivity: connectivity,
+        remoteFor: (_) async => _Remote({
+          'up': _info('../../.bashrc'),
+          'abs': _info('/tmp/report.pdf'),
+          'folder': _info('Photos', itemType: 'folder'),
+          'dot': _info('..'),
+        }, failing),
+        localDir: () async => dir,
+      );
+    });
+
+    test('names from the server cannot leave the shares folder', () async {
+      final up = await service.add('https://cloud.example/s/up');
+      final abs = await service.add('https://cloud.example/s/abs');
+      expect(up.localPath, p.join(dir, '.bashrc'));
+      expect(abs.localPath, p.join(dir, 'report.pdf'));
+      expect(File(p.join(root.path, '.bashrc')).existsSync(), isFalse);
+
+      await expectLater(
+        service.add('https://cloud.example/s/dot'),
+        throwsStateError,
+      );
+    });
+
+    test('folders arrive as a numbered zip when the name is used', () async {
+      File(p.join(dir, 'Photos.zip')).writeAsStringSync('mine');
+      final folder = await service.add('https://cloud.example/s/folder');
+      expect(folder.localPath, p.join(dir, 'Photos (2).zip'));
+      expect(File(p.join(dir, 'Photos.zip')).readAsStringSync(), 'mine');
+
+      // Later syncs replace the same copy.
+      final again = await service.sync(folder);
+      expect(again.localPath, folder.localPath);
+    });
+
+    test('each share reports its state in one call', () async {
+      final ok = await service.add('https://cloud.example/s/abs');
+      await service.add('https://cloud.example/s/folder');
+      failing.add('folder');
+      await service.syncAll();
+
+      final status = {
+        for (final pair in await service.getFolderPairStatus())
+          pair.share.token: pair,
+      };
+      expect(status['abs']!.state, FolderPairState.idle);
+      expect(status['abs']!.lastSyncedAt, isNotNull);
+      expect(status['abs']!.pendingCount, 0);
+      expect(status['folder']!.state, FolderPairState.error);
+      expect(status['folder']!.lastError, contains('Not found'));
+
+      // A share that syncs again is no longer in error.
+      failing.clear();
+      await service.syncAll();
+      final after = await service.getFolderPairStatus();
+      expect(after.map((s) => s.state).toSet(), {FolderPairState.idle});
+      expect(after.map((s) => s.lastError).toSet(), {null});
+
+      connectivity.isOnline = false;
+      final offline = await service.getFolderPairStatus();
+      expect(offline.map((s) => s.state).toSet(), {FolderPairState.paused});
+      expect(ok.localPath, p.join(dir, 'report.pdf'));
+    });
+  });
+}
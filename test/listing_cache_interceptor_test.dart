@@ -0,0 +1,83 @@
+import 'dart:convert';
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/interceptors/listing_cache_interceptor.dart';
+
+/// Answers every request with [status], counting hits and recording the
+/// `If-None-Match` header of the last one.
+class _FakeAdapter implements HttpClientAdapter {
+  int calls = 0;
+  int status = 200;
+  String? lastIfNoneMatch;
+  String body = '{"folders":[],"files":[]}';
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    calls++;
+    lastIfNoneMatch = options.headers['If-None-Match'] as String?;
+    return ResponseBody.fromString(
+      status == 304 ? '' : body,
+      status,
+      headers: {
+        Headers.contentTypeHeader: [Headers.jsonContentType],
+        'etag': ['"v1"'],
+      },
+    );
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  late DateTime now;
+  late _FakeAdapter adapter;
+  late Dio dio;
+
+  setUp(() {
+    now = DateTime(2024, 1, 1);
+    adapter = _FakeAdapter();
+    dio = Dio(BaseOptions(baseUrl: 'https://cloud.test/api'))
+      ..httpClientAdapter = adapter
+      ..interceptors.add(ListingCacheInterceptor(clock: () => now));
+  });
+
+  test('repeated listings within the fresh window skip the network', () async {
+    await dio.get('/folders/a/listing');
+    final second = await dio.get('/folders/a/listing');
+    expect(adapter.calls, 1);
+    expect(second.data, json.decode(adapter.body));
+  });
+
+  test('stale listings are revalidated and a 304 served from cache', () async {
+    await dio.get('/folders/a/listing');
+    now = now.add(const Duration(minutes: 1));
+    adapter.status = 304;
+
+    final response = await dio.get('/folders/a/listing');
+    expect(adapter.calls, 2);
+    expect(adapter.lastIfNoneMatch, '"v1"');
+    expect(response.statusCode, 200);
+    expect(response.data, json.decode(adapter.body));
+  });
+
+  test('writes clear the cache', () async {
+    await dio.get('/folders/a/listing');
+    await dio.post('/folders', data: {'name': 'new'});
+    await dio.get('/folders/a/listing');
+    expect(adapter.calls, 3);
+  });
+
+  test('downloads are never cached', () async {
+    await dio.get('/files/a');
+    await dio.get('/files/a');
+    expect(adapter.calls, 2);
+  });
+}
@@ -0,0 +1,169 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/local_copy_verifier.dart';
+import 'package:oxicloud/core/sync/sync_events.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+class _Files extends Fake implements FileRepository {
+  final content = <String, String>{};
+
+  @override
+  Future<Stream<List<int>>> downloadFile(String id) async =>
+      Stream.value(utf8.encode(content[id]!));
+}
+
+void main() {
+  late AppDatabase db;
+  late Directory dir;
+  late SyncEventBus events;
+  late _Files files;
+  late LocalCopyVerifier verifier;
+
+  Future<void> serverHas(String id, {String? etag}) {
+    return db.upsertFile(
+      FilesTableCompanion.insert(
+        id: id,
+        name: '$id.txt',
+        path: '/$id.txt',
+        size: 5,
+        mimeType: 'text/plain',
+        etag: Value(etag),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+  }
+
+  Future<File> download(String id, String content, {String? etag}) async {
+    await serverHas(id, etag: etag);
+    final file = File('${dir.path}/$id.txt');
+    await file.writeAsString(content);
+    await verifier.track(id, file.path);
+    return file;
+  }
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    dir = await Directory.systemTemp.createTemp('verifier_test');
+    events = SyncEventBus();
+    files = _Files();
+    verifier = LocalCopyVerifier(db: db, events: events, files: files);
+  });
+  tearDown(() async {
+    await db.close();
+    await events.dispose();
+    await dir.delete(recursive: true);
+  });
+
+  test('intact copies pass', () async {
+    await download('a', 'hello', etag: 'v1');
+
+    final report = await verifier.verifyNow();
+    expect(report.checked, 1);
+    expect(report.isClean, isTrue);
+  });
+
+  test('silent damage and outdated copies need a download', () async {
+    final damaged = await download('a', 'hello');
+    await download('b', 'world', etag: 'v1');
+
+    final stamp = await damaged.lastModified();
+    await damaged.writeAsString('hellp');
+    await damaged.setLastModified(stamp);
+    await serverHas('b', etag: 'v2');
+
+    final emitted = events.on<IntegrityDiscrepancies>().first;
+    final report = await verifier.verifyNow();
+    expect(report.pendingDownload, ['a.txt', 'b.txt']);
+    expect(report.pendingUpload, isEmpty);
+    expect((await emitted).pendingDownload, ['a.txt', 'b.txt']);
+    expect((await db.getCachedFile('a'))!.state, 'pendingDownload');
+  });
+
+  test('local edits need an upload and are reported once', () async {
+    final edited = await download('a', 'hello');
+    await edited.writeAsString('hello, edited');
+    await edited.setLastModified(
+      DateTime.now().add(const Duration(minutes: 1)),
+    );
+
+    expect((await verifier.verifyNow()).pendingUpload, ['a.txt']);
+    expect((await verifier.verifyNow()).isClean, isTrue);
+  });
+
+  test('deleted copies are forgotten', () async {
+    final gone = await download('a', 'hello');
+    await gone.delete();
+
+    expect((await verifier.verifyNow()).checked, 0);
+    expect(await db.getCachedFile('a'), isNull);
+  });
+
+  test('text edited on both sides is merged or flagged', () async {
+    final merges = await download('a', 'one\ntwo\nthree\n', etag: 'v1');
+    final clashes = await download('b', 'one\ntwo\n', etag: 'v1');
+    final later = DateTime.now().add(const Duration(minutes: 1));
+    await merges.writeAsString('ONE\ntwo\nthree\n');
+    await merges.setLastModified(later);
+    await clashes.writeAsString('mine\ntwo\n');
+    await clashes.setLastModified(later);
+    files.content['a'] = 'one\ntwo\nTHREE\n';
+    files.content['b'] = 'theirs\ntwo\n';
+    await serverHas('a', etag: 'v2');
+    await serverHas('b', etag: 'v2');
+
+    final report = await verifier.verifyNow();
+    expect(report.merged, ['a.txt']);
+    expect(report.pendingUpload, ['a.txt']);
+    expect(report.conflicted, ['b.txt']);
+    expect(await merges.readAsString(), 'ONE\ntwo\nTHREE\n');
+    expect(await clashes.readAsString(), 'mine\ntwo\n');
+    expect((await db.getCachedFile('a'))!.etag, 'v2');
+    expect((await verifier.verifyNow()).isClean, isTrue);
+  });
+
+  test('emptied copies are held back and logged', () async {
+    expect(LocalCopyVerifier.looksTruncated(100, 0), isTrue);
+    expect(LocalCopyVerifier.looksTruncated(100, 9), isTrue);
+    expect(LocalCopyVerifier.looksTruncated(100, 10), isFalse);
+    expect(LocalCopyVerifier.looksTruncated(0, 0), isFalse);
+
+    final emptied = await download('a', 'hello');
+    await emptied.writeAsString('');
+    await emptied.setLastModified(
+      DateTime.now().add(const Duration(minutes: 1)),
+    );
+
+    final report = await verifier.verifyNow();
+    expect(report.heldBack, ['a.txt']);
+    expect(report.pendingUpload, isEmpty);
+    expect((await db.getCachedFile('a'))!.state, 'truncated');
+    expect((await db.getActivity()).single.outcome, 'held');
+
+    await emptied.writeAsString('hello');
+    expect((await verifier.verifyNow()).isClean, isTrue);
+    expect((await db.getCachedFile('a'))!.state, 'ok');
+  });
+
+  test('truncations still there after the window count as edits', () async {
+    verifier = LocalCopyVerifier(db: db, truncationWindow: Duration.zero);
+    final emptied = await download('a', 'hello');
+    await emptied.writeAsString('');
+    await emptied.setLastModified(
+      DateTime.now().add(const Duration(minutes: 1)),
+    );
+
+    expect((await verifier.verifyNow()).heldBack, ['a.txt']);
+    expect((await verifier.verifyNow()).pendingUpload, ['a.txt']);
+    expect(
+      [for (final a in await db.getActivity()) a.outcome],
+      containsAll(['held', 'confirmed']),
+    );
+  });
+}
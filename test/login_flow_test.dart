@@ -0,0 +1,81 @@
+import 'dart:async';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/services/login_flow_service.dart';
+import 'package:oxicloud/data/datasources/remote/login_flow_remote_datasource.dart';
+
+/// Answers polls from [answers] in turn, then keeps saying "not yet".
+class _Remote extends Fake implements LoginFlowRemoteDatasource {
+  final List<Object?> answers;
+  var polls = 0;
+
+  _Remote(this.answers);
+
+  @override
+  Future<LoginFlowCredentials?> poll(LoginFlowSession session) async {
+    final answer = polls < answers.length ? answers[polls] : null;
+    polls++;
+    if (answer is Exception) throw answer;
+    return answer as LoginFlowCredentials?;
+  }
+}
+
+final _session = LoginFlowSession.fromJson({
+  'poll': {'token': 'abc', 'endpoint': 'https://cloud.test/login/v2/poll'},
+  'login': 'https://cloud.test/login/v2/flow/xyz',
+});
+
+final _credentials = LoginFlowCredentials.fromJson({
+  'server': 'https://cloud.test',
+  'loginName': 'alice',
+  'appPassword': 'xxxx-yyyy',
+});
+
+void main() {
+  test('polls past pending answers and dropped connections', () async {
+    final remote = _Remote([
+      null,
+      const ServerUnreachableException('offline'),
+      _credentials,
+    ]);
+    final flow = LoginFlowService(remote, pollInterval: Duration.zero);
+
+    final credentials = await flow.waitForCredentials(_session);
+    expect(credentials!.loginName, 'alice');
+    expect(remote.polls, 3);
+  });
+
+  test('stops when cancelled or expired', () async {
+    final cancelled = LoginFlowService(
+      _Remote([]),
+      pollInterval: Duration.zero,
+    );
+    expect(
+      await cancelled.waitForCredentials(_session, cancelled: () => true),
+      isNull,
+    );
+
+    final expired = LoginFlowService(
+      _Remote([]),
+      pollInterval: Duration.zero,
+      timeout: Duration.zero,
+    );
+    await expectLater(
+      expired.waitForCredentials(_session),
+      throwsA(isA<TimeoutException>()),
+    );
+  });
+
+  test('other server errors end the flow', () async {
+    final flow = LoginFlowService(
+      _Remote([const ForbiddenException('denied')]),
+      pollInterval: Duration.zero,
+    );
+    await expectLater(
+      flow.waitForCredentials(_session),
+      throwsA(isA<ForbiddenException>()),
+    );
+  });
+}
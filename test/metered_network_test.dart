@@ -0,0 +1,64 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/platform/network_status.dart';
+import 'package:oxicloud/core/sync/metered_network.dart';
+import 'package:oxicloud/core/sync/resource_throttle.dart';
+
+void main() {
+  const metered = NetworkStatus(metered: true);
+  const roaming = NetworkStatus(metered: true, roaming: true);
+
+  test('costly connections hold sync back as configured', () {
+    ThrottleLevel level(MeteredAction action, NetworkStatus status) =>
+        MeteredNetworkMonitor.levelFor(action, status);
+
+    expect(
+      level(MeteredAction.pause, NetworkStatus.unrestricted),
+      ThrottleLevel.normal,
+    );
+    expect(level(MeteredAction.pause, metered), ThrottleLevel.paused);
+    expect(level(MeteredAction.reduce, metered), ThrottleLevel.reduced);
+    expect(level(MeteredAction.reduce, roaming), ThrottleLevel.paused);
+    expect(level(MeteredAction.ignore, roaming), ThrottleLevel.normal);
+  });
+
+  test('the network level overrides a calm machine', () {
+    final throttle = ResourceThrottle();
+    addTearDown(throttle.dispose);
+    throttle.networkLevel = ThrottleLevel.paused;
+    expect(throttle.isPaused, isTrue);
+    throttle.networkLevel = ThrottleLevel.normal;
+    expect(throttle.level, ThrottleLevel.normal);
+  });
+
+  test('reads the connection cost of each platform', () {
+    expect(
+      NetworkStatusProbe.parseWindowsCost('Fixed False False\r\n'),
+      metered,
+    );
+    expect(
+      NetworkStatusProbe.parseWindowsCost('Unrestricted True False'),
+      const NetworkStatus(roaming: true),
+    );
+    expect(NetworkStatusProbe.parseWindowsCost(''), isNull);
+    expect(NetworkStatusProbe.parseNetworkManagerMetered('u 3\n'), isTrue);
+    expect(NetworkStatusProbe.parseNetworkManagerMetered('u 4\n'), isFalse);
+    expect(
+      NetworkStatusProbe.parseModemRoaming(
+        'modem.3gpp.registration-state                  : roaming\n',
+      ),
+      isTrue,
+    );
+    expect(
+      NetworkStatusProbe.isPhoneHotspot(address: '172.20.10.4', dhcpPacket: ''),
+      isTrue,
+    );
+    expect(
+      NetworkStatusProbe.isPhoneHotspot(
+        address: '192.168.1.20',
+        dhcpPacket: 'vendor_encapsulated_options (opaque): ANDROID_METERED',
+      ),
+      isTrue,
+    );
+  });
+}
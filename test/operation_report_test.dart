@@ -0,0 +1,31 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/services/operation_report_service.dart';
+
+void main() {
+  test('the report lists failures and skips with their reasons', () {
+    final report = OperationReport(
+      'Upload of 3 dropped item(s)',
+      startedAt: DateTime(2026, 10, 16, 9),
+    );
+    report
+      ..complete('/home/a/photos/1.jpg')
+      ..fail('/home/a/photos/2.jpg', 'Quota exceeded')
+      ..skip('/home/a/photos/.cache', 'Matched .oxicloudignore')
+      ..finish(DateTime(2026, 10, 16, 9, 2, 5));
+
+    final md = report.toMarkdown();
+    expect(report.total, 3);
+    expect(md, startsWith('# Upload of 3 dropped item(s)\n'));
+    expect(md, contains('- Finished: 2026-10-16 09:02:05 (2m 5s)'));
+    expect(md, contains('- Failed: 1'));
+    expect(md, contains('- `/home/a/photos/2.jpg`: Quota exceeded'));
+    expect(md, contains('- `/home/a/photos/.cache`: Matched .oxicloudignore'));
+    expect(md, contains('## Completed\n\n- `/home/a/photos/1.jpg`'));
+  });
+
+  test('empty sections are left out', () {
+    final report = OperationReport('Sync')..complete('a');
+    expect(report.toMarkdown(), isNot(contains('## Failed')));
+  });
+}
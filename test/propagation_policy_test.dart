@@ -0,0 +1,71 @@
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/propagation_policy.dart';
+import 'package:oxicloud/core/sync/sync_direction.dart';
+import 'package:oxicloud/domain/entities/folder_entity.dart';
+
+void main() {
+  late AppDatabase db;
+  late PropagationPolicy policy;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    policy = PropagationPolicy(db);
+    addTearDown(db.close);
+
+    await SyncDirectionService(db).set(
+      FolderEntity(
+        id: 'camera',
+        name: 'Camera',
+        path: '/Camera',
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+      SyncDirection.uploadOnly,
+    );
+    await db.upsertFile(
+      FilesTableCompanion.insert(
+        id: 'f1',
+        name: 'a.jpg',
+        path: '/Camera/a.jpg',
+        size: 5,
+        mimeType: 'image/jpeg',
+        folderId: const Value('camera'),
+        isAvailableOffline: const Value(true),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+  });
+
+  Future<SyncQueueTableData> queue(String type) async {
+    final now = DateTime.now();
+    final id = await db.insertSyncOp(
+      SyncQueueTableCompanion.insert(
+        operationType: type,
+        itemId: 'f1',
+        itemType: 'file',
+        payload: '{}',
+        createdAt: now,
+        updatedAt: now,
+      ),
+    );
+    return (await db.getPendingSyncOps()).firstWhere((op) => op.id == id);
+  }
+
+  test('local deletions in a backup folder are withheld', () async {
+    expect(await policy.withhold(await queue('delete')), isNotNull);
+    expect(await policy.withhold(await queue('rename')), isNull);
+  });
+
+  test('files deleted on the server are logged once', () async {
+    await policy.logRemoteDeletes('camera', {});
+    await policy.logRemoteDeletes('camera', {});
+    final logged = await db.getActivity();
+    expect(logged.single.itemId, 'f1');
+    expect(logged.single.outcome, 'withheld');
+  });
+}
@@ -0,0 +1,140 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/proxy_settings.dart';
+
+/// A SOCKS5 proxy without login that answers every HTTP request itself,
+/// remembering where it was asked to connect.
+class _FakeSocksProxy {
+  late final ServerSocket server;
+  String? target;
+
+  Future<void> start() async {
+    server = await ServerSocket.bind(InternetAddress.loopbackIPv4, 0);
+    server.listen(_serve);
+  }
+
+  void _serve(Socket client) {
+    final buffer = <int>[];
+    var stage = 0;
+    client.listen((data) {
+      buffer.addAll(data);
+      if (stage == 0 && buffer.length >= 3) {
+        buffer.removeRange(0, 3);
+        client.add([5, 0]);
+        stage = 1;
+      }
+      if (stage == 1 && buffer.length >= 5 && buffer.length >= 7 + buffer[4]) {
+        final length = buffer[4];
+        final host = utf8.decode(buffer.sublist(5, 5 + length));
+        final port = buffer[5 + length] << 8 | buffer[6 + length];
+        target = '$host:$port';
+        buffer.removeRange(0, 7 + length);
+        client.add([5, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+        stage = 2;
+      }
+      if (stage == 2 && latin1.decode(buffer).contains('\r\n\r\n')) {
+        client.write(
+          'HTTP/1.1 200 OK\r\nContent-Length: 2\r\n'
+          'Connection: close\r\n\r\nok',
+        );
+        stage = 3;
+        client.close();
+      }
+    });
+  }
+}
+
+/// Remembers how the settings route requests.
+class _Client extends Fake implements HttpClient {
+  String Function(Uri url)? route;
+
+  @override
+  set findProxy(String Function(Uri url)? f) => route = f;
+
+  @override
+  set connectionFactory(
+    Future<ConnectionTask<Socket>> Function(
+      Uri url,
+      String? proxyHost,
+      int? proxyPort,
+    )?
+    f,
+  ) {}
+}
+
+void main() {
+  test('proxy addresses are validated', () {
+    expect(ProxySettings.validateUrl('http://proxy.lan:3128'), isNull);
+    expect(ProxySettings.validateUrl('socks5://10.0.0.1:1080'), isNull);
+    expect(ProxySettings.validateUrl('http://proxy.lan'), isNotNull);
+    expect(ProxySettings.validateUrl('ftp://proxy.lan:21'), isNotNull);
+    expect(ProxySettings.validateUrl('proxy.lan:3128'), isNotNull);
+  });
+
+  test('settings round-trip through storage', () {
+    const settings = ProxySettings(
+      mode: ProxyMode.manual,
+      url: 'http://proxy.lan:3128',
+      username: 'me',
+      password: 'secret',
+    );
+    final decoded = ProxySettings.decode(settings.encode());
+    expect(decoded.mode, ProxyMode.manual);
+    expect(decoded.url, settings.url);
+    expect(decoded.password, 'secret');
+    expect(ProxySettings.decode(null).mode, ProxyMode.system);
+  });
+
+  test('HTTP and system proxies are picked per request', () {
+    final cloud = Uri.parse('https://cloud.test/remote.php/webdav/');
+
+    final manual = _Client();
+    const ProxySettings(
+      mode: ProxyMode.manual,
+      url: 'http://proxy.lan:3128',
+    ).applyTo(manual);
+    expect(manual.route!(cloud), 'PROXY proxy.lan:3128');
+
+    final system = _Client();
+    const ProxySettings(mode: ProxyMode.system).applyTo(
+      system,
+      environment: {'https_proxy': 'proxy.lan:3128', 'no_proxy': 'intra.test'},
+    );
+    expect(system.route!(cloud), 'PROXY proxy.lan:3128');
+    expect(system.route!(Uri.parse('https://intra.test/')), 'DIRECT');
+  });
+
+  test('no proxy and SOCKS ignore the environment', () {
+    final cloud = Uri.parse('https://cloud.test/');
+    for (final settings in [
+      const ProxySettings(mode: ProxyMode.none),
+      const ProxySettings(mode: ProxyMode.manual, url: 'socks5://s.lan:1080'),
+    ]) {
+      final client = _Client();
+      settings.applyTo(client, environment: {'https_proxy': 'proxy.lan:3128'});
+      expect(client.route!(cloud), 'DIRECT', reason: settings.mode.name);
+    }
+  });
+
+  test('requests go through a SOCKS5 tunnel', () async {
+    final proxy = _FakeSocksProxy();
+    await proxy.start();
+    addTearDown(proxy.server.close);
+
+    final client = HttpClient();
+    ProxySettings(
+      mode: ProxyMode.manual,
+      url: 'socks5://127.0.0.1:${proxy.server.port}',
+    ).applyTo(client);
+
+    final request = await client.getUrl(Uri.parse('http://cloud.test:8080/'));
+    final response = await request.close();
+    expect(response.statusCode, 200);
+    expect(await utf8.decodeStream(response), 'ok');
+    expect(proxy.target, 'cloud.test:8080');
+    client.close(force: true);
+  });
+}
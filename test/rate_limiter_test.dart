@@ -0,0 +1,35 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/rate_limiter.dart';
+
+void main() {
+  late DateTime now;
+  late RateLimiter limiter;
+
+  setUp(() {
+    now = DateTime(2026);
+    limiter = RateLimiter(bytesPerSecond: 1000, clock: () => now);
+  });
+
+  test('callers wait behind the debt of earlier ones', () {
+    expect(limiter.reserve(500), const Duration(milliseconds: 500));
+    expect(limiter.reserve(500), const Duration(seconds: 1));
+
+    now = now.add(const Duration(seconds: 1));
+    expect(limiter.reserve(0), Duration.zero);
+  });
+
+  test('idle time builds up at most one second of credit', () {
+    limiter.reserve(0);
+    now = now.add(const Duration(minutes: 1));
+    expect(limiter.reserve(1000), Duration.zero);
+    expect(limiter.reserve(1000), const Duration(seconds: 1));
+  });
+
+  test('a new rate applies to the next reservation', () {
+    expect(limiter.reserve(1000), const Duration(seconds: 1));
+    limiter.bytesPerSecond = 0;
+    expect(limiter.isLimited, isFalse);
+    expect(limiter.reserve(1 << 30), Duration.zero);
+  });
+}
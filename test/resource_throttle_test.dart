@@ -0,0 +1,36 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/platform/system_load.dart';
+import 'package:oxicloud/core/sync/resource_throttle.dart';
+
+void main() {
+  ThrottleLevel after(ThrottleLevel current, double load, {bool hot = false}) =>
+      ResourceThrottle.levelFor(
+        current,
+        SystemLoad(cpuLoad: load, thermalPressure: hot),
+      );
+
+  test('busy or hot machines slow down', () {
+    expect(after(ThrottleLevel.normal, 0.5), ThrottleLevel.normal);
+    expect(after(ThrottleLevel.normal, 1.0), ThrottleLevel.reduced);
+    expect(after(ThrottleLevel.normal, 2.0), ThrottleLevel.paused);
+    expect(after(ThrottleLevel.normal, 0.1, hot: true), ThrottleLevel.paused);
+  });
+
+  test('levels are only left once the load clearly drops', () {
+    expect(after(ThrottleLevel.paused, 1.4), ThrottleLevel.paused);
+    expect(after(ThrottleLevel.paused, 1.2), ThrottleLevel.reduced);
+    expect(after(ThrottleLevel.reduced, 0.8), ThrottleLevel.reduced);
+    expect(after(ThrottleLevel.reduced, 0.6), ThrottleLevel.normal);
+  });
+
+  test('reads load averages and thermal limits', () {
+    expect(
+      SystemLoadProbe.parseLoadAverage('0.52 0.58 0.59 1/467 12345\n'),
+      0.52,
+    );
+    expect(SystemLoadProbe.parseLoadAverage(''), isNull);
+    expect(SystemLoadProbe.parseSpeedLimit('CPU_Speed_Limit \t= 70\n'), 70);
+    expect(SystemLoadProbe.parseSpeedLimit('No thermal warning level'), 100);
+  });
+}
@@ -0,0 +1,138 @@
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/core/sync/sync_models.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/favorites_repository.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/domain/repositories/trash_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+}
+
+/// Renames fail while the file is [deleted].
+class _Files extends Fake implements FileRepository {
+  var deleted = true;
+  final renamed = <String>[];
+
+  @override
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async {
+    if (deleted) throw const NotFoundException();
+    renamed.add(newName);
+    return FileEntity(
+      id: id,
+      name: newName,
+      path: '/$newName',
+      size: 1,
+      mimeType: 'text/plain',
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+  }
+}
+
+class _Folders extends Fake implements FolderRepository {}
+
+class _Favorites extends Fake implements FavoritesRepository {}
+
+/// Holds the deleted file while [inTrash].
+class _Trash extends Fake implements TrashRepository {
+  final _Files files;
+  final bool inTrash;
+  final restored = <String>[];
+
+  _Trash(this.files, {this.inTrash = true});
+
+  @override
+  Future<void> restoreItem(String id) async {
+    if (!inTrash) throw const NotFoundException();
+    restored.add(id);
+    files.deleted = false;
+  }
+}
+
+void main() {
+  late AppDatabase db;
+  late _Files files;
+
+  setUp(() {
+    db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    files = _Files();
+  });
+
+  /// Queue a rename of a file deleted on the server and run a sync pass.
+  Future<SyncEngine> renameDeleted(_Trash trash, {bool restore = true}) async {
+    final engine = SyncEngine(
+      db: db,
+      connectivity: _Online(),
+      fileRepo: files,
+      folderRepo: _Folders(),
+      favoritesRepo: _Favorites(),
+      trashRepo: trash,
+      restoreDeletedOnEdit: restore,
+    );
+    addTearDown(engine.dispose);
+    await engine.enqueue(
+      SyncTask(
+        id: 'r',
+        operation: SyncOperation.rename,
+        entityType: 'file',
+        entityId: 'f1',
+        payload: const {'new_name': 'b.txt'},
+        createdAt: DateTime.now(),
+      ),
+    );
+    await engine.sync();
+    return engine;
+  }
+
+  test('the item is restored and the edit applied', () async {
+    final trash = _Trash(files);
+    final engine = await renameDeleted(trash);
+    expect(trash.restored, ['f1']);
+    expect((await db.getSyncOp(1))!.status, 'pending');
+
+    await engine.sync();
+    expect(files.renamed, ['b.txt']);
+    expect((await db.getSyncOp(1))!.status, 'completed');
+    expect(await db.getUnresolvedConflicts(), isEmpty);
+    final logged = await db.getResolvedConflicts();
+    expect(logged.single.conflictType, 'deleted_remotely');
+    expect(logged.single.resolution, 'restored');
+  });
+
+  test('an item gone from the trash is a conflict at once', () async {
+    await renameDeleted(_Trash(files, inTrash: false));
+
+    expect(files.renamed, isEmpty);
+    expect((await db.getSyncOp(1))!.status, 'failed');
+    final conflict = (await db.getUnresolvedConflicts()).single;
+    expect(conflict.conflictType, 'deleted_remotely');
+  });
+
+  test('nothing is restored when turned off', () async {
+    final trash = _Trash(files);
+    await renameDeleted(trash, restore: false);
+
+    expect(trash.restored, isEmpty);
+    expect(
+      (await db.getUnresolvedConflicts()).single.conflictType,
+      'deleted_remotely',
+    );
+  });
+}
@@ -0,0 +1,203 @@
+import 'dart:convert';
+import 'dart:io';
+import 'dart:typed_data';
+
+import 'package:crypto/crypto.dart';
+import 'package:dio/dio.dart';
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/dav_multistatus.dart';
+import 'package:oxicloud/core/platform/temp_files.dart';
+import 'package:oxicloud/core/services/resumable_download_service.dart';
+import 'package:oxicloud/core/services/webdav_properties_service.dart';
+import 'package:oxicloud/data/datasources/remote/file_remote_datasource.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+/// Sends `hello` then `world` as version [etag]; the connection drops
+/// after the first half while [dropping].
+class _Files extends Fake implements FileRepository {
+  var dropping = true;
+  var etag = '"v1"';
+  final requests = <({bool resume, String? ifRange})>[];
+
+  @override
+  Future<String> downloadFileToPath(
+    String id,
+    String localPath, {
+    TransferProgressCallback? onProgress,
+    Future<void>? stop,
+    bool resume = false,
+    String? ifRange,
+    void Function(String? etag)? onEtag,
+  }) async {
+    requests.add((resume: resume, ifRange: ifRange));
+    onEtag?.call(etag);
+    final file = File(localPath);
+    if (!resume || !file.existsSync()) file.writeAsStringSync('hello');
+    if (dropping) throw const ServerUnreachableException();
+    file.writeAsStringSync('world', mode: FileMode.append);
+    return localPath;
+  }
+}
+
+/// Keeps checksums of the whole file.
+class _Properties extends Fake implements WebDavPropertiesService {
+  final String digest;
+
+  _Properties(this.digest);
+
+  @override
+  Future<DavResource?> lookup(String path) async => DavResource(path, {
+    '{http://owncloud.org/ns}checksums': 'SHA1:$digest',
+  });
+}
+
+/// Serves `new copy`, refusing ranges from the end of a file of [size]
+/// bytes.
+class _Server implements HttpClientAdapter {
+  final int size;
+  final ranges = <String?>[];
+
+  _Server(this.size);
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    final range = options.headers['Range'] as String?;
+    ranges.add(range);
+    if (range != null) {
+      return ResponseBody.fromString(
+        '',
+        416,
+        headers: {
+          'content-range': ['bytes */$size'],
+        },
+      );
+    }
+    return ResponseBody.fromString('new copy', 200);
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  late AppDatabase db;
+  late Directory dir;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    dir = await Directory.systemTemp.createTemp('resume');
+    await db.upsertFile(
+      FilesTableCompanion.insert(
+        id: 'f1',
+        name: 'a.txt',
+        path: '/a.txt',
+        size: 10,
+        mimeType: 'text/plain',
+        etag: const Value('"v1"'),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+    addTearDown(() async {
+      await db.close();
+      await dir.delete(recursive: true);
+    });
+  });
+
+  test('a cut-off download continues where it stopped', () async {
+    final files = _Files();
+    final downloads = ResumableDownloadService(
+      db: db,
+      files: files,
+      tempFiles: TempFileAllocator(),
+      retryDelays: const [],
+    );
+    final target = p.join(dir.path, 'a.txt');
+
+    final partial = await downloads.partialFor('f1', target);
+    await expectLater(
+      downloads.download('f1', partial, target),
+      throwsA(isA<ServerUnreachableException>()),
+    );
+    expect(await downloads.isKept('f1', partial), isTrue);
+    expect(await downloads.partialFor('f1', target), partial);
+
+    files.dropping = false;
+    await downloads.download('f1', partial, target);
+    expect(File(partial).readAsStringSync(), 'helloworld');
+    expect(files.requests.last, (resume: true, ifRange: '"v1"'));
+    expect(await downloads.isKept('f1', partial), isFalse);
+  });
+  test('the ETag the bytes came with is journaled', () async {
+    final files = _Files()..etag = '"v2"';
+    final downloads = ResumableDownloadService(
+      db: db,
+      files: files,
+      tempFiles: TempFileAllocator(),
+      retryDelays: const [],
+    );
+    final target = p.join(dir.path, 'a.txt');
+    final partial = await downloads.partialFor('f1', target);
+
+    await expectLater(
+      downloads.download('f1', partial, target),
+      throwsA(isA<ServerUnreachableException>()),
+    );
+    files.dropping = false;
+    await downloads.download('f1', partial, target);
+    expect(files.requests.last, (resume: true, ifRange: '"v2"'));
+  });
+
+  group('a partial file the server has nothing past', () {
+    Future<String> resumeFrom(_Server server) async {
+      final partial = p.join(dir.path, 'a.part');
+      File(partial).writeAsStringSync('helloworld');
+      final dio = Dio()..httpClientAdapter = server;
+      await FileRemoteDatasource(
+        dio,
+      ).downloadFileToPath('f1', partial, resume: true, ifRange: '"v1"');
+      return File(partial).readAsStringSync();
+    }
+
+    test('is complete when it is the whole file', () async {
+      final server = _Server(10);
+      expect(await resumeFrom(server), 'helloworld');
+      expect(server.ranges, ['bytes=10-']);
+    });
+
+    test('starts over when the file is another size', () async {
+      final server = _Server(8);
+      expect(await resumeFrom(server), 'new copy');
+      expect(server.ranges, ['bytes=10-', null]);
+    });
+  });
+
+  test('downloads that do not match the server checksum fail', () async {
+    final other = sha1.convert(utf8.encode('hellowurld')).toString();
+    final downloads = ResumableDownloadService(
+      db: db,
+      files: _Files()..dropping = false,
+      tempFiles: TempFileAllocator(),
+      properties: _Properties(other),
+      retryDelays: const [],
+    );
+    final target = p.join(dir.path, 'a.txt');
+    final partial = await downloads.partialFor('f1', target);
+
+    await expectLater(
+      downloads.download('f1', partial, target),
+      throwsA(isA<ChecksumMismatchException>()),
+    );
+    expect(await downloads.isKept('f1', partial), isFalse);
+  });
+}
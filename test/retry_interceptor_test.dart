@@ -0,0 +1,85 @@
+import 'dart:io';
+import 'dart:math';
+
+import 'package:dio/dio.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/interceptors/retry_interceptor.dart';
+import 'package:oxicloud/core/network/retry_after.dart';
+
+DioException _error(
+  String method, {
+  int? status,
+  String? retryAfter,
+  DioExceptionType type = DioExceptionType.badResponse,
+  Object? error,
+}) {
+  final options = RequestOptions(path: '/f', method: method);
+  return DioException(
+    requestOptions: options,
+    type: type,
+    error: error,
+    response: status == null
+        ? null
+        : Response(
+            requestOptions: options,
+            statusCode: status,
+            headers: Headers.fromMap({
+              'retry-after': [?retryAfter],
+            }),
+          ),
+  );
+}
+
+void main() {
+  final retry = RetryInterceptor(
+    dio: Dio(),
+    baseDelay: const Duration(seconds: 1),
+    maxDelay: const Duration(seconds: 8),
+    random: Random(1),
+  );
+
+  test('backoff doubles up to the cap, with jitter', () {
+    final err = _error('GET', status: 503);
+    for (final (attempt, full) in [(0, 1000), (2, 4000), (6, 8000)]) {
+      final delay = retry.delayFor(err, attempt)!.inMilliseconds;
+      expect(delay, inInclusiveRange(full ~/ 2, full));
+    }
+  });
+
+  test('Retry-After is honoured unless it exceeds the cap', () {
+    expect(
+      retry.delayFor(_error('PUT', status: 503, retryAfter: '3'), 0),
+      const Duration(seconds: 3),
+    );
+    expect(
+      retry.delayFor(_error('PUT', status: 429, retryAfter: '60'), 0),
+      isNull,
+    );
+    expect(
+      parseRetryAfter(
+        'Fri, 16 Oct 2026 10:00:05 GMT',
+        now: DateTime.utc(2026, 10, 16, 10),
+      ),
+      const Duration(seconds: 5),
+    );
+  });
+
+  test('classifies what is worth retrying', () {
+    expect(RetryInterceptor.shouldRetry(_error('GET', status: 502)), isTrue);
+    expect(RetryInterceptor.shouldRetry(_error('GET', status: 404)), isFalse);
+    expect(RetryInterceptor.shouldRetry(_error('POST', status: 500)), isFalse);
+    expect(RetryInterceptor.shouldRetry(_error('MOVE', status: 503)), isFalse);
+    expect(RetryInterceptor.shouldRetry(_error('POST', status: 429)), isTrue);
+    expect(
+      RetryInterceptor.shouldRetry(
+        _error(
+          'PROPFIND',
+          type: DioExceptionType.unknown,
+          error: const SocketException('Connection reset by peer'),
+        ),
+      ),
+      isTrue,
+    );
+  });
+}
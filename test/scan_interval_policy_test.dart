@@ -0,0 +1,30 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/folder_scan_scheduler.dart';
+
+void main() {
+  const policy = ScanIntervalPolicy(
+    minInterval: Duration(minutes: 1),
+    maxInterval: Duration(hours: 1),
+  );
+
+  test('changed folders are scanned more often, down to the minimum', () {
+    var interval = const Duration(minutes: 8);
+    interval = policy.next(interval, changed: true);
+    expect(interval, const Duration(minutes: 4));
+    for (var i = 0; i < 10; i++) {
+      interval = policy.next(interval, changed: true);
+    }
+    expect(interval, const Duration(minutes: 1));
+  });
+
+  test('quiet folders back off up to the full-rescan deadline', () {
+    var interval = const Duration(minutes: 5);
+    interval = policy.next(interval, changed: false);
+    expect(interval, const Duration(minutes: 10));
+    for (var i = 0; i < 10; i++) {
+      interval = policy.next(interval, changed: false);
+    }
+    expect(interval, const Duration(hours: 1));
+  });
+}
@@ -0,0 +1,106 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/auth/secure_storage.dart';
+import 'package:oxicloud/data/datasources/remote/app_password_remote_datasource.dart';
+import 'package:oxicloud/data/datasources/remote/device_auth_remote_datasource.dart';
+import 'package:oxicloud/data/repositories/session_repository_impl.dart';
+import 'package:oxicloud/domain/entities/session_entity.dart';
+import 'package:oxicloud/domain/repositories/auth_repository.dart';
+
+class _Devices extends Fake implements DeviceAuthRemoteDatasource {
+  final revoked = <String>[];
+
+  @override
+  Future<List<DeviceInfo>> listDevices() async => const [
+    DeviceInfo(
+      id: 'laptop',
+      name: 'Laptop',
+      platform: 'linux',
+      createdAt: '2026-01-01T00:00:00Z',
+      lastUsed: '2026-03-01T00:00:00Z',
+    ),
+    DeviceInfo(id: 'old', name: 'Old phone', createdAt: '2025-01-01T00:00:00Z'),
+  ];
+
+  @override
+  Future<void> revokeDevice(String deviceId) async => revoked.add(deviceId);
+}
+
+class _AppPasswords extends Fake implements AppPasswordRemoteDatasource {
+  final revoked = <String>[];
+
+  @override
+  Future<List<AppPasswordDto>> list() async => const [
+    AppPasswordDto(
+      id: 'this',
+      name: 'This computer',
+      prefix: 'ab',
+      createdAt: '2025-06-01T00:00:00Z',
+    ),
+    AppPasswordDto(
+      id: 'script',
+      name: 'Backup script',
+      prefix: 'cd',
+      createdAt: '2026-02-01T00:00:00Z',
+      lastUsed: '2026-04-01T00:00:00Z',
+    ),
+  ];
+
+  @override
+  Future<void> revoke(String id) async => revoked.add(id);
+}
+
+class _Auth extends Fake implements AuthRepository {
+  var logouts = 0;
+
+  @override
+  Future<void> logout() async {
+    logouts++;
+  }
+}
+
+void main() {
+  late _Devices devices;
+  late _AppPasswords appPasswords;
+  late _Auth auth;
+  late SessionRepositoryImpl sessions;
+
+  setUp(() async {
+    devices = _Devices();
+    appPasswords = _AppPasswords();
+    auth = _Auth();
+    final storage = SecureStorage.memory();
+    await storage.saveAppPassword('this', 'secret');
+    sessions = SessionRepositoryImpl(
+      devices: devices,
+      appPasswords: appPasswords,
+      secureStorage: storage,
+      auth: auth,
+    );
+  });
+
+  test('this device comes first, then the most recently used', () async {
+    final list = await sessions.listSessions();
+    expect(list.map((s) => s.id), ['this', 'script', 'laptop', 'old']);
+    expect(list.first.isCurrent, isTrue);
+    expect(list.where((s) => s.isCurrent), hasLength(1));
+    expect(list[2].kind, SessionKind.device);
+    expect(list[2].platform, 'linux');
+  });
+
+  test('revoking goes to the endpoint of its kind', () async {
+    final list = await sessions.listSessions();
+    await sessions.revokeSession(list.firstWhere((s) => s.id == 'script'));
+    await sessions.revokeSession(list.firstWhere((s) => s.id == 'old'));
+    expect(appPasswords.revoked, ['script']);
+    expect(devices.revoked, ['old']);
+    expect(auth.logouts, 0);
+  });
+
+  test('revoking this device signs out', () async {
+    final list = await sessions.listSessions();
+    await sessions.revokeSession(list.first);
+    expect(auth.logouts, 1);
+    expect(appPasswords.revoked, isEmpty);
+  });
+}
@@ -0,0 +1,121 @@
+import 'dart:math';
+
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/setup_scan.dart';
+import 'package:oxicloud/core/sync/sync_events.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/entities/folder_entity.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+
+class _Server extends Fake implements FolderRepository {
+  final listings = <String, FolderContents>{};
+  var inFlight = 0;
+  var mostInFlight = 0;
+
+  @override
+  Future<FolderContents> listRootContents() async => listings['']!;
+
+  @override
+  Future<FolderContents> listFolderContents(String folderId) async {
+    mostInFlight = max(mostInFlight, ++inFlight);
+    await Future<void>.delayed(const Duration(milliseconds: 5));
+    inFlight--;
+    return listings[folderId]!;
+  }
+}
+
+FolderEntity _folder(String id) => FolderEntity(
+  id: id,
+  name: id,
+  path: '/$id',
+  createdAt: DateTime(2026),
+  modifiedAt: DateTime(2026),
+);
+
+FileEntity _file(String id, int size) => FileEntity(
+  id: id,
+  name: id,
+  path: '/$id',
+  size: size,
+  mimeType: 'application/octet-stream',
+  createdAt: DateTime(2026),
+  modifiedAt: DateTime(2026),
+);
+
+void main() {
+  test('progress is estimated from storage use and reported', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    final events = SyncEventBus();
+    addTearDown(() async {
+      await db.close();
+      await events.dispose();
+    });
+    await db.upsertUser(
+      UserTableCompanion.insert(
+        id: 'u1',
+        username: 'me',
+        storageUsedBytes: const Value(400),
+      ),
+    );
+    final server = _Server()
+      ..listings[''] = FolderContents(
+        folders: [_folder('a'), _folder('b')],
+        files: [_file('f1', 100)],
+      )
+      ..listings['a'] = FolderContents(
+        folders: [_folder('c')],
+        files: [_file('f2', 100)],
+      )
+      ..listings['b'] = FolderContents(folders: [], files: [_file('f3', 100)])
+      ..listings['c'] = FolderContents(folders: [], files: [_file('f4', 100)]);
+
+    final scan = SetupScan(
+      db: db,
+      folderRepo: server,
+      events: events,
+      notifyInterval: Duration.zero,
+    );
+    final reported = events.on<SetupScanProgressed>().take(2).toList();
+    final result = (await scan.run())!;
+
+    final first = (await reported).first.progress;
+    expect(first.foldersDiscovered, 2);
+    expect(first.filesCounted, 1);
+    expect(first.estimatedTotalFiles, 4);
+    expect(first.fraction, 0.25);
+
+    expect(result.done, isTrue);
+    expect(result.foldersDiscovered, 3);
+    expect(result.foldersScanned, 3);
+    expect(result.filesCounted, 4);
+    expect(scan.progress, same(result));
+  });
+  test('top-level folders are listed side by side', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    final top = [for (var i = 0; i < 5; i++) _folder('t$i')];
+    final server = _Server()
+      ..listings[''] = FolderContents(folders: top, files: []);
+    for (final folder in top) {
+      server.listings[folder.id] = FolderContents(
+        folders: [_folder('${folder.id}s')],
+        files: [],
+      );
+      server.listings['${folder.id}s'] = FolderContents(
+        folders: [],
+        files: [_file('${folder.id}f', 1)],
+      );
+    }
+
+    final scan = SetupScan(db: db, folderRepo: server, concurrency: 3);
+    final result = (await scan.run())!;
+
+    expect(result.foldersScanned, 10);
+    expect(result.filesCounted, 5);
+    expect(server.mostInFlight, 3);
+  });
+}
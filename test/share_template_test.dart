@@ -0,0 +1,46 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/data/datasources/remote/share_remote_datasource.dart';
+import 'package:oxicloud/data/repositories/share_repository_impl.dart';
+import 'package:oxicloud/domain/entities/share_template.dart';
+
+class _Remote extends Fake implements ShareRemoteDatasource {}
+
+void main() {
+  test('templates round-trip and fall back to the first as default', () {
+    const templates = ShareTemplates(
+      templates: [
+        ShareTemplate(name: 'Team', downloadOnly: false),
+        ShareTemplate(name: 'Client', expiresInDays: 7, requirePassword: true),
+      ],
+      defaultName: 'Client',
+    );
+
+    final decoded = ShareTemplates.decode(
+      templates.encode(),
+      perAccount: true,
+    )!;
+    expect(decoded.perAccount, isTrue);
+    expect(decoded.defaultTemplate!.name, 'Client');
+    expect(
+      decoded.defaultTemplate!.summary,
+      '7 days · password · download only',
+    );
+    expect(decoded.templates.first.permissions.write, isTrue);
+
+    expect(decoded.copyWith(defaultName: 'Gone').defaultTemplate!.name, 'Team');
+    expect(ShareTemplates.decode('not json'), isNull);
+  });
+
+  test('links from a template that requires a password need one', () {
+    final repo = ShareRepositoryImpl(remote: _Remote());
+    expect(
+      () => repo.createShare(
+        itemId: 'f1',
+        itemType: 'file',
+        template: const ShareTemplate(name: 'Private', requirePassword: true),
+      ),
+      throwsArgumentError,
+    );
+  });
+}
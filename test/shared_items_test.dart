@@ -0,0 +1,51 @@
+import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/domain/entities/share_entity.dart';
+import 'package:oxicloud/domain/repositories/share_repository.dart';
+import 'package:oxicloud/providers.dart';
+
+/// Lists a link for each of [itemIds], or fails without them.
+class _Shares extends Fake implements ShareRepository {
+  final List<String>? itemIds;
+
+  _Shares(this.itemIds);
+
+  @override
+  Future<List<ShareEntity>> listShares() async {
+    final ids = itemIds;
+    if (ids == null) throw const ServerUnreachableException();
+    return [
+      for (final id in ids)
+        ShareEntity(
+          id: 's-$id',
+          itemId: id,
+          itemType: 'file',
+          token: id,
+          url: 'https://cloud.example/s/$id',
+          permissions: const SharePermissions(),
+          createdAt: DateTime(2026),
+          createdBy: 'u1',
+        ),
+    ];
+  }
+}
+
+void main() {
+  Future<Set<String>> sharedWith(ShareRepository shares) {
+    final container = ProviderContainer(
+      overrides: [shareRepositoryProvider.overrideWithValue(shares)],
+    );
+    addTearDown(container.dispose);
+    return container.read(sharedItemIdsProvider.future);
+  }
+
+  test('items with a share link are collected once each', () async {
+    expect(await sharedWith(_Shares(['f1', 'd1', 'f1'])), {'f1', 'd1'});
+  });
+
+  test('no badges when the shares cannot be listed', () async {
+    expect(await sharedWith(_Shares(null)), isEmpty);
+  });
+}
@@ -0,0 +1,164 @@
+import 'dart:async';
+import 'dart:io';
+
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/services/chunked_upload_service.dart';
+import 'package:oxicloud/core/services/shutdown_service.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/data/datasources/remote/chunked_upload_datasource.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/favorites_repository.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/domain/repositories/trash_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+}
+
+FileEntity _file(String id, String name) => FileEntity(
+  id: id,
+  name: name,
+  path: '/$name',
+  size: 1,
+  mimeType: 'text/plain',
+  createdAt: DateTime(2026),
+  modifiedAt: DateTime(2026),
+);
+
+/// Renames take as long as [delay], or forever without one. The server
+/// calls `f1` [name].
+class _Files extends Fake implements FileRepository {
+  final Duration? delay;
+  final started = Completer<void>();
+  var renames = 0;
+  var name = 'a.txt';
+
+  _Files([this.delay]);
+
+  @override
+  Future<FileEntity> getFile(String id) async => _file(id, name);
+
+  @override
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async {
+    renames++;
+    if (!started.isCompleted) started.complete();
+    final delay = this.delay;
+    if (delay == null) await Completer<void>().future;
+    await Future<void>.delayed(delay!);
+    return _file(id, newName);
+  }
+}
+
+class _Folders extends Fake implements FolderRepository {}
+
+class _Favorites extends Fake implements FavoritesRepository {}
+
+class _Trash extends Fake implements TrashRepository {}
+
+class _Chunks extends Fake implements ChunkedUploadDatasource {}
+
+void main() {
+  late AppDatabase db;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    final now = DateTime.now();
+    await db.insertSyncOp(
+      SyncQueueTableCompanion.insert(
+        operationType: 'rename',
+        itemId: 'f1',
+        itemType: 'file',
+        payload: '{"new_name": "b.txt"}',
+        createdAt: now,
+        updatedAt: now,
+      ),
+    );
+  });
+
+  SyncEngine engineWith(_Files files) {
+    final engine = SyncEngine(
+      db: db,
+      connectivity: _Online(),
+      fileRepo: files,
+      folderRepo: _Folders(),
+      favoritesRepo: _Favorites(),
+      trashRepo: _Trash(),
+    );
+    addTearDown(engine.dispose);
+    return engine;
+  }
+
+  /// Start a sync pass with [files] and shut down once its rename is
+  /// under way.
+  Future<ChunkedUploadService> shutDownDuring(_Files files) async {
+    final engine = engineWith(files);
+    final uploads = ChunkedUploadService(_Chunks());
+    unawaited(engine.sync());
+    await files.started.future;
+
+    await ShutdownService(
+      syncEngine: engine,
+      uploads: uploads,
+      timeout: const Duration(milliseconds: 100),
+    ).shutdown();
+    return uploads;
+  }
+
+  test('the operation in flight is allowed to finish', () async {
+    final uploads = await shutDownDuring(
+      _Files(const Duration(milliseconds: 10)),
+    );
+    expect((await db.getSyncOp(1))!.status, 'completed');
+    expect(await db.getUnfinishedOperations(), isEmpty);
+    await expectLater(
+      uploads.uploadFile(file: File('a.bin'), filename: 'a.bin'),
+      throwsStateError,
+    );
+  });
+
+  test('a hung operation is left for the next start', () async {
+    await shutDownDuring(_Files());
+    expect((await db.getSyncOp(1))!.status, 'inProgress');
+    // Left open, so the next start asks the server whether it went through.
+    expect((await db.getUnfinishedOperations()).single.syncOpId, 1);
+  });
+
+  test('the next start settles a hung operation with the server', () async {
+    await shutDownDuring(_Files());
+
+    // The server applied the rename before the app quit.
+    final files = _Files(Duration.zero)..name = 'b.txt';
+    await engineWith(files).recover();
+    expect((await db.getSyncOp(1))!.status, 'completed');
+    expect(await db.getUnfinishedOperations(), isEmpty);
+    expect(files.renames, 0);
+  });
+
+  test('a hung operation the server never saw is sent again', () async {
+    await shutDownDuring(_Files());
+
+    final files = _Files(Duration.zero);
+    final engine = engineWith(files);
+    await engine.recover();
+    expect((await db.getSyncOp(1))!.status, 'pending');
+    expect(await db.getUnfinishedOperations(), isEmpty);
+
+    await engine.sync();
+    expect(files.renames, 1);
+    expect((await db.getSyncOp(1))!.status, 'completed');
+  });
+}
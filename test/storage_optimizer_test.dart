@@ -0,0 +1,101 @@
+import 'dart:io';
+
+import 'package:drift/drift.dart' hide isNull;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/storage_optimizer.dart';
+
+void main() {
+  late Directory dir;
+  late AppDatabase db;
+  final now = DateTime(2026, 6, 1);
+  var free = 0;
+
+  setUp(() async {
+    dir = await Directory.systemTemp.createTemp('storage_optimizer_test');
+    db = AppDatabase(NativeDatabase.memory());
+    free = 100;
+  });
+  tearDown(() async {
+    await db.close();
+    await dir.delete(recursive: true);
+  });
+
+  Future<File> cached(
+    String id, {
+    required DateTime used,
+    bool pinned = false,
+    String state = 'ok',
+  }) async {
+    final file = File(p.join(dir.path, '$id.bin'));
+    await file.writeAsBytes(List.filled(50, 1));
+    await file.setLastAccessed(used);
+    await db.upsertCachedFile(
+      CachedFilesTableCompanion.insert(
+        fileId: id,
+        localPath: file.path,
+        sizeBytes: 50,
+        cachedAt: used,
+        lastAccessedAt: used,
+        isPinned: Value(pinned),
+        state: Value(state),
+      ),
+    );
+    return file;
+  }
+
+  StorageOptimizer optimizer() =>
+      StorageOptimizer(db: db, freeSpace: (_) async => free)
+        ..settings = const StorageOptimizerSettings(
+          enabled: true,
+          unusedDays: 30,
+          minFreeBytes: 180,
+        );
+
+  test('unused copies are removed oldest first until space is free', () async {
+    final oldest = await cached('a', used: DateTime(2026, 1, 1));
+    final older = await cached('b', used: DateTime(2026, 2, 1));
+    final third = await cached('c', used: DateTime(2026, 3, 1));
+    final recent = await cached('d', used: DateTime(2026, 5, 20));
+
+    expect(await optimizer().runNow(now: now), ['a.bin', 'b.bin']);
+    expect(oldest.existsSync(), isFalse);
+    expect(older.existsSync(), isFalse);
+    expect(third.existsSync(), isTrue);
+    expect(recent.existsSync(), isTrue);
+    expect(await db.getCachedFile('a'), isNull);
+
+    final activity = await db.getActivity();
+    expect(activity.map((a) => a.itemId), unorderedEquals(['a', 'b']));
+    expect(activity.first.category, 'storage');
+    expect(activity.first.bytes, 50);
+  });
+
+  test('pinned and locally edited copies are kept', () async {
+    final pinned = await cached(
+      'a',
+      used: DateTime(2026, 1, 1),
+      pinned: true,
+    );
+    final edited = await cached(
+      'b',
+      used: DateTime(2026, 1, 1),
+      state: 'pendingUpload',
+    );
+
+    expect(await optimizer().runNow(now: now), isEmpty);
+    expect(pinned.existsSync(), isTrue);
+    expect(edited.existsSync(), isTrue);
+  });
+
+  test('nothing is removed while there is enough space', () async {
+    free = 500;
+    final file = await cached('a', used: DateTime(2026, 1, 1));
+
+    expect(await optimizer().runNow(now: now), isEmpty);
+    expect(file.existsSync(), isTrue);
+  });
+}
@@ -0,0 +1,94 @@
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/sync/storage_quota.dart';
+import 'package:oxicloud/core/sync/sync_events.dart';
+import 'package:oxicloud/domain/entities/user_entity.dart';
+
+String _multistatus(int used, int available) =>
+    '<?xml version="1.0"?><d:multistatus xmlns:d="DAV:"><d:response>'
+    '<d:href>/webdav/</d:href><d:propstat><d:prop>'
+    '<d:quota-used-bytes>$used</d:quota-used-bytes>'
+    '<d:quota-available-bytes>$available</d:quota-available-bytes>'
+    '</d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat>'
+    '</d:response></d:multistatus>';
+
+/// Answers PROPFIND requests with [body].
+class _Adapter implements HttpClientAdapter {
+  String body = _multistatus(0, 0);
+  String? method;
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    method = options.method;
+    return ResponseBody.fromString(body, 207);
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  test('quotas are read from PROPFIND responses', () {
+    final quota = StorageQuota.parse(_multistatus(300, 700))!;
+    expect(quota.totalBytes, 1000);
+    expect(quota.fraction, 0.3);
+    expect(quota.fits(700), isTrue);
+    expect(quota.fits(701), isFalse);
+
+    final unlimited = StorageQuota.parse(_multistatus(300, -3))!;
+    expect(unlimited.availableBytes, isNull);
+    expect(unlimited.fits(1 << 40), isTrue);
+    expect(StorageQuota.parse('<d:multistatus/>'), isNull);
+  });
+
+  test('uploads that do not fit are refused and reported once', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    final adapter = _Adapter()..body = _multistatus(900, 100);
+    final events = SyncEventBus();
+    final full = <ServerStorageFull>[];
+    events.on<ServerStorageFull>().listen(full.add);
+    final service = StorageQuotaService(
+      dio: Dio()..httpClientAdapter = adapter,
+      db: db,
+      webdavUrl: 'https://cloud.test/webdav/',
+      currentUser: () async => const UserEntity(id: 'u1', username: 'ann'),
+      events: events,
+    );
+
+    await service.refresh();
+    expect(adapter.method, 'PROPFIND');
+    expect((await db.getCurrentUser())!.storageQuotaBytes, 1000);
+
+    service.ensureRoom('small.txt', 100);
+    expect(
+      () => service.ensureRoom('big.iso', 101),
+      throwsA(isA<QuotaExceededException>()),
+    );
+    expect(
+      () => service.ensureRoom('bigger.iso', 500),
+      throwsA(isA<QuotaExceededException>()),
+    );
+    await Future<void>.delayed(Duration.zero);
+    expect(full, hasLength(1));
+
+    final reloaded = StorageQuotaService(
+      dio: Dio(),
+      db: db,
+      webdavUrl: '',
+      currentUser: () => throw UnimplementedError(),
+    );
+    await reloaded.load();
+    expect(reloaded.quota!.availableBytes, 100);
+  });
+}
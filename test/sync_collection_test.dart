@@ -0,0 +1,197 @@
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/network/dav_multistatus.dart';
+import 'package:oxicloud/core/services/webdav_changes_service.dart';
+import 'package:oxicloud/core/sync/folder_scan_scheduler.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+}
+
+/// Reports [changes] since any token, handing out `t1`, `t2`, ...
+class _Changes extends Fake implements WebDavChangesService {
+  final asked = <String?>[];
+  var changes = <DavResource>[];
+
+  @override
+  Future<DavSyncReport?> changesSince(String path, String? token) async {
+    asked.add(token);
+    return DavSyncReport(changes, 't${asked.length}');
+  }
+}
+
+/// Lists one file called [name].
+class _Folders extends Fake implements FolderRepository {
+  var name = 'a.txt';
+  var listings = 0;
+
+  @override
+  Future<FolderContents> listFolderContents(String folderId) async {
+    listings++;
+    return FolderContents(
+      folders: const [],
+      files: [
+        FileEntity(
+          id: 'f1',
+          name: name,
+          path: '/Docs/$name',
+          size: 1,
+          mimeType: 'text/plain',
+          etag: '"v1"',
+          createdAt: DateTime(2026),
+          modifiedAt: DateTime(2026),
+        ),
+      ],
+    );
+  }
+}
+
+class _Offline extends Fake implements FolderRepository {
+  @override
+  Future<FolderContents> listFolderContents(String folderId) async =>
+      throw const NetworkException();
+}
+
+/// Answers reports with [status] until a token-less one, which gets
+/// `fresh`; keeps the bodies it was sent.
+class _Server implements HttpClientAdapter {
+  final int status;
+  final bodies = <String>[];
+
+  _Server(this.status);
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    final body = options.data as String;
+    bodies.add(body);
+    if (status == 403 && body.contains('<d:sync-token/>')) {
+      return ResponseBody.fromString(
+        '<d:multistatus xmlns:d="DAV:">'
+        '<d:sync-token>fresh</d:sync-token></d:multistatus>',
+        207,
+      );
+    }
+    return ResponseBody.fromString('', status);
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  group('scheduler', () {
+    late AppDatabase db;
+    late _Changes changes;
+    late _Folders folders;
+    late FolderScanScheduler scheduler;
+
+    setUp(() async {
+      db = AppDatabase(NativeDatabase.memory());
+      addTearDown(db.close);
+      await db.upsertFolder(
+        FoldersTableCompanion.insert(
+          id: 'd1',
+          name: 'Docs',
+          path: '/Docs',
+          createdAt: DateTime(2026),
+          modifiedAt: DateTime(2026),
+        ),
+      );
+      changes = _Changes();
+      folders = _Folders();
+      scheduler = FolderScanScheduler(
+        db: db,
+        folderRepo: folders,
+        connectivity: _Online(),
+        changes: changes,
+      );
+    });
+
+    /// Make the folder due and run a tick of [other], or [scheduler].
+    Future<int> rescan([FolderScanScheduler? other]) async {
+      final state = (await db.getFolderScanStates()).single;
+      await db.upsertFolderScanState(
+        state.copyWith(nextScanAt: DateTime(2000)).toCompanion(false),
+      );
+      return (other ?? scheduler).runDue();
+    }
+
+    test('a folder is listed only when the report has changes', () async {
+      await scheduler.runDue();
+      expect(changes.asked, [null]);
+      expect(folders.listings, 1);
+      expect((await db.getFolderScanStates()).single.syncToken, 't1');
+
+      expect(await rescan(), 0);
+      expect(changes.asked, [null, 't1']);
+      expect(folders.listings, 1, reason: 'nothing changed');
+      expect((await db.getFolderScanStates()).single.syncToken, 't2');
+
+      changes.changes = [const DavResource('/webdav/Docs/b.txt', {})];
+      folders.name = 'b.txt';
+      expect(await rescan(), 1);
+      expect(folders.listings, 2);
+      expect((await db.getFolderScanStates()).single.syncToken, 't3');
+    });
+
+    test('the token only moves on once the listing arrived', () async {
+      await scheduler.runDue();
+      changes.changes = [const DavResource('/webdav/Docs/b.txt', {})];
+      await rescan(
+        FolderScanScheduler(
+          db: db,
+          folderRepo: _Offline(),
+          connectivity: _Online(),
+          changes: changes,
+        ),
+      );
+      expect(changes.asked, [null, 't1']);
+      // Asked again from t1 next time, so the change is not lost.
+      expect((await db.getFolderScanStates()).single.syncToken, 't1');
+    });
+  });
+
+  group('reports', () {
+    test('an expired token starts over without one', () async {
+      final server = _Server(403);
+      final service = WebDavChangesService(
+        dio: Dio()..httpClientAdapter = server,
+        webdavUrl: 'http://cloud.test/webdav/',
+      );
+
+      final report = await service.changesSince('/Docs', 'a&b');
+      expect(report!.token, 'fresh');
+      expect(report.changes, isEmpty);
+      expect(server.bodies.first, contains('<d:sync-token>a&amp;b<'));
+      expect(server.bodies.last, contains('<d:sync-token/>'));
+      expect(service.isSupported, isTrue);
+    });
+
+    test('servers without the report are asked once', () async {
+      final server = _Server(405);
+      final service = WebDavChangesService(
+        dio: Dio()..httpClientAdapter = server,
+        webdavUrl: 'http://cloud.test/webdav/',
+      );
+
+      expect(await service.changesSince('/Docs', null), isNull);
+      expect(await service.changesSince('/Docs', null), isNull);
+      expect(server.bodies, hasLength(1));
+      expect(service.isSupported, isFalse);
+    });
+  });
+}
@@ -0,0 +1,62 @@
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/sync_direction.dart';
+import 'package:oxicloud/core/sync/sync_filter.dart';
+import 'package:oxicloud/domain/entities/folder_entity.dart';
+
+FolderEntity _folder(String id, String path) => FolderEntity(
+  id: id,
+  name: id,
+  path: path,
+  createdAt: DateTime(2026),
+  modifiedAt: DateTime(2026),
+);
+
+void main() {
+  late AppDatabase db;
+  late SyncDirectionService directions;
+
+  setUp(() {
+    db = AppDatabase(NativeDatabase.memory());
+    directions = SyncDirectionService(db);
+    addTearDown(db.close);
+  });
+
+  test('subfolders follow the closest folder with a direction', () async {
+    await directions.set(
+      _folder('camera', '/Camera'),
+      SyncDirection.uploadOnly,
+    );
+    expect(await directions.forPath('/Camera/2026'), SyncDirection.uploadOnly);
+    expect(await directions.forPath('/CameraRoll'), SyncDirection.twoWay);
+
+    await directions.set(
+      _folder('shared', '/Camera/Shared'),
+      SyncDirection.twoWay,
+    );
+    expect(await directions.forPath('/Camera/Shared/a'), SyncDirection.twoWay);
+    expect(await directions.forPath('/Camera/2026'), SyncDirection.uploadOnly);
+  });
+
+  test('download-only folders refuse uploads', () async {
+    final mirror = _folder('mirror', '/Mirror');
+    await db.upsertFolder(
+      FoldersTableCompanion.insert(
+        id: mirror.id,
+        name: mirror.name,
+        path: mirror.path,
+        createdAt: mirror.createdAt,
+        modifiedAt: mirror.modifiedAt,
+      ),
+    );
+    await directions.set(mirror, SyncDirection.downloadOnly);
+
+    await expectLater(
+      directions.ensureUploadAllowed('a.txt', 'mirror'),
+      throwsA(isA<SyncFilteredException>()),
+    );
+    await directions.ensureUploadAllowed('a.txt', null);
+  });
+}
@@ -0,0 +1,151 @@
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/core/sync/sync_errors.dart';
+import 'package:oxicloud/core/sync/sync_models.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/favorites_repository.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/domain/repositories/trash_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+}
+
+/// Refuses every rename with [error].
+class _Files extends Fake implements FileRepository {
+  final ServerException error;
+
+  _Files([this.error = const ForbiddenException()]);
+
+  @override
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async => throw error;
+}
+
+class _Folders extends Fake implements FolderRepository {}
+
+class _Favorites extends Fake implements FavoritesRepository {}
+
+class _Trash extends Fake implements TrashRepository {}
+
+SyncTask _rename() => SyncTask(
+  id: 'r',
+  operation: SyncOperation.rename,
+  entityType: 'file',
+  entityId: 'f1',
+  payload: const {'new_name': 'b.txt'},
+  createdAt: DateTime.now(),
+);
+
+SyncEngine _engine(AppDatabase db, _Files files) {
+  final engine = SyncEngine(
+    db: db,
+    connectivity: _Online(),
+    fileRepo: files,
+    folderRepo: _Folders(),
+    favoritesRepo: _Favorites(),
+    trashRepo: _Trash(),
+    retryPolicy: const SyncRetryPolicy(base: Duration(seconds: 10)),
+  );
+  addTearDown(engine.dispose);
+  return engine;
+}
+
+void main() {
+  test('failed items are recorded, retried and ignored', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    final engine = _engine(db, _Files());
+    final center = SyncErrorCenter(db: db, sync: engine.sync);
+
+    await engine.enqueue(_rename());
+    await engine.sync();
+    var errors = await center.failed();
+    expect(errors.single.itemId, 'f1');
+    expect(
+      SyncErrorCategory.parse(errors.single.category),
+      SyncErrorCategory.permission,
+    );
+    expect(engine.failedCount, 1);
+
+    await center.retry('f1');
+    errors = await center.failed();
+    expect(errors.single.retryCount, 2);
+
+    await center.ignore('f1');
+    expect(await center.failed(), isEmpty);
+    expect((await center.ignored()).single.itemId, 'f1');
+    expect(await db.getPendingSyncOps(), isEmpty);
+
+    await engine.enqueue(_rename());
+    expect(await db.getPendingSyncOps(), isEmpty);
+  });
+
+  test('busy servers are retried with growing delays', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    final engine = _engine(
+      db,
+      _Files(const ServerException('Unavailable', statusCode: 503)),
+    );
+    await engine.enqueue(_rename());
+
+    Future<Duration> waitAfterPass() async {
+      await db.setSyncOpPriority('f1', 0);
+      final started = DateTime.now();
+      await engine.sync();
+      final op = (await db.getPendingSyncOps()).single;
+      return op.scheduledAt!.difference(started);
+    }
+
+    final delays = [for (var i = 0; i < 6; i++) await waitAfterPass()];
+    for (var i = 1; i < delays.length; i++) {
+      expect(delays[i], greaterThan(delays[i - 1]));
+    }
+    final op = (await db.getPendingSyncOps()).single;
+    expect(op.status, 'pending');
+    expect(op.retryCount, 0);
+    expect(op.transientRetryCount, 6);
+    expect(await SyncErrorCenter(db: db, sync: engine.sync).failed(), isEmpty);
+  });
+
+  test('the server decides the wait with Retry-After', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    final engine = _engine(
+      db,
+      _Files(
+        const ServerException(
+          'Slow down',
+          statusCode: 429,
+          retryAfter: Duration(minutes: 5),
+        ),
+      ),
+    );
+    await engine.enqueue(_rename());
+    final started = DateTime.now();
+    await engine.sync();
+    final op = (await db.getPendingSyncOps()).single;
+    expect(
+      op.scheduledAt!.difference(started),
+      inInclusiveRange(
+        // Stored to the second.
+        const Duration(minutes: 4, seconds: 59),
+        const Duration(minutes: 5, seconds: 5),
+      ),
+    );
+  });
+}
@@ -0,0 +1,53 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/sync_events.dart';
+import 'package:oxicloud/core/sync/transfer_monitor.dart';
+
+void main() {
+  test('a transfer publishes progress and then its outcome', () async {
+    final bus = SyncEventBus();
+    final seen = <SyncEvent>[];
+    final sub = bus.events.listen(seen.add);
+    var now = DateTime(2026);
+    final monitor = TransferMonitor(clock: () => now, events: bus);
+
+    final id = monitor.begin(
+      name: 'a.txt',
+      direction: TransferDirection.upload,
+      totalBytes: 100,
+    );
+    monitor.update(id, 40);
+    // Inside the throttle window: no second progress event.
+    monitor.update(id, 60);
+    now = now.add(const Duration(seconds: 1));
+    monitor.update(id, 90);
+    monitor.complete(id);
+    await pumpEventQueue();
+
+    final progress = seen.whereType<TransferProgressed>().toList();
+    expect(progress.map((e) => e.bytes), [40, 90]);
+    expect(progress.first.total, 100);
+    final done = seen.last as ItemCompleted;
+    expect(done.itemId, id);
+    expect(done.action, 'upload');
+    expect(done.name, 'a.txt');
+
+    await sub.cancel();
+    await bus.dispose();
+  });
+
+  test('on() filters by event type', () async {
+    final bus = SyncEventBus();
+    final failed = <ItemFailed>[];
+    final sub = bus.on<ItemFailed>().listen(failed.add);
+
+    bus
+      ..emit(ItemQueued(itemId: '1', itemType: 'file', operation: 'delete'))
+      ..emit(ItemFailed(itemId: '1', action: 'delete', reason: 'gone'));
+    await pumpEventQueue();
+
+    expect(failed.single.reason, 'gone');
+    await sub.cancel();
+    await bus.dispose();
+  });
+}
@@ -0,0 +1,38 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/sync_filter.dart';
+
+void main() {
+  test('files above the size limit are ignored', () {
+    const filter = SyncFilter(maxFileSizeBytes: 2 * 1024 * 1024 * 1024);
+    expect(
+      filter.reasonToIgnore('disk.img', size: 3 * 1024 * 1024 * 1024),
+      'Larger than the 2 GB limit',
+    );
+    expect(filter.reasonToIgnore('notes.txt', size: 10), isNull);
+    expect(SyncFilter.none.reasonToIgnore('disk.img', size: 1 << 40), isNull);
+  });
+
+  test('types match by extension or MIME type', () {
+    final filter = SyncFilter(exclude: SyncFilter.parseTypes('.ISO, video/*'));
+    expect(
+      filter.reasonToIgnore('ubuntu.iso', size: 1),
+      'Excluded type (.ISO)',
+    );
+    expect(
+      filter.reasonToIgnore('clip', size: 1, mimeType: 'video/mp4'),
+      'Excluded type (video/*)',
+    );
+    expect(filter.reasonToIgnore('a.pdf', size: 1), isNull);
+  });
+
+  test('with include types, everything else is ignored', () {
+    const filter = SyncFilter(include: ['pdf', 'text/plain']);
+    expect(filter.reasonToIgnore('a.PDF', size: 1), isNull);
+    expect(
+      filter.reasonToIgnore('readme', size: 1, mimeType: 'text/plain'),
+      isNull,
+    );
+    expect(filter.reasonToIgnore('a.png', size: 1), isNotNull);
+  });
+}
@@ -0,0 +1,103 @@
+import 'dart:convert';
+
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/favorites_repository.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/domain/repositories/trash_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+}
+
+class _Files extends Fake implements FileRepository {
+  final names = <String, String>{};
+
+  @override
+  Future<FileEntity> getFile(String id) async {
+    final name = names[id];
+    if (name == null) throw const NotFoundException();
+    return FileEntity(
+      id: id,
+      name: name,
+      path: '/$name',
+      size: 1,
+      mimeType: 'text/plain',
+      createdAt: DateTime(2026),
+      modifiedAt: DateTime(2026),
+    );
+  }
+}
+
+class _Folders extends Fake implements FolderRepository {}
+
+class _Favorites extends Fake implements FavoritesRepository {}
+
+class _Trash extends Fake implements TrashRepository {}
+
+void main() {
+  test('interrupted operations already applied are not sent again', () async {
+    final db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    final files = _Files()
+      ..names['renamed'] = 'new.txt'
+      ..names['pending'] = 'old.txt';
+
+    Future<SyncQueueTableData> interrupted(
+      String type,
+      String itemId,
+      Map<String, dynamic> payload,
+    ) async {
+      final now = DateTime.now();
+      final id = await db.insertSyncOp(
+        SyncQueueTableCompanion.insert(
+          operationType: type,
+          itemId: itemId,
+          itemType: 'file',
+          payload: json.encode(payload),
+          createdAt: now,
+          updatedAt: now,
+        ),
+      );
+      await db.updateSyncOpStatus(id, 'inProgress');
+      final op = (await db.getSyncOp(id))!;
+      await db.startOperation(op);
+      return op;
+    }
+
+    final renamed = await interrupted('rename', 'renamed', {
+      'new_name': 'new.txt',
+    });
+    final deleted = await interrupted('delete', 'deleted', {});
+    final pending = await interrupted('rename', 'pending', {
+      'new_name': 'new.txt',
+    });
+
+    final engine = SyncEngine(
+      db: db,
+      connectivity: _Online(),
+      fileRepo: files,
+      folderRepo: _Folders(),
+      favoritesRepo: _Favorites(),
+      trashRepo: _Trash(),
+    );
+    addTearDown(engine.dispose);
+    await engine.recover();
+
+    expect((await db.getSyncOp(renamed.id))!.status, 'completed');
+    expect((await db.getSyncOp(deleted.id))!.status, 'completed');
+    expect((await db.getSyncOp(pending.id))!.status, 'pending');
+    expect(await db.getUnfinishedOperations(), isEmpty);
+  });
+}
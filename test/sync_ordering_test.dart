@@ -0,0 +1,84 @@
+import 'dart:convert';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/sync_ordering.dart';
+
+var _nextId = 0;
+
+SyncQueueTableData _op(
+  String type,
+  String itemId, {
+  Map<String, dynamic> payload = const {},
+  int priority = 0,
+}) {
+  return SyncQueueTableData(
+    id: _nextId++,
+    operationType: type,
+    status: 'pending',
+    itemId: itemId,
+    itemType: type.endsWith('Folder') ? 'folder' : 'file',
+    payload: json.encode(payload),
+    retryCount: 0,
+    createdAt: DateTime(2026),
+    updatedAt: DateTime(2026),
+    priority: priority,
+  );
+}
+
+List<String> _order(
+  List<SyncQueueTableData> ops, [
+  Map<String, String> paths = const {},
+]) => [
+  for (final op in SyncOrdering.sort(ops, paths: paths))
+    '${op.operationType} ${op.itemId}',
+];
+
+void main() {
+  test('creations, then renames, then updates, then deletions', () {
+    final ops = [
+      _op('delete', 'a', priority: 100),
+      _op('favorite', 'b', priority: 30),
+      _op('rename', 'b', payload: {'new_name': 'c'}),
+      _op('createFolder', 'new', payload: {'name': 'n'}),
+    ];
+    expect(_order(ops), [
+      'createFolder new',
+      'rename b',
+      'favorite b',
+      'delete a',
+    ]);
+  });
+
+  test('parent folders are created before their children', () {
+    final ops = [
+      _op('createFolder', 'child', payload: {'parent_id': 'parent'}),
+      _op('createFolder', 'other'),
+      _op('createFolder', 'parent'),
+    ];
+    expect(_order(ops), [
+      'createFolder other',
+      'createFolder parent',
+      'createFolder child',
+    ]);
+  });
+
+  test('deletions run bottom-up', () {
+    final ops = [
+      _op('deleteFolder', 'docs', priority: 20),
+      _op('deleteFolder', 'old', priority: 10),
+      _op('delete', 'note'),
+    ];
+    final paths = {
+      'docs': '/docs',
+      'old': '/docs/old',
+      'note': '/docs/old/note.txt',
+    };
+    expect(_order(ops, paths), [
+      'delete note',
+      'deleteFolder old',
+      'deleteFolder docs',
+    ]);
+  });
+}
@@ -0,0 +1,114 @@
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/sync/sync_engine.dart';
+import 'package:oxicloud/core/sync/sync_models.dart';
+import 'package:oxicloud/domain/repositories/favorites_repository.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+import 'package:oxicloud/domain/repositories/folder_repository.dart';
+import 'package:oxicloud/domain/repositories/trash_repository.dart';
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+
+  @override
+  Stream<bool> get onConnectivityChanged => const Stream.empty();
+}
+
+/// Nothing may reach the server during a dry run.
+class _Files extends Fake implements FileRepository {}
+
+class _Folders extends Fake implements FolderRepository {}
+
+class _Favorites extends Fake implements FavoritesRepository {}
+
+class _Trash extends Fake implements TrashRepository {}
+
+void main() {
+  late AppDatabase db;
+  late SyncEngine engine;
+
+  Future<void> cache(String id, String etag) {
+    return db.upsertFile(
+      FilesTableCompanion.insert(
+        id: id,
+        name: '$id.txt',
+        path: '/$id.txt',
+        size: 1,
+        mimeType: 'text/plain',
+        etag: Value(etag),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+  }
+
+  Future<void> queue(String type, String itemId, String payload) {
+    final now = DateTime.now();
+    return db.insertSyncOp(
+      SyncQueueTableCompanion.insert(
+        operationType: type,
+        itemId: itemId,
+        itemType: 'file',
+        payload: payload,
+        createdAt: now,
+        updatedAt: now,
+      ),
+    );
+  }
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    engine = SyncEngine(
+      db: db,
+      connectivity: _Online(),
+      fileRepo: _Files(),
+      folderRepo: _Folders(),
+      favoritesRepo: _Favorites(),
+      trashRepo: _Trash(),
+    );
+    addTearDown(engine.dispose);
+  });
+
+  test('each pending operation gets a predicted outcome', () async {
+    await cache('edited', '"v1"');
+    await cache('fav', '"v1"');
+    await engine.enqueue(
+      SyncTask(
+        id: 'r',
+        operation: SyncOperation.rename,
+        entityType: 'file',
+        entityId: 'edited',
+        payload: const {'new_name': 'b.txt'},
+        createdAt: DateTime.now(),
+      ),
+    );
+    // Listed again after someone else edited it.
+    await cache('edited', '"v2"');
+    await queue('favorite', 'fav', '{}');
+    await queue('frobnicate', 'x', '{}');
+    await queue('delete', 'y', 'not json');
+
+    final plan = {
+      for (final action in await engine.plan()) action.itemId: action,
+    };
+    expect(plan['edited']!.outcome, PlannedOutcome.conflict);
+    expect(plan['edited']!.path, '/edited.txt');
+    expect(plan['fav']!.outcome, PlannedOutcome.apply);
+    expect(plan['x']!.outcome, PlannedOutcome.skip);
+    expect(plan['x']!.reason, 'Unknown operation');
+    expect(plan['y']!.outcome, PlannedOutcome.skip);
+    expect(plan['y']!.reason, 'Unreadable payload');
+    expect(plan['y']!.path, isNull);
+
+    // The queue is left as it was.
+    final pending = await db.getPendingSyncOps();
+    expect(pending, hasLength(4));
+    expect(pending.every((op) => op.status == 'pending'), isTrue);
+  });
+}
@@ -0,0 +1,34 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/sync_models.dart';
+
+void main() {
+  test('user work goes first, then small files, then large ones', () {
+    const large = 100 * 1024 * 1024;
+    final ranked = [
+      SyncPriority.of(userInitiated: false, size: large),
+      SyncPriority.of(userInitiated: false, size: 1),
+      SyncPriority.of(userInitiated: true, size: large),
+      SyncPriority.of(userInitiated: true, size: 1),
+      SyncPriority.bumped,
+    ];
+    expect(ranked, orderedEquals([...ranked]..sort()));
+    expect(ranked.toSet(), hasLength(ranked.length));
+    expect(
+      SyncPriority.of(userInitiated: false),
+      SyncPriority.of(userInitiated: false, size: 1),
+    );
+  });
+
+  test('retry delays double up to the cap', () {
+    const policy = SyncRetryPolicy(
+      base: Duration(seconds: 5),
+      max: Duration(minutes: 1),
+    );
+    expect(
+      [for (var i = 1; i <= 6; i++) policy.delayFor(i).inSeconds],
+      [5, 10, 20, 40, 60, 60],
+    );
+    expect(policy.delayFor(1000), const Duration(minutes: 1));
+  });
+}
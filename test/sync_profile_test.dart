@@ -0,0 +1,46 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/sync_profile.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+
+FileEntity _file({String? etag, String? hash, String? mountType}) {
+  return FileEntity(
+    id: 'f1',
+    name: 'report.pdf',
+    path: '/report.pdf',
+    size: 1024,
+    mimeType: 'application/pdf',
+    etag: etag,
+    hash: hash,
+    mountType: mountType,
+    createdAt: DateTime.utc(2025),
+    modifiedAt: DateTime.utc(2025, 3, 1),
+  );
+}
+
+void main() {
+  test('own storage is compared by ETag and propagates deletes', () {
+    final profile = SyncProfile.forMount(null);
+
+    expect(profile.propagateDeletes, isTrue);
+    expect(profile.versionOf(_file(etag: 'e1', hash: 'h1')), 'e1');
+  });
+
+  test('external storage ignores ETags and keeps local copies', () {
+    final profile = SyncProfile.forMount('smb');
+
+    expect(profile.propagateDeletes, isFalse);
+    expect(profile.versionOf(_file(etag: 'e1', hash: 'h1')), 'h1');
+    expect(
+      profile.versionOf(_file(etag: 'e1')),
+      '1024:${DateTime.utc(2025, 3, 1).millisecondsSinceEpoch}',
+    );
+  });
+
+  test('deletes on external storage can be propagated on request', () {
+    final profile = SyncProfile.forMount('s3', propagateExternalDeletes: true);
+
+    expect(profile.propagateDeletes, isTrue);
+    expect(profile.trustEtags, isFalse);
+  });
+}
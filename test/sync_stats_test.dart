@@ -0,0 +1,105 @@
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/sync/sync_events.dart';
+import 'package:oxicloud/core/sync/sync_stats.dart';
+
+void main() {
+  late AppDatabase db;
+  late SyncEventBus events;
+  late SyncStatsCollector stats;
+
+  setUp(() {
+    db = AppDatabase(NativeDatabase.memory());
+    events = SyncEventBus();
+    stats = SyncStatsCollector(db: db, events: events);
+  });
+  tearDown(() async {
+    await db.close();
+    await events.dispose();
+  });
+
+  test('transfers and failures add up per day', () async {
+    final monday = DateTime(2026, 3, 2, 9);
+    await stats.record(
+      ItemCompleted(
+        itemId: 't1',
+        action: 'upload',
+        bytes: 3000,
+        elapsed: const Duration(seconds: 1),
+        at: monday,
+      ),
+    );
+    await stats.record(
+      ItemCompleted(
+        itemId: 't2',
+        action: 'download',
+        bytes: 1000,
+        elapsed: const Duration(seconds: 1),
+        at: monday.add(const Duration(hours: 5)),
+      ),
+    );
+    await stats.record(ItemFailed(itemId: 't3', action: 'upload', at: monday));
+    await stats.record(
+      ItemFailed(
+        itemId: 't4',
+        action: 'upload',
+        reason: 'Cancelled',
+        at: monday,
+      ),
+    );
+
+    final days = await stats.daily();
+    expect(days, hasLength(1));
+    final summary = await stats.summary();
+    expect(summary.bytesUploaded, 3000);
+    expect(summary.bytesDownloaded, 1000);
+    expect(summary.filesSynced, 2);
+    expect(summary.errors, 1);
+    expect(summary.averageBytesPerSecond, 2000);
+
+    final later = await stats.summary(since: DateTime(2026, 3, 3));
+    expect(later.filesSynced, 0);
+  });
+
+  test('applied changes mark their folder synced', () async {
+    await db.upsertFolder(
+      FoldersTableCompanion.insert(
+        id: 'docs',
+        name: 'Docs',
+        path: '/Docs',
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+    await db.upsertFile(
+      FilesTableCompanion.insert(
+        id: 'f1',
+        name: 'a.txt',
+        path: '/Docs/a.txt',
+        size: 1,
+        mimeType: 'text/plain',
+        folderId: const Value('docs'),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+
+    final at = DateTime(2026, 3, 2, 10);
+    await stats.record(ItemCompleted(itemId: 'f1', action: 'rename', at: at));
+
+    final folders = await stats.folders();
+    expect(folders.single.path, '/Docs');
+    expect(folders.single.lastSyncedAt, at);
+  });
+  test('clearing all data drops the statistics', () async {
+    await db.addSyncStats(DateTime(2026, 3, 2), filesSynced: 1);
+    await db.markFolderSynced('docs', DateTime(2026, 3, 2));
+
+    await db.clearAllData();
+    expect(await db.getSyncStats(), isEmpty);
+    expect(await db.getFolderSyncStats(), isEmpty);
+  });
+}
@@ -0,0 +1,74 @@
+import 'dart:io';
+
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/platform/temp_files.dart';
+import 'package:oxicloud/core/sync/ignore_rules.dart';
+
+void main() {
+  late Directory dir;
+  final allocator = TempFileAllocator();
+
+  setUp(() async {
+    dir = await Directory.systemTemp.createTemp('temp_files_test');
+  });
+  tearDown(() => dir.delete(recursive: true));
+
+  test('same-named targets get distinct hidden names beside them', () {
+    final a = allocator.allocate(p.join(dir.path, 'report.pdf'));
+    final b = allocator.allocate(p.join(dir.path, 'report.pdf'));
+
+    expect(a, isNot(b));
+    expect(p.dirname(a), dir.path);
+    expect(p.basename(a), startsWith('.'));
+    expect(TempFileAllocator.isTempName(p.basename(a)), isTrue);
+    expect(TempFileAllocator.isTempName('report.pdf'), isFalse);
+  });
+
+  test('temp files are ignored by folder scans', () async {
+    final temp = allocator.allocate(p.join(dir.path, 'a.txt'));
+
+    final ignore = IgnoreRuleCache();
+    expect(await ignore.isIgnored(dir.path, temp, isDirectory: false), isTrue);
+  });
+
+  test('commit replaces the target with the finished download', () async {
+    final target = File(p.join(dir.path, 'a.txt'));
+    await target.writeAsString('old');
+    final partial = allocator.allocate(target.path);
+    await File(partial).writeAsString('new');
+
+    await allocator.commit(partial, target.path);
+    expect(await target.readAsString(), 'new');
+    expect(File(partial).existsSync(), isFalse);
+  });
+
+  test('cleanup removes only stale temp files', () async {
+    final stale = File(allocator.allocate(p.join(dir.path, 'a.txt')));
+    final fresh = File(allocator.allocate(p.join(dir.path, 'b.txt')));
+    final kept = File(p.join(dir.path, 'c.txt'));
+    for (final file in [stale, fresh, kept]) {
+      await file.writeAsString('x');
+    }
+    final old = DateTime.now().subtract(const Duration(days: 2));
+    await stale.setLastModified(old);
+    await kept.setLastModified(old);
+
+    expect(await allocator.cleanUp([dir.path]), 1);
+    expect(stale.existsSync(), isFalse);
+    expect(fresh.existsSync(), isTrue);
+    expect(kept.existsSync(), isTrue);
+  });
+
+  test('cleanup spares downloads journaled to be continued', () async {
+    final partial = File(allocator.allocate(p.join(dir.path, 'a.txt')));
+    await partial.writeAsString('x');
+    await partial.setLastModified(
+      DateTime.now().subtract(const Duration(days: 2)),
+    );
+
+    expect(await allocator.cleanUp([dir.path], keep: {partial.path}), 0);
+    expect(partial.existsSync(), isTrue);
+  });
+}
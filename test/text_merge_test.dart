@@ -0,0 +1,48 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/text_merge.dart';
+
+void main() {
+  const base = 'title\n\nintro\nbody\nfooter\n';
+
+  test('edits to different lines are combined', () {
+    expect(
+      TextMerge.merge(
+        base,
+        'Title\n\nintro\nbody\nfooter\n',
+        'title\n\nintro\nbody\nfooter\nappendix\n',
+      ),
+      'Title\n\nintro\nbody\nfooter\nappendix\n',
+    );
+  });
+
+  test('identical edits on both sides merge', () {
+    const edited = 'title\n\nintro\nnew body\nfooter\n';
+    expect(TextMerge.merge(base, edited, edited), edited);
+  });
+
+  test('different edits to the same or adjacent lines conflict', () {
+    expect(
+      TextMerge.merge(
+        base,
+        'title\n\nintro\nmine\nfooter\n',
+        'title\n\nintro\ntheirs\nfooter\n',
+      ),
+      isNull,
+    );
+    expect(
+      TextMerge.merge(
+        base,
+        'title\n\nINTRO\nbody\nfooter\n',
+        'title\n\nintro\nBODY\nfooter\n',
+      ),
+      isNull,
+    );
+  });
+
+  test('only text files are merged', () {
+    expect(TextMerge.isMergeable('notes.md'), isTrue);
+    expect(TextMerge.isMergeable('data', mimeType: 'text/csv'), isTrue);
+    expect(TextMerge.isMergeable('photo.jpg'), isFalse);
+  });
+}
@@ -0,0 +1,115 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/throughput_estimator.dart';
+import 'package:oxicloud/core/sync/transfer_monitor.dart';
+
+void main() {
+  late DateTime now;
+  DateTime clock() => now;
+
+  setUp(() => now = DateTime(2024, 1, 1));
+
+  group('ThroughputEstimator', () {
+    test('speed is unknown until time has elapsed', () {
+      final estimator = ThroughputEstimator(clock: clock)..addSample(0);
+      expect(estimator.bytesPerSecond, 0);
+      expect(estimator.eta(1000), isNull);
+    });
+
+    test('steady transfer converges on the real rate', () {
+      final estimator = ThroughputEstimator(clock: clock)..addSample(0);
+      for (var i = 1; i <= 20; i++) {
+        now = now.add(const Duration(seconds: 1));
+        estimator.addSample(i * 1000);
+      }
+      expect(estimator.bytesPerSecond, closeTo(1000, 1));
+      expect(estimator.eta(5000), const Duration(seconds: 5));
+      expect(estimator.eta(0), Duration.zero);
+    });
+
+    test('old samples fall out of the window', () {
+      final estimator = ThroughputEstimator(
+        window: const Duration(seconds: 5),
+        smoothing: 1,
+        clock: clock,
+      )..addSample(0);
+      // Fast start, then a slow steady phase longer than the window.
+      now = now.add(const Duration(seconds: 1));
+      estimator.addSample(100000);
+      for (var i = 1; i <= 10; i++) {
+        now = now.add(const Duration(seconds: 1));
+        estimator.addSample(100000 + i * 100);
+      }
+      expect(estimator.bytesPerSecond, closeTo(100, 1));
+    });
+  });
+
+  group('TransferMonitor', () {
+    test('aggregates active transfers and drops them when done', () {
+      final monitor = TransferMonitor(clock: clock);
+      final a = monitor.begin(
+        name: 'a.bin',
+        direction: TransferDirection.upload,
+        totalBytes: 10000,
+      );
+      final b = monitor.begin(
+        name: 'b.bin',
+        direction: TransferDirection.download,
+        totalBytes: 10000,
+      );
+      for (var i = 1; i <= 4; i++) {
+        now = now.add(const Duration(seconds: 1));
+        monitor
+          ..update(a, i * 500)
+          ..update(b, i * 500);
+      }
+      expect(monitor.activeCount, 2);
+      // Smoothed towards the combined 1000 B/s, 16000 B still to go.
+      expect(monitor.bytesPerSecond, inInclusiveRange(800, 1000));
+      expect(monitor.eta!.inSeconds, inInclusiveRange(16, 20));
+      expect(monitor.progressOf(a)!.fraction, closeTo(0.2, 1e-9));
+
+      monitor
+        ..complete(a)
+        ..fail(b);
+      expect(monitor.activeCount, 0);
+      expect(monitor.bytesPerSecond, 0);
+      expect(monitor.eta, isNull);
+      expect(monitor.progressOf(a)!.state, TransferState.completed);
+
+      monitor.clearFinished();
+      expect(monitor.transfers, isEmpty);
+      monitor.dispose();
+    });
+
+    test('downloads of unknown size have no ETA', () {
+      final monitor = TransferMonitor(clock: clock);
+      final known = monitor.begin(
+        name: 'a.bin',
+        direction: TransferDirection.download,
+        totalBytes: 10000,
+      );
+      final unknown = monitor.begin(
+        name: 'b.bin',
+        direction: TransferDirection.download,
+        totalBytes: -1,
+      );
+      for (var i = 1; i <= 4; i++) {
+        now = now.add(const Duration(seconds: 1));
+        monitor
+          ..update(known, i * 500)
+          ..update(unknown, i * 500);
+      }
+      expect(monitor.progressOf(known)!.eta, isNotNull);
+      expect(monitor.progressOf(unknown)!.eta, isNull);
+      expect(monitor.bytesPerSecond, greaterThan(0));
+      expect(monitor.eta, isNull);
+
+      // The size arrives with a later chunk.
+      monitor.update(unknown, 2500, totalBytes: 10000);
+      expect(monitor.progressOf(unknown)!.eta, isNotNull);
+      expect(monitor.eta, isNotNull);
+      monitor.dispose();
+    });
+  });
+}
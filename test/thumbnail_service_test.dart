@@ -0,0 +1,60 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/services/thumbnail_service.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+class _Files extends Fake implements FileRepository {
+  final requested = <String>[];
+  final replies = <String, Completer<Uint8List>>{};
+
+  @override
+  Future<Uint8List> getThumbnail(String id, {String size = '256'}) {
+    requested.add(id);
+    return (replies[id] = Completer()).future;
+  }
+
+  void reply(String id) => replies[id]!.complete(Uint8List(1));
+}
+
+void main() {
+  test('prefetch stays within the concurrency limit', () async {
+    final files = _Files();
+    final service = ThumbnailService(files, maxConcurrent: 2);
+
+    service.prefetch(['a', 'b', 'c', 'd']);
+    expect(files.requested, ['a', 'b']);
+
+    files.reply('a');
+    await pumpEventQueue();
+    expect(files.requested, ['a', 'b', 'c']);
+    expect(service.cached('a'), isNotNull);
+  });
+
+  test('a visible tile jumps ahead of the prefetch', () async {
+    final files = _Files();
+    final service = ThumbnailService(files, maxConcurrent: 1);
+
+    service.prefetch(['a', 'b', 'c']);
+    final wanted = service.load('c');
+    files.reply('a');
+    await pumpEventQueue();
+    expect(files.requested, ['a', 'c']);
+
+    files.reply('c');
+    expect(await wanted, isNotNull);
+  });
+
+  test('navigating away drops prefetches that have not started', () async {
+    final files = _Files();
+    final service = ThumbnailService(files, maxConcurrent: 1);
+
+    service.prefetch(['a', 'b']);
+    service.prefetch(['x']);
+    files.reply('a');
+    await pumpEventQueue();
+    expect(files.requested, ['a', 'x']);
+  });
+}
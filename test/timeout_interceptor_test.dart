@@ -0,0 +1,68 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/interceptors/timeout_interceptor.dart';
+
+/// Never answers, like a server that accepted the connection and hung; it
+/// only gives up when the request is cancelled.
+class _HangingAdapter implements HttpClientAdapter {
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    await (cancelFuture ?? Completer<void>().future);
+    throw StateError('unreachable: dio reports the cancellation');
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  late TimeoutInterceptor timeouts;
+  late Dio dio;
+
+  setUp(() {
+    timeouts = TimeoutInterceptor(deadline: const Duration(milliseconds: 50));
+    dio = Dio(BaseOptions(baseUrl: 'https://cloud.test/api'))
+      ..httpClientAdapter = _HangingAdapter()
+      ..interceptors.add(timeouts);
+  });
+
+  test('API calls are cancelled once the deadline passes', () async {
+    await expectLater(
+      dio.get('/folders/a/listing'),
+      throwsA(
+        isA<DioException>().having(
+          (e) => e.type,
+          'type',
+          DioExceptionType.cancel,
+        ),
+      ),
+    );
+  });
+
+  test('cancelAll aborts downloads, which have no deadline', () async {
+    final download = dio.get<ResponseBody>(
+      '/files/a',
+      options: Options(responseType: ResponseType.stream),
+    );
+    await Future<void>.delayed(const Duration(milliseconds: 100));
+    timeouts.cancelAll('stop');
+    await expectLater(
+      download,
+      throwsA(
+        isA<DioException>().having(
+          (e) => e.type,
+          'type',
+          DioExceptionType.cancel,
+        ),
+      ),
+    );
+  });
+}
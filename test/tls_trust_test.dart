@@ -0,0 +1,50 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/network/tls_trust.dart';
+
+void main() {
+  const fingerprint =
+      '9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08';
+
+  test('accepted fingerprints and pinning decide what is trusted', () {
+    const open = TlsTrust(fingerprints: {fingerprint});
+    expect(open.accepts(fingerprint, systemTrusted: false), isTrue);
+    expect(open.accepts('other', systemTrusted: false), isFalse);
+    expect(open.accepts('other', systemTrusted: true), isTrue);
+
+    final pinned = open.copyWith(pinned: true);
+    expect(pinned.accepts(fingerprint, systemTrusted: false), isTrue);
+    expect(pinned.accepts('other', systemTrusted: true), isFalse);
+
+    // Pinning nothing would lock the account out.
+    const empty = TlsTrust(pinned: true);
+    expect(empty.isPinned, isFalse);
+    expect(empty.accepts('other', systemTrusted: true), isTrue);
+  });
+
+  test('round-trips through storage', () {
+    final trust = const TlsTrust(pinned: true).trust(fingerprint);
+    final decoded = TlsTrust.decode(trust.encode());
+    expect(decoded.fingerprints, {fingerprint});
+    expect(decoded.isPinned, isTrue);
+    expect(decoded.caPem, isNull);
+    expect(TlsTrust.decode('not json').fingerprints, isEmpty);
+  });
+
+  test('fingerprints display in colon-separated pairs', () {
+    expect(
+      UntrustedCertificate.formatFingerprint(fingerprint.substring(0, 8)),
+      '9F:86:D0:81',
+    );
+  });
+
+  test('a CA must be a PEM certificate', () {
+    expect(TlsTrust.validateCa('hello'), isNotNull);
+    expect(
+      TlsTrust.validateCa(
+        '-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----',
+      ),
+      isNotNull,
+    );
+  });
+}
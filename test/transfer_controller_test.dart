@@ -0,0 +1,67 @@
+import 'dart:async';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/sync/transfer_controller.dart';
+import 'package:oxicloud/core/sync/transfer_monitor.dart';
+
+void main() {
+  late TransferMonitor monitor;
+  late TransferController controller;
+  late List<bool> attempts;
+  late Completer<void> finish;
+
+  /// A transfer that runs until [finish] completes or it is stopped.
+  Future<String> start(Future<void> stop, {required bool resume}) {
+    attempts.add(resume);
+    return Future.any([
+      finish.future.then((_) => 'done'),
+      stop.then((_) => throw const RequestCancelledException()),
+    ]);
+  }
+
+  setUp(() {
+    monitor = TransferMonitor();
+    controller = TransferController(monitor);
+    attempts = [];
+    finish = Completer();
+    monitor.begin(
+      id: 'f1',
+      name: 'big.iso',
+      direction: TransferDirection.download,
+      totalBytes: 100,
+    );
+  });
+
+  test('a paused transfer restarts in resume mode', () async {
+    final result = controller.run('f1', start);
+    await pumpEventQueue();
+
+    expect(controller.pause('f1'), isTrue);
+    await pumpEventQueue();
+    expect(monitor.progressOf('f1')!.state, TransferState.paused);
+    expect(controller.isPaused('f1'), isTrue);
+
+    expect(controller.resume('f1'), isTrue);
+    await pumpEventQueue();
+    expect(monitor.progressOf('f1')!.state, TransferState.active);
+
+    finish.complete();
+    expect(await result, 'done');
+    expect(attempts, [false, true]);
+  });
+
+  test('cancelling a paused transfer ends it', () async {
+    final result = controller.run('f1', start);
+    await pumpEventQueue();
+    controller.pause('f1');
+    await pumpEventQueue();
+
+    expect(controller.cancel('f1'), isTrue);
+    await expectLater(result, throwsA(isA<RequestCancelledException>()));
+    expect(monitor.progressOf('f1')!.state, TransferState.cancelled);
+    expect(attempts, [false]);
+    expect(controller.resume('f1'), isFalse);
+  });
+}
@@ -0,0 +1,98 @@
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/network/connectivity_service.dart';
+import 'package:oxicloud/core/sync/transfer_ledger_verifier.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+final _uploaded = DateTime(2026, 3, 2, 10);
+
+class _Online extends Fake implements ConnectivityService {
+  @override
+  bool get isOnline => true;
+}
+
+/// What the server now reports for each uploaded file.
+class _Files extends Fake implements FileRepository {
+  @override
+  Future<FileEntity> getFile(String id) async {
+    final (size, modifiedAt) = switch (id) {
+      'ok' => (5, _uploaded),
+      'short' => (3, _uploaded),
+      'edited' => (9, _uploaded.add(const Duration(hours: 1))),
+      'gone' => throw const NotFoundException(),
+      _ => throw const ServerUnreachableException(),
+    };
+    return FileEntity(
+      id: id,
+      name: '$id.txt',
+      path: '/$id.txt',
+      size: size,
+      mimeType: 'text/plain',
+      createdAt: _uploaded,
+      modifiedAt: modifiedAt,
+    );
+  }
+}
+
+void main() {
+  late AppDatabase db;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    addTearDown(db.close);
+    for (final id in ['ok', 'short', 'edited', 'gone', 'flaky']) {
+      await db.insertLedgerEntry(
+        TransferLedgerTableCompanion.insert(
+          itemId: id,
+          itemName: '$id.txt',
+          size: 5,
+          remoteModifiedAt: Value(_uploaded),
+          uploadedAt: _uploaded,
+        ),
+      );
+    }
+  });
+
+  Future<Map<String, String>> statuses() async => {
+    for (final entry in await db.select(db.transferLedgerTable).get())
+      entry.itemId: entry.status,
+  };
+
+  test('uploads are checked against what the server reports', () async {
+    final verifier = TransferLedgerVerifier(
+      db: db,
+      fileRepo: _Files(),
+      connectivity: _Online(),
+    );
+    expect(await verifier.verify(), 1);
+    expect(await statuses(), {
+      'ok': 'verified',
+      'short': 'mismatch',
+      'edited': 'superseded',
+      'gone': 'superseded',
+      'flaky': 'pending',
+    });
+
+    final incident = (await db.getActivity()).single;
+    expect(incident.category, 'integrity');
+    expect(incident.itemId, 'short');
+    expect(incident.reason, 'Uploaded 5 bytes, server reports 3');
+  });
+
+  test('uploads left out of the sample are skipped', () async {
+    final verifier = TransferLedgerVerifier(
+      db: db,
+      fileRepo: _Files(),
+      connectivity: _Online(),
+      sampleRate: 0,
+    );
+    expect(await verifier.verify(), 0);
+    expect((await statuses()).values.toSet(), {'skipped'});
+    expect(await db.getActivity(), isEmpty);
+  });
+}
@@ -0,0 +1,142 @@
+import 'dart:async';
+
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/sync/transfer_scheduler.dart';
+
+void main() {
+  const mb = 1024 * 1024;
+
+  /// Occupies the only slot, queues [items] behind it, then releases it and
+  /// returns the order in which the queued items ran.
+  Future<List<String>> runOrder(
+    TransferScheduler scheduler,
+    List<(String, int)> items, {
+    void Function()? beforeRelease,
+  }) async {
+    final order = <String>[];
+    final gate = Completer<void>();
+    final first = scheduler.schedule(
+      id: 'gate',
+      name: 'gate',
+      size: 1,
+      run: () => gate.future,
+    );
+    final queued = [
+      for (final (id, size) in items)
+        scheduler.schedule(
+          id: id,
+          name: id,
+          size: size,
+          run: () async => order.add(id),
+        ),
+    ];
+    beforeRelease?.call();
+    gate.complete();
+    await first;
+    await Future.wait(queued);
+    return order;
+  }
+
+  const items = [
+    ('L1', 100 * mb),
+    ('L2', 100 * mb),
+    ('s1', 1),
+    ('s2', 1),
+    ('s3', 1),
+    ('s4', 1),
+    ('s5', 1),
+  ];
+
+  test('fifo keeps submission order', () async {
+    final scheduler = TransferScheduler(maxConcurrent: 1);
+    expect(await runOrder(scheduler, items), [
+      'L1',
+      'L2',
+      's1',
+      's2',
+      's3',
+      's4',
+      's5',
+    ]);
+  });
+
+  test('smallFirst favours small files without starving large ones', () async {
+    final scheduler = TransferScheduler(
+      maxConcurrent: 1,
+      policy: TransferPriorityPolicy.smallFirst,
+    );
+    expect(await runOrder(scheduler, items), [
+      's1',
+      's2',
+      's3',
+      's4',
+      'L1',
+      's5',
+      'L2',
+    ]);
+  });
+
+  test('boosted items jump the queue', () async {
+    final scheduler = TransferScheduler(
+      maxConcurrent: 1,
+      policy: TransferPriorityPolicy.smallFirst,
+    );
+    final order = await runOrder(
+      scheduler,
+      items,
+      beforeRelease: () {
+        expect(scheduler.boost('L2'), isTrue);
+        expect(scheduler.boost('missing'), isFalse);
+        expect(scheduler.queued.first.id, 'L2');
+      },
+    );
+    expect(order.first, 'L2');
+    expect(scheduler.queued, isEmpty);
+  });
+
+  test('never runs more than maxConcurrent at once', () async {
+    final scheduler = TransferScheduler(maxConcurrent: 2);
+    var running = 0;
+    var peak = 0;
+    await Future.wait([
+      for (var i = 0; i < 6; i++)
+        scheduler.schedule(
+          id: '$i',
+          name: '$i',
+          size: i * mb,
+          run: () async {
+            running++;
+            peak = running > peak ? running : peak;
+            await Future<void>.delayed(Duration.zero);
+            running--;
+          },
+        ),
+    ]);
+    expect(peak, 2);
+  });
+
+  test('throttled to zero, only boosted transfers start', () async {
+    final scheduler = TransferScheduler(maxConcurrent: 3)..throttle(0);
+    final started = <String>[];
+    final done = [
+      for (final id in ['a', 'b'])
+        scheduler.schedule(
+          id: id,
+          name: id,
+          size: 1,
+          run: () async => started.add(id),
+        ),
+    ];
+    await Future<void>.delayed(Duration.zero);
+    expect(started, isEmpty);
+
+    scheduler.boost('b');
+    await done[1];
+    expect(started, ['b']);
+
+    scheduler.throttle(null);
+    await done[0];
+    expect(started, ['b', 'a']);
+  });
+}
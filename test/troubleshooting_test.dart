@@ -0,0 +1,59 @@
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/services/diagnostics_service.dart';
+import 'package:oxicloud/core/services/troubleshooting_service.dart';
+
+void main() {
+  test('clock skew beyond two minutes is a finding', () {
+    final server = DateTime.utc(2026, 5, 1, 12);
+
+    expect(
+      DiagnosticsService.checkClockSkew(
+        server,
+        server.add(const Duration(seconds: 90)),
+      ),
+      isNull,
+    );
+    final finding = DiagnosticsService.checkClockSkew(
+      server,
+      server.subtract(const Duration(hours: 3)),
+    );
+    expect(finding?.kind, DiagnosticKind.clockSkew);
+    expect(finding?.detail, contains('3 hours behind'));
+  });
+
+  test('free space is read from df output', () {
+    const output =
+        'Filesystem     1024-blocks      Used Available Capacity Mounted on\n'
+        '/dev/nvme0n1p2   490691512 489000000    204800     100% /\n';
+
+    final free = DiagnosticsService.parseDfAvailable(output);
+    expect(free, 204800 * 1024);
+    expect(
+      DiagnosticsService.checkFreeSpace('/home/me', free!)?.kind,
+      DiagnosticKind.diskFull,
+    );
+    expect(DiagnosticsService.parseDfAvailable('df: /nope: not found'), isNull);
+  });
+
+  test('every finding has a guide ending in something to do', () {
+    for (final kind in DiagnosticKind.values) {
+      final guide = TroubleshootingService.guideFor(
+        DiagnosticFinding(kind, 'detail'),
+      );
+      expect(guide.steps, isNotEmpty, reason: '$kind');
+      expect(guide.steps.any((s) => s.action != null), isTrue, reason: '$kind');
+    }
+  });
+
+  test('the watch limit guide offers the sysctl command', () {
+    final guide = TroubleshootingService.guideFor(
+      DiagnosticsService.checkWatchLimit(8192)!,
+    );
+
+    final step = guide.steps.first;
+    expect(step.action, TroubleshootingAction.copyCommand);
+    expect(step.command, contains('fs.inotify.max_user_watches=524288'));
+    expect(DiagnosticsService.checkWatchLimit(524288), isNull);
+  });
+}
@@ -0,0 +1,156 @@
+import 'dart:io';
+
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/error/exceptions.dart';
+import 'package:oxicloud/core/services/checksum_service.dart';
+import 'package:oxicloud/core/sync/checksum_negotiation.dart';
+import 'package:oxicloud/core/sync/upload_dedup.dart';
+import 'package:oxicloud/domain/entities/file_entity.dart';
+import 'package:oxicloud/domain/repositories/file_repository.dart';
+
+class _Checksums extends Fake implements ChecksumService {
+  @override
+  Future<ChecksumAlgorithm?> algorithm() async => ChecksumAlgorithm.sha256;
+}
+
+/// The server has every file at ETag [etag] unless [offline].
+class _Files extends Fake implements FileRepository {
+  final copies = <String>[];
+  var etag = '"v1"';
+  var offline = false;
+
+  FileEntity _file(
+    String id,
+    String name,
+    String folderId, {
+    String? etag,
+  }) => FileEntity(
+    id: id,
+    name: name,
+    path: '/$name',
+    size: 5,
+    mimeType: 'text/plain',
+    folderId: folderId,
+    etag: etag ?? '"$id"',
+    createdAt: DateTime(2026),
+    modifiedAt: DateTime(2026),
+  );
+
+  @override
+  Future<FileEntity> getFile(String id) async {
+    if (offline) throw const ServerUnreachableException();
+    return _file(id, 'a.txt', 'home', etag: etag);
+  }
+
+  @override
+  Future<FileEntity> copyFile(String id, String targetFolderId) async {
+    copies.add('$id>$targetFolderId');
+    return _file('copy', 'a.txt', targetFolderId);
+  }
+
+  @override
+  Future<FileEntity> renameFile(
+    String id,
+    String newName, {
+    String? ifMatch,
+  }) async => _file(id, newName, 'docs');
+}
+
+void main() {
+  late AppDatabase db;
+  late _Files files;
+  late UploadDeduplicator dedup;
+  late ContentDigest hello;
+
+  setUp(() async {
+    db = AppDatabase(NativeDatabase.memory());
+    files = _Files();
+    dedup = UploadDeduplicator(db: db, files: files, checksums: _Checksums());
+    final dir = await Directory.systemTemp.createTemp('dedup');
+    final local = File(p.join(dir.path, 'a.txt'))..writeAsStringSync('hello');
+    hello = (await dedup.digestOf(local))!;
+    addTearDown(() async {
+      await db.close();
+      await dir.delete(recursive: true);
+    });
+
+    await db.upsertFile(
+      FilesTableCompanion.insert(
+        id: 'f1',
+        name: 'a.txt',
+        path: '/a.txt',
+        size: 5,
+        mimeType: 'text/plain',
+        folderId: const Value('home'),
+        etag: const Value('"v1"'),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+    await dedup.remember('f1', hello, etag: '"v1"');
+  });
+
+  test('unchanged content next to itself is not uploaded', () async {
+    final avoided = await dedup.avoidUpload(
+      hello,
+      name: 'a.txt',
+      folderId: 'home',
+    );
+    expect(avoided?.$1, DedupOutcome.unchanged);
+    expect(files.copies, isEmpty);
+  });
+
+  test('content found elsewhere is copied on the server', () async {
+    final avoided = await dedup.avoidUpload(
+      hello,
+      name: 'b.txt',
+      folderId: 'docs',
+    );
+    expect(avoided?.$1, DedupOutcome.copied);
+    expect(avoided?.$2.name, 'b.txt');
+    expect(files.copies, ['f1>docs']);
+  });
+
+  test('digests outdated by a change on the server are dropped', () async {
+    await db.upsertFile(
+      FilesTableCompanion.insert(
+        id: 'f1',
+        name: 'a.txt',
+        path: '/a.txt',
+        size: 5,
+        mimeType: 'text/plain',
+        folderId: const Value('home'),
+        etag: const Value('"v2"'),
+        createdAt: DateTime(2026),
+        modifiedAt: DateTime(2026),
+      ),
+    );
+    expect(
+      await dedup.avoidUpload(hello, name: 'a.txt', folderId: 'home'),
+      isNull,
+    );
+    expect(await db.getContentDigest('f1'), isNull);
+  });
+  test('a digest the server contradicts is not trusted', () async {
+    files.etag = '"v2"';
+    expect(
+      await dedup.avoidUpload(hello, name: 'a.txt', folderId: 'home'),
+      isNull,
+    );
+    expect(await db.getContentDigest('f1'), isNull);
+  });
+
+  test('content is uploaded when the server cannot confirm', () async {
+    files.offline = true;
+    expect(
+      await dedup.avoidUpload(hello, name: 'a.txt', folderId: 'home'),
+      isNull,
+    );
+    expect(await db.getContentDigest('f1'), isNotNull);
+  });
+}
@@ -0,0 +1,147 @@
+import 'dart:async';
+import 'dart:io';
+
+import 'package:drift/drift.dart' show Value;
+import 'package:drift/native.dart';
+import 'package:flutter/foundation.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as p;
+
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/services/chunked_upload_service.dart';
+import 'package:oxicloud/core/services/upload_session_cleanup_service.dart';
+import 'package:oxicloud/data/datasources/remote/chunked_upload_datasource.dart';
+
+class _Server extends Fake implements ChunkedUploadDatasource {
+  final List<UploadSession> sessions;
+  final cancelled = <String>[];
+
+  _Server(this.sessions);
+
+  @override
+  Future<List<UploadSession>> listSessions() async => sessions;
+
+  @override
+  Future<void> cancel(String uploadId) async => cancelled.add(uploadId);
+}
+
+/// Resumes only finish when [finish] completes.
+class _Uploads extends Fake implements ChunkedUploadService {
+  final resumed = <String>[];
+  final finish = Completer<void>();
+
+  @override
+  bool isActive(String uploadId) => false;
+
+  @override
+  Future<ChunkedUploadResult> resume(
+    UploadSessionsTableData entry, {
+    ValueChanged<double>? onProgress,
+  }) async {
+    resumed.add(entry.uploadId);
+    await finish.future;
+    return ChunkedUploadResult(
+      fileId: entry.uploadId,
+      filename: entry.filename,
+      size: entry.totalSize,
+      path: entry.localPath,
+    );
+  }
+}
+
+UploadSession _session(String id, {DateTime? createdAt}) => UploadSession(
+  uploadId: id,
+  chunkSize: 4,
+  totalChunks: 2,
+  expiresAt: DateTime.now().add(const Duration(days: 1)),
+  createdAt: createdAt ?? DateTime.now(),
+);
+
+void main() {
+  late AppDatabase db;
+  late Directory dir;
+
+  setUp(() {
+    db = AppDatabase(NativeDatabase.memory());
+    dir = Directory.systemTemp.createTempSync('oxicloud-uploads');
+    addTearDown(() async {
+      await db.close();
+      dir.deleteSync(recursive: true);
+    });
+  });
+
+  /// Journal an upload of a new local file [name].
+  Future<File> journal(String id, String name) async {
+    final file = File(p.join(dir.path, name))..writeAsStringSync('12345678');
+    final stat = file.statSync();
+    final now = DateTime.now();
+    await db.upsertUploadSession(
+      UploadSessionsTableCompanion.insert(
+        uploadId: id,
+        localPath: file.path,
+        filename: name,
+        totalSize: stat.size,
+        localModifiedAt: Value(stat.modified),
+        chunkSize: 4,
+        totalChunks: 2,
+        createdAt: now,
+        updatedAt: now,
+      ),
+    );
+    return file;
+  }
+
+  test('stale sessions of other devices are deleted', () async {
+    final server = _Server([
+      _session(
+        'old',
+        createdAt: DateTime.now().subtract(const Duration(days: 2)),
+      ),
+      _session('fresh'),
+    ]);
+    final report = await UploadSessionCleanupService(
+      datasource: server,
+      uploads: _Uploads(),
+      db: db,
+    ).run();
+    expect(server.cancelled, ['old']);
+    expect(report.deleted, 1);
+  });
+
+  test('resumes are queued without holding up the pass', () async {
+    await journal('ok', 'a.bin');
+    final uploads = _Uploads();
+    final service = UploadSessionCleanupService(
+      datasource: _Server([_session('ok')]),
+      uploads: uploads,
+      db: db,
+    );
+
+    final report = await service.run();
+    expect(report.resumed, 1);
+    expect(uploads.resumed, ['ok']);
+    expect((await service.run()).resumed, 0, reason: 'already queued');
+
+    uploads.finish.complete();
+    await service.resumesDone;
+    expect(uploads.resumed, ['ok']);
+  });
+
+  test('a file edited since the upload began is not resumed', () async {
+    final file = await journal('edited', 'b.bin');
+    file.writeAsStringSync('87654321');
+    file.setLastModifiedSync(DateTime.now().add(const Duration(minutes: 1)));
+    final server = _Server([_session('edited')]);
+    final uploads = _Uploads();
+
+    final report = await UploadSessionCleanupService(
+      datasource: server,
+      uploads: uploads,
+      db: db,
+    ).run();
+    expect(uploads.resumed, isEmpty);
+    expect(server.cancelled, ['edited']);
+    expect(report.deleted, 1);
+    expect(await db.getUploadSessions(), isEmpty);
+  });
+}
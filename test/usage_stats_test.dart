@@ -0,0 +1,81 @@
+import 'dart:typed_data';
+
+import 'package:dio/dio.dart';
+import 'package:drift/native.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+import 'package:oxicloud/core/auth/secure_storage.dart';
+import 'package:oxicloud/core/database/app_database.dart';
+import 'package:oxicloud/core/services/usage_stats_service.dart';
+import 'package:oxicloud/core/sync/sync_events.dart';
+import 'package:oxicloud/core/sync/sync_stats.dart';
+
+/// Accepts every request and records the paths it was sent to.
+class _Adapter implements HttpClientAdapter {
+  final paths = <String>[];
+
+  @override
+  Future<ResponseBody> fetch(
+    RequestOptions options,
+    Stream<Uint8List>? requestStream,
+    Future<void>? cancelFuture,
+  ) async {
+    paths.add(options.path);
+    return ResponseBody.fromString('', 204);
+  }
+
+  @override
+  void close({bool force = false}) {}
+}
+
+void main() {
+  late AppDatabase db;
+  late _Adapter adapter;
+  late UsageStatsService usage;
+
+  setUp(() {
+    db = AppDatabase(NativeDatabase.memory());
+    adapter = _Adapter();
+    usage = UsageStatsService(
+      db: db,
+      syncStats: SyncStatsCollector(db: db, events: SyncEventBus()),
+      storage: SecureStorage.memory(),
+      dio: Dio(BaseOptions(baseUrl: 'https://cloud.test/api'))
+        ..httpClientAdapter = adapter,
+    );
+  });
+  tearDown(() => db.close());
+
+  test('nothing is counted until usage statistics are turned on', () async {
+    await usage.count(UsageFeature.upload);
+    expect(await usage.counters(), isEmpty);
+
+    usage.settings = const UsageStatisticsSettings(enabled: true);
+    await usage.count(UsageFeature.upload);
+    await usage.count(UsageFeature.upload);
+    await usage.count(UsageFeature.search);
+    await db.addSyncStats(DateTime.now(), filesSynced: 3);
+
+    final report = await usage.report();
+    expect(report['features'], {'upload': 2, 'search': 1});
+    expect((report['sync_last_7_days'] as Map)['files_synced'], 3);
+  });
+
+  test('reports are only sent with the second opt-in, weekly', () async {
+    final now = DateTime(2024, 3, 1);
+    usage.settings = const UsageStatisticsSettings(enabled: true);
+    expect(await usage.uploadIfDue(now: now), isFalse);
+
+    usage.settings = const UsageStatisticsSettings(enabled: true, upload: true);
+    expect(await usage.uploadIfDue(now: now), isTrue);
+    expect(
+      await usage.uploadIfDue(now: now.add(const Duration(days: 1))),
+      isFalse,
+    );
+    expect(
+      await usage.uploadIfDue(now: now.add(const Duration(days: 7))),
+      isTrue,
+    );
+    expect(adapter.paths, hasLength(2));
+  });
+}